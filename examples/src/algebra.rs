@@ -0,0 +1,45 @@
+use graph_algorithms::{bfs_levels, sssp_distances, SparseMatrix};
+
+pub fn run() -> (Vec<Option<usize>>, Vec<usize>) {
+    // One-way streets between five intersections, lengths in meters.
+    let streets = vec![
+        (0, 1, 300),
+        (0, 2, 100),
+        (2, 1, 150),
+        (1, 3, 80),
+        (2, 3, 400),
+    ];
+
+    let hops = SparseMatrix::from_triplets(
+        5,
+        5,
+        streets
+            .iter()
+            .map(|&(source, target, _)| (source, target, true)),
+        |a, _| a,
+    )
+    .unwrap();
+    let lengths = SparseMatrix::from_triplets(5, 5, streets, usize::min).unwrap();
+
+    // Levels by boolean products, distances by min-plus products.
+    (
+        bfs_levels(&hops, 0).unwrap(),
+        sssp_distances(&lengths, 0).unwrap(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(
+            run(),
+            (
+                vec![Some(0), Some(1), Some(1), Some(2), None],
+                vec![0, 250, 100, 330, usize::MAX]
+            )
+        );
+    }
+}
@@ -0,0 +1,26 @@
+use graph_algorithms::DijkstraAlgorithm;
+
+pub fn run() -> Vec<Vec<usize>> {
+    // Data center fabric: two leaf switches joined through three spine switches.
+    let mut algorithm = DijkstraAlgorithm::new();
+
+    for spine in [1, 2, 3] {
+        algorithm.add_edge(0, spine, 1);
+        algorithm.add_edge(spine, 4, 1);
+    }
+
+    // Equal-cost paths the traffic between the leaves can be spread over.
+    let dag = algorithm.shortest_path_dag(0).unwrap();
+
+    dag.paths(4).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![vec![0, 1, 4], vec![0, 2, 4], vec![0, 3, 4]]);
+    }
+}
@@ -0,0 +1,35 @@
+use graph_algorithms::{AStarAlgorithm, GraphAlgorithm};
+
+pub fn run() -> Option<(usize, Vec<usize>)> {
+    // Ring road of six junctions with a bridge, travel times in minutes in both directions.
+    let mut algorithm = AStarAlgorithm::new();
+
+    for (source, target, minutes) in [
+        (0, 1, 5),
+        (1, 2, 4),
+        (2, 3, 6),
+        (3, 4, 3),
+        (4, 5, 7),
+        (5, 0, 2),
+        (1, 4, 9),
+    ] {
+        algorithm.set_edge(source, target, minutes);
+        algorithm.set_edge(target, source, minutes);
+    }
+
+    // Precompute the distances of two landmarks once, then query with the bounds they give.
+    let mut algorithm = algorithm.with_landmarks(2);
+    algorithm.set_target(3);
+
+    algorithm.run(Some(0)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), Some((12, vec![0, 5, 4, 3])));
+    }
+}
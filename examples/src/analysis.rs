@@ -0,0 +1,25 @@
+use graph_algorithms::{GraphAnalysis, GraphAnalyzer};
+
+pub fn run() -> GraphAnalysis {
+    let mut analyzer = GraphAnalyzer::new();
+    analyzer.set_edges(vec![
+        (0, vec![(1, 4), (2, 1)]),
+        (1, vec![(3, 1)]),
+        (2, vec![(1, 2), (3, 5)]),
+    ]);
+
+    analyzer.analyze()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let analysis = run();
+
+        assert!(analysis.is_dag);
+        assert_eq!(analysis.recommendations[0].algorithm, "DijkstraAlgorithm");
+    }
+}
@@ -0,0 +1,26 @@
+use graph_algorithms::SimilarityIndex;
+
+pub fn run() -> Vec<(usize, f64)> {
+    let mut index = SimilarityIndex::new();
+    index.set_vectors(vec![
+        vec![1.0, 0.0, 0.0],
+        vec![0.0, 1.0, 0.0],
+        vec![0.8, 0.2, 0.0],
+        vec![0.0, 0.0, 1.0],
+        vec![0.7, 0.0, 0.3],
+    ]);
+
+    index.similar_nodes(0, 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let nodes: Vec<usize> = run().into_iter().map(|(node, _)| node).collect();
+
+        assert_eq!(nodes, vec![2, 4]);
+    }
+}
@@ -0,0 +1,21 @@
+use graph_algorithms::{ApproximationAlgorithm, GraphAlgorithm};
+
+pub fn run() -> (Vec<usize>, Vec<usize>) {
+    let mut algorithm = ApproximationAlgorithm::new();
+    algorithm.set_edges(vec![(0, vec![1, 2]), (1, vec![3]), (2, vec![3])]);
+
+    (
+        algorithm.run(None).unwrap_or_default(),
+        algorithm.independent_set(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), (vec![0, 1, 2, 3], vec![0, 3]));
+    }
+}
@@ -0,0 +1,27 @@
+use graph_algorithms::{AStarAlgorithm, EuclideanHeuristic, GraphAlgorithm};
+
+pub fn run() -> Option<(usize, Vec<usize>)> {
+    // Intersections in metres, with travel times in seconds at up to 20 m/s.
+    let mut algorithm = AStarAlgorithm::with_heuristic(EuclideanHeuristic {
+        positions: vec![(0.0, 0.0), (400.0, 0.0), (400.0, 300.0), (800.0, 300.0)],
+        cost_per_unit: 1.0 / 20.0,
+    });
+    algorithm.set_edges(vec![
+        (0, vec![(1, 30), (2, 45)]),
+        (1, vec![(2, 20), (3, 40)]),
+        (2, vec![(3, 30)]),
+    ]);
+    algorithm.set_target(3);
+
+    algorithm.run(Some(0)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), Some((70, vec![0, 1, 3])));
+    }
+}
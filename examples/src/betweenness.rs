@@ -0,0 +1,24 @@
+use graph_algorithms::{BetweennessAlgorithm, GraphAlgorithm};
+
+pub fn run() -> Vec<f64> {
+    let mut algorithm = BetweennessAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, 1), (2, 1)]),
+        (1, vec![(2, 1)]),
+        (2, vec![(3, 1)]),
+        (3, vec![(4, 1)]),
+    ]);
+    algorithm.set_directed(false);
+
+    algorithm.run(None).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![0.0, 0.0, 4.0, 3.0, 0.0]);
+    }
+}
@@ -0,0 +1,18 @@
+use graph_algorithms::{BipartiteAlgorithm, GraphAlgorithm};
+
+pub fn run() -> Vec<usize> {
+    let mut algorithm = BipartiteAlgorithm::new();
+    algorithm.set_edges(vec![(0, vec![1, 3]), (1, vec![2]), (2, vec![3])]);
+
+    algorithm.run(None).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![0, 1, 0, 1]);
+    }
+}
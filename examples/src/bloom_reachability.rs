@@ -0,0 +1,31 @@
+use graph_algorithms::BloomReachabilityIndex;
+
+pub fn run() -> Vec<Option<bool>> {
+    let mut index = BloomReachabilityIndex::new();
+    index.set_edges(vec![
+        (0, vec![1, 2]),
+        (1, vec![3]),
+        (2, vec![3, 4]),
+        (5, vec![4]),
+    ]);
+
+    if index.build().is_err() {
+        return Vec::new();
+    }
+
+    vec![
+        index.can_reach(0, 4),
+        index.can_reach(4, 0),
+        index.can_reach_exact(5, 3),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![Some(true), Some(false), Some(false)]);
+    }
+}
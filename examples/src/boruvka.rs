@@ -0,0 +1,21 @@
+use graph_algorithms::{BoruvkaAlgorithm, GraphAlgorithm};
+
+pub fn run() -> Vec<(usize, usize, usize)> {
+    let mut algorithm = BoruvkaAlgorithm::new();
+    algorithm.set_edge(0, 1, 4);
+    algorithm.set_edge(1, 2, 1);
+    algorithm.set_edge(0, 2, 2);
+    algorithm.set_edge(2, 3, 5);
+
+    algorithm.run(None).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![(1, 2, 1), (0, 2, 2), (2, 3, 5)]);
+    }
+}
@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use graph_algorithms::DijkstraAlgorithm;
+
+pub fn run() -> (f64, Option<usize>) {
+    // Delivery hubs with travel times in minutes, refreshed for a dashboard every few seconds.
+    let mut algorithm = DijkstraAlgorithm::new();
+    algorithm.add_weighted_edges(vec![
+        (0, 1, 12),
+        (1, 0, 12),
+        (1, 2, 7),
+        (2, 3, 9),
+        (3, 1, 4),
+        (3, 0, 20),
+    ]);
+
+    // The headquarters at hub 3 goes first, then the busiest hubs while the budget lasts.
+    let matrix = algorithm.budgeted_distance_matrix(Duration::from_millis(100), &[3]);
+
+    (matrix.coverage(), matrix.distance(3, 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), (1.0, Some(11)));
+    }
+}
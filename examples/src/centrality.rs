@@ -0,0 +1,36 @@
+use graph_algorithms::{CentralityAlgorithm, CentralityMeasure};
+
+pub fn run() -> (Vec<f64>, Vec<f64>) {
+    let mut algorithm = CentralityAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, 1.0), (2, 1.0)]),
+        (1, vec![(2, 1.0)]),
+        (2, vec![(3, 1.0)]),
+    ]);
+    algorithm.set_directed(false);
+
+    let eigenvector = algorithm.centrality();
+
+    algorithm.set_measure(CentralityMeasure::Katz {
+        attenuation: 0.1,
+        base: 1.0,
+    });
+
+    let katz = algorithm.centrality();
+
+    (eigenvector.scores, katz.scores)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let (eigenvector, katz) = run();
+
+        assert_eq!(eigenvector.len(), 4);
+        assert!(eigenvector[2] > eigenvector[3]);
+        assert!(katz[2] > katz[0]);
+    }
+}
@@ -0,0 +1,26 @@
+use graph_algorithms::{ChinesePostmanAlgorithm, GraphAlgorithm};
+
+pub fn run() -> (usize, Vec<usize>) {
+    let mut algorithm = ChinesePostmanAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, 4), (2, 3)]),
+        (1, vec![(2, 2), (3, 5)]),
+        (2, vec![(3, 7)]),
+    ]);
+
+    algorithm.run(Some(0)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let (cost, walk) = run();
+
+        assert_eq!(cost, 23);
+        assert_eq!(walk.first(), Some(&0));
+        assert_eq!(walk.last(), Some(&0));
+    }
+}
@@ -0,0 +1,18 @@
+use graph_algorithms::ChordalAlgorithm;
+
+pub fn run() -> Vec<usize> {
+    let mut algorithm = ChordalAlgorithm::new();
+    algorithm.set_edges(vec![(0, vec![1, 2]), (1, vec![2, 3]), (2, vec![3])]);
+
+    algorithm.maximum_clique().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![0, 1, 2]);
+    }
+}
@@ -0,0 +1,34 @@
+use graph_algorithms::{ConstrainedPathAlgorithm, LabelAutomaton};
+
+const ROAD: usize = 0;
+const FERRY: usize = 1;
+
+pub fn run() -> Option<(usize, Vec<usize>)> {
+    // No two consecutive ferry edges.
+    let mut automaton = LabelAutomaton::new();
+    automaton.set_transition(0, ROAD, 0);
+    automaton.set_transition(0, FERRY, 1);
+    automaton.set_transition(1, ROAD, 0);
+    automaton.set_accepting(0);
+    automaton.set_accepting(1);
+
+    let mut algorithm = ConstrainedPathAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, 1, FERRY), (2, 4, ROAD)]),
+        (1, vec![(3, 1, FERRY)]),
+        (2, vec![(3, 1, ROAD)]),
+    ]);
+    algorithm.set_automaton(automaton);
+
+    algorithm.shortest_path(0, 3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), Some((5, vec![0, 2, 3])));
+    }
+}
@@ -0,0 +1,40 @@
+use graph_algorithms::ContractionHierarchiesAlgorithm;
+
+pub fn run() -> Vec<Option<usize>> {
+    // Two-way streets of a small town, with travel times in minutes.
+    let mut algorithm = ContractionHierarchiesAlgorithm::new();
+
+    for (source, target, minutes) in [
+        (0, 1, 4),
+        (1, 2, 3),
+        (2, 3, 2),
+        (0, 4, 7),
+        (4, 3, 1),
+        (1, 4, 2),
+    ] {
+        algorithm.set_edge(source, target, minutes);
+        algorithm.set_edge(target, source, minutes);
+    }
+
+    // Preprocess once, then answer every query on the hierarchy.
+    let hierarchy = algorithm.preprocess();
+
+    [(0, 3), (3, 0), (2, 4)]
+        .into_iter()
+        .map(|(source, target)| {
+            hierarchy
+                .shortest_path(source, target)
+                .map(|(cost, _)| cost)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![Some(7), Some(7), Some(3)]);
+    }
+}
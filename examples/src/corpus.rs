@@ -0,0 +1,20 @@
+use graph_algorithms::{BenchmarkCorpus, CorpusFormat, LoadedGraph};
+
+pub fn run() -> LoadedGraph {
+    let contents = "c two one-way streets\np sp 3 2\na 1 2 120\na 2 3 80\n";
+
+    BenchmarkCorpus::parse(contents, CorpusFormat::Dimacs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let graph = run();
+
+        assert_eq!(graph.total_nodes, 3);
+        assert_eq!(graph.edges, vec![(0, 1, 120), (1, 2, 80)]);
+    }
+}
@@ -0,0 +1,23 @@
+use graph_algorithms::{CycleEnumerationAlgorithm, GraphAlgorithm};
+
+pub fn run() -> Vec<Vec<usize>> {
+    let mut algorithm = CycleEnumerationAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![1]),
+        (1, vec![2, 3]),
+        (2, vec![0]),
+        (3, vec![0, 1]),
+    ]);
+
+    algorithm.run(None).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![vec![0, 1, 2], vec![0, 1, 3], vec![1, 3]]);
+    }
+}
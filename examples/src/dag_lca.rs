@@ -0,0 +1,23 @@
+use graph_algorithms::DagLcaAlgorithm;
+
+pub fn run() -> Vec<usize> {
+    let mut algorithm = DagLcaAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![1, 2]),
+        (1, vec![3, 4]),
+        (2, vec![3, 4]),
+        (3, vec![5]),
+    ]);
+
+    algorithm.lowest_common_ancestors(4, 5).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![1, 2]);
+    }
+}
@@ -0,0 +1,21 @@
+use graph_algorithms::{DeadlockDetector, GraphAlgorithm};
+
+pub fn run() -> Vec<usize> {
+    let mut detector = DeadlockDetector::new();
+    detector.set_wait(0, 1);
+    detector.set_wait(1, 2);
+    detector.set_wait(2, 0);
+    detector.set_wait(3, 1);
+
+    detector.run(None).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run().len(), 1);
+    }
+}
@@ -0,0 +1,26 @@
+use graph_algorithms::{DensestSubgraphAlgorithm, GraphAlgorithm};
+
+pub fn run() -> Option<Vec<usize>> {
+    // Accounts linked by transfers; accounts 2 to 5 all trade with each other.
+    let mut algorithm = DensestSubgraphAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![1, 2]),
+        (2, vec![3, 4, 5]),
+        (3, vec![4, 5]),
+        (4, vec![5]),
+        (5, vec![6]),
+        (6, vec![7]),
+    ]);
+
+    algorithm.run(None).ok().map(|subgraph| subgraph.nodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), Some(vec![2, 3, 4, 5]));
+    }
+}
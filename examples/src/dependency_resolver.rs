@@ -0,0 +1,25 @@
+use graph_algorithms::DependencyResolver;
+
+pub fn run() -> Vec<Vec<usize>> {
+    let mut resolver = DependencyResolver::new();
+    resolver.set_dependencies(vec![
+        (0, vec![1, 2]),
+        (1, vec![3]),
+        (2, vec![3]),
+        (4, vec![5]),
+    ]);
+    resolver.set_conflict(3, 5);
+    resolver.set_requested(vec![0]);
+
+    resolver.resolve().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![vec![3], vec![1], vec![2], vec![0]]);
+    }
+}
@@ -0,0 +1,21 @@
+use graph_algorithms::GraphDisplay;
+
+pub fn run() -> String {
+    let mut display = GraphDisplay::new();
+    display.set_edges(vec![(0, vec![(1, 4), (2, 1)]), (2, vec![(1, 2)])]);
+    display.set_path(vec![0, 2, 1]);
+
+    println!("{display}");
+
+    display.fmt_edge_list()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), "0 -> 1 (4)\n0 -> 2 (1) *\n2 -> 1 (2) *");
+    }
+}
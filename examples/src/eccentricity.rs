@@ -0,0 +1,24 @@
+use graph_algorithms::EccentricityAlgorithm;
+
+pub fn run() -> (Option<usize>, Option<usize>) {
+    let mut algorithm = EccentricityAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, 2), (2, 4)]),
+        (1, vec![(2, 1), (3, 7)]),
+        (2, vec![(4, 3)]),
+        (3, vec![(5, 1)]),
+        (4, vec![(3, 2), (5, 5)]),
+    ]);
+
+    (algorithm.bounded_diameter(), algorithm.bounded_radius())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), (Some(9), Some(6)));
+    }
+}
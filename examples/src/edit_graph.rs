@@ -0,0 +1,20 @@
+use graph_algorithms::{Alignment, EditGraph};
+
+pub fn run() -> Alignment {
+    let graph = EditGraph::new();
+
+    graph.align(b"graph", b"giraffe")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let alignment = run();
+
+        assert_eq!(alignment.cost, 4);
+        assert_eq!(alignment.operations.len(), 7);
+    }
+}
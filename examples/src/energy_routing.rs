@@ -0,0 +1,28 @@
+use graph_algorithms::{EnergyRoute, EnergyRoutingAlgorithm};
+
+pub fn run() -> Option<EnergyRoute> {
+    let mut algorithm = EnergyRoutingAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, 7), (2, 3)]),
+        (1, vec![(3, -4)]),
+        (2, vec![(3, 9)]),
+        (3, vec![(4, 5)]),
+    ]);
+    algorithm.set_battery(10, 10);
+    algorithm.set_charger(2);
+
+    algorithm.route(0, 4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let route = run().unwrap();
+
+        assert_eq!(route.path, vec![0, 1, 3, 4]);
+        assert_eq!(route.energy, 8);
+    }
+}
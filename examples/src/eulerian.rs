@@ -0,0 +1,18 @@
+use graph_algorithms::{EulerianAlgorithm, GraphAlgorithm};
+
+pub fn run() -> Vec<(usize, usize)> {
+    let mut algorithm = EulerianAlgorithm::new();
+    algorithm.set_edges(vec![(0, vec![1]), (1, vec![2]), (2, vec![0])]);
+
+    algorithm.run(Some(0)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![(0, 1), (1, 2), (2, 0)]);
+    }
+}
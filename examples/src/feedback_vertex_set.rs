@@ -0,0 +1,24 @@
+use graph_algorithms::{FeedbackVertexSetAlgorithm, GraphAlgorithm};
+
+pub fn run() -> Vec<usize> {
+    let mut algorithm = FeedbackVertexSetAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![1]),
+        (1, vec![2, 3]),
+        (2, vec![0]),
+        (3, vec![4]),
+        (4, vec![1]),
+    ]);
+
+    algorithm.run(None).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![1]);
+    }
+}
@@ -0,0 +1,27 @@
+use graph_algorithms::{DijkstraAlgorithm, GraphAlgorithm, GraphGenerator, RandomGraphModel};
+
+pub fn run() -> Vec<usize> {
+    let mut generator = GraphGenerator::new();
+    generator.set_total_nodes(10);
+    generator.set_model(RandomGraphModel::WattsStrogatz {
+        neighbors: 2,
+        rewiring: 0.0,
+    });
+
+    let graph = generator.run(None).unwrap();
+
+    let mut algorithm = DijkstraAlgorithm::new();
+    algorithm.set_nodes(graph.directed_nodes(1));
+
+    algorithm.run(Some(0)).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![0, 1, 2, 3, 4, 5, 4, 3, 2, 1]);
+    }
+}
@@ -0,0 +1,25 @@
+use graph_algorithms::{GeometricGraph, GraphAlgorithm, Neighborhood};
+
+pub fn run() -> Vec<usize> {
+    let mut graph = GeometricGraph::new();
+    graph.set_points(&[
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [1.0, 1.0, 1.0],
+        [4.0, 4.0, 4.0],
+    ]);
+    graph.set_neighborhood(Neighborhood::Radius(1.5));
+
+    graph.to_dijkstra(0.1).run(Some(0)).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![0, 10, 14, 24, usize::MAX]);
+    }
+}
@@ -0,0 +1,26 @@
+use graph_algorithms::{GirthAlgorithm, GraphAlgorithm, ShortestCycle};
+
+pub fn run() -> Option<ShortestCycle> {
+    let mut algorithm = GirthAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, 3)]),
+        (1, vec![(2, 2), (3, 1)]),
+        (2, vec![(0, 4)]),
+        (3, vec![(0, 2)]),
+    ]);
+
+    algorithm.run(None).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let cycle = run().unwrap();
+
+        assert_eq!(cycle.weight, 6);
+        assert_eq!(cycle.nodes, vec![0, 1, 3]);
+    }
+}
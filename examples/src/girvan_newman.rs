@@ -0,0 +1,25 @@
+use graph_algorithms::{GirvanNewmanAlgorithm, GraphAlgorithm};
+
+pub fn run() -> Vec<Vec<usize>> {
+    let mut algorithm = GirvanNewmanAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, 1), (2, 1), (3, 1)]),
+        (1, vec![(2, 1), (3, 1)]),
+        (2, vec![(3, 1), (4, 1)]),
+        (4, vec![(5, 1), (6, 1), (7, 1)]),
+        (5, vec![(6, 1), (7, 1)]),
+        (6, vec![(7, 1)]),
+    ]);
+
+    algorithm.run(None).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7]]);
+    }
+}
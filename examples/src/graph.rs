@@ -0,0 +1,36 @@
+use graph_algorithms::{BellmanFordAlgorithm, DiGraph, DijkstraAlgorithm, FloydWarshallAlgorithm};
+
+pub fn run() -> (Vec<i64>, Vec<i64>, Option<i64>) {
+    // Road network with travel times in seconds, built once and shared by the three algorithms.
+    let mut graph = DiGraph::new();
+    graph.add_weighted_edges([
+        (0, 1, 120),
+        (1, 2, 90),
+        (0, 2, 240),
+        (2, 3, 60),
+        (3, 0, 300),
+    ]);
+
+    let dijkstra = DijkstraAlgorithm::run_on(&graph, 0).unwrap();
+    let bellman_ford = BellmanFordAlgorithm::run_on(&graph, 0).unwrap();
+    let floyd_warshall = FloydWarshallAlgorithm::run_on(&graph).unwrap();
+
+    (
+        dijkstra.distances,
+        bellman_ford.distances,
+        floyd_warshall.distance(3, 2),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(
+            run(),
+            (vec![0, 120, 210, 270], vec![0, 120, 210, 270], Some(510))
+        );
+    }
+}
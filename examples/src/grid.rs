@@ -0,0 +1,31 @@
+use graph_algorithms::{Connectivity, GridGraph};
+
+pub fn run() -> Option<(usize, Vec<(usize, usize)>)> {
+    let mut grid = GridGraph::from_ascii(
+        "\
+....#
+.##.#
+.#3..
+.....",
+    );
+    grid.set_connectivity(Connectivity::Eight);
+    grid.set_step_costs(10, 14);
+
+    grid.shortest_path((0, 0), (2, 4))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let (cost, path) = run().unwrap();
+
+        assert_eq!(cost, 60);
+        assert_eq!(
+            path,
+            vec![(0, 0), (0, 1), (0, 2), (0, 3), (1, 3), (2, 3), (2, 4)]
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use graph_algorithms::{GraphAlgorithm, HeldKarpAlgorithm};
+
+pub fn run() -> (usize, Vec<usize>) {
+    let mut algorithm = HeldKarpAlgorithm::new();
+    algorithm.set_costs(vec![
+        vec![0, 2, 9, 10],
+        vec![1, 0, 6, 4],
+        vec![15, 7, 0, 8],
+        vec![6, 3, 12, 0],
+    ]);
+
+    algorithm.run(Some(0)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), (21, vec![0, 2, 3, 1, 0]));
+    }
+}
@@ -0,0 +1,26 @@
+use graph_algorithms::{Hypergraph, KruskalAlgorithm, MstAlgorithm};
+
+pub fn run() -> usize {
+    // Co-authorship: each paper joins its authors.
+    let mut hypergraph = Hypergraph::new();
+    hypergraph.set_hyperedge(vec![0, 1, 2], 1);
+    hypergraph.set_hyperedge(vec![2, 3], 2);
+
+    let mut algorithm = KruskalAlgorithm::new();
+
+    for (source, target, weight) in hypergraph.clique_expansion() {
+        algorithm.set_edge(source, target, weight);
+    }
+
+    algorithm.total_weight()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), 4);
+    }
+}
@@ -0,0 +1,56 @@
+use graph_algorithms::{GraphAlgorithm, IdaStarAlgorithm, SearchProblem};
+
+/// Towers of Hanoi with 4 disks, the peg of each disk from the smallest.
+struct Hanoi;
+
+impl SearchProblem for Hanoi {
+    type State = [u8; 4];
+
+    fn successors(&self, state: &[u8; 4]) -> Vec<([u8; 4], usize)> {
+        let mut moves = Vec::new();
+
+        for disk in 0..4 {
+            // Only the top disk of a peg moves, onto a peg without smaller disks.
+            if state[..disk].contains(&state[disk]) {
+                continue;
+            }
+
+            for peg in 0..3 {
+                if peg != state[disk] && !state[..disk].contains(&peg) {
+                    let mut next = *state;
+                    next[disk] = peg;
+                    moves.push((next, 1));
+                }
+            }
+        }
+
+        moves
+    }
+
+    fn heuristic(&self, state: &[u8; 4]) -> usize {
+        state.iter().filter(|&&peg| peg != 2).count()
+    }
+
+    fn is_goal(&self, state: &[u8; 4]) -> bool {
+        state.iter().all(|&peg| peg == 2)
+    }
+}
+
+pub fn run() -> usize {
+    let algorithm = IdaStarAlgorithm::new(Hanoi);
+
+    algorithm
+        .run(Some([0; 4]))
+        .map(|path| path.cost)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), 15);
+    }
+}
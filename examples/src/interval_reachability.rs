@@ -0,0 +1,31 @@
+use graph_algorithms::IntervalReachabilityIndex;
+
+pub fn run() -> Vec<Option<bool>> {
+    let mut index = IntervalReachabilityIndex::new();
+    index.set_edges(vec![(0, vec![1, 2]), (1, vec![3]), (2, vec![3])]);
+
+    if index.build().is_err() {
+        return Vec::new();
+    }
+
+    // A derived node pointing to its inputs is labeled without a rebuild.
+    let Ok(derived) = index.append_node(vec![2]) else {
+        return Vec::new();
+    };
+
+    vec![
+        index.can_reach(0, 3),
+        index.can_reach(derived, 3),
+        index.can_reach(derived, 1),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![Some(true), Some(true), Some(false)]);
+    }
+}
@@ -0,0 +1,19 @@
+use graph_algorithms::IntervalSchedulingAlgorithm;
+
+pub fn run() -> Option<Vec<Vec<usize>>> {
+    // Meetings from 9:00 to 14:00 in hours, to be allocated to rooms.
+    let mut algorithm = IntervalSchedulingAlgorithm::new();
+    algorithm.set_intervals(vec![(9, 11), (10, 12), (11, 13), (9, 10), (12, 14)]);
+
+    algorithm.machines().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), Some(vec![vec![0, 2], vec![3, 1, 4]]));
+    }
+}
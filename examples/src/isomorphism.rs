@@ -0,0 +1,35 @@
+use graph_algorithms::{IsomorphismAlgorithm, LabeledGraph, MatchKind};
+
+pub fn run() -> Vec<Vec<usize>> {
+    // Hydroxyl groups (C-O) in ethylene glycol HO-CH2-CH2-OH, heavy atoms labeled by element.
+    let mut molecule = LabeledGraph::new(false);
+    molecule.set_label(0, 8);
+    molecule.set_label(1, 6);
+    molecule.set_label(2, 6);
+    molecule.set_label(3, 8);
+    molecule.set_edge(0, 1, 1);
+    molecule.set_edge(1, 2, 1);
+    molecule.set_edge(2, 3, 1);
+
+    let mut hydroxyl = LabeledGraph::new(false);
+    hydroxyl.set_label(0, 6);
+    hydroxyl.set_label(1, 8);
+    hydroxyl.set_edge(0, 1, 1);
+
+    let mut algorithm = IsomorphismAlgorithm::new();
+    algorithm.set_pattern(hydroxyl);
+    algorithm.set_target(molecule);
+    algorithm.set_kind(MatchKind::Subgraph);
+
+    algorithm.mappings()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![vec![1, 0], vec![2, 3]]);
+    }
+}
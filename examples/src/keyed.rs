@@ -0,0 +1,25 @@
+use graph_algorithms::KeyedGraph;
+
+pub fn run() -> Option<(usize, Vec<&'static str>)> {
+    // Flights between airports with their durations in hours, keyed by airport code.
+    let mut graph = KeyedGraph::new();
+    graph.add_weighted_edges([
+        ("JFK", "LHR", 7),
+        ("LHR", "DXB", 7),
+        ("JFK", "DXB", 13),
+        ("DXB", "SIN", 8),
+        ("LHR", "SIN", 13),
+    ]);
+
+    graph.shortest_path(&"JFK", &"SIN")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), Some((20, vec!["JFK", "LHR", "SIN"])));
+    }
+}
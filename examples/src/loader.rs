@@ -0,0 +1,23 @@
+use graph_algorithms::{BellmanFordAlgorithm, BulkLoader, GraphAlgorithm};
+
+pub fn run() -> (Vec<i32>, usize) {
+    let mut loader = BulkLoader::new();
+    loader.set_allow_negative_weights(true);
+
+    let graph = loader.load(["0 1 4", "0 2 5", "1 2 -2", "2 x 1", "2 3 3"]);
+
+    let mut algorithm = BellmanFordAlgorithm::new();
+    algorithm.set_nodes(graph.nodes());
+
+    (algorithm.run(Some(0)).unwrap(), graph.report.errors.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), (vec![0, 4, 2, 5], 1));
+    }
+}
@@ -1,6 +1,78 @@
+mod algebra;
+mod all_shortest_paths;
+mod alt;
+mod analysis;
+mod ann;
+mod approximation;
+mod astar;
 mod bellman_ford;
+mod betweenness;
+mod bipartite;
+mod bloom_reachability;
+mod boruvka;
+mod budgeted_apsp;
+mod centrality;
+mod chinese_postman;
+mod chordal;
+mod constrained_path;
+mod contraction_hierarchies;
+mod corpus;
+mod cycles;
+mod dag_lca;
+mod deadlock;
+mod densest_subgraph;
+mod dependency_resolver;
 mod dijkstra;
+mod display;
+mod eccentricity;
+mod edit_graph;
+mod energy_routing;
+mod eulerian;
+mod feedback_vertex_set;
 mod floyd_warshall;
+mod generators;
+mod geometric;
+mod girth;
+mod girvan_newman;
+mod graph;
+mod grid;
+mod held_karp;
+mod hypergraph;
+mod ida_star;
+mod interval_reachability;
+mod interval_scheduling;
+mod isomorphism;
+mod keyed;
+mod kruskal;
+mod loader;
+mod matching;
+mod max_flow;
+mod morphism;
+mod od_matrix;
+mod pagerank;
+mod path_count;
+mod prim;
+mod properties;
+mod random_walk;
+mod rewiring;
+mod round_trip;
+mod router;
+mod sbm;
+mod series_parallel;
+mod signed;
+mod simplification;
+mod spectral;
+mod strahler;
+mod terrain;
+mod topological_sort;
+mod transit;
+mod transitive_closure;
+mod tree;
+mod tree_decomposition_dp;
+mod tree_lca;
+mod treewidth;
+mod triangles;
+mod tsp;
 
 fn main() {
     // Run the Dijkstra example
@@ -11,6 +83,222 @@ fn main() {
 
     // Run the Floyd-Warshall example
     floyd_warshall::run();
+
+    // Run the OD Matrix example
+    od_matrix::run();
+
+    // Run the Kruskal's example
+    kruskal::run();
+
+    // Run the Prim's example
+    prim::run();
+
+    // Run the Borůvka's example
+    boruvka::run();
+
+    // Run the Graph Simplification example
+    simplification::run();
+
+    // Run the Strahler Numbers example
+    strahler::run();
+
+    // Run the Topological Sort example
+    topological_sort::run();
+
+    // Run the Series-Parallel example
+    series_parallel::run();
+
+    // Run the Chordality Test example
+    chordal::run();
+
+    // Run the Treewidth example
+    treewidth::run();
+
+    // Run the Tree Decomposition DP example
+    tree_decomposition_dp::run();
+
+    // Run the Maximum Flow example
+    max_flow::run();
+
+    // Run the Graph Properties example
+    properties::run();
+
+    // Run the Graph Display example
+    display::run();
+
+    // Run the Bulk Loader example
+    loader::run();
+
+    // Run the Blossom example
+    matching::run();
+
+    // Run the Graph Morphism example
+    morphism::run();
+
+    // Run the Bipartiteness Test example
+    bipartite::run();
+
+    // Run the Label-Constrained Shortest Path example
+    constrained_path::run();
+
+    // Run the Hypergraph example
+    hypergraph::run();
+
+    // Run the Structural Balance example
+    signed::run();
+
+    // Run the Approximation Algorithms example
+    approximation::run();
+
+    // Run the Degree-Preserving Rewiring example
+    rewiring::run();
+
+    // Run the Hierholzer's example
+    eulerian::run();
+
+    // Run the Stochastic Block Model example
+    sbm::run();
+
+    // Run the Spectral Embedding example
+    spectral::run();
+
+    // Run the Held-Karp example
+    held_karp::run();
+
+    // Run the TSP Heuristics example
+    tsp::run();
+
+    // Run the Similarity Index example
+    ann::run();
+
+    // Run the Chinese Postman example
+    chinese_postman::run();
+
+    // Run the Bloom Reachability Index example
+    bloom_reachability::run();
+
+    // Run the PageRank example
+    pagerank::run();
+
+    // Run the Interval Reachability Index example
+    interval_reachability::run();
+
+    // Run the DAG Lowest Common Ancestors example
+    dag_lca::run();
+
+    // Run the Betweenness Centrality example
+    betweenness::run();
+
+    // Run the Dependency Resolver example
+    dependency_resolver::run();
+
+    // Run the Deadlock Detector example
+    deadlock::run();
+
+    // Run the Eigenvector and Katz Centrality example
+    centrality::run();
+
+    // Run the Feedback Vertex Set example
+    feedback_vertex_set::run();
+
+    // Run the Edit Graph example
+    edit_graph::run();
+
+    // Run the Girvan-Newman example
+    girvan_newman::run();
+
+    // Run the Triangle Counting example
+    triangles::run();
+
+    // Run the Eccentricity example
+    eccentricity::run();
+
+    // Run the Graph Analyzer example
+    analysis::run();
+
+    // Run the Transitive Closure example
+    transitive_closure::run();
+
+    // Run the Energy-Optimal Routing example
+    energy_routing::run();
+
+    // Run the Terrain Graph example
+    terrain::run();
+
+    // Run the Johnson's Cycles example
+    cycles::run();
+
+    // Run the Girth example
+    girth::run();
+
+    // Run the Transfer Patterns example
+    transit::run();
+
+    // Run the Tree Lowest Common Ancestor example
+    tree_lca::run();
+
+    // Run the Round Trip example
+    round_trip::run();
+
+    // Run the Tree Utilities example
+    tree::run();
+
+    // Run the Benchmark Corpus example
+    corpus::run();
+
+    // Run the Random Graph Generators example
+    generators::run();
+
+    // Run the Grid Graph example
+    grid::run();
+
+    // Run the Geometric Graph example
+    geometric::run();
+
+    // Run the VF2 Isomorphism example
+    isomorphism::run();
+
+    // Run the Random Walk example
+    random_walk::run();
+
+    // Run the IDA* example
+    ida_star::run();
+
+    // Run the A* example
+    astar::run();
+
+    // Run the Interval Scheduling example
+    interval_scheduling::run();
+
+    // Run the Contraction Hierarchies example
+    contraction_hierarchies::run();
+
+    // Run the Densest Subgraph example
+    densest_subgraph::run();
+
+    // Run the ALT example
+    alt::run();
+
+    // Run the Graph Algebra example
+    algebra::run();
+
+    // Run the Shortest Path Counting example
+    path_count::run();
+
+    // Run the All Shortest Paths example
+    all_shortest_paths::run();
+
+    // Run the Budgeted All-Pairs Shortest Paths example
+    budgeted_apsp::run();
+
+    // Run the Route Service example
+    router::run();
+
+    // Run the Keyed Graph example
+    keyed::run();
+
+    // Run the Shared Graph example
+    graph::run();
 }
 
 #[cfg(test)]
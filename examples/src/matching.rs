@@ -0,0 +1,22 @@
+use graph_algorithms::{GraphAlgorithm, MatchingAlgorithm};
+
+pub fn run() -> Vec<(usize, usize)> {
+    let mut algorithm = MatchingAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, 1), (2, 1), (3, 1)]),
+        (1, vec![(2, 1), (4, 1)]),
+        (2, vec![(5, 1)]),
+    ]);
+
+    algorithm.run(None).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![(0, 3), (1, 4), (2, 5)]);
+    }
+}
@@ -0,0 +1,26 @@
+use graph_algorithms::MaxFlowAlgorithm;
+
+pub fn run() -> Vec<(usize, usize, usize)> {
+    let mut algorithm = MaxFlowAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, 3), (2, 2)]),
+        (1, vec![(3, 1)]),
+        (2, vec![(3, 5)]),
+    ]);
+    algorithm.set_terminals(0, 3);
+
+    algorithm
+        .minimum_cut()
+        .map(|cut| cut.edges)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![(0, 2, 2), (1, 3, 1)]);
+    }
+}
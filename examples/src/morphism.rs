@@ -0,0 +1,25 @@
+use graph_algorithms::GraphMorphism;
+
+pub fn run() -> Vec<(usize, usize, usize)> {
+    let mut graph = GraphMorphism::new();
+    graph.set_edges(vec![
+        (2, vec![(5, 1)]),
+        (5, vec![(8, 2)]),
+        (8, vec![(2, 3)]),
+    ]);
+    graph.set_attribute(5, "hub");
+
+    graph.compact_ids();
+
+    graph.edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![(0, 1, 1), (1, 2, 2), (2, 0, 3)]);
+    }
+}
@@ -0,0 +1,25 @@
+use graph_algorithms::{GraphAlgorithm, OdMatrixAlgorithm};
+
+pub fn run() -> Vec<Vec<f64>> {
+    let mut algorithm = OdMatrixAlgorithm::new();
+    algorithm.set_costs(vec![vec![0, 2], vec![2, 0]]);
+    algorithm.set_production(0, 30.0);
+    algorithm.set_production(1, 10.0);
+    algorithm.set_attraction(0, 20.0);
+    algorithm.set_attraction(1, 20.0);
+
+    algorithm.run(None).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let result = run();
+
+        assert!((result[0][0] + result[0][1] - 30.0).abs() < 1e-3);
+        assert!((result[0][1] + result[1][1] - 20.0).abs() < 1e-3);
+    }
+}
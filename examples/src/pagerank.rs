@@ -0,0 +1,30 @@
+use graph_algorithms::{GraphAlgorithm, PageRankAlgorithm};
+
+pub fn run() -> Vec<f64> {
+    let mut algorithm = PageRankAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![1, 2]),
+        (1, vec![2]),
+        (2, vec![0]),
+        (3, vec![2]),
+    ]);
+    algorithm.set_damping(0.85);
+    algorithm.set_tolerance(1e-10);
+
+    algorithm.run(None).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let scores = run();
+
+        assert_eq!(scores.len(), 4);
+        assert!(scores[2] > scores[0]);
+        assert!(scores[0] > scores[1]);
+        assert!(scores[1] > scores[3]);
+    }
+}
@@ -0,0 +1,38 @@
+use graph_algorithms::{DijkstraAlgorithm, PathCounting};
+
+pub fn run() -> Vec<String> {
+    // Street grid of 3x3 blocks, every block one minute long, from the north-west corner.
+    let mut algorithm = DijkstraAlgorithm::new();
+
+    for row in 0..3 {
+        for column in 0..3 {
+            let node = row * 3 + column;
+
+            if column < 2 {
+                algorithm.add_edge(node, node + 1, 1);
+            }
+
+            if row < 2 {
+                algorithm.add_edge(node, node + 3, 1);
+            }
+        }
+    }
+
+    // Number of equally fast routes to each intersection.
+    algorithm
+        .count_shortest_paths(0, PathCounting::Exact)
+        .unwrap()
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec!["1", "1", "1", "1", "2", "3", "1", "3", "6"]);
+    }
+}
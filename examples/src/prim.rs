@@ -0,0 +1,21 @@
+use graph_algorithms::{GraphAlgorithm, PrimAlgorithm};
+
+pub fn run() -> Vec<(usize, usize, usize)> {
+    let mut algorithm = PrimAlgorithm::new();
+    algorithm.set_edge(0, 1, 4);
+    algorithm.set_edge(1, 2, 1);
+    algorithm.set_edge(0, 2, 2);
+    algorithm.set_edge(2, 3, 5);
+
+    algorithm.run(Some(0)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![(0, 2, 2), (2, 1, 1), (2, 3, 5)]);
+    }
+}
@@ -0,0 +1,18 @@
+use graph_algorithms::GraphProperties;
+
+pub fn run() -> (bool, bool) {
+    let mut properties = GraphProperties::new();
+    properties.set_edges(vec![(0, vec![1, 2]), (1, vec![3]), (2, vec![3])]);
+
+    (properties.is_dag(), properties.is_tree())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), (true, false));
+    }
+}
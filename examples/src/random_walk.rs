@@ -0,0 +1,29 @@
+use graph_algorithms::RandomWalkAlgorithm;
+
+pub fn run() -> Vec<Vec<usize>> {
+    let mut algorithm = RandomWalkAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, 1.0), (2, 1.0)]),
+        (1, vec![(2, 1.0)]),
+        (2, vec![(3, 1.0)]),
+    ]);
+    algorithm.set_walks(2, 5);
+    algorithm.set_restart_probability(0.2);
+    algorithm.set_seed(42);
+
+    algorithm.walks(&[0, 3])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let walks = run();
+
+        assert_eq!(walks.len(), 4);
+        assert!(walks.iter().all(|walk| walk.len() == 5));
+        assert_eq!(walks[1][0], 3);
+    }
+}
@@ -0,0 +1,33 @@
+use graph_algorithms::{GraphAlgorithm, RewiringAlgorithm};
+
+pub fn run() -> Vec<(usize, usize, usize)> {
+    let mut algorithm = RewiringAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, 1), (2, 1)]),
+        (1, vec![(3, 1)]),
+        (2, vec![(4, 1)]),
+        (3, vec![(5, 1)]),
+        (4, vec![(5, 1)]),
+    ]);
+    algorithm.set_seed(42);
+
+    algorithm.run(None).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let edges = run();
+        let mut degrees = [0; 6];
+
+        for (source, target, _) in edges {
+            degrees[source] += 1;
+            degrees[target] += 1;
+        }
+
+        assert_eq!(degrees, [2; 6]);
+    }
+}
@@ -0,0 +1,28 @@
+use graph_algorithms::{GraphAlgorithm, RoundTrip, RoundTripAlgorithm};
+
+pub fn run() -> Option<RoundTrip> {
+    let mut algorithm = RoundTripAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, 2), (2, 2)]),
+        (1, vec![(3, 2)]),
+        (2, vec![(4, 2)]),
+        (3, vec![(5, 2)]),
+        (4, vec![(5, 2)]),
+    ]);
+    algorithm.set_target_cost(12);
+
+    algorithm.run(Some(0)).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let trip = run().unwrap();
+
+        assert_eq!(trip.path, vec![0, 1, 3, 5, 4, 2, 0]);
+        assert_eq!(trip.cost, 12);
+    }
+}
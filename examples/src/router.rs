@@ -0,0 +1,32 @@
+use graph_algorithms::{Preprocessing, Router, RouterConfig};
+
+pub fn run() -> (Option<usize>, Vec<Vec<usize>>, usize) {
+    // City streets with travel times in seconds, preprocessed once with Contraction Hierarchies.
+    let mut config = RouterConfig::new();
+    config.set_preprocessing(Preprocessing::ContractionHierarchies);
+    config.set_undirected(true);
+
+    let streets = ["0 1 60", "1 2 45", "2 3 30", "0 4 90", "4 3 50", "3 5 20"];
+    let (router, _report) = Router::from_edge_list(config, streets);
+
+    // Save the preprocessed graph, so that other instances of the service start right away.
+    let mut artifact = Vec::new();
+    router.write_artifact(&mut artifact).unwrap();
+    let router = Router::read_artifact(artifact.as_slice()).unwrap();
+
+    let route = router.route(0, 5).map(|(cost, _)| cost);
+    let matrix = router.many_to_many(&[0, 5], &[2, 4]);
+    let reachable = router.isochrone(0, 120).len();
+
+    (route, matrix, reachable)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), (Some(155), vec![vec![105, 90], vec![50, 70]], 4));
+    }
+}
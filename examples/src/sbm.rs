@@ -0,0 +1,25 @@
+use graph_algorithms::{adjusted_rand_index, GraphAlgorithm, StochasticBlockModel};
+
+pub fn run() -> f64 {
+    let mut model = StochasticBlockModel::new();
+    model.set_block_sizes(vec![10, 10]);
+    model.set_probabilities(0.8, 0.05);
+    model.set_seed(42);
+
+    let graph = model.run(None).unwrap();
+
+    // Score a detection that swapped the labels of the two blocks.
+    let detected: Vec<usize> = graph.blocks.iter().map(|block| 1 - block).collect();
+
+    adjusted_rand_index(&graph.blocks, &detected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), 1.0);
+    }
+}
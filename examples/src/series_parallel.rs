@@ -0,0 +1,22 @@
+use graph_algorithms::{GraphAlgorithm, SeriesParallelAlgorithm};
+
+pub fn run() -> Option<f64> {
+    let mut algorithm = SeriesParallelAlgorithm::new();
+    algorithm.set_edges(vec![(0, vec![1, 2]), (1, vec![3]), (2, vec![3])]);
+    algorithm.set_terminals(0, 3);
+
+    algorithm
+        .run(None)
+        .ok()?
+        .evaluate(&[1.0, 2.0, 3.0, 4.0], |a, b| a + b, |a, b| a * b / (a + b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert!((run().unwrap() - 2.4).abs() < 1e-9);
+    }
+}
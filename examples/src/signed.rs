@@ -0,0 +1,21 @@
+use graph_algorithms::{GraphAlgorithm, Sign, SignedGraphAlgorithm};
+
+pub fn run() -> Vec<usize> {
+    let mut algorithm = SignedGraphAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, Sign::Positive), (2, Sign::Negative)]),
+        (1, vec![(2, Sign::Negative)]),
+    ]);
+
+    algorithm.run(None).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![0, 0, 1]);
+    }
+}
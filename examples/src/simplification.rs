@@ -0,0 +1,27 @@
+use graph_algorithms::{GraphAlgorithm, SimplificationAlgorithm};
+
+pub fn run() -> Vec<usize> {
+    let mut algorithm = SimplificationAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, 2)]),
+        (1, vec![(2, 3)]),
+        (2, vec![(3, 4)]),
+    ]);
+    algorithm.set_protected(0);
+    algorithm.set_protected(3);
+
+    algorithm
+        .run(None)
+        .map(|simplified| simplified.expand_path(&[0, 3]))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![0, 1, 2, 3]);
+    }
+}
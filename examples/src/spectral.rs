@@ -0,0 +1,29 @@
+use graph_algorithms::{GraphAlgorithm, SpectralAlgorithm};
+
+pub fn run() -> Vec<Vec<f64>> {
+    let mut algorithm = SpectralAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, 1.0)]),
+        (1, vec![(2, 1.0)]),
+        (2, vec![(3, 1.0)]),
+        (3, vec![(0, 1.0)]),
+    ]);
+
+    algorithm
+        .run(None)
+        .map(|result| result.coordinates)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let coordinates = run();
+
+        assert_eq!(coordinates.len(), 4);
+        assert!(coordinates.iter().all(|point| point.len() == 2));
+    }
+}
@@ -0,0 +1,21 @@
+use graph_algorithms::{GraphAlgorithm, StrahlerAlgorithm};
+
+pub fn run() -> Vec<usize> {
+    let mut algorithm = StrahlerAlgorithm::new();
+    algorithm.set_edges(vec![(0, vec![1, 2]), (1, vec![3, 4])]);
+
+    algorithm
+        .run(None)
+        .map(|metrics| metrics.strahler)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![2, 2, 1, 1, 1]);
+    }
+}
@@ -0,0 +1,28 @@
+use graph_algorithms::{CostModel, TerrainGraph};
+
+pub fn run() -> Option<(usize, Vec<usize>)> {
+    let mut graph = TerrainGraph::new();
+    graph.set_node(0, (0.0, 0.0), 120.0);
+    graph.set_node(1, (800.0, 0.0), 380.0);
+    graph.set_node(2, (600.0, 700.0), 150.0);
+    graph.set_node(3, (1500.0, 300.0), 200.0);
+    graph.set_road(0, 1);
+    graph.set_road(0, 2);
+    graph.set_road(1, 3);
+    graph.set_road(2, 3);
+    graph.set_model(CostModel::Hiking);
+
+    graph.to_dijkstra(1.0).shortest_path(0, 3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let (_, path) = run().unwrap();
+
+        assert_eq!(path, vec![0, 2, 3]);
+    }
+}
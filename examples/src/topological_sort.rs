@@ -0,0 +1,18 @@
+use graph_algorithms::TopologicalSortAlgorithm;
+
+pub fn run() -> Vec<Vec<usize>> {
+    let mut algorithm = TopologicalSortAlgorithm::new();
+    algorithm.set_edges(vec![(0, vec![2]), (1, vec![2]), (2, vec![3])]);
+
+    algorithm.topological_generations().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![vec![0, 1], vec![2], vec![3]]);
+    }
+}
@@ -0,0 +1,25 @@
+use graph_algorithms::{GraphAlgorithm, Journey, TransitAlgorithm};
+
+pub fn run() -> Option<Journey> {
+    let mut algorithm = TransitAlgorithm::new();
+    algorithm.set_transfer_time(3);
+    algorithm.set_trip(vec![(0, 0, 0), (1, 10, 10), (2, 25, 25)]);
+    algorithm.set_trip(vec![(1, 12, 15), (2, 20, 20)]);
+
+    let patterns = algorithm.run(Some(0)).unwrap();
+
+    patterns.query(0, 2, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let journey = run().unwrap();
+
+        assert_eq!(journey.arrival, 20);
+        assert_eq!(journey.pattern(), vec![0, 1, 2]);
+    }
+}
@@ -0,0 +1,23 @@
+use graph_algorithms::TransitiveClosureAlgorithm;
+
+pub fn run() -> Vec<usize> {
+    let mut algorithm = TransitiveClosureAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![1, 2]),
+        (1, vec![3]),
+        (2, vec![3]),
+        (3, vec![4]),
+    ]);
+
+    algorithm.closure().reachable(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,28 @@
+use graph_algorithms::{GraphAlgorithm, TreeAlgorithm};
+
+pub fn run() -> (usize, Vec<usize>, Vec<usize>) {
+    let mut algorithm = TreeAlgorithm::new();
+    algorithm.set_edges(vec![
+        (0, vec![(1, 2), (2, 3)]),
+        (1, vec![(3, 4)]),
+        (2, vec![(4, 1)]),
+    ]);
+
+    let (length, _) = algorithm.run(None).unwrap();
+
+    (
+        length,
+        algorithm.center().unwrap(),
+        algorithm.centroid().unwrap(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), (10, vec![0, 1], vec![0]));
+    }
+}
@@ -0,0 +1,19 @@
+use graph_algorithms::{GraphAlgorithm, IndependentSetAlgorithm};
+
+pub fn run() -> (usize, Vec<usize>) {
+    let mut algorithm = IndependentSetAlgorithm::new();
+    algorithm.set_edges(vec![(0, vec![1]), (1, vec![2]), (2, vec![3])]);
+    algorithm.set_weight(1, 5);
+
+    algorithm.run(None).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), (6, vec![1, 3]));
+    }
+}
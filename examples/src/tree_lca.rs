@@ -0,0 +1,20 @@
+use graph_algorithms::{GraphAlgorithm, TreeLcaAlgorithm};
+
+pub fn run() -> (Option<usize>, Option<usize>) {
+    let mut algorithm = TreeLcaAlgorithm::new();
+    algorithm.set_edges(vec![(0, vec![(1, 2), (2, 5)]), (1, vec![(3, 1), (4, 3)])]);
+
+    let index = algorithm.run(Some(0)).unwrap();
+
+    (index.lca(3, 4), index.distance(3, 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), (Some(1), Some(8)));
+    }
+}
@@ -0,0 +1,22 @@
+use graph_algorithms::{EliminationHeuristic, GraphAlgorithm, TreewidthAlgorithm};
+
+pub fn run() -> usize {
+    let mut algorithm = TreewidthAlgorithm::new();
+    algorithm.set_edges(vec![(0, vec![1, 3]), (1, vec![2]), (2, vec![3])]);
+    algorithm.set_heuristic(EliminationHeuristic::MinFill);
+
+    algorithm
+        .run(None)
+        .map(|decomposition| decomposition.width)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), 2);
+    }
+}
@@ -0,0 +1,18 @@
+use graph_algorithms::{GraphAlgorithm, TriangleAlgorithm};
+
+pub fn run() -> Vec<f64> {
+    let mut algorithm = TriangleAlgorithm::new();
+    algorithm.set_edges(vec![(0, vec![1, 2, 3]), (1, vec![2]), (2, vec![3])]);
+
+    algorithm.run(None).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        assert_eq!(run(), vec![2.0 / 3.0, 1.0, 2.0 / 3.0, 1.0]);
+    }
+}
@@ -0,0 +1,28 @@
+use graph_algorithms::{GraphAlgorithm, TourConstruction, TspHeuristicAlgorithm};
+
+pub fn run() -> (usize, Vec<usize>) {
+    let mut algorithm = TspHeuristicAlgorithm::new();
+    algorithm.set_costs(vec![
+        vec![0, 2, 9, 10, 7],
+        vec![2, 0, 6, 4, 3],
+        vec![9, 6, 0, 8, 5],
+        vec![10, 4, 8, 0, 6],
+        vec![7, 3, 5, 6, 0],
+    ]);
+    algorithm.set_construction(TourConstruction::GreedyEdge);
+
+    algorithm.run(Some(0)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_run() {
+        let (length, tour) = run();
+
+        assert_eq!(length, 26);
+        assert_eq!(tour.len(), 6);
+    }
+}
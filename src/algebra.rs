@@ -0,0 +1,825 @@
+use std::{
+    marker::PhantomData,
+    ops::{Add, Mul},
+};
+
+use crate::GraphError;
+
+/// Semiring over which matrices are multiplied: `⊕` combines alternatives, such as paths to the
+/// same node, and `⊗` extends them, such as a path by an edge.
+pub trait Semiring {
+    /// Type of the values.
+    type Value: Copy;
+
+    /// Identity of `⊕` and annihilator of `⊗`, the value of missing entries.
+    ///
+    /// # Returns
+    ///
+    /// Zero of the semiring.
+    fn zero(&self) -> Self::Value;
+
+    /// Combine two alternatives.
+    ///
+    /// # Arguments
+    ///
+    /// - `a`: First value.
+    /// - `b`: Second value.
+    ///
+    /// # Returns
+    ///
+    /// `a ⊕ b`.
+    fn add(&self, a: Self::Value, b: Self::Value) -> Self::Value;
+
+    /// Extend a value by another.
+    ///
+    /// # Arguments
+    ///
+    /// - `a`: First value.
+    /// - `b`: Second value.
+    ///
+    /// # Returns
+    ///
+    /// `a ⊗ b`.
+    fn multiply(&self, a: Self::Value, b: Self::Value) -> Self::Value;
+}
+
+/// Arithmetic semiring `(+, ×)`, counting paths or propagating scores.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlusTimes<T>(PhantomData<T>);
+
+impl<T: Copy + Default + Add<Output = T> + Mul<Output = T>> Semiring for PlusTimes<T> {
+    /// Type of the values.
+    type Value = T;
+
+    /// Zero of the number type.
+    ///
+    /// # Returns
+    ///
+    /// Default value of the number type.
+    fn zero(&self) -> T {
+        T::default()
+    }
+
+    /// Add two numbers.
+    ///
+    /// # Arguments
+    ///
+    /// - `a`: First number.
+    /// - `b`: Second number.
+    ///
+    /// # Returns
+    ///
+    /// `a + b`.
+    fn add(&self, a: T, b: T) -> T {
+        a + b
+    }
+
+    /// Multiply two numbers.
+    ///
+    /// # Arguments
+    ///
+    /// - `a`: First number.
+    /// - `b`: Second number.
+    ///
+    /// # Returns
+    ///
+    /// `a × b`.
+    fn multiply(&self, a: T, b: T) -> T {
+        a * b
+    }
+}
+
+/// Tropical semiring `(min, +)` of path lengths, `usize::MAX` standing for no path.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MinPlus;
+
+impl Semiring for MinPlus {
+    /// Type of the values.
+    type Value = usize;
+
+    /// Length of a missing path.
+    ///
+    /// # Returns
+    ///
+    /// `usize::MAX`.
+    fn zero(&self) -> usize {
+        usize::MAX
+    }
+
+    /// Keep the shorter of two lengths.
+    ///
+    /// # Arguments
+    ///
+    /// - `a`: First length.
+    /// - `b`: Second length.
+    ///
+    /// # Returns
+    ///
+    /// `min(a, b)`.
+    fn add(&self, a: usize, b: usize) -> usize {
+        a.min(b)
+    }
+
+    /// Concatenate two paths.
+    ///
+    /// # Arguments
+    ///
+    /// - `a`: First length.
+    /// - `b`: Second length.
+    ///
+    /// # Returns
+    ///
+    /// `a + b`, or `usize::MAX` if either path is missing or the sum overflows.
+    fn multiply(&self, a: usize, b: usize) -> usize {
+        a.saturating_add(b)
+    }
+}
+
+/// Boolean semiring `(or, and)` of reachability.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OrAnd;
+
+impl Semiring for OrAnd {
+    /// Type of the values.
+    type Value = bool;
+
+    /// Missing connection.
+    ///
+    /// # Returns
+    ///
+    /// `false`.
+    fn zero(&self) -> bool {
+        false
+    }
+
+    /// Connected by either alternative.
+    ///
+    /// # Arguments
+    ///
+    /// - `a`: First value.
+    /// - `b`: Second value.
+    ///
+    /// # Returns
+    ///
+    /// `a || b`.
+    fn add(&self, a: bool, b: bool) -> bool {
+        a || b
+    }
+
+    /// Connected by both steps.
+    ///
+    /// # Arguments
+    ///
+    /// - `a`: First value.
+    /// - `b`: Second value.
+    ///
+    /// # Returns
+    ///
+    /// `a && b`.
+    fn multiply(&self, a: bool, b: bool) -> bool {
+        a && b
+    }
+}
+
+/// Set of positions an operation may write, as in GraphBLAS masks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    /// Whether each position is in the set.
+    pub members: Vec<bool>,
+
+    /// Whether the operation writes the positions outside the set instead.
+    pub complement: bool,
+}
+
+impl Mask {
+    /// Create a mask from the positions it allows.
+    ///
+    /// # Arguments
+    ///
+    /// - `size`: Number of positions.
+    /// - `indices`: Allowed positions, those out of range are ignored.
+    ///
+    /// # Returns
+    ///
+    /// New mask.
+    pub fn from_indices(size: usize, indices: impl IntoIterator<Item = usize>) -> Self {
+        let mut members = vec![false; size];
+
+        for index in indices {
+            if let Some(member) = members.get_mut(index) {
+                *member = true;
+            }
+        }
+
+        Self {
+            members,
+            complement: false,
+        }
+    }
+
+    /// Swap the allowed and the forbidden positions.
+    ///
+    /// # Returns
+    ///
+    /// Complemented mask.
+    pub fn complement(mut self) -> Self {
+        self.complement = !self.complement;
+        self
+    }
+
+    /// Determine whether a position may be written.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`: Position.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the position is allowed.
+    pub fn allows(&self, index: usize) -> bool {
+        self.members.get(index).copied().unwrap_or(false) != self.complement
+    }
+}
+
+/// Sparse vector of explicit entries, the others being the zero of the semiring.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseVector<T> {
+    /// Number of positions.
+    pub size: usize,
+
+    /// Explicit entries as `(index, value)`, sorted by index.
+    pub entries: Vec<(usize, T)>,
+}
+
+impl<T: Copy> SparseVector<T> {
+    /// Create a vector from entries, keeping the last value given for a position.
+    ///
+    /// # Arguments
+    ///
+    /// - `size`: Number of positions.
+    /// - `entries`: Entries as `(index, value)`.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the vector, or an error if an index is out of range.
+    pub fn from_entries(
+        size: usize,
+        entries: impl IntoIterator<Item = (usize, T)>,
+    ) -> Result<Self, GraphError> {
+        let mut dense: Vec<Option<T>> = vec![None; size];
+
+        for (index, value) in entries {
+            *dense
+                .get_mut(index)
+                .ok_or(GraphError::NodeOutOfRange(index))? = Some(value);
+        }
+
+        Ok(Self::from_dense(dense))
+    }
+
+    /// Create a vector from optional values of every position.
+    ///
+    /// # Arguments
+    ///
+    /// - `dense`: Value of each position, `None` for an implicit zero.
+    ///
+    /// # Returns
+    ///
+    /// New vector.
+    fn from_dense(dense: Vec<Option<T>>) -> Self {
+        Self {
+            size: dense.len(),
+            entries: dense
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, value)| Some((index, value?)))
+                .collect(),
+        }
+    }
+
+    /// Get the explicit value of a position.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`: Position.
+    ///
+    /// # Returns
+    ///
+    /// Value of the position, or `None` if it is implicit.
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.entries
+            .binary_search_by_key(&index, |&(index, _)| index)
+            .ok()
+            .map(|position| self.entries[position].1)
+    }
+}
+
+/// Sparse matrix in compressed sparse row (CSR) format, such as the adjacency matrix of a graph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseMatrix<T> {
+    /// Number of rows.
+    pub rows: usize,
+
+    /// Number of columns.
+    pub columns: usize,
+
+    /// Start of the entries of each row in `entries`, followed by their total.
+    pub row_offsets: Vec<usize>,
+
+    /// Explicit entries as `(column, value)`, grouped by row and sorted by column.
+    pub entries: Vec<(usize, T)>,
+}
+
+impl<T: Copy> SparseMatrix<T> {
+    /// Create a matrix from triplets, combining duplicates such as parallel edges.
+    ///
+    /// # Arguments
+    ///
+    /// - `rows`: Number of rows.
+    /// - `columns`: Number of columns.
+    /// - `triplets`: Entries as `(row, column, value)`.
+    /// - `duplicate`: Combination of two values given for the same entry, e.g. the minimum weight.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the matrix, or an error if an entry is out of range.
+    pub fn from_triplets(
+        rows: usize,
+        columns: usize,
+        triplets: impl IntoIterator<Item = (usize, usize, T)>,
+        duplicate: impl Fn(T, T) -> T,
+    ) -> Result<Self, GraphError> {
+        let mut triplets: Vec<(usize, usize, T)> = triplets.into_iter().collect();
+
+        if let Some(&(row, column, _)) = triplets
+            .iter()
+            .find(|&&(row, column, _)| row >= rows || column >= columns)
+        {
+            let index = if row >= rows { row } else { column };
+
+            return Err(GraphError::NodeOutOfRange(index));
+        }
+
+        triplets.sort_by_key(|&(row, column, _)| (row, column));
+
+        let mut row_offsets = vec![0; rows + 1];
+        let mut entries: Vec<(usize, T)> = Vec::with_capacity(triplets.len());
+        let mut last = None;
+
+        for (row, column, value) in triplets {
+            if last == Some((row, column)) {
+                let entry = entries.last_mut().map(|(_, known)| known);
+
+                if let Some(known) = entry {
+                    *known = duplicate(*known, value);
+                }
+
+                continue;
+            }
+
+            last = Some((row, column));
+            entries.push((column, value));
+            row_offsets[row + 1] += 1;
+        }
+
+        for row in 0..rows {
+            row_offsets[row + 1] += row_offsets[row];
+        }
+
+        Ok(Self {
+            rows,
+            columns,
+            row_offsets,
+            entries,
+        })
+    }
+
+    /// Get the explicit entries of a row.
+    ///
+    /// # Arguments
+    ///
+    /// - `row`: Row.
+    ///
+    /// # Returns
+    ///
+    /// Entries of the row as `(column, value)`, sorted by column.
+    pub fn row(&self, row: usize) -> &[(usize, T)] {
+        &self.entries[self.row_offsets[row]..self.row_offsets[row + 1]]
+    }
+
+    /// Get the explicit value of an entry.
+    ///
+    /// # Arguments
+    ///
+    /// - `row`: Row.
+    /// - `column`: Column.
+    ///
+    /// # Returns
+    ///
+    /// Value of the entry, or `None` if it is implicit.
+    pub fn get(&self, row: usize, column: usize) -> Option<T> {
+        if row >= self.rows {
+            return None;
+        }
+
+        let entries = self.row(row);
+
+        entries
+            .binary_search_by_key(&column, |&(column, _)| column)
+            .ok()
+            .map(|position| entries[position].1)
+    }
+
+    /// Transpose the matrix, such as reversing the edges of a graph.
+    ///
+    /// # Returns
+    ///
+    /// Transposed matrix.
+    pub fn transpose(&self) -> Self {
+        let triplets = (0..self.rows).flat_map(|row| {
+            self.row(row)
+                .iter()
+                .map(move |&(column, value)| (column, row, value))
+        });
+
+        // Entries are distinct, so no duplicates need combining.
+        Self::from_triplets(self.columns, self.rows, triplets, |_, value| value)
+            .unwrap_or_else(|_| unreachable!("transposed entries stay in range"))
+    }
+
+    /// Multiply the matrix by a column vector: `y[i] = ⊕_j A[i][j] ⊗ x[j]`.
+    ///
+    /// # Arguments
+    ///
+    /// - `semiring`: Semiring of the operation.
+    /// - `vector`: Vector with one position per column.
+    /// - `mask`: Positions of the result to compute, all if `None`.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the product, or an error if the sizes differ.
+    pub fn mxv<S: Semiring<Value = T>>(
+        &self,
+        semiring: &S,
+        vector: &SparseVector<T>,
+        mask: Option<&Mask>,
+    ) -> Result<SparseVector<T>, GraphError> {
+        if vector.size != self.columns {
+            return Err(GraphError::DimensionMismatch);
+        }
+
+        let mut dense: Vec<Option<T>> = vec![None; vector.size];
+
+        for &(index, value) in &vector.entries {
+            dense[index] = Some(value);
+        }
+
+        let result = (0..self.rows)
+            .map(|row| {
+                if mask.is_some_and(|mask| !mask.allows(row)) {
+                    return None;
+                }
+
+                self.row(row)
+                    .iter()
+                    .filter_map(|&(column, value)| Some(semiring.multiply(value, dense[column]?)))
+                    .reduce(|a, b| semiring.add(a, b))
+            })
+            .collect();
+
+        Ok(SparseVector::from_dense(result))
+    }
+
+    /// Multiply a row vector by the matrix: `y[j] = ⊕_i x[i] ⊗ A[i][j]`, such as expanding a
+    /// frontier of nodes along their edges.
+    ///
+    /// # Arguments
+    ///
+    /// - `semiring`: Semiring of the operation.
+    /// - `vector`: Vector with one position per row.
+    /// - `mask`: Positions of the result to compute, all if `None`.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the product, or an error if the sizes differ.
+    pub fn vxm<S: Semiring<Value = T>>(
+        &self,
+        semiring: &S,
+        vector: &SparseVector<T>,
+        mask: Option<&Mask>,
+    ) -> Result<SparseVector<T>, GraphError> {
+        if vector.size != self.rows {
+            return Err(GraphError::DimensionMismatch);
+        }
+
+        let mut result: Vec<Option<T>> = vec![None; self.columns];
+
+        for &(row, scale) in &vector.entries {
+            for &(column, value) in self.row(row) {
+                if mask.is_some_and(|mask| !mask.allows(column)) {
+                    continue;
+                }
+
+                let product = semiring.multiply(scale, value);
+
+                result[column] = Some(match result[column] {
+                    Some(known) => semiring.add(known, product),
+                    None => product,
+                });
+            }
+        }
+
+        Ok(SparseVector::from_dense(result))
+    }
+
+    /// Multiply two matrices row by row (Gustavson's Algorithm): `C[i][j] = ⊕_k A[i][k] ⊗ B[k][j]`.
+    ///
+    /// # Arguments
+    ///
+    /// - `semiring`: Semiring of the operation.
+    /// - `other`: Right-hand matrix.
+    /// - `mask`: Structural mask, computing only the entries stored in it, all if `None`.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the product, or an error if the sizes differ.
+    pub fn mxm<S: Semiring<Value = T>, M: Copy>(
+        &self,
+        semiring: &S,
+        other: &SparseMatrix<T>,
+        mask: Option<&SparseMatrix<M>>,
+    ) -> Result<SparseMatrix<T>, GraphError> {
+        if self.columns != other.rows
+            || mask.is_some_and(|mask| mask.rows != self.rows || mask.columns != other.columns)
+        {
+            return Err(GraphError::DimensionMismatch);
+        }
+
+        let mut row_offsets = vec![0; self.rows + 1];
+        let mut entries = Vec::new();
+        let mut accumulator: Vec<Option<T>> = vec![None; other.columns];
+        let mut allowed = vec![mask.is_none(); other.columns];
+
+        for row in 0..self.rows {
+            if let Some(mask) = mask {
+                for &(column, _) in mask.row(row) {
+                    allowed[column] = true;
+                }
+            }
+
+            let mut touched = Vec::new();
+
+            for &(middle, left) in self.row(row) {
+                for &(column, right) in other.row(middle) {
+                    if !allowed[column] {
+                        continue;
+                    }
+
+                    let product = semiring.multiply(left, right);
+
+                    accumulator[column] = Some(match accumulator[column] {
+                        Some(known) => semiring.add(known, product),
+                        None => {
+                            touched.push(column);
+                            product
+                        }
+                    });
+                }
+            }
+
+            touched.sort_unstable();
+
+            for column in touched {
+                entries.extend(accumulator[column].take().map(|value| (column, value)));
+            }
+
+            if let Some(mask) = mask {
+                for &(column, _) in mask.row(row) {
+                    allowed[column] = false;
+                }
+            }
+
+            row_offsets[row + 1] = entries.len();
+        }
+
+        Ok(SparseMatrix {
+            rows: self.rows,
+            columns: other.columns,
+            row_offsets,
+            entries,
+        })
+    }
+}
+
+/// Compute the breadth-first levels from a node with boolean vector-matrix products, each masked by
+/// the complement of the visited nodes.
+///
+/// # Arguments
+///
+/// - `adjacency`: Square adjacency matrix, `true` for every edge.
+/// - `source`: Starting node.
+///
+/// # Returns
+///
+/// Result containing the level of each node, `None` if unreachable, or an error if the source is
+/// out of range or the matrix is not square.
+pub fn bfs_levels(
+    adjacency: &SparseMatrix<bool>,
+    source: usize,
+) -> Result<Vec<Option<usize>>, GraphError> {
+    if adjacency.rows != adjacency.columns {
+        return Err(GraphError::DimensionMismatch);
+    }
+
+    let mut levels = vec![None; adjacency.rows];
+    let mut frontier = SparseVector::from_entries(adjacency.rows, [(source, true)])?;
+    let mut visited = Mask::from_indices(adjacency.rows, [source]);
+    let mut level = 0;
+
+    while !frontier.entries.is_empty() {
+        for &(node, _) in &frontier.entries {
+            levels[node] = Some(level);
+        }
+
+        let unvisited = visited.complement();
+        frontier = adjacency.vxm(&OrAnd, &frontier, Some(&unvisited))?;
+        visited = unvisited.complement();
+
+        for &(node, _) in &frontier.entries {
+            visited.members[node] = true;
+        }
+
+        level += 1;
+    }
+
+    Ok(levels)
+}
+
+/// Compute the shortest distances from a node with min-plus vector-matrix products (Bellman-Ford),
+/// relaxing every edge until the distances stop changing.
+///
+/// # Arguments
+///
+/// - `adjacency`: Square matrix of edge weights.
+/// - `source`: Starting node.
+///
+/// # Returns
+///
+/// Result containing the distance of each node, `usize::MAX` if unreachable, or an error if the
+/// source is out of range or the matrix is not square.
+pub fn sssp_distances(
+    adjacency: &SparseMatrix<usize>,
+    source: usize,
+) -> Result<Vec<usize>, GraphError> {
+    if adjacency.rows != adjacency.columns {
+        return Err(GraphError::DimensionMismatch);
+    }
+
+    let mut distances = SparseVector::from_entries(adjacency.rows, [(source, 0)])?;
+
+    for _ in 0..adjacency.rows {
+        let relaxed = adjacency.vxm(&MinPlus, &distances, None)?;
+        let merged = SparseVector::from_dense(
+            (0..adjacency.rows)
+                .map(|node| match (distances.get(node), relaxed.get(node)) {
+                    (Some(a), Some(b)) => Some(MinPlus.add(a, b)),
+                    (a, b) => a.or(b),
+                })
+                .collect(),
+        );
+
+        if merged == distances {
+            break;
+        }
+
+        distances = merged;
+    }
+
+    Ok((0..adjacency.rows)
+        .map(|node| distances.get(node).unwrap_or(usize::MAX))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph() -> SparseMatrix<usize> {
+        SparseMatrix::from_triplets(
+            5,
+            5,
+            vec![
+                (0, 1, 4),
+                (0, 2, 1),
+                (2, 1, 2),
+                (1, 3, 1),
+                (2, 3, 5),
+                (0, 1, 7),
+            ],
+            usize::min,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_from_triplets() {
+        let matrix = graph();
+
+        assert_eq!(matrix.row_offsets, vec![0, 2, 3, 5, 5, 5]);
+        assert_eq!(matrix.get(0, 1), Some(4));
+        assert_eq!(matrix.get(1, 0), None);
+        assert_eq!(matrix.transpose().get(3, 2), Some(5));
+        assert_eq!(
+            SparseMatrix::from_triplets(2, 2, vec![(0, 3, 1)], usize::min),
+            Err(GraphError::NodeOutOfRange(3))
+        );
+    }
+
+    #[test]
+    fn test_mxv_and_vxm() {
+        let matrix = graph();
+        let vector = SparseVector::from_entries(5, [(1, 10), (3, 1)]).unwrap();
+
+        // Rows reaching a node of the vector, and where the nodes of the vector lead.
+        let pulled = matrix.mxv(&PlusTimes::default(), &vector, None).unwrap();
+        let pushed = matrix.vxm(&PlusTimes::default(), &vector, None).unwrap();
+
+        assert_eq!(pulled.entries, vec![(0, 40), (1, 1), (2, 25)]);
+        assert_eq!(pushed.entries, vec![(3, 10)]);
+
+        let mask = Mask::from_indices(5, [0]).complement();
+
+        assert_eq!(
+            matrix
+                .mxv(&PlusTimes::default(), &vector, Some(&mask))
+                .unwrap()
+                .entries,
+            vec![(1, 1), (2, 25)]
+        );
+        assert_eq!(
+            matrix.mxv(&MinPlus, &SparseVector::from_entries(4, []).unwrap(), None),
+            Err(GraphError::DimensionMismatch)
+        );
+    }
+
+    #[test]
+    fn test_mxm_triangles() {
+        // Two triangles sharing the edge 1-2, and a pendant node.
+        let edges = [(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)];
+        let adjacency = SparseMatrix::from_triplets(
+            5,
+            5,
+            edges.iter().flat_map(|&(a, b)| [(a, b, 1usize), (b, a, 1)]),
+            |a, _| a,
+        )
+        .unwrap();
+
+        // Masking `A²` by `A` counts the common neighbors of adjacent nodes only.
+        let wedges = adjacency
+            .mxm(&PlusTimes::default(), &adjacency, Some(&adjacency))
+            .unwrap();
+        let triangles: usize = wedges
+            .entries
+            .iter()
+            .map(|&(_, count)| count)
+            .sum::<usize>()
+            / 6;
+
+        assert_eq!(triangles, 2);
+        assert_eq!(wedges.get(0, 3), None);
+
+        let full = adjacency
+            .mxm::<_, bool>(&PlusTimes::default(), &adjacency, None)
+            .unwrap();
+
+        assert_eq!(full.get(0, 3), Some(2));
+    }
+
+    #[test]
+    fn test_bfs_levels() {
+        let adjacency = SparseMatrix::from_triplets(
+            5,
+            5,
+            vec![(0, 1, true), (1, 2, true), (0, 2, true), (3, 0, true)],
+            |a, _| a,
+        )
+        .unwrap();
+
+        assert_eq!(
+            bfs_levels(&adjacency, 0).unwrap(),
+            vec![Some(0), Some(1), Some(1), None, None]
+        );
+        assert_eq!(
+            bfs_levels(&adjacency, 7),
+            Err(GraphError::NodeOutOfRange(7))
+        );
+    }
+
+    #[test]
+    fn test_sssp_distances() {
+        assert_eq!(
+            sssp_distances(&graph(), 0).unwrap(),
+            vec![0, 3, 1, 4, usize::MAX]
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{DijkstraAlgorithm, GraphAlgorithm, GraphError};
+
+/// Shortest path DAG of a single source run: the predecessors of each node on its shortest paths.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortestPathDag {
+    /// Source of the run.
+    pub start: usize,
+
+    /// Distance of each node, `usize::MAX` if unreachable.
+    pub distances: Vec<usize>,
+
+    /// Distinct predecessors of each node over edges on shortest paths, sorted.
+    pub predecessors: Vec<Vec<usize>>,
+}
+
+impl ShortestPathDag {
+    /// Iterate over every shortest path to a node, as sequences of nodes from the start.
+    /// Paths are reconstructed one at a time from the predecessors, so that only the path being
+    /// built is kept however many there are. Along cycles of zero weight, only simple paths are
+    /// given.
+    ///
+    /// # Arguments
+    ///
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Iterator over the shortest paths, empty if the target is unreachable.
+    pub fn paths(&self, target: usize) -> AllShortestPaths<'_> {
+        let mut paths = AllShortestPaths {
+            dag: self,
+            stack: Vec::new(),
+            on_path: vec![false; self.distances.len()],
+        };
+
+        if self.distance(target).is_some() {
+            paths.stack.push((target, 0));
+            paths.on_path[target] = true;
+        }
+
+        paths
+    }
+
+    /// Get the distance of a node from the start.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node.
+    ///
+    /// # Returns
+    ///
+    /// Distance of the node, or `None` if it is unreachable.
+    pub fn distance(&self, node: usize) -> Option<usize> {
+        self.distances
+            .get(node)
+            .copied()
+            .filter(|&distance| distance != usize::MAX)
+    }
+}
+
+/// Lazy iterator over the shortest paths to a node, by depth-first search over the predecessors.
+#[derive(Debug, Clone)]
+pub struct AllShortestPaths<'a> {
+    /// Shortest path DAG the paths follow.
+    dag: &'a ShortestPathDag,
+
+    /// Nodes of the partial path from the target, with the index of the next predecessor to try.
+    stack: Vec<(usize, usize)>,
+
+    /// Whether each node lies on the partial path.
+    on_path: Vec<bool>,
+}
+
+impl Iterator for AllShortestPaths<'_> {
+    /// Type of the item.
+    type Item = Vec<usize>;
+
+    /// Reconstruct the next shortest path.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of the next path from the start to the target, or `None` after the last path.
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, index)) = self.stack.last_mut() {
+            let node = *node;
+
+            if node == self.dag.start {
+                let path = self.stack.iter().rev().map(|&(node, _)| node).collect();
+                self.stack.pop();
+                self.on_path[node] = false;
+
+                return Some(path);
+            }
+
+            match self.dag.predecessors[node].get(*index) {
+                Some(&predecessor) => {
+                    *index += 1;
+
+                    if !self.on_path[predecessor] {
+                        self.stack.push((predecessor, 0));
+                        self.on_path[predecessor] = true;
+                    }
+                }
+                None => {
+                    self.stack.pop();
+                    self.on_path[node] = false;
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl DijkstraAlgorithm {
+    /// Run Dijkstra's Algorithm and keep every shortest path, not just one, as the predecessors of
+    /// each node over the edges whose weight matches the difference of the distances.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the shortest path DAG, or an error if the start is missing.
+    pub fn shortest_path_dag(&self, start: usize) -> Result<ShortestPathDag, GraphError> {
+        if start >= self.graph.len() || !self.graph.contains_key(&start) {
+            return Err(GraphError::MissingStartNode);
+        }
+
+        let distances = self.run(Some(start))?;
+        let mut predecessors = vec![Vec::new(); distances.len()];
+
+        for (node, &distance) in distances.iter().enumerate() {
+            if distance == usize::MAX {
+                continue;
+            }
+
+            for &(neighbor, weight) in self.graph.get(&node).into_iter().flatten() {
+                if neighbor < distances.len()
+                    && distance.checked_add(weight) == Some(distances[neighbor])
+                {
+                    predecessors[neighbor].push(node);
+                }
+            }
+        }
+
+        for list in &mut predecessors {
+            list.sort_unstable();
+            list.dedup();
+        }
+
+        Ok(ShortestPathDag {
+            start,
+            distances,
+            predecessors,
+        })
+    }
+
+    /// Iterate over every shortest path between two nodes, such as the routes of equal-cost
+    /// multipath routing.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the paths, or an error if the start or the target is missing or unreachable.
+    pub fn all_shortest_paths(
+        &self,
+        start: usize,
+        target: usize,
+    ) -> Result<Vec<Vec<usize>>, GraphError> {
+        let dag = self.shortest_path_dag(start)?;
+
+        if dag.distance(target).is_none() {
+            return Err(GraphError::MissingTargetNode);
+        }
+
+        Ok(dag.paths(target).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diamond() -> DijkstraAlgorithm {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.add_weighted_edges(vec![
+            (0, 1, 1),
+            (0, 2, 1),
+            (1, 3, 1),
+            (2, 3, 1),
+            (0, 3, 3),
+            (3, 4, 1),
+            (3, 4, 1),
+        ]);
+        algorithm.set_total_nodes(6);
+
+        algorithm
+    }
+
+    #[test]
+    fn test_shortest_path_dag() {
+        let dag = diamond().shortest_path_dag(0).unwrap();
+
+        assert_eq!(dag.predecessors[3], vec![1, 2]);
+        assert_eq!(dag.predecessors[4], vec![3]);
+        assert_eq!(dag.distance(4), Some(3));
+        assert_eq!(dag.distance(5), None);
+        assert_eq!(dag.paths(5).next(), None);
+        assert_eq!(dag.paths(0).collect::<Vec<_>>(), vec![vec![0]]);
+    }
+
+    #[test]
+    fn test_all_shortest_paths() {
+        assert_eq!(
+            diamond().all_shortest_paths(0, 4),
+            Ok(vec![vec![0, 1, 3, 4], vec![0, 2, 3, 4]])
+        );
+        assert_eq!(
+            diamond().all_shortest_paths(0, 5),
+            Err(GraphError::MissingTargetNode)
+        );
+        assert_eq!(
+            diamond().all_shortest_paths(9, 4),
+            Err(GraphError::MissingStartNode)
+        );
+    }
+
+    #[test]
+    fn test_paths_lazily() {
+        // Chain of 64 diamonds with 2^64 shortest paths, of which only a few are built.
+        let mut algorithm = DijkstraAlgorithm::new();
+
+        for diamond in 0..64 {
+            let node = 3 * diamond;
+            algorithm.add_weighted_edges(vec![
+                (node, node + 1, 1),
+                (node, node + 2, 1),
+                (node + 1, node + 3, 1),
+                (node + 2, node + 3, 1),
+            ]);
+        }
+
+        let dag = algorithm.shortest_path_dag(0).unwrap();
+        let paths: Vec<Vec<usize>> = dag.paths(192).take(3).collect();
+
+        assert_eq!(paths.len(), 3);
+        assert!(paths.iter().all(|path| path.len() == 129));
+        assert_ne!(paths[0], paths[1]);
+    }
+
+    #[test]
+    fn test_paths_zero_weight_cycle() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.add_weighted_edges(vec![(0, 1, 1), (1, 2, 0), (2, 1, 0), (2, 3, 1)]);
+
+        assert_eq!(
+            algorithm.all_shortest_paths(0, 3),
+            Ok(vec![vec![0, 1, 2, 3]])
+        );
+    }
+}
@@ -0,0 +1,315 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::{AStarAlgorithm, Heuristic};
+
+/// ALT Heuristic (A*, Landmarks, Triangle inequality).
+/// Bound the cost between two nodes with precomputed distances to and from a few landmarks:
+/// `d(u, t) >= d(L, t) - d(L, u)` and `d(u, t) >= d(u, L) - d(t, L)` for every landmark `L`.
+/// Landmarks behind the target, such as at the edge of a road network, give the tightest bounds.
+///
+/// The bounds are consistent on strongly connected graphs. Otherwise, landmarks only bound the
+/// nodes they reach and are reached from, which keeps the bounds admissible for the unidirectional
+/// search but not consistent for the bidirectional one.
+#[derive(Debug, Clone, Default)]
+pub struct LandmarkHeuristic {
+    /// Landmark nodes.
+    pub landmarks: Vec<usize>,
+
+    /// Distances from each landmark to every node, `usize::MAX` if unreachable.
+    pub from_landmark: Vec<Vec<usize>>,
+
+    /// Distances from every node to each landmark, `usize::MAX` if unreachable.
+    pub to_landmark: Vec<Vec<usize>>,
+}
+
+impl LandmarkHeuristic {
+    /// Create a new heuristic from chosen landmarks.
+    ///
+    /// # Arguments
+    ///
+    /// - `graph`: Graph to search.
+    /// - `landmarks`: Landmark nodes.
+    ///
+    /// # Returns
+    ///
+    /// New heuristic with the distances to and from the landmarks.
+    pub fn new<H>(graph: &AStarAlgorithm<H>, landmarks: Vec<usize>) -> Self {
+        let landmarks: Vec<usize> = landmarks
+            .into_iter()
+            .filter(|&landmark| landmark < graph.total_nodes)
+            .collect();
+
+        Self {
+            from_landmark: landmarks
+                .iter()
+                .map(|&landmark| Self::distances(&graph.adjacency, landmark))
+                .collect(),
+            to_landmark: landmarks
+                .iter()
+                .map(|&landmark| Self::distances(&graph.reverse, landmark))
+                .collect(),
+            landmarks,
+        }
+    }
+
+    /// Create a new heuristic from landmarks spread by farthest selection.
+    /// The first landmark is the farthest node from node `0`, and every next one the node farthest
+    /// from the landmarks chosen so far, counting distances in both directions.
+    ///
+    /// # Arguments
+    ///
+    /// - `graph`: Graph to search.
+    /// - `count`: Number of landmarks.
+    ///
+    /// # Returns
+    ///
+    /// New heuristic with at most `count` landmarks.
+    pub fn farthest<H>(graph: &AStarAlgorithm<H>, count: usize) -> Self {
+        let mut landmarks = Vec::with_capacity(count);
+
+        if graph.total_nodes == 0 || count == 0 {
+            return Self::new(graph, landmarks);
+        }
+
+        // Smallest distance of every node to the landmarks, in either direction.
+        let mut nearest = Self::distances(&graph.adjacency, 0);
+
+        while landmarks.len() < count {
+            let Some(landmark) = (0..graph.total_nodes)
+                .filter(|&node| nearest[node] != usize::MAX && !landmarks.contains(&node))
+                .max_by_key(|&node| (nearest[node], Reverse(node)))
+            else {
+                break;
+            };
+
+            if !landmarks.is_empty() && nearest[landmark] == 0 {
+                break;
+            }
+
+            landmarks.push(landmark);
+
+            let forward = Self::distances(&graph.adjacency, landmark);
+            let backward = Self::distances(&graph.reverse, landmark);
+
+            for node in 0..graph.total_nodes {
+                let distance = forward[node].min(backward[node]);
+
+                // The first landmark replaces the distances from node `0`.
+                nearest[node] = match landmarks.len() {
+                    1 => distance,
+                    _ => nearest[node].min(distance),
+                };
+            }
+        }
+
+        Self::new(graph, landmarks)
+    }
+
+    /// Compute the distances from a node with Dijkstra's Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `adjacency`: Neighbors of each node with the weight of the edge.
+    /// - `source`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Distance to every node, `usize::MAX` if unreachable.
+    fn distances(adjacency: &[Vec<(usize, usize)>], source: usize) -> Vec<usize> {
+        let mut distances = vec![usize::MAX; adjacency.len()];
+        let mut queue = BinaryHeap::from([Reverse((0, source))]);
+        distances[source] = 0;
+
+        while let Some(Reverse((cost, node))) = queue.pop() {
+            if cost > distances[node] {
+                continue;
+            }
+
+            for &(neighbor, weight) in &adjacency[node] {
+                let next = cost + weight;
+
+                if next < distances[neighbor] {
+                    distances[neighbor] = next;
+                    queue.push(Reverse((next, neighbor)));
+                }
+            }
+        }
+
+        distances
+    }
+}
+
+impl Heuristic for LandmarkHeuristic {
+    /// Estimate the cost between two nodes from the triangle inequality on every landmark.
+    ///
+    /// # Arguments
+    ///
+    /// - `from`: First node.
+    /// - `to`: Second node.
+    ///
+    /// # Returns
+    ///
+    /// Lower bound of the cost, zero for nodes no landmark bounds.
+    fn estimate(&self, from: usize, to: usize) -> usize {
+        let bound = |ahead: usize, behind: usize| match (ahead, behind) {
+            (usize::MAX, _) | (_, usize::MAX) => 0,
+            _ => ahead.saturating_sub(behind),
+        };
+
+        self.from_landmark
+            .iter()
+            .zip(&self.to_landmark)
+            .filter(|(forward, _)| from < forward.len() && to < forward.len())
+            .map(|(forward, backward)| {
+                bound(forward[to], forward[from]).max(bound(backward[from], backward[to]))
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl<H> AStarAlgorithm<H> {
+    /// Replace the heuristic by landmarks spread by farthest selection.
+    ///
+    /// # Arguments
+    ///
+    /// - `count`: Number of landmarks.
+    ///
+    /// # Returns
+    ///
+    /// A* Algorithm on the same graph, guided by the landmarks.
+    pub fn with_landmarks(self, count: usize) -> AStarAlgorithm<LandmarkHeuristic> {
+        let heuristic = LandmarkHeuristic::farthest(&self, count);
+
+        AStarAlgorithm {
+            total_nodes: self.total_nodes,
+            adjacency: self.adjacency,
+            reverse: self.reverse,
+            heuristic,
+            target: self.target,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DijkstraAlgorithm, GraphAlgorithm};
+
+    /// Grid of `size × size` nodes with pseudo-random weights in both directions.
+    fn grid(size: usize) -> AStarAlgorithm {
+        let mut algorithm = AStarAlgorithm::new();
+
+        for node in 0..size * size {
+            let weight = |salt: usize| 1 + (node * 37 + salt * 11) % 19;
+
+            if node % size + 1 < size {
+                algorithm.set_edge(node, node + 1, weight(1));
+                algorithm.set_edge(node + 1, node, weight(2));
+            }
+
+            if node + size < size * size {
+                algorithm.set_edge(node, node + size, weight(3));
+                algorithm.set_edge(node + size, node, weight(4));
+            }
+        }
+
+        algorithm
+    }
+
+    #[test]
+    fn test_farthest() {
+        let algorithm = grid(6);
+        let heuristic = LandmarkHeuristic::farthest(&algorithm, 4);
+
+        assert_eq!(heuristic.landmarks.len(), 4);
+        assert_eq!(heuristic.from_landmark.len(), 4);
+
+        // The first landmark is the node farthest from node `0`.
+        let distances = LandmarkHeuristic::distances(&algorithm.adjacency, 0);
+        let farthest = distances.iter().max().copied();
+
+        assert_eq!(Some(distances[heuristic.landmarks[0]]), farthest);
+
+        let mut landmarks = heuristic.landmarks.clone();
+        landmarks.sort_unstable();
+        landmarks.dedup();
+
+        assert_eq!(landmarks.len(), 4);
+    }
+
+    #[test]
+    fn test_estimate_is_lower_bound() {
+        let algorithm = grid(6);
+        let heuristic = LandmarkHeuristic::farthest(&algorithm, 3);
+        let mut dijkstra = DijkstraAlgorithm::new();
+        dijkstra.set_nodes(
+            algorithm
+                .adjacency
+                .clone()
+                .into_iter()
+                .enumerate()
+                .collect(),
+        );
+
+        for source in 0..36 {
+            let distances = dijkstra.run(Some(source)).unwrap();
+
+            for (target, &distance) in distances.iter().enumerate() {
+                assert!(heuristic.estimate(source, target) <= distance);
+            }
+
+            // A landmark bounds the cost to itself exactly.
+            let landmark = heuristic.landmarks[0];
+
+            assert_eq!(heuristic.estimate(source, landmark), distances[landmark]);
+        }
+    }
+
+    #[test]
+    fn test_with_landmarks() {
+        let plain = grid(8);
+        let mut algorithm = grid(8).with_landmarks(4);
+        algorithm.set_target(63);
+
+        for (source, target) in [(0, 63), (63, 0), (7, 56), (20, 43), (9, 9)] {
+            let expected = plain.shortest_path(source, target).unwrap().0;
+
+            assert_eq!(algorithm.shortest_path(source, target).unwrap().0, expected);
+            assert_eq!(
+                algorithm.bidirectional_path(source, target).unwrap().0,
+                expected
+            );
+            assert_eq!(
+                algorithm.front_to_front_path(source, target).unwrap().0,
+                expected
+            );
+        }
+
+        assert_eq!(
+            algorithm.run(Some(0)).unwrap().0,
+            plain.shortest_path(0, 63).unwrap().0
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let algorithm = AStarAlgorithm::new();
+        let heuristic = LandmarkHeuristic::farthest(&algorithm, 2);
+
+        assert!(heuristic.landmarks.is_empty());
+        assert_eq!(heuristic.estimate(0, 1), 0);
+    }
+
+    #[test]
+    fn test_unreachable_landmark() {
+        let mut algorithm = AStarAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 3)]), (1, vec![(2, 4)])]);
+
+        let heuristic = LandmarkHeuristic::new(&algorithm, vec![2, 7]);
+
+        assert_eq!(heuristic.landmarks, vec![2]);
+        assert_eq!(heuristic.estimate(0, 1), 3);
+        assert_eq!(heuristic.estimate(0, 2), 7);
+    }
+}
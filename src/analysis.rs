@@ -0,0 +1,471 @@
+use std::collections::BTreeSet;
+
+use crate::{disjoint_set::DisjointSet, scc, DijkstraAlgorithm, GraphAlgorithm, GraphError};
+
+/// Recommended algorithm for a task on the analyzed graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recommendation {
+    /// Task the recommendation is for, e.g. "single-source shortest paths".
+    pub task: &'static str,
+
+    /// Name of the recommended algorithm in this crate.
+    pub algorithm: &'static str,
+
+    /// Suggested configuration of the algorithm, empty if the defaults fit.
+    pub configuration: String,
+
+    /// Why the algorithm fits the graph.
+    pub reason: String,
+
+    /// Rough number of elementary operations of one run.
+    pub estimated_operations: usize,
+}
+
+/// Profile of a graph with recommended algorithms.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphAnalysis {
+    /// Number of nodes.
+    pub nodes: usize,
+
+    /// Number of edges.
+    pub edges: usize,
+
+    /// Whether the edges are directed.
+    pub directed: bool,
+
+    /// Fraction of the possible edges between distinct nodes that are present.
+    pub density: f64,
+
+    /// Smallest and largest edge weight, `None` without edges.
+    pub weight_range: Option<(i32, i32)>,
+
+    /// Whether some edge has a negative weight.
+    pub negative_weights: bool,
+
+    /// Whether the graph is directed and acyclic.
+    pub is_dag: bool,
+
+    /// Whether the graph is connected when ignoring edge directions.
+    pub weakly_connected: bool,
+
+    /// Whether every node reaches every other node along the edge directions.
+    pub strongly_connected: bool,
+
+    /// Problems in the input that may be mistakes.
+    pub warnings: Vec<String>,
+
+    /// Recommended algorithms, one per task.
+    pub recommendations: Vec<Recommendation>,
+}
+
+/// Graph Analyzer.
+/// Inspect the size, density, weights, acyclicity and connectivity of a graph, flag suspicious input,
+/// and recommend which of the crate's algorithms and configurations to use with an estimated cost.
+#[derive(Debug, Clone)]
+pub struct GraphAnalyzer {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Edges in the graph.
+    pub edges: Vec<(usize, usize, i32)>,
+
+    /// Whether the edges are directed.
+    pub directed: bool,
+}
+
+impl Default for GraphAnalyzer {
+    /// Create a new default instance of the graph analyzer.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the graph analyzer.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GraphAnalyzer {
+    /// Create a new instance of the graph analyzer.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the graph analyzer.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            edges: Vec::new(),
+            directed: true,
+        }
+    }
+
+    /// Set a single edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: i32) {
+        self.edges.push((source, target, weight));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, i32)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+    }
+
+    /// Set whether the edges are directed.
+    ///
+    /// # Arguments
+    ///
+    /// - `directed`: Whether the edges are directed.
+    pub fn set_directed(&mut self, directed: bool) {
+        self.directed = directed;
+    }
+
+    /// Analyze the graph.
+    ///
+    /// # Returns
+    ///
+    /// Profile of the graph with warnings and recommendations.
+    pub fn analyze(&self) -> GraphAnalysis {
+        let n = self.total_nodes;
+        let m = self.edges.len();
+        let pairs = n * n.saturating_sub(1) / if self.directed { 1 } else { 2 };
+        let weight_range = self.edges.iter().map(|&(_, _, weight)| weight).fold(
+            None,
+            |range: Option<(i32, i32)>, weight| match range {
+                Some((low, high)) => Some((low.min(weight), high.max(weight))),
+                None => Some((weight, weight)),
+            },
+        );
+        let negative_weights = weight_range.is_some_and(|(low, _)| low < 0);
+
+        let mut adjacency = vec![Vec::new(); n];
+        let mut set = DisjointSet::new(n);
+        let mut degrees = vec![0; n];
+        let mut seen = BTreeSet::new();
+        let mut self_loops = 0;
+        let mut parallel_edges = 0;
+
+        for &(source, target, _) in &self.edges {
+            adjacency[source].push(target);
+
+            if !self.directed {
+                adjacency[target].push(source);
+            }
+
+            set.union(source, target);
+            degrees[source] += 1;
+            degrees[target] += 1;
+            self_loops += usize::from(source == target);
+
+            let key = match self.directed {
+                true => (source, target),
+                false => (source.min(target), source.max(target)),
+            };
+
+            parallel_edges += usize::from(!seen.insert(key));
+        }
+
+        let components = scc::strongly_connected_components(&adjacency);
+        let roots: BTreeSet<usize> = (0..n).map(|node| set.find(node)).collect();
+        let isolated = degrees.iter().filter(|&&degree| degree == 0).count();
+
+        let mut analysis = GraphAnalysis {
+            nodes: n,
+            edges: m,
+            directed: self.directed,
+            density: match pairs {
+                0 => 0.0,
+                pairs => (m - self_loops) as f64 / pairs as f64,
+            },
+            weight_range,
+            negative_weights,
+            is_dag: self.directed && self_loops == 0 && components.len() == n,
+            weakly_connected: roots.len() <= 1,
+            strongly_connected: components.len() <= 1,
+            warnings: Vec::new(),
+            recommendations: Vec::new(),
+        };
+
+        if self_loops > 0 {
+            analysis.warnings.push(format!(
+                "{self_loops} self-loop(s), ignored by most algorithms"
+            ));
+        }
+
+        if parallel_edges > 0 {
+            analysis.warnings.push(format!(
+                "{parallel_edges} parallel edge(s) between the same nodes"
+            ));
+        }
+
+        if isolated > 0 && n > 1 {
+            analysis
+                .warnings
+                .push(format!("{isolated} isolated node(s) without edges"));
+        }
+
+        if !analysis.weakly_connected {
+            analysis.warnings.push(format!(
+                "{} connected components, unreachable nodes get infinite distances",
+                roots.len()
+            ));
+        }
+
+        if negative_weights && !self.directed {
+            analysis.warnings.push(
+                "negative undirected edges form negative cycles, shortest paths are undefined"
+                    .to_string(),
+            );
+        }
+
+        self.recommend(&mut analysis);
+        analysis
+    }
+
+    /// Add the recommendations for shortest paths, spanning trees and ordering.
+    ///
+    /// # Arguments
+    ///
+    /// - `analysis`: Profile of the graph to complete.
+    fn recommend(&self, analysis: &mut GraphAnalysis) {
+        let n = analysis.nodes;
+        let m = analysis.edges;
+        let log = (usize::BITS - n.leading_zeros()).max(1) as usize;
+        let recommendations = &mut analysis.recommendations;
+
+        if analysis.negative_weights && self.directed {
+            recommendations.push(Recommendation {
+                task: "single-source shortest paths",
+                algorithm: "BellmanFordAlgorithm",
+                configuration: String::new(),
+                reason:
+                    "negative weights rule out Dijkstra's Algorithm; negative cycles are reported"
+                        .to_string(),
+                estimated_operations: n * m,
+            });
+            recommendations.push(Recommendation {
+                task: "all-pairs shortest paths",
+                algorithm: "FloydWarshallAlgorithm",
+                configuration: String::new(),
+                reason: "handles negative weights for every pair at once".to_string(),
+                estimated_operations: n * n * n,
+            });
+        } else if !analysis.negative_weights {
+            let mut dijkstra = DijkstraAlgorithm::new();
+
+            for node in 0..n {
+                dijkstra.set_node(node, Vec::new());
+            }
+
+            for &(source, target, weight) in &self.edges {
+                dijkstra
+                    .graph
+                    .entry(source)
+                    .or_default()
+                    .push((target, weight as usize));
+
+                if !self.directed {
+                    dijkstra
+                        .graph
+                        .entry(target)
+                        .or_default()
+                        .push((source, weight as usize));
+                }
+            }
+
+            let single = (n + m) * log;
+
+            recommendations.push(Recommendation {
+                task: "single-source shortest paths",
+                algorithm: "DijkstraAlgorithm",
+                configuration: format!("PriorityQueueKind::{:?}", dijkstra.selected_queue()),
+                reason: "non-negative weights; the queue is chosen from the size and weight range"
+                    .to_string(),
+                estimated_operations: single,
+            });
+
+            let (algorithm, reason, estimated_operations) = match n * single < n * n * n {
+                true => (
+                    "DijkstraAlgorithm",
+                    "sparse graph, one search from every node is cheaper than the cubic table",
+                    n * single,
+                ),
+                false => (
+                    "FloydWarshallAlgorithm",
+                    "dense graph, the cubic table is cheaper than one search from every node",
+                    n * n * n,
+                ),
+            };
+
+            recommendations.push(Recommendation {
+                task: "all-pairs shortest paths",
+                algorithm,
+                configuration: String::new(),
+                reason: reason.to_string(),
+                estimated_operations,
+            });
+        }
+
+        if !self.directed {
+            let (algorithm, reason, estimated_operations) = match analysis.density > 0.25 {
+                true => (
+                    "PrimAlgorithm",
+                    "dense graph, growing one tree avoids sorting every edge",
+                    (n + m) * log,
+                ),
+                false => (
+                    "KruskalAlgorithm",
+                    "sparse graph, sorting the few edges is cheapest",
+                    m * log,
+                ),
+            };
+
+            recommendations.push(Recommendation {
+                task: "minimum spanning tree",
+                algorithm,
+                configuration: String::new(),
+                reason: reason.to_string(),
+                estimated_operations,
+            });
+        }
+
+        if analysis.is_dag {
+            recommendations.push(Recommendation {
+                task: "dependency order",
+                algorithm: "TopologicalSortAlgorithm",
+                configuration: String::new(),
+                reason: "the graph is acyclic, so every edge can point forward".to_string(),
+                estimated_operations: n + m,
+            });
+        }
+    }
+}
+
+impl GraphAlgorithm for GraphAnalyzer {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = GraphAnalysis;
+
+    /// Run the graph analyzer.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the graph analyzer.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the profile of the graph with recommendations.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.analyze())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn algorithms(analysis: &GraphAnalysis) -> Vec<(&str, &str)> {
+        analysis
+            .recommendations
+            .iter()
+            .map(|recommendation| (recommendation.task, recommendation.algorithm))
+            .collect()
+    }
+
+    #[test]
+    fn test_new() {
+        let analyzer = GraphAnalyzer::new();
+        let analyzer_default = GraphAnalyzer::default();
+
+        assert!(analyzer.directed);
+        assert_eq!(analyzer_default.analyze().nodes, 0);
+    }
+
+    #[test]
+    fn test_run_negative_dag() {
+        let mut analyzer = GraphAnalyzer::new();
+        analyzer.set_edges(vec![(0, vec![(1, 4), (2, -2)]), (2, vec![(1, 3)])]);
+
+        let analysis = analyzer.run(None).unwrap();
+
+        assert!(analysis.negative_weights);
+        assert!(analysis.is_dag);
+        assert!(analysis.weakly_connected);
+        assert!(!analysis.strongly_connected);
+        assert_eq!(analysis.weight_range, Some((-2, 4)));
+        assert_eq!(analysis.density, 0.5);
+        assert!(analysis.warnings.is_empty());
+        assert_eq!(
+            algorithms(&analysis),
+            vec![
+                ("single-source shortest paths", "BellmanFordAlgorithm"),
+                ("all-pairs shortest paths", "FloydWarshallAlgorithm"),
+                ("dependency order", "TopologicalSortAlgorithm"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_run_undirected() {
+        let mut analyzer = GraphAnalyzer::new();
+        analyzer.set_directed(false);
+        analyzer.set_edges(vec![
+            (0, vec![(1, 1), (1, 2)]),
+            (1, vec![(2, 1)]),
+            (2, vec![(2, 5)]),
+            (3, vec![(4, 1)]),
+        ]);
+        analyzer.set_total_nodes(7);
+
+        let analysis = analyzer.run(None).unwrap();
+
+        assert!(!analysis.is_dag);
+        assert!(!analysis.weakly_connected);
+        assert_eq!(analysis.warnings.len(), 4);
+        assert_eq!(
+            analysis.recommendations[0].configuration,
+            "PriorityQueueKind::Binary"
+        );
+        assert_eq!(
+            algorithms(&analysis),
+            vec![
+                ("single-source shortest paths", "DijkstraAlgorithm"),
+                ("all-pairs shortest paths", "DijkstraAlgorithm"),
+                ("minimum spanning tree", "KruskalAlgorithm"),
+            ]
+        );
+
+        // Negative undirected edges leave no shortest path recommendation.
+        analyzer.set_edge(4, 5, -1);
+
+        assert_eq!(
+            algorithms(&analyzer.analyze()),
+            vec![("minimum spanning tree", "KruskalAlgorithm")]
+        );
+    }
+}
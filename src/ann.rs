@@ -0,0 +1,476 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BTreeSet, BinaryHeap},
+};
+
+use crate::random::Random;
+
+/// Node of the index at a given distance from a query.
+#[derive(Debug, Clone, Copy)]
+struct Candidate {
+    /// Cosine distance to the query.
+    distance: f64,
+
+    /// Node.
+    node: usize,
+}
+
+impl PartialEq for Candidate {
+    /// Check whether two candidates are equal.
+    ///
+    /// # Arguments
+    ///
+    /// - `other`: The other candidate to compare.
+    ///
+    /// # Returns
+    ///
+    /// `true` if both the distance and the node are equal.
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    /// Compare two candidates partially.
+    ///
+    /// # Arguments
+    ///
+    /// - `other`: The other candidate to compare.
+    ///
+    /// # Returns
+    ///
+    /// Ordering of the two candidates.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    /// Compare two candidates by distance, then by node.
+    ///
+    /// # Arguments
+    ///
+    /// - `other`: The other candidate to compare.
+    ///
+    /// # Returns
+    ///
+    /// Ordering of the two candidates.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then_with(|| self.node.cmp(&other.node))
+    }
+}
+
+/// Similarity Index.
+/// Approximate nearest neighbor index over per-node vectors, such as node embeddings,
+/// using a small hierarchical navigable small world (HNSW) graph with cosine similarity.
+#[derive(Debug, Clone)]
+pub struct SimilarityIndex {
+    /// Vector of each node.
+    pub vectors: Vec<Vec<f64>>,
+
+    /// Maximum number of neighbors per node on the upper layers, twice as many on the bottom layer.
+    pub max_neighbors: usize,
+
+    /// Number of candidates explored while inserting a node.
+    pub ef_construction: usize,
+
+    /// Minimum number of candidates explored while searching.
+    pub ef_search: usize,
+
+    /// Seed of the layer assignment.
+    pub seed: u64,
+
+    /// Neighbors of each node on each of its layers.
+    layers: Vec<Vec<Vec<usize>>>,
+
+    /// Node of the top layer where searches start.
+    entry: Option<usize>,
+
+    /// Generator of the layers.
+    random: Random,
+}
+
+impl Default for SimilarityIndex {
+    /// Create a new default instance of the similarity index.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the similarity index.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SimilarityIndex {
+    /// Create a new instance of the similarity index.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the similarity index.
+    pub fn new() -> Self {
+        Self {
+            vectors: Vec::new(),
+            max_neighbors: 8,
+            ef_construction: 64,
+            ef_search: 32,
+            seed: 0,
+            layers: Vec::new(),
+            entry: None,
+            random: Random::new(0),
+        }
+    }
+
+    /// Set the maximum number of neighbors per node. Only affects nodes inserted afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_neighbors`: Maximum number of neighbors on the upper layers.
+    pub fn set_max_neighbors(&mut self, max_neighbors: usize) {
+        self.max_neighbors = max_neighbors.max(2);
+    }
+
+    /// Set the number of candidates explored while inserting and searching.
+    ///
+    /// # Arguments
+    ///
+    /// - `ef_construction`: Number of candidates explored while inserting.
+    /// - `ef_search`: Minimum number of candidates explored while searching.
+    pub fn set_ef(&mut self, ef_construction: usize, ef_search: usize) {
+        self.ef_construction = ef_construction.max(1);
+        self.ef_search = ef_search.max(1);
+    }
+
+    /// Set the seed of the layer assignment. Only affects nodes inserted afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// - `seed`: Seed.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.random = Random::new(seed);
+    }
+
+    /// Insert the vectors of the nodes, numbered from the current number of nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `vectors`: Vector of each node, e.g. the result of an embedding.
+    pub fn set_vectors(&mut self, vectors: Vec<Vec<f64>>) {
+        for vector in vectors {
+            self.insert(vector);
+        }
+    }
+
+    /// Insert the vector of the next node.
+    ///
+    /// # Arguments
+    ///
+    /// - `vector`: Vector of the node.
+    ///
+    /// # Returns
+    ///
+    /// Node ID of the vector.
+    pub fn insert(&mut self, vector: Vec<f64>) -> usize {
+        let node = self.vectors.len();
+        let scale = 1.0 / (self.max_neighbors as f64).ln();
+        let level = (-(1.0 - self.random.next_f64()).ln() * scale) as usize;
+
+        self.vectors.push(vector);
+        self.layers.push(vec![Vec::new(); level + 1]);
+
+        let Some(entry) = self.entry else {
+            self.entry = Some(node);
+            return node;
+        };
+
+        let top = self.layers[entry].len() - 1;
+        let query = self.vectors[node].clone();
+        let mut nearest = vec![self.candidate(&query, entry)];
+
+        for layer in (level + 1..=top).rev() {
+            nearest = self.search_layer(&query, &nearest, 1, layer);
+        }
+
+        for layer in (0..=level.min(top)).rev() {
+            nearest = self.search_layer(&query, &nearest, self.ef_construction, layer);
+
+            let capacity = self.capacity(layer);
+            let neighbors: Vec<usize> = nearest
+                .iter()
+                .take(capacity)
+                .map(|candidate| candidate.node)
+                .collect();
+
+            for &neighbor in &neighbors {
+                self.layers[neighbor][layer].push(node);
+
+                if self.layers[neighbor][layer].len() > capacity {
+                    self.prune(neighbor, layer, capacity);
+                }
+            }
+
+            self.layers[node][layer] = neighbors;
+        }
+
+        if level > top {
+            self.entry = Some(node);
+        }
+
+        node
+    }
+
+    /// Get the number of nodes in the index.
+    ///
+    /// # Returns
+    ///
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.vectors.len()
+    }
+
+    /// Check whether the index has no nodes.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the index is empty.
+    pub fn is_empty(&self) -> bool {
+        self.vectors.is_empty()
+    }
+
+    /// Find the nodes most similar to a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node to compare with.
+    /// - `k`: Number of nodes to return.
+    ///
+    /// # Returns
+    ///
+    /// Up to `k` other nodes with their cosine similarity, most similar first.
+    pub fn similar_nodes(&self, node: usize, k: usize) -> Vec<(usize, f64)> {
+        match self.vectors.get(node) {
+            Some(vector) => self
+                .search(vector, k + 1)
+                .into_iter()
+                .filter(|&(other, _)| other != node)
+                .take(k)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Find the nodes most similar to a vector.
+    ///
+    /// # Arguments
+    ///
+    /// - `query`: Vector to compare with.
+    /// - `k`: Number of nodes to return.
+    ///
+    /// # Returns
+    ///
+    /// Up to `k` nodes with their cosine similarity, most similar first.
+    pub fn search(&self, query: &[f64], k: usize) -> Vec<(usize, f64)> {
+        let Some(entry) = self.entry else {
+            return Vec::new();
+        };
+
+        let mut nearest = vec![self.candidate(query, entry)];
+
+        for layer in (1..self.layers[entry].len()).rev() {
+            nearest = self.search_layer(query, &nearest, 1, layer);
+        }
+
+        self.search_layer(query, &nearest, self.ef_search.max(k), 0)
+            .into_iter()
+            .take(k)
+            .map(|candidate| (candidate.node, 1.0 - candidate.distance))
+            .collect()
+    }
+
+    /// Get the maximum number of neighbors on a layer.
+    ///
+    /// # Arguments
+    ///
+    /// - `layer`: Layer.
+    ///
+    /// # Returns
+    ///
+    /// Maximum number of neighbors.
+    fn capacity(&self, layer: usize) -> usize {
+        match layer {
+            0 => 2 * self.max_neighbors,
+            _ => self.max_neighbors,
+        }
+    }
+
+    /// Compute the cosine distance between a vector and a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `query`: Vector.
+    /// - `node`: Node.
+    ///
+    /// # Returns
+    ///
+    /// Candidate holding the node and its distance.
+    fn candidate(&self, query: &[f64], node: usize) -> Candidate {
+        let vector = &self.vectors[node];
+        let dot: f64 = query.iter().zip(vector).map(|(a, b)| a * b).sum();
+        let norms = query.iter().map(|a| a * a).sum::<f64>().sqrt()
+            * vector.iter().map(|a| a * a).sum::<f64>().sqrt();
+        let similarity = if norms > 0.0 { dot / norms } else { 0.0 };
+
+        Candidate {
+            distance: 1.0 - similarity,
+            node,
+        }
+    }
+
+    /// Keep only the nearest neighbors of a node on a layer.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node.
+    /// - `layer`: Layer.
+    /// - `capacity`: Number of neighbors to keep.
+    fn prune(&mut self, node: usize, layer: usize, capacity: usize) {
+        let vector = &self.vectors[node];
+        let mut neighbors: Vec<Candidate> = self.layers[node][layer]
+            .iter()
+            .map(|&neighbor| self.candidate(vector, neighbor))
+            .collect();
+        neighbors.sort_unstable();
+
+        self.layers[node][layer] = neighbors
+            .into_iter()
+            .take(capacity)
+            .map(|candidate| candidate.node)
+            .collect();
+    }
+
+    /// Greedy best-first search of a layer.
+    ///
+    /// # Arguments
+    ///
+    /// - `query`: Vector to compare with.
+    /// - `entries`: Nodes where the search starts.
+    /// - `ef`: Number of candidates to keep.
+    /// - `layer`: Layer.
+    ///
+    /// # Returns
+    ///
+    /// Up to `ef` nearest nodes found, nearest first.
+    fn search_layer(
+        &self,
+        query: &[f64],
+        entries: &[Candidate],
+        ef: usize,
+        layer: usize,
+    ) -> Vec<Candidate> {
+        let mut visited: BTreeSet<usize> = entries.iter().map(|entry| entry.node).collect();
+        let mut frontier: BinaryHeap<Reverse<Candidate>> =
+            entries.iter().copied().map(Reverse).collect();
+        let mut found: BinaryHeap<Candidate> = entries.iter().copied().collect();
+
+        while let Some(Reverse(current)) = frontier.pop() {
+            let farthest = found.peek().map_or(f64::INFINITY, |c| c.distance);
+
+            if current.distance > farthest && found.len() >= ef {
+                break;
+            }
+
+            for &neighbor in &self.layers[current.node][layer] {
+                if !visited.insert(neighbor) {
+                    continue;
+                }
+
+                let candidate = self.candidate(query, neighbor);
+                let farthest = found.peek().map_or(f64::INFINITY, |c| c.distance);
+
+                if found.len() < ef || candidate.distance < farthest {
+                    frontier.push(Reverse(candidate));
+                    found.push(candidate);
+
+                    if found.len() > ef {
+                        found.pop();
+                    }
+                }
+            }
+        }
+
+        found.into_sorted_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn random_vectors(count: usize, dimensions: usize, seed: u64) -> Vec<Vec<f64>> {
+        let mut random = Random::new(seed);
+
+        (0..count)
+            .map(|_| (0..dimensions).map(|_| random.next_f64() - 0.5).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_new() {
+        let index = SimilarityIndex::new();
+        let index_default = SimilarityIndex::default();
+
+        assert!(index.is_empty());
+        assert_eq!(index_default.max_neighbors, 8);
+        assert_eq!(index.search(&[1.0], 3), vec![]);
+    }
+
+    #[test]
+    fn test_similar_nodes() {
+        let mut index = SimilarityIndex::new();
+        index.set_vectors(vec![
+            vec![1.0, 0.0],
+            vec![0.0, 1.0],
+            vec![0.9, 0.1],
+            vec![-1.0, 0.0],
+            vec![0.1, 0.9],
+        ]);
+
+        let similar = index.similar_nodes(0, 2);
+
+        assert_eq!(index.len(), 5);
+        assert_eq!(similar[0].0, 2);
+        assert_eq!(similar[1].0, 4);
+        assert!(similar[0].1 > similar[1].1);
+        assert_eq!(index.similar_nodes(9, 2), vec![]);
+    }
+
+    #[test]
+    fn test_search_recall() {
+        let vectors = random_vectors(500, 8, 1);
+        let mut index = SimilarityIndex::new();
+        index.set_seed(2);
+        index.set_vectors(vectors.clone());
+
+        let mut hits = 0;
+
+        for query in random_vectors(20, 8, 3) {
+            let mut exact: Vec<Candidate> = (0..vectors.len())
+                .map(|node| index.candidate(&query, node))
+                .collect();
+            exact.sort_unstable();
+
+            let expected: BTreeSet<usize> = exact.iter().take(10).map(|c| c.node).collect();
+            hits += index
+                .search(&query, 10)
+                .iter()
+                .filter(|(node, _)| expected.contains(node))
+                .count();
+        }
+
+        assert!(hits >= 180, "recall {hits}/200");
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::BTreeSet;
+
+use crate::{GraphAlgorithm, GraphError};
+
+/// Approximation Algorithms.
+/// Compute a 2-approximate minimum vertex cover and greedy maximal independent sets of an undirected graph.
+#[derive(Debug, Clone)]
+pub struct ApproximationAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Neighbors of each node.
+    pub adjacency: Vec<BTreeSet<usize>>,
+}
+
+impl Default for ApproximationAlgorithm {
+    /// Create a new default instance of the approximation algorithms.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the approximation algorithms.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ApproximationAlgorithm {
+    /// Create a new instance of the approximation algorithms.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the approximation algorithms.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].insert(target);
+        self.adjacency[target].insert(source);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its neighbors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, BTreeSet::new());
+    }
+
+    /// Compute a vertex cover at most twice the minimum size,
+    /// taking both endpoints of the edges of a maximal matching.
+    ///
+    /// # Returns
+    ///
+    /// Sorted nodes of the cover.
+    pub fn vertex_cover(&self) -> Vec<usize> {
+        let mut covered = vec![false; self.total_nodes];
+
+        for source in 0..self.total_nodes {
+            for &target in &self.adjacency[source] {
+                if !covered[source] && !covered[target] {
+                    covered[source] = true;
+                    covered[target] = true;
+                }
+            }
+        }
+
+        (0..self.total_nodes)
+            .filter(|&node| covered[node])
+            .collect()
+    }
+
+    /// Compute a maximal independent set, repeatedly taking a node of minimum remaining degree.
+    ///
+    /// # Returns
+    ///
+    /// Sorted nodes of the independent set.
+    pub fn independent_set(&self) -> Vec<usize> {
+        let mut removed = vec![false; self.total_nodes];
+        let mut degrees: Vec<usize> = self.adjacency.iter().map(BTreeSet::len).collect();
+        let mut selected = Vec::new();
+
+        while let Some(node) = (0..self.total_nodes)
+            .filter(|&node| !removed[node] && !self.adjacency[node].contains(&node))
+            .min_by_key(|&node| (degrees[node], node))
+        {
+            selected.push(node);
+
+            // Remove the node and its neighbors, updating the degrees of their neighbors.
+            for &gone in std::iter::once(&node).chain(&self.adjacency[node]) {
+                if std::mem::replace(&mut removed[gone], true) {
+                    continue;
+                }
+
+                for &neighbor in &self.adjacency[gone] {
+                    degrees[neighbor] -= 1;
+                }
+            }
+        }
+
+        selected.sort_unstable();
+        selected
+    }
+
+    /// Compute a maximal independent set, taking nodes greedily in the given order.
+    ///
+    /// # Arguments
+    ///
+    /// - `order`: Nodes in order of preference.
+    ///
+    /// # Returns
+    ///
+    /// Sorted nodes of the independent set.
+    pub fn independent_set_in_order(&self, order: &[usize]) -> Vec<usize> {
+        let mut blocked = vec![false; self.total_nodes];
+        let mut selected = Vec::new();
+
+        for &node in order
+            .iter()
+            .chain(&(0..self.total_nodes).collect::<Vec<_>>())
+        {
+            if node >= self.total_nodes || blocked[node] || self.adjacency[node].contains(&node) {
+                continue;
+            }
+
+            selected.push(node);
+            blocked[node] = true;
+
+            for &neighbor in &self.adjacency[node] {
+                blocked[neighbor] = true;
+            }
+        }
+
+        selected.sort_unstable();
+        selected
+    }
+}
+
+impl GraphAlgorithm for ApproximationAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<usize>;
+
+    /// Run the vertex cover 2-approximation.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the approximation algorithms.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the sorted nodes of the vertex cover.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.vertex_cover())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn star() -> ApproximationAlgorithm {
+        let mut algorithm = ApproximationAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1, 2, 3, 4]), (4, vec![5])]);
+        algorithm
+    }
+
+    fn is_independent(algorithm: &ApproximationAlgorithm, nodes: &[usize]) -> bool {
+        nodes.iter().all(|node| {
+            nodes
+                .iter()
+                .all(|other| !algorithm.adjacency[*node].contains(other))
+        })
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = ApproximationAlgorithm::new();
+        let algorithm_default = ApproximationAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.total_nodes, 0);
+    }
+
+    #[test]
+    fn test_run() {
+        let algorithm = star();
+        let cover = algorithm.run(None).unwrap();
+
+        assert_eq!(cover, vec![0, 1, 4, 5]);
+
+        for (source, targets) in algorithm.adjacency.iter().enumerate() {
+            for target in targets {
+                assert!(cover.contains(&source) || cover.contains(target));
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_self_loop() {
+        let mut algorithm = ApproximationAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![0]), (1, vec![2])]);
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![0, 1, 2]);
+        assert_eq!(algorithm.independent_set(), vec![1]);
+    }
+
+    #[test]
+    fn test_independent_set() {
+        let algorithm = star();
+        let independent = algorithm.independent_set();
+
+        assert_eq!(independent, vec![1, 2, 3, 4]);
+        assert!(is_independent(&algorithm, &independent));
+    }
+
+    #[test]
+    fn test_independent_set_in_order() {
+        let algorithm = star();
+        let independent = algorithm.independent_set_in_order(&[0]);
+
+        assert_eq!(independent, vec![0, 5]);
+        assert!(is_independent(&algorithm, &independent));
+    }
+}
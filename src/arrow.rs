@@ -0,0 +1,336 @@
+use std::{fs::File, path::Path, sync::Arc};
+
+use arrow_array::{
+    ArrayRef, Float64Array, Int32Array, Int64Array, RecordBatch, UInt32Array, UInt64Array,
+};
+use arrow_schema::{ArrowError, DataType, Field, Schema};
+use parquet::{arrow::ArrowWriter, errors::ParquetError};
+
+/// Value that can be stored in an Arrow column, such as a weight or a distance.
+pub trait ArrowValue: Copy {
+    /// Arrow type of the column.
+    ///
+    /// # Returns
+    ///
+    /// Data type of the column.
+    fn data_type() -> DataType;
+
+    /// Build a column from optional values, `None` becoming null.
+    ///
+    /// # Arguments
+    ///
+    /// - `values`: Values of the column.
+    ///
+    /// # Returns
+    ///
+    /// Arrow array of the values.
+    fn array(values: Vec<Option<Self>>) -> ArrayRef;
+}
+
+impl ArrowValue for usize {
+    /// Arrow type of the column.
+    ///
+    /// # Returns
+    ///
+    /// Unsigned 64-bit integers.
+    fn data_type() -> DataType {
+        DataType::UInt64
+    }
+
+    /// Build a column from optional values, `None` becoming null.
+    ///
+    /// # Arguments
+    ///
+    /// - `values`: Values of the column.
+    ///
+    /// # Returns
+    ///
+    /// Arrow array of the values.
+    fn array(values: Vec<Option<Self>>) -> ArrayRef {
+        Arc::new(UInt64Array::from_iter(
+            values
+                .into_iter()
+                .map(|value| value.map(|value| value as u64)),
+        ))
+    }
+}
+
+impl ArrowValue for u32 {
+    /// Arrow type of the column.
+    ///
+    /// # Returns
+    ///
+    /// Unsigned 32-bit integers.
+    fn data_type() -> DataType {
+        DataType::UInt32
+    }
+
+    /// Build a column from optional values, `None` becoming null.
+    ///
+    /// # Arguments
+    ///
+    /// - `values`: Values of the column.
+    ///
+    /// # Returns
+    ///
+    /// Arrow array of the values.
+    fn array(values: Vec<Option<Self>>) -> ArrayRef {
+        Arc::new(UInt32Array::from(values))
+    }
+}
+
+impl ArrowValue for i32 {
+    /// Arrow type of the column.
+    ///
+    /// # Returns
+    ///
+    /// Signed 32-bit integers.
+    fn data_type() -> DataType {
+        DataType::Int32
+    }
+
+    /// Build a column from optional values, `None` becoming null.
+    ///
+    /// # Arguments
+    ///
+    /// - `values`: Values of the column.
+    ///
+    /// # Returns
+    ///
+    /// Arrow array of the values.
+    fn array(values: Vec<Option<Self>>) -> ArrayRef {
+        Arc::new(Int32Array::from(values))
+    }
+}
+
+impl ArrowValue for i64 {
+    /// Arrow type of the column.
+    ///
+    /// # Returns
+    ///
+    /// Signed 64-bit integers.
+    fn data_type() -> DataType {
+        DataType::Int64
+    }
+
+    /// Build a column from optional values, `None` becoming null.
+    ///
+    /// # Arguments
+    ///
+    /// - `values`: Values of the column.
+    ///
+    /// # Returns
+    ///
+    /// Arrow array of the values.
+    fn array(values: Vec<Option<Self>>) -> ArrayRef {
+        Arc::new(Int64Array::from(values))
+    }
+}
+
+impl ArrowValue for f64 {
+    /// Arrow type of the column.
+    ///
+    /// # Returns
+    ///
+    /// 64-bit floating point numbers.
+    fn data_type() -> DataType {
+        DataType::Float64
+    }
+
+    /// Build a column from optional values, `None` becoming null.
+    ///
+    /// # Arguments
+    ///
+    /// - `values`: Values of the column.
+    ///
+    /// # Returns
+    ///
+    /// Arrow array of the values.
+    fn array(values: Vec<Option<Self>>) -> ArrayRef {
+        Arc::new(Float64Array::from(values))
+    }
+}
+
+/// Export weighted edges as a record batch with the columns `source`, `target` and `weight`.
+///
+/// # Arguments
+///
+/// - `edges`: Edges as `(source, target, weight)`.
+///
+/// # Returns
+///
+/// Result containing the record batch, or an error from Arrow.
+pub fn edges_batch<W: ArrowValue>(
+    edges: impl IntoIterator<Item = (usize, usize, W)>,
+) -> Result<RecordBatch, ArrowError> {
+    let (mut sources, mut targets, mut weights) = (Vec::new(), Vec::new(), Vec::new());
+
+    for (source, target, weight) in edges {
+        sources.push(Some(source));
+        targets.push(Some(target));
+        weights.push(Some(weight));
+    }
+
+    let schema = Schema::new(vec![
+        Field::new("source", DataType::UInt64, false),
+        Field::new("target", DataType::UInt64, false),
+        Field::new("weight", W::data_type(), false),
+    ]);
+
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            usize::array(sources),
+            usize::array(targets),
+            W::array(weights),
+        ],
+    )
+}
+
+/// Export a result with one value per node, such as distances or ranks, as a record batch with the
+/// columns `node` and the named value column.
+///
+/// # Arguments
+///
+/// - `column`: Name of the value column.
+/// - `values`: Value of each node in order, `None` for a missing value such as an unreachable node.
+///
+/// # Returns
+///
+/// Result containing the record batch, or an error from Arrow.
+pub fn nodes_batch<W: ArrowValue>(
+    column: &str,
+    values: impl IntoIterator<Item = Option<W>>,
+) -> Result<RecordBatch, ArrowError> {
+    let values: Vec<Option<W>> = values.into_iter().collect();
+    let nodes = (0..values.len()).map(Some).collect();
+
+    let schema = Schema::new(vec![
+        Field::new("node", DataType::UInt64, false),
+        Field::new(column, W::data_type(), true),
+    ]);
+
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![usize::array(nodes), W::array(values)],
+    )
+}
+
+/// Export distances, such as those of Dijkstra's Algorithm, as a record batch with the columns
+/// `node` and `distance`, unreachable nodes at `usize::MAX` becoming null.
+///
+/// # Arguments
+///
+/// - `distances`: Distance of each node.
+///
+/// # Returns
+///
+/// Result containing the record batch, or an error from Arrow.
+pub fn distances_batch(distances: &[usize]) -> Result<RecordBatch, ArrowError> {
+    nodes_batch(
+        "distance",
+        distances
+            .iter()
+            .map(|&distance| (distance != usize::MAX).then_some(distance)),
+    )
+}
+
+/// Write record batches sharing a schema to a Parquet file.
+///
+/// # Arguments
+///
+/// - `path`: Path of the file, created or truncated.
+/// - `batches`: Record batches, at least one.
+///
+/// # Returns
+///
+/// Result containing nothing, or an error if the batches are missing or cannot be written.
+pub fn write_parquet(path: impl AsRef<Path>, batches: &[RecordBatch]) -> Result<(), ParquetError> {
+    let schema = batches
+        .first()
+        .ok_or_else(|| ParquetError::General("no record batches to write".to_string()))?
+        .schema();
+    let file = File::create(path)?;
+    let mut writer = ArrowWriter::try_new(file, schema, None)?;
+
+    for batch in batches {
+        writer.write(batch)?;
+    }
+
+    writer.close()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::Array;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    #[test]
+    fn test_edges_batch() {
+        let batch = edges_batch(vec![(0, 1, 2.5), (1, 2, 4.0)]).unwrap();
+
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema().field(2).data_type(), &DataType::Float64);
+
+        let targets = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .unwrap();
+
+        assert_eq!(targets.values().to_vec(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_distances_batch() {
+        let batch = distances_batch(&[0, 4, usize::MAX]).unwrap();
+        let distances = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .unwrap();
+
+        assert_eq!(batch.schema().field(1).name(), "distance");
+        assert_eq!(distances.value(1), 4);
+        assert!(distances.is_null(2));
+    }
+
+    #[test]
+    fn test_write_parquet() {
+        let path = std::env::temp_dir().join(format!(
+            "graph-algorithms-rs-{}-edges.parquet",
+            std::process::id()
+        ));
+        let batch = edges_batch(vec![(0, 1, -3i32), (1, 0, 5)]).unwrap();
+
+        write_parquet(&path, &[batch.clone(), batch.clone()]).unwrap();
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let read: Vec<RecordBatch> = reader.map(Result::unwrap).collect();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(read.iter().map(RecordBatch::num_rows).sum::<usize>(), 4);
+        assert_eq!(read[0].schema(), batch.schema());
+
+        let weights = read[0]
+            .column(2)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+
+        assert_eq!(weights.values()[..2], [-3, 5]);
+    }
+
+    #[test]
+    fn test_write_parquet_without_batches() {
+        let path = std::env::temp_dir().join("graph-algorithms-rs-empty.parquet");
+
+        assert!(write_parquet(path, &[]).is_err());
+    }
+}
@@ -0,0 +1,706 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+};
+
+use crate::{GraphAlgorithm, GraphError, MemoryEstimate, MemoryEstimation};
+
+/// Estimate of the cost between two nodes for A* search.
+/// Front-to-end searches estimate from a node to an end of the path with `estimate`, while
+/// front-to-front searches estimate from a node to the frontier of the opposite search with
+/// `estimate_to_front` and `estimate_from_front`, which a heuristic may override with a faster
+/// nearest-node query than the scan over the frontier.
+pub trait Heuristic {
+    /// Estimate the cost of a shortest path between two nodes.
+    /// The estimate must be consistent, `estimate(u, t) <= weight(u, v) + estimate(v, t)` for every
+    /// edge, and symmetrically for the source, for the paths to be shortest.
+    ///
+    /// # Arguments
+    ///
+    /// - `from`: First node.
+    /// - `to`: Second node.
+    ///
+    /// # Returns
+    ///
+    /// Lower bound of the cost from the first node to the second.
+    fn estimate(&self, from: usize, to: usize) -> usize;
+
+    /// Estimate the cost from a node to the target through the frontier of the backward search.
+    ///
+    /// # Arguments
+    ///
+    /// - `from`: Node.
+    /// - `front`: Nodes of the frontier with their cost to the target.
+    ///
+    /// # Returns
+    ///
+    /// Lower bound of the cost from the node to the target, `usize::MAX` for an empty frontier.
+    fn estimate_to_front(&self, from: usize, front: &[(usize, usize)]) -> usize {
+        front
+            .iter()
+            .map(|&(node, cost)| self.estimate(from, node).saturating_add(cost))
+            .min()
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Estimate the cost from the source to a node through the frontier of the forward search.
+    ///
+    /// # Arguments
+    ///
+    /// - `front`: Nodes of the frontier with their cost from the source.
+    /// - `to`: Node.
+    ///
+    /// # Returns
+    ///
+    /// Lower bound of the cost from the source to the node, `usize::MAX` for an empty frontier.
+    fn estimate_from_front(&self, front: &[(usize, usize)], to: usize) -> usize {
+        front
+            .iter()
+            .map(|&(node, cost)| cost.saturating_add(self.estimate(node, to)))
+            .min()
+            .unwrap_or(usize::MAX)
+    }
+}
+
+/// Heuristic estimating every cost as zero, turning A* into Dijkstra's Algorithm.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ZeroHeuristic;
+
+impl Heuristic for ZeroHeuristic {
+    /// Estimate the cost between two nodes as zero.
+    ///
+    /// # Arguments
+    ///
+    /// - `from`: First node.
+    /// - `to`: Second node.
+    ///
+    /// # Returns
+    ///
+    /// Zero.
+    fn estimate(&self, _from: usize, _to: usize) -> usize {
+        0
+    }
+}
+
+/// Heuristic from the straight-line distance between node coordinates, such as projected road
+/// network positions.
+#[derive(Debug, Clone, Default)]
+pub struct EuclideanHeuristic {
+    /// Planar position of each node.
+    pub positions: Vec<(f64, f64)>,
+
+    /// Smallest cost of one unit of distance over all edges, e.g. seconds per metre at the top speed.
+    pub cost_per_unit: f64,
+}
+
+impl Heuristic for EuclideanHeuristic {
+    /// Estimate the cost between two nodes from their straight-line distance, rounded down.
+    ///
+    /// # Arguments
+    ///
+    /// - `from`: First node.
+    /// - `to`: Second node.
+    ///
+    /// # Returns
+    ///
+    /// Lower bound of the cost, zero for nodes without a position.
+    fn estimate(&self, from: usize, to: usize) -> usize {
+        match (self.positions.get(from), self.positions.get(to)) {
+            (Some(&(x1, y1)), Some(&(x2, y2))) => {
+                ((x1 - x2).hypot(y1 - y2) * self.cost_per_unit).floor() as usize
+            }
+            _ => 0,
+        }
+    }
+}
+
+/// A* Algorithm.
+/// Find a shortest path between two nodes of a directed weighted graph, guided by a pluggable
+/// heuristic. The bidirectional variant searches from both ends on edges reduced by the average
+/// of the forward and backward potentials, so that both searches see the same consistent costs and
+/// can stop as soon as their frontiers together reach the best path met in the middle. The
+/// front-to-front variant estimates instead between each node and the opposite frontier.
+#[derive(Debug, Clone)]
+pub struct AStarAlgorithm<H = ZeroHeuristic> {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Successors of each node with the weight of the edge.
+    pub adjacency: Vec<Vec<(usize, usize)>>,
+
+    /// Predecessors of each node with the weight of the edge.
+    pub reverse: Vec<Vec<(usize, usize)>>,
+
+    /// Heuristic guiding the search.
+    pub heuristic: H,
+
+    /// Target node of `run`.
+    pub target: Option<usize>,
+}
+
+impl Default for AStarAlgorithm {
+    /// Create a new default instance of A* Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of A* Algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AStarAlgorithm {
+    /// Create a new instance of A* Algorithm without a heuristic.
+    ///
+    /// # Returns
+    ///
+    /// New instance of A* Algorithm.
+    pub fn new() -> Self {
+        Self::with_heuristic(ZeroHeuristic)
+    }
+}
+
+impl<H: Heuristic> AStarAlgorithm<H> {
+    /// Create a new instance of A* Algorithm with a heuristic.
+    ///
+    /// # Arguments
+    ///
+    /// - `heuristic`: Heuristic guiding the search.
+    ///
+    /// # Returns
+    ///
+    /// New instance of A* Algorithm.
+    pub fn with_heuristic(heuristic: H) -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+            reverse: Vec::new(),
+            heuristic,
+            target: None,
+        }
+    }
+
+    /// Set a single directed edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].push((target, weight));
+        self.reverse[target].push((source, weight));
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, Vec::new());
+        self.reverse.resize(self.total_nodes, Vec::new());
+    }
+
+    /// Set the heuristic guiding the search.
+    ///
+    /// # Arguments
+    ///
+    /// - `heuristic`: Heuristic.
+    pub fn set_heuristic(&mut self, heuristic: H) {
+        self.heuristic = heuristic;
+    }
+
+    /// Set the target node of `run`.
+    ///
+    /// # Arguments
+    ///
+    /// - `target`: Target node.
+    pub fn set_target(&mut self, target: usize) {
+        self.target = Some(target);
+    }
+
+    /// Find a shortest path with a unidirectional A* search.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Cost and nodes of the path, or `None` if the target is unreachable.
+    pub fn shortest_path(&self, source: usize, target: usize) -> Option<(usize, Vec<usize>)> {
+        if source >= self.total_nodes || target >= self.total_nodes {
+            return None;
+        }
+
+        let mut distances = HashMap::from([(source, 0)]);
+        let mut parents = HashMap::new();
+        let mut queue =
+            BinaryHeap::from([Reverse((self.heuristic.estimate(source, target), source))]);
+
+        while let Some(Reverse((estimate, node))) = queue.pop() {
+            let cost = distances[&node];
+
+            if estimate > cost + self.heuristic.estimate(node, target) {
+                continue;
+            }
+
+            if node == target {
+                return Some((cost, Self::trace(&parents, target, true)));
+            }
+
+            for &(neighbor, weight) in &self.adjacency[node] {
+                let next = cost + weight;
+
+                if distances.get(&neighbor).map_or(true, |&known| next < known) {
+                    distances.insert(neighbor, next);
+                    parents.insert(neighbor, node);
+                    queue.push(Reverse((
+                        next + self.heuristic.estimate(neighbor, target),
+                        neighbor,
+                    )));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Find a shortest path with a bidirectional A* search.
+    /// Both searches run on the reduced weights `2w(u, v) - p(u) + p(v)` of the average potential
+    /// `p(v) = h(v, target) - h(source, v)`, which are non-negative for a consistent heuristic.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Cost and nodes of the path, or `None` if the target is unreachable.
+    pub fn bidirectional_path(&self, source: usize, target: usize) -> Option<(usize, Vec<usize>)> {
+        if source >= self.total_nodes || target >= self.total_nodes {
+            return None;
+        }
+
+        let potential = |node: usize| {
+            self.heuristic.estimate(node, target) as i64
+                - self.heuristic.estimate(source, node) as i64
+        };
+        let graphs = [&self.adjacency, &self.reverse];
+        let mut distances = [HashMap::from([(source, 0)]), HashMap::from([(target, 0)])];
+        let mut parents: [HashMap<usize, usize>; 2] = [HashMap::new(), HashMap::new()];
+        let mut queues = [
+            BinaryHeap::from([Reverse((0, source))]),
+            BinaryHeap::from([Reverse((0, target))]),
+        ];
+        let mut best: Option<(i64, usize)> = (source == target).then_some((0, source));
+
+        loop {
+            let fronts = queues
+                .each_ref()
+                .map(|queue| queue.peek().map(|&Reverse((cost, _))| cost));
+
+            let side = match fronts {
+                [Some(forward), Some(backward)] => {
+                    if best.is_some_and(|(cost, _)| forward + backward >= cost) {
+                        break;
+                    }
+
+                    usize::from(backward < forward)
+                }
+                _ => break,
+            };
+
+            let Some(Reverse((cost, node))) = queues[side].pop() else {
+                break;
+            };
+
+            if cost > distances[side][&node] {
+                continue;
+            }
+
+            for &(neighbor, weight) in &graphs[side][node] {
+                // Reduced weight of the edge in its original direction.
+                let (tail, head) = if side == 0 {
+                    (node, neighbor)
+                } else {
+                    (neighbor, node)
+                };
+                let next = cost + 2 * weight as i64 - potential(tail) + potential(head);
+
+                if distances[side]
+                    .get(&neighbor)
+                    .map_or(true, |&known| next < known)
+                {
+                    distances[side].insert(neighbor, next);
+                    parents[side].insert(neighbor, node);
+                    queues[side].push(Reverse((next, neighbor)));
+
+                    if let Some(&other) = distances[1 - side].get(&neighbor) {
+                        if best.map_or(true, |(known, _)| next + other < known) {
+                            best = Some((next + other, neighbor));
+                        }
+                    }
+                }
+            }
+        }
+
+        let (reduced, meeting) = best?;
+        let cost = (reduced + potential(source) - potential(target)) / 2;
+        let mut path = Self::trace(&parents[0], meeting, true);
+        path.extend(Self::trace(&parents[1], meeting, false).into_iter().skip(1));
+
+        Some((cost as usize, path))
+    }
+
+    /// Find a shortest path with a front-to-front bidirectional search.
+    /// Both searches settle nodes by their cost from their end and skip a node whose cost plus the
+    /// estimate through the frontier of the opposite search cannot improve the best path met so
+    /// far. The estimate scans the frontier for each settled node unless the heuristic overrides it.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Cost and nodes of the path, or `None` if the target is unreachable.
+    pub fn front_to_front_path(&self, source: usize, target: usize) -> Option<(usize, Vec<usize>)> {
+        if source >= self.total_nodes || target >= self.total_nodes {
+            return None;
+        }
+
+        let graphs = [&self.adjacency, &self.reverse];
+        let mut distances: [HashMap<usize, usize>; 2] =
+            [HashMap::from([(source, 0)]), HashMap::from([(target, 0)])];
+        let mut parents: [HashMap<usize, usize>; 2] = [HashMap::new(), HashMap::new()];
+        let mut settled: [HashSet<usize>; 2] = [HashSet::new(), HashSet::new()];
+        let mut queues: [BinaryHeap<Reverse<(usize, usize)>>; 2] = [
+            BinaryHeap::from([Reverse((0, source))]),
+            BinaryHeap::from([Reverse((0, target))]),
+        ];
+        let mut best: Option<(usize, usize)> = (source == target).then_some((0, source));
+
+        loop {
+            let fronts = queues
+                .each_ref()
+                .map(|queue| queue.peek().map(|&Reverse((cost, _))| cost));
+
+            let side = match fronts {
+                [Some(forward), Some(backward)] => {
+                    if best.is_some_and(|(cost, _)| forward.saturating_add(backward) >= cost) {
+                        break;
+                    }
+
+                    usize::from(backward < forward)
+                }
+                _ => break,
+            };
+
+            let Some(Reverse((cost, node))) = queues[side].pop() else {
+                break;
+            };
+
+            if !settled[side].insert(node) {
+                continue;
+            }
+
+            if let Some((known, _)) = best {
+                // A node settled by the opposite search already met it at its exact cost.
+                let estimate = if settled[1 - side].contains(&node) {
+                    usize::MAX
+                } else {
+                    let front: Vec<(usize, usize)> = distances[1 - side]
+                        .iter()
+                        .filter(|(other, _)| !settled[1 - side].contains(other))
+                        .map(|(&other, &distance)| (other, distance))
+                        .collect();
+
+                    if side == 0 {
+                        self.heuristic.estimate_to_front(node, &front)
+                    } else {
+                        self.heuristic.estimate_from_front(&front, node)
+                    }
+                };
+
+                if cost.saturating_add(estimate) >= known {
+                    continue;
+                }
+            }
+
+            for &(neighbor, weight) in &graphs[side][node] {
+                let next = cost.saturating_add(weight);
+
+                if distances[side]
+                    .get(&neighbor)
+                    .map_or(true, |&known| next < known)
+                {
+                    distances[side].insert(neighbor, next);
+                    parents[side].insert(neighbor, node);
+                    queues[side].push(Reverse((next, neighbor)));
+
+                    if let Some(&other) = distances[1 - side].get(&neighbor) {
+                        let total = next.saturating_add(other);
+
+                        if best.map_or(true, |(known, _)| total < known) {
+                            best = Some((total, neighbor));
+                        }
+                    }
+                }
+            }
+        }
+
+        let (cost, meeting) = best?;
+        let mut path = Self::trace(&parents[0], meeting, true);
+        path.extend(Self::trace(&parents[1], meeting, false).into_iter().skip(1));
+
+        Some((cost, path))
+    }
+
+    /// Follow the parents of a search tree from a node back to its root.
+    ///
+    /// # Arguments
+    ///
+    /// - `parents`: Parent of each reached node.
+    /// - `node`: Node.
+    /// - `from_root`: Whether to return the path from the root instead of towards it.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of the path.
+    fn trace(parents: &HashMap<usize, usize>, node: usize, from_root: bool) -> Vec<usize> {
+        let mut path = vec![node];
+
+        while let Some(&parent) = parents.get(&path[path.len() - 1]) {
+            path.push(parent);
+        }
+
+        if from_root {
+            path.reverse();
+        }
+
+        path
+    }
+}
+
+impl<H: Heuristic> GraphAlgorithm for AStarAlgorithm<H> {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = (usize, Vec<usize>);
+
+    /// Run A* Algorithm from a start node to the target node, searching from both ends.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the cost and nodes of a shortest path, or an error if the start or the
+    /// target is missing or the target is unreachable.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let start = start
+            .filter(|&start| start < self.total_nodes)
+            .ok_or(GraphError::MissingStartNode)?;
+        let target = self.target.ok_or(GraphError::MissingTargetNode)?;
+
+        self.bidirectional_path(start, target)
+            .ok_or(GraphError::MissingTargetNode)
+    }
+}
+
+impl<H: Heuristic> MemoryEstimation for AStarAlgorithm<H> {
+    /// Estimate the memory of the A* Algorithm, without the heuristic: both searches keep a
+    /// distance and a parent per node and a queue entry per edge.
+    ///
+    /// # Returns
+    ///
+    /// Memory estimate of a run.
+    fn estimate_memory(&self) -> MemoryEstimate {
+        let n = self.total_nodes;
+        let edges: usize = self.adjacency.iter().map(Vec::len).sum();
+        let search = MemoryEstimate::bytes::<(usize, usize)>(n.saturating_mul(2)).saturating_add(
+            MemoryEstimate::bytes::<(usize, usize)>(edges.saturating_add(1)),
+        );
+
+        MemoryEstimate::new(
+            MemoryEstimate::bytes::<Vec<(usize, usize)>>(n.saturating_mul(2)).saturating_add(
+                MemoryEstimate::bytes::<(usize, usize)>(edges.saturating_mul(2)),
+            ),
+            search.saturating_mul(2),
+            MemoryEstimate::bytes::<usize>(n),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DijkstraAlgorithm;
+
+    /// Grid of `size × size` nodes 100 units apart, with pseudo-random weights of at least 100.
+    fn grid(size: usize) -> AStarAlgorithm<EuclideanHeuristic> {
+        let positions = (0..size * size)
+            .map(|node| ((node % size) as f64 * 100.0, (node / size) as f64 * 100.0))
+            .collect();
+        let mut algorithm = AStarAlgorithm::with_heuristic(EuclideanHeuristic {
+            positions,
+            cost_per_unit: 1.0,
+        });
+
+        for node in 0..size * size {
+            let weight = |salt: usize| 100 + (node * 37 + salt * 11) % 90;
+
+            if node % size + 1 < size {
+                algorithm.set_edge(node, node + 1, weight(1));
+                algorithm.set_edge(node + 1, node, weight(2));
+            }
+
+            if node + size < size * size {
+                algorithm.set_edge(node, node + size, weight(3));
+                algorithm.set_edge(node + size, node, weight(4));
+            }
+        }
+
+        algorithm
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = AStarAlgorithm::new();
+        let algorithm_default = AStarAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.target, None);
+        assert_eq!(algorithm.run(Some(0)), Err(GraphError::MissingStartNode));
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let mut algorithm = AStarAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 4), (2, 1)]),
+            (1, vec![(3, 1)]),
+            (2, vec![(1, 2), (3, 5)]),
+        ]);
+        algorithm.set_total_nodes(5);
+
+        assert_eq!(algorithm.shortest_path(0, 3), Some((4, vec![0, 2, 1, 3])));
+        assert_eq!(
+            algorithm.bidirectional_path(0, 3),
+            Some((4, vec![0, 2, 1, 3]))
+        );
+        assert_eq!(algorithm.bidirectional_path(2, 2), Some((0, vec![2])));
+        assert_eq!(
+            algorithm.front_to_front_path(0, 3),
+            Some((4, vec![0, 2, 1, 3]))
+        );
+        assert_eq!(algorithm.front_to_front_path(2, 2), Some((0, vec![2])));
+        assert_eq!(algorithm.shortest_path(3, 0), None);
+        assert_eq!(algorithm.bidirectional_path(0, 4), None);
+        assert_eq!(algorithm.front_to_front_path(3, 0), None);
+    }
+
+    #[test]
+    fn test_matches_dijkstra() {
+        let algorithm = grid(12);
+        let mut dijkstra = DijkstraAlgorithm::new();
+        dijkstra.set_nodes(
+            algorithm
+                .adjacency
+                .clone()
+                .into_iter()
+                .enumerate()
+                .collect(),
+        );
+
+        for (source, target) in [(0, 143), (143, 0), (5, 130), (77, 12), (40, 40)] {
+            let distances = dijkstra.run(Some(source)).unwrap();
+
+            for (cost, path) in [
+                algorithm.shortest_path(source, target).unwrap(),
+                algorithm.bidirectional_path(source, target).unwrap(),
+                algorithm.front_to_front_path(source, target).unwrap(),
+            ] {
+                let length: usize = path
+                    .windows(2)
+                    .map(|edge| {
+                        algorithm.adjacency[edge[0]]
+                            .iter()
+                            .find(|&&(next, _)| next == edge[1])
+                            .unwrap()
+                            .1
+                    })
+                    .sum();
+
+                assert_eq!(cost, distances[target]);
+                assert_eq!(length, cost);
+                assert_eq!((path[0], path[path.len() - 1]), (source, target));
+            }
+        }
+    }
+
+    #[test]
+    fn test_front_estimates() {
+        let heuristic = EuclideanHeuristic {
+            positions: vec![(0.0, 0.0), (300.0, 400.0), (600.0, 0.0)],
+            cost_per_unit: 1.0,
+        };
+
+        assert_eq!(heuristic.estimate_to_front(0, &[(1, 10), (2, 5)]), 510);
+        assert_eq!(heuristic.estimate_from_front(&[(0, 700), (1, 0)], 2), 500);
+        assert_eq!(heuristic.estimate_to_front(0, &[]), usize::MAX);
+        assert_eq!(ZeroHeuristic.estimate_from_front(&[(0, 3), (1, 2)], 2), 2);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = grid(4);
+
+        assert_eq!(algorithm.run(Some(0)), Err(GraphError::MissingTargetNode));
+
+        algorithm.set_target(15);
+        let (cost, path) = algorithm.run(Some(0)).unwrap();
+
+        assert_eq!(
+            Some(cost),
+            algorithm.shortest_path(0, 15).map(|(cost, _)| cost)
+        );
+        assert_eq!((path[0], path[path.len() - 1]), (0, 15));
+    }
+
+    #[test]
+    fn test_estimate_memory() {
+        let mut algorithm = AStarAlgorithm::new();
+        algorithm.set_edge(0, 1, 2);
+        algorithm.set_edge(1, 2, 3);
+
+        let estimate = algorithm.estimate_memory();
+
+        assert_eq!(estimate.input, 6 * 24 + 4 * 16);
+        assert_eq!(estimate.working, 2 * (6 * 16 + 3 * 16));
+    }
+}
@@ -1,8 +1,11 @@
-use crate::{GraphAlgorithm, GraphError};
+use crate::{
+    AlgorithmEvent, DiGraph, EventSink, GraphAlgorithm, GraphError, MemoryEstimate,
+    MemoryEstimation, ObservableAlgorithm, ShortestPaths, Weight,
+};
 
 /// Edge in the graph.
 #[derive(Debug, Clone)]
-pub struct Edge {
+pub struct Edge<W = i32> {
     /// Source node.
     pub source: usize,
 
@@ -10,18 +13,18 @@ pub struct Edge {
     pub destination: usize,
 
     /// Weight of the edge.
-    pub weight: i32,
+    pub weight: W,
 }
 
 /// Bellman-Ford Algorithm.
 /// Compute shortest paths from a single source vertex to all of the other vertices in a weighted digraph.
 #[derive(Debug, Clone)]
-pub struct BellmanFordAlgorithm {
+pub struct BellmanFordAlgorithm<W = i32> {
     /// Total number of vertices in the graph.
     pub total_vertices: usize,
 
     /// Edges in the graph.
-    pub edges: Vec<Edge>,
+    pub edges: Vec<Edge<W>>,
 }
 
 impl Default for BellmanFordAlgorithm {
@@ -42,6 +45,18 @@ impl BellmanFordAlgorithm {
     ///
     /// New instance of Bellman-Ford Algorithm.
     pub fn new() -> Self {
+        Self::with_weight_type()
+    }
+}
+
+impl<W: Weight> BellmanFordAlgorithm<W> {
+    /// Create a new instance of Bellman-Ford Algorithm with weights of another type than `i32`,
+    /// such as `BellmanFordAlgorithm::<f64>::with_weight_type()`.
+    ///
+    /// # Returns
+    ///
+    /// New instance of Bellman-Ford Algorithm.
+    pub fn with_weight_type() -> Self {
         BellmanFordAlgorithm {
             total_vertices: 0,
             edges: Vec::new(),
@@ -54,7 +69,18 @@ impl BellmanFordAlgorithm {
     ///
     /// - `source`: Source node.
     /// - `edges`: Edges of the source node.
-    pub fn set_edge(&mut self, source: usize, edges: Vec<(usize, i32)>) {
+    #[deprecated(note = "use `set_node` for a node's edges or `add_edge` for a single edge")]
+    pub fn set_edge(&mut self, source: usize, edges: Vec<(usize, W)>) {
+        self.set_node(source, edges);
+    }
+
+    /// Set a node's edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `edges`: Edges of the source node.
+    pub fn set_node(&mut self, source: usize, edges: Vec<(usize, W)>) {
         if edges.is_empty() {
             self.total_vertices = self.total_vertices.max(source + 1);
             return;
@@ -76,21 +102,106 @@ impl BellmanFordAlgorithm {
     /// # Arguments
     ///
     /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
-    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, i32)>)>) {
+    #[deprecated(note = "use `set_nodes`")]
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, W)>)>) {
+        self.set_nodes(nodes);
+    }
+
+    /// Set the nodes of the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of nodes and their edges.
+    pub fn set_nodes(&mut self, nodes: Vec<(usize, Vec<(usize, W)>)>) {
         for (source, edges) in nodes {
-            self.set_edge(source, edges);
+            self.set_node(source, edges);
         }
     }
-}
 
-impl GraphAlgorithm for BellmanFordAlgorithm {
-    /// Type of node.
-    type Node = isize;
+    /// Add a single directed edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn add_edge(&mut self, source: usize, target: usize, weight: W) {
+        self.set_node(source, vec![(target, weight)]);
+    }
 
-    /// Type of weight.
-    type Weight = Vec<i32>;
+    /// Add directed edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `edges`: Edges as `(source, target, weight)`.
+    pub fn add_weighted_edges(&mut self, edges: impl IntoIterator<Item = (usize, usize, W)>) {
+        for (source, target, weight) in edges {
+            self.add_edge(source, target, weight);
+        }
+    }
 
-    /// Run Bellman-Ford Algorithm.
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_vertices = self.total_vertices.max(total);
+    }
+
+    /// Find a negative weight cycle anywhere in the graph, as a witness for `NegativeWeightCycle`.
+    /// Every node starts at distance zero, so cycles are found whether or not a start reaches them.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of a negative weight cycle starting at its smallest node, or `None` if there is none.
+    pub fn negative_cycle(&self) -> Option<Vec<usize>> {
+        let n = self.total_vertices;
+        let mut distances = vec![W::ZERO; n];
+        let mut predecessors = vec![usize::MAX; n];
+        let mut updated = None;
+
+        for _ in 0..n {
+            updated = None;
+
+            for edge in &self.edges {
+                let new_distance = distances[edge.source].saturating_add(edge.weight);
+
+                if new_distance < distances[edge.destination] {
+                    distances[edge.destination] = new_distance;
+                    predecessors[edge.destination] = edge.source;
+                    updated = Some(edge.destination);
+                }
+            }
+
+            updated?;
+        }
+
+        // A node updated in the last round leads back into the cycle after `n` predecessors.
+        let mut node = updated?;
+
+        for _ in 0..n {
+            node = predecessors[node];
+        }
+
+        let mut cycle = vec![node];
+        let mut current = predecessors[node];
+
+        while current != node {
+            cycle.push(current);
+            current = predecessors[current];
+        }
+
+        cycle.reverse();
+
+        let smallest = (0..cycle.len()).min_by_key(|&index| cycle[index])?;
+        cycle.rotate_left(smallest);
+
+        Some(cycle)
+    }
+
+    /// Run Bellman-Ford Algorithm and keep the predecessor of each node, so that the shortest
+    /// routes can be recovered and not only their lengths.
     ///
     /// # Arguments
     ///
@@ -98,43 +209,240 @@ impl GraphAlgorithm for BellmanFordAlgorithm {
     ///
     /// # Returns
     ///
-    /// Result containing a vector of shortest paths, or an error if applicable.
-    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
-        let start = start.ok_or(GraphError::MissingStartNode)?;
+    /// Result containing the distances and predecessors, or an error if applicable.
+    pub fn run_with_predecessors(
+        &self,
+        start: Option<isize>,
+    ) -> Result<ShortestPaths<W>, GraphError> {
+        let (start, distances, predecessors) = self.relax(start, &mut ())?;
+
+        Ok(ShortestPaths {
+            start,
+            distances,
+            predecessors,
+        })
+    }
 
-        let mut distances = vec![i32::MAX; self.total_vertices];
-        distances[start as usize] = 0;
+    /// Run Bellman-Ford Algorithm on a shared graph, reading its edges in place.
+    ///
+    /// # Arguments
+    ///
+    /// - `graph`: Directed graph, or undirected graph through its directed view.
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the distances and predecessors, or an error if applicable.
+    pub fn run_on(graph: &DiGraph<W>, start: usize) -> Result<ShortestPaths<W>, GraphError> {
+        let n = graph.total_nodes();
+
+        if start >= n {
+            return Err(GraphError::MissingStartNode);
+        }
 
-        for _ in 0..self.total_vertices - 1 {
-            let mut is_distance_updated = false;
+        let (distances, predecessors) = Self::relax_edges(n, start, || graph.edges(), &mut ())?;
 
-            for edge in &self.edges {
-                if distances[edge.source] != i32::MAX {
-                    let new_distance = distances[edge.source] + edge.weight;
+        Ok(ShortestPaths {
+            start,
+            distances,
+            predecessors,
+        })
+    }
+
+    /// Relax every edge until the distances stop improving, emitting the relaxed edges, updated
+    /// distances and finished passes.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `sink`: Receiver of the events.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the start, the distances and the predecessors, or an error if applicable.
+    fn relax<S: EventSink + ?Sized>(
+        &self,
+        start: Option<isize>,
+        sink: &mut S,
+    ) -> Result<(usize, Vec<W>, Vec<usize>), GraphError> {
+        let start = start.ok_or(GraphError::MissingStartNode)?;
+        let start = usize::try_from(start)
+            .ok()
+            .filter(|&start| start < self.total_vertices)
+            .ok_or(GraphError::MissingStartNode)?;
+        let edges = || {
+            self.edges
+                .iter()
+                .map(|edge| (edge.source, edge.destination, edge.weight))
+        };
+        let (distances, predecessors) = Self::relax_edges(self.total_vertices, start, edges, sink)?;
+
+        Ok((start, distances, predecessors))
+    }
 
-                    if new_distance < distances[edge.destination] {
-                        distances[edge.destination] = new_distance;
-                        is_distance_updated = true;
+    /// Relax edges given by source until the distances stop improving.
+    ///
+    /// # Arguments
+    ///
+    /// - `n`: Number of nodes, above the start.
+    /// - `start`: Starting node.
+    /// - `edges`: Edges as `(source, target, weight)`, iterated once per pass.
+    /// - `sink`: Receiver of the events.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the distances and the predecessors, or an error if applicable.
+    fn relax_edges<S, I>(
+        n: usize,
+        start: usize,
+        edges: impl Fn() -> I,
+        sink: &mut S,
+    ) -> Result<(Vec<W>, Vec<usize>), GraphError>
+    where
+        S: EventSink + ?Sized,
+        I: Iterator<Item = (usize, usize, W)>,
+    {
+        let mut distances = vec![W::INFINITY; n];
+        let mut predecessors = vec![usize::MAX; n];
+        distances[start] = W::ZERO;
+
+        for iteration in 0..n - 1 {
+            let mut updates = 0;
+
+            for (source, destination, weight) in edges() {
+                if distances[source] != W::INFINITY {
+                    sink.emit(AlgorithmEvent::EdgeRelaxed {
+                        source,
+                        target: destination,
+                        weight: weight.to_i64(),
+                    });
+
+                    let new_distance = distances[source]
+                        .checked_add(weight)
+                        .ok_or(GraphError::WeightOverflow)?;
+
+                    if new_distance < distances[destination] {
+                        distances[destination] = new_distance;
+                        predecessors[destination] = source;
+                        updates += 1;
+                        sink.emit(AlgorithmEvent::DistanceUpdated {
+                            node: destination,
+                            distance: new_distance.to_i64(),
+                            predecessor: Some(source),
+                        });
                     }
                 }
             }
 
-            if !is_distance_updated {
+            sink.emit(AlgorithmEvent::IterationFinished {
+                iteration,
+                change: updates as f64,
+            });
+
+            if updates == 0 {
                 break;
             }
         }
 
-        for edge in &self.edges {
-            if distances[edge.source] != i32::MAX {
-                let new_distance = distances[edge.source] + edge.weight;
+        for (source, destination, weight) in edges() {
+            if distances[source] != W::INFINITY {
+                let new_distance = distances[source]
+                    .checked_add(weight)
+                    .ok_or(GraphError::WeightOverflow)?;
 
-                if new_distance < distances[edge.destination] {
+                if new_distance < distances[destination] {
                     return Err(GraphError::NegativeWeightCycle);
                 }
             }
         }
 
-        Ok(distances)
+        Ok((distances, predecessors))
+    }
+}
+
+impl<W: Weight> GraphAlgorithm for BellmanFordAlgorithm<W> {
+    /// Type of node.
+    type Node = isize;
+
+    /// Type of weight.
+    type Weight = Vec<W>;
+
+    /// Run Bellman-Ford Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing a vector of shortest paths, or an error if applicable.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        self.relax(start, &mut ())
+            .map(|(_, distances, _)| distances)
+    }
+
+    /// Check that every edge connects nodes of the graph and that the start is one of them.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing nothing, or an error describing the malformed input.
+    fn validate(&self, start: Option<&Self::Node>) -> Result<(), GraphError> {
+        let start = *start.ok_or(GraphError::MissingStartNode)?;
+
+        if start < 0 || start as usize >= self.total_vertices {
+            return Err(GraphError::MissingStartNode);
+        }
+
+        for edge in &self.edges {
+            for node in [edge.source, edge.destination] {
+                if node >= self.total_vertices {
+                    return Err(GraphError::NodeOutOfRange(node));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Weight> ObservableAlgorithm for BellmanFordAlgorithm<W> {
+    /// Run the Bellman-Ford Algorithm and emit every relaxed edge, updated distance and finished
+    /// pass over the edges.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `sink`: Receiver of the events.
+    ///
+    /// # Returns
+    ///
+    /// Result containing a vector of shortest paths, or an error if applicable.
+    fn run_observed(
+        &self,
+        start: Option<Self::Node>,
+        sink: &mut dyn EventSink,
+    ) -> Result<Self::Weight, GraphError> {
+        self.relax(start, sink).map(|(_, distances, _)| distances)
+    }
+}
+
+impl<W: Weight> MemoryEstimation for BellmanFordAlgorithm<W> {
+    /// Estimate the memory of the Bellman-Ford Algorithm, which keeps a distance and a predecessor
+    /// per vertex.
+    ///
+    /// # Returns
+    ///
+    /// Memory estimate of a run.
+    fn estimate_memory(&self) -> MemoryEstimate {
+        MemoryEstimate::new(
+            MemoryEstimate::bytes::<Edge<W>>(self.edges.len()),
+            MemoryEstimate::bytes::<usize>(self.total_vertices),
+            MemoryEstimate::bytes::<W>(self.total_vertices),
+        )
     }
 }
 
@@ -151,11 +459,146 @@ mod tests {
         assert_eq!(algorithm_default.edges.len(), 0);
     }
 
+    #[test]
+    fn test_negative_cycle() {
+        let mut algorithm = BellmanFordAlgorithm::new();
+        algorithm.set_nodes(vec![
+            (0, vec![(1, 1)]),
+            (1, vec![(2, 1)]),
+            (2, vec![(3, -4), (1, 2)]),
+            (3, vec![(1, 1)]),
+        ]);
+
+        assert_eq!(algorithm.negative_cycle(), Some(vec![1, 2, 3]));
+        assert_eq!(algorithm.run(Some(0)), Err(GraphError::NegativeWeightCycle));
+
+        let mut algorithm = BellmanFordAlgorithm::new();
+        algorithm.set_nodes(vec![(0, vec![(1, -1)]), (1, vec![(2, -1)])]);
+
+        assert_eq!(algorithm.negative_cycle(), None);
+    }
+
+    #[test]
+    fn test_builders() {
+        let mut algorithm = BellmanFordAlgorithm::new();
+        algorithm.add_edge(0, 1, 4);
+        algorithm.add_weighted_edges([(1, 2, -1), (0, 2, 5)]);
+        algorithm.set_total_nodes(4);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 4, 3, i32::MAX]);
+
+        #[allow(deprecated)]
+        algorithm.set_edge(2, vec![(3, 1)]);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap()[3], 4);
+
+        let mut algorithm = BellmanFordAlgorithm::new();
+        algorithm.set_nodes(vec![(0, vec![(1, 2)]), (2, vec![])]);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 2, i32::MAX]);
+
+        #[allow(deprecated)]
+        algorithm.set_edges(vec![(1, vec![(2, 3)])]);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 2, 5]);
+    }
+
+    #[test]
+    fn test_run_with_predecessors() {
+        let mut algorithm = BellmanFordAlgorithm::new();
+        algorithm.add_weighted_edges([(0, 1, 4), (0, 2, 5), (2, 1, -3), (1, 3, 2)]);
+        algorithm.set_total_nodes(5);
+
+        let paths = algorithm.run_with_predecessors(Some(0)).unwrap();
+
+        assert_eq!(paths.distances, algorithm.run(Some(0)).unwrap());
+        assert_eq!(paths.predecessors[3], 1);
+        assert_eq!(paths.path_to(3), Some(vec![0, 2, 1, 3]));
+        assert_eq!(paths.distance(3), Some(4));
+        assert_eq!(paths.path_to(0), Some(vec![0]));
+        assert_eq!(paths.path_to(4), None);
+        assert_eq!(paths.path_to(9), None);
+
+        algorithm.add_edge(3, 2, -5);
+
+        assert_eq!(
+            algorithm.run_with_predecessors(Some(0)),
+            Err(GraphError::NegativeWeightCycle)
+        );
+    }
+
+    #[test]
+    fn test_weight_types() {
+        let mut algorithm = BellmanFordAlgorithm::<f64>::with_weight_type();
+        algorithm.add_weighted_edges([(0, 1, 2.5), (1, 2, -1.25), (0, 2, 1.5)]);
+
+        assert_eq!(algorithm.run(Some(0)), Ok(vec![0.0, 2.5, 1.25]));
+
+        let mut algorithm = BellmanFordAlgorithm::<i64>::with_weight_type();
+        algorithm.add_weighted_edges([(0, 1, i64::from(i32::MAX)), (1, 2, i64::from(i32::MAX))]);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap()[2], 2 * i64::from(i32::MAX));
+    }
+
+    #[test]
+    fn test_run_on() {
+        let mut graph = DiGraph::new();
+        graph.add_weighted_edges([(0, 1, 4), (0, 2, 5), (2, 1, -3), (1, 3, 2)]);
+
+        let paths = BellmanFordAlgorithm::run_on(&graph, 0).unwrap();
+
+        assert_eq!(paths.distances, vec![0, 2, 5, 4]);
+        assert_eq!(paths.path_to(3), Some(vec![0, 2, 1, 3]));
+        assert_eq!(
+            BellmanFordAlgorithm::run_on(&graph, 4),
+            Err(GraphError::MissingStartNode)
+        );
+
+        graph.add_edge(3, 2, -5);
+
+        assert_eq!(
+            BellmanFordAlgorithm::run_on(&graph, 0),
+            Err(GraphError::NegativeWeightCycle)
+        );
+    }
+
     #[test]
     fn test_missing_start_node() {
         let algorithm = BellmanFordAlgorithm::new();
 
         assert_eq!(algorithm.run(None), Err(GraphError::MissingStartNode));
+        assert_eq!(algorithm.run(Some(0)), Err(GraphError::MissingStartNode));
+    }
+
+    #[test]
+    fn test_try_run() {
+        let mut algorithm = BellmanFordAlgorithm::new();
+        algorithm.set_nodes(vec![(0, vec![(1, 2)]), (1, vec![(2, i32::MAX)])]);
+
+        assert_eq!(
+            algorithm.try_run(Some(-1)),
+            Err(GraphError::MissingStartNode)
+        );
+        assert_eq!(
+            algorithm.try_run(Some(3)),
+            Err(GraphError::MissingStartNode)
+        );
+        assert_eq!(algorithm.try_run(Some(0)), Err(GraphError::WeightOverflow));
+        assert_eq!(
+            algorithm.try_run(Some(1)).unwrap(),
+            vec![i32::MAX, 0, i32::MAX]
+        );
+
+        algorithm.edges.push(Edge {
+            source: 2,
+            destination: 7,
+            weight: 1,
+        });
+
+        assert_eq!(
+            algorithm.try_run(Some(0)),
+            Err(GraphError::NodeOutOfRange(7))
+        );
     }
 
     #[test]
@@ -221,7 +664,7 @@ mod tests {
         ];
 
         for (source, destination, weight) in edges {
-            algorithm.set_edge(source, vec![(destination, weight)]);
+            algorithm.set_node(source, vec![(destination, weight)]);
         }
 
         let result = algorithm.run(Some(0)).unwrap();
@@ -240,7 +683,7 @@ mod tests {
     #[test]
     fn test_run_single_node_graph() {
         let mut algorithm = BellmanFordAlgorithm::new();
-        algorithm.set_edge(0, vec![]);
+        algorithm.set_node(0, vec![]);
 
         assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0]);
     }
@@ -248,9 +691,9 @@ mod tests {
     #[test]
     fn test_run_simple_graph_no_negative_edges() {
         let mut algorithm = BellmanFordAlgorithm::new();
-        algorithm.set_edge(0, vec![(1, 4), (2, 3)]);
-        algorithm.set_edge(1, vec![(2, 1), (3, 2)]);
-        algorithm.set_edge(2, vec![(3, 5)]);
+        algorithm.set_node(0, vec![(1, 4), (2, 3)]);
+        algorithm.set_node(1, vec![(2, 1), (3, 2)]);
+        algorithm.set_node(2, vec![(3, 5)]);
 
         assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 4, 3, 6]);
     }
@@ -258,9 +701,9 @@ mod tests {
     #[test]
     fn test_run_graph_with_negative_edge() {
         let mut algorithm = BellmanFordAlgorithm::new();
-        algorithm.set_edge(0, vec![(1, 4), (2, 3)]);
-        algorithm.set_edge(1, vec![(2, -2), (3, 2)]);
-        algorithm.set_edge(2, vec![(3, 3)]);
+        algorithm.set_node(0, vec![(1, 4), (2, 3)]);
+        algorithm.set_node(1, vec![(2, -2), (3, 2)]);
+        algorithm.set_node(2, vec![(3, 3)]);
 
         assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 4, 2, 5]);
     }
@@ -268,10 +711,10 @@ mod tests {
     #[test]
     fn test_run_graph_with_no_edges() {
         let mut algorithm = BellmanFordAlgorithm::new();
-        algorithm.set_edge(0, vec![]);
-        algorithm.set_edge(1, vec![]);
-        algorithm.set_edge(2, vec![]);
-        algorithm.set_edge(3, vec![]);
+        algorithm.set_node(0, vec![]);
+        algorithm.set_node(1, vec![]);
+        algorithm.set_node(2, vec![]);
+        algorithm.set_node(3, vec![]);
 
         assert_eq!(
             algorithm.run(Some(0)).unwrap(),
@@ -282,9 +725,9 @@ mod tests {
     #[test]
     fn test_run_run_from_different_start_node() {
         let mut algorithm = BellmanFordAlgorithm::new();
-        algorithm.set_edge(0, vec![(1, 4), (2, 3)]);
-        algorithm.set_edge(1, vec![(2, 1), (3, 2)]);
-        algorithm.set_edge(2, vec![(3, 5)]);
+        algorithm.set_node(0, vec![(1, 4), (2, 3)]);
+        algorithm.set_node(1, vec![(2, 1), (3, 2)]);
+        algorithm.set_node(2, vec![(3, 5)]);
 
         assert_eq!(algorithm.run(Some(1)).unwrap(), vec![i32::MAX, 0, 1, 2]);
     }
@@ -292,8 +735,8 @@ mod tests {
     #[test]
     fn test_run_disconnected_graph() {
         let mut algorithm = BellmanFordAlgorithm::new();
-        algorithm.set_edge(0, vec![(1, 4)]);
-        algorithm.set_edge(2, vec![(3, 5)]);
+        algorithm.set_node(0, vec![(1, 4)]);
+        algorithm.set_node(2, vec![(3, 5)]);
 
         assert_eq!(
             algorithm.run(Some(0)).unwrap(),
@@ -304,7 +747,7 @@ mod tests {
     #[test]
     fn test_run_graph_with_negative_weight_cycle() {
         let mut algorithm = BellmanFordAlgorithm::new();
-        algorithm.set_edges(vec![
+        algorithm.set_nodes(vec![
             (0, vec![(1, 1)]),
             (1, vec![(2, -1)]),
             (2, vec![(0, -1)]),
@@ -316,10 +759,10 @@ mod tests {
     #[test]
     fn test_run_early_exit_no_updates() {
         let mut algorithm = BellmanFordAlgorithm::new();
-        algorithm.set_edge(0, vec![(1, 2)]);
-        algorithm.set_edge(1, vec![(2, 3)]);
-        algorithm.set_edge(2, vec![(3, 4)]);
-        algorithm.set_edge(3, vec![(4, 1)]);
+        algorithm.set_node(0, vec![(1, 2)]);
+        algorithm.set_node(1, vec![(2, 3)]);
+        algorithm.set_node(2, vec![(3, 4)]);
+        algorithm.set_node(3, vec![(4, 1)]);
 
         let result = algorithm.run(Some(0)).unwrap();
 
@@ -333,10 +776,10 @@ mod tests {
     #[test]
     fn test_run_early_exit_with_no_negative_cycle() {
         let mut algorithm = BellmanFordAlgorithm::new();
-        algorithm.set_edge(0, vec![(1, 2)]);
-        algorithm.set_edge(1, vec![(2, 3)]);
-        algorithm.set_edge(2, vec![(3, -5)]);
-        algorithm.set_edge(3, vec![(4, 1)]);
+        algorithm.set_node(0, vec![(1, 2)]);
+        algorithm.set_node(1, vec![(2, 3)]);
+        algorithm.set_node(2, vec![(3, -5)]);
+        algorithm.set_node(3, vec![(4, 1)]);
 
         let result = algorithm.run(Some(0)).unwrap();
 
@@ -350,10 +793,41 @@ mod tests {
     #[test]
     fn test_run_early_exit_with_negative_cycle() {
         let mut algorithm = BellmanFordAlgorithm::new();
-        algorithm.set_edge(0, vec![(1, 1)]);
-        algorithm.set_edge(1, vec![(2, -2)]);
-        algorithm.set_edge(2, vec![(0, -1)]); // Negative cycle here
+        algorithm.set_node(0, vec![(1, 1)]);
+        algorithm.set_node(1, vec![(2, -2)]);
+        algorithm.set_node(2, vec![(0, -1)]); // Negative cycle here
 
         assert_eq!(algorithm.run(Some(0)), Err(GraphError::NegativeWeightCycle));
     }
+
+    #[test]
+    fn test_estimate_memory() {
+        let mut algorithm = BellmanFordAlgorithm::new();
+        algorithm.set_total_nodes(4);
+        algorithm.add_edge(0, 1, 2);
+
+        let estimate = algorithm.estimate_memory();
+
+        assert_eq!(estimate.input, std::mem::size_of::<Edge>());
+        assert_eq!(estimate.working, 4 * std::mem::size_of::<usize>());
+        assert_eq!(estimate.output, 16);
+    }
+
+    #[test]
+    fn test_run_observed() {
+        let mut algorithm = BellmanFordAlgorithm::new();
+        algorithm.add_weighted_edges(vec![(0, 1, 4), (0, 2, 1), (2, 1, -2)]);
+
+        let mut passes = Vec::new();
+        let distances = algorithm
+            .run_observed(Some(0), &mut |event| {
+                if let AlgorithmEvent::IterationFinished { change, .. } = event {
+                    passes.push(change);
+                }
+            })
+            .unwrap();
+
+        assert_eq!(distances, vec![0, -1, 1]);
+        assert_eq!(passes, vec![3.0, 0.0]);
+    }
 }
@@ -0,0 +1,568 @@
+use std::collections::{BinaryHeap, VecDeque};
+
+use crate::{
+    dijkstra::State, ConfiguredAlgorithm, DiGraph, GraphAlgorithm, GraphError, MemoryEstimate,
+    MemoryEstimation, Provenance,
+};
+
+/// Betweenness Centrality (Brandes' Algorithm).
+/// Score each node and edge by the fraction of shortest paths between other pairs of nodes that
+/// pass through it. Shortest paths count edges with a breadth-first search, or weights with
+/// Dijkstra's Algorithm when the graph is weighted.
+#[derive(Debug, Clone)]
+pub struct BetweennessAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Edges in the graph.
+    pub edges: Vec<(usize, usize, usize)>,
+
+    /// Whether the edges are directed.
+    pub directed: bool,
+
+    /// Whether shortest paths use the edge weights instead of the number of edges.
+    pub weighted: bool,
+
+    /// Whether to divide the scores by the number of node pairs they can cover.
+    pub normalized: bool,
+}
+
+impl Default for BetweennessAlgorithm {
+    /// Create a new default instance of Brandes' Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of Brandes' Algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BetweennessAlgorithm {
+    /// Create a new instance of Brandes' Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of Brandes' Algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            edges: Vec::new(),
+            directed: true,
+            weighted: false,
+            normalized: false,
+        }
+    }
+
+    /// Set a single edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.edges.push((source, target, weight));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+    }
+
+    /// Set whether the edges are directed.
+    ///
+    /// # Arguments
+    ///
+    /// - `directed`: Whether the edges are directed.
+    pub fn set_directed(&mut self, directed: bool) {
+        self.directed = directed;
+    }
+
+    /// Set whether shortest paths use the edge weights.
+    ///
+    /// # Arguments
+    ///
+    /// - `weighted`: Whether to use Dijkstra's Algorithm instead of a breadth-first search.
+    pub fn set_weighted(&mut self, weighted: bool) {
+        self.weighted = weighted;
+    }
+
+    /// Set whether to normalize the scores.
+    ///
+    /// # Arguments
+    ///
+    /// - `normalized`: Whether to divide by the number of node pairs.
+    pub fn set_normalized(&mut self, normalized: bool) {
+        self.normalized = normalized;
+    }
+
+    /// Compute the betweenness of every node and every edge.
+    ///
+    /// # Returns
+    ///
+    /// Score of each node, and score of each edge in the order the edges were set.
+    pub fn centrality(&self) -> (Vec<f64>, Vec<f64>) {
+        let n = self.total_nodes;
+        let mut adjacency = vec![Vec::new(); n];
+
+        for (index, &(source, target, weight)) in self.edges.iter().enumerate() {
+            let weight = if self.weighted { weight } else { 1 };
+            adjacency[source].push((target, weight, index));
+
+            if !self.directed {
+                adjacency[target].push((source, weight, index));
+            }
+        }
+
+        let mut nodes = vec![0.0; n];
+        let mut edges = vec![0.0; self.edges.len()];
+
+        for source in 0..n {
+            let (order, predecessors, paths) = match self.weighted {
+                true => Self::dijkstra(&adjacency, source),
+                false => Self::bfs(&adjacency, source),
+            };
+
+            // Accumulate the dependencies from the farthest nodes back to the source.
+            let mut dependency = vec![0.0; n];
+
+            for &node in order.iter().rev() {
+                for &(predecessor, index) in &predecessors[node] {
+                    let credit = paths[predecessor] / paths[node] * (1.0 + dependency[node]);
+                    dependency[predecessor] += credit;
+                    edges[index] += credit;
+                }
+
+                if node != source {
+                    nodes[node] += dependency[node];
+                }
+            }
+        }
+
+        // Undirected paths are found once from each end.
+        let scale = if self.directed { 1.0 } else { 0.5 };
+        let pairs = |count: usize| match self.directed {
+            true => count as f64,
+            false => count as f64 / 2.0,
+        };
+        let node_scale = match self.normalized && n > 2 {
+            true => scale / pairs((n - 1) * (n - 2)),
+            false => scale,
+        };
+        let edge_scale = match self.normalized && n > 1 {
+            true => scale / pairs(n * (n - 1)),
+            false => scale,
+        };
+
+        nodes.iter_mut().for_each(|score| *score *= node_scale);
+        edges.iter_mut().for_each(|score| *score *= edge_scale);
+
+        (nodes, edges)
+    }
+
+    /// Compute the betweenness of every edge.
+    ///
+    /// # Returns
+    ///
+    /// Score of each edge in the order the edges were set.
+    pub fn edge_betweenness(&self) -> Vec<f64> {
+        self.centrality().1
+    }
+
+    /// Find the shortest paths from a source by breadth-first search.
+    ///
+    /// # Arguments
+    ///
+    /// - `adjacency`: Neighbors of each node with the weight and index of the edge.
+    /// - `source`: Source node.
+    ///
+    /// # Returns
+    ///
+    /// Nodes in order of distance, predecessors of each node on shortest paths with the edge index,
+    /// and number of shortest paths to each node.
+    fn bfs(adjacency: &[Vec<(usize, usize, usize)>], source: usize) -> ShortestPaths {
+        let n = adjacency.len();
+        let mut distances = vec![usize::MAX; n];
+        let mut predecessors = vec![Vec::new(); n];
+        let mut paths = vec![0.0; n];
+        let mut order = Vec::with_capacity(n);
+        let mut queue = VecDeque::from([source]);
+
+        distances[source] = 0;
+        paths[source] = 1.0;
+
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+
+            for &(neighbor, _, index) in &adjacency[node] {
+                if distances[neighbor] == usize::MAX {
+                    distances[neighbor] = distances[node] + 1;
+                    queue.push_back(neighbor);
+                }
+
+                if distances[neighbor] == distances[node] + 1 {
+                    paths[neighbor] += paths[node];
+                    predecessors[neighbor].push((node, index));
+                }
+            }
+        }
+
+        (order, predecessors, paths)
+    }
+
+    /// Find the shortest paths from a source with Dijkstra's Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `adjacency`: Neighbors of each node with the weight and index of the edge.
+    /// - `source`: Source node.
+    ///
+    /// # Returns
+    ///
+    /// Nodes in order of distance, predecessors of each node on shortest paths with the edge index,
+    /// and number of shortest paths to each node.
+    fn dijkstra(adjacency: &[Vec<(usize, usize, usize)>], source: usize) -> ShortestPaths {
+        let n = adjacency.len();
+        let mut distances = vec![usize::MAX; n];
+        let mut predecessors = vec![Vec::new(); n];
+        let mut paths = vec![0.0; n];
+        let mut settled = vec![false; n];
+        let mut order = Vec::with_capacity(n);
+        let mut queue = BinaryHeap::from([State {
+            cost: 0,
+            position: source,
+        }]);
+
+        distances[source] = 0;
+        paths[source] = 1.0;
+
+        while let Some(State { cost, position }) = queue.pop() {
+            if settled[position] || cost > distances[position] {
+                continue;
+            }
+
+            settled[position] = true;
+            order.push(position);
+
+            for &(neighbor, weight, index) in &adjacency[position] {
+                let next = cost + weight;
+
+                if next < distances[neighbor] {
+                    distances[neighbor] = next;
+                    paths[neighbor] = 0.0;
+                    predecessors[neighbor].clear();
+                    queue.push(State {
+                        cost: next,
+                        position: neighbor,
+                    });
+                }
+
+                if next == distances[neighbor] && !settled[neighbor] {
+                    paths[neighbor] += paths[position];
+                    predecessors[neighbor].push((position, index));
+                }
+            }
+        }
+
+        (order, predecessors, paths)
+    }
+}
+
+/// Nodes in order of distance, predecessors with edge indices, and numbers of shortest paths.
+type ShortestPaths = (Vec<usize>, Vec<Vec<(usize, usize)>>, Vec<f64>);
+
+impl From<&DiGraph<usize>> for BetweennessAlgorithm {
+    /// Create an instance of Brandes' Algorithm from a shared graph, copying its edges and nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `graph`: Graph whose edges are read as directed edges.
+    ///
+    /// # Returns
+    ///
+    /// New instance of Brandes' Algorithm with the edges of the graph.
+    fn from(graph: &DiGraph<usize>) -> Self {
+        let mut algorithm = Self::new();
+
+        for (source, target, weight) in graph.edges() {
+            algorithm.set_edge(source, target, weight);
+        }
+
+        algorithm.set_total_nodes(graph.total_nodes());
+        algorithm
+    }
+}
+
+impl GraphAlgorithm for BetweennessAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<f64>;
+
+    /// Run Brandes' Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in Brandes' Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the betweenness of each node.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.centrality().0)
+    }
+}
+
+impl ConfiguredAlgorithm for BetweennessAlgorithm {
+    /// Describe the configuration of the betweenness centrality.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("betweenness")
+            .with("directed", self.directed)
+            .with("weighted", self.weighted)
+            .with("normalized", self.normalized)
+    }
+}
+
+impl MemoryEstimation for BetweennessAlgorithm {
+    /// Estimate the memory of Brandes' Algorithm: the adjacency with edge indices, the scores of
+    /// every edge, and the order, predecessors, path counts and dependencies of one source at a time.
+    ///
+    /// # Returns
+    ///
+    /// Memory estimate of a run.
+    fn estimate_memory(&self) -> MemoryEstimate {
+        let n = self.total_nodes;
+        let arcs = match self.directed {
+            true => self.edges.len(),
+            false => self.edges.len().saturating_mul(2),
+        };
+        let source = MemoryEstimate::bytes::<usize>(n.saturating_mul(2))
+            .saturating_add(MemoryEstimate::bytes::<f64>(n.saturating_mul(2)))
+            .saturating_add(MemoryEstimate::bytes::<Vec<(usize, usize)>>(n))
+            .saturating_add(MemoryEstimate::bytes::<(usize, usize)>(arcs))
+            .saturating_add(MemoryEstimate::bytes::<State>(arcs.saturating_add(1)));
+
+        MemoryEstimate::new(
+            MemoryEstimate::bytes::<(usize, usize, usize)>(self.edges.len()),
+            MemoryEstimate::bytes::<Vec<(usize, usize, usize)>>(n)
+                .saturating_add(MemoryEstimate::bytes::<(usize, usize, usize)>(arcs))
+                .saturating_add(MemoryEstimate::bytes::<f64>(self.edges.len()))
+                .saturating_add(source),
+            MemoryEstimate::bytes::<f64>(n),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{random::Random, Graph};
+
+    fn assert_close(left: &[f64], right: &[f64]) {
+        assert_eq!(left.len(), right.len());
+
+        for (a, b) in left.iter().zip(right) {
+            assert!((a - b).abs() < 1e-9, "{left:?} != {right:?}");
+        }
+    }
+
+    /// Betweenness from all-pairs distances and path counts.
+    fn brute_force(algorithm: &BetweennessAlgorithm) -> Vec<f64> {
+        let n = algorithm.total_nodes;
+        let mut distance = vec![vec![usize::MAX; n]; n];
+        let mut paths = vec![vec![0.0; n]; n];
+
+        for source in 0..n {
+            distance[source][source] = 0;
+            paths[source][source] = 1.0;
+        }
+
+        // Relax in rounds until the distances settle, counting paths once they are final.
+        for _ in 0..n {
+            for &(source, target, weight) in &algorithm.edges {
+                let weight = if algorithm.weighted { weight } else { 1 };
+
+                for (s, t) in [(source, target), (target, source)]
+                    .into_iter()
+                    .take(if algorithm.directed { 1 } else { 2 })
+                {
+                    for row in distance.iter_mut() {
+                        if row[s] != usize::MAX && row[s] + weight < row[t] {
+                            row[t] = row[s] + weight;
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut order: Vec<(usize, usize)> =
+            (0..n).flat_map(|s| (0..n).map(move |t| (s, t))).collect();
+        order.sort_by_key(|&(s, t)| distance[s][t]);
+
+        for &(s, t) in &order {
+            if s == t || distance[s][t] == usize::MAX {
+                continue;
+            }
+
+            for &(source, target, weight) in &algorithm.edges {
+                let weight = if algorithm.weighted { weight } else { 1 };
+
+                for (u, v) in [(source, target), (target, source)]
+                    .into_iter()
+                    .take(if algorithm.directed { 1 } else { 2 })
+                {
+                    if v == t
+                        && distance[s][u] != usize::MAX
+                        && distance[s][u] + weight == distance[s][t]
+                    {
+                        paths[s][t] += paths[s][u];
+                    }
+                }
+            }
+        }
+
+        let mut scores = vec![0.0; n];
+
+        for s in 0..n {
+            for t in 0..n {
+                for v in 0..n {
+                    if v != s
+                        && v != t
+                        && s != t
+                        && distance[s][t] != usize::MAX
+                        && distance[s][v] != usize::MAX
+                        && distance[v][t] != usize::MAX
+                        && distance[s][v] + distance[v][t] == distance[s][t]
+                    {
+                        scores[v] += paths[s][v] * paths[v][t] / paths[s][t];
+                    }
+                }
+            }
+        }
+
+        if !algorithm.directed {
+            scores.iter_mut().for_each(|score| *score /= 2.0);
+        }
+
+        scores
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = BetweennessAlgorithm::new();
+        let algorithm_default = BetweennessAlgorithm::default();
+
+        assert!(algorithm.directed);
+        assert!(!algorithm_default.weighted);
+    }
+
+    #[test]
+    fn test_run() {
+        // Path 0 - 1 - 2 - 3.
+        let mut algorithm = BetweennessAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 1)]),
+            (1, vec![(2, 1)]),
+            (2, vec![(3, 1)]),
+        ]);
+        algorithm.set_directed(false);
+
+        assert_close(&algorithm.run(None).unwrap(), &[0.0, 2.0, 2.0, 0.0]);
+        assert_close(&algorithm.edge_betweenness(), &[3.0, 4.0, 3.0]);
+
+        algorithm.set_normalized(true);
+
+        assert_close(
+            &algorithm.run(None).unwrap(),
+            &[0.0, 2.0 / 3.0, 2.0 / 3.0, 0.0],
+        );
+        assert_close(&algorithm.edge_betweenness(), &[0.5, 4.0 / 6.0, 0.5]);
+    }
+
+    #[test]
+    fn test_run_weighted() {
+        // The direct edge 0 -> 2 is longer than the path through 1 only when weighted.
+        let mut algorithm = BetweennessAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 1), (2, 5)]), (1, vec![(2, 1)])]);
+
+        assert_close(&algorithm.run(None).unwrap(), &[0.0, 0.0, 0.0]);
+
+        algorithm.set_weighted(true);
+
+        assert_close(&algorithm.run(None).unwrap(), &[0.0, 1.0, 0.0]);
+        assert_close(&algorithm.edge_betweenness(), &[2.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn test_run_random() {
+        let mut random = Random::new(3);
+
+        for case in 0..40 {
+            let mut algorithm = BetweennessAlgorithm::new();
+            algorithm.set_total_nodes(7);
+            algorithm.set_directed(case % 2 == 0);
+            algorithm.set_weighted(case % 4 < 2);
+
+            for _ in 0..12 {
+                let (source, target) = (random.below(7), random.below(7));
+
+                if source != target {
+                    algorithm.set_edge(source, target, 1 + random.below(3));
+                }
+            }
+
+            assert_close(&algorithm.run(None).unwrap(), &brute_force(&algorithm));
+        }
+    }
+
+    #[test]
+    fn test_estimate_memory() {
+        let mut algorithm = BetweennessAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 1)]), (1, vec![(2, 1)])]);
+
+        let directed = algorithm.estimate_memory();
+        algorithm.set_directed(false);
+
+        assert!(algorithm.estimate_memory().working > directed.working);
+        assert_eq!(directed.output, 24);
+    }
+
+    #[test]
+    fn test_from_graph() {
+        // Path 0 - 1 - 2 - 3 stored in both directions, so each ordered pair counts once.
+        let mut graph = Graph::new();
+        graph.add_weighted_edges([(0, 1, 1), (1, 2, 1), (2, 3, 1)]);
+
+        let algorithm = BetweennessAlgorithm::from(&*graph);
+
+        assert!(algorithm.directed);
+        assert_eq!(algorithm.edges.len(), 6);
+        assert_close(&algorithm.run(None).unwrap(), &[0.0, 4.0, 4.0, 0.0]);
+    }
+}
@@ -0,0 +1,265 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use crate::{GraphAlgorithm, GraphError};
+
+/// Bipartiteness Test.
+/// Determine whether an undirected graph is bipartite and compute a 2-coloring, or an odd cycle witnessing failure.
+#[derive(Debug, Clone)]
+pub struct BipartiteAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Neighbors of each node.
+    pub adjacency: Vec<BTreeSet<usize>>,
+}
+
+/// Breadth-first search forest of the 2-coloring.
+struct Search {
+    /// Color of each node, its depth parity.
+    colors: Vec<usize>,
+
+    /// Parent of each node in the search forest.
+    parents: Vec<Option<usize>>,
+
+    /// Depth of each node in the search forest.
+    depths: Vec<usize>,
+
+    /// First edge found between two nodes of the same color.
+    conflict: Option<(usize, usize)>,
+}
+
+impl Default for BipartiteAlgorithm {
+    /// Create a new default instance of the bipartiteness test.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the bipartiteness test.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BipartiteAlgorithm {
+    /// Create a new instance of the bipartiteness test.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the bipartiteness test.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].insert(target);
+        self.adjacency[target].insert(source);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its neighbors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, BTreeSet::new());
+    }
+
+    /// Color the nodes by the parity of their breadth-first search depth, stopping at the first conflict.
+    ///
+    /// # Returns
+    ///
+    /// Search forest with the colors and the conflicting edge, if any.
+    fn search(&self) -> Search {
+        let mut search = Search {
+            colors: vec![usize::MAX; self.total_nodes],
+            parents: vec![None; self.total_nodes],
+            depths: vec![0; self.total_nodes],
+            conflict: None,
+        };
+
+        for root in 0..self.total_nodes {
+            if search.colors[root] != usize::MAX {
+                continue;
+            }
+
+            search.colors[root] = 0;
+            let mut queue = VecDeque::from([root]);
+
+            while let Some(node) = queue.pop_front() {
+                for &neighbor in &self.adjacency[node] {
+                    if search.colors[neighbor] == usize::MAX {
+                        search.colors[neighbor] = 1 - search.colors[node];
+                        search.parents[neighbor] = Some(node);
+                        search.depths[neighbor] = search.depths[node] + 1;
+                        queue.push_back(neighbor);
+                    } else if search.colors[neighbor] == search.colors[node] {
+                        search.conflict = Some((node, neighbor));
+                        return search;
+                    }
+                }
+            }
+        }
+
+        search
+    }
+
+    /// Determine whether the graph is bipartite.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the graph has no odd cycle.
+    pub fn is_bipartite(&self) -> bool {
+        self.search().conflict.is_none()
+    }
+
+    /// Find an odd cycle witnessing that the graph is not bipartite.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of an odd cycle in order, or `None` if the graph is bipartite.
+    pub fn odd_cycle(&self) -> Option<Vec<usize>> {
+        let search = self.search();
+        let (mut left, mut right) = search.conflict?;
+
+        // Both endpoints have the same depth parity, so the tree paths to their
+        // lowest common ancestor together with the conflicting edge form an odd cycle.
+        let mut left_path = vec![left];
+        let mut right_path = vec![right];
+
+        while left != right {
+            if search.depths[left] >= search.depths[right] {
+                left = search.parents[left]?;
+                left_path.push(left);
+            } else {
+                right = search.parents[right]?;
+                right_path.push(right);
+            }
+        }
+
+        right_path.pop();
+        left_path.extend(right_path.into_iter().rev());
+
+        Some(left_path)
+    }
+}
+
+impl GraphAlgorithm for BipartiteAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<usize>;
+
+    /// Run the bipartiteness test.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the bipartiteness test.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the color (0 or 1) of each node, or an error if the graph is not bipartite.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let search = self.search();
+
+        match search.conflict {
+            Some(_) => Err(GraphError::NotBipartite),
+            None => Ok(search.colors),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_odd_cycle(algorithm: &BipartiteAlgorithm, cycle: &[usize]) {
+        assert_eq!(cycle.len() % 2, 1);
+
+        for (index, &node) in cycle.iter().enumerate() {
+            let next = cycle[(index + 1) % cycle.len()];
+            assert!(algorithm.adjacency[node].contains(&next));
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = BipartiteAlgorithm::new();
+        let algorithm_default = BipartiteAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.total_nodes, 0);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = BipartiteAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 3]),
+            (1, vec![2]),
+            (2, vec![3]),
+            (4, vec![5]),
+        ]);
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![0, 1, 0, 1, 0, 1]);
+        assert!(algorithm.is_bipartite());
+        assert_eq!(algorithm.odd_cycle(), None);
+    }
+
+    #[test]
+    fn test_run_not_bipartite() {
+        let mut algorithm = BipartiteAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1]),
+            (1, vec![2]),
+            (2, vec![3]),
+            (3, vec![4]),
+            (4, vec![0]),
+            (5, vec![0]),
+        ]);
+
+        assert_eq!(algorithm.run(None), Err(GraphError::NotBipartite));
+        assert_odd_cycle(&algorithm, &algorithm.odd_cycle().unwrap());
+    }
+
+    #[test]
+    fn test_odd_cycle_self_loop() {
+        let mut algorithm = BipartiteAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1]), (1, vec![1])]);
+
+        assert_eq!(algorithm.odd_cycle(), Some(vec![1]));
+    }
+
+    #[test]
+    fn test_odd_cycle_triangle() {
+        let mut algorithm = BipartiteAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1, 2]), (1, vec![2])]);
+
+        assert_eq!(algorithm.odd_cycle(), Some(vec![1, 0, 2]));
+    }
+}
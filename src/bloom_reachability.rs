@@ -0,0 +1,345 @@
+use crate::{random::Random, GraphAlgorithm, GraphError, TopologicalSortAlgorithm};
+
+/// Bloom Reachability Index.
+/// Label every node of a directed acyclic graph with a Bloom filter of the nodes it can reach,
+/// so that reachability queries take a few word operations. A negative answer is always exact;
+/// a positive answer is wrong with a small probability, which grows with the number of descendants
+/// per filter bit.
+#[derive(Debug, Clone)]
+pub struct BloomReachabilityIndex {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Successors of each node.
+    pub adjacency: Vec<Vec<usize>>,
+
+    /// Size of each filter in 64-bit words.
+    pub filter_words: usize,
+
+    /// Number of bits set per node in a filter.
+    pub hashes: usize,
+
+    /// Filter of the nodes reachable from each node, empty until the index is built.
+    filters: Vec<Vec<u64>>,
+
+    /// Position of each node in topological order.
+    positions: Vec<usize>,
+}
+
+impl Default for BloomReachabilityIndex {
+    /// Create a new default instance of the Bloom reachability index.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the Bloom reachability index.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BloomReachabilityIndex {
+    /// Create a new instance of the Bloom reachability index.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the Bloom reachability index.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+            filter_words: 4,
+            hashes: 2,
+            filters: Vec::new(),
+            positions: Vec::new(),
+        }
+    }
+
+    /// Set a single directed edge to the graph. The index must be built again afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].push(target);
+        self.filters.clear();
+    }
+
+    /// Set multiple nodes' edges to the graph. The index must be built again afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its successors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph. The index must be built again afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, Vec::new());
+        self.filters.clear();
+    }
+
+    /// Set the size of the filters. Larger filters lower the false positive rate.
+    ///
+    /// # Arguments
+    ///
+    /// - `filter_words`: Size of each filter in 64-bit words.
+    /// - `hashes`: Number of bits set per node.
+    pub fn set_filter_size(&mut self, filter_words: usize, hashes: usize) {
+        self.filter_words = filter_words.max(1);
+        self.hashes = hashes.max(1);
+        self.filters.clear();
+    }
+
+    /// Build the filters in reverse topological order.
+    ///
+    /// # Returns
+    ///
+    /// Result of the build, or an error if the graph has a cycle.
+    pub fn build(&mut self) -> Result<(), GraphError> {
+        let mut sort = TopologicalSortAlgorithm::new();
+        sort.set_total_nodes(self.total_nodes);
+        sort.adjacency = self.adjacency.clone();
+
+        let order = sort.run(None)?;
+        let mut filters = vec![vec![0; self.filter_words]; self.total_nodes];
+        self.positions = vec![0; self.total_nodes];
+
+        for (position, &node) in order.iter().enumerate().rev() {
+            self.positions[node] = position;
+
+            let mut filter = self.signature(node);
+
+            for &target in &self.adjacency[node] {
+                filter
+                    .iter_mut()
+                    .zip(&filters[target])
+                    .for_each(|(word, bits)| *word |= bits);
+            }
+
+            filters[node] = filter;
+        }
+
+        self.filters = filters;
+
+        Ok(())
+    }
+
+    /// Compute the bits of a node in a filter.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node.
+    ///
+    /// # Returns
+    ///
+    /// Filter containing only the node.
+    fn signature(&self, node: usize) -> Vec<u64> {
+        let mut random = Random::new(node as u64);
+        let mut signature = vec![0; self.filter_words];
+
+        for _ in 0..self.hashes {
+            let bit = random.below(64 * self.filter_words);
+            signature[bit / 64] |= 1 << (bit % 64);
+        }
+
+        signature
+    }
+
+    /// Check whether a path may lead from one node to another. Never misses a reachable node,
+    /// but may report an unreachable one.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Whether the target is probably reachable, or `None` if the index is not built or a node is unknown.
+    pub fn can_reach(&self, source: usize, target: usize) -> Option<bool> {
+        if source.max(target) >= self.filters.len() {
+            return None;
+        }
+
+        if source == target {
+            return Some(true);
+        }
+
+        Some(self.may_reach(source, target))
+    }
+
+    /// Check whether a path leads from one node to another, using the filters to prune
+    /// a depth-first search.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Whether the target is reachable, or `None` if the index is not built or a node is unknown.
+    pub fn can_reach_exact(&self, source: usize, target: usize) -> Option<bool> {
+        if !self.can_reach(source, target)? {
+            return Some(false);
+        }
+
+        let mut visited = vec![false; self.total_nodes];
+        let mut stack = vec![source];
+        visited[source] = true;
+
+        while let Some(node) = stack.pop() {
+            if node == target {
+                return Some(true);
+            }
+
+            for &next in &self.adjacency[node] {
+                if !visited[next] && (next == target || self.may_reach(next, target)) {
+                    visited[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+
+        Some(false)
+    }
+
+    /// Estimate the probability that a positive answer from a node is wrong.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    ///
+    /// # Returns
+    ///
+    /// Fraction of set filter bits raised to the number of hashes, or `None` if the index is not built
+    /// or the node is unknown.
+    pub fn false_positive_rate(&self, source: usize) -> Option<f64> {
+        let filter = self.filters.get(source)?;
+        let set: u32 = filter.iter().map(|word| word.count_ones()).sum();
+
+        Some((set as f64 / (64 * self.filter_words) as f64).powi(self.hashes as i32))
+    }
+
+    /// Check the topological positions and the filters.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node, different from the source.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the target is certainly unreachable.
+    fn may_reach(&self, source: usize, target: usize) -> bool {
+        self.positions[source] < self.positions[target]
+            && self
+                .signature(target)
+                .iter()
+                .zip(&self.filters[source])
+                .all(|(bits, word)| bits & word == *bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let index = BloomReachabilityIndex::new();
+        let index_default = BloomReachabilityIndex::default();
+
+        assert_eq!(index.filter_words, 4);
+        assert_eq!(index_default.hashes, 2);
+        assert_eq!(index.can_reach(0, 0), None);
+    }
+
+    #[test]
+    fn test_can_reach() {
+        let mut index = BloomReachabilityIndex::new();
+        index.set_edges(vec![
+            (0, vec![1, 2]),
+            (1, vec![3]),
+            (2, vec![3]),
+            (4, vec![2]),
+        ]);
+
+        assert_eq!(index.can_reach(0, 3), None);
+
+        index.build().unwrap();
+
+        assert_eq!(index.can_reach(0, 3), Some(true));
+        assert_eq!(index.can_reach(3, 0), Some(false));
+        assert_eq!(index.can_reach(2, 2), Some(true));
+        assert_eq!(index.can_reach_exact(4, 3), Some(true));
+        assert_eq!(index.can_reach_exact(4, 1), Some(false));
+        assert_eq!(index.can_reach_exact(1, 9), None);
+        assert!(index.false_positive_rate(0).unwrap() < 0.01);
+    }
+
+    #[test]
+    fn test_can_reach_random() {
+        // Compare with a transitive closure on a random DAG, with small filters to force false positives.
+        let mut random = Random::new(5);
+        let mut index = BloomReachabilityIndex::new();
+        index.set_total_nodes(200);
+
+        for source in 0..200 {
+            for _ in 0..2 {
+                let target = source + 1 + random.below(20);
+
+                if target < 200 {
+                    index.set_edge(source, target);
+                }
+            }
+        }
+
+        index.set_filter_size(1, 2);
+        index.build().unwrap();
+
+        let mut closure = vec![vec![false; 200]; 200];
+
+        for source in (0..200).rev() {
+            closure[source][source] = true;
+
+            for &target in &index.adjacency[source] {
+                for node in 0..200 {
+                    closure[source][node] |= closure[target][node];
+                }
+            }
+        }
+
+        for (source, row) in closure.iter().enumerate() {
+            for (target, &reachable) in row.iter().enumerate() {
+                assert_eq!(index.can_reach_exact(source, target), Some(reachable));
+
+                if reachable {
+                    assert_eq!(index.can_reach(source, target), Some(true));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_build_cycle() {
+        let mut index = BloomReachabilityIndex::new();
+        index.set_edges(vec![(0, vec![1]), (1, vec![0])]);
+
+        assert_eq!(index.build(), Err(GraphError::CycleDetected));
+    }
+}
@@ -0,0 +1,338 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{disjoint_set::DisjointSet, DiGraph, GraphAlgorithm, GraphError, MstAlgorithm};
+
+/// Borůvka's Algorithm.
+/// Find a minimum spanning forest of an undirected weighted graph by repeatedly merging every component along its cheapest outgoing edge.
+#[derive(Debug, Clone)]
+pub struct BoruvkaAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Edges in the graph.
+    pub edges: Vec<(usize, usize, usize)>,
+}
+
+impl Default for BoruvkaAlgorithm {
+    /// Create a new default instance of Borůvka's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of Borůvka's Algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BoruvkaAlgorithm {
+    /// Create a new instance of Borůvka's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of Borůvka's Algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            edges: Vec::new(),
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.edges.push((source, target, weight));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+    }
+
+    /// Record an edge as the cheapest outgoing edge of its components if it is lighter.
+    ///
+    /// Ties are broken by the edge index so that every round adds no cycle.
+    ///
+    /// # Arguments
+    ///
+    /// - `cheapest`: Cheapest outgoing edge index of each component.
+    /// - `components`: Component of each node.
+    /// - `index`: Index of the edge.
+    fn offer(&self, cheapest: &mut [Option<usize>], components: &[usize], index: usize) {
+        let (source, target, weight) = self.edges[index];
+        let (a, b) = (components[source], components[target]);
+
+        if a == b {
+            return;
+        }
+
+        for component in [a, b] {
+            let lighter = cheapest[component]
+                .map(|best| (weight, index) < (self.edges[best].2, best))
+                .unwrap_or(true);
+
+            if lighter {
+                cheapest[component] = Some(index);
+            }
+        }
+    }
+
+    /// Find the cheapest outgoing edge of each component.
+    ///
+    /// # Arguments
+    ///
+    /// - `components`: Component of each node.
+    ///
+    /// # Returns
+    ///
+    /// Cheapest outgoing edge index of each component, if any.
+    #[cfg(not(feature = "rayon"))]
+    fn cheapest_edges(&self, components: &[usize]) -> Vec<Option<usize>> {
+        let mut cheapest = vec![None; self.total_nodes];
+
+        for index in 0..self.edges.len() {
+            self.offer(&mut cheapest, components, index);
+        }
+
+        cheapest
+    }
+
+    /// Find the cheapest outgoing edge of each component in parallel.
+    ///
+    /// # Arguments
+    ///
+    /// - `components`: Component of each node.
+    ///
+    /// # Returns
+    ///
+    /// Cheapest outgoing edge index of each component, if any.
+    #[cfg(feature = "rayon")]
+    fn cheapest_edges(&self, components: &[usize]) -> Vec<Option<usize>> {
+        (0..self.edges.len())
+            .into_par_iter()
+            .fold(
+                || vec![None; self.total_nodes],
+                |mut cheapest, index| {
+                    self.offer(&mut cheapest, components, index);
+                    cheapest
+                },
+            )
+            .reduce(
+                || vec![None; self.total_nodes],
+                |mut cheapest, other| {
+                    for index in other.into_iter().flatten() {
+                        self.offer(&mut cheapest, components, index);
+                    }
+
+                    cheapest
+                },
+            )
+    }
+}
+
+impl From<&DiGraph<usize>> for BoruvkaAlgorithm {
+    /// Create an instance of Borůvka's Algorithm from a shared graph, copying its edges and nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `graph`: Graph whose edges are read as undirected edges.
+    ///
+    /// # Returns
+    ///
+    /// New instance of Borůvka's Algorithm with the edges of the graph.
+    fn from(graph: &DiGraph<usize>) -> Self {
+        let mut algorithm = Self::new();
+
+        for (source, target, weight) in graph.edges() {
+            algorithm.set_edge(source, target, weight);
+        }
+
+        algorithm.set_total_nodes(graph.total_nodes());
+        algorithm
+    }
+}
+
+impl MstAlgorithm for BoruvkaAlgorithm {
+    /// Compute the minimum spanning forest with Borůvka's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Edges of the minimum spanning forest.
+    fn minimum_spanning_tree(&self) -> Vec<(usize, usize, usize)> {
+        let mut components = DisjointSet::new(self.total_nodes);
+        let mut tree = Vec::new();
+
+        loop {
+            let roots: Vec<usize> = (0..self.total_nodes)
+                .map(|node| components.find(node))
+                .collect();
+
+            let mut selected: Vec<usize> =
+                self.cheapest_edges(&roots).into_iter().flatten().collect();
+
+            selected.sort_unstable();
+            selected.dedup();
+
+            let mut merged = false;
+
+            for index in selected {
+                let (source, target, weight) = self.edges[index];
+
+                if components.union(source, target) {
+                    tree.push((source, target, weight));
+                    merged = true;
+                }
+            }
+
+            if !merged {
+                break;
+            }
+        }
+
+        tree
+    }
+}
+
+impl GraphAlgorithm for BoruvkaAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<(usize, usize, usize)>;
+
+    /// Run Borůvka's Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in Borůvka's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the edges of the minimum spanning forest.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.minimum_spanning_tree())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let algorithm = BoruvkaAlgorithm::new();
+        let algorithm_default = BoruvkaAlgorithm::default();
+
+        assert_eq!(algorithm.edges.len(), 0);
+        assert_eq!(algorithm_default.edges.len(), 0);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = BoruvkaAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 4), (7, 8)]),
+            (1, vec![(2, 8), (7, 11)]),
+            (2, vec![(3, 7), (5, 4), (8, 2)]),
+            (3, vec![(4, 9), (5, 14)]),
+            (4, vec![(5, 10)]),
+            (5, vec![(6, 2)]),
+            (6, vec![(7, 1), (8, 6)]),
+            (7, vec![(8, 7)]),
+        ]);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.len(), 8);
+        assert_eq!(algorithm.total_weight(), 37);
+    }
+
+    #[test]
+    fn test_run_equal_weights() {
+        let mut algorithm = BoruvkaAlgorithm::new();
+        algorithm.set_edge(0, 1, 1);
+        algorithm.set_edge(1, 2, 1);
+        algorithm.set_edge(2, 0, 1);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(algorithm.total_weight(), 2);
+    }
+
+    #[test]
+    fn test_run_disconnected_graph() {
+        let mut algorithm = BoruvkaAlgorithm::new();
+        algorithm.set_edge(0, 1, 2);
+        algorithm.set_edge(2, 3, 1);
+        algorithm.set_total_nodes(5);
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![(0, 1, 2), (2, 3, 1)]);
+    }
+
+    #[test]
+    fn test_run_empty_graph() {
+        let algorithm = BoruvkaAlgorithm::new();
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_run_self_loop() {
+        let mut algorithm = BoruvkaAlgorithm::new();
+        algorithm.set_edge(0, 0, 1);
+        algorithm.set_edge(0, 1, 3);
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![(0, 1, 3)]);
+    }
+
+    #[test]
+    fn test_from_graph() {
+        let mut graph = DiGraph::new();
+        graph.add_weighted_edges([
+            (0, 1, 4),
+            (0, 7, 8),
+            (1, 2, 8),
+            (1, 7, 11),
+            (2, 3, 7),
+            (2, 5, 4),
+            (2, 8, 2),
+            (3, 4, 9),
+            (3, 5, 14),
+            (4, 5, 10),
+            (5, 6, 2),
+            (6, 7, 1),
+            (6, 8, 6),
+            (7, 8, 7),
+        ]);
+
+        let algorithm = BoruvkaAlgorithm::from(&graph);
+
+        assert_eq!(algorithm.total_nodes, 9);
+        assert_eq!(algorithm.run(None).unwrap().len(), 8);
+        assert_eq!(algorithm.total_weight(), 37);
+    }
+}
@@ -0,0 +1,163 @@
+use std::{
+    cmp::Reverse,
+    collections::HashSet,
+    time::{Duration, Instant},
+};
+
+use crate::{DijkstraAlgorithm, GraphAlgorithm};
+
+/// All-pairs distances computed within a time budget, with the rows of the sources reached so far.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialDistanceMatrix {
+    /// Distances from each source to every node, `usize::MAX` if unreachable, or `None` if the row
+    /// was not computed within the budget.
+    pub rows: Vec<Option<Vec<usize>>>,
+
+    /// Sources whose rows were computed, in the order they were computed.
+    pub computed: Vec<usize>,
+
+    /// Sources left out by the budget, in the order they would have been computed.
+    pub pending: Vec<usize>,
+
+    /// Time spent computing the rows.
+    pub elapsed: Duration,
+}
+
+impl PartialDistanceMatrix {
+    /// Get the distance between two nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Distance, `usize::MAX` if unreachable, or `None` if the row of the source was not computed.
+    pub fn distance(&self, source: usize, target: usize) -> Option<usize> {
+        self.rows.get(source)?.as_ref()?.get(target).copied()
+    }
+
+    /// Compute the fraction of the rows that were computed.
+    ///
+    /// # Returns
+    ///
+    /// Coverage between 0 and 1, 1 for an empty graph.
+    pub fn coverage(&self) -> f64 {
+        match self.rows.len() {
+            0 => 1.0,
+            total => self.computed.len() as f64 / total as f64,
+        }
+    }
+
+    /// Determine whether every row was computed.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no source is pending.
+    pub fn is_complete(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+impl DijkstraAlgorithm {
+    /// Compute all-pairs distances on a best-effort basis within a wall-clock budget, one Dijkstra
+    /// run per source. The important sources go first in the given order, then the others by
+    /// decreasing out-degree, so that the rows most looked at are the freshest. The budget is
+    /// checked before each row, so the last row may end after it.
+    ///
+    /// # Arguments
+    ///
+    /// - `budget`: Time allowed for the computation.
+    /// - `important`: Sources to compute first, such as the nodes shown on a dashboard.
+    ///
+    /// # Returns
+    ///
+    /// Distance matrix with the rows computed within the budget and the coverage they reach.
+    pub fn budgeted_distance_matrix(
+        &self,
+        budget: Duration,
+        important: &[usize],
+    ) -> PartialDistanceMatrix {
+        let began = Instant::now();
+        let n = self.graph.len();
+        let mut seen = HashSet::new();
+        let mut order: Vec<usize> = important
+            .iter()
+            .copied()
+            .filter(|&source| source < n && self.graph.contains_key(&source))
+            .filter(|&source| seen.insert(source))
+            .collect();
+        let mut rest: Vec<usize> = (0..n)
+            .filter(|source| self.graph.contains_key(source) && !seen.contains(source))
+            .collect();
+
+        rest.sort_by_key(|&source| (Reverse(self.graph[&source].len()), source));
+        order.extend(rest);
+
+        let mut rows = vec![None; n];
+        let mut computed = Vec::new();
+        let mut pending = Vec::new();
+        let mut order = order.into_iter();
+
+        for source in order.by_ref() {
+            if began.elapsed() >= budget {
+                pending.push(source);
+                break;
+            }
+
+            rows[source] = self.run(Some(source)).ok();
+            computed.push(source);
+        }
+
+        pending.extend(order);
+
+        PartialDistanceMatrix {
+            rows,
+            computed,
+            pending,
+            elapsed: began.elapsed(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn star() -> DijkstraAlgorithm {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.add_weighted_edges(vec![
+            (0, 1, 2),
+            (1, 0, 2),
+            (1, 2, 1),
+            (1, 3, 4),
+            (2, 3, 1),
+            (3, 0, 1),
+        ]);
+
+        algorithm
+    }
+
+    #[test]
+    fn test_budgeted_distance_matrix() {
+        let matrix = star().budgeted_distance_matrix(Duration::from_secs(60), &[3, 3]);
+
+        assert!(matrix.is_complete());
+        assert_eq!(matrix.coverage(), 1.0);
+        assert_eq!(matrix.computed, vec![3, 1, 0, 2]);
+        assert_eq!(matrix.distance(1, 0), Some(2));
+        assert_eq!(matrix.distance(0, 3), Some(4));
+        assert_eq!(matrix.rows[0], star().run(Some(0)).ok());
+    }
+
+    #[test]
+    fn test_budgeted_distance_matrix_exhausted() {
+        let matrix = star().budgeted_distance_matrix(Duration::ZERO, &[2]);
+
+        assert!(!matrix.is_complete());
+        assert_eq!(matrix.coverage(), 0.0);
+        assert_eq!(matrix.pending, vec![2, 1, 0, 3]);
+        assert_eq!(matrix.distance(2, 3), None);
+    }
+}
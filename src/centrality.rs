@@ -0,0 +1,397 @@
+use crate::{ConfiguredAlgorithm, DiGraph, GraphAlgorithm, GraphError, Provenance};
+
+/// Influence measure computed by power iteration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CentralityMeasure {
+    /// Eigenvector centrality: a node is as important as the sum of the nodes pointing to it.
+    Eigenvector,
+
+    /// Katz centrality: every node gets a base score plus the attenuated scores of the nodes pointing to it.
+    Katz {
+        /// Attenuation factor, below the inverse of the largest eigenvalue for convergence.
+        attenuation: f64,
+
+        /// Base score of every node.
+        base: f64,
+    },
+}
+
+/// Scores of a centrality measure with convergence diagnostics.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CentralityScores {
+    /// Score of each node, scaled to unit Euclidean norm.
+    pub scores: Vec<f64>,
+
+    /// Number of iterations run.
+    pub iterations: usize,
+
+    /// Total change of the scores in the last iteration.
+    pub residual: f64,
+
+    /// Whether the residual reached the tolerance.
+    pub converged: bool,
+}
+
+/// Eigenvector and Katz Centrality.
+/// Score the nodes of a weighted graph by power iteration, following the edges into each node.
+#[derive(Debug, Clone)]
+pub struct CentralityAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Edges in the graph.
+    pub edges: Vec<(usize, usize, f64)>,
+
+    /// Whether the edges are directed.
+    pub directed: bool,
+
+    /// Measure to compute.
+    pub measure: CentralityMeasure,
+
+    /// Maximum total change of the scores to stop iterating.
+    pub tolerance: f64,
+
+    /// Maximum number of iterations.
+    pub max_iterations: usize,
+}
+
+impl Default for CentralityAlgorithm {
+    /// Create a new default instance of the centrality algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the centrality algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CentralityAlgorithm {
+    /// Create a new instance of the centrality algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the centrality algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            edges: Vec::new(),
+            directed: true,
+            measure: CentralityMeasure::Eigenvector,
+            tolerance: 1e-9,
+            max_iterations: 1000,
+        }
+    }
+
+    /// Set a single edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: f64) {
+        self.edges.push((source, target, weight));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, f64)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+    }
+
+    /// Set whether the edges are directed.
+    ///
+    /// # Arguments
+    ///
+    /// - `directed`: Whether the edges are directed.
+    pub fn set_directed(&mut self, directed: bool) {
+        self.directed = directed;
+    }
+
+    /// Set the measure to compute.
+    ///
+    /// # Arguments
+    ///
+    /// - `measure`: Eigenvector or Katz centrality.
+    pub fn set_measure(&mut self, measure: CentralityMeasure) {
+        self.measure = measure;
+    }
+
+    /// Set the tolerance of the iterations.
+    ///
+    /// # Arguments
+    ///
+    /// - `tolerance`: Maximum total change of the scores to stop iterating.
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        self.tolerance = tolerance;
+    }
+
+    /// Set the maximum number of iterations.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_iterations`: Maximum number of iterations.
+    pub fn set_max_iterations(&mut self, max_iterations: usize) {
+        self.max_iterations = max_iterations;
+    }
+
+    /// Sum the weighted scores flowing into each node.
+    ///
+    /// # Arguments
+    ///
+    /// - `scores`: Score of each node.
+    ///
+    /// # Returns
+    ///
+    /// Incoming weighted score of each node.
+    fn incoming(&self, scores: &[f64]) -> Vec<f64> {
+        let mut result = vec![0.0; self.total_nodes];
+
+        for &(source, target, weight) in &self.edges {
+            result[target] += weight * scores[source];
+
+            if !self.directed && source != target {
+                result[source] += weight * scores[target];
+            }
+        }
+
+        result
+    }
+
+    /// Scale a vector to unit Euclidean norm.
+    ///
+    /// # Arguments
+    ///
+    /// - `vector`: Vector to scale in place.
+    fn normalize(vector: &mut [f64]) {
+        let norm = vector.iter().map(|a| a * a).sum::<f64>().sqrt();
+
+        if norm > 0.0 {
+            vector.iter_mut().for_each(|a| *a /= norm);
+        }
+    }
+
+    /// Compute the scores by power iteration.
+    ///
+    /// # Returns
+    ///
+    /// Scores with convergence diagnostics. Scores that did not converge are the last iterate.
+    pub fn centrality(&self) -> CentralityScores {
+        let n = self.total_nodes;
+        let mut scores = vec![1.0 / (n as f64).sqrt(); n];
+        let mut iterations = 0;
+        let mut residual = f64::INFINITY;
+
+        if n == 0 {
+            residual = 0.0;
+        }
+
+        while iterations < self.max_iterations && residual > self.tolerance {
+            let incoming = self.incoming(&scores);
+
+            let mut next: Vec<f64> = match self.measure {
+                // Adding the current scores shifts the spectrum so that bipartite graphs do not oscillate.
+                CentralityMeasure::Eigenvector => {
+                    incoming.iter().zip(&scores).map(|(a, b)| a + b).collect()
+                }
+                CentralityMeasure::Katz { attenuation, base } => {
+                    incoming.iter().map(|a| attenuation * a + base).collect()
+                }
+            };
+
+            if self.measure == CentralityMeasure::Eigenvector {
+                Self::normalize(&mut next);
+            }
+
+            residual = next.iter().zip(&scores).map(|(a, b)| (a - b).abs()).sum();
+            scores = next;
+            iterations += 1;
+        }
+
+        Self::normalize(&mut scores);
+
+        CentralityScores {
+            scores,
+            iterations,
+            residual,
+            converged: residual <= self.tolerance,
+        }
+    }
+}
+
+impl From<&DiGraph<f64>> for CentralityAlgorithm {
+    /// Create an instance of the centrality algorithm from a shared graph, copying its edges and nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `graph`: Graph whose edges are read as directed edges.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the centrality algorithm with the edges of the graph.
+    fn from(graph: &DiGraph<f64>) -> Self {
+        let mut algorithm = Self::new();
+
+        for (source, target, weight) in graph.edges() {
+            algorithm.set_edge(source, target, weight);
+        }
+
+        algorithm.set_total_nodes(graph.total_nodes());
+        algorithm
+    }
+}
+
+impl GraphAlgorithm for CentralityAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<f64>;
+
+    /// Run the centrality algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the centrality algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the score of each node, or an error if the scores do not converge.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let result = self.centrality();
+
+        match result.converged {
+            true => Ok(result.scores),
+            false => Err(GraphError::NoConvergence),
+        }
+    }
+}
+
+impl ConfiguredAlgorithm for CentralityAlgorithm {
+    /// Describe the configuration of the centrality algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("centrality")
+            .with("measure", self.measure)
+            .with("directed", self.directed)
+            .with("tolerance", self.tolerance)
+            .with("max_iterations", self.max_iterations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(left: &[f64], right: &[f64]) {
+        assert_eq!(left.len(), right.len());
+
+        for (a, b) in left.iter().zip(right) {
+            assert!((a - b).abs() < 1e-6, "{left:?} != {right:?}");
+        }
+    }
+
+    fn star() -> CentralityAlgorithm {
+        let mut algorithm = CentralityAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 1.0), (2, 1.0), (3, 1.0)])]);
+        algorithm.set_directed(false);
+        algorithm
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = CentralityAlgorithm::new();
+        let algorithm_default = CentralityAlgorithm::default();
+
+        assert_eq!(algorithm.measure, CentralityMeasure::Eigenvector);
+        assert!(algorithm_default.directed);
+        assert!(algorithm.centrality().converged);
+    }
+
+    #[test]
+    fn test_run_eigenvector() {
+        // The leading eigenvector of a star with three leaves is (sqrt(3), 1, 1, 1) up to scale.
+        let algorithm = star();
+        let result = algorithm.centrality();
+        let norm = 6f64.sqrt();
+
+        assert!(result.converged);
+        assert!(result.residual <= algorithm.tolerance);
+        assert_close(
+            &result.scores,
+            &[3f64.sqrt() / norm, 1.0 / norm, 1.0 / norm, 1.0 / norm],
+        );
+    }
+
+    #[test]
+    fn test_run_katz() {
+        // On a directed path 0 -> 1 -> 2, Katz scores are (b, b + ab, b + ab + a^2 b) up to scale.
+        let mut algorithm = CentralityAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 1.0)]), (1, vec![(2, 1.0)])]);
+        algorithm.set_measure(CentralityMeasure::Katz {
+            attenuation: 0.5,
+            base: 1.0,
+        });
+
+        let scores = algorithm.run(None).unwrap();
+        let raw = [1.0, 1.5, 1.75];
+        let norm = raw.iter().map(|a: &f64| a * a).sum::<f64>().sqrt();
+
+        assert_close(&scores, &raw.map(|a| a / norm));
+    }
+
+    #[test]
+    fn test_run_no_convergence() {
+        // The largest eigenvalue of the star is sqrt(3), so attenuation 1 diverges.
+        let mut algorithm = star();
+        algorithm.set_measure(CentralityMeasure::Katz {
+            attenuation: 1.0,
+            base: 1.0,
+        });
+
+        let result = algorithm.centrality();
+
+        assert!(!result.converged);
+        assert_eq!(result.iterations, 1000);
+        assert_eq!(algorithm.run(None), Err(GraphError::NoConvergence));
+    }
+
+    #[test]
+    fn test_from_graph() {
+        let mut graph = DiGraph::new();
+
+        for leaf in 1..4 {
+            graph.add_edge(0, leaf, 1.0);
+            graph.add_edge(leaf, 0, 1.0);
+        }
+
+        let algorithm = CentralityAlgorithm::from(&graph);
+
+        assert!(algorithm.directed);
+        assert_eq!(algorithm.total_nodes, 4);
+        assert_close(&algorithm.centrality().scores, &star().centrality().scores);
+    }
+}
@@ -0,0 +1,307 @@
+use crate::{DijkstraAlgorithm, EulerianAlgorithm, GraphAlgorithm, GraphError, MatchingAlgorithm};
+
+/// Chinese Postman Algorithm.
+/// Find a minimum-cost closed walk of an undirected weighted graph that traverses every edge at least once.
+/// The odd-degree nodes are paired by a minimum-cost perfect matching on their shortest path distances,
+/// the matched shortest paths are duplicated, and an Eulerian circuit of the result is returned.
+#[derive(Debug, Clone)]
+pub struct ChinesePostmanAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Edges in the graph.
+    pub edges: Vec<(usize, usize, usize)>,
+}
+
+impl Default for ChinesePostmanAlgorithm {
+    /// Create a new default instance of the Chinese Postman Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the Chinese Postman Algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChinesePostmanAlgorithm {
+    /// Create a new instance of the Chinese Postman Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the Chinese Postman Algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            edges: Vec::new(),
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.edges.push((source, target, weight));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+    }
+
+    /// Find the shortest paths to duplicate so that every node has an even degree.
+    ///
+    /// # Returns
+    ///
+    /// Edges to duplicate, or `None` if some odd-degree nodes cannot be paired.
+    pub fn duplicated_edges(&self) -> Option<Vec<(usize, usize, usize)>> {
+        let mut degrees = vec![0; self.total_nodes];
+        let mut adjacency = vec![Vec::new(); self.total_nodes];
+
+        for &(source, target, weight) in &self.edges {
+            degrees[source] += 1;
+            degrees[target] += 1;
+            adjacency[source].push((target, weight));
+
+            if source != target {
+                adjacency[target].push((source, weight));
+            }
+        }
+
+        let odd: Vec<usize> = (0..self.total_nodes)
+            .filter(|&node| degrees[node] % 2 == 1)
+            .collect();
+
+        let mut dijkstra = DijkstraAlgorithm::new();
+        dijkstra.set_nodes(adjacency.iter().cloned().enumerate().collect());
+
+        let distances: Vec<Vec<usize>> = odd
+            .iter()
+            .map(|&node| dijkstra.run(Some(node)).unwrap_or_default())
+            .collect();
+
+        // Minimum-cost perfect matching as a maximum cardinality matching on complemented weights.
+        let longest = distances
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| odd.iter().skip(i + 1).map(move |&node| row[node]))
+            .filter(|&distance| distance != usize::MAX)
+            .max()
+            .unwrap_or(0);
+
+        let mut matching = MatchingAlgorithm::new();
+        matching.set_total_nodes(odd.len());
+        matching.set_max_cardinality(true);
+
+        for (i, row) in distances.iter().enumerate() {
+            for (j, &node) in odd.iter().enumerate().skip(i + 1) {
+                if row[node] != usize::MAX {
+                    matching.set_edge(i, j, longest + 1 - row[node]);
+                }
+            }
+        }
+
+        let pairs = matching.maximum_weight_matching();
+
+        if 2 * pairs.len() != odd.len() {
+            return None;
+        }
+
+        // Walk each matched path back from its end along edges that are tight for the distances.
+        let mut duplicated = Vec::new();
+
+        for (i, j) in pairs {
+            let distance = &distances[i];
+            let mut node = odd[j];
+
+            while node != odd[i] {
+                let &(previous, weight) = adjacency[node]
+                    .iter()
+                    .filter(|&&(previous, weight)| {
+                        distance[previous] != usize::MAX
+                            && distance[previous] + weight == distance[node]
+                            && previous != node
+                    })
+                    .min()?;
+
+                duplicated.push((previous, node, weight));
+                node = previous;
+            }
+        }
+
+        Some(duplicated)
+    }
+}
+
+impl GraphAlgorithm for ChinesePostmanAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = (usize, Vec<usize>);
+
+    /// Run the Chinese Postman Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node of the walk, the source of the first edge if omitted.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the cost of the walk and its nodes, starting and ending at the start node,
+    /// or an error if the edges are not connected or the start node has no edge.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let Some(&(first, _, _)) = self.edges.first() else {
+            return Ok((0, start.into_iter().collect()));
+        };
+
+        let start = start.unwrap_or(first);
+
+        if !self
+            .edges
+            .iter()
+            .any(|&(source, target, _)| source == start || target == start)
+        {
+            return Err(GraphError::Infeasible);
+        }
+
+        let duplicated = self.duplicated_edges().ok_or(GraphError::Infeasible)?;
+
+        let mut eulerian = EulerianAlgorithm::new();
+        eulerian.set_directed(false);
+
+        for &(source, target, _) in self.edges.iter().chain(&duplicated) {
+            eulerian.set_edge(source, target);
+        }
+
+        let trail = eulerian
+            .run(Some(start))
+            .map_err(|_| GraphError::Infeasible)?;
+
+        let cost = self
+            .edges
+            .iter()
+            .chain(&duplicated)
+            .map(|&(_, _, weight)| weight)
+            .sum();
+        let walk = std::iter::once(start)
+            .chain(trail.into_iter().map(|(_, target)| target))
+            .collect();
+
+        Ok((cost, walk))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check that the walk is closed and traverses every edge.
+    fn assert_covers(algorithm: &ChinesePostmanAlgorithm, walk: &[usize]) {
+        assert_eq!(walk.first(), walk.last());
+
+        for &(source, target, _) in &algorithm.edges {
+            assert!(walk
+                .windows(2)
+                .any(|pair| pair == [source, target] || pair == [target, source]));
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = ChinesePostmanAlgorithm::new();
+        let algorithm_default = ChinesePostmanAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.edges.len(), 0);
+        assert_eq!(algorithm.run(None), Ok((0, vec![])));
+    }
+
+    #[test]
+    fn test_run_eulerian() {
+        let mut algorithm = ChinesePostmanAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 2)]),
+            (1, vec![(2, 3)]),
+            (2, vec![(0, 4)]),
+        ]);
+
+        assert_eq!(algorithm.duplicated_edges(), Some(vec![]));
+        assert_eq!(algorithm.run(Some(1)).unwrap().0, 9);
+    }
+
+    #[test]
+    fn test_run() {
+        // Square with a diagonal: nodes 0 and 2 have odd degree, the path 0 - 1 - 2 is duplicated.
+        let mut algorithm = ChinesePostmanAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 1), (3, 4), (2, 5)]),
+            (1, vec![(2, 1)]),
+            (2, vec![(3, 4)]),
+        ]);
+
+        let (cost, walk) = algorithm.run(None).unwrap();
+
+        assert_eq!(
+            algorithm.duplicated_edges(),
+            Some(vec![(1, 2, 1), (0, 1, 1)])
+        );
+        assert_eq!(cost, 17);
+        assert_eq!(walk.len(), 8);
+        assert_eq!(walk[0], 0);
+        assert_covers(&algorithm, &walk);
+    }
+
+    #[test]
+    fn test_run_path() {
+        let mut algorithm = ChinesePostmanAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 3)]), (1, vec![(2, 2)])]);
+
+        let (cost, walk) = algorithm.run(Some(1)).unwrap();
+
+        assert_eq!(cost, 10);
+        assert_eq!(walk.len(), 5);
+        assert_covers(&algorithm, &walk);
+    }
+
+    #[test]
+    fn test_run_infeasible() {
+        let mut algorithm = ChinesePostmanAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 1)]), (2, vec![(3, 1)])]);
+
+        // Each component can be made even, but no single walk covers both.
+        assert_eq!(
+            algorithm.duplicated_edges(),
+            Some(vec![(0, 1, 1), (2, 3, 1)])
+        );
+        assert_eq!(algorithm.run(None), Err(GraphError::Infeasible));
+
+        algorithm.set_edge(1, 2, 1);
+
+        assert_eq!(algorithm.run(Some(5)), Err(GraphError::Infeasible));
+    }
+}
@@ -0,0 +1,514 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use crate::{GraphAlgorithm, GraphError};
+
+/// Chordality Test (Maximum Cardinality Search).
+/// Determine whether an undirected graph is chordal and compute a perfect elimination ordering.
+#[derive(Debug, Clone)]
+pub struct ChordalAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Neighbors of each node.
+    pub adjacency: Vec<BTreeSet<usize>>,
+}
+
+impl Default for ChordalAlgorithm {
+    /// Create a new default instance of the chordality test.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the chordality test.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChordalAlgorithm {
+    /// Create a new instance of the chordality test.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the chordality test.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+
+        if source != target {
+            self.adjacency[source].insert(target);
+            self.adjacency[target].insert(source);
+        }
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its neighbors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, BTreeSet::new());
+    }
+
+    /// Visit the nodes with maximum cardinality search,
+    /// always picking the node with the most visited neighbors.
+    ///
+    /// # Returns
+    ///
+    /// Nodes in visiting order. Its reverse is a perfect elimination ordering if the graph is chordal.
+    fn maximum_cardinality_search(&self) -> Vec<usize> {
+        let mut weights = vec![0; self.total_nodes];
+        let mut visited = vec![false; self.total_nodes];
+        let mut order = Vec::with_capacity(self.total_nodes);
+
+        for _ in 0..self.total_nodes {
+            let node = (0..self.total_nodes)
+                .filter(|&node| !visited[node])
+                .max_by_key(|&node| (weights[node], std::cmp::Reverse(node)))
+                .unwrap_or_default();
+
+            visited[node] = true;
+            order.push(node);
+
+            for &neighbor in &self.adjacency[node] {
+                weights[neighbor] += 1;
+            }
+        }
+
+        order
+    }
+
+    /// Find a node whose later neighbors in the ordering do not form a clique.
+    ///
+    /// # Arguments
+    ///
+    /// - `ordering`: Candidate elimination ordering.
+    ///
+    /// # Returns
+    ///
+    /// The violating node, its earliest later neighbor, and a later neighbor not adjacent to it.
+    fn violation(&self, ordering: &[usize]) -> Option<(usize, usize, usize)> {
+        let mut position = vec![0; self.total_nodes];
+
+        for (index, &node) in ordering.iter().enumerate() {
+            position[node] = index;
+        }
+
+        for &node in ordering {
+            let later: Vec<usize> = self.adjacency[node]
+                .iter()
+                .copied()
+                .filter(|&neighbor| position[neighbor] > position[node])
+                .collect();
+
+            let Some(&parent) = later.iter().min_by_key(|&&neighbor| position[neighbor]) else {
+                continue;
+            };
+
+            if let Some(&other) = later.iter().find(|&&neighbor| {
+                neighbor != parent && !self.adjacency[parent].contains(&neighbor)
+            }) {
+                return Some((node, parent, other));
+            }
+        }
+
+        None
+    }
+
+    /// Find a chordless cycle of length at least four, witnessing that the graph is not chordal.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of the chordless cycle, or `None` if the graph is chordal.
+    pub fn hole(&self) -> Option<Vec<usize>> {
+        let mut ordering = self.maximum_cardinality_search();
+        ordering.reverse();
+
+        let (node, start, end) = self.violation(&ordering)?;
+
+        // A shortest path avoiding the node's other neighbors closes a chordless cycle.
+        let mut blocked = vec![false; self.total_nodes];
+        blocked[node] = true;
+
+        for &neighbor in &self.adjacency[node] {
+            blocked[neighbor] = neighbor != start && neighbor != end;
+        }
+
+        let mut parent = vec![None; self.total_nodes];
+        let mut queue = VecDeque::from([start]);
+        blocked[start] = true;
+
+        while let Some(current) = queue.pop_front() {
+            if current == end {
+                break;
+            }
+
+            for &neighbor in &self.adjacency[current] {
+                if !blocked[neighbor] {
+                    blocked[neighbor] = true;
+                    parent[neighbor] = Some(current);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        let mut cycle = vec![node];
+        let mut current = Some(end);
+
+        while let Some(next) = current {
+            cycle.push(next);
+            current = parent[next];
+        }
+
+        cycle.reverse();
+        cycle.rotate_right(1);
+
+        Some(cycle)
+    }
+
+    /// Find a maximum clique of a chordal graph.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the nodes of a maximum clique, or an error if the graph is not chordal.
+    pub fn maximum_clique(&self) -> Result<Vec<usize>, GraphError> {
+        let ordering = self.run(None)?;
+        let mut position = vec![0; self.total_nodes];
+
+        for (index, &node) in ordering.iter().enumerate() {
+            position[node] = index;
+        }
+
+        // Each node together with its later neighbors forms a clique.
+        let clique = ordering
+            .iter()
+            .map(|&node| {
+                let mut clique: Vec<usize> = self.adjacency[node]
+                    .iter()
+                    .copied()
+                    .filter(|&neighbor| position[neighbor] > position[node])
+                    .chain([node])
+                    .collect();
+                clique.sort_unstable();
+                clique
+            })
+            .max_by_key(|clique| clique.len())
+            .unwrap_or_default();
+
+        Ok(clique)
+    }
+
+    /// Color a chordal graph with the minimum number of colors.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the color of each node, or an error if the graph is not chordal.
+    pub fn coloring(&self) -> Result<Vec<usize>, GraphError> {
+        let ordering = self.run(None)?;
+        let mut colors: Vec<Option<usize>> = vec![None; self.total_nodes];
+
+        // Greedy coloring in reverse elimination order is optimal for chordal graphs.
+        for &node in ordering.iter().rev() {
+            let used: BTreeSet<usize> = self.adjacency[node]
+                .iter()
+                .filter_map(|&neighbor| colors[neighbor])
+                .collect();
+
+            colors[node] = (0..).find(|color| !used.contains(color));
+        }
+
+        Ok(colors.into_iter().map(Option::unwrap_or_default).collect())
+    }
+
+    /// Label the connected components of the graph after removing a node and its neighbors.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node whose closed neighborhood is removed.
+    ///
+    /// # Returns
+    ///
+    /// Component of each remaining node, or `None` for the removed nodes.
+    fn components_avoiding(&self, node: usize) -> Vec<Option<usize>> {
+        let mut blocked = vec![false; self.total_nodes];
+        blocked[node] = true;
+
+        for &neighbor in &self.adjacency[node] {
+            blocked[neighbor] = true;
+        }
+
+        let mut component = vec![None; self.total_nodes];
+        let mut total = 0;
+
+        for root in 0..self.total_nodes {
+            if blocked[root] || component[root].is_some() {
+                continue;
+            }
+
+            component[root] = Some(total);
+            let mut queue = VecDeque::from([root]);
+
+            while let Some(current) = queue.pop_front() {
+                for &neighbor in &self.adjacency[current] {
+                    if !blocked[neighbor] && component[neighbor].is_none() {
+                        component[neighbor] = Some(total);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+
+            total += 1;
+        }
+
+        component
+    }
+
+    /// Find an asteroidal triple: three pairwise non-adjacent nodes where each pair is joined
+    /// by a path avoiding the neighbors of the third.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of an asteroidal triple, or `None` if the graph is asteroidal triple-free.
+    pub fn asteroidal_triple(&self) -> Option<(usize, usize, usize)> {
+        let components: Vec<Vec<Option<usize>>> = (0..self.total_nodes)
+            .map(|node| self.components_avoiding(node))
+            .collect();
+
+        let together = |avoided: usize, a: usize, b: usize| {
+            components[avoided][a].is_some() && components[avoided][a] == components[avoided][b]
+        };
+
+        for x in 0..self.total_nodes {
+            for y in x + 1..self.total_nodes {
+                if self.adjacency[x].contains(&y) {
+                    continue;
+                }
+
+                for z in y + 1..self.total_nodes {
+                    if together(z, x, y) && together(y, x, z) && together(x, y, z) {
+                        return Some((x, y, z));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Test whether the graph is an interval graph, the intersection graph of intervals on a line.
+    ///
+    /// # Returns
+    ///
+    /// Whether the graph is chordal and asteroidal triple-free (Lekkerkerker–Boland).
+    pub fn is_interval_graph(&self) -> bool {
+        self.run(None).is_ok() && self.asteroidal_triple().is_none()
+    }
+}
+
+impl GraphAlgorithm for ChordalAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<usize>;
+
+    /// Run the chordality test.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the chordality test.
+    ///
+    /// # Returns
+    ///
+    /// Result containing a perfect elimination ordering, or an error if the graph is not chordal.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let mut ordering = self.maximum_cardinality_search();
+        ordering.reverse();
+
+        match self.violation(&ordering) {
+            Some(_) => Err(GraphError::NotChordal),
+            None => Ok(ordering),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let algorithm = ChordalAlgorithm::new();
+        let algorithm_default = ChordalAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.total_nodes, 0);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = ChordalAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 2]),
+            (1, vec![2, 3]),
+            (2, vec![3, 4]),
+            (3, vec![4]),
+        ]);
+
+        let ordering = algorithm.run(None).unwrap();
+
+        assert_eq!(ordering.len(), 5);
+        assert_eq!(algorithm.hole(), None);
+    }
+
+    #[test]
+    fn test_run_square() {
+        let mut algorithm = ChordalAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1]), (1, vec![2]), (2, vec![3]), (3, vec![0])]);
+
+        assert_eq!(algorithm.run(None), Err(GraphError::NotChordal));
+
+        let hole = algorithm.hole().unwrap();
+        let mut sorted = hole.clone();
+        sorted.sort_unstable();
+
+        assert_eq!(sorted, vec![0, 1, 2, 3]);
+
+        for (index, &node) in hole.iter().enumerate() {
+            let next = hole[(index + 1) % hole.len()];
+
+            assert!(algorithm.adjacency[node].contains(&next));
+        }
+    }
+
+    #[test]
+    fn test_hole_is_chordless() {
+        let mut algorithm = ChordalAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 5, 6]),
+            (1, vec![2, 6]),
+            (2, vec![3]),
+            (3, vec![4]),
+            (4, vec![5]),
+        ]);
+
+        let hole = algorithm.hole().unwrap();
+
+        assert!(hole.len() >= 4);
+
+        for (i, &a) in hole.iter().enumerate() {
+            for (j, &b) in hole.iter().enumerate() {
+                let consecutive = (i + 1) % hole.len() == j || (j + 1) % hole.len() == i;
+
+                assert_eq!(algorithm.adjacency[a].contains(&b), consecutive);
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_empty_graph() {
+        let algorithm = ChordalAlgorithm::new();
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![]);
+        assert_eq!(algorithm.maximum_clique().unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_maximum_clique() {
+        let mut algorithm = ChordalAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 2, 3]),
+            (1, vec![2, 3]),
+            (2, vec![3, 4]),
+            (4, vec![5]),
+        ]);
+
+        assert_eq!(algorithm.maximum_clique().unwrap(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_coloring() {
+        let mut algorithm = ChordalAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 2, 3]),
+            (1, vec![2, 3]),
+            (2, vec![3, 4]),
+            (4, vec![5]),
+        ]);
+
+        let colors = algorithm.coloring().unwrap();
+
+        assert_eq!(colors.iter().max(), Some(&3));
+
+        for (node, neighbors) in algorithm.adjacency.iter().enumerate() {
+            for &neighbor in neighbors {
+                assert_ne!(colors[node], colors[neighbor]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_coloring_not_chordal() {
+        let mut algorithm = ChordalAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1]), (1, vec![2]), (2, vec![3]), (3, vec![0])]);
+
+        assert_eq!(algorithm.coloring(), Err(GraphError::NotChordal));
+        assert_eq!(algorithm.maximum_clique(), Err(GraphError::NotChordal));
+    }
+
+    #[test]
+    fn test_is_interval_graph() {
+        let mut algorithm = ChordalAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 2]),
+            (1, vec![2, 3]),
+            (2, vec![3]),
+            (3, vec![4]),
+        ]);
+
+        assert!(algorithm.is_interval_graph());
+        assert_eq!(algorithm.asteroidal_triple(), None);
+    }
+
+    #[test]
+    fn test_is_interval_graph_subdivided_claw() {
+        let mut algorithm = ChordalAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 3, 5]),
+            (1, vec![2]),
+            (3, vec![4]),
+            (5, vec![6]),
+        ]);
+
+        assert!(algorithm.run(None).is_ok());
+        assert_eq!(algorithm.asteroidal_triple(), Some((2, 4, 6)));
+        assert!(!algorithm.is_interval_graph());
+    }
+}
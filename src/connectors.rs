@@ -0,0 +1,119 @@
+use std::fmt;
+
+use crate::{BulkLoader, LoadedGraph};
+
+/// Error fetching edges from a database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectorError {
+    /// SQL query failed or returned rows of another shape.
+    Sql(String),
+
+    /// Neo4j query failed or returned rows without the expected columns.
+    Neo4j(String),
+}
+
+impl std::error::Error for ConnectorError {}
+
+impl fmt::Display for ConnectorError {
+    /// Display the error message.
+    ///
+    /// # Arguments
+    ///
+    /// - `f`: Formatter.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the formatted error message.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sql(message) => write!(f, "SQL query failed: {message}"),
+            Self::Neo4j(message) => write!(f, "Neo4j query failed: {message}"),
+        }
+    }
+}
+
+impl BulkLoader {
+    /// Load a graph from the rows of a SQL query, on any database supported by sqlx.
+    /// The query returns `(source, target, weight)` rows of 64-bit integers, such as
+    /// `SELECT src, dst, CAST(weight AS BIGINT) FROM edges`, with a null weight for the default one.
+    /// Requires the optional `sql` feature.
+    ///
+    /// # Arguments
+    ///
+    /// - `executor`: Connection or pool to run the query on.
+    /// - `query`: SQL query.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the graph built from the valid rows, or an error if the query failed.
+    #[cfg(feature = "sql")]
+    pub async fn load_sql<'e, DB, E>(
+        &self,
+        executor: E,
+        query: &'e str,
+    ) -> Result<LoadedGraph, ConnectorError>
+    where
+        DB: sqlx::Database,
+        E: sqlx::Executor<'e, Database = DB>,
+        for<'r> (i64, i64, Option<i64>): sqlx::FromRow<'r, DB::Row>,
+        for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    {
+        let rows = sqlx::query_as::<DB, (i64, i64, Option<i64>)>(query)
+            .fetch_all(executor)
+            .await
+            .map_err(|error| ConnectorError::Sql(error.to_string()))?;
+
+        Ok(self.load_rows(rows))
+    }
+
+    /// Load a graph from the rows of a Cypher query over Bolt.
+    /// The query returns the columns `source`, `target` and optionally `weight` as integers, such as
+    /// `MATCH (a)-[r:ROAD]->(b) RETURN a.id AS source, b.id AS target, r.minutes AS weight`.
+    /// Requires the optional `neo4j` feature.
+    ///
+    /// # Arguments
+    ///
+    /// - `graph`: Connection to the Neo4j database.
+    /// - `query`: Cypher query.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the graph built from the valid rows, or an error if the query failed.
+    #[cfg(feature = "neo4j")]
+    pub async fn load_neo4j(
+        &self,
+        graph: &neo4rs::Graph,
+        query: neo4rs::Query,
+    ) -> Result<LoadedGraph, ConnectorError> {
+        let error = |error: &dyn fmt::Display| ConnectorError::Neo4j(error.to_string());
+        let mut stream = graph.execute(query).await.map_err(|e| error(&e))?;
+        let mut rows = Vec::new();
+
+        while let Some(row) = stream.next().await.map_err(|e| error(&e))? {
+            let source: i64 = row.get("source").map_err(|e| error(&e))?;
+            let target: i64 = row.get("target").map_err(|e| error(&e))?;
+            let weight: Option<i64> = row.get("weight").ok();
+
+            rows.push((source, target, weight));
+        }
+
+        Ok(self.load_rows(rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connector_error() {
+        assert_eq!(
+            ConnectorError::Sql("no such table: edges".to_string()).to_string(),
+            "SQL query failed: no such table: edges"
+        );
+        assert_eq!(
+            ConnectorError::Neo4j("missing column".to_string()).to_string(),
+            "Neo4j query failed: missing column"
+        );
+    }
+}
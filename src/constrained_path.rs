@@ -0,0 +1,341 @@
+use std::collections::{BTreeSet, BinaryHeap, HashMap};
+
+use crate::{dijkstra::State, GraphAlgorithm, GraphError};
+
+/// Outgoing labeled edge as `(target, weight, label)`.
+pub type LabeledEdge = (usize, usize, usize);
+
+/// Deterministic finite automaton over edge labels.
+/// The automaton starts in state 0, and a missing transition forbids the edge.
+#[derive(Debug, Clone, Default)]
+pub struct LabelAutomaton {
+    /// Number of states.
+    pub total_states: usize,
+
+    /// Next state for each `(state, label)` pair.
+    pub transitions: HashMap<(usize, usize), usize>,
+
+    /// States in which a path may end.
+    pub accepting: BTreeSet<usize>,
+}
+
+impl LabelAutomaton {
+    /// Create a new automaton with a single, non-accepting start state.
+    ///
+    /// # Returns
+    ///
+    /// New automaton.
+    pub fn new() -> Self {
+        Self {
+            total_states: 1,
+            transitions: HashMap::new(),
+            accepting: BTreeSet::new(),
+        }
+    }
+
+    /// Set a transition.
+    ///
+    /// # Arguments
+    ///
+    /// - `from`: Current state.
+    /// - `label`: Label of the traversed edge.
+    /// - `to`: Next state.
+    pub fn set_transition(&mut self, from: usize, label: usize, to: usize) {
+        self.transitions.insert((from, label), to);
+        self.total_states = self.total_states.max(from + 1).max(to + 1);
+    }
+
+    /// Set an accepting state.
+    ///
+    /// # Arguments
+    ///
+    /// - `state`: State in which a path may end.
+    pub fn set_accepting(&mut self, state: usize) {
+        self.accepting.insert(state);
+        self.total_states = self.total_states.max(state + 1);
+    }
+}
+
+/// Label-Constrained Shortest Path.
+/// Find shortest paths whose sequence of edge labels is accepted by an automaton,
+/// e.g. "no two consecutive ferry edges" or "must pass a charging station".
+#[derive(Debug, Clone)]
+pub struct ConstrainedPathAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Outgoing edges of each node as `(target, weight, label)`.
+    pub adjacency: Vec<Vec<LabeledEdge>>,
+
+    /// Automaton the label sequence must be accepted by.
+    pub automaton: LabelAutomaton,
+}
+
+impl Default for ConstrainedPathAlgorithm {
+    /// Create a new default instance of the label-constrained shortest path.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the label-constrained shortest path.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConstrainedPathAlgorithm {
+    /// Create a new instance of the label-constrained shortest path.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the label-constrained shortest path.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+            automaton: LabelAutomaton::new(),
+        }
+    }
+
+    /// Set a single labeled edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    /// - `label`: Label of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize, label: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].push((target, weight, label));
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its `(target, weight, label)` edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<LabeledEdge>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight, label) in edges {
+                self.set_edge(source, target, weight, label);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, Vec::new());
+    }
+
+    /// Set the automaton the label sequence must be accepted by.
+    ///
+    /// # Arguments
+    ///
+    /// - `automaton`: Automaton over edge labels.
+    pub fn set_automaton(&mut self, automaton: LabelAutomaton) {
+        self.automaton = automaton;
+    }
+
+    /// Run Dijkstra's Algorithm on the product of the graph and the automaton.
+    /// Product state `node * total_states + state` means reaching `node` in automaton `state`.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Distance and predecessor of each product state.
+    fn search(&self, start: usize) -> (Vec<usize>, Vec<Option<usize>>) {
+        let states = self.automaton.total_states;
+        let mut distances = vec![usize::MAX; self.total_nodes * states];
+        let mut predecessors = vec![None; self.total_nodes * states];
+        let mut priority_queue = BinaryHeap::new();
+
+        if start < self.total_nodes {
+            distances[start * states] = 0;
+            priority_queue.push(State {
+                cost: 0,
+                position: start * states,
+            });
+        }
+
+        while let Some(State { cost, position }) = priority_queue.pop() {
+            if cost > distances[position] {
+                continue;
+            }
+
+            let (node, state) = (position / states, position % states);
+
+            for &(target, weight, label) in &self.adjacency[node] {
+                let Some(&next_state) = self.automaton.transitions.get(&(state, label)) else {
+                    continue;
+                };
+
+                let next = target * states + next_state;
+
+                if cost + weight < distances[next] {
+                    distances[next] = cost + weight;
+                    predecessors[next] = Some(position);
+                    priority_queue.push(State {
+                        cost: cost + weight,
+                        position: next,
+                    });
+                }
+            }
+        }
+
+        (distances, predecessors)
+    }
+
+    /// Find a shortest accepted path between two nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Cost and nodes of the path, or `None` if no accepted path exists.
+    pub fn shortest_path(&self, start: usize, target: usize) -> Option<(usize, Vec<usize>)> {
+        if target >= self.total_nodes {
+            return None;
+        }
+
+        let states = self.automaton.total_states;
+        let (distances, predecessors) = self.search(start);
+        let (cost, mut position) = self
+            .automaton
+            .accepting
+            .iter()
+            .map(|&state| (distances[target * states + state], target * states + state))
+            .filter(|&(cost, _)| cost != usize::MAX)
+            .min()?;
+
+        let mut path = vec![target];
+
+        while let Some(previous) = predecessors[position] {
+            path.push(previous / states);
+            position = previous;
+        }
+
+        path.reverse();
+
+        Some((cost, path))
+    }
+}
+
+impl GraphAlgorithm for ConstrainedPathAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<usize>;
+
+    /// Run the label-constrained shortest path.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the cost of the shortest accepted path to each node, `usize::MAX` if there is none.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let start = start.ok_or(GraphError::MissingStartNode)?;
+        let states = self.automaton.total_states;
+        let (distances, _) = self.search(start);
+
+        Ok((0..self.total_nodes)
+            .map(|node| {
+                self.automaton
+                    .accepting
+                    .iter()
+                    .map(|&state| distances[node * states + state])
+                    .min()
+                    .unwrap_or(usize::MAX)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROAD: usize = 0;
+    const FERRY: usize = 1;
+
+    /// Automaton forbidding two consecutive ferry edges.
+    fn no_double_ferry() -> LabelAutomaton {
+        let mut automaton = LabelAutomaton::new();
+        automaton.set_transition(0, ROAD, 0);
+        automaton.set_transition(0, FERRY, 1);
+        automaton.set_transition(1, ROAD, 0);
+        automaton.set_accepting(0);
+        automaton.set_accepting(1);
+        automaton
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = ConstrainedPathAlgorithm::new();
+        let algorithm_default = ConstrainedPathAlgorithm::default();
+
+        assert_eq!(algorithm.automaton.total_states, 1);
+        assert_eq!(algorithm_default.total_nodes, 0);
+    }
+
+    #[test]
+    fn test_missing_start_node() {
+        let algorithm = ConstrainedPathAlgorithm::new();
+
+        assert_eq!(algorithm.run(None), Err(GraphError::MissingStartNode));
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = ConstrainedPathAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 1, FERRY), (2, 5, ROAD)]),
+            (1, vec![(2, 1, FERRY), (3, 4, ROAD)]),
+            (2, vec![(3, 1, ROAD)]),
+        ]);
+        algorithm.set_automaton(no_double_ferry());
+
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 1, 5, 5]);
+        assert_eq!(algorithm.shortest_path(0, 3), Some((5, vec![0, 1, 3])));
+    }
+
+    #[test]
+    fn test_shortest_path_required_label() {
+        const CHARGER: usize = 2;
+
+        // State 1 is reached after passing a charging edge.
+        let mut automaton = LabelAutomaton::new();
+        automaton.set_transition(0, ROAD, 0);
+        automaton.set_transition(0, CHARGER, 1);
+        automaton.set_transition(1, ROAD, 1);
+        automaton.set_transition(1, CHARGER, 1);
+        automaton.set_accepting(1);
+
+        let mut algorithm = ConstrainedPathAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 1, ROAD), (2, 2, CHARGER)]),
+            (1, vec![(3, 1, ROAD)]),
+            (2, vec![(1, 2, ROAD)]),
+        ]);
+        algorithm.set_automaton(automaton);
+
+        assert_eq!(algorithm.shortest_path(0, 3), Some((5, vec![0, 2, 1, 3])));
+        assert_eq!(algorithm.shortest_path(0, 0), None);
+    }
+}
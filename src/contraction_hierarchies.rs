@@ -0,0 +1,659 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap, HashMap},
+};
+
+use crate::{GraphAlgorithm, GraphError, MemoryEstimate, MemoryEstimation};
+
+/// Contraction Hierarchies.
+/// Answer repeated shortest path queries on a directed weighted graph, such as a road network.
+/// Preprocessing contracts the nodes one by one in order of importance, adding a shortcut edge
+/// whenever a contracted node lies on the only shortest path between two of its neighbors.
+/// Queries then run a bidirectional search that only climbs to more important nodes.
+#[derive(Debug, Clone)]
+pub struct ContractionHierarchiesAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Successors of each node with the weight of the edge.
+    pub adjacency: Vec<Vec<(usize, usize)>>,
+
+    /// Maximum number of nodes settled by each witness search during preprocessing.
+    /// Lower limits preprocess faster but may add unnecessary shortcuts.
+    pub witness_limit: usize,
+
+    /// Target node of `run`.
+    pub target: Option<usize>,
+}
+
+/// Preprocessed hierarchy answering shortest path queries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractionHierarchy {
+    /// Position of each node in the contraction order, more important nodes last.
+    pub rank: Vec<usize>,
+
+    /// Edges of each node to more important nodes, with their weight.
+    pub upward: Vec<Vec<(usize, usize)>>,
+
+    /// Edges into each node from more important nodes, with their weight.
+    pub downward: Vec<Vec<(usize, usize)>>,
+
+    /// Weight of every edge and shortcut by source and target, with the node a shortcut bypasses.
+    pub edges: HashMap<(usize, usize), (usize, Option<usize>)>,
+
+    /// Number of shortcuts added by the contraction.
+    pub shortcuts: usize,
+}
+
+impl Default for ContractionHierarchiesAlgorithm {
+    /// Create a new default instance of Contraction Hierarchies.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of Contraction Hierarchies.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContractionHierarchiesAlgorithm {
+    /// Create a new instance of Contraction Hierarchies.
+    ///
+    /// # Returns
+    ///
+    /// New instance of Contraction Hierarchies.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+            witness_limit: 64,
+            target: None,
+        }
+    }
+
+    /// Set a single directed edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].push((target, weight));
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, Vec::new());
+    }
+
+    /// Set the maximum number of nodes settled by each witness search.
+    ///
+    /// # Arguments
+    ///
+    /// - `limit`: Maximum number of settled nodes.
+    pub fn set_witness_limit(&mut self, limit: usize) {
+        self.witness_limit = limit;
+    }
+
+    /// Set the target node of `run`.
+    ///
+    /// # Arguments
+    ///
+    /// - `target`: Target node.
+    pub fn set_target(&mut self, target: usize) {
+        self.target = Some(target);
+    }
+
+    /// Contract every node of the graph, from the least to the most important.
+    /// The importance of a node is its edge difference, the shortcuts its contraction adds minus the
+    /// edges it removes, plus its number of contracted neighbors to spread the contraction evenly.
+    /// Priorities are updated lazily when a node reaches the front of the queue.
+    ///
+    /// # Returns
+    ///
+    /// Hierarchy answering shortest path queries.
+    pub fn preprocess(&self) -> ContractionHierarchy {
+        let mut outgoing: Vec<BTreeMap<usize, usize>> = vec![BTreeMap::new(); self.total_nodes];
+        let mut incoming: Vec<BTreeMap<usize, usize>> = vec![BTreeMap::new(); self.total_nodes];
+        let mut edges = HashMap::new();
+
+        for (source, targets) in self.adjacency.iter().enumerate() {
+            for &(target, weight) in targets {
+                // Self-loops never shorten a path, and only the lightest parallel edge matters.
+                if source == target
+                    || outgoing[source]
+                        .get(&target)
+                        .is_some_and(|&known| known <= weight)
+                {
+                    continue;
+                }
+
+                outgoing[source].insert(target, weight);
+                incoming[target].insert(source, weight);
+                edges.insert((source, target), (weight, None));
+            }
+        }
+
+        let mut contracted_neighbors = vec![0; self.total_nodes];
+        let priority = |outgoing: &[BTreeMap<usize, usize>],
+                        incoming: &[BTreeMap<usize, usize>],
+                        contracted_neighbors: &[i64],
+                        node: usize| {
+            let added = self.shortcuts(outgoing, incoming, node).len() as i64;
+            let removed = (outgoing[node].len() + incoming[node].len()) as i64;
+
+            added - removed + contracted_neighbors[node]
+        };
+
+        let mut queue: BinaryHeap<Reverse<(i64, usize)>> = (0..self.total_nodes)
+            .map(|node| {
+                Reverse((
+                    priority(&outgoing, &incoming, &contracted_neighbors, node),
+                    node,
+                ))
+            })
+            .collect();
+        let mut rank = vec![0; self.total_nodes];
+        let mut order = 0;
+        let mut shortcuts = 0;
+
+        while let Some(Reverse((_, node))) = queue.pop() {
+            let current = priority(&outgoing, &incoming, &contracted_neighbors, node);
+
+            if queue
+                .peek()
+                .is_some_and(|&Reverse(next)| (current, node) > next)
+            {
+                queue.push(Reverse((current, node)));
+                continue;
+            }
+
+            rank[node] = order;
+            order += 1;
+
+            for (source, target, weight) in self.shortcuts(&outgoing, &incoming, node) {
+                if outgoing[source]
+                    .get(&target)
+                    .map_or(true, |&known| weight < known)
+                {
+                    outgoing[source].insert(target, weight);
+                    incoming[target].insert(source, weight);
+                    edges.insert((source, target), (weight, Some(node)));
+                    shortcuts += 1;
+                }
+            }
+
+            // Remove the node from the remaining graph.
+            for neighbor in outgoing[node].keys() {
+                incoming[*neighbor].remove(&node);
+                contracted_neighbors[*neighbor] += 1;
+            }
+
+            for neighbor in incoming[node].keys() {
+                outgoing[*neighbor].remove(&node);
+                contracted_neighbors[*neighbor] += 1;
+            }
+
+            outgoing[node].clear();
+            incoming[node].clear();
+        }
+
+        let mut upward = vec![Vec::new(); self.total_nodes];
+        let mut downward = vec![Vec::new(); self.total_nodes];
+
+        for (&(source, target), &(weight, _)) in &edges {
+            if rank[source] < rank[target] {
+                upward[source].push((target, weight));
+            } else {
+                downward[target].push((source, weight));
+            }
+        }
+
+        for neighbors in upward.iter_mut().chain(downward.iter_mut()) {
+            neighbors.sort_unstable();
+        }
+
+        ContractionHierarchy {
+            rank,
+            upward,
+            downward,
+            edges,
+            shortcuts,
+        }
+    }
+
+    /// Find the shortcuts needed to contract a node from the remaining graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `outgoing`: Successors of each remaining node with the weight of the edge.
+    /// - `incoming`: Predecessors of each remaining node with the weight of the edge.
+    /// - `node`: Node to contract.
+    ///
+    /// # Returns
+    ///
+    /// Shortcuts as `(source, target, weight)`.
+    fn shortcuts(
+        &self,
+        outgoing: &[BTreeMap<usize, usize>],
+        incoming: &[BTreeMap<usize, usize>],
+        node: usize,
+    ) -> Vec<(usize, usize, usize)> {
+        let mut shortcuts = Vec::new();
+
+        for (&source, &first) in &incoming[node] {
+            let Some(limit) = outgoing[node]
+                .iter()
+                .filter(|&(&target, _)| target != source)
+                .map(|(_, &second)| first + second)
+                .max()
+            else {
+                continue;
+            };
+
+            let distances = self.witness_search(outgoing, source, node, limit);
+
+            for (&target, &second) in &outgoing[node] {
+                let via = first + second;
+
+                if target != source && distances.get(&target).map_or(true, |&known| known > via) {
+                    shortcuts.push((source, target, via));
+                }
+            }
+        }
+
+        shortcuts
+    }
+
+    /// Search for paths avoiding a node, up to a cost limit and the witness limit.
+    ///
+    /// # Arguments
+    ///
+    /// - `outgoing`: Successors of each remaining node with the weight of the edge.
+    /// - `source`: Starting node.
+    /// - `avoid`: Node to avoid.
+    /// - `limit`: Maximum cost of interest.
+    ///
+    /// # Returns
+    ///
+    /// Upper bounds of the distances from the source to the reached nodes.
+    fn witness_search(
+        &self,
+        outgoing: &[BTreeMap<usize, usize>],
+        source: usize,
+        avoid: usize,
+        limit: usize,
+    ) -> HashMap<usize, usize> {
+        let mut distances = HashMap::from([(source, 0)]);
+        let mut queue = BinaryHeap::from([Reverse((0, source))]);
+        let mut settled = 0;
+
+        while let Some(Reverse((cost, node))) = queue.pop() {
+            if cost > distances[&node] {
+                continue;
+            }
+
+            if cost > limit || settled >= self.witness_limit {
+                break;
+            }
+
+            settled += 1;
+
+            for (&neighbor, &weight) in &outgoing[node] {
+                let next = cost + weight;
+
+                if neighbor != avoid && distances.get(&neighbor).map_or(true, |&known| next < known)
+                {
+                    distances.insert(neighbor, next);
+                    queue.push(Reverse((next, neighbor)));
+                }
+            }
+        }
+
+        distances
+    }
+}
+
+impl ContractionHierarchy {
+    /// Find a shortest path with a bidirectional search climbing the hierarchy from both ends.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Cost and nodes of the path with its shortcuts unpacked, or `None` if the target is unreachable.
+    pub fn shortest_path(&self, source: usize, target: usize) -> Option<(usize, Vec<usize>)> {
+        if source >= self.rank.len() || target >= self.rank.len() {
+            return None;
+        }
+
+        let graphs = [&self.upward, &self.downward];
+        let mut distances = [HashMap::from([(source, 0)]), HashMap::from([(target, 0)])];
+        let mut parents: [HashMap<usize, usize>; 2] = [HashMap::new(), HashMap::new()];
+        let mut queues = [
+            BinaryHeap::from([Reverse((0, source))]),
+            BinaryHeap::from([Reverse((0, target))]),
+        ];
+        let mut best: Option<(usize, usize)> = (source == target).then_some((0, source));
+
+        // Each search stops once its front cannot improve the best path met so far.
+        while let Some(side) = (0..2)
+            .filter_map(|side| queues[side].peek().map(|&Reverse((cost, _))| (cost, side)))
+            .filter(|&(cost, _)| best.map_or(true, |(known, _)| cost < known))
+            .min()
+            .map(|(_, side)| side)
+        {
+            let Some(Reverse((cost, node))) = queues[side].pop() else {
+                break;
+            };
+
+            if cost > distances[side][&node] {
+                continue;
+            }
+
+            for &(neighbor, weight) in &graphs[side][node] {
+                let next = cost + weight;
+
+                if distances[side]
+                    .get(&neighbor)
+                    .map_or(true, |&known| next < known)
+                {
+                    distances[side].insert(neighbor, next);
+                    parents[side].insert(neighbor, node);
+                    queues[side].push(Reverse((next, neighbor)));
+
+                    if let Some(&other) = distances[1 - side].get(&neighbor) {
+                        if best.map_or(true, |(known, _)| next + other < known) {
+                            best = Some((next + other, neighbor));
+                        }
+                    }
+                }
+            }
+        }
+
+        let (cost, meeting) = best?;
+        let mut hierarchy_path = vec![meeting];
+
+        while let Some(&parent) = parents[0].get(&hierarchy_path[hierarchy_path.len() - 1]) {
+            hierarchy_path.push(parent);
+        }
+
+        hierarchy_path.reverse();
+
+        while let Some(&parent) = parents[1].get(&hierarchy_path[hierarchy_path.len() - 1]) {
+            hierarchy_path.push(parent);
+        }
+
+        let mut path = vec![source];
+
+        for edge in hierarchy_path.windows(2) {
+            self.unpack(edge[0], edge[1], &mut path);
+        }
+
+        Some((cost, path))
+    }
+
+    /// Replace an edge of the hierarchy by the original edges it bypasses.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source of the edge.
+    /// - `target`: Target of the edge.
+    /// - `path`: Path ending at the source, extended up to the target.
+    fn unpack(&self, source: usize, target: usize, path: &mut Vec<usize>) {
+        let mut stack = vec![(source, target)];
+
+        while let Some((source, target)) = stack.pop() {
+            match self.edges.get(&(source, target)) {
+                Some(&(_, Some(middle))) => {
+                    stack.push((middle, target));
+                    stack.push((source, middle));
+                }
+                _ => path.push(target),
+            }
+        }
+    }
+}
+
+impl GraphAlgorithm for ContractionHierarchiesAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = (usize, Vec<usize>);
+
+    /// Run Contraction Hierarchies from a start node to the target node.
+    /// This preprocesses the graph on every call; keep the hierarchy from `preprocess`
+    /// to answer repeated queries.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the cost and nodes of a shortest path, or an error if the start or the
+    /// target is missing or the target is unreachable.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let start = start
+            .filter(|&start| start < self.total_nodes)
+            .ok_or(GraphError::MissingStartNode)?;
+        let target = self.target.ok_or(GraphError::MissingTargetNode)?;
+
+        self.preprocess()
+            .shortest_path(start, target)
+            .ok_or(GraphError::MissingTargetNode)
+    }
+}
+
+impl MemoryEstimation for ContractionHierarchiesAlgorithm {
+    /// Estimate the memory of Contraction Hierarchies, projecting as many shortcuts as edges, as
+    /// is typical of road networks. Every edge and shortcut is kept in both directions during the
+    /// contraction, then once more in the hierarchy.
+    ///
+    /// # Returns
+    ///
+    /// Memory estimate of a run.
+    fn estimate_memory(&self) -> MemoryEstimate {
+        let n = self.total_nodes;
+        let edges: usize = self.adjacency.iter().map(Vec::len).sum();
+        let projected = edges.saturating_mul(2);
+        let contraction = MemoryEstimate::bytes::<BTreeMap<usize, usize>>(n.saturating_mul(2))
+            .saturating_add(MemoryEstimate::bytes::<(usize, usize)>(
+                projected.saturating_mul(2),
+            ))
+            .saturating_add(MemoryEstimate::bytes::<(usize, Reverse<(i64, usize)>)>(n));
+        let hierarchy = MemoryEstimate::bytes::<usize>(n)
+            .saturating_add(MemoryEstimate::bytes::<Vec<(usize, usize)>>(
+                n.saturating_mul(2),
+            ))
+            .saturating_add(MemoryEstimate::bytes::<(usize, usize)>(projected))
+            .saturating_add(MemoryEstimate::bytes::<(
+                (usize, usize),
+                (usize, Option<usize>),
+            )>(projected));
+
+        MemoryEstimate::new(
+            MemoryEstimate::bytes::<Vec<(usize, usize)>>(n)
+                .saturating_add(MemoryEstimate::bytes::<(usize, usize)>(edges)),
+            contraction.saturating_add(hierarchy),
+            MemoryEstimate::bytes::<usize>(n),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DijkstraAlgorithm;
+
+    /// Grid of `size × size` nodes with pseudo-random weights in both directions.
+    fn grid(size: usize) -> ContractionHierarchiesAlgorithm {
+        let mut algorithm = ContractionHierarchiesAlgorithm::new();
+
+        for node in 0..size * size {
+            let weight = |salt: usize| 1 + (node * 37 + salt * 11) % 19;
+
+            if node % size + 1 < size {
+                algorithm.set_edge(node, node + 1, weight(1));
+                algorithm.set_edge(node + 1, node, weight(2));
+            }
+
+            if node + size < size * size {
+                algorithm.set_edge(node, node + size, weight(3));
+                algorithm.set_edge(node + size, node, weight(4));
+            }
+        }
+
+        algorithm
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = ContractionHierarchiesAlgorithm::new();
+        let algorithm_default = ContractionHierarchiesAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.witness_limit, 64);
+        assert_eq!(algorithm.run(Some(0)), Err(GraphError::MissingStartNode));
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let mut algorithm = ContractionHierarchiesAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 4), (2, 1)]),
+            (1, vec![(3, 1)]),
+            (2, vec![(1, 2), (3, 5)]),
+        ]);
+        algorithm.set_total_nodes(5);
+
+        let hierarchy = algorithm.preprocess();
+
+        assert_eq!(hierarchy.shortest_path(0, 3), Some((4, vec![0, 2, 1, 3])));
+        assert_eq!(hierarchy.shortest_path(2, 2), Some((0, vec![2])));
+        assert_eq!(hierarchy.shortest_path(3, 0), None);
+        assert_eq!(hierarchy.shortest_path(0, 4), None);
+        assert_eq!(hierarchy.shortest_path(0, 5), None);
+    }
+
+    #[test]
+    fn test_matches_dijkstra() {
+        for limit in [1, 64] {
+            let mut algorithm = grid(8);
+            algorithm.set_witness_limit(limit);
+
+            let hierarchy = algorithm.preprocess();
+            let mut dijkstra = DijkstraAlgorithm::new();
+            dijkstra.set_nodes(
+                algorithm
+                    .adjacency
+                    .clone()
+                    .into_iter()
+                    .enumerate()
+                    .collect(),
+            );
+
+            for source in 0..64 {
+                let distances = dijkstra.run(Some(source)).unwrap();
+
+                for (target, &distance) in distances.iter().enumerate() {
+                    let (cost, path) = hierarchy.shortest_path(source, target).unwrap();
+                    let length: usize = path
+                        .windows(2)
+                        .map(|edge| {
+                            algorithm.adjacency[edge[0]]
+                                .iter()
+                                .find(|&&(next, _)| next == edge[1])
+                                .unwrap()
+                                .1
+                        })
+                        .sum();
+
+                    assert_eq!(cost, distance);
+                    assert_eq!(length, cost);
+                    assert_eq!((path[0], path[path.len() - 1]), (source, target));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_preprocess_shortcuts() {
+        // A path contracted from its middle needs a shortcut in each direction.
+        let mut algorithm = ContractionHierarchiesAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 2)]),
+            (1, vec![(0, 2), (2, 3)]),
+            (2, vec![(1, 3)]),
+        ]);
+
+        let hierarchy = algorithm.preprocess();
+        let mut ranks = hierarchy.rank.clone();
+        ranks.sort_unstable();
+
+        assert_eq!(ranks, vec![0, 1, 2]);
+        assert!(hierarchy.shortcuts <= 2);
+        assert_eq!(hierarchy.shortest_path(0, 2), Some((5, vec![0, 1, 2])));
+        assert_eq!(hierarchy.shortest_path(2, 0), Some((5, vec![2, 1, 0])));
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = grid(4);
+
+        assert_eq!(algorithm.run(Some(0)), Err(GraphError::MissingTargetNode));
+
+        algorithm.set_target(15);
+        let (cost, path) = algorithm.run(Some(0)).unwrap();
+
+        assert_eq!(
+            Some(cost),
+            algorithm
+                .preprocess()
+                .shortest_path(0, 15)
+                .map(|(cost, _)| cost)
+        );
+        assert_eq!((path[0], path[path.len() - 1]), (0, 15));
+    }
+
+    #[test]
+    fn test_estimate_memory() {
+        let mut small = ContractionHierarchiesAlgorithm::new();
+        small.set_edges(vec![(0, vec![(1, 1)]), (1, vec![(2, 1)])]);
+
+        let mut large = small.clone();
+        large.set_edges(vec![(2, vec![(3, 1), (0, 1)]), (3, vec![(1, 1)])]);
+
+        assert!(large.estimate_memory().working > small.estimate_memory().working);
+        assert_eq!(
+            large.estimate_memory().output,
+            4 * std::mem::size_of::<usize>()
+        );
+    }
+}
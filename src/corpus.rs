@@ -0,0 +1,543 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{BulkLoader, LoadedGraph};
+
+/// File format of a benchmark graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorpusFormat {
+    /// DIMACS shortest path format: `p sp <nodes> <arcs>` and 1-based arcs `a <source> <target> <weight>`.
+    Dimacs,
+
+    /// SNAP edge list: 0-based `<source> <target>` pairs with `#` comments.
+    Snap,
+}
+
+/// Standard benchmark graph of the catalog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchmarkGraph {
+    /// Name of the graph.
+    pub name: &'static str,
+
+    /// Download address of the compressed file.
+    pub url: &'static str,
+
+    /// Name of the uncompressed file in the corpus directory.
+    pub file_name: &'static str,
+
+    /// Format of the file.
+    pub format: CorpusFormat,
+
+    /// Whether the edges are directed.
+    pub directed: bool,
+
+    /// Published SHA-256 checksum of the uncompressed file, `None` until recorded from a verified
+    /// download.
+    pub sha256: Option<&'static str>,
+}
+
+/// Road networks of the 9th DIMACS Implementation Challenge and social graphs of the SNAP collection.
+pub const BENCHMARK_GRAPHS: &[BenchmarkGraph] = &[
+    BenchmarkGraph {
+        name: "USA-road-d.NY",
+        url: "http://www.diag.uniroma1.it/challenge9/data/USA-road-d/USA-road-d.NY.gr.gz",
+        file_name: "USA-road-d.NY.gr",
+        format: CorpusFormat::Dimacs,
+        directed: true,
+        sha256: None,
+    },
+    BenchmarkGraph {
+        name: "USA-road-d.BAY",
+        url: "http://www.diag.uniroma1.it/challenge9/data/USA-road-d/USA-road-d.BAY.gr.gz",
+        file_name: "USA-road-d.BAY.gr",
+        format: CorpusFormat::Dimacs,
+        directed: true,
+        sha256: None,
+    },
+    BenchmarkGraph {
+        name: "USA-road-d.COL",
+        url: "http://www.diag.uniroma1.it/challenge9/data/USA-road-d/USA-road-d.COL.gr.gz",
+        file_name: "USA-road-d.COL.gr",
+        format: CorpusFormat::Dimacs,
+        directed: true,
+        sha256: None,
+    },
+    BenchmarkGraph {
+        name: "ca-GrQc",
+        url: "https://snap.stanford.edu/data/ca-GrQc.txt.gz",
+        file_name: "ca-GrQc.txt",
+        format: CorpusFormat::Snap,
+        directed: false,
+        sha256: None,
+    },
+    BenchmarkGraph {
+        name: "facebook_combined",
+        url: "https://snap.stanford.edu/data/facebook_combined.txt.gz",
+        file_name: "facebook_combined.txt",
+        format: CorpusFormat::Snap,
+        directed: false,
+        sha256: None,
+    },
+    BenchmarkGraph {
+        name: "email-Enron",
+        url: "https://snap.stanford.edu/data/email-Enron.txt.gz",
+        file_name: "email-Enron.txt",
+        format: CorpusFormat::Snap,
+        directed: false,
+        sha256: None,
+    },
+    BenchmarkGraph {
+        name: "soc-Epinions1",
+        url: "https://snap.stanford.edu/data/soc-Epinions1.txt.gz",
+        file_name: "soc-Epinions1.txt",
+        format: CorpusFormat::Snap,
+        directed: true,
+        sha256: None,
+    },
+];
+
+/// Error loading a benchmark graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CorpusError {
+    /// Graph is not in the catalog.
+    UnknownGraph(String),
+
+    /// File is not in the corpus directory yet, to be downloaded from the address and uncompressed.
+    Missing {
+        /// Download address of the compressed file.
+        url: String,
+
+        /// Expected location of the uncompressed file.
+        path: PathBuf,
+    },
+
+    /// File could not be read or the checksum could not be recorded.
+    Io(String),
+
+    /// Graph of the catalog has no known checksum to verify the file against, to be compared with
+    /// the published one and set with `set_checksum`.
+    UnknownChecksum {
+        /// Checksum of the file.
+        actual: String,
+    },
+
+    /// Contents differ from the expected or previously recorded SHA-256 checksum.
+    ChecksumMismatch {
+        /// Expected checksum.
+        expected: String,
+
+        /// Checksum of the file.
+        actual: String,
+    },
+}
+
+/// Benchmark Corpus.
+/// Load standard benchmark graphs from a local corpus directory into a `LoadedGraph`, verifying
+/// their SHA-256 checksums against the catalog or `set_checksum`. The crate has no network client,
+/// so a missing file is reported with its download address. Files outside the catalog have their
+/// checksum pinned to a `<file>.sha256` sidecar on first load, and every later load must match it.
+#[derive(Debug, Clone)]
+pub struct BenchmarkCorpus {
+    /// Directory holding the uncompressed files.
+    pub directory: PathBuf,
+
+    /// Expected SHA-256 checksums by graph name, taking precedence over the sidecars.
+    pub checksums: BTreeMap<String, String>,
+}
+
+impl BenchmarkCorpus {
+    /// Create a new corpus over a directory.
+    ///
+    /// # Arguments
+    ///
+    /// - `directory`: Directory holding the uncompressed files.
+    ///
+    /// # Returns
+    ///
+    /// New corpus.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            checksums: BTreeMap::new(),
+        }
+    }
+
+    /// Set the expected checksum of a graph, such as one published with the dataset.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: Name of the graph.
+    /// - `sha256`: Hexadecimal SHA-256 checksum of the uncompressed file.
+    pub fn set_checksum(&mut self, name: &str, sha256: &str) {
+        self.checksums
+            .insert(name.to_string(), sha256.to_ascii_lowercase());
+    }
+
+    /// Find a graph of the catalog.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: Name of the graph.
+    ///
+    /// # Returns
+    ///
+    /// Graph of the catalog, or `None` if unknown.
+    pub fn graph(name: &str) -> Option<&'static BenchmarkGraph> {
+        BENCHMARK_GRAPHS.iter().find(|graph| graph.name == name)
+    }
+
+    /// Get the location of a graph in the corpus directory.
+    ///
+    /// # Arguments
+    ///
+    /// - `graph`: Graph of the catalog.
+    ///
+    /// # Returns
+    ///
+    /// Path of the uncompressed file.
+    pub fn path(&self, graph: &BenchmarkGraph) -> PathBuf {
+        self.directory.join(graph.file_name)
+    }
+
+    /// Load a graph of the catalog, verifying its checksum.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: Name of the graph.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the loaded graph, or an error if it is unknown, missing, corrupted or
+    /// without a known checksum.
+    pub fn load(&self, name: &str) -> Result<LoadedGraph, CorpusError> {
+        let graph = Self::graph(name).ok_or_else(|| CorpusError::UnknownGraph(name.to_string()))?;
+        let path = self.path(graph);
+
+        if !path.is_file() {
+            return Err(CorpusError::Missing {
+                url: graph.url.to_string(),
+                path,
+            });
+        }
+
+        let bytes = fs::read(&path).map_err(|error| CorpusError::Io(error.to_string()))?;
+        let actual = sha256(&bytes);
+
+        // A file without a known checksum is never trusted, it could be a truncated download.
+        let expected = match self.checksums.get(name) {
+            Some(expected) => expected.clone(),
+            None => match graph.sha256 {
+                Some(published) => published.to_ascii_lowercase(),
+                None => return Err(CorpusError::UnknownChecksum { actual }),
+            },
+        };
+
+        if expected != actual {
+            return Err(CorpusError::ChecksumMismatch { expected, actual });
+        }
+
+        Ok(Self::parse(&String::from_utf8_lossy(&bytes), graph.format))
+    }
+
+    /// Load a graph from any file in a benchmark format, pinning its checksum to a
+    /// `<file>.sha256` sidecar on first load.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: Path of the file.
+    /// - `format`: Format of the file.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the loaded graph, or an error if the file cannot be read or differs from
+    /// its pinned checksum.
+    pub fn load_file(path: &Path, format: CorpusFormat) -> Result<LoadedGraph, CorpusError> {
+        let bytes = fs::read(path).map_err(|error| CorpusError::Io(error.to_string()))?;
+        let actual = sha256(&bytes);
+        let mut sidecar = path.as_os_str().to_owned();
+        sidecar.push(".sha256");
+
+        match fs::read_to_string(&sidecar) {
+            Ok(pinned) if pinned.trim() != actual => {
+                return Err(CorpusError::ChecksumMismatch {
+                    expected: pinned.trim().to_string(),
+                    actual,
+                })
+            }
+            Ok(_) => {}
+            Err(_) => fs::write(&sidecar, format!("{actual}\n"))
+                .map_err(|error| CorpusError::Io(error.to_string()))?,
+        }
+
+        Ok(Self::parse(&String::from_utf8_lossy(&bytes), format))
+    }
+
+    /// Parse the contents of a file, reporting malformed records in the load report.
+    ///
+    /// # Arguments
+    ///
+    /// - `contents`: Contents of the file.
+    /// - `format`: Format of the file.
+    ///
+    /// # Returns
+    ///
+    /// Loaded graph with 0-based nodes.
+    pub fn parse(contents: &str, format: CorpusFormat) -> LoadedGraph {
+        let mut loader = BulkLoader::new();
+
+        match format {
+            CorpusFormat::Snap => loader.load(contents.lines()),
+            CorpusFormat::Dimacs => {
+                // Arcs are renumbered from 1-based, the problem line only sets the node count.
+                let mut total_nodes = 0;
+                let records = contents.lines().map(|line| {
+                    let fields: Vec<&str> = line.split_whitespace().collect();
+
+                    match fields.as_slice() {
+                        ["p", _, nodes, ..] => {
+                            total_nodes = nodes.parse().unwrap_or(0);
+                            String::new()
+                        }
+                        ["a", source, target, weight] => {
+                            match (source.parse::<usize>(), target.parse::<usize>()) {
+                                (Ok(source), Ok(target)) if source > 0 && target > 0 => {
+                                    format!("{} {} {weight}", source - 1, target - 1)
+                                }
+                                _ => line.to_string(),
+                            }
+                        }
+                        ["a", ..] => line.to_string(),
+                        _ => String::new(),
+                    }
+                });
+
+                loader.set_allow_negative_weights(true);
+                let mut graph = loader.load(records.collect::<Vec<_>>());
+                graph.total_nodes = graph.total_nodes.max(total_nodes);
+                graph
+            }
+        }
+    }
+}
+
+/// Compute the SHA-256 digest of bytes.
+///
+/// # Arguments
+///
+/// - `bytes`: Bytes to hash.
+///
+/// # Returns
+///
+/// Hexadecimal digest.
+fn sha256(bytes: &[u8]) -> String {
+    const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4,
+        0xab1c5ed5, 0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe,
+        0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f,
+        0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7,
+        0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc,
+        0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b,
+        0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116,
+        0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+        0xc67178f2,
+    ];
+
+    let mut state: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+
+    // Pad with a one bit, zeros and the bit length to a multiple of 64 bytes.
+    let mut message = bytes.to_vec();
+    message.push(0x80);
+
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+
+    message.extend_from_slice(&(bytes.len() as u64 * 8).to_be_bytes());
+
+    for block in message.chunks_exact(64) {
+        let mut words = [0u32; 64];
+
+        for (word, chunk) in words.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+
+        for i in 16..64 {
+            let s0 = words[i - 15].rotate_right(7)
+                ^ words[i - 15].rotate_right(18)
+                ^ (words[i - 15] >> 3);
+            let s1 = words[i - 2].rotate_right(17)
+                ^ words[i - 2].rotate_right(19)
+                ^ (words[i - 2] >> 10);
+            words[i] = words[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(words[i - 7])
+                .wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+
+        for (&k, &word) in K.iter().zip(&words) {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let choice = (e & f) ^ (!e & g);
+            let t1 = h
+                .wrapping_add(s1)
+                .wrapping_add(choice)
+                .wrapping_add(k)
+                .wrapping_add(word);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let majority = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(majority);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+
+        for (value, added) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *value = value.wrapping_add(added);
+        }
+    }
+
+    state.iter().fold(String::new(), |mut digest, word| {
+        let _ = write!(digest, "{word:08x}");
+        digest
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Road network in the DIMACS format, with an isolated fourth node.
+    const DIMACS: &str = "c tiny road network\np sp 4 3\na 1 2 7\na 2 3 3\na 3 1 5\n";
+
+    /// Fresh corpus directory for a test.
+    fn directory(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("graph-corpus-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(
+            sha256(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            sha256(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            sha256(&[b'a'; 1000]),
+            "41edece42d63e8d9bf515a9ba6932e1c20cbc9f5a5d134645adb5db1b9737ea3"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let graph = BenchmarkCorpus::parse(DIMACS, CorpusFormat::Dimacs);
+
+        assert_eq!(graph.edges, vec![(0, 1, 7), (1, 2, 3), (2, 0, 5)]);
+        assert_eq!(graph.total_nodes, 4);
+        assert!(graph.report.is_clean());
+
+        let graph = BenchmarkCorpus::parse("# Nodes: 3\n0\t2\n2\t1\nx 1\n", CorpusFormat::Snap);
+
+        assert_eq!(graph.edges, vec![(0, 2, 1), (2, 1, 1)]);
+        assert_eq!(graph.report.errors.len(), 1);
+    }
+
+    #[test]
+    fn test_load() {
+        let directory = directory("load");
+        let mut corpus = BenchmarkCorpus::new(&directory);
+
+        assert_eq!(
+            corpus.load("unknown"),
+            Err(CorpusError::UnknownGraph("unknown".to_string()))
+        );
+        assert_eq!(
+            corpus.load("USA-road-d.NY"),
+            Err(CorpusError::Missing {
+                url: BENCHMARK_GRAPHS[0].url.to_string(),
+                path: directory.join("USA-road-d.NY.gr"),
+            })
+        );
+
+        // A file without a known checksum is not trusted, nor pinned.
+        let path = directory.join("USA-road-d.NY.gr");
+        fs::write(&path, DIMACS).unwrap();
+
+        assert_eq!(
+            corpus.load("USA-road-d.NY"),
+            Err(CorpusError::UnknownChecksum {
+                actual: sha256(DIMACS.as_bytes()),
+            })
+        );
+        assert!(!directory.join("USA-road-d.NY.gr.sha256").exists());
+
+        corpus.set_checksum("USA-road-d.NY", &sha256(DIMACS.as_bytes()).to_uppercase());
+
+        assert_eq!(corpus.load("USA-road-d.NY").unwrap().edges.len(), 3);
+
+        fs::write(&path, "p sp 1 0\n").unwrap();
+
+        assert!(matches!(
+            corpus.load("USA-road-d.NY"),
+            Err(CorpusError::ChecksumMismatch { .. })
+        ));
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_load_file() {
+        let directory = directory("load-file");
+        let path = directory.join("road.gr");
+
+        assert!(matches!(
+            BenchmarkCorpus::load_file(&path, CorpusFormat::Dimacs),
+            Err(CorpusError::Io(_))
+        ));
+
+        // The first load pins the checksum, a changed file is then rejected.
+        fs::write(&path, DIMACS).unwrap();
+
+        assert_eq!(
+            BenchmarkCorpus::load_file(&path, CorpusFormat::Dimacs)
+                .unwrap()
+                .edges
+                .len(),
+            3
+        );
+        assert!(directory.join("road.gr.sha256").is_file());
+
+        fs::write(&path, "p sp 1 0\n").unwrap();
+
+        assert_eq!(
+            BenchmarkCorpus::load_file(&path, CorpusFormat::Dimacs),
+            Err(CorpusError::ChecksumMismatch {
+                expected: sha256(DIMACS.as_bytes()),
+                actual: sha256(b"p sp 1 0\n"),
+            })
+        );
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
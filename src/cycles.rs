@@ -0,0 +1,387 @@
+use std::collections::BTreeSet;
+
+use crate::{scc::strongly_connected_components, GraphAlgorithm, GraphError};
+
+/// Cycle Enumeration (Johnson's Algorithm).
+/// Enumerate every elementary cycle of a directed graph, each once, starting at its smallest node.
+/// For every start node the search stays within its strongly connected component among the larger
+/// nodes, and blocks nodes that cannot lead back to the start until a cycle through them is found.
+/// With a maximum length, blocking is replaced by a depth-bounded search.
+#[derive(Debug, Clone)]
+pub struct CycleEnumerationAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Successors of each node.
+    pub adjacency: Vec<BTreeSet<usize>>,
+
+    /// Maximum number of edges of a cycle, unbounded if `None`.
+    pub max_length: Option<usize>,
+}
+
+/// Iterator over the elementary cycles of a graph.
+#[derive(Debug, Clone)]
+pub struct SimpleCycles<'a> {
+    /// Graph to search.
+    algorithm: &'a CycleEnumerationAlgorithm,
+
+    /// Successors of each node, as a vector for indexed access.
+    successors: Vec<Vec<usize>>,
+
+    /// Next start node to search from.
+    next_start: usize,
+
+    /// Current start node, the smallest node of the cycles being enumerated.
+    start: usize,
+
+    /// Whether each node belongs to the component of the current start.
+    component: Vec<bool>,
+
+    /// Whether each node is blocked.
+    blocked: Vec<bool>,
+
+    /// Nodes to unblock together with each node.
+    blocked_by: Vec<BTreeSet<usize>>,
+
+    /// Current path from the start.
+    path: Vec<usize>,
+
+    /// Position of the next successor and whether a cycle was found below, for every node on the path.
+    frames: Vec<(usize, bool)>,
+}
+
+impl Default for CycleEnumerationAlgorithm {
+    /// Create a new default instance of Johnson's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of Johnson's Algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CycleEnumerationAlgorithm {
+    /// Create a new instance of Johnson's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of Johnson's Algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+            max_length: None,
+        }
+    }
+
+    /// Set a single directed edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].insert(target);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its successors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, BTreeSet::new());
+    }
+
+    /// Set the maximum length of the enumerated cycles.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_length`: Maximum number of edges of a cycle, or `None` for no bound.
+    pub fn set_max_length(&mut self, max_length: Option<usize>) {
+        self.max_length = max_length;
+    }
+
+    /// Enumerate the elementary cycles lazily.
+    ///
+    /// # Returns
+    ///
+    /// Iterator over the cycles, each starting at its smallest node, in order of that node.
+    pub fn cycles(&self) -> SimpleCycles<'_> {
+        let n = self.total_nodes;
+
+        SimpleCycles {
+            algorithm: self,
+            successors: self
+                .adjacency
+                .iter()
+                .map(|targets| targets.iter().copied().collect())
+                .collect(),
+            next_start: 0,
+            start: 0,
+            component: vec![false; n],
+            blocked: vec![false; n],
+            blocked_by: vec![BTreeSet::new(); n],
+            path: Vec::new(),
+            frames: Vec::new(),
+        }
+    }
+}
+
+impl SimpleCycles<'_> {
+    /// Prepare the search from the next start node lying on a cycle.
+    ///
+    /// # Returns
+    ///
+    /// `false` once every start node has been searched.
+    fn next_component(&mut self) -> bool {
+        let n = self.algorithm.total_nodes;
+
+        while self.next_start < n {
+            let start = self.next_start;
+            self.next_start += 1;
+
+            // Strongly connected components of the subgraph induced by the nodes from the start on.
+            let induced: Vec<Vec<usize>> = (0..n)
+                .map(|node| match node >= start {
+                    true => self.successors[node]
+                        .iter()
+                        .copied()
+                        .filter(|&target| target >= start)
+                        .collect(),
+                    false => Vec::new(),
+                })
+                .collect();
+            let members = strongly_connected_components(&induced)
+                .into_iter()
+                .find(|members| members.contains(&start))
+                .unwrap_or_default();
+
+            if members.len() == 1 && !self.algorithm.adjacency[start].contains(&start) {
+                continue;
+            }
+
+            self.start = start;
+            self.component.iter_mut().for_each(|flag| *flag = false);
+            members.iter().for_each(|&node| {
+                self.component[node] = true;
+                self.blocked[node] = false;
+                self.blocked_by[node].clear();
+            });
+            self.path = vec![start];
+            self.frames = vec![(0, false)];
+            self.blocked[start] = true;
+
+            return true;
+        }
+
+        false
+    }
+
+    /// Unblock a node and, recursively, the nodes waiting on it.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node to unblock.
+    fn unblock(&mut self, node: usize) {
+        let mut stack = vec![node];
+
+        while let Some(node) = stack.pop() {
+            if self.blocked[node] {
+                self.blocked[node] = false;
+                stack.extend(std::mem::take(&mut self.blocked_by[node]));
+            }
+        }
+    }
+}
+
+impl Iterator for SimpleCycles<'_> {
+    /// Type of item.
+    type Item = Vec<usize>;
+
+    /// Find the next cycle.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of the next cycle, or `None` once all cycles were enumerated.
+    fn next(&mut self) -> Option<Self::Item> {
+        let bounded = self.algorithm.max_length;
+
+        loop {
+            let Some(&node) = self.path.last() else {
+                if !self.next_component() {
+                    return None;
+                }
+
+                continue;
+            };
+            let depth = self.frames.len() - 1;
+            let (index, _) = self.frames[depth];
+
+            if let Some(&target) = self.successors[node].get(index) {
+                self.frames[depth].0 += 1;
+
+                if !self.component[target] {
+                    continue;
+                }
+
+                if target == self.start {
+                    self.frames[depth].1 = true;
+                    return Some(self.path.clone());
+                }
+
+                if !self.blocked[target] && bounded.map_or(true, |max| self.path.len() < max) {
+                    self.path.push(target);
+                    self.frames.push((0, false));
+                    self.blocked[target] = true;
+                }
+
+                continue;
+            }
+
+            // Every successor was explored: unblock the node if it leads back to the start,
+            // or let its successors unblock it later.
+            let (_, found) = self.frames.pop()?;
+            self.path.pop();
+
+            if found || bounded.is_some() {
+                self.unblock(node);
+            } else {
+                for &target in &self.successors[node] {
+                    if self.component[target] {
+                        self.blocked_by[target].insert(node);
+                    }
+                }
+            }
+
+            if let Some(parent) = self.frames.last_mut() {
+                parent.1 |= found;
+            }
+        }
+    }
+}
+
+impl GraphAlgorithm for CycleEnumerationAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<Vec<usize>>;
+
+    /// Run Johnson's Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in Johnson's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing every elementary cycle.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.cycles().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Random;
+
+    #[test]
+    fn test_new() {
+        let algorithm = CycleEnumerationAlgorithm::new();
+        let algorithm_default = CycleEnumerationAlgorithm::default();
+
+        assert_eq!(algorithm.max_length, None);
+        assert_eq!(algorithm_default.run(None), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = CycleEnumerationAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![0, 1, 2]),
+            (1, vec![2]),
+            (2, vec![0, 1, 3]),
+            (3, vec![4]),
+            (4, vec![3]),
+        ]);
+
+        assert_eq!(
+            algorithm.run(None).unwrap(),
+            vec![vec![0], vec![0, 1, 2], vec![0, 2], vec![1, 2], vec![3, 4],]
+        );
+
+        algorithm.set_max_length(Some(2));
+
+        assert_eq!(
+            algorithm.cycles().collect::<Vec<_>>(),
+            vec![vec![0], vec![0, 2], vec![1, 2], vec![3, 4]]
+        );
+        assert_eq!(algorithm.cycles().next(), Some(vec![0]));
+    }
+
+    #[test]
+    fn test_run_complete() {
+        // A complete directed graph on 5 nodes has sum over k of C(5, k) * (k - 1)! = 84 cycles.
+        let mut algorithm = CycleEnumerationAlgorithm::new();
+
+        for source in 0..5 {
+            for target in 0..5 {
+                if source != target {
+                    algorithm.set_edge(source, target);
+                }
+            }
+        }
+
+        let cycles = algorithm.run(None).unwrap();
+        let unique: BTreeSet<Vec<usize>> = cycles.iter().cloned().collect();
+
+        assert_eq!(cycles.len(), 84);
+        assert_eq!(unique.len(), 84);
+
+        algorithm.set_max_length(Some(3));
+
+        assert_eq!(algorithm.cycles().count(), 10 + 20);
+    }
+
+    #[test]
+    fn test_run_random() {
+        let mut random = Random::new(9);
+
+        for _ in 0..10 {
+            let mut algorithm = CycleEnumerationAlgorithm::new();
+            algorithm.set_total_nodes(8);
+
+            for _ in 0..16 {
+                algorithm.set_edge(random.below(8), random.below(8));
+            }
+
+            let bounded = {
+                let mut bounded = algorithm.clone();
+                bounded.set_max_length(Some(8));
+                bounded.cycles().collect::<Vec<_>>()
+            };
+
+            assert_eq!(algorithm.run(None).unwrap(), bounded);
+        }
+    }
+}
@@ -0,0 +1,389 @@
+use std::cmp::Reverse;
+
+use crate::{GraphAlgorithm, GraphError, TopologicalSortAlgorithm};
+
+/// DAG Lowest Common Ancestors.
+/// Find all lowest common ancestors of two nodes of a directed acyclic graph, where an edge leads
+/// from a parent to its child. A common ancestor is lowest if none of its descendants is also
+/// a common ancestor; unlike in a tree there may be several.
+#[derive(Debug, Clone)]
+pub struct DagLcaAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Children of each node.
+    pub adjacency: Vec<Vec<usize>>,
+}
+
+impl Default for DagLcaAlgorithm {
+    /// Create a new default instance of the DAG lowest common ancestors.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the DAG lowest common ancestors.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DagLcaAlgorithm {
+    /// Create a new instance of the DAG lowest common ancestors.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the DAG lowest common ancestors.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+        }
+    }
+
+    /// Set a single directed edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Parent node.
+    /// - `target`: Child node.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].push(target);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its children.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the parents of a commit, as recorded in a version-control history.
+    ///
+    /// # Arguments
+    ///
+    /// - `commit`: Commit node.
+    /// - `parents`: Parent commits, each getting an edge to the commit.
+    pub fn set_parents(&mut self, commit: usize, parents: Vec<usize>) {
+        self.set_total_nodes(commit + 1);
+
+        for parent in parents {
+            self.set_edge(parent, commit);
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, Vec::new());
+    }
+
+    /// Collect the parents of each node.
+    ///
+    /// # Returns
+    ///
+    /// Parents of each node.
+    fn parents(&self) -> Vec<Vec<usize>> {
+        let mut parents = vec![Vec::new(); self.total_nodes];
+
+        for (source, targets) in self.adjacency.iter().enumerate() {
+            for &target in targets {
+                parents[target].push(source);
+            }
+        }
+
+        parents
+    }
+
+    /// Mark the nodes that can reach a node, including the node itself.
+    ///
+    /// # Arguments
+    ///
+    /// - `parents`: Parents of each node.
+    /// - `node`: Node.
+    ///
+    /// # Returns
+    ///
+    /// Whether each node is an ancestor of the node.
+    fn ancestors(parents: &[Vec<usize>], node: usize) -> Vec<bool> {
+        let mut ancestors = vec![false; parents.len()];
+        let mut stack = vec![node];
+        ancestors[node] = true;
+
+        while let Some(current) = stack.pop() {
+            for &parent in &parents[current] {
+                if !ancestors[parent] {
+                    ancestors[parent] = true;
+                    stack.push(parent);
+                }
+            }
+        }
+
+        ancestors
+    }
+
+    /// Find all lowest common ancestors of two nodes. A node counts as its own ancestor,
+    /// so if one node is an ancestor of the other, it is the only result.
+    ///
+    /// # Arguments
+    ///
+    /// - `first`: First node.
+    /// - `second`: Second node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the lowest common ancestors in ascending order, empty if the nodes share
+    /// no ancestor, or an error if a node is unknown or the graph has a cycle.
+    pub fn lowest_common_ancestors(
+        &self,
+        first: usize,
+        second: usize,
+    ) -> Result<Vec<usize>, GraphError> {
+        if first >= self.total_nodes {
+            return Err(GraphError::MissingStartNode);
+        }
+
+        if second >= self.total_nodes {
+            return Err(GraphError::MissingTargetNode);
+        }
+
+        let mut sort = TopologicalSortAlgorithm::new();
+        sort.set_total_nodes(self.total_nodes);
+        sort.adjacency = self.adjacency.clone();
+        sort.run(None)?;
+
+        let parents = self.parents();
+
+        let of_first = Self::ancestors(&parents, first);
+        let of_second = Self::ancestors(&parents, second);
+        let common: Vec<bool> = of_first
+            .iter()
+            .zip(&of_second)
+            .map(|(a, b)| a & b)
+            .collect();
+
+        // Every node on a path between two common ancestors is a common ancestor too,
+        // so a common ancestor is lowest exactly when none of its children is common.
+        Ok((0..self.total_nodes)
+            .filter(|&node| common[node])
+            .filter(|&node| !self.adjacency[node].iter().any(|&child| common[child]))
+            .collect())
+    }
+
+    /// Check whether a node is an ancestor of another, like `git merge-base --is-ancestor`.
+    ///
+    /// # Arguments
+    ///
+    /// - `ancestor`: Possible ancestor.
+    /// - `node`: Node.
+    ///
+    /// # Returns
+    ///
+    /// Whether the first node can reach the second, a node being its own ancestor.
+    pub fn is_ancestor(&self, ancestor: usize, node: usize) -> bool {
+        node < self.total_nodes
+            && ancestor < self.total_nodes
+            && Self::ancestors(&self.parents(), node)[ancestor]
+    }
+
+    /// Find all merge bases of two commits, like `git merge-base --all`: the best common ancestors,
+    /// i.e. the lowest common ancestors. With a criss-cross history there are several; they are ordered
+    /// by generation, the length of the longest path from a root, newest first, then by node.
+    ///
+    /// # Arguments
+    ///
+    /// - `first`: First commit.
+    /// - `second`: Second commit.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the merge bases, best first, or an error if a commit is unknown
+    /// or the history has a cycle.
+    pub fn merge_bases(&self, first: usize, second: usize) -> Result<Vec<usize>, GraphError> {
+        let mut bases = self.lowest_common_ancestors(first, second)?;
+
+        if bases.len() > 1 {
+            let mut sort = TopologicalSortAlgorithm::new();
+            sort.set_total_nodes(self.total_nodes);
+            sort.adjacency = self.adjacency.clone();
+
+            let mut generations = vec![0; self.total_nodes];
+
+            for node in sort.run(None)? {
+                for &child in &self.adjacency[node] {
+                    generations[child] = generations[child].max(generations[node] + 1);
+                }
+            }
+
+            bases.sort_by_key(|&base| (Reverse(generations[base]), base));
+        }
+
+        Ok(bases)
+    }
+
+    /// Find the best merge base of two commits, like `git merge-base`. When there are several,
+    /// the first of [`DagLcaAlgorithm::merge_bases`] is chosen.
+    ///
+    /// # Arguments
+    ///
+    /// - `first`: First commit.
+    /// - `second`: Second commit.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the merge base, `None` if the histories are unrelated, or an error
+    /// if a commit is unknown or the history has a cycle.
+    pub fn merge_base(&self, first: usize, second: usize) -> Result<Option<usize>, GraphError> {
+        Ok(self.merge_bases(first, second)?.first().copied())
+    }
+}
+
+impl GraphAlgorithm for DagLcaAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<usize>;
+
+    /// Run the DAG lowest common ancestors.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Node whose ancestors are listed.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the ancestors of the node in ascending order, including the node itself,
+    /// or an error if the node is missing.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let start = start
+            .filter(|&start| start < self.total_nodes)
+            .ok_or(GraphError::MissingStartNode)?;
+
+        let parents = self.parents();
+
+        Ok(Self::ancestors(&parents, start)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(node, ancestor)| ancestor.then_some(node))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let algorithm = DagLcaAlgorithm::new();
+        let algorithm_default = DagLcaAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.adjacency.len(), 0);
+    }
+
+    #[test]
+    fn test_lowest_common_ancestors() {
+        // Criss-cross: 2 and 3 both descend from 0 and 1, and 4 and 5 both descend from 2 and 3.
+        let mut algorithm = DagLcaAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![2, 3]),
+            (1, vec![2, 3]),
+            (2, vec![4, 5]),
+            (3, vec![4, 5]),
+            (6, vec![1]),
+        ]);
+
+        assert_eq!(algorithm.lowest_common_ancestors(4, 5), Ok(vec![2, 3]));
+        assert_eq!(algorithm.lowest_common_ancestors(2, 3), Ok(vec![0, 1]));
+        assert_eq!(algorithm.lowest_common_ancestors(1, 4), Ok(vec![1]));
+        assert_eq!(algorithm.lowest_common_ancestors(0, 6), Ok(vec![]));
+        assert_eq!(algorithm.lowest_common_ancestors(5, 5), Ok(vec![5]));
+    }
+
+    #[test]
+    fn test_lowest_common_ancestors_errors() {
+        let mut algorithm = DagLcaAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1]), (1, vec![2]), (2, vec![1])]);
+
+        assert_eq!(
+            algorithm.lowest_common_ancestors(3, 0),
+            Err(GraphError::MissingStartNode)
+        );
+        assert_eq!(
+            algorithm.lowest_common_ancestors(0, 3),
+            Err(GraphError::MissingTargetNode)
+        );
+        assert_eq!(
+            algorithm.lowest_common_ancestors(0, 1),
+            Err(GraphError::CycleDetected)
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = DagLcaAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![2]), (1, vec![2]), (2, vec![3])]);
+
+        assert_eq!(algorithm.run(Some(3)), Ok(vec![0, 1, 2, 3]));
+        assert_eq!(algorithm.run(None), Err(GraphError::MissingStartNode));
+    }
+
+    #[test]
+    fn test_merge_base() {
+        // 0 - 1 - 2 on main, 3 branches from 1 and is merged into 4; 5 branches from 4, 6 from 2.
+        let mut algorithm = DagLcaAlgorithm::new();
+        algorithm.set_parents(1, vec![0]);
+        algorithm.set_parents(2, vec![1]);
+        algorithm.set_parents(3, vec![1]);
+        algorithm.set_parents(4, vec![2, 3]);
+        algorithm.set_parents(5, vec![4]);
+        algorithm.set_parents(6, vec![2]);
+        algorithm.set_total_nodes(8);
+
+        assert_eq!(algorithm.merge_base(5, 6), Ok(Some(2)));
+        assert_eq!(algorithm.merge_base(3, 6), Ok(Some(1)));
+        assert_eq!(algorithm.merge_base(4, 5), Ok(Some(4)));
+        assert_eq!(algorithm.merge_base(7, 5), Ok(None));
+        assert!(algorithm.is_ancestor(3, 5));
+        assert!(!algorithm.is_ancestor(6, 5));
+        assert!(!algorithm.is_ancestor(9, 5));
+    }
+
+    #[test]
+    fn test_merge_bases_criss_cross() {
+        // Two branches 2 and 3 from 1 merge into each other: 4 = merge(2, 3) and 5 = merge(3, 2),
+        // then 3 gets a further commit 6 which is merged into 7 = merge(4, 6).
+        let mut algorithm = DagLcaAlgorithm::new();
+        algorithm.set_parents(2, vec![1]);
+        algorithm.set_parents(3, vec![1]);
+        algorithm.set_parents(4, vec![2, 3]);
+        algorithm.set_parents(5, vec![3, 2]);
+        algorithm.set_parents(6, vec![3]);
+        algorithm.set_parents(7, vec![4, 6]);
+
+        assert_eq!(algorithm.merge_bases(4, 5), Ok(vec![2, 3]));
+        assert_eq!(algorithm.merge_bases(7, 5), Ok(vec![2, 3]));
+        assert_eq!(algorithm.merge_bases(6, 2), Ok(vec![1]));
+
+        // A newer commit on one side wins over an older one.
+        algorithm.set_parents(8, vec![3]);
+        algorithm.set_parents(9, vec![4, 8]);
+        algorithm.set_parents(10, vec![5, 8]);
+
+        assert_eq!(algorithm.merge_bases(9, 10), Ok(vec![8, 2]));
+        assert_eq!(algorithm.merge_base(9, 10), Ok(Some(8)));
+    }
+}
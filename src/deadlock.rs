@@ -0,0 +1,229 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    scc::strongly_connected_components, FeedbackVertexSetAlgorithm, GraphAlgorithm, GraphError,
+};
+
+/// Deadlock Detector.
+/// Detect deadlocks in a wait-for graph, where an edge leads from a waiting transaction to the
+/// transaction holding the lock it waits for, and choose victims whose abort breaks every cycle.
+#[derive(Debug, Clone)]
+pub struct DeadlockDetector {
+    /// Total number of transactions.
+    pub total_nodes: usize,
+
+    /// Transactions each transaction waits for.
+    pub waits: Vec<BTreeSet<usize>>,
+}
+
+impl Default for DeadlockDetector {
+    /// Create a new default instance of the deadlock detector.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the deadlock detector.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeadlockDetector {
+    /// Create a new instance of the deadlock detector.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the deadlock detector.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            waits: Vec::new(),
+        }
+    }
+
+    /// Set a lock wait.
+    ///
+    /// # Arguments
+    ///
+    /// - `waiter`: Waiting transaction.
+    /// - `holder`: Transaction holding the lock.
+    pub fn set_wait(&mut self, waiter: usize, holder: usize) {
+        self.set_total_nodes(waiter.max(holder) + 1);
+        self.waits[waiter].insert(holder);
+    }
+
+    /// Set multiple transactions' lock waits.
+    ///
+    /// # Arguments
+    ///
+    /// - `waits`: Vector of tuples where each tuple contains a transaction and the transactions it waits for.
+    pub fn set_waits(&mut self, waits: Vec<(usize, Vec<usize>)>) {
+        for (waiter, holders) in waits {
+            self.set_total_nodes(waiter + 1);
+
+            for holder in holders {
+                self.set_wait(waiter, holder);
+            }
+        }
+    }
+
+    /// Set the total number of transactions.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of transactions.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.waits.resize(self.total_nodes, BTreeSet::new());
+    }
+
+    /// Remove a transaction that committed or aborted, with all its waits.
+    ///
+    /// # Arguments
+    ///
+    /// - `transaction`: Finished transaction.
+    pub fn remove_transaction(&mut self, transaction: usize) {
+        if let Some(waits) = self.waits.get_mut(transaction) {
+            waits.clear();
+        }
+
+        for waits in &mut self.waits {
+            waits.remove(&transaction);
+        }
+    }
+
+    /// Find the groups of deadlocked transactions: the strongly connected components with a cycle.
+    ///
+    /// # Arguments
+    ///
+    /// - `removed`: Transactions to ignore.
+    ///
+    /// # Returns
+    ///
+    /// Deadlocked groups with their transactions in ascending order.
+    fn cycles(&self, removed: &[bool]) -> Vec<Vec<usize>> {
+        let adjacency: Vec<Vec<usize>> = (0..self.total_nodes)
+            .map(|waiter| match removed[waiter] {
+                true => Vec::new(),
+                false => self.waits[waiter]
+                    .iter()
+                    .copied()
+                    .filter(|&holder| !removed[holder])
+                    .collect(),
+            })
+            .collect();
+
+        let mut groups: Vec<Vec<usize>> = strongly_connected_components(&adjacency)
+            .into_iter()
+            .filter(|group| group.len() > 1 || adjacency[group[0]].contains(&group[0]))
+            .collect();
+        groups.sort_unstable();
+        groups
+    }
+
+    /// Find the groups of deadlocked transactions.
+    ///
+    /// # Returns
+    ///
+    /// Groups of transactions waiting on each other in a cycle, sorted.
+    pub fn deadlocks(&self) -> Vec<Vec<usize>> {
+        self.cycles(&vec![false; self.total_nodes])
+    }
+
+    /// Check whether any transactions are deadlocked.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the wait-for graph has a cycle.
+    pub fn has_deadlock(&self) -> bool {
+        !self.deadlocks().is_empty()
+    }
+
+    /// Choose victims whose abort breaks every deadlock, as a feedback vertex set of the wait-for graph.
+    ///
+    /// # Returns
+    ///
+    /// Victims in ascending order, none of which can be spared.
+    pub fn victims(&self) -> Vec<usize> {
+        let mut algorithm = FeedbackVertexSetAlgorithm::new();
+        algorithm.set_total_nodes(self.total_nodes);
+        algorithm.adjacency = self.waits.clone();
+
+        algorithm.feedback_vertex_set()
+    }
+}
+
+impl GraphAlgorithm for DeadlockDetector {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<usize>;
+
+    /// Run the deadlock detector.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the deadlock detector.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the victims to abort, empty if there is no deadlock.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.victims())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let detector = DeadlockDetector::new();
+        let detector_default = DeadlockDetector::default();
+
+        assert_eq!(detector.total_nodes, 0);
+        assert!(!detector_default.has_deadlock());
+    }
+
+    #[test]
+    fn test_deadlocks() {
+        let mut detector = DeadlockDetector::new();
+        detector.set_waits(vec![
+            (0, vec![1]),
+            (1, vec![2]),
+            (2, vec![0]),
+            (3, vec![0]),
+            (4, vec![4]),
+        ]);
+
+        assert!(detector.has_deadlock());
+        assert_eq!(detector.deadlocks(), vec![vec![0, 1, 2], vec![4]]);
+
+        detector.remove_transaction(1);
+
+        assert_eq!(detector.deadlocks(), vec![vec![4]]);
+    }
+
+    #[test]
+    fn test_run() {
+        // Two cycles sharing transaction 2: aborting it alone is enough.
+        let mut detector = DeadlockDetector::new();
+        detector.set_waits(vec![
+            (0, vec![1]),
+            (1, vec![2]),
+            (2, vec![0, 3]),
+            (3, vec![4]),
+            (4, vec![2]),
+            (5, vec![6]),
+            (6, vec![5]),
+        ]);
+
+        assert_eq!(detector.run(None), Ok(vec![2, 6]));
+
+        detector.remove_transaction(2);
+        detector.remove_transaction(6);
+
+        assert_eq!(detector.run(None), Ok(vec![]));
+    }
+}
@@ -0,0 +1,333 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, BinaryHeap},
+};
+
+use crate::{GraphAlgorithm, GraphError, MaxFlowAlgorithm};
+
+/// Maximum Density Subgraph (Goldberg's Algorithm).
+/// Find the set of nodes of an undirected graph maximizing the number of edges between them per node,
+/// such as a ring of accounts trading mostly with each other.
+#[derive(Debug, Clone)]
+pub struct DensestSubgraphAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Neighbors of each node.
+    pub adjacency: Vec<BTreeSet<usize>>,
+}
+
+/// Subgraph induced by a set of nodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DenseSubgraph {
+    /// Nodes of the subgraph, sorted.
+    pub nodes: Vec<usize>,
+
+    /// Number of edges between the nodes.
+    pub edges: usize,
+
+    /// Number of edges per node, zero for an empty subgraph.
+    pub density: f64,
+}
+
+impl Default for DensestSubgraphAlgorithm {
+    /// Create a new default instance of the densest subgraph algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the densest subgraph algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DensestSubgraphAlgorithm {
+    /// Create a new instance of the densest subgraph algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the densest subgraph algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+
+        if source != target {
+            self.adjacency[source].insert(target);
+            self.adjacency[target].insert(source);
+        }
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its neighbors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, BTreeSet::new());
+    }
+
+    /// Describe the subgraph induced by a set of nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Nodes of the subgraph, sorted.
+    ///
+    /// # Returns
+    ///
+    /// Subgraph with its number of edges and density.
+    pub fn subgraph(&self, nodes: Vec<usize>) -> DenseSubgraph {
+        let inside: BTreeSet<usize> = nodes.iter().copied().collect();
+        let edges = nodes
+            .iter()
+            .map(|&node| self.adjacency[node].intersection(&inside).count())
+            .sum::<usize>()
+            / 2;
+        let density = match nodes.len() {
+            0 => 0.0,
+            total => edges as f64 / total as f64,
+        };
+
+        DenseSubgraph {
+            nodes,
+            edges,
+            density,
+        }
+    }
+
+    /// Find a subgraph at least half as dense as the densest one by peeling (Charikar's Algorithm),
+    /// repeatedly removing a node of minimum degree and keeping the densest remaining graph.
+    ///
+    /// # Returns
+    ///
+    /// Subgraph whose density is at least half of the maximum density.
+    pub fn peeling(&self) -> DenseSubgraph {
+        let mut degrees: Vec<usize> = self.adjacency.iter().map(BTreeSet::len).collect();
+        let mut removed = vec![false; self.total_nodes];
+        let mut queue: BinaryHeap<Reverse<(usize, usize)>> = degrees
+            .iter()
+            .enumerate()
+            .map(|(node, &degree)| Reverse((degree, node)))
+            .collect();
+        let mut edges = degrees.iter().sum::<usize>() / 2;
+        let mut remaining = self.total_nodes;
+        let mut order = Vec::with_capacity(self.total_nodes);
+        let mut best = (0, 1, 0);
+
+        while let Some(Reverse((degree, node))) = queue.pop() {
+            if removed[node] || degree != degrees[node] {
+                continue;
+            }
+
+            // Compare densities `edges / remaining` as fractions.
+            if edges * best.1 > best.0 * remaining {
+                best = (edges, remaining, order.len());
+            }
+
+            removed[node] = true;
+            order.push(node);
+            edges -= degree;
+            remaining -= 1;
+
+            for &neighbor in &self.adjacency[node] {
+                if !removed[neighbor] {
+                    degrees[neighbor] -= 1;
+                    queue.push(Reverse((degrees[neighbor], neighbor)));
+                }
+            }
+        }
+
+        let mut nodes = match best.0 {
+            0 => Vec::new(),
+            _ => order.split_off(best.2),
+        };
+        nodes.sort_unstable();
+
+        self.subgraph(nodes)
+    }
+
+    /// Find the nodes maximizing `q·|E(S)| - p·|S|` with a minimum cut,
+    /// the subgraphs denser than `p / q` if any.
+    ///
+    /// # Arguments
+    ///
+    /// - `edges`: Number of edges of the graph.
+    /// - `p`: Numerator of the density to beat.
+    /// - `q`: Denominator of the density to beat.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the nodes, empty if no subgraph is denser than `p / q`, or an error.
+    fn denser_than(&self, edges: usize, p: usize, q: usize) -> Result<Vec<usize>, GraphError> {
+        let (source, sink) = (self.total_nodes, self.total_nodes + 1);
+        let mut flow = MaxFlowAlgorithm::new();
+        flow.set_terminals(source, sink);
+
+        // A cut of source side `S` costs `n·m·q + 2(p·|S| - q·|E(S)|)`.
+        for (node, neighbors) in self.adjacency.iter().enumerate() {
+            flow.set_edge(source, node, edges * q);
+            flow.set_edge(node, sink, edges * q + 2 * p - neighbors.len() * q);
+
+            for &neighbor in neighbors {
+                flow.set_edge(node, neighbor, q);
+            }
+        }
+
+        let cut = flow.minimum_cut()?;
+
+        Ok(cut
+            .source_side
+            .into_iter()
+            .filter(|&node| node != source)
+            .collect())
+    }
+}
+
+impl GraphAlgorithm for DensestSubgraphAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = DenseSubgraph;
+
+    /// Run the densest subgraph algorithm.
+    /// Starting from the density of the whole graph, every minimum cut finds a strictly denser
+    /// subgraph (Dinkelbach's iteration) until none exists.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the densest subgraph algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing a subgraph of maximum density, empty for a graph without edges, or an error.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let edges = self.adjacency.iter().map(BTreeSet::len).sum::<usize>() / 2;
+
+        if edges == 0 {
+            return Ok(self.subgraph(Vec::new()));
+        }
+
+        let mut best = self.subgraph((0..self.total_nodes).collect());
+
+        loop {
+            let nodes = self.denser_than(edges, best.edges, best.nodes.len())?;
+
+            if nodes.is_empty() {
+                return Ok(best);
+            }
+
+            best = self.subgraph(nodes);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Clique of five nodes with a sparse tail of a path and a star attached.
+    fn ring() -> DensestSubgraphAlgorithm {
+        let mut algorithm = DensestSubgraphAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 2, 3, 4]),
+            (1, vec![2, 3, 4]),
+            (2, vec![3, 4]),
+            (3, vec![4]),
+            (4, vec![5]),
+            (5, vec![6]),
+            (6, vec![7, 8, 9, 10]),
+        ]);
+        algorithm
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = DensestSubgraphAlgorithm::new();
+        let algorithm_default = DensestSubgraphAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.total_nodes, 0);
+    }
+
+    #[test]
+    fn test_run() {
+        let algorithm = ring();
+        let densest = algorithm.run(None).unwrap();
+
+        assert_eq!(densest.nodes, vec![0, 1, 2, 3, 4]);
+        assert_eq!(densest.edges, 10);
+        assert_eq!(densest.density, 2.0);
+    }
+
+    #[test]
+    fn test_run_is_maximum() {
+        // Wheel of six nodes around a hub, denser as a whole than any part of it.
+        let mut algorithm = DensestSubgraphAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 2, 3, 4, 5, 6]),
+            (1, vec![2]),
+            (2, vec![3]),
+            (3, vec![4]),
+            (4, vec![5]),
+            (5, vec![6]),
+            (6, vec![1]),
+        ]);
+
+        let densest = algorithm.run(None).unwrap();
+
+        assert_eq!(densest.nodes, (0..7).collect::<Vec<_>>());
+
+        for mask in 1..1usize << 7 {
+            let nodes = (0..7).filter(|&node| mask >> node & 1 == 1).collect();
+
+            assert!(algorithm.subgraph(nodes).density <= densest.density);
+        }
+    }
+
+    #[test]
+    fn test_peeling() {
+        let algorithm = ring();
+        let peeled = algorithm.peeling();
+
+        assert_eq!(peeled.nodes, vec![0, 1, 2, 3, 4]);
+        assert!(2.0 * peeled.density >= algorithm.run(None).unwrap().density);
+    }
+
+    #[test]
+    fn test_run_without_edges() {
+        let mut algorithm = DensestSubgraphAlgorithm::new();
+        algorithm.set_total_nodes(3);
+
+        assert_eq!(algorithm.run(None).unwrap().nodes, Vec::<usize>::new());
+        assert_eq!(algorithm.peeling().nodes, Vec::<usize>::new());
+        assert_eq!(algorithm.peeling().density, 0.0);
+    }
+}
@@ -0,0 +1,297 @@
+use std::collections::VecDeque;
+
+use crate::{scc::strongly_connected_components, GraphAlgorithm, GraphError};
+
+/// Explanation of why the requested packages cannot be installed together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConflictExplanation {
+    /// Conflicting packages.
+    pub conflict: (usize, usize),
+
+    /// Dependency chain from a requested package to the first conflicting package.
+    pub first: Vec<usize>,
+
+    /// Dependency chain from a requested package to the second conflicting package.
+    pub second: Vec<usize>,
+}
+
+/// Dependency Resolver.
+/// Resolve the packages to install for a request, given dependencies and conflicting pairs such as
+/// two versions of the same package. As a 2-SAT problem every dependency is an implication and every
+/// conflict a negated pair; since all dependencies are mandatory, the smallest solution installs exactly
+/// the packages reachable from the request, and conflicts only decide whether it is feasible.
+/// Dependency cycles form strongly connected components that are installed together.
+#[derive(Debug, Clone)]
+pub struct DependencyResolver {
+    /// Total number of packages.
+    pub total_nodes: usize,
+
+    /// Dependencies of each package.
+    pub dependencies: Vec<Vec<usize>>,
+
+    /// Pairs of packages that cannot be installed together.
+    pub conflicts: Vec<(usize, usize)>,
+
+    /// Requested packages.
+    pub requested: Vec<usize>,
+}
+
+impl Default for DependencyResolver {
+    /// Create a new default instance of the dependency resolver.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the dependency resolver.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DependencyResolver {
+    /// Create a new instance of the dependency resolver.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the dependency resolver.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            dependencies: Vec::new(),
+            conflicts: Vec::new(),
+            requested: Vec::new(),
+        }
+    }
+
+    /// Set a single dependency.
+    ///
+    /// # Arguments
+    ///
+    /// - `package`: Dependent package.
+    /// - `dependency`: Package it requires.
+    pub fn set_dependency(&mut self, package: usize, dependency: usize) {
+        self.set_total_nodes(package.max(dependency) + 1);
+        self.dependencies[package].push(dependency);
+    }
+
+    /// Set the dependencies of multiple packages.
+    ///
+    /// # Arguments
+    ///
+    /// - `packages`: Vector of tuples where each tuple contains a package and its dependencies.
+    pub fn set_dependencies(&mut self, packages: Vec<(usize, Vec<usize>)>) {
+        for (package, dependencies) in packages {
+            self.set_total_nodes(package + 1);
+
+            for dependency in dependencies {
+                self.set_dependency(package, dependency);
+            }
+        }
+    }
+
+    /// Set a pair of packages that cannot be installed together.
+    ///
+    /// # Arguments
+    ///
+    /// - `first`: First package.
+    /// - `second`: Second package.
+    pub fn set_conflict(&mut self, first: usize, second: usize) {
+        self.set_total_nodes(first.max(second) + 1);
+        self.conflicts.push((first, second));
+    }
+
+    /// Set the requested packages.
+    ///
+    /// # Arguments
+    ///
+    /// - `packages`: Packages to install.
+    pub fn set_requested(&mut self, packages: Vec<usize>) {
+        for &package in &packages {
+            self.set_total_nodes(package + 1);
+        }
+
+        self.requested = packages;
+    }
+
+    /// Set the total number of packages.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of packages.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.dependencies.resize(self.total_nodes, Vec::new());
+    }
+
+    /// Follow the parents of a breadth-first search back to a requested package.
+    ///
+    /// # Arguments
+    ///
+    /// - `parents`: Package through which each package was first required.
+    /// - `package`: Package to explain.
+    ///
+    /// # Returns
+    ///
+    /// Chain of packages from a requested package to the package.
+    fn chain(parents: &[usize], package: usize) -> Vec<usize> {
+        let mut chain = vec![package];
+
+        while let Some(&parent) = chain.last().map(|&last| &parents[last]) {
+            if parent == usize::MAX {
+                break;
+            }
+
+            chain.push(parent);
+        }
+
+        chain.reverse();
+        chain
+    }
+
+    /// Resolve the request.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the groups of packages in installation order, dependencies first, where
+    /// a group holds more than one package only for a dependency cycle, or the conflict with the
+    /// shortest dependency chains.
+    pub fn resolve(&self) -> Result<Vec<Vec<usize>>, ConflictExplanation> {
+        let n = self.total_nodes;
+        let mut depths = vec![usize::MAX; n];
+        let mut parents = vec![usize::MAX; n];
+        let mut queue = VecDeque::new();
+
+        for &package in &self.requested {
+            if depths[package] == usize::MAX {
+                depths[package] = 0;
+                queue.push_back(package);
+            }
+        }
+
+        while let Some(package) = queue.pop_front() {
+            for &dependency in &self.dependencies[package] {
+                if depths[dependency] == usize::MAX {
+                    depths[dependency] = depths[package] + 1;
+                    parents[dependency] = package;
+                    queue.push_back(dependency);
+                }
+            }
+        }
+
+        let conflict = self
+            .conflicts
+            .iter()
+            .filter(|&&(first, second)| depths[first] != usize::MAX && depths[second] != usize::MAX)
+            .min_by_key(|&&(first, second)| (depths[first] + depths[second], first, second));
+
+        if let Some(&(first, second)) = conflict {
+            return Err(ConflictExplanation {
+                conflict: (first, second),
+                first: Self::chain(&parents, first),
+                second: Self::chain(&parents, second),
+            });
+        }
+
+        // Components come out with dependencies before their dependents.
+        let installed: Vec<Vec<usize>> = (0..n)
+            .map(|package| match depths[package] {
+                usize::MAX => Vec::new(),
+                _ => self.dependencies[package].clone(),
+            })
+            .collect();
+
+        Ok(strongly_connected_components(&installed)
+            .into_iter()
+            .filter(|group| depths[group[0]] != usize::MAX)
+            .collect())
+    }
+}
+
+impl GraphAlgorithm for DependencyResolver {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<usize>;
+
+    /// Run the dependency resolver.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Package to request in addition to the requested packages, if any.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the packages in installation order, or an error if the request is infeasible.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let mut resolver = self.clone();
+
+        if let Some(start) = start {
+            let mut requested = resolver.requested.clone();
+            requested.push(start);
+            resolver.set_requested(requested);
+        }
+
+        resolver
+            .resolve()
+            .map(|groups| groups.into_iter().flatten().collect())
+            .map_err(|_| GraphError::Infeasible)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let resolver = DependencyResolver::new();
+        let resolver_default = DependencyResolver::default();
+
+        assert_eq!(resolver.total_nodes, 0);
+        assert_eq!(resolver_default.resolve(), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_resolve() {
+        // 0 = app, 1 = web, 2 = json, 3 = log, 4 = log-compat, 5 = unused.
+        let mut resolver = DependencyResolver::new();
+        resolver.set_dependencies(vec![
+            (0, vec![1, 2]),
+            (1, vec![2, 3]),
+            (3, vec![4]),
+            (4, vec![3]),
+        ]);
+        resolver.set_total_nodes(6);
+        resolver.set_requested(vec![0]);
+
+        assert_eq!(
+            resolver.resolve(),
+            Ok(vec![vec![2], vec![3, 4], vec![1], vec![0]])
+        );
+        assert_eq!(resolver.run(Some(5)), Ok(vec![2, 3, 4, 1, 0, 5]));
+    }
+
+    #[test]
+    fn test_resolve_conflict() {
+        // 0 and 1 are requested; they need versions 3 and 4 of the same library through 2.
+        let mut resolver = DependencyResolver::new();
+        resolver.set_dependencies(vec![(0, vec![2]), (2, vec![3]), (1, vec![4]), (5, vec![6])]);
+        resolver.set_conflict(3, 4);
+        resolver.set_conflict(5, 6);
+        resolver.set_requested(vec![0, 1]);
+
+        assert_eq!(
+            resolver.resolve(),
+            Err(ConflictExplanation {
+                conflict: (3, 4),
+                first: vec![0, 2, 3],
+                second: vec![1, 4],
+            })
+        );
+        assert_eq!(resolver.run(None), Err(GraphError::Infeasible));
+
+        resolver.set_requested(vec![0]);
+
+        assert_eq!(resolver.run(None), Ok(vec![3, 2, 0]));
+    }
+}
@@ -1,26 +1,114 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 use std::{
-    cmp::Ordering,
-    collections::{BinaryHeap, HashMap},
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, HashSet},
 };
 
-use crate::{GraphAlgorithm, GraphError};
+use crate::{
+    priority_queue::{BinaryQueue, FourAryQueue, MinQueue, Ordered, PairingQueue, RadixQueue},
+    AlgorithmEvent, ConfiguredAlgorithm, DiGraph, EventSink, GraphAlgorithm, GraphError,
+    MemoryEstimate, MemoryEstimation, ObservableAlgorithm, Provenance, ShortestPaths, Weight,
+};
 
 /// Dijkstra's Algorithm.
 /// Find the shortest path from a starting node to all other nodes in a weighted graph.
 #[derive(Debug, Clone)]
-pub struct DijkstraAlgorithm {
+pub struct DijkstraAlgorithm<W = usize> {
     /// Graph to search.
-    pub graph: HashMap<usize, Vec<(usize, usize)>>,
+    pub graph: HashMap<usize, Vec<(usize, W)>>,
+
+    /// Rule choosing among shortest paths of equal cost.
+    pub tie_breaking: TieBreaking,
+
+    /// Previously returned path that new shortest paths are biased toward.
+    pub preferred_path: Vec<usize>,
+
+    /// Extra cost of every edge off the preferred path when searching.
+    pub deviation_penalty: W,
+
+    /// Priority queue backing the search.
+    pub queue: PriorityQueueKind,
+
+    /// Attribute bits of the edges, e.g. unpaved or residential, keyed by source and target.
+    pub edge_tags: HashMap<(usize, usize), u64>,
+
+    /// Soft constraints penalizing tagged edges when searching shortest paths.
+    pub soft_constraints: Vec<SoftConstraint>,
+}
+
+/// Soft constraint making edges with some attribute more expensive, e.g. unpaved roads +20%.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SoftConstraint {
+    /// Attribute bits of the penalized edges, matching edges with any of the bits.
+    pub tags: u64,
+
+    /// Penalty in percent of the edge weight, added up over all matching constraints.
+    pub percent: usize,
+}
+
+/// Priority queue backing the search. All queues give the same distances but favor different workloads.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PriorityQueueKind {
+    /// Choose a queue from the size of the graph and the range of its weights.
+    #[default]
+    Auto,
+
+    /// Binary heap, a good default for small and sparse graphs.
+    Binary,
+
+    /// 4-ary heap, with cheaper pushes for graphs with many edges per node.
+    FourAry,
+
+    /// Pairing heap, with constant time pushes.
+    Pairing,
+
+    /// Radix heap over the bits of integer distances, fast for large graphs with small weights.
+    Radix,
 }
 
+/// Rule choosing among several shortest paths of equal cost, so that routes stay stable across runs.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum TieBreaking {
+    /// Keep the first path found by the search.
+    #[default]
+    FirstFound,
+
+    /// Prefer the path with the fewest edges.
+    FewerHops,
+
+    /// Prefer the lexicographically smallest sequence of nodes.
+    SmallerNode,
+
+    /// Prefer the path with the smallest total penalty, given for each step as
+    /// `penalty(previous, node, next)`, e.g. the turn angle at `node` for straighter routes.
+    Penalty(fn(Option<usize>, usize, usize) -> usize),
+}
+
+/// Search state of a tie-broken path: node and previous node, if the penalty depends on it.
+type PathState = (usize, Option<usize>);
+
+/// Cost and nodes of a path.
+pub type CostPath<W = usize> = (W, Vec<usize>);
+
 /// State of the algorithm.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-struct State {
+pub(crate) struct State {
     /// Cost of the path.
-    cost: usize,
+    pub(crate) cost: usize,
 
     /// Position of the node.
-    position: usize,
+    pub(crate) position: usize,
+}
+
+/// Minimal cost to reach a node and the fewest edges of a path with that cost.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct HopCost<W = usize> {
+    /// Fewest edges among the paths of minimal cost to the node.
+    pub hops: usize,
+
+    /// Minimal total weight to reach the node.
+    pub cost: W,
 }
 
 impl Ord for State {
@@ -56,6 +144,94 @@ impl PartialOrd for State {
     }
 }
 
+/// Labels of the nodes reached by a search.
+#[derive(Debug, Clone)]
+struct Labels<W> {
+    /// Distance of each reached node, final once the node is settled.
+    distances: HashMap<usize, W>,
+
+    /// Predecessor of each reached node but the start.
+    predecessors: HashMap<usize, usize>,
+
+    /// Number of edges from the start of each reached node, the fewest among equal distances.
+    hops: HashMap<usize, usize>,
+
+    /// Nodes whose distances are final.
+    settled: HashSet<usize>,
+}
+
+impl<W: Weight> Labels<W> {
+    /// Get the distances of the nodes numbered below a count.
+    ///
+    /// # Arguments
+    ///
+    /// - `n`: Number of nodes.
+    ///
+    /// # Returns
+    ///
+    /// Distance of each node, `Weight::INFINITY` if it was not settled.
+    fn distances(&self, n: usize) -> Vec<W> {
+        (0..n)
+            .map(|node| self.distance(node).unwrap_or(W::INFINITY))
+            .collect()
+    }
+
+    /// Get the distance of a settled node.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node.
+    ///
+    /// # Returns
+    ///
+    /// Distance of the node, or `None` if it was not settled.
+    fn distance(&self, node: usize) -> Option<W> {
+        self.settled.contains(&node).then(|| self.distances[&node])
+    }
+
+    /// Rebuild the path to a settled node.
+    ///
+    /// # Arguments
+    ///
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Cost and nodes of the path, or `None` if the target was not settled.
+    fn path(&self, target: usize) -> Option<CostPath<W>> {
+        let cost = self.distance(target)?;
+        let mut path = vec![target];
+
+        while let Some(&parent) = self.predecessors.get(&path[path.len() - 1]) {
+            path.push(parent);
+        }
+
+        path.reverse();
+
+        Some((cost, path))
+    }
+
+    /// Turn the labels into the shortest paths from the start to the nodes numbered below a count.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `n`: Number of nodes.
+    ///
+    /// # Returns
+    ///
+    /// Shortest paths from the start.
+    fn shortest_paths(&self, start: usize, n: usize) -> ShortestPaths<W> {
+        ShortestPaths {
+            start,
+            distances: self.distances(n),
+            predecessors: (0..n)
+                .map(|node| self.predecessors.get(&node).copied().unwrap_or(usize::MAX))
+                .collect(),
+        }
+    }
+}
+
 impl Default for DijkstraAlgorithm {
     /// Create a new default instance of Dijkstra's Algorithm.
     ///
@@ -74,8 +250,27 @@ impl DijkstraAlgorithm {
     ///
     /// New instance of Dijkstra's Algorithm.
     pub fn new() -> Self {
+        Self::with_weight_type()
+    }
+}
+
+impl<W: Weight> DijkstraAlgorithm<W> {
+    /// Create a new instance of Dijkstra's Algorithm with weights of another type than `usize`,
+    /// such as `DijkstraAlgorithm::<f64>::with_weight_type()`. Searches reaching a negative weight
+    /// return `GraphError::NegativeWeight`.
+    ///
+    /// # Returns
+    ///
+    /// New instance of Dijkstra's Algorithm.
+    pub fn with_weight_type() -> Self {
         DijkstraAlgorithm {
             graph: HashMap::new(),
+            tie_breaking: TieBreaking::default(),
+            preferred_path: Vec::new(),
+            deviation_penalty: W::ZERO,
+            queue: PriorityQueueKind::default(),
+            edge_tags: HashMap::new(),
+            soft_constraints: Vec::new(),
         }
     }
 
@@ -85,7 +280,7 @@ impl DijkstraAlgorithm {
     ///
     /// - `node`: Node of the graph.
     /// - `edges`: Edges of the node.
-    pub fn set_node(&mut self, node: usize, edges: Vec<(usize, usize)>) {
+    pub fn set_node(&mut self, node: usize, edges: Vec<(usize, W)>) {
         self.graph.insert(node, edges);
     }
 
@@ -94,21 +289,48 @@ impl DijkstraAlgorithm {
     /// # Arguments
     ///
     /// - `nodes`: Vector of nodes and their edges.
-    pub fn set_nodes(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+    pub fn set_nodes(&mut self, nodes: Vec<(usize, Vec<(usize, W)>)>) {
         for (node, edges) in nodes {
             self.graph.insert(node, edges);
         }
     }
-}
 
-impl GraphAlgorithm for DijkstraAlgorithm {
-    /// Type of node.
-    type Node = usize;
+    /// Add a single directed edge to the graph, adding its nodes if needed.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn add_edge(&mut self, source: usize, target: usize, weight: W) {
+        self.graph.entry(source).or_default().push((target, weight));
+        self.graph.entry(target).or_default();
+    }
 
-    /// Type of weight.
-    type Weight = Vec<usize>;
+    /// Add directed edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `edges`: Edges as `(source, target, weight)`.
+    pub fn add_weighted_edges(&mut self, edges: impl IntoIterator<Item = (usize, usize, W)>) {
+        for (source, target, weight) in edges {
+            self.add_edge(source, target, weight);
+        }
+    }
 
-    /// Run Dijkstra's Algorithm.
+    /// Set the total number of nodes in the graph, adding the missing nodes without edges.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        for node in 0..total {
+            self.graph.entry(node).or_default();
+        }
+    }
+
+    /// Run Dijkstra's Algorithm and keep the predecessor of each node alongside the distances, so
+    /// that the route to any reachable node can be materialized and not only its cost.
     ///
     /// # Arguments
     ///
@@ -116,218 +338,1454 @@ impl GraphAlgorithm for DijkstraAlgorithm {
     ///
     /// # Returns
     ///
-    /// Vector of the shortest path from the starting node to all other nodes.
-    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
-        let start = start.ok_or(GraphError::MissingStartNode)?;
-
-        let mut priority_queue = BinaryHeap::new();
-        let mut distances = HashMap::new();
-        let mut result = vec![usize::MAX; self.graph.len()];
-
-        distances.insert(start, 0);
-        priority_queue.push(State {
-            cost: 0,
-            position: start,
-        });
-
-        while let Some(state) = priority_queue.pop() {
-            // Determine if the current shortest path is already known.
-            // If it is, skip the current node.
-            if distances
-                .get(&state.position)
-                .map(|&d| state.cost > d)
-                .unwrap_or(false)
-            {
-                continue;
-            }
-
-            if let Some(neighbors) = self.graph.get(&state.position) {
-                for &(neighbor, weight) in neighbors {
-                    let next = State {
-                        cost: state.cost + weight,
-                        position: neighbor,
-                    };
-
-                    // Determine if the new path is shorter than the current shortest path.
-                    // If it is, update the shortest path.
-                    if distances
-                        .get(&neighbor)
-                        .map(|&d| next.cost < d)
-                        .unwrap_or(true)
-                    {
-                        distances.insert(neighbor, next.cost);
-                        priority_queue.push(next);
-                    }
-                }
-            }
+    /// Result containing the shortest paths from the start, or an error if the start is missing
+    /// or a reachable edge has a negative weight.
+    pub fn shortest_path_tree(&self, start: usize) -> Result<ShortestPaths<W>, GraphError> {
+        if !self.graph.contains_key(&start) {
+            return Err(GraphError::MissingStartNode);
         }
 
-        // Convert the distances to a vector of shortest paths.
-        for (node, dist) in distances.into_iter() {
-            if node < result.len() {
-                result[node] = dist;
-            }
-        }
+        let labels = self.search(start, None, &mut ())?;
 
-        Ok(result)
+        Ok(labels.shortest_paths(start, self.graph.len()))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Run Dijkstra's Algorithm on a shared graph, reading its edges in place.
+    ///
+    /// # Arguments
+    ///
+    /// - `graph`: Directed graph, or undirected graph through its directed view.
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the shortest paths from the start, or an error if the start is missing
+    /// or a reachable edge has a negative weight.
+    pub fn run_on(graph: &DiGraph<W>, start: usize) -> Result<ShortestPaths<W>, GraphError> {
+        if start >= graph.total_nodes() {
+            return Err(GraphError::MissingStartNode);
+        }
 
-    #[test]
-    fn test_new() {
-        let algorithm = DijkstraAlgorithm::new();
-        let algorithm_default = DijkstraAlgorithm::default();
+        let kind = Self::auto_queue(
+            graph.total_nodes(),
+            graph.edges().map(|(_, _, weight)| weight),
+        );
+        let labels = Self::explore(
+            Self::queue_of(kind),
+            start,
+            |node| graph.neighbors(node),
+            |_, _, weight| weight,
+            None,
+            &mut (),
+        )?;
 
-        assert_eq!(algorithm.graph.len(), 0);
-        assert_eq!(algorithm_default.graph.len(), 0);
+        Ok(labels.shortest_paths(start, graph.total_nodes()))
     }
 
-    #[test]
-    fn test_missing_start_node() {
-        let algorithm = DijkstraAlgorithm::new();
-
-        assert_eq!(algorithm.run(None), Err(GraphError::MissingStartNode));
+    /// Compute the distances from a node, such as `f64` road lengths or `u64` weights too large
+    /// for `usize` on 32-bit targets.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the distance of each node, `Weight::INFINITY` if unreachable, or an error
+    /// if the start is missing or a reachable edge has a negative weight.
+    pub fn distances(&self, start: usize) -> Result<Vec<W>, GraphError> {
+        Ok(self.shortest_path_tree(start)?.distances)
     }
 
-    #[test]
-    fn test_run() {
-        let mut algorithm = DijkstraAlgorithm::new();
-        let nodes = vec![
-            (0, vec![(1, 1), (2, 4), (3, 7)]),
-            (1, vec![(2, 2), (3, 5), (4, 12)]),
-            (2, vec![(3, 1), (4, 3)]),
-            (3, vec![(4, 2), (5, 8)]),
-            (4, vec![(5, 1), (6, 5)]),
-            (5, vec![(6, 2), (7, 3)]),
-            (6, vec![(7, 1), (8, 4)]),
-            (7, vec![(8, 2), (9, 6)]),
-            (8, vec![(9, 1)]),
-            (9, vec![(10, 2), (11, 3)]),
-            (10, vec![(11, 1), (12, 4)]),
-            (11, vec![(12, 2), (13, 6)]),
-            (12, vec![(13, 1), (14, 5)]),
-            (13, vec![(14, 2), (15, 3)]),
-            (14, vec![(15, 1), (16, 4)]),
-            (15, vec![(16, 2), (17, 6)]),
-            (16, vec![(17, 1), (18, 5)]),
-            (17, vec![(18, 2), (19, 3)]),
-            (18, vec![(19, 1)]),
-            (19, vec![]),
-        ];
-        algorithm.set_nodes(nodes);
-
-        assert_eq!(
-            algorithm.run(Some(0)).unwrap(),
-            vec![0, 1, 3, 4, 6, 7, 9, 10, 12, 13, 15, 16, 18, 19, 21, 22, 24, 25, 27, 28]
-        );
+    /// Set the priority queue backing the search.
+    ///
+    /// # Arguments
+    ///
+    /// - `queue`: Priority queue, or `Auto` to choose one from the graph.
+    pub fn set_queue(&mut self, queue: PriorityQueueKind) {
+        self.queue = queue;
     }
 
-    #[test]
-    fn test_run_single_node_graph() {
-        let mut algorithm = DijkstraAlgorithm::new();
-        algorithm.set_nodes(vec![(0, vec![])]);
+    /// Resolve the priority queue used by the search. The automatic choice keeps a binary heap for
+    /// small graphs, takes a radix heap when the weights are small enough for few redistributions,
+    /// and otherwise a 4-ary heap for graphs with many edges per node.
+    ///
+    /// # Returns
+    ///
+    /// Selected priority queue, never `Auto`.
+    pub fn selected_queue(&self) -> PriorityQueueKind {
+        if self.queue != PriorityQueueKind::Auto {
+            return self.queue;
+        }
 
-        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0]);
+        Self::auto_queue(
+            self.graph.len(),
+            self.graph.values().flatten().map(|&(_, weight)| weight),
+        )
     }
 
-    #[test]
-    fn test_run_two_node_graph() {
-        let mut algorithm = DijkstraAlgorithm::new();
-        algorithm.set_node(0, vec![(1, 1)]);
-        algorithm.set_node(1, vec![]);
+    /// Choose a priority queue from the size of a graph and the range of its weights.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Number of nodes.
+    /// - `weights`: Weight of every edge.
+    ///
+    /// # Returns
+    ///
+    /// Priority queue, never `Auto`.
+    fn auto_queue(nodes: usize, weights: impl Iterator<Item = W>) -> PriorityQueueKind {
+        let (edges, max_key) = weights.fold((0, 0), |(edges, max_key), weight| {
+            (edges + 1, weight.radix_key().max(max_key))
+        });
 
-        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 1]);
+        match nodes {
+            0..1024 => PriorityQueueKind::Binary,
+            _ if max_key <= u16::MAX as u64 => PriorityQueueKind::Radix,
+            _ if edges >= 8 * nodes => PriorityQueueKind::FourAry,
+            _ => PriorityQueueKind::Binary,
+        }
     }
 
-    #[test]
-    fn test_run_disconnected_graph() {
-        let mut algorithm = DijkstraAlgorithm::new();
-        algorithm.set_nodes(vec![(0, vec![]), (1, vec![])]);
-
-        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, usize::MAX]);
+    /// Set the rule choosing among shortest paths of equal cost.
+    ///
+    /// # Arguments
+    ///
+    /// - `tie_breaking`: Tie-breaking rule.
+    pub fn set_tie_breaking(&mut self, tie_breaking: TieBreaking) {
+        self.tie_breaking = tie_breaking;
     }
 
-    #[test]
-    fn test_run_simple_path() {
-        let mut algorithm = DijkstraAlgorithm::new();
-        algorithm.set_nodes(vec![(0, vec![(1, 1)]), (1, vec![(2, 1)]), (2, vec![])]);
-
-        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 1, 2]);
+    /// Set a previously returned path to bias new shortest paths toward, so that recomputed routes
+    /// do not flip between near-equal alternatives. A route leaves the preferred path only if it
+    /// saves more than the penalty for every edge off the preferred path.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: Nodes of the previous path, empty to disable the bias.
+    /// - `penalty`: Extra cost of every edge off the path when searching.
+    pub fn set_preferred_path(&mut self, path: Vec<usize>, penalty: W) {
+        self.preferred_path = path;
+        self.deviation_penalty = penalty;
     }
 
-    #[test]
-    fn test_run_multiple_paths() {
-        let mut algorithm = DijkstraAlgorithm::new();
-        algorithm.set_nodes(vec![
-            (0, vec![(1, 1), (2, 4)]),
-            (1, vec![(2, 2)]),
-            (2, vec![]),
-        ]);
+    /// Set the attribute bits of an edge, used by soft constraints.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node of the edge.
+    /// - `target`: Target node of the edge.
+    /// - `tags`: Attribute bits, e.g. `UNPAVED | RESIDENTIAL` with user-defined constants.
+    pub fn set_edge_tags(&mut self, source: usize, target: usize, tags: u64) {
+        self.edge_tags.insert((source, target), tags);
+    }
 
-        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 1, 3]);
+    /// Set the soft constraints of the next queries, composed from any combination of preferences
+    /// instead of a precomputed weight set per combination.
+    ///
+    /// # Arguments
+    ///
+    /// - `constraints`: Soft constraints, empty to search on the plain weights.
+    pub fn set_soft_constraints(&mut self, constraints: Vec<SoftConstraint>) {
+        self.soft_constraints = constraints;
     }
 
-    #[test]
-    fn test_run_graph_with_cycle() {
-        let mut algorithm = DijkstraAlgorithm::new();
-        algorithm.set_nodes(vec![
-            (0, vec![(1, 1)]),
-            (1, vec![(2, 1)]),
-            (2, vec![(0, 1)]),
-        ]);
+    /// Find a shortest path between two nodes, chosen among equal-cost paths by the tie-breaking rule,
+    /// penalized by the soft constraints and biased toward the preferred path, if any.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Cost and nodes of the path, or `None` if the target is unreachable or the search reaches a
+    /// negative weight.
+    pub fn shortest_path(&self, start: usize, target: usize) -> Option<CostPath<W>> {
+        if (self.deviation_penalty > W::ZERO && self.preferred_path.len() > 1)
+            || !self.soft_constraints.is_empty()
+        {
+            return self.biased_path(start, target);
+        }
 
-        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 1, 2]);
+        self.tie_broken_path(start, target, &|_, _, weight| weight)
     }
 
-    #[test]
-    fn test_run_graph_with_multiple_shortest_paths() {
-        let mut algorithm = DijkstraAlgorithm::new();
-        algorithm.set_nodes(vec![
+    /// Find a shortest path chosen among equal-cost paths by the tie-breaking rule.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `target`: Target node.
+    /// - `weigh`: Weight searched for an edge as `(source, target, weight)`, at least its weight.
+    ///
+    /// # Returns
+    ///
+    /// Cost of the path under `weigh` and its nodes, or `None` if the target is unreachable or the
+    /// search reaches a negative weight.
+    fn tie_broken_path(
+        &self,
+        start: usize,
+        target: usize,
+        weigh: &dyn Fn(usize, usize, W) -> W,
+    ) -> Option<CostPath<W>> {
+        match self.tie_breaking {
+            TieBreaking::SmallerNode => self.smallest_path(start, target, weigh),
+            _ => self.penalized_path(start, target, weigh),
+        }
+    }
+
+    /// Find a shortest path to a single target, stopping as soon as the target is settled instead of
+    /// settling the whole graph. Tie-breaking rules, preferred paths and soft constraints are honored
+    /// by `shortest_path`, which this falls back to when any of them is set.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the cost and nodes of the path, or an error if the start is missing, the
+    /// target is missing or unreachable, or the search reaches a negative weight.
+    pub fn run_to_target(&self, start: usize, target: usize) -> Result<CostPath<W>, GraphError> {
+        if !self.graph.contains_key(&start) {
+            return Err(GraphError::MissingStartNode);
+        }
+
+        if !self.graph.contains_key(&target) {
+            return Err(GraphError::MissingTargetNode);
+        }
+
+        if !matches!(self.tie_breaking, TieBreaking::FirstFound)
+            || (self.deviation_penalty > W::ZERO && self.preferred_path.len() > 1)
+            || !self.soft_constraints.is_empty()
+        {
+            self.validate_weights()?;
+
+            return self
+                .shortest_path(start, target)
+                .ok_or(GraphError::MissingTargetNode);
+        }
+
+        self.search(start, Some((&HashSet::from([target]), 1)), &mut ())?
+            .path(target)
+            .ok_or(GraphError::MissingTargetNode)
+    }
+
+    /// Search the distances from a node on the graph and the configured queue.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `targets`: Nodes whose distances are needed, and how many of them to settle before
+    ///   stopping, or `None` to settle every reachable node.
+    /// - `sink`: Receiver of the events.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the labels of the reached nodes, or an error if the search reaches a
+    /// negative weight.
+    fn search<S: EventSink + ?Sized>(
+        &self,
+        start: usize,
+        targets: Option<(&HashSet<usize>, usize)>,
+        sink: &mut S,
+    ) -> Result<Labels<W>, GraphError> {
+        Self::explore(
+            self.min_queue(),
+            start,
+            |node| self.graph.get(&node).map_or(&[], Vec::as_slice),
+            |_, _, weight| weight,
+            targets,
+            sink,
+        )
+    }
+
+    /// Search the distances from a node over any adjacency, emitting the settled nodes, relaxed
+    /// edges and updated distances.
+    ///
+    /// # Arguments
+    ///
+    /// - `priority_queue`: Empty priority queue backing the search.
+    /// - `start`: Starting node.
+    /// - `neighbors`: Outgoing edges of a node as `(target, weight)`.
+    /// - `weigh`: Weight searched for an edge as `(source, target, weight)`, at least its weight.
+    /// - `targets`: Nodes whose distances are needed, and how many of them to settle before
+    ///   stopping, or `None` to settle every reachable node.
+    /// - `sink`: Receiver of the events.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the labels of the reached nodes, or an error if the search reaches a
+    /// negative weight.
+    fn explore<'a, S: EventSink + ?Sized>(
+        mut priority_queue: Box<dyn MinQueue<W>>,
+        start: usize,
+        neighbors: impl Fn(usize) -> &'a [(usize, W)],
+        weigh: impl Fn(usize, usize, W) -> W,
+        targets: Option<(&HashSet<usize>, usize)>,
+        sink: &mut S,
+    ) -> Result<Labels<W>, GraphError> {
+        let mut labels = Labels {
+            distances: HashMap::from([(start, W::ZERO)]),
+            predecessors: HashMap::new(),
+            hops: HashMap::from([(start, 0)]),
+            settled: HashSet::new(),
+        };
+        let mut remaining = targets.map(|(_, needed)| needed);
+        let mut expanded = HashMap::new();
+
+        priority_queue.push(W::ZERO, start);
+
+        while remaining != Some(0) {
+            let Some((cost, position)) = priority_queue.pop() else {
+                break;
+            };
+
+            // Skip the stale entries of nodes settled at a lower cost or already expanded with
+            // their fewest edges.
+            let hops = labels.hops[&position];
+
+            if cost > labels.distances[&position] || expanded.insert(position, hops) == Some(hops) {
+                continue;
+            }
+
+            // A node is settled once, even if a path as short with fewer edges expands it again.
+            if labels.settled.insert(position) {
+                if let (Some((targets, _)), Some(remaining)) = (targets, remaining.as_mut()) {
+                    if targets.contains(&position) {
+                        *remaining -= 1;
+                    }
+                }
+            }
+
+            let hops = hops + 1;
+
+            sink.emit(AlgorithmEvent::NodeSettled {
+                node: position,
+                distance: cost.to_i64(),
+            });
+
+            for &(neighbor, weight) in neighbors(position) {
+                // Negative weights could be improved on forever around a negative cycle.
+                if weight.partial_cmp(&W::ZERO).map_or(true, Ordering::is_lt) {
+                    return Err(GraphError::NegativeWeight(position, neighbor));
+                }
+
+                let weight = weigh(position, neighbor, weight);
+
+                sink.emit(AlgorithmEvent::EdgeRelaxed {
+                    source: position,
+                    target: neighbor,
+                    weight: weight.to_i64(),
+                });
+
+                let next = cost.saturating_add(weight);
+
+                // Determine if the new path is shorter than the current shortest path, or as short
+                // with fewer edges. If it is, update the shortest path, expanding the node again
+                // if zero weights shorten its edges after it was settled.
+                if labels.distances.get(&neighbor).map_or(true, |&known| {
+                    next < known || (next == known && hops < labels.hops[&neighbor])
+                }) {
+                    labels.distances.insert(neighbor, next);
+                    labels.predecessors.insert(neighbor, position);
+                    labels.hops.insert(neighbor, hops);
+                    priority_queue.push(next, neighbor);
+
+                    sink.emit(AlgorithmEvent::DistanceUpdated {
+                        node: neighbor,
+                        distance: next.to_i64(),
+                        predecessor: Some(position),
+                    });
+                }
+            }
+        }
+
+        Ok(labels)
+    }
+
+    /// Create the priority queue backing the search.
+    ///
+    /// # Returns
+    ///
+    /// Empty priority queue of the selected kind.
+    fn min_queue(&self) -> Box<dyn MinQueue<W>> {
+        Self::queue_of(self.selected_queue())
+    }
+
+    /// Create an empty priority queue of a kind.
+    ///
+    /// # Arguments
+    ///
+    /// - `kind`: Kind of priority queue, `Auto` giving a binary heap.
+    ///
+    /// # Returns
+    ///
+    /// Empty priority queue.
+    fn queue_of(kind: PriorityQueueKind) -> Box<dyn MinQueue<W>> {
+        match kind {
+            PriorityQueueKind::FourAry => Box::<FourAryQueue<W>>::default(),
+            PriorityQueueKind::Pairing => Box::<PairingQueue<W>>::default(),
+            PriorityQueueKind::Radix => Box::<RadixQueue<W>>::default(),
+            _ => Box::<BinaryQueue<W>>::default(),
+        }
+    }
+
+    /// Check that no edge has a negative weight, before searches that do not check it themselves.
+    ///
+    /// # Returns
+    ///
+    /// Result containing nothing, or an error naming a negative edge.
+    fn validate_weights(&self) -> Result<(), GraphError> {
+        for (&node, edges) in &self.graph {
+            for &(target, weight) in edges {
+                if weight.partial_cmp(&W::ZERO).map_or(true, Ordering::is_lt) {
+                    return Err(GraphError::NegativeWeight(node, target));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Find a shortest path whose edges are penalized, while searching, by the soft constraints
+    /// and, when off the preferred path, by the deviation penalty.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Unbiased cost and nodes of the path, or `None` if the target is unreachable.
+    fn biased_path(&self, start: usize, target: usize) -> Option<CostPath<W>> {
+        let preferred: HashSet<(usize, usize)> = self
+            .preferred_path
+            .windows(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect();
+        let weigh = |node: usize, neighbor: usize, weight: W| {
+            let tags = self.edge_tags.get(&(node, neighbor)).copied().unwrap_or(0);
+            let percent: usize = self
+                .soft_constraints
+                .iter()
+                .filter(|constraint| constraint.tags & tags != 0)
+                .map(|constraint| constraint.percent)
+                .sum();
+            let biased = weight.saturating_add(weight.percent(percent));
+
+            match !preferred.is_empty() && !preferred.contains(&(node, neighbor)) {
+                true => biased.saturating_add(self.deviation_penalty),
+                false => biased,
+            }
+        };
+
+        let (_, path) = self.tie_broken_path(start, target, &weigh)?;
+        let cost = path
+            .windows(2)
+            .filter_map(|pair| {
+                self.graph
+                    .get(&pair[0])
+                    .into_iter()
+                    .flatten()
+                    .filter(|&&(neighbor, _)| neighbor == pair[1])
+                    .map(|&(_, weight)| weight)
+                    .min_by(Weight::total_cmp)
+            })
+            .fold(W::ZERO, Weight::saturating_add);
+
+        Some((cost, path))
+    }
+
+    /// Find the shortest path with the smallest total penalty, by Dijkstra's Algorithm on the pair
+    /// of cost and penalty. States keep the previous node only when the penalty depends on it.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `target`: Target node.
+    /// - `weigh`: Weight searched for an edge as `(source, target, weight)`, at least its weight.
+    ///
+    /// # Returns
+    ///
+    /// Cost and nodes of the path, or `None` if the target is unreachable or the search reaches a
+    /// negative weight.
+    fn penalized_path(
+        &self,
+        start: usize,
+        target: usize,
+        weigh: &dyn Fn(usize, usize, W) -> W,
+    ) -> Option<CostPath<W>> {
+        let mut best: HashMap<PathState, (Ordered<W>, usize)> = HashMap::new();
+        let mut parents: HashMap<PathState, PathState> = HashMap::new();
+        let mut queue = BinaryHeap::from([Reverse((Ordered(W::ZERO), 0, start, None))]);
+
+        best.insert((start, None), (Ordered(W::ZERO), 0));
+
+        while let Some(Reverse((cost, penalty, node, previous))) = queue.pop() {
+            if best.get(&(node, previous)) != Some(&(cost, penalty)) {
+                continue;
+            }
+
+            if node == target {
+                let mut path = vec![node];
+                let mut state = (node, previous);
+
+                while let Some(&parent) = parents.get(&state) {
+                    path.push(parent.0);
+                    state = parent;
+                }
+
+                path.reverse();
+                return Some((cost.0, path));
+            }
+
+            for &(neighbor, weight) in self.graph.get(&node).into_iter().flatten() {
+                if weight.partial_cmp(&W::ZERO).map_or(true, Ordering::is_lt) {
+                    return None;
+                }
+
+                let weight = weigh(node, neighbor, weight);
+                let (step, next) = match self.tie_breaking {
+                    TieBreaking::FewerHops => (1, None),
+                    TieBreaking::Penalty(penalty) => {
+                        (penalty(previous, node, neighbor), Some(node))
+                    }
+                    _ => (0, None),
+                };
+                let key = (Ordered(cost.0.saturating_add(weight)), penalty + step);
+
+                // Keep the first state found on equal keys.
+                if best
+                    .get(&(neighbor, next))
+                    .map_or(true, |&known| key < known)
+                {
+                    best.insert((neighbor, next), key);
+                    parents.insert((neighbor, next), (node, previous));
+                    queue.push(Reverse((key.0, key.1, neighbor, next)));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Find the lexicographically smallest shortest path, by walking from the start along the
+    /// smallest successor on a shortest path that can still reach the target, and backing out of
+    /// successors that only lead back to the path.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `target`: Target node.
+    /// - `weigh`: Weight searched for an edge as `(source, target, weight)`, at least its weight.
+    ///
+    /// # Returns
+    ///
+    /// Cost and nodes of the path, or `None` if the target is unreachable or the search reaches a
+    /// negative weight.
+    fn smallest_path(
+        &self,
+        start: usize,
+        target: usize,
+        weigh: &dyn Fn(usize, usize, W) -> W,
+    ) -> Option<CostPath<W>> {
+        if !self.graph.contains_key(&start) {
+            return None;
+        }
+
+        let labels = Self::explore(
+            self.min_queue(),
+            start,
+            |node| self.graph.get(&node).map_or(&[], Vec::as_slice),
+            weigh,
+            None,
+            &mut (),
+        )
+        .ok()?;
+        let distance = |node: usize| labels.distance(node).unwrap_or(W::INFINITY);
+        let on_shortest_path = |node: usize, weight: W, neighbor: usize| {
+            distance(node) != W::INFINITY
+                && distance(node).checked_add(weigh(node, neighbor, weight))
+                    == Some(distance(neighbor))
+        };
+        let cost = distance(target);
+
+        if cost == W::INFINITY {
+            return None;
+        }
+
+        // Collect the nodes that reach the target along edges of shortest paths.
+        let mut predecessors: HashMap<usize, Vec<usize>> = HashMap::new();
+
+        for (&node, edges) in &self.graph {
+            for &(neighbor, weight) in edges {
+                if on_shortest_path(node, weight, neighbor) {
+                    predecessors.entry(neighbor).or_default().push(node);
+                }
+            }
+        }
+
+        let mut useful = HashSet::from([target]);
+        let mut stack = vec![target];
+
+        while let Some(node) = stack.pop() {
+            for &predecessor in predecessors.get(&node).into_iter().flatten() {
+                if useful.insert(predecessor) {
+                    stack.push(predecessor);
+                }
+            }
+        }
+
+        // Successors of a node on shortest paths that reach the target, the smallest last.
+        let successors = |node: usize| {
+            let mut next: Vec<usize> = self
+                .graph
+                .get(&node)
+                .into_iter()
+                .flatten()
+                .filter(|&&(neighbor, weight)| {
+                    on_shortest_path(node, weight, neighbor) && useful.contains(&neighbor)
+                })
+                .map(|&(neighbor, _)| neighbor)
+                .collect();
+            next.sort_unstable_by(|a, b| b.cmp(a));
+
+            next
+        };
+
+        // Walk along the smallest successors, backing out of the dead ends that zero-weight
+        // cycles leave at visited nodes.
+        let mut path = vec![start];
+        let mut pending = vec![successors(start)];
+        let mut visited = HashSet::from([start]);
+
+        while path.last() != Some(&target) {
+            match pending.last_mut()?.pop() {
+                Some(next) => {
+                    if visited.insert(next) {
+                        path.push(next);
+                        pending.push(successors(next));
+                    }
+                }
+                None => {
+                    pending.pop();
+                    path.pop();
+                }
+            }
+        }
+
+        Some((cost, path))
+    }
+
+    /// Compute the minimal cost from a starting node to all other nodes together with the fewest
+    /// edges of a path of that cost, in a single search breaking cost ties on the hop count.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the hop count and cost of each node, `None` if the node is unreachable,
+    /// or an error if the start node is missing or the search reaches a negative weight.
+    pub fn hops_and_costs(&self, start: usize) -> Result<Vec<Option<HopCost<W>>>, GraphError> {
+        if !self.graph.contains_key(&start) {
+            return Err(GraphError::MissingStartNode);
+        }
+
+        let labels = self.search(start, None, &mut ())?;
+
+        Ok((0..self.graph.len())
+            .map(|node| {
+                labels.distance(node).map(|cost| HopCost {
+                    hops: labels.hops[&node],
+                    cost,
+                })
+            })
+            .collect())
+    }
+
+    /// Compute the shortest distances between every pair of a subset of nodes.
+    /// One search runs from each node of the subset and stops as soon as the whole subset is
+    /// settled, instead of computing the full matrix of all pairs. The searches run in parallel
+    /// with the optional `rayon` feature.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Nodes of the subset, e.g. depots.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the matrix whose entry `[i][j]` is the distance from `nodes[i]` to
+    /// `nodes[j]`, `Weight::INFINITY` if unreachable, or an error if a search reaches a negative
+    /// weight.
+    pub fn distance_matrix(&self, nodes: &[usize]) -> Result<Vec<Vec<W>>, GraphError> {
+        let targets: HashSet<usize> = nodes.iter().copied().collect();
+
+        #[cfg(feature = "rayon")]
+        let sources = nodes.par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let sources = nodes.iter();
+
+        sources
+            .map(|&source| {
+                let labels = self.search(source, Some((&targets, targets.len())), &mut ())?;
+
+                Ok(nodes
+                    .iter()
+                    .map(|&node| labels.distance(node).unwrap_or(W::INFINITY))
+                    .collect())
+            })
+            .collect()
+    }
+
+    /// Find shortest paths to a set of targets, stopping as soon as all of them are settled instead
+    /// of exploring the whole graph. Like `distance_matrix`, the search ignores the tie-breaking
+    /// rule, preferred path and soft constraints.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `targets`: Target nodes.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the cost and nodes of the path to each target in the given order, `None`
+    /// if unreachable, or an error if the start is missing or the search reaches a negative weight.
+    pub fn run_to_targets(
+        &self,
+        start: usize,
+        targets: &[usize],
+    ) -> Result<Vec<Option<CostPath<W>>>, GraphError> {
+        if !self.graph.contains_key(&start) {
+            return Err(GraphError::MissingStartNode);
+        }
+
+        let set: HashSet<usize> = targets.iter().copied().collect();
+        let labels = self.search(start, Some((&set, set.len())), &mut ())?;
+
+        Ok(targets.iter().map(|&target| labels.path(target)).collect())
+    }
+
+    /// Find the nearest nodes of a set, such as the 5 nearest hospitals, stopping as soon as that
+    /// many of them are settled.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `targets`: Candidate nodes.
+    /// - `count`: Number of candidates to find.
+    ///
+    /// # Returns
+    ///
+    /// Result containing up to `count` candidates as `(node, cost, path)` by increasing cost, or
+    /// an error if the start is missing or the search reaches a negative weight.
+    pub fn nearest_targets(
+        &self,
+        start: usize,
+        targets: &[usize],
+        count: usize,
+    ) -> Result<Vec<(usize, W, Vec<usize>)>, GraphError> {
+        if !self.graph.contains_key(&start) {
+            return Err(GraphError::MissingStartNode);
+        }
+
+        let set: HashSet<usize> = targets.iter().copied().collect();
+        let labels = self.search(start, Some((&set, count)), &mut ())?;
+        let mut nearest: Vec<(usize, W, Vec<usize>)> = set
+            .into_iter()
+            .filter_map(|target| labels.path(target).map(|(cost, path)| (target, cost, path)))
+            .collect();
+
+        nearest.sort_unstable_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        nearest.truncate(count);
+
+        Ok(nearest)
+    }
+}
+
+impl<W: Weight> GraphAlgorithm for DijkstraAlgorithm<W> {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<W>;
+
+    /// Run Dijkstra's Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Vector of the shortest path from the starting node to all other nodes, or an error if the
+    /// search reaches a negative weight.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let labels = self.search(start.ok_or(GraphError::MissingStartNode)?, None, &mut ())?;
+
+        Ok(labels.distances(self.graph.len()))
+    }
+
+    /// Check that the start and every edge target are nodes of the graph, numbered below its size,
+    /// that no weight is negative and that no path can overflow the weights.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing nothing, or an error describing the malformed input.
+    fn validate(&self, start: Option<&Self::Node>) -> Result<(), GraphError> {
+        let start = start.ok_or(GraphError::MissingStartNode)?;
+
+        if !self.graph.contains_key(start) {
+            return Err(GraphError::MissingStartNode);
+        }
+
+        self.validate_weights()?;
+
+        let mut total = W::ZERO;
+
+        for (&node, edges) in &self.graph {
+            if node >= self.graph.len() {
+                return Err(GraphError::NodeOutOfRange(node));
+            }
+
+            for &(target, weight) in edges {
+                if target >= self.graph.len() {
+                    return Err(GraphError::NodeOutOfRange(target));
+                }
+
+                total = total
+                    .checked_add(weight)
+                    .ok_or(GraphError::WeightOverflow)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Weight> ConfiguredAlgorithm for DijkstraAlgorithm<W> {
+    /// Describe the configuration of the Dijkstra's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("dijkstra")
+            .with("tie_breaking", self.tie_breaking)
+            .with("queue", self.queue)
+            .with("deviation_penalty", self.deviation_penalty)
+            .with("preferred_path", &self.preferred_path)
+            .with("soft_constraints", &self.soft_constraints)
+    }
+}
+
+impl<W: Weight> ObservableAlgorithm for DijkstraAlgorithm<W> {
+    /// Run Dijkstra's Algorithm and emit every settled node, relaxed edge and updated distance.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `sink`: Receiver of the events.
+    ///
+    /// # Returns
+    ///
+    /// Vector of the shortest path from the starting node to all other nodes, or an error if the
+    /// search reaches a negative weight.
+    fn run_observed(
+        &self,
+        start: Option<Self::Node>,
+        sink: &mut dyn EventSink,
+    ) -> Result<Self::Weight, GraphError> {
+        let labels = self.search(start.ok_or(GraphError::MissingStartNode)?, None, sink)?;
+
+        Ok(labels.distances(self.graph.len()))
+    }
+}
+
+impl<W: Weight> MemoryEstimation for DijkstraAlgorithm<W> {
+    /// Estimate the memory of Dijkstra's Algorithm: a distance per node and, with lazy deletion,
+    /// a queue entry per improved distance, at most one per edge.
+    ///
+    /// # Returns
+    ///
+    /// Memory estimate of a run.
+    fn estimate_memory(&self) -> MemoryEstimate {
+        let nodes = self.graph.len();
+        let edges: usize = self.graph.values().map(Vec::len).sum();
+
+        MemoryEstimate::new(
+            MemoryEstimate::bytes::<(usize, Vec<(usize, W)>)>(nodes)
+                .saturating_add(MemoryEstimate::bytes::<(usize, W)>(edges))
+                .saturating_add(MemoryEstimate::bytes::<((usize, usize), u64)>(
+                    self.edge_tags.len(),
+                ))
+                .saturating_add(MemoryEstimate::bytes::<usize>(self.preferred_path.len()))
+                .saturating_add(MemoryEstimate::bytes::<SoftConstraint>(
+                    self.soft_constraints.len(),
+                )),
+            MemoryEstimate::bytes::<(W, usize)>(nodes.saturating_add(edges).saturating_add(1)),
+            MemoryEstimate::bytes::<W>(nodes),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Graph;
+
+    #[test]
+    fn test_new() {
+        let algorithm = DijkstraAlgorithm::new();
+        let algorithm_default = DijkstraAlgorithm::default();
+
+        assert_eq!(algorithm.graph.len(), 0);
+        assert_eq!(algorithm_default.graph.len(), 0);
+    }
+
+    #[test]
+    fn test_builders() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.add_edge(0, 1, 4);
+        algorithm.add_weighted_edges([(1, 2, 1), (0, 2, 6)]);
+        algorithm.set_total_nodes(4);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 4, 5, usize::MAX]);
+    }
+
+    #[test]
+    fn test_missing_start_node() {
+        let algorithm = DijkstraAlgorithm::new();
+
+        assert_eq!(algorithm.run(None), Err(GraphError::MissingStartNode));
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        let nodes = vec![
+            (0, vec![(1, 1), (2, 4), (3, 7)]),
+            (1, vec![(2, 2), (3, 5), (4, 12)]),
+            (2, vec![(3, 1), (4, 3)]),
+            (3, vec![(4, 2), (5, 8)]),
+            (4, vec![(5, 1), (6, 5)]),
+            (5, vec![(6, 2), (7, 3)]),
+            (6, vec![(7, 1), (8, 4)]),
+            (7, vec![(8, 2), (9, 6)]),
+            (8, vec![(9, 1)]),
+            (9, vec![(10, 2), (11, 3)]),
+            (10, vec![(11, 1), (12, 4)]),
+            (11, vec![(12, 2), (13, 6)]),
+            (12, vec![(13, 1), (14, 5)]),
+            (13, vec![(14, 2), (15, 3)]),
+            (14, vec![(15, 1), (16, 4)]),
+            (15, vec![(16, 2), (17, 6)]),
+            (16, vec![(17, 1), (18, 5)]),
+            (17, vec![(18, 2), (19, 3)]),
+            (18, vec![(19, 1)]),
+            (19, vec![]),
+        ];
+        algorithm.set_nodes(nodes);
+
+        assert_eq!(
+            algorithm.run(Some(0)).unwrap(),
+            vec![0, 1, 3, 4, 6, 7, 9, 10, 12, 13, 15, 16, 18, 19, 21, 22, 24, 25, 27, 28]
+        );
+    }
+
+    #[test]
+    fn test_run_single_node_graph() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_nodes(vec![(0, vec![])]);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_run_two_node_graph() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_node(0, vec![(1, 1)]);
+        algorithm.set_node(1, vec![]);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_run_disconnected_graph() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_nodes(vec![(0, vec![]), (1, vec![])]);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, usize::MAX]);
+    }
+
+    #[test]
+    fn test_run_simple_path() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_nodes(vec![(0, vec![(1, 1)]), (1, vec![(2, 1)]), (2, vec![])]);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_run_multiple_paths() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_nodes(vec![
+            (0, vec![(1, 1), (2, 4)]),
+            (1, vec![(2, 2)]),
+            (2, vec![]),
+        ]);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn test_run_graph_with_cycle() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_nodes(vec![
+            (0, vec![(1, 1)]),
+            (1, vec![(2, 1)]),
+            (2, vec![(0, 1)]),
+        ]);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_run_graph_with_multiple_shortest_paths() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_nodes(vec![
             (0, vec![(1, 1), (2, 1)]),
             (1, vec![(2, 1)]),
-            (2, vec![]),
+            (2, vec![]),
+        ]);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 1, 1]);
+    }
+
+    #[test]
+    fn test_run_large_weights() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_nodes(vec![
+            (0, vec![(1, 1000)]),
+            (1, vec![(2, 1000)]),
+            (2, vec![]),
+        ]);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 1000, 2000]);
+    }
+
+    #[test]
+    fn test_run_no_edges() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_nodes(vec![(0, vec![]), (1, vec![]), (2, vec![])]);
+
+        assert_eq!(
+            algorithm.run(Some(0)).unwrap(),
+            vec![0, usize::MAX, usize::MAX]
+        );
+    }
+
+    #[test]
+    fn test_run_multiple_edges_to_same_node() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_nodes(vec![
+            (0, vec![(1, 1), (1, 2)]),
+            (1, vec![(2, 1)]),
+            (2, vec![]),
+        ]);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_hops_and_costs() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_nodes(vec![
+            (0, vec![(1, 1), (3, 10)]),
+            (1, vec![(2, 1)]),
+            (2, vec![(3, 1)]),
+            (3, vec![]),
+            (4, vec![]),
         ]);
 
-        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 1, 1]);
+        assert_eq!(
+            algorithm.hops_and_costs(0),
+            Ok(vec![
+                Some(HopCost { hops: 0, cost: 0 }),
+                Some(HopCost { hops: 1, cost: 1 }),
+                Some(HopCost { hops: 2, cost: 2 }),
+                Some(HopCost { hops: 3, cost: 3 }),
+                None,
+            ])
+        );
+        assert_eq!(
+            algorithm.hops_and_costs(5),
+            Err(GraphError::MissingStartNode)
+        );
+
+        // Among paths of equal cost the one with fewer edges wins, even through zero weights.
+        algorithm.set_nodes(vec![
+            (0, vec![(1, 0), (4, 0)]),
+            (1, vec![(2, 0)]),
+            (2, vec![(3, 3)]),
+            (4, vec![(3, 3)]),
+        ]);
+
+        assert_eq!(
+            algorithm.hops_and_costs(0).unwrap()[2..],
+            [
+                Some(HopCost { hops: 2, cost: 0 }),
+                Some(HopCost { hops: 2, cost: 3 }),
+                Some(HopCost { hops: 1, cost: 0 }),
+            ]
+        );
+
+        algorithm.set_nodes(vec![(0, vec![(2, 0), (1, 0)]), (1, vec![(2, 0)])]);
+
+        assert_eq!(
+            algorithm.hops_and_costs(0).unwrap()[1..3],
+            [
+                Some(HopCost { hops: 1, cost: 0 }),
+                Some(HopCost { hops: 1, cost: 0 }),
+            ]
+        );
+        assert_eq!(
+            algorithm.shortest_path_tree(0).unwrap().path_to(2),
+            Some(vec![0, 2])
+        );
     }
 
     #[test]
-    fn test_run_large_weights() {
+    fn test_shortest_path_tie_breaking() {
+        // Three paths of cost 4 from 0 to 5: 0-2-5, 0-1-3-5 and 0-4-5.
         let mut algorithm = DijkstraAlgorithm::new();
         algorithm.set_nodes(vec![
-            (0, vec![(1, 1000)]),
-            (1, vec![(2, 1000)]),
-            (2, vec![]),
+            (0, vec![(4, 2), (1, 1), (2, 2)]),
+            (1, vec![(3, 1)]),
+            (2, vec![(5, 2)]),
+            (3, vec![(5, 2)]),
+            (4, vec![(5, 2)]),
+            (5, vec![]),
         ]);
 
-        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 1000, 2000]);
+        assert_eq!(algorithm.shortest_path(0, 5).unwrap().0, 4);
+        assert_eq!(algorithm.shortest_path(5, 0), None);
+
+        algorithm.set_tie_breaking(TieBreaking::SmallerNode);
+        assert_eq!(algorithm.shortest_path(0, 5), Some((4, vec![0, 1, 3, 5])));
+
+        // A zero-weight cycle leads back to the start before the target.
+        let mut cycle = DijkstraAlgorithm::new();
+        cycle.add_weighted_edges(vec![(0, 1, 0), (1, 0, 0), (0, 2, 0)]);
+        cycle.set_tie_breaking(TieBreaking::SmallerNode);
+
+        assert_eq!(cycle.run(Some(0)).unwrap(), vec![0, 0, 0]);
+        assert_eq!(cycle.shortest_path(0, 2), Some((0, vec![0, 2])));
+        assert_eq!(cycle.run_to_target(0, 2), Ok((0, vec![0, 2])));
+
+        algorithm.set_tie_breaking(TieBreaking::FewerHops);
+        assert_eq!(algorithm.shortest_path(0, 5), Some((4, vec![0, 2, 5])));
+
+        // Count the hops and penalize every step into node 2, as if turning there.
+        algorithm.set_tie_breaking(TieBreaking::Penalty(|_, _, next| 1 + (next == 2) as usize));
+        assert_eq!(algorithm.shortest_path(0, 5), Some((4, vec![0, 4, 5])));
+
+        // The penalty may depend on the previous node: only the turns along 0 -> 1 -> 3 -> 5 are straight.
+        algorithm.set_tie_breaking(TieBreaking::Penalty(|previous, node, next| {
+            match (previous, node, next) {
+                (None, _, _) | (Some(0), 1, 3) | (Some(1), 3, 5) => 0,
+                _ => 5,
+            }
+        }));
+        assert_eq!(algorithm.shortest_path(0, 5), Some((4, vec![0, 1, 3, 5])));
+        assert_eq!(algorithm.shortest_path(0, 0), Some((0, vec![0])));
     }
 
     #[test]
-    fn test_run_no_edges() {
+    fn test_shortest_path_preferred() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_nodes(vec![
+            (0, vec![(1, 10), (2, 10)]),
+            (1, vec![(3, 10)]),
+            (2, vec![(3, 11)]),
+            (3, vec![]),
+        ]);
+
+        let (_, previous) = algorithm.shortest_path(0, 3).unwrap();
+        assert_eq!(previous, vec![0, 1, 3]);
+
+        // Traffic makes the previous route slightly slower.
+        algorithm.set_node(0, vec![(1, 12), (2, 10)]);
+        assert_eq!(algorithm.shortest_path(0, 3), Some((21, vec![0, 2, 3])));
+
+        algorithm.set_preferred_path(previous.clone(), 2);
+        assert_eq!(algorithm.shortest_path(0, 3), Some((22, vec![0, 1, 3])));
+
+        // A large enough saving still switches the route.
+        algorithm.set_node(0, vec![(1, 30), (2, 10)]);
+        assert_eq!(algorithm.shortest_path(0, 3), Some((21, vec![0, 2, 3])));
+        assert_eq!(algorithm.preferred_path, previous);
+    }
+
+    #[test]
+    fn test_run_queues() {
         let mut algorithm = DijkstraAlgorithm::new();
-        algorithm.set_nodes(vec![(0, vec![]), (1, vec![]), (2, vec![])]);
 
+        for node in 0..2000 {
+            let edges = (1..4)
+                .map(|step| ((node * 7 + step * 13) % 2000, (node * step) % 97))
+                .collect();
+            algorithm.set_node(node, edges);
+        }
+
+        assert_eq!(algorithm.selected_queue(), PriorityQueueKind::Radix);
+
+        let expected = algorithm.run(Some(0)).unwrap();
+
+        for queue in [
+            PriorityQueueKind::Binary,
+            PriorityQueueKind::FourAry,
+            PriorityQueueKind::Pairing,
+            PriorityQueueKind::Radix,
+        ] {
+            algorithm.set_queue(queue);
+
+            assert_eq!(algorithm.selected_queue(), queue);
+            assert_eq!(algorithm.run(Some(0)).unwrap(), expected);
+        }
+
+        algorithm.set_queue(PriorityQueueKind::Auto);
+        algorithm.set_node(0, vec![(1, 1 << 20)]);
+
+        assert_eq!(algorithm.selected_queue(), PriorityQueueKind::Binary);
         assert_eq!(
-            algorithm.run(Some(0)).unwrap(),
-            vec![0, usize::MAX, usize::MAX]
+            DijkstraAlgorithm::new().selected_queue(),
+            PriorityQueueKind::Binary
         );
     }
 
     #[test]
-    fn test_run_multiple_edges_to_same_node() {
+    fn test_shortest_path_soft_constraints() {
+        const UNPAVED: u64 = 1;
+        const RESIDENTIAL: u64 = 2;
+
+        // Direct unpaved track 0 - 2, or a residential detour through 1.
         let mut algorithm = DijkstraAlgorithm::new();
         algorithm.set_nodes(vec![
-            (0, vec![(1, 1), (1, 2)]),
-            (1, vec![(2, 1)]),
+            (0, vec![(2, 100), (1, 55)]),
+            (1, vec![(2, 55)]),
             (2, vec![]),
         ]);
+        algorithm.set_edge_tags(0, 2, UNPAVED);
+        algorithm.set_edge_tags(0, 1, RESIDENTIAL);
+        algorithm.set_edge_tags(1, 2, RESIDENTIAL);
 
-        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 1, 2]);
+        let unpaved = SoftConstraint {
+            tags: UNPAVED,
+            percent: 20,
+        };
+        let residential = SoftConstraint {
+            tags: RESIDENTIAL,
+            percent: 10,
+        };
+
+        assert_eq!(algorithm.shortest_path(0, 2), Some((100, vec![0, 2])));
+
+        algorithm.set_soft_constraints(vec![unpaved]);
+        assert_eq!(algorithm.shortest_path(0, 2), Some((110, vec![0, 1, 2])));
+
+        algorithm.set_soft_constraints(vec![unpaved, residential]);
+        assert_eq!(algorithm.shortest_path(0, 2), Some((100, vec![0, 2])));
+
+        // Constraints matching several bits of an edge still count once each.
+        algorithm.set_soft_constraints(vec![SoftConstraint {
+            tags: UNPAVED | RESIDENTIAL,
+            percent: 50,
+        }]);
+        assert_eq!(algorithm.shortest_path(0, 2), Some((100, vec![0, 2])));
+
+        // Penalties on large weights saturate instead of overflowing.
+        algorithm.set_node(0, vec![(2, usize::MAX / 2), (1, 55)]);
+        algorithm.set_soft_constraints(vec![unpaved]);
+        assert_eq!(algorithm.shortest_path(0, 2), Some((110, vec![0, 1, 2])));
+
+        // A preferred path through nodes without edges is ignored.
+        algorithm.set_preferred_path(vec![7, 8], 5);
+        assert_eq!(algorithm.shortest_path(0, 2), Some((110, vec![0, 1, 2])));
+
+        // Tie-breaking rules search on the penalized weights too.
+        for rule in [TieBreaking::SmallerNode, TieBreaking::FewerHops] {
+            algorithm.set_tie_breaking(rule);
+            assert_eq!(algorithm.shortest_path(0, 2), Some((110, vec![0, 1, 2])));
+        }
+    }
+
+    #[test]
+    fn test_run_recorded() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_nodes(vec![(0, vec![(1, 2)]), (1, vec![(2, 3)]), (2, vec![])]);
+        algorithm.set_tie_breaking(TieBreaking::FewerHops);
+
+        let recorded = algorithm.run_recorded(Some(0)).unwrap();
+
+        assert_eq!(recorded.value, vec![0, 2, 5]);
+        assert_eq!(recorded.provenance.algorithm, "dijkstra");
+        assert_eq!(
+            recorded.provenance.configuration[..2],
+            [
+                ("tie_breaking", "FewerHops".to_string()),
+                ("queue", "Auto".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_run_to_targets() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.add_weighted_edges(vec![(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 1), (3, 4, 9)]);
+        algorithm.set_total_nodes(6);
+
+        assert_eq!(
+            algorithm.run_to_targets(0, &[3, 1, 5]),
+            Ok(vec![
+                Some((4, vec![0, 2, 1, 3])),
+                Some((3, vec![0, 2, 1])),
+                None
+            ])
+        );
+        assert_eq!(algorithm.run_to_targets(0, &[]), Ok(vec![]));
+
+        for queue in [
+            PriorityQueueKind::FourAry,
+            PriorityQueueKind::Pairing,
+            PriorityQueueKind::Radix,
+        ] {
+            algorithm.set_queue(queue);
+
+            assert_eq!(
+                algorithm.run_to_targets(0, &[3]),
+                Ok(vec![Some((4, vec![0, 2, 1, 3]))])
+            );
+        }
+
+        // Paths whose cost would overflow are clamped instead of panicking.
+        algorithm.add_edge(4, 5, usize::MAX);
+
+        assert_eq!(
+            algorithm.run_to_targets(0, &[5]),
+            Ok(vec![Some((usize::MAX, vec![0, 2, 1, 3, 4, 5]))])
+        );
+        assert_eq!(
+            algorithm.run_to_targets(9, &[1]),
+            Err(GraphError::MissingStartNode)
+        );
+    }
+
+    #[test]
+    fn test_targets_with_hop_ties() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.add_weighted_edges(vec![(0, 1, 1), (1, 4, 0), (4, 2, 1), (0, 3, 2), (3, 2, 0)]);
+
+        for queue in [
+            PriorityQueueKind::Binary,
+            PriorityQueueKind::FourAry,
+            PriorityQueueKind::Pairing,
+            PriorityQueueKind::Radix,
+        ] {
+            algorithm.set_queue(queue);
+
+            assert_eq!(algorithm.run(Some(0)).unwrap()[2], 2);
+            assert_eq!(
+                algorithm.run_to_targets(0, &[2, 3]),
+                Ok(vec![Some((2, vec![0, 3, 2])), Some((2, vec![0, 3]))])
+            );
+            assert_eq!(
+                algorithm.distance_matrix(&[0, 2, 3]),
+                Ok(vec![
+                    vec![0, 2, 2],
+                    vec![usize::MAX, 0, usize::MAX],
+                    vec![usize::MAX, 0, 0],
+                ])
+            );
+            assert_eq!(
+                algorithm.hops_and_costs(0).unwrap()[2],
+                Some(HopCost { hops: 2, cost: 2 })
+            );
+        }
+    }
+
+    #[test]
+    fn test_nearest_targets() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.add_weighted_edges(vec![(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 1), (3, 4, 9)]);
+
+        assert_eq!(
+            algorithm.nearest_targets(0, &[4, 3, 1], 2),
+            Ok(vec![(1, 3, vec![0, 2, 1]), (3, 4, vec![0, 2, 1, 3])])
+        );
+        assert_eq!(algorithm.nearest_targets(0, &[4, 3], 5).unwrap().len(), 2);
+        assert_eq!(algorithm.nearest_targets(0, &[4], 0), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_distance_matrix() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_nodes(vec![
+            (0, vec![(1, 4), (2, 1)]),
+            (1, vec![(3, 1)]),
+            (2, vec![(1, 2), (3, 5)]),
+            (3, vec![(0, 3)]),
+            (4, vec![(0, 1)]),
+        ]);
+
+        assert_eq!(
+            algorithm.distance_matrix(&[3, 1, 4]),
+            Ok(vec![
+                vec![0, 6, usize::MAX],
+                vec![1, 0, usize::MAX],
+                vec![5, 4, 0],
+            ])
+        );
+        assert_eq!(algorithm.distance_matrix(&[]), Ok(vec![]));
+
+        let distances = algorithm.run(Some(4)).unwrap();
+        let nodes = [0, 1, 2, 3, 4];
+        let matrix = algorithm.distance_matrix(&nodes).unwrap();
+
+        assert_eq!(matrix[4], distances);
+    }
+
+    #[test]
+    fn test_try_run() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_nodes(vec![(0, vec![(1, 2)]), (1, vec![])]);
+
+        assert_eq!(algorithm.try_run(Some(0)).unwrap(), vec![0, 2]);
+        assert_eq!(
+            algorithm.try_run(Some(5)),
+            Err(GraphError::MissingStartNode)
+        );
+
+        algorithm.set_node(1, vec![(0, usize::MAX)]);
+
+        assert_eq!(algorithm.try_run(Some(0)), Err(GraphError::WeightOverflow));
+
+        algorithm.set_node(1, vec![(9, 1)]);
+
+        assert_eq!(
+            algorithm.try_run(Some(0)),
+            Err(GraphError::NodeOutOfRange(9))
+        );
     }
 
     #[test]
@@ -342,4 +1800,282 @@ mod tests {
 
         assert_eq!(algorithm.run(Some(0)).unwrap(), vec![0, 1, 2, usize::MAX]);
     }
+
+    #[test]
+    fn test_run_to_target() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_nodes(vec![
+            (0, vec![(1, 4), (2, 1)]),
+            (1, vec![(3, 1)]),
+            (2, vec![(1, 2), (3, 5)]),
+            (3, vec![]),
+            (4, vec![(0, 1)]),
+        ]);
+
+        assert_eq!(algorithm.run_to_target(0, 3), Ok((4, vec![0, 2, 1, 3])));
+        assert_eq!(algorithm.run_to_target(2, 2), Ok((0, vec![2])));
+        assert_eq!(
+            algorithm.run_to_target(0, 4),
+            Err(GraphError::MissingTargetNode)
+        );
+        assert_eq!(
+            algorithm.run_to_target(0, 7),
+            Err(GraphError::MissingTargetNode)
+        );
+        assert_eq!(
+            algorithm.run_to_target(7, 0),
+            Err(GraphError::MissingStartNode)
+        );
+
+        for queue in [
+            PriorityQueueKind::FourAry,
+            PriorityQueueKind::Pairing,
+            PriorityQueueKind::Radix,
+        ] {
+            algorithm.set_queue(queue);
+
+            assert_eq!(algorithm.run_to_target(4, 3), Ok((5, vec![4, 0, 2, 1, 3])));
+        }
+
+        algorithm.set_tie_breaking(TieBreaking::SmallerNode);
+
+        assert_eq!(algorithm.run_to_target(0, 3), Ok((4, vec![0, 2, 1, 3])));
+    }
+
+    #[test]
+    fn test_run_to_target_stops_at_target() {
+        // Settling node 2 would overflow on its edge, so the search must stop at node 1.
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.set_nodes(vec![
+            (0, vec![(1, 1), (2, 5)]),
+            (1, vec![]),
+            (2, vec![(3, usize::MAX)]),
+            (3, vec![]),
+        ]);
+
+        assert_eq!(algorithm.run_to_target(0, 1), Ok((1, vec![0, 1])));
+
+        // Paths whose cost would overflow are clamped instead of panicking.
+        algorithm.set_node(1, vec![(3, usize::MAX)]);
+
+        assert_eq!(
+            algorithm.run_to_target(0, 3),
+            Ok((usize::MAX, vec![0, 1, 3]))
+        );
+    }
+
+    #[test]
+    fn test_shortest_path_tree() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.add_weighted_edges(vec![(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 1), (3, 0, 5)]);
+        algorithm.set_total_nodes(5);
+
+        let tree = algorithm.shortest_path_tree(0).unwrap();
+
+        assert_eq!(tree.distances, algorithm.run(Some(0)).unwrap());
+        assert_eq!(tree.predecessors, vec![usize::MAX, 2, 0, 1, usize::MAX]);
+        assert_eq!(tree.path_to(3), Some(vec![0, 2, 1, 3]));
+        assert_eq!(tree.distance(3), Some(4));
+        assert_eq!(tree.path_to(0), Some(vec![0]));
+        assert_eq!(tree.path_to(4), None);
+        assert_eq!(tree.path_to(7), None);
+        assert_eq!(
+            algorithm.shortest_path_tree(9),
+            Err(GraphError::MissingStartNode)
+        );
+    }
+
+    #[test]
+    fn test_weight_types() {
+        let mut algorithm = DijkstraAlgorithm::<f64>::with_weight_type();
+        algorithm.add_weighted_edges([(0, 1, 0.5), (1, 2, 0.25), (0, 2, 1.0)]);
+        algorithm.set_total_nodes(4);
+
+        assert_eq!(
+            algorithm.distances(0),
+            Ok(vec![0.0, 0.5, 0.75, f64::INFINITY])
+        );
+        assert_eq!(
+            algorithm.shortest_path_tree(0).unwrap().path_to(2),
+            Some(vec![0, 1, 2])
+        );
+
+        let mut algorithm = DijkstraAlgorithm::<u64>::with_weight_type();
+        algorithm.add_weighted_edges([(0, 1, u64::MAX - 1), (1, 2, 5)]);
+
+        assert_eq!(algorithm.distances(0), Ok(vec![0, u64::MAX - 1, u64::MAX]));
+        assert_eq!(algorithm.distances(3), Err(GraphError::MissingStartNode));
+    }
+
+    #[test]
+    fn test_weight_types_queries() {
+        let mut algorithm = DijkstraAlgorithm::<f64>::with_weight_type();
+        algorithm.add_weighted_edges([(0, 1, 0.5), (1, 2, 0.25), (0, 2, 1.0), (2, 3, 2.0)]);
+
+        assert_eq!(algorithm.run(Some(0)), Ok(vec![0.0, 0.5, 0.75, 2.75]));
+        assert_eq!(algorithm.run_to_target(0, 2), Ok((0.75, vec![0, 1, 2])));
+        assert_eq!(
+            algorithm.shortest_path(0, 3),
+            Some((2.75, vec![0, 1, 2, 3]))
+        );
+        assert_eq!(
+            algorithm.run_to_targets(0, &[3, 1]),
+            Ok(vec![
+                Some((2.75, vec![0, 1, 2, 3])),
+                Some((0.5, vec![0, 1]))
+            ])
+        );
+        assert_eq!(
+            algorithm.nearest_targets(0, &[3, 2], 1),
+            Ok(vec![(2, 0.75, vec![0, 1, 2])])
+        );
+        assert_eq!(
+            algorithm.distance_matrix(&[0, 3]),
+            Ok(vec![vec![0.0, 2.75], vec![f64::INFINITY, 0.0]])
+        );
+        assert_eq!(
+            algorithm.hops_and_costs(0).unwrap()[3],
+            Some(HopCost {
+                hops: 3,
+                cost: 2.75
+            })
+        );
+
+        algorithm.set_queue(PriorityQueueKind::Radix);
+        assert_eq!(algorithm.run(Some(0)), Ok(vec![0.0, 0.5, 0.75, 2.75]));
+
+        algorithm.set_tie_breaking(TieBreaking::SmallerNode);
+        assert_eq!(
+            algorithm.shortest_path(0, 3),
+            Some((2.75, vec![0, 1, 2, 3]))
+        );
+
+        algorithm.set_preferred_path(vec![0, 2, 3], 0.5);
+        assert_eq!(algorithm.shortest_path(0, 3), Some((3.0, vec![0, 2, 3])));
+    }
+
+    #[test]
+    fn test_negative_weights() {
+        // A negative cycle between 1 and 2 would keep improving the distances forever.
+        let mut graph = DiGraph::new();
+        graph.add_weighted_edges([(0, 1, 1i64), (1, 2, -3), (2, 1, 1)]);
+
+        assert_eq!(
+            DijkstraAlgorithm::run_on(&graph, 0),
+            Err(GraphError::NegativeWeight(1, 2))
+        );
+
+        let mut algorithm = DijkstraAlgorithm::<i64>::with_weight_type();
+        algorithm.add_weighted_edges(graph.edges());
+
+        assert_eq!(
+            algorithm.run(Some(0)),
+            Err(GraphError::NegativeWeight(1, 2))
+        );
+        assert_eq!(
+            algorithm.try_run(Some(2)),
+            Err(GraphError::NegativeWeight(1, 2))
+        );
+        assert_eq!(
+            algorithm.run_to_target(0, 2),
+            Err(GraphError::NegativeWeight(1, 2))
+        );
+        assert_eq!(
+            algorithm.distance_matrix(&[0, 2]),
+            Err(GraphError::NegativeWeight(1, 2))
+        );
+        assert_eq!(algorithm.shortest_path(0, 2), None);
+        assert_eq!(
+            algorithm.hops_and_costs(0),
+            Err(GraphError::NegativeWeight(1, 2))
+        );
+
+        algorithm.set_tie_breaking(TieBreaking::FewerHops);
+        assert_eq!(
+            algorithm.run_to_target(0, 2),
+            Err(GraphError::NegativeWeight(1, 2))
+        );
+
+        // Searches that stop before reaching a negative weight still succeed.
+        assert_eq!(
+            algorithm.run_to_targets(0, &[0]),
+            Ok(vec![Some((0, vec![0]))])
+        );
+
+        let mut algorithm = DijkstraAlgorithm::<f64>::with_weight_type();
+        algorithm.add_edge(0, 1, f64::NAN);
+
+        assert_eq!(
+            algorithm.run(Some(0)),
+            Err(GraphError::NegativeWeight(0, 1))
+        );
+    }
+
+    #[test]
+    fn test_run_on() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edges([(0, 1, 4i64), (1, 2, 1), (0, 2, 6)]);
+        graph.set_total_nodes(4);
+
+        let tree = DijkstraAlgorithm::run_on(&graph, 2).unwrap();
+
+        assert_eq!(tree.distances, vec![5, 1, 0, i64::MAX]);
+        assert_eq!(tree.path_to(0), Some(vec![2, 1, 0]));
+        assert_eq!(
+            DijkstraAlgorithm::run_on(&graph, 4),
+            Err(GraphError::MissingStartNode)
+        );
+    }
+
+    #[test]
+    fn test_estimate_memory() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.add_weighted_edges(vec![(0, 1, 4), (1, 2, 3), (0, 2, 9)]);
+
+        let estimate = algorithm.estimate_memory();
+
+        assert_eq!(estimate.output, 3 * std::mem::size_of::<usize>());
+        assert_eq!(estimate.working, 7 * std::mem::size_of::<(usize, usize)>());
+        assert_eq!(
+            algorithm.run_within(Some(0), estimate.total() - 1),
+            Err(GraphError::MemoryLimitExceeded(estimate.total()))
+        );
+        assert_eq!(
+            algorithm.run_within(Some(0), estimate.total()),
+            Ok(vec![0, 4, 7])
+        );
+    }
+
+    #[test]
+    fn test_run_observed() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.add_weighted_edges(vec![(0, 1, 4), (0, 2, 1), (2, 1, 2)]);
+
+        let mut events = Vec::new();
+        let distances = algorithm.run_observed(Some(0), &mut events).unwrap();
+
+        assert_eq!(distances, algorithm.run(Some(0)).unwrap());
+
+        let settled: Vec<(usize, i64)> = events
+            .iter()
+            .filter_map(|event| match *event {
+                AlgorithmEvent::NodeSettled { node, distance } => Some((node, distance)),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(settled, vec![(0, 0), (2, 1), (1, 3)]);
+        assert!(events.contains(&AlgorithmEvent::DistanceUpdated {
+            node: 1,
+            distance: 3,
+            predecessor: Some(2)
+        }));
+        assert_eq!(
+            events
+                .iter()
+                .filter(|event| matches!(event, AlgorithmEvent::EdgeRelaxed { .. }))
+                .count(),
+            3
+        );
+    }
 }
@@ -0,0 +1,102 @@
+use std::cmp::Ordering;
+
+/// Disjoint-set forest used to track connected components.
+#[derive(Debug, Clone)]
+pub(crate) struct DisjointSet {
+    /// Parent of each node.
+    parent: Vec<usize>,
+
+    /// Upper bound of the height of each tree.
+    rank: Vec<usize>,
+}
+
+impl DisjointSet {
+    /// Create a new disjoint-set forest where each node is its own set.
+    ///
+    /// # Arguments
+    ///
+    /// - `size`: Number of nodes.
+    ///
+    /// # Returns
+    ///
+    /// New disjoint-set forest.
+    pub(crate) fn new(size: usize) -> Self {
+        Self {
+            parent: (0..size).collect(),
+            rank: vec![0; size],
+        }
+    }
+
+    /// Find the representative of a node's set.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node to look up.
+    ///
+    /// # Returns
+    ///
+    /// Representative of the set.
+    pub(crate) fn find(&mut self, node: usize) -> usize {
+        let mut root = node;
+
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // Compress the path to the root.
+        let mut current = node;
+
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    /// Merge the sets of two nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `a`: First node.
+    /// - `b`: Second node.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the nodes were in different sets.
+    pub(crate) fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+
+        if a == b {
+            return false;
+        }
+
+        match self.rank[a].cmp(&self.rank[b]) {
+            Ordering::Less => self.parent[a] = b,
+            Ordering::Greater => self.parent[b] = a,
+            Ordering::Equal => {
+                self.parent[b] = a;
+                self.rank[a] += 1;
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_find() {
+        let mut components = DisjointSet::new(4);
+
+        assert!(components.union(0, 1));
+        assert!(components.union(2, 3));
+        assert!(!components.union(1, 0));
+        assert!(components.union(1, 3));
+        assert_eq!(components.find(0), components.find(2));
+    }
+}
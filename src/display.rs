@@ -0,0 +1,285 @@
+use std::{collections::HashSet, fmt};
+
+/// Graph Display.
+/// Render a small weighted graph as an aligned adjacency table or an ASCII edge list, optionally highlighting a path.
+#[derive(Debug, Clone)]
+pub struct GraphDisplay {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Edges in the graph as `(source, target, weight)`.
+    pub edges: Vec<(usize, usize, usize)>,
+
+    /// Whether the edges are directed.
+    pub directed: bool,
+
+    /// Path to highlight, as a sequence of nodes.
+    pub path: Vec<usize>,
+}
+
+impl Default for GraphDisplay {
+    /// Create a new default instance of the graph display.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the graph display.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GraphDisplay {
+    /// Create a new instance of the graph display for a directed graph.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the graph display.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            edges: Vec::new(),
+            directed: true,
+            path: Vec::new(),
+        }
+    }
+
+    /// Set a single edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.edges.push((source, target, weight));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+    }
+
+    /// Set whether the edges are directed.
+    ///
+    /// # Arguments
+    ///
+    /// - `directed`: `true` for a directed graph, `false` for an undirected one.
+    pub fn set_directed(&mut self, directed: bool) {
+        self.directed = directed;
+    }
+
+    /// Set the path to highlight.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: Sequence of nodes, e.g. the result of a shortest path query.
+    pub fn set_path(&mut self, path: Vec<usize>) {
+        self.path = path;
+    }
+
+    /// Collect the highlighted edges of the path.
+    ///
+    /// # Returns
+    ///
+    /// Set of highlighted `(source, target)` pairs, in both directions for undirected graphs.
+    fn highlighted(&self) -> HashSet<(usize, usize)> {
+        self.path
+            .windows(2)
+            .flat_map(|pair| {
+                let reverse = (!self.directed).then_some((pair[1], pair[0]));
+                std::iter::once((pair[0], pair[1])).chain(reverse)
+            })
+            .collect()
+    }
+
+    /// Format the graph as an aligned adjacency table.
+    /// Each cell holds the lightest edge weight, `.` when there is no edge, and highlighted edges are wrapped in brackets.
+    ///
+    /// # Returns
+    ///
+    /// Adjacency table, one row per node.
+    pub fn fmt_adjacency(&self) -> String {
+        let mut matrix = vec![vec![None; self.total_nodes]; self.total_nodes];
+
+        for &(source, target, weight) in &self.edges {
+            let mut cells = vec![(source, target)];
+
+            if !self.directed {
+                cells.push((target, source));
+            }
+
+            for (row, column) in cells {
+                let cell: &mut Option<usize> = &mut matrix[row][column];
+                *cell = Some(cell.map_or(weight, |current| current.min(weight)));
+            }
+        }
+
+        let highlighted = self.highlighted();
+        let cells: Vec<Vec<String>> = matrix
+            .iter()
+            .enumerate()
+            .map(|(row, weights)| {
+                weights
+                    .iter()
+                    .enumerate()
+                    .map(|(column, weight)| match weight {
+                        Some(weight) if highlighted.contains(&(row, column)) => {
+                            format!("[{weight}]")
+                        }
+                        Some(weight) => weight.to_string(),
+                        None => ".".to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let label_width = self.total_nodes.saturating_sub(1).to_string().len();
+        let widths: Vec<usize> = (0..self.total_nodes)
+            .map(|column| {
+                cells
+                    .iter()
+                    .map(|row| row[column].len())
+                    .chain(std::iter::once(column.to_string().len()))
+                    .max()
+                    .unwrap_or(1)
+            })
+            .collect();
+
+        let mut output = format!("{:>label_width$} |", "");
+
+        for (column, width) in widths.iter().enumerate() {
+            output.push_str(&format!(" {column:>width$}"));
+        }
+
+        for (row, values) in cells.iter().enumerate() {
+            output.push_str(&format!("\n{row:>label_width$} |"));
+
+            for (value, width) in values.iter().zip(&widths) {
+                output.push_str(&format!(" {value:>width$}"));
+            }
+        }
+
+        output
+    }
+
+    /// Format the graph as an ASCII edge list, sorted by source and target.
+    /// Highlighted edges are marked with `*`.
+    ///
+    /// # Returns
+    ///
+    /// Edge list, one line per edge.
+    pub fn fmt_edge_list(&self) -> String {
+        let highlighted = self.highlighted();
+        let arrow = if self.directed { "->" } else { "--" };
+        let mut edges = self.edges.clone();
+        edges.sort_unstable();
+
+        edges
+            .iter()
+            .map(|&(source, target, weight)| {
+                let marker = if highlighted.contains(&(source, target)) {
+                    " *"
+                } else {
+                    ""
+                };
+
+                format!("{source} {arrow} {target} ({weight}){marker}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl fmt::Display for GraphDisplay {
+    /// Format the graph as an aligned adjacency table.
+    ///
+    /// # Arguments
+    ///
+    /// - `f`: Formatter.
+    ///
+    /// # Returns
+    ///
+    /// Result of the formatting.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.fmt_adjacency())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let display = GraphDisplay::new();
+        let display_default = GraphDisplay::default();
+
+        assert!(display.directed);
+        assert_eq!(display_default.edges.len(), 0);
+    }
+
+    #[test]
+    fn test_fmt_adjacency() {
+        let mut display = GraphDisplay::new();
+        display.set_edges(vec![(0, vec![(1, 4), (2, 12)]), (1, vec![(2, 3)])]);
+        display.set_path(vec![0, 1, 2]);
+
+        assert_eq!(
+            display.fmt_adjacency(),
+            "  | 0   1   2\n0 | . [4]  12\n1 | .   . [3]\n2 | .   .   ."
+        );
+        assert_eq!(display.to_string(), display.fmt_adjacency());
+    }
+
+    #[test]
+    fn test_fmt_adjacency_undirected() {
+        let mut display = GraphDisplay::new();
+        display.set_directed(false);
+        display.set_edge(0, 1, 5);
+        display.set_edge(1, 0, 2);
+
+        assert_eq!(display.fmt_adjacency(), "  | 0 1\n0 | . 2\n1 | 2 .");
+    }
+
+    #[test]
+    fn test_fmt_edge_list() {
+        let mut display = GraphDisplay::new();
+        display.set_edges(vec![(1, vec![(2, 3)]), (0, vec![(1, 4)])]);
+        display.set_path(vec![1, 2]);
+
+        assert_eq!(display.fmt_edge_list(), "0 -> 1 (4)\n1 -> 2 (3) *");
+
+        display.set_directed(false);
+        display.set_path(vec![2, 1]);
+
+        assert_eq!(display.fmt_edge_list(), "0 -- 1 (4)\n1 -- 2 (3) *");
+    }
+
+    #[test]
+    fn test_fmt_empty() {
+        let display = GraphDisplay::new();
+
+        assert_eq!(display.fmt_adjacency(), "  |");
+        assert_eq!(display.fmt_edge_list(), "");
+    }
+}
@@ -0,0 +1,340 @@
+use std::collections::BinaryHeap;
+
+use crate::{dijkstra::State, GraphAlgorithm, GraphError};
+
+/// Eccentricity, Diameter and Radius.
+/// Measure how far every node of a connected undirected graph is from the farthest other node.
+/// The exact mode searches from every node, while the bounding modes prune most searches:
+/// iFUB for the diameter, and eccentricity bounds from the triangle inequality for the radius.
+#[derive(Debug, Clone)]
+pub struct EccentricityAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Neighbors of each node with the weight of the edge.
+    pub adjacency: Vec<Vec<(usize, usize)>>,
+}
+
+impl Default for EccentricityAlgorithm {
+    /// Create a new default instance of the eccentricity algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the eccentricity algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EccentricityAlgorithm {
+    /// Create a new instance of the eccentricity algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the eccentricity algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].push((target, weight));
+        self.adjacency[target].push((source, weight));
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, Vec::new());
+    }
+
+    /// Compute the distances from a source with Dijkstra's Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    ///
+    /// # Returns
+    ///
+    /// Distance to each node, `usize::MAX` if unreachable.
+    pub fn distances(&self, source: usize) -> Vec<usize> {
+        let mut distances = vec![usize::MAX; self.total_nodes];
+        let mut queue = BinaryHeap::from([State {
+            cost: 0,
+            position: source,
+        }]);
+
+        distances[source] = 0;
+
+        while let Some(State { cost, position }) = queue.pop() {
+            if cost > distances[position] {
+                continue;
+            }
+
+            for &(neighbor, weight) in &self.adjacency[position] {
+                if cost + weight < distances[neighbor] {
+                    distances[neighbor] = cost + weight;
+                    queue.push(State {
+                        cost: cost + weight,
+                        position: neighbor,
+                    });
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Compute the eccentricity of a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node to measure.
+    ///
+    /// # Returns
+    ///
+    /// Largest distance from the node, `usize::MAX` if some node is unreachable.
+    pub fn eccentricity(&self, node: usize) -> usize {
+        self.distances(node).into_iter().max().unwrap_or(0)
+    }
+
+    /// Compute the eccentricity of every node exactly, searching from every node.
+    ///
+    /// # Returns
+    ///
+    /// Eccentricity of each node, `usize::MAX` if the graph is disconnected.
+    pub fn eccentricities(&self) -> Vec<usize> {
+        (0..self.total_nodes)
+            .map(|node| self.eccentricity(node))
+            .collect()
+    }
+
+    /// Compute the diameter exactly, searching from every node.
+    ///
+    /// # Returns
+    ///
+    /// Largest eccentricity, or `None` if the graph is empty or disconnected.
+    pub fn diameter(&self) -> Option<usize> {
+        self.eccentricities()
+            .into_iter()
+            .max()
+            .filter(|&diameter| diameter != usize::MAX)
+    }
+
+    /// Compute the radius exactly, searching from every node.
+    ///
+    /// # Returns
+    ///
+    /// Smallest eccentricity, or `None` if the graph is empty or disconnected.
+    pub fn radius(&self) -> Option<usize> {
+        self.eccentricities()
+            .into_iter()
+            .min()
+            .filter(|&radius| radius != usize::MAX)
+    }
+
+    /// Find the center of the graph.
+    ///
+    /// # Returns
+    ///
+    /// Nodes whose eccentricity equals the radius, empty if the graph is disconnected.
+    pub fn center(&self) -> Vec<usize> {
+        let eccentricities = self.eccentricities();
+        let radius = eccentricities.iter().copied().min().unwrap_or(usize::MAX);
+
+        match radius {
+            usize::MAX => Vec::new(),
+            _ => (0..self.total_nodes)
+                .filter(|&node| eccentricities[node] == radius)
+                .collect(),
+        }
+    }
+
+    /// Compute the diameter exactly with iFUB (iterative fringe upper bound). Starting from a node
+    /// of highest degree, the nodes are searched from the farthest: once the largest eccentricity
+    /// found is at least twice the distance of the next node, no pair left can be farther apart.
+    ///
+    /// # Returns
+    ///
+    /// Diameter, or `None` if the graph is empty or disconnected.
+    pub fn bounded_diameter(&self) -> Option<usize> {
+        let start = (0..self.total_nodes).max_by_key(|&node| self.adjacency[node].len())?;
+        let from = self.distances(start);
+        let mut lower = from.iter().copied().max()?;
+
+        if lower == usize::MAX {
+            return None;
+        }
+
+        let mut order: Vec<usize> = (0..self.total_nodes).collect();
+        order.sort_by_key(|&node| std::cmp::Reverse(from[node]));
+
+        for node in order {
+            if lower >= 2 * from[node] {
+                break;
+            }
+
+            lower = lower.max(self.eccentricity(node));
+        }
+
+        Some(lower)
+    }
+
+    /// Compute the radius exactly with eccentricity bounds. Every search from a node `v` bounds
+    /// the eccentricity of each node `w` below by `max(d(v, w), ecc(v) - d(v, w))` and above by
+    /// `ecc(v) + d(v, w)`, so searches stop once no node can beat the best eccentricity found.
+    ///
+    /// # Returns
+    ///
+    /// Radius, or `None` if the graph is empty or disconnected.
+    pub fn bounded_radius(&self) -> Option<usize> {
+        let n = self.total_nodes;
+        let mut lower = vec![0; n];
+        let mut upper = vec![usize::MAX; n];
+        let mut searched = vec![false; n];
+        let mut radius = usize::MAX;
+
+        // Search the most promising node first, with the smallest upper bound.
+        while let Some(node) = (0..n)
+            .filter(|&node| !searched[node] && lower[node] < radius)
+            .min_by_key(|&node| (upper[node], lower[node], node))
+        {
+            let distances = self.distances(node);
+            let eccentricity = distances.iter().copied().max().unwrap_or(0);
+
+            if eccentricity == usize::MAX {
+                return None;
+            }
+
+            searched[node] = true;
+            radius = radius.min(eccentricity);
+
+            for (other, &distance) in distances.iter().enumerate() {
+                lower[other] = lower[other].max(distance).max(eccentricity - distance);
+                upper[other] = upper[other].min(eccentricity + distance);
+            }
+        }
+
+        (radius != usize::MAX).then_some(radius)
+    }
+}
+
+impl GraphAlgorithm for EccentricityAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<usize>;
+
+    /// Run the eccentricity algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the eccentricity algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the eccentricity of each node.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.eccentricities())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Random;
+
+    #[test]
+    fn test_new() {
+        let algorithm = EccentricityAlgorithm::new();
+        let algorithm_default = EccentricityAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.diameter(), None);
+        assert_eq!(algorithm.bounded_diameter(), None);
+        assert_eq!(algorithm.bounded_radius(), None);
+    }
+
+    #[test]
+    fn test_run() {
+        // Path 0 - 1 - 2 - 3 with a branch 1 - 4.
+        let mut algorithm = EccentricityAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 1)]),
+            (1, vec![(2, 1), (4, 1)]),
+            (2, vec![(3, 1)]),
+        ]);
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![3, 2, 2, 3, 3]);
+        assert_eq!(algorithm.diameter(), Some(3));
+        assert_eq!(algorithm.radius(), Some(2));
+        assert_eq!(algorithm.center(), vec![1, 2]);
+        assert_eq!(algorithm.bounded_diameter(), Some(3));
+        assert_eq!(algorithm.bounded_radius(), Some(2));
+    }
+
+    #[test]
+    fn test_run_disconnected() {
+        let mut algorithm = EccentricityAlgorithm::new();
+        algorithm.set_edge(0, 1, 1);
+        algorithm.set_total_nodes(3);
+
+        assert_eq!(algorithm.eccentricity(0), usize::MAX);
+        assert_eq!(algorithm.diameter(), None);
+        assert_eq!(algorithm.radius(), None);
+        assert_eq!(algorithm.center(), Vec::<usize>::new());
+        assert_eq!(algorithm.bounded_diameter(), None);
+        assert_eq!(algorithm.bounded_radius(), None);
+    }
+
+    #[test]
+    fn test_bounded_random() {
+        let mut random = Random::new(5);
+
+        for _ in 0..20 {
+            // Random spanning tree plus extra edges keeps the graph connected.
+            let mut algorithm = EccentricityAlgorithm::new();
+
+            for node in 1..40 {
+                algorithm.set_edge(random.below(node), node, 1 + random.below(9));
+            }
+
+            for _ in 0..20 {
+                algorithm.set_edge(random.below(40), random.below(40), 1 + random.below(9));
+            }
+
+            assert_eq!(algorithm.bounded_diameter(), algorithm.diameter());
+            assert_eq!(algorithm.bounded_radius(), algorithm.radius());
+        }
+    }
+}
@@ -0,0 +1,256 @@
+use crate::{DijkstraAlgorithm, GraphAlgorithm};
+
+/// Operation of an alignment between two sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOperation {
+    /// Source element at the first index equals the target element at the second index.
+    Match(usize, usize),
+
+    /// Source element at the first index is replaced by the target element at the second index.
+    Substitute(usize, usize),
+
+    /// Target element at the index is inserted.
+    Insert(usize),
+
+    /// Source element at the index is deleted.
+    Delete(usize),
+}
+
+/// Optimal alignment of two sequences.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alignment {
+    /// Total cost of the operations, the weighted edit distance.
+    pub cost: usize,
+
+    /// Operations in sequence order.
+    pub operations: Vec<EditOperation>,
+}
+
+/// Edit Graph.
+/// Align two sequences as a shortest path problem: node `(i, j)` of a layered grid stands for the first
+/// `i` source elements aligned with the first `j` target elements, and its edges delete a source element,
+/// insert a target element, or match or substitute both. Dijkstra's Algorithm finds the cheapest path
+/// from `(0, 0)` to the last node, whose edges are the operations of an optimal alignment.
+#[derive(Debug, Clone)]
+pub struct EditGraph {
+    /// Cost of inserting a target element.
+    pub insertion_cost: usize,
+
+    /// Cost of deleting a source element.
+    pub deletion_cost: usize,
+
+    /// Cost of substituting a source element by a different target element.
+    pub substitution_cost: usize,
+}
+
+impl Default for EditGraph {
+    /// Create a new default instance of the edit graph.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the edit graph.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EditGraph {
+    /// Create a new instance of the edit graph with unit costs, giving the Levenshtein distance.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the edit graph.
+    pub fn new() -> Self {
+        Self {
+            insertion_cost: 1,
+            deletion_cost: 1,
+            substitution_cost: 1,
+        }
+    }
+
+    /// Set the costs of the operations.
+    ///
+    /// # Arguments
+    ///
+    /// - `insertion`: Cost of inserting a target element.
+    /// - `deletion`: Cost of deleting a source element.
+    /// - `substitution`: Cost of substituting a source element.
+    pub fn set_costs(&mut self, insertion: usize, deletion: usize, substitution: usize) {
+        self.insertion_cost = insertion;
+        self.deletion_cost = deletion;
+        self.substitution_cost = substitution;
+    }
+
+    /// Compute the cost of the diagonal edge into a grid node.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source sequence.
+    /// - `target`: Target sequence.
+    /// - `i`: Index of the source element.
+    /// - `j`: Index of the target element.
+    ///
+    /// # Returns
+    ///
+    /// Zero for equal elements, the substitution cost otherwise.
+    fn diagonal<T: PartialEq>(&self, source: &[T], target: &[T], i: usize, j: usize) -> usize {
+        match source[i] == target[j] {
+            true => 0,
+            false => self.substitution_cost,
+        }
+    }
+
+    /// Build the edit graph of two sequences.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source sequence.
+    /// - `target`: Target sequence.
+    ///
+    /// # Returns
+    ///
+    /// Graph whose node `i * (target.len() + 1) + j` is the grid node `(i, j)`.
+    pub fn build<T: PartialEq>(&self, source: &[T], target: &[T]) -> DijkstraAlgorithm {
+        let (n, m) = (source.len(), target.len());
+        let id = |i: usize, j: usize| i * (m + 1) + j;
+        let mut graph = DijkstraAlgorithm::new();
+
+        for i in 0..=n {
+            for j in 0..=m {
+                let mut edges = Vec::with_capacity(3);
+
+                if i < n {
+                    edges.push((id(i + 1, j), self.deletion_cost));
+                }
+
+                if j < m {
+                    edges.push((id(i, j + 1), self.insertion_cost));
+                }
+
+                if i < n && j < m {
+                    edges.push((id(i + 1, j + 1), self.diagonal(source, target, i, j)));
+                }
+
+                graph.set_node(id(i, j), edges);
+            }
+        }
+
+        graph
+    }
+
+    /// Align two sequences with minimal total cost.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source sequence.
+    /// - `target`: Target sequence.
+    ///
+    /// # Returns
+    ///
+    /// Optimal alignment, preferring matches and substitutions, then deletions, on equal costs.
+    pub fn align<T: PartialEq>(&self, source: &[T], target: &[T]) -> Alignment {
+        let (n, m) = (source.len(), target.len());
+        let id = |i: usize, j: usize| i * (m + 1) + j;
+        let distances = self.build(source, target).run(Some(0)).unwrap_or_default();
+
+        // Walk back from the last node along edges that are tight for the distances.
+        let (mut i, mut j) = (n, m);
+        let mut operations = Vec::with_capacity(n.max(m));
+
+        while i > 0 || j > 0 {
+            let distance = distances[id(i, j)];
+
+            if i > 0 && j > 0 {
+                let cost = self.diagonal(source, target, i - 1, j - 1);
+
+                if distances[id(i - 1, j - 1)] + cost == distance {
+                    operations.push(match cost == 0 && source[i - 1] == target[j - 1] {
+                        true => EditOperation::Match(i - 1, j - 1),
+                        false => EditOperation::Substitute(i - 1, j - 1),
+                    });
+                    (i, j) = (i - 1, j - 1);
+                    continue;
+                }
+            }
+
+            if i > 0 && distances[id(i - 1, j)] + self.deletion_cost == distance {
+                operations.push(EditOperation::Delete(i - 1));
+                i -= 1;
+            } else {
+                operations.push(EditOperation::Insert(j - 1));
+                j -= 1;
+            }
+        }
+
+        operations.reverse();
+
+        Alignment {
+            cost: distances[id(n, m)],
+            operations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use EditOperation::*;
+
+    #[test]
+    fn test_new() {
+        let graph = EditGraph::new();
+        let graph_default = EditGraph::default();
+
+        assert_eq!(graph.insertion_cost, 1);
+        assert_eq!(graph_default.substitution_cost, 1);
+    }
+
+    #[test]
+    fn test_align() {
+        let graph = EditGraph::new();
+        let alignment = graph.align(b"kitten", b"sitting");
+
+        assert_eq!(alignment.cost, 3);
+        assert_eq!(
+            alignment.operations,
+            vec![
+                Substitute(0, 0),
+                Match(1, 1),
+                Match(2, 2),
+                Match(3, 3),
+                Substitute(4, 4),
+                Match(5, 5),
+                Insert(6),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_align_costs() {
+        // With expensive substitutions, a deletion and an insertion are cheaper.
+        let mut graph = EditGraph::new();
+        graph.set_costs(1, 1, 3);
+
+        let alignment = graph.align(&['a', 'b'], &['a', 'c']);
+
+        assert_eq!(alignment.cost, 2);
+        assert_eq!(
+            alignment.operations,
+            vec![Match(0, 0), Insert(1), Delete(1)]
+        );
+    }
+
+    #[test]
+    fn test_align_empty() {
+        let graph = EditGraph::new();
+        let empty: [u8; 0] = [];
+
+        assert_eq!(graph.align(&empty, &empty).operations, vec![]);
+        assert_eq!(
+            graph.align(b"ab", &empty).operations,
+            vec![Delete(0), Delete(1)]
+        );
+        assert_eq!(graph.align(&empty, b"a").cost, 1);
+        assert_eq!(graph.build(b"ab", b"abc").graph.len(), 12);
+    }
+}
@@ -0,0 +1,508 @@
+use std::collections::BTreeMap;
+
+use crate::{random::Random, ConfiguredAlgorithm, GraphAlgorithm, GraphError, Provenance};
+
+/// Node2vec Embedding.
+/// Learn a vector per node of an undirected weighted graph by training a skip-gram model with
+/// negative sampling on biased second-order random walks.
+#[derive(Debug, Clone)]
+pub struct Node2VecAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Neighbors of each node with the weight of the edge.
+    pub adjacency: Vec<BTreeMap<usize, f64>>,
+
+    /// Return parameter `p`: higher values make walks less likely to step back.
+    pub return_parameter: f64,
+
+    /// In-out parameter `q`: higher values keep walks local, lower values make them explore.
+    pub in_out_parameter: f64,
+
+    /// Number of walks started from each node.
+    pub walks_per_node: usize,
+
+    /// Number of nodes in each walk.
+    pub walk_length: usize,
+
+    /// Number of dimensions of the vectors.
+    pub dimensions: usize,
+
+    /// Maximum distance between two nodes of a walk to be trained as a pair.
+    pub window: usize,
+
+    /// Number of negative samples per pair.
+    pub negative_samples: usize,
+
+    /// Number of passes over the walks.
+    pub epochs: usize,
+
+    /// Initial learning rate, decreasing linearly to zero.
+    pub learning_rate: f64,
+
+    /// Seed of the walks and the training.
+    pub seed: u64,
+}
+
+impl Default for Node2VecAlgorithm {
+    /// Create a new default instance of the node2vec embedding.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the node2vec embedding.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node2VecAlgorithm {
+    /// Create a new instance of the node2vec embedding.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the node2vec embedding.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+            return_parameter: 1.0,
+            in_out_parameter: 1.0,
+            walks_per_node: 10,
+            walk_length: 20,
+            dimensions: 16,
+            window: 5,
+            negative_samples: 5,
+            epochs: 1,
+            learning_rate: 0.025,
+            seed: 0,
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: f64) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].insert(target, weight);
+        self.adjacency[target].insert(source, weight);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, f64)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, BTreeMap::new());
+    }
+
+    /// Set the bias parameters of the walks.
+    ///
+    /// # Arguments
+    ///
+    /// - `return_parameter`: Return parameter `p`.
+    /// - `in_out_parameter`: In-out parameter `q`.
+    pub fn set_bias(&mut self, return_parameter: f64, in_out_parameter: f64) {
+        self.return_parameter = return_parameter;
+        self.in_out_parameter = in_out_parameter;
+    }
+
+    /// Set the number and length of the walks.
+    ///
+    /// # Arguments
+    ///
+    /// - `walks_per_node`: Number of walks started from each node.
+    /// - `walk_length`: Number of nodes in each walk.
+    pub fn set_walks(&mut self, walks_per_node: usize, walk_length: usize) {
+        self.walks_per_node = walks_per_node;
+        self.walk_length = walk_length;
+    }
+
+    /// Set the number of dimensions of the vectors.
+    ///
+    /// # Arguments
+    ///
+    /// - `dimensions`: Number of dimensions.
+    pub fn set_dimensions(&mut self, dimensions: usize) {
+        self.dimensions = dimensions;
+    }
+
+    /// Set the context window of the training.
+    ///
+    /// # Arguments
+    ///
+    /// - `window`: Maximum distance between two trained nodes of a walk.
+    pub fn set_window(&mut self, window: usize) {
+        self.window = window;
+    }
+
+    /// Set the number of negative samples per pair.
+    ///
+    /// # Arguments
+    ///
+    /// - `negative_samples`: Number of negative samples.
+    pub fn set_negative_samples(&mut self, negative_samples: usize) {
+        self.negative_samples = negative_samples;
+    }
+
+    /// Set the number of passes over the walks.
+    ///
+    /// # Arguments
+    ///
+    /// - `epochs`: Number of passes.
+    pub fn set_epochs(&mut self, epochs: usize) {
+        self.epochs = epochs;
+    }
+
+    /// Set the initial learning rate.
+    ///
+    /// # Arguments
+    ///
+    /// - `learning_rate`: Initial learning rate.
+    pub fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
+
+    /// Set the seed of the walks and the training.
+    ///
+    /// # Arguments
+    ///
+    /// - `seed`: Seed.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    /// Generate the biased random walks, `walks_per_node` from each node in turn.
+    /// From `current`, reached from `previous`, a neighbor is chosen with probability proportional
+    /// to the edge weight divided by `p` if it is `previous`, by 1 if it is adjacent to `previous`,
+    /// and by `q` otherwise.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of each walk. A walk stops early at a node without neighbors.
+    pub fn walks(&self) -> Vec<Vec<usize>> {
+        let mut random = Random::new(self.seed);
+        let mut walks = Vec::with_capacity(self.walks_per_node * self.total_nodes);
+
+        for _ in 0..self.walks_per_node {
+            for start in 0..self.total_nodes {
+                walks.push(self.walk(start, &mut random));
+            }
+        }
+
+        walks
+    }
+
+    /// Generate a single biased random walk.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: First node of the walk.
+    /// - `random`: Random number generator.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of the walk.
+    fn walk(&self, start: usize, random: &mut Random) -> Vec<usize> {
+        let mut walk = vec![start];
+
+        while walk.len() < self.walk_length {
+            let current = walk[walk.len() - 1];
+            let previous = walk.len().checked_sub(2).map(|index| walk[index]);
+            let biased = |(&next, &weight): (&usize, &f64)| match previous {
+                Some(previous) if next == previous => (next, weight / self.return_parameter),
+                Some(previous) if self.adjacency[previous].contains_key(&next) => (next, weight),
+                Some(_) => (next, weight / self.in_out_parameter),
+                None => (next, weight),
+            };
+
+            let candidates: Vec<(usize, f64)> =
+                self.adjacency[current].iter().map(biased).collect();
+            let total: f64 = candidates.iter().map(|&(_, weight)| weight).sum();
+
+            if candidates.is_empty() || total <= 0.0 {
+                break;
+            }
+
+            let mut threshold = random.next_f64() * total;
+            let mut chosen = candidates[candidates.len() - 1].0;
+
+            for &(next, weight) in &candidates {
+                if threshold < weight {
+                    chosen = next;
+                    break;
+                }
+
+                threshold -= weight;
+            }
+
+            walk.push(chosen);
+        }
+
+        walk
+    }
+
+    /// Train the skip-gram model with negative sampling on the given walks.
+    /// Negative samples are drawn from the node frequencies in the walks raised to the power 0.75.
+    ///
+    /// # Arguments
+    ///
+    /// - `walks`: Walks over the nodes of the graph.
+    ///
+    /// # Returns
+    ///
+    /// Vector of each node.
+    pub fn train(&self, walks: &[Vec<usize>]) -> Vec<Vec<f64>> {
+        let n = self.total_nodes;
+        let dimensions = self.dimensions;
+        let mut random = Random::new(self.seed.wrapping_add(1));
+
+        let mut embeddings: Vec<Vec<f64>> = (0..n)
+            .map(|_| {
+                (0..dimensions)
+                    .map(|_| (random.next_f64() - 0.5) / dimensions as f64)
+                    .collect()
+            })
+            .collect();
+        let mut contexts = vec![vec![0.0; dimensions]; n];
+
+        // Cumulative distribution of the negative samples.
+        let mut frequencies = vec![0.0; n];
+
+        for &node in walks.iter().flatten() {
+            frequencies[node] += 1.0;
+        }
+
+        let mut cumulative = Vec::with_capacity(n);
+        let mut total = 0.0;
+
+        for frequency in frequencies {
+            total += f64::powf(frequency, 0.75);
+            cumulative.push(total);
+        }
+
+        let pairs_per_epoch: usize = walks
+            .iter()
+            .map(|walk| {
+                (0..walk.len())
+                    .map(|i| i.min(self.window) + (walk.len() - 1 - i).min(self.window))
+                    .sum::<usize>()
+            })
+            .sum();
+        let total_pairs = (pairs_per_epoch * self.epochs).max(1) as f64;
+        let mut trained = 0.0;
+        let mut gradient = vec![0.0; dimensions];
+
+        for _ in 0..self.epochs {
+            for walk in walks {
+                for (i, &center) in walk.iter().enumerate() {
+                    let low = i.saturating_sub(self.window);
+                    let high = (i + self.window + 1).min(walk.len());
+
+                    for (j, &context) in walk.iter().enumerate().take(high).skip(low) {
+                        if i == j {
+                            continue;
+                        }
+
+                        let rate = self.learning_rate * (1.0 - trained / total_pairs).max(1e-4);
+                        trained += 1.0;
+                        gradient.iter_mut().for_each(|g| *g = 0.0);
+
+                        for sample in 0..=self.negative_samples {
+                            let (target, label) = match sample {
+                                0 => (context, 1.0),
+                                _ => {
+                                    let threshold = random.next_f64() * total;
+                                    let target =
+                                        cumulative.partition_point(|&c| c <= threshold).min(n - 1);
+
+                                    if target == context {
+                                        continue;
+                                    }
+
+                                    (target, 0.0)
+                                }
+                            };
+
+                            let dot: f64 = embeddings[center]
+                                .iter()
+                                .zip(&contexts[target])
+                                .map(|(a, b)| a * b)
+                                .sum();
+                            let step = rate * (label - sigmoid(dot));
+
+                            for ((g, c), e) in gradient
+                                .iter_mut()
+                                .zip(contexts[target].iter_mut())
+                                .zip(&embeddings[center])
+                            {
+                                *g += step * *c;
+                                *c += step * e;
+                            }
+                        }
+
+                        embeddings[center]
+                            .iter_mut()
+                            .zip(&gradient)
+                            .for_each(|(a, g)| *a += g);
+                    }
+                }
+            }
+        }
+
+        embeddings
+    }
+}
+
+/// Logistic function.
+///
+/// # Arguments
+///
+/// - `x`: Input.
+///
+/// # Returns
+///
+/// `1 / (1 + e^-x)`.
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+impl GraphAlgorithm for Node2VecAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<Vec<f64>>;
+
+    /// Run the node2vec embedding.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the node2vec embedding.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the vector of each node.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.train(&self.walks()))
+    }
+}
+
+impl ConfiguredAlgorithm for Node2VecAlgorithm {
+    /// Describe the configuration of the node2vec embedding.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("embeddings")
+            .with("return_parameter", self.return_parameter)
+            .with("in_out_parameter", self.in_out_parameter)
+            .with("walks_per_node", self.walks_per_node)
+            .with("walk_length", self.walk_length)
+            .with("dimensions", self.dimensions)
+            .with("window", self.window)
+            .with("negative_samples", self.negative_samples)
+            .with("epochs", self.epochs)
+            .with("learning_rate", self.learning_rate)
+            .with("seed", self.seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cosine(a: &[f64], b: &[f64]) -> f64 {
+        let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        let norm = |v: &[f64]| v.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+        dot / (norm(a) * norm(b))
+    }
+
+    fn two_cliques() -> Node2VecAlgorithm {
+        let mut algorithm = Node2VecAlgorithm::new();
+
+        for offset in [0, 5] {
+            for source in offset..offset + 5 {
+                for target in source + 1..offset + 5 {
+                    algorithm.set_edge(source, target, 1.0);
+                }
+            }
+        }
+
+        algorithm.set_edge(4, 5, 1.0);
+        algorithm
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = Node2VecAlgorithm::new();
+        let algorithm_default = Node2VecAlgorithm::default();
+
+        assert_eq!(algorithm.dimensions, 16);
+        assert_eq!(algorithm_default.total_nodes, 0);
+    }
+
+    #[test]
+    fn test_walks() {
+        let mut algorithm = two_cliques();
+        algorithm.set_total_nodes(11);
+        algorithm.set_walks(2, 8);
+        algorithm.set_bias(0.5, 2.0);
+
+        let walks = algorithm.walks();
+
+        assert_eq!(walks.len(), 22);
+        assert_eq!(walks, algorithm.walks());
+        assert_eq!(walks[10], vec![10]);
+
+        for (index, walk) in walks.iter().enumerate() {
+            assert_eq!(walk[0], index % 11);
+
+            if walk[0] != 10 {
+                assert_eq!(walk.len(), 8);
+            }
+
+            for pair in walk.windows(2) {
+                assert!(algorithm.adjacency[pair[0]].contains_key(&pair[1]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = two_cliques();
+        algorithm.set_dimensions(8);
+        algorithm.set_epochs(5);
+        algorithm.set_seed(7);
+
+        let embeddings = algorithm.run(None).unwrap();
+        let within = cosine(&embeddings[0], &embeddings[1]);
+        let across = cosine(&embeddings[0], &embeddings[8]);
+
+        assert_eq!(embeddings.len(), 10);
+        assert_eq!(embeddings[0].len(), 8);
+        assert!(within > across, "{within} <= {across}");
+    }
+}
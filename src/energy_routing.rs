@@ -0,0 +1,375 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use crate::{ConfiguredAlgorithm, GraphAlgorithm, GraphError, Provenance};
+
+/// Route of an electric vehicle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnergyRoute {
+    /// Nodes of the route.
+    pub path: Vec<usize>,
+
+    /// Energy drawn from the battery and the chargers: charged energy plus initial minus arrival charge.
+    pub energy: i64,
+
+    /// Charge of the battery at the target.
+    pub arrival_charge: usize,
+
+    /// Charging stops with the charged amount, in route order.
+    pub charging: Vec<(usize, usize)>,
+}
+
+/// Transition into a search state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    /// Initial state.
+    Start,
+
+    /// Edge driven from the previous state.
+    Drive(usize),
+
+    /// One unit charged from the previous state.
+    Charge(usize),
+}
+
+/// Energy-Optimal Routing.
+/// Route an electric vehicle whose edges consume energy, or regenerate it when the cost is negative,
+/// while the state of charge stays within `[0, capacity]`: a route running the battery below zero
+/// is infeasible, and regeneration beyond the capacity is lost. Charging nodes add one unit at a time.
+/// A 0-1 breadth-first search over the states `(node, charge)` minimizes the charged energy,
+/// then the route with the least energy drawn overall is chosen.
+#[derive(Debug, Clone)]
+pub struct EnergyRoutingAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Successors of each node with the energy cost of the edge, negative for regeneration.
+    pub adjacency: Vec<Vec<(usize, i64)>>,
+
+    /// Capacity of the battery in energy units.
+    pub capacity: usize,
+
+    /// Charge of the battery at the start, at most the capacity.
+    pub initial_charge: usize,
+
+    /// Nodes where the battery can be charged.
+    pub chargers: BTreeSet<usize>,
+}
+
+impl Default for EnergyRoutingAlgorithm {
+    /// Create a new default instance of the energy-optimal routing.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the energy-optimal routing.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EnergyRoutingAlgorithm {
+    /// Create a new instance of the energy-optimal routing.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the energy-optimal routing.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+            capacity: 0,
+            initial_charge: 0,
+            chargers: BTreeSet::new(),
+        }
+    }
+
+    /// Set a single directed edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `energy`: Energy cost of the edge, negative for regeneration.
+    pub fn set_edge(&mut self, source: usize, target: usize, energy: i64) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].push((target, energy));
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, i64)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, energy) in edges {
+                self.set_edge(source, target, energy);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, Vec::new());
+    }
+
+    /// Set the battery of the vehicle.
+    ///
+    /// # Arguments
+    ///
+    /// - `capacity`: Capacity of the battery in energy units.
+    /// - `initial_charge`: Charge at the start, clamped to the capacity.
+    pub fn set_battery(&mut self, capacity: usize, initial_charge: usize) {
+        self.capacity = capacity;
+        self.initial_charge = initial_charge.min(capacity);
+    }
+
+    /// Set a charging node.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node where the battery can be charged.
+    pub fn set_charger(&mut self, node: usize) {
+        self.set_total_nodes(node + 1);
+        self.chargers.insert(node);
+    }
+
+    /// Search the states `(node, charge)` reachable from a start.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Minimal charged energy and last step of each state, `usize::MAX` if unreachable.
+    fn search(&self, start: usize) -> (Vec<usize>, Vec<Step>) {
+        let levels = self.capacity + 1;
+        let initial = start * levels + self.initial_charge.min(self.capacity);
+        let mut charged = vec![usize::MAX; self.total_nodes * levels];
+        let mut steps = vec![Step::Start; self.total_nodes * levels];
+        let mut queue = VecDeque::from([initial]);
+
+        charged[initial] = 0;
+
+        while let Some(state) = queue.pop_front() {
+            let (node, charge) = (state / levels, state % levels);
+
+            for &(target, energy) in &self.adjacency[node] {
+                let remaining = charge as i64 - energy;
+
+                if remaining < 0 {
+                    continue;
+                }
+
+                let next = target * levels + (remaining as usize).min(self.capacity);
+
+                if charged[state] < charged[next] {
+                    charged[next] = charged[state];
+                    steps[next] = Step::Drive(state);
+                    queue.push_front(next);
+                }
+            }
+
+            if charge < self.capacity
+                && self.chargers.contains(&node)
+                && charged[state] + 1 < charged[state + 1]
+            {
+                charged[state + 1] = charged[state] + 1;
+                steps[state + 1] = Step::Charge(state);
+                queue.push_back(state + 1);
+            }
+        }
+
+        (charged, steps)
+    }
+
+    /// Choose the arrival charge of a node drawing the least energy.
+    ///
+    /// # Arguments
+    ///
+    /// - `charged`: Minimal charged energy of each state.
+    /// - `node`: Node to reach.
+    ///
+    /// # Returns
+    ///
+    /// Energy drawn and arrival charge, or `None` if the node is unreachable.
+    fn best_arrival(&self, charged: &[usize], node: usize) -> Option<(i64, usize)> {
+        let levels = self.capacity + 1;
+
+        (0..levels)
+            .filter(|&charge| charged[node * levels + charge] != usize::MAX)
+            .map(|charge| {
+                let energy = charged[node * levels + charge] as i64 + self.initial_charge as i64
+                    - charge as i64;
+                (energy, std::cmp::Reverse(charge))
+            })
+            .min()
+            .map(|(energy, std::cmp::Reverse(charge))| (energy, charge))
+    }
+
+    /// Find a feasible route drawing the least energy, preferring the highest arrival charge on ties.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Route, or `None` if no route keeps the charge within the battery.
+    pub fn route(&self, start: usize, target: usize) -> Option<EnergyRoute> {
+        if start >= self.total_nodes || target >= self.total_nodes {
+            return None;
+        }
+
+        let levels = self.capacity + 1;
+        let (charged, steps) = self.search(start);
+        let (energy, arrival_charge) = self.best_arrival(&charged, target)?;
+
+        let mut path = vec![target];
+        let mut charging: Vec<(usize, usize)> = Vec::new();
+        let mut state = target * levels + arrival_charge;
+        let mut charging_stop = false;
+
+        // Consecutive charges form one stop.
+        loop {
+            match steps[state] {
+                Step::Start => break,
+                Step::Drive(previous) => {
+                    path.push(previous / levels);
+                    state = previous;
+                    charging_stop = false;
+                }
+                Step::Charge(previous) => {
+                    match charging.last_mut() {
+                        Some((_, amount)) if charging_stop => *amount += 1,
+                        _ => charging.push((state / levels, 1)),
+                    }
+
+                    state = previous;
+                    charging_stop = true;
+                }
+            }
+        }
+
+        path.reverse();
+        charging.reverse();
+
+        Some(EnergyRoute {
+            path,
+            energy,
+            arrival_charge,
+            charging,
+        })
+    }
+}
+
+impl GraphAlgorithm for EnergyRoutingAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<Option<i64>>;
+
+    /// Run the energy-optimal routing.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the least energy drawn to reach each node, `None` if infeasible.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let start = start
+            .filter(|&start| start < self.total_nodes)
+            .ok_or(GraphError::MissingStartNode)?;
+        let (charged, _) = self.search(start);
+
+        Ok((0..self.total_nodes)
+            .map(|node| self.best_arrival(&charged, node).map(|(energy, _)| energy))
+            .collect())
+    }
+}
+
+impl ConfiguredAlgorithm for EnergyRoutingAlgorithm {
+    /// Describe the configuration of the energy-optimal routing.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("energy_routing")
+            .with("capacity", self.capacity)
+            .with("initial_charge", self.initial_charge)
+            .with("chargers", &self.chargers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let algorithm = EnergyRoutingAlgorithm::new();
+        let algorithm_default = EnergyRoutingAlgorithm::default();
+
+        assert_eq!(algorithm.capacity, 0);
+        assert!(algorithm_default.chargers.is_empty());
+        assert_eq!(algorithm.run(Some(0)), Err(GraphError::MissingStartNode));
+    }
+
+    #[test]
+    fn test_route_regeneration() {
+        // Downhill 1 -> 2 regenerates 5 units, but only 2 fit into the battery.
+        let mut algorithm = EnergyRoutingAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 2), (3, 9)]),
+            (1, vec![(2, -5)]),
+            (2, vec![(3, 8)]),
+        ]);
+        algorithm.set_battery(8, 8);
+
+        let route = algorithm.route(0, 3).unwrap();
+
+        assert_eq!(route.path, vec![0, 1, 2, 3]);
+        assert_eq!(route.energy, 8);
+        assert_eq!(route.arrival_charge, 0);
+        assert!(route.charging.is_empty());
+        assert_eq!(
+            algorithm.run(Some(0)).unwrap(),
+            vec![Some(0), Some(2), Some(0), Some(8)]
+        );
+    }
+
+    #[test]
+    fn test_route_charging() {
+        let mut algorithm = EnergyRoutingAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 6)]), (1, vec![(2, 6)])]);
+        algorithm.set_battery(8, 10);
+
+        assert_eq!(algorithm.initial_charge, 8);
+        assert_eq!(algorithm.route(0, 2), None);
+
+        algorithm.set_charger(1);
+
+        assert_eq!(
+            algorithm.route(0, 2),
+            Some(EnergyRoute {
+                path: vec![0, 1, 2],
+                energy: 12,
+                arrival_charge: 2,
+                charging: vec![(1, 6)],
+            })
+        );
+        assert_eq!(algorithm.route(0, 5), None);
+    }
+}
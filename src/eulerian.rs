@@ -0,0 +1,349 @@
+use crate::{GraphAlgorithm, GraphError};
+
+/// Hierholzer's Algorithm.
+/// Find an Eulerian trail or circuit, using every edge exactly once, in a directed or undirected graph.
+#[derive(Debug, Clone)]
+pub struct EulerianAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Edges in the graph.
+    pub edges: Vec<(usize, usize)>,
+
+    /// Whether the edges are directed.
+    pub directed: bool,
+}
+
+impl Default for EulerianAlgorithm {
+    /// Create a new default instance of Hierholzer's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of Hierholzer's Algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EulerianAlgorithm {
+    /// Create a new instance of Hierholzer's Algorithm for a directed graph.
+    ///
+    /// # Returns
+    ///
+    /// New instance of Hierholzer's Algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            edges: Vec::new(),
+            directed: true,
+        }
+    }
+
+    /// Set a single edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.edges.push((source, target));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its neighbors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.total_nodes = self.total_nodes.max(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set whether the edges are directed.
+    ///
+    /// # Arguments
+    ///
+    /// - `directed`: `true` for a directed graph, `false` for an undirected one.
+    pub fn set_directed(&mut self, directed: bool) {
+        self.directed = directed;
+    }
+
+    /// Compute the balance of each node: out-degree minus in-degree for directed graphs,
+    /// and the degree parity for undirected graphs.
+    ///
+    /// # Returns
+    ///
+    /// Balance of each node.
+    fn balances(&self) -> Vec<isize> {
+        let mut balances = vec![0; self.total_nodes];
+
+        for &(source, target) in &self.edges {
+            balances[source] += 1;
+
+            match self.directed {
+                true => balances[target] -= 1,
+                false => balances[target] += 1,
+            }
+        }
+
+        if !self.directed {
+            balances.iter_mut().for_each(|balance| *balance %= 2);
+        }
+
+        balances
+    }
+
+    /// Find the nodes violating the degree conditions of an Eulerian trail.
+    /// A directed trail allows one node with one extra outgoing edge and one with one extra incoming edge,
+    /// an undirected trail allows two nodes of odd degree.
+    ///
+    /// # Returns
+    ///
+    /// Nodes whose degrees prevent any Eulerian trail, empty if the conditions hold.
+    pub fn violations(&self) -> Vec<usize> {
+        let balances = self.balances();
+        let unbalanced: Vec<usize> = (0..self.total_nodes)
+            .filter(|&node| balances[node] != 0)
+            .collect();
+
+        let valid = match self.directed {
+            true => {
+                unbalanced.is_empty()
+                    || (unbalanced.len() == 2
+                        && unbalanced.iter().map(|&node| balances[node]).sum::<isize>() == 0
+                        && unbalanced.iter().all(|&node| balances[node].abs() == 1))
+            }
+            false => unbalanced.len() <= 2,
+        };
+
+        match valid {
+            true => Vec::new(),
+            false => unbalanced,
+        }
+    }
+
+    /// Determine whether the graph has an Eulerian circuit.
+    ///
+    /// # Returns
+    ///
+    /// `true` if an Eulerian circuit exists.
+    pub fn has_circuit(&self) -> bool {
+        self.balances().iter().all(|&balance| balance == 0) && self.run(None).is_ok()
+    }
+
+    /// Build the trail with Hierholzer's Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Node to start from.
+    ///
+    /// # Returns
+    ///
+    /// Edges in traversal order, shorter than the edge list if the edges are not connected.
+    fn hierholzer(&self, start: usize) -> Vec<(usize, usize)> {
+        let mut adjacency = vec![Vec::new(); self.total_nodes];
+
+        for (index, &(source, target)) in self.edges.iter().enumerate() {
+            adjacency[source].push((target, index));
+
+            if !self.directed && source != target {
+                adjacency[target].push((source, index));
+            }
+        }
+
+        let mut used = vec![false; self.edges.len()];
+        let mut next = vec![0; self.total_nodes];
+        let mut stack = vec![(start, None)];
+        let mut trail = Vec::with_capacity(self.edges.len());
+
+        while let Some(&(node, previous)) = stack.last() {
+            // Skip the edges already used from the other endpoint.
+            while next[node] < adjacency[node].len() && used[adjacency[node][next[node]].1] {
+                next[node] += 1;
+            }
+
+            match adjacency[node].get(next[node]) {
+                Some(&(neighbor, index)) => {
+                    used[index] = true;
+                    stack.push((neighbor, Some(node)));
+                }
+                None => {
+                    stack.pop();
+
+                    if let Some(previous) = previous {
+                        trail.push((previous, node));
+                    }
+                }
+            }
+        }
+
+        trail.reverse();
+        trail
+    }
+}
+
+impl GraphAlgorithm for EulerianAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<(usize, usize)>;
+
+    /// Run Hierholzer's Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. If omitted, the trail starts at the node required by the degrees, or the smallest node with an edge.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the edges of an Eulerian trail in traversal order, which is a circuit if it ends at its start,
+    /// or an error if the degree conditions fail or the edges are not connected.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        if !self.violations().is_empty() {
+            return Err(GraphError::NotEulerian);
+        }
+
+        let Some(&(first, _)) = self.edges.first() else {
+            return Ok(Vec::new());
+        };
+
+        let balances = self.balances();
+        let required = (0..self.total_nodes).find(|&node| balances[node] > 0);
+
+        let start = match (start, required) {
+            (Some(start), Some(required)) if self.directed && start != required => {
+                return Err(GraphError::NotEulerian);
+            }
+            (Some(start), Some(_)) if start >= self.total_nodes || balances[start] == 0 => {
+                return Err(GraphError::NotEulerian);
+            }
+            (Some(start), _) => start,
+            (None, Some(required)) => required,
+            (None, None) => first,
+        };
+
+        let trail = self.hierholzer(start);
+
+        match trail.len() == self.edges.len() {
+            true => Ok(trail),
+            false => Err(GraphError::NotEulerian),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_trail(algorithm: &EulerianAlgorithm, trail: &[(usize, usize)]) {
+        assert_eq!(trail.len(), algorithm.edges.len());
+
+        for pair in trail.windows(2) {
+            assert_eq!(pair[0].1, pair[1].0);
+        }
+
+        let mut expected: Vec<(usize, usize)> = algorithm.edges.clone();
+        let mut actual: Vec<(usize, usize)> = trail.to_vec();
+
+        if !algorithm.directed {
+            for edges in [&mut expected, &mut actual] {
+                edges
+                    .iter_mut()
+                    .for_each(|(a, b)| (*a, *b) = ((*a).min(*b), (*a).max(*b)));
+            }
+        }
+
+        expected.sort_unstable();
+        actual.sort_unstable();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = EulerianAlgorithm::new();
+        let algorithm_default = EulerianAlgorithm::default();
+
+        assert!(algorithm.directed);
+        assert_eq!(algorithm_default.edges.len(), 0);
+    }
+
+    #[test]
+    fn test_run_directed_circuit() {
+        let mut algorithm = EulerianAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1]),
+            (1, vec![2, 3]),
+            (2, vec![0]),
+            (3, vec![1]),
+        ]);
+
+        let trail = algorithm.run(None).unwrap();
+
+        assert_trail(&algorithm, &trail);
+        assert_eq!(trail, vec![(0, 1), (1, 3), (3, 1), (1, 2), (2, 0)]);
+        assert!(algorithm.has_circuit());
+    }
+
+    #[test]
+    fn test_run_directed_trail() {
+        let mut algorithm = EulerianAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1]), (1, vec![2]), (2, vec![0, 3])]);
+
+        assert_eq!(algorithm.run(Some(1)), Err(GraphError::NotEulerian));
+
+        let trail = algorithm.run(None).unwrap();
+
+        assert_trail(&algorithm, &trail);
+        assert_eq!(trail[0].0, 2);
+        assert!(!algorithm.has_circuit());
+    }
+
+    #[test]
+    fn test_run_undirected() {
+        // House shape: a square with a roof, nodes 0 and 1 have odd degree.
+        let mut algorithm = EulerianAlgorithm::new();
+        algorithm.set_directed(false);
+        algorithm.set_edges(vec![(0, vec![1, 2, 4]), (1, vec![3, 4]), (2, vec![3])]);
+
+        let trail = algorithm.run(Some(1)).unwrap();
+
+        assert_trail(&algorithm, &trail);
+        assert_eq!(trail[0].0, 1);
+        assert_eq!(trail.last().unwrap().1, 0);
+        assert_eq!(algorithm.run(Some(2)), Err(GraphError::NotEulerian));
+    }
+
+    #[test]
+    fn test_run_violations() {
+        let mut algorithm = EulerianAlgorithm::new();
+        algorithm.set_directed(false);
+        algorithm.set_edges(vec![(0, vec![1, 2, 3])]);
+
+        assert_eq!(algorithm.violations(), vec![0, 1, 2, 3]);
+        assert_eq!(algorithm.run(None), Err(GraphError::NotEulerian));
+    }
+
+    #[test]
+    fn test_run_disconnected() {
+        let mut algorithm = EulerianAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1]), (1, vec![0]), (2, vec![3]), (3, vec![2])]);
+
+        assert!(algorithm.violations().is_empty());
+        assert_eq!(algorithm.run(None), Err(GraphError::NotEulerian));
+    }
+
+    #[test]
+    fn test_run_empty() {
+        let algorithm = EulerianAlgorithm::new();
+
+        assert_eq!(algorithm.run(None), Ok(Vec::new()));
+    }
+}
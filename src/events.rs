@@ -0,0 +1,245 @@
+use std::sync::mpsc::Sender;
+
+use crate::{GraphAlgorithm, GraphError};
+
+/// Step of an algorithm run, emitted live so that external tools can animate the execution.
+/// Each event serializes to one line of JSON with `to_json`, or with serde under the optional
+/// `serde` feature, tagged by its `event` name in snake case.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "event", rename_all = "snake_case")
+)]
+pub enum AlgorithmEvent {
+    /// Distance of a node became final.
+    NodeSettled {
+        /// Settled node.
+        node: usize,
+
+        /// Final distance of the node.
+        distance: i64,
+    },
+
+    /// Edge was examined to improve the distance of its target.
+    EdgeRelaxed {
+        /// Source node.
+        source: usize,
+
+        /// Target node.
+        target: usize,
+
+        /// Weight of the edge.
+        weight: i64,
+    },
+
+    /// Tentative distance of a node improved.
+    DistanceUpdated {
+        /// Updated node.
+        node: usize,
+
+        /// New distance of the node.
+        distance: i64,
+
+        /// Node the improving edge comes from, if any.
+        predecessor: Option<usize>,
+    },
+
+    /// Pass of an iterative algorithm ended.
+    IterationFinished {
+        /// Number of the pass, starting at zero.
+        iteration: usize,
+
+        /// Change made by the pass, such as the number of updated distances or the total change of
+        /// the scores.
+        change: f64,
+    },
+}
+
+impl AlgorithmEvent {
+    /// Serialize the event as one line of JSON, such as
+    /// `{"event":"node_settled","node":3,"distance":7}`, non-finite numbers becoming `null`.
+    ///
+    /// # Returns
+    ///
+    /// JSON object of the event.
+    pub fn to_json(&self) -> String {
+        match *self {
+            Self::NodeSettled { node, distance } => {
+                format!(r#"{{"event":"node_settled","node":{node},"distance":{distance}}}"#)
+            }
+            Self::EdgeRelaxed {
+                source,
+                target,
+                weight,
+            } => format!(
+                r#"{{"event":"edge_relaxed","source":{source},"target":{target},"weight":{weight}}}"#
+            ),
+            Self::DistanceUpdated {
+                node,
+                distance,
+                predecessor,
+            } => {
+                let predecessor = predecessor.map_or("null".to_string(), |node| node.to_string());
+
+                format!(
+                    r#"{{"event":"distance_updated","node":{node},"distance":{distance},"predecessor":{predecessor}}}"#
+                )
+            }
+            Self::IterationFinished { iteration, change } => {
+                let change = match change.is_finite() {
+                    true => format!("{change:?}"),
+                    false => "null".to_string(),
+                };
+
+                format!(
+                    r#"{{"event":"iteration_finished","iteration":{iteration},"change":{change}}}"#
+                )
+            }
+        }
+    }
+}
+
+/// Receiver of the events of a run, such as a callback, a channel or a vector.
+pub trait EventSink {
+    /// Receive an event.
+    ///
+    /// # Arguments
+    ///
+    /// - `event`: Event of the run.
+    fn emit(&mut self, event: AlgorithmEvent);
+}
+
+impl EventSink for () {
+    /// Discard the event.
+    ///
+    /// # Arguments
+    ///
+    /// - `_event`: Event of the run.
+    fn emit(&mut self, _event: AlgorithmEvent) {}
+}
+
+impl EventSink for Vec<AlgorithmEvent> {
+    /// Record the event.
+    ///
+    /// # Arguments
+    ///
+    /// - `event`: Event of the run.
+    fn emit(&mut self, event: AlgorithmEvent) {
+        self.push(event);
+    }
+}
+
+impl EventSink for Sender<AlgorithmEvent> {
+    /// Send the event to another thread, dropping it if the receiver is gone.
+    ///
+    /// # Arguments
+    ///
+    /// - `event`: Event of the run.
+    fn emit(&mut self, event: AlgorithmEvent) {
+        let _ = self.send(event);
+    }
+}
+
+impl<F: FnMut(AlgorithmEvent)> EventSink for F {
+    /// Call the function with the event.
+    ///
+    /// # Arguments
+    ///
+    /// - `event`: Event of the run.
+    fn emit(&mut self, event: AlgorithmEvent) {
+        self(event);
+    }
+}
+
+/// A trait for algorithms that can report the steps of a run as events.
+pub trait ObservableAlgorithm: GraphAlgorithm {
+    /// Run the algorithm and emit its steps.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node, if applicable.
+    /// - `sink`: Receiver of the events.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the result of the algorithm, or an error.
+    fn run_observed(
+        &self,
+        start: Option<Self::Node>,
+        sink: &mut dyn EventSink,
+    ) -> Result<Self::Weight, GraphError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            AlgorithmEvent::NodeSettled {
+                node: 3,
+                distance: 7
+            }
+            .to_json(),
+            r#"{"event":"node_settled","node":3,"distance":7}"#
+        );
+        assert_eq!(
+            AlgorithmEvent::EdgeRelaxed {
+                source: 0,
+                target: 1,
+                weight: -2
+            }
+            .to_json(),
+            r#"{"event":"edge_relaxed","source":0,"target":1,"weight":-2}"#
+        );
+        assert_eq!(
+            AlgorithmEvent::DistanceUpdated {
+                node: 1,
+                distance: 4,
+                predecessor: None
+            }
+            .to_json(),
+            r#"{"event":"distance_updated","node":1,"distance":4,"predecessor":null}"#
+        );
+        assert_eq!(
+            AlgorithmEvent::IterationFinished {
+                iteration: 2,
+                change: 1.0
+            }
+            .to_json(),
+            r#"{"event":"iteration_finished","iteration":2,"change":1.0}"#
+        );
+        assert_eq!(
+            AlgorithmEvent::IterationFinished {
+                iteration: 0,
+                change: f64::NAN
+            }
+            .to_json(),
+            r#"{"event":"iteration_finished","iteration":0,"change":null}"#
+        );
+    }
+
+    #[test]
+    fn test_sinks() {
+        let event = AlgorithmEvent::NodeSettled {
+            node: 0,
+            distance: 0,
+        };
+        let (sender, receiver) = mpsc::channel();
+        let mut sender = sender;
+        let mut count = 0;
+        let mut events = Vec::new();
+
+        sender.emit(event);
+        (|_| count += 1).emit(event);
+        events.emit(event);
+        ().emit(event);
+
+        assert_eq!(receiver.recv(), Ok(event));
+        assert_eq!(count, 1);
+        assert_eq!(events, vec![event]);
+    }
+}
@@ -0,0 +1,298 @@
+use std::{cmp::Reverse, collections::BTreeSet};
+
+use crate::{scc::strongly_connected_components, GraphAlgorithm, GraphError};
+
+/// Feedback Vertex Set.
+/// Find a small set of nodes whose removal makes a directed graph acyclic. Nodes that cannot lie on
+/// a cycle are dropped and nodes with a single predecessor or successor are bypassed, which never
+/// worsens the result; when no such reduction applies, the node with the largest product of in- and
+/// out-degree is taken. Finally, every taken node that is not needed is given back.
+#[derive(Debug, Clone)]
+pub struct FeedbackVertexSetAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Successors of each node.
+    pub adjacency: Vec<BTreeSet<usize>>,
+}
+
+impl Default for FeedbackVertexSetAlgorithm {
+    /// Create a new default instance of the feedback vertex set heuristic.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the feedback vertex set heuristic.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FeedbackVertexSetAlgorithm {
+    /// Create a new instance of the feedback vertex set heuristic.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the feedback vertex set heuristic.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+        }
+    }
+
+    /// Set a single directed edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].insert(target);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its successors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, BTreeSet::new());
+    }
+
+    /// Check whether the graph is acyclic without the given nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `removed`: Nodes to ignore.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the remaining graph has no cycle.
+    pub fn is_acyclic_without(&self, removed: &[bool]) -> bool {
+        let adjacency: Vec<Vec<usize>> = (0..self.total_nodes)
+            .map(|source| match removed.get(source) {
+                Some(true) => Vec::new(),
+                _ => self.adjacency[source]
+                    .iter()
+                    .copied()
+                    .filter(|&target| !removed.get(target).copied().unwrap_or(false))
+                    .collect(),
+            })
+            .collect();
+
+        strongly_connected_components(&adjacency)
+            .iter()
+            .all(|component| {
+                component.len() == 1 && !adjacency[component[0]].contains(&component[0])
+            })
+    }
+
+    /// Compute the feedback vertex set.
+    ///
+    /// # Returns
+    ///
+    /// Nodes to remove in ascending order, none of which can be spared.
+    pub fn feedback_vertex_set(&self) -> Vec<usize> {
+        let n = self.total_nodes;
+        let mut successors = self.adjacency.clone();
+        let mut predecessors = vec![BTreeSet::new(); n];
+
+        for (source, targets) in self.adjacency.iter().enumerate() {
+            for &target in targets {
+                predecessors[target].insert(source);
+            }
+        }
+
+        let mut alive: BTreeSet<usize> = (0..n).collect();
+        let mut taken = Vec::new();
+
+        let remove = |node: usize,
+                      successors: &mut Vec<BTreeSet<usize>>,
+                      predecessors: &mut Vec<BTreeSet<usize>>| {
+            for target in std::mem::take(&mut successors[node]) {
+                predecessors[target].remove(&node);
+            }
+
+            for source in std::mem::take(&mut predecessors[node]) {
+                successors[source].remove(&node);
+            }
+        };
+
+        while !alive.is_empty() {
+            let mut reduced = false;
+
+            for node in alive.clone() {
+                if successors[node].contains(&node) {
+                    taken.push(node);
+                } else if predecessors[node].len() == 1 {
+                    // Every cycle through the node also passes its only predecessor.
+                    let source = *predecessors[node].first().unwrap_or(&node);
+
+                    for target in successors[node].clone() {
+                        successors[source].insert(target);
+                        predecessors[target].insert(source);
+                    }
+                } else if successors[node].len() == 1 {
+                    let target = *successors[node].first().unwrap_or(&node);
+
+                    for source in predecessors[node].clone() {
+                        successors[source].insert(target);
+                        predecessors[target].insert(source);
+                    }
+                } else if !predecessors[node].is_empty() && !successors[node].is_empty() {
+                    continue;
+                }
+
+                remove(node, &mut successors, &mut predecessors);
+                alive.remove(&node);
+                reduced = true;
+            }
+
+            if reduced {
+                continue;
+            }
+
+            let Some(&node) = alive.iter().min_by_key(|&&node| {
+                (
+                    Reverse(predecessors[node].len() * successors[node].len()),
+                    node,
+                )
+            }) else {
+                break;
+            };
+
+            taken.push(node);
+            remove(node, &mut successors, &mut predecessors);
+            alive.remove(&node);
+        }
+
+        // Give back the nodes whose return does not close a cycle, latest choices first.
+        let mut removed = vec![false; n];
+        taken.iter().for_each(|&node| removed[node] = true);
+
+        for index in (0..taken.len()).rev() {
+            removed[taken[index]] = false;
+
+            if self.is_acyclic_without(&removed) {
+                taken.remove(index);
+            } else {
+                removed[taken[index]] = true;
+            }
+        }
+
+        taken.sort_unstable();
+        taken
+    }
+}
+
+impl GraphAlgorithm for FeedbackVertexSetAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<usize>;
+
+    /// Run the feedback vertex set heuristic.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the feedback vertex set heuristic.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the nodes to remove in ascending order.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.feedback_vertex_set())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Random;
+
+    #[test]
+    fn test_new() {
+        let algorithm = FeedbackVertexSetAlgorithm::new();
+        let algorithm_default = FeedbackVertexSetAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.run(None), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_run() {
+        // Three cycles through node 2, and a separate self-loop.
+        let mut algorithm = FeedbackVertexSetAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1]),
+            (1, vec![2]),
+            (2, vec![0, 3, 5]),
+            (3, vec![4]),
+            (4, vec![2, 5]),
+            (5, vec![1]),
+            (6, vec![6]),
+        ]);
+
+        assert_eq!(algorithm.run(None), Ok(vec![2, 6]));
+    }
+
+    #[test]
+    fn test_run_complete() {
+        // A complete directed graph on n nodes needs n - 1 removals.
+        let mut algorithm = FeedbackVertexSetAlgorithm::new();
+
+        for source in 0..5 {
+            for target in 0..5 {
+                if source != target {
+                    algorithm.set_edge(source, target);
+                }
+            }
+        }
+
+        assert_eq!(algorithm.feedback_vertex_set().len(), 4);
+    }
+
+    #[test]
+    fn test_run_random() {
+        let mut random = Random::new(9);
+
+        for _ in 0..50 {
+            let mut algorithm = FeedbackVertexSetAlgorithm::new();
+            algorithm.set_total_nodes(12);
+
+            for _ in 0..24 {
+                algorithm.set_edge(random.below(12), random.below(12));
+            }
+
+            let set = algorithm.feedback_vertex_set();
+            let mut removed = vec![false; 12];
+            set.iter().for_each(|&node| removed[node] = true);
+
+            assert!(algorithm.is_acyclic_without(&removed));
+
+            for &node in &set {
+                removed[node] = false;
+                assert!(!algorithm.is_acyclic_without(&removed));
+                removed[node] = true;
+            }
+        }
+    }
+}
@@ -1,14 +1,82 @@
-use crate::{GraphAlgorithm, GraphError};
+use crate::{
+    DiGraph, GraphAlgorithm, GraphError, MemoryEstimate, MemoryEstimation, ReachabilityMatrix,
+    Weight,
+};
+
+/// Distances between all pairs of nodes with the next hop of a shortest path between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortestPathMatrix<W = i32> {
+    /// Distance from each node to each other, `Weight::INFINITY` if unreachable.
+    pub distances: Vec<Vec<W>>,
+
+    /// Successor of each node on a shortest path to each other, `usize::MAX` if unreachable.
+    pub next: Vec<Vec<usize>>,
+}
+
+impl<W: Weight> ShortestPathMatrix<W> {
+    /// Get the distance between two nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Distance, or `None` if the target is unreachable or a node is out of range.
+    pub fn distance(&self, source: usize, target: usize) -> Option<W> {
+        self.distances
+            .get(source)?
+            .get(target)
+            .copied()
+            .filter(|&distance| distance != W::INFINITY)
+    }
+
+    /// Reconstruct a shortest path by following the next hops.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of the path from the source to the target, or `None` if the target is unreachable, a
+    /// node is out of range, or the path runs into a negative weight cycle and has no shortest form.
+    pub fn path(&self, source: usize, target: usize) -> Option<Vec<usize>> {
+        self.distance(source, target)?;
+
+        if self.distances[source][source] < W::ZERO {
+            return None;
+        }
+
+        let mut path = vec![source];
+        let mut node = source;
+
+        while node != target {
+            node = self.next[node][target];
+
+            // A simple path visits every node at most once.
+            if path.len() == self.next.len() || self.distances[node][node] < W::ZERO {
+                return None;
+            }
+
+            path.push(node);
+        }
+
+        Some(path)
+    }
+}
 
 /// Floyd-Warshall Algorithm.
 /// Compute shortest paths between all pairs of vertices in a weighted graph.
 #[derive(Debug, Clone)]
-pub struct FloydWarshallAlgorithm {
+pub struct FloydWarshallAlgorithm<W = i32> {
     /// Total number of nodes in the graph.
     pub total_nodes: usize,
 
     /// Edges in the graph.
-    pub edges: Vec<(usize, usize, i32)>,
+    pub edges: Vec<(usize, usize, W)>,
 }
 
 impl Default for FloydWarshallAlgorithm {
@@ -29,6 +97,18 @@ impl FloydWarshallAlgorithm {
     ///
     /// New instance of Floyd-Warshall Algorithm.
     pub fn new() -> Self {
+        Self::with_weight_type()
+    }
+}
+
+impl<W: Weight> FloydWarshallAlgorithm<W> {
+    /// Create a new instance of Floyd-Warshall Algorithm with weights of another type than `i32`,
+    /// such as `FloydWarshallAlgorithm::<f64>::with_weight_type()`.
+    ///
+    /// # Returns
+    ///
+    /// New instance of Floyd-Warshall Algorithm.
+    pub fn with_weight_type() -> Self {
         Self {
             total_nodes: 0,
             edges: Vec::new(),
@@ -42,9 +122,8 @@ impl FloydWarshallAlgorithm {
     /// - `source`: Source node.
     /// - `target`: Target node.
     /// - `weight`: Weight of the edge.
-    pub fn set_edge(&mut self, source: usize, target: usize, weight: i32) {
-        self.edges.push((source, target, weight));
-        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: W) {
+        self.add_edge(source, target, weight);
     }
 
     /// Set multiple nodes' edges to the graph.
@@ -52,14 +131,49 @@ impl FloydWarshallAlgorithm {
     /// # Arguments
     ///
     /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
-    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, i32)>)>) {
+    #[deprecated(note = "use `set_nodes`")]
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, W)>)>) {
+        self.set_nodes(nodes);
+    }
+
+    /// Set the nodes of the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of nodes and their edges.
+    pub fn set_nodes(&mut self, nodes: Vec<(usize, Vec<(usize, W)>)>) {
         for (source, edges) in nodes {
+            self.total_nodes = self.total_nodes.max(source + 1);
+
             for (target, weight) in edges {
-                self.set_edge(source, target, weight);
+                self.add_edge(source, target, weight);
             }
         }
     }
 
+    /// Add a single directed edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn add_edge(&mut self, source: usize, target: usize, weight: W) {
+        self.edges.push((source, target, weight));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Add directed edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `edges`: Edges as `(source, target, weight)`.
+    pub fn add_weighted_edges(&mut self, edges: impl IntoIterator<Item = (usize, usize, W)>) {
+        for (source, target, weight) in edges {
+            self.add_edge(source, target, weight);
+        }
+    }
+
     /// Set the total number of nodes in the graph.
     ///
     /// # Arguments
@@ -68,14 +182,150 @@ impl FloydWarshallAlgorithm {
     pub fn set_total_nodes(&mut self, total: usize) {
         self.total_nodes = self.total_nodes.max(total);
     }
+
+    /// Compute which nodes reach which others, without distances.
+    /// Warshall's recurrence runs on rows of bits: when a node reaches the intermediate node, the
+    /// row of the intermediate node is merged into its own, 64 targets per word, instead of
+    /// relaxing a matrix of distances.
+    ///
+    /// # Returns
+    ///
+    /// Reachability matrix, where a node reaches itself only through a cycle.
+    pub fn reachability(&self) -> ReachabilityMatrix {
+        let mut matrix = ReachabilityMatrix::new(self.total_nodes);
+        let words = matrix.words_per_row;
+
+        for &(source, target, _) in &self.edges {
+            matrix.bits[source * words + target / 64] |= 1 << (target % 64);
+        }
+
+        let mut through = vec![0; words];
+
+        for k in 0..self.total_nodes {
+            through.copy_from_slice(matrix.row(k));
+
+            for row in matrix.bits.chunks_mut(words) {
+                if row[k / 64] & (1 << (k % 64)) != 0 {
+                    for (word, &bits) in row.iter_mut().zip(&through) {
+                        *word |= bits;
+                    }
+                }
+            }
+        }
+
+        matrix
+    }
+
+    /// Compute the distances between all pairs of nodes together with the next hops, so that the
+    /// shortest paths themselves can be reconstructed.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the distances and next hops, or an error if the weights overflow.
+    pub fn shortest_paths(&self) -> Result<ShortestPathMatrix<W>, GraphError> {
+        self.relax(true)
+    }
+
+    /// Compute the distances and next hops between all pairs of nodes of a shared graph, reading
+    /// its edges in place.
+    ///
+    /// # Arguments
+    ///
+    /// - `graph`: Directed graph, or undirected graph through its directed view.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the distances and next hops, or an error if the weights overflow.
+    pub fn run_on(graph: &DiGraph<W>) -> Result<ShortestPathMatrix<W>, GraphError> {
+        Self::relax_edges(graph.total_nodes(), graph.edges(), true)
+    }
+
+    /// Relax the distances through every intermediate node in turn.
+    ///
+    /// # Arguments
+    ///
+    /// - `with_next`: Whether to track the next hops, left empty otherwise.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the distances and next hops, or an error if the weights overflow.
+    fn relax(&self, with_next: bool) -> Result<ShortestPathMatrix<W>, GraphError> {
+        Self::relax_edges(self.total_nodes, self.edges.iter().copied(), with_next)
+    }
+
+    /// Relax the distances of a list of edges through every intermediate node in turn.
+    ///
+    /// # Arguments
+    ///
+    /// - `n`: Number of nodes, above every node of the edges.
+    /// - `edges`: Edges as `(source, target, weight)`, the cheapest of parallel edges taking effect.
+    /// - `with_next`: Whether to track the next hops, left empty otherwise.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the distances and next hops, or an error if the weights overflow.
+    fn relax_edges(
+        n: usize,
+        edges: impl Iterator<Item = (usize, usize, W)>,
+        with_next: bool,
+    ) -> Result<ShortestPathMatrix<W>, GraphError> {
+        let mut distances = vec![vec![W::INFINITY; n]; n];
+        let mut next = match with_next {
+            true => vec![vec![usize::MAX; n]; n],
+            false => Vec::new(),
+        };
+
+        for (u, v, w) in edges {
+            if w < distances[u][v] {
+                distances[u][v] = w;
+
+                if with_next {
+                    next[u][v] = v;
+                }
+            }
+        }
+
+        // Keep negative self-loops, so that the diagonal still reveals negative cycles.
+        for (v, row) in distances.iter_mut().enumerate().take(n) {
+            if W::ZERO < row[v] {
+                row[v] = W::ZERO;
+
+                if with_next {
+                    next[v][v] = v;
+                }
+            }
+        }
+
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    if distances[i][k] != W::INFINITY && distances[k][j] != W::INFINITY {
+                        let through = distances[i][k]
+                            .checked_add(distances[k][j])
+                            .ok_or(GraphError::WeightOverflow)?;
+
+                        if through < distances[i][j] {
+                            distances[i][j] = through;
+
+                            if with_next {
+                                next[i][j] = next[i][k];
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(ShortestPathMatrix { distances, next })
+    }
 }
 
-impl GraphAlgorithm for FloydWarshallAlgorithm {
+impl<W: Weight> GraphAlgorithm for FloydWarshallAlgorithm<W> {
     /// Type of node.
     type Node = usize;
 
     /// Type of weight.
-    type Weight = Vec<Vec<i32>>;
+    type Weight = Vec<Vec<W>>;
 
     /// Run Floyd-Warshall algorithm.
     ///
@@ -87,27 +337,46 @@ impl GraphAlgorithm for FloydWarshallAlgorithm {
     ///
     /// Result containing a vector of shortest paths, or an error if applicable.
     fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
-        let mut distances = vec![vec![i32::MAX; self.total_nodes]; self.total_nodes];
-
-        for &(u, v, w) in &self.edges {
-            distances[u][v] = w;
-        }
-
-        for (v, row) in distances.iter_mut().enumerate().take(self.total_nodes) {
-            row[v] = 0;
-        }
+        Ok(self.relax(false)?.distances)
+    }
 
-        for k in 0..self.total_nodes {
-            for i in 0..self.total_nodes {
-                for j in 0..self.total_nodes {
-                    if distances[i][k] != i32::MAX && distances[k][j] != i32::MAX {
-                        distances[i][j] = distances[i][j].min(distances[i][k] + distances[k][j]);
-                    }
+    /// Check that every edge connects nodes of the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in Floyd-Warshall algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing nothing, or an error describing the malformed input.
+    fn validate(&self, _start: Option<&Self::Node>) -> Result<(), GraphError> {
+        for &(source, target, _) in &self.edges {
+            for node in [source, target] {
+                if node >= self.total_nodes {
+                    return Err(GraphError::NodeOutOfRange(node));
                 }
             }
         }
 
-        Ok(distances)
+        Ok(())
+    }
+}
+
+impl<W: Weight> MemoryEstimation for FloydWarshallAlgorithm<W> {
+    /// Estimate the memory of the Floyd-Warshall Algorithm, dominated by its matrix of V² distances.
+    ///
+    /// # Returns
+    ///
+    /// Memory estimate of a run.
+    fn estimate_memory(&self) -> MemoryEstimate {
+        let n = self.total_nodes;
+
+        MemoryEstimate::new(
+            MemoryEstimate::bytes::<(usize, usize, W)>(self.edges.len()),
+            0,
+            MemoryEstimate::bytes::<Vec<W>>(n)
+                .saturating_add(MemoryEstimate::bytes::<W>(n.saturating_mul(n))),
+        )
     }
 }
 
@@ -115,6 +384,50 @@ impl GraphAlgorithm for FloydWarshallAlgorithm {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_run_on() {
+        let mut graph = DiGraph::new();
+        graph.add_weighted_edges([(0, 1, 3), (1, 2, -1), (0, 2, 5), (0, 2, 4)]);
+
+        let paths = FloydWarshallAlgorithm::run_on(&graph).unwrap();
+        let mut algorithm = FloydWarshallAlgorithm::new();
+        algorithm.add_weighted_edges(graph.edges());
+
+        assert_eq!(paths, algorithm.shortest_paths().unwrap());
+        assert_eq!(paths.path(0, 2), Some(vec![0, 1, 2]));
+
+        // The cheapest of parallel edges wins whatever their order.
+        let mut graph = DiGraph::new();
+        graph.add_weighted_edges([(0, 1, 2), (0, 1, 7), (1, 0, 9), (1, 0, 4)]);
+
+        let paths = FloydWarshallAlgorithm::run_on(&graph).unwrap();
+
+        assert_eq!(paths.distances, vec![vec![0, 2], vec![4, 0]]);
+        assert_eq!(paths.path(1, 0), Some(vec![1, 0]));
+
+        // A negative self-loop stays on the diagonal as a negative cycle.
+        graph.add_edge(1, 1, -1);
+
+        let paths = FloydWarshallAlgorithm::run_on(&graph).unwrap();
+
+        assert!(paths.distances[1][1] < 0);
+        assert_eq!(paths.path(0, 1), None);
+    }
+
+    #[test]
+    fn test_weight_types() {
+        let mut algorithm = FloydWarshallAlgorithm::<f64>::with_weight_type();
+        algorithm.add_weighted_edges([(0, 1, 0.5), (1, 2, -0.25), (2, 0, 1.0)]);
+        algorithm.set_total_nodes(4);
+
+        let paths = algorithm.shortest_paths().unwrap();
+
+        assert_eq!(paths.distance(0, 2), Some(0.25));
+        assert_eq!(paths.distance(0, 3), None);
+        assert_eq!(paths.path(2, 1), Some(vec![2, 0, 1]));
+        assert_eq!(algorithm.run(None).unwrap()[3][3], 0.0);
+    }
+
     #[test]
     fn test_new() {
         let algorithm = FloydWarshallAlgorithm::new();
@@ -128,20 +441,20 @@ mod tests {
     fn test_run() {
         let mut algorithm = FloydWarshallAlgorithm::new();
 
-        algorithm.set_edge(0, 1, 4);
-        algorithm.set_edge(1, 2, 1);
-        algorithm.set_edge(0, 2, 7);
-        algorithm.set_edge(2, 3, 3);
-        algorithm.set_edge(3, 4, 2);
-        algorithm.set_edge(4, 5, 1);
-        algorithm.set_edge(5, 6, 6);
-        algorithm.set_edge(0, 6, 15);
-        algorithm.set_edge(1, 4, 8);
-        algorithm.set_edge(2, 5, 12);
-        algorithm.set_edge(3, 6, 7);
-        algorithm.set_edge(4, 2, 5);
-        algorithm.set_edge(5, 0, 10);
-        algorithm.set_edge(6, 1, 11);
+        algorithm.add_edge(0, 1, 4);
+        algorithm.add_edge(1, 2, 1);
+        algorithm.add_edge(0, 2, 7);
+        algorithm.add_edge(2, 3, 3);
+        algorithm.add_edge(3, 4, 2);
+        algorithm.add_edge(4, 5, 1);
+        algorithm.add_edge(5, 6, 6);
+        algorithm.add_edge(0, 6, 15);
+        algorithm.add_edge(1, 4, 8);
+        algorithm.add_edge(2, 5, 12);
+        algorithm.add_edge(3, 6, 7);
+        algorithm.add_edge(4, 2, 5);
+        algorithm.add_edge(5, 0, 10);
+        algorithm.add_edge(6, 1, 11);
 
         let result = algorithm.run(None).unwrap();
 
@@ -159,10 +472,38 @@ mod tests {
         assert_eq!(result[4][6], 7);
     }
 
+    #[test]
+    fn test_builders() {
+        let mut algorithm = FloydWarshallAlgorithm::new();
+        algorithm.add_edge(0, 1, 4);
+        algorithm.add_weighted_edges([(1, 2, -1), (0, 2, 5)]);
+
+        assert_eq!(algorithm.run(None).unwrap()[0], vec![0, 4, 3]);
+
+        let mut algorithm = FloydWarshallAlgorithm::new();
+        algorithm.set_nodes(vec![(0, vec![(1, 2)]), (2, vec![])]);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0][1], 2);
+        assert_eq!(result[0][2], i32::MAX);
+
+        algorithm.set_edge(1, 2, 3);
+
+        #[allow(deprecated)]
+        algorithm.set_edges(vec![(2, vec![(0, 1)])]);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result[0][2], 5);
+        assert_eq!(result[2][1], 3);
+    }
+
     #[test]
     fn test_run_single_node() {
         let mut algorithm = FloydWarshallAlgorithm::new();
-        algorithm.set_edge(0, 0, 0);
+        algorithm.add_edge(0, 0, 0);
 
         let result = algorithm.run(None).unwrap();
 
@@ -172,7 +513,7 @@ mod tests {
     #[test]
     fn test_run_two_nodes_one_edge() {
         let mut algorithm = FloydWarshallAlgorithm::new();
-        algorithm.set_edge(0, 1, 5);
+        algorithm.add_edge(0, 1, 5);
 
         let result = algorithm.run(None).unwrap();
 
@@ -183,7 +524,7 @@ mod tests {
     #[test]
     fn test_run_negative_weights_without_cycle() {
         let mut algorithm = FloydWarshallAlgorithm::new();
-        algorithm.set_edges(vec![
+        algorithm.set_nodes(vec![
             (0, vec![(1, -2), (2, 4)]),
             (1, vec![(2, 1)]),
             (0, vec![(2, 4)]),
@@ -198,12 +539,12 @@ mod tests {
     #[test]
     fn test_run_complete_graph() {
         let mut algorithm = FloydWarshallAlgorithm::new();
-        algorithm.set_edge(0, 1, 1);
-        algorithm.set_edge(0, 2, 2);
-        algorithm.set_edge(1, 2, 1);
-        algorithm.set_edge(1, 0, 3);
-        algorithm.set_edge(2, 0, 4);
-        algorithm.set_edge(2, 1, 5);
+        algorithm.add_edge(0, 1, 1);
+        algorithm.add_edge(0, 2, 2);
+        algorithm.add_edge(1, 2, 1);
+        algorithm.add_edge(1, 0, 3);
+        algorithm.add_edge(2, 0, 4);
+        algorithm.add_edge(2, 1, 5);
 
         let result = algorithm.run(None).unwrap();
 
@@ -215,8 +556,8 @@ mod tests {
     #[test]
     fn test_run_disconnected_nodes() {
         let mut algorithm = FloydWarshallAlgorithm::new();
-        algorithm.set_edge(0, 1, 3);
-        algorithm.set_edge(1, 2, 4);
+        algorithm.add_edge(0, 1, 3);
+        algorithm.add_edge(1, 2, 4);
         algorithm.set_total_nodes(4);
 
         let result = algorithm.run(None).unwrap();
@@ -225,12 +566,74 @@ mod tests {
         assert_eq!(result[3][3], 0);
     }
 
+    #[test]
+    fn test_reachability() {
+        let mut algorithm = FloydWarshallAlgorithm::new();
+        algorithm.add_edge(0, 1, 3);
+        algorithm.add_edge(1, 2, -4);
+        algorithm.add_edge(2, 1, 2);
+        algorithm.add_edge(3, 0, 1);
+        algorithm.set_total_nodes(5);
+
+        let matrix = algorithm.reachability();
+
+        assert_eq!(matrix.reachable(0), vec![1, 2]);
+        assert_eq!(matrix.reachable(1), vec![1, 2]);
+        assert_eq!(matrix.reachable(3), vec![0, 1, 2]);
+        assert!(matrix.reachable(4).is_empty());
+        assert!(!matrix.reaches(0, 0));
+        assert!(!matrix.reaches(2, 3));
+    }
+
+    #[test]
+    fn test_reachability_matches_distances() {
+        let mut algorithm = FloydWarshallAlgorithm::new();
+
+        for node in 0..150 {
+            algorithm.add_edge(node, (node * 7 + 3) % 150, 1);
+
+            if node % 5 == 0 {
+                algorithm.add_edge(node, (node + 70) % 150, 2);
+            }
+        }
+
+        let distances = algorithm.run(None).unwrap();
+        let matrix = algorithm.reachability();
+
+        for (source, row) in distances.iter().enumerate() {
+            for (target, &distance) in row.iter().enumerate() {
+                if source != target {
+                    assert_eq!(matrix.reaches(source, target), distance != i32::MAX);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_run() {
+        let mut algorithm = FloydWarshallAlgorithm::new();
+        algorithm.add_edge(0, 1, i32::MAX - 1);
+        algorithm.add_edge(1, 2, 2);
+
+        assert_eq!(algorithm.try_run(None), Err(GraphError::WeightOverflow));
+
+        let mut algorithm = FloydWarshallAlgorithm::new();
+        algorithm.add_edge(0, 1, 1);
+        algorithm.edges.push((1, 4, 1));
+
+        assert_eq!(algorithm.try_run(None), Err(GraphError::NodeOutOfRange(4)));
+
+        algorithm.set_total_nodes(5);
+
+        assert_eq!(algorithm.try_run(None).unwrap()[0][4], 2);
+    }
+
     #[test]
     fn test_run_zero_weight_cycle() {
         let mut algorithm = FloydWarshallAlgorithm::new();
-        algorithm.set_edge(0, 1, 2);
-        algorithm.set_edge(1, 2, -3);
-        algorithm.set_edge(2, 0, 1);
+        algorithm.add_edge(0, 1, 2);
+        algorithm.add_edge(1, 2, -3);
+        algorithm.add_edge(2, 0, 1);
 
         let result = algorithm.run(None).unwrap();
 
@@ -239,4 +642,47 @@ mod tests {
         assert_eq!(result[2][0], 1);
         assert_eq!(result[0][2], -1);
     }
+
+    #[test]
+    fn test_estimate_memory() {
+        let mut algorithm = FloydWarshallAlgorithm::new();
+        algorithm.set_total_nodes(100_000);
+        let estimate = algorithm.estimate_memory();
+
+        // Forty gigabytes of distances are refused before allocating them.
+        assert!(estimate.output > 40_000_000_000);
+        assert!(matches!(
+            algorithm.run_within(None, 1 << 30),
+            Err(GraphError::MemoryLimitExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn test_shortest_paths() {
+        let mut algorithm = FloydWarshallAlgorithm::new();
+        algorithm.add_weighted_edges(vec![(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 1), (3, 0, -2)]);
+        algorithm.set_total_nodes(5);
+
+        let matrix = algorithm.shortest_paths().unwrap();
+
+        assert_eq!(matrix.distances, algorithm.run(None).unwrap());
+        assert_eq!(matrix.path(0, 3), Some(vec![0, 2, 1, 3]));
+        assert_eq!(matrix.path(3, 1), Some(vec![3, 0, 2, 1]));
+        assert_eq!(matrix.path(2, 2), Some(vec![2]));
+        assert_eq!(matrix.path(0, 4), None);
+        assert_eq!(matrix.path(0, 9), None);
+        assert_eq!(matrix.distance(3, 1), Some(1));
+    }
+
+    #[test]
+    fn test_shortest_paths_negative_cycle() {
+        let mut algorithm = FloydWarshallAlgorithm::new();
+        algorithm.add_weighted_edges(vec![(0, 1, 1), (1, 2, -3), (2, 1, 1), (2, 3, 1)]);
+
+        let matrix = algorithm.shortest_paths().unwrap();
+
+        assert_eq!(matrix.path(0, 3), None);
+        assert_eq!(matrix.path(1, 2), None);
+        assert!(algorithm.run(None).unwrap()[1][1] < 0);
+    }
 }
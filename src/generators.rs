@@ -0,0 +1,449 @@
+use std::collections::BTreeSet;
+
+use crate::{random::Random, ConfiguredAlgorithm, GraphAlgorithm, GraphError, Provenance};
+
+/// Random graph model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RandomGraphModel {
+    /// Erdős–Rényi `G(n, p)`: every pair of nodes is joined independently with a probability.
+    ErdosRenyi {
+        /// Probability of each edge.
+        probability: f64,
+    },
+
+    /// Barabási–Albert preferential attachment: every new node joins existing nodes
+    /// with a probability proportional to their degree, giving a scale-free degree distribution.
+    BarabasiAlbert {
+        /// Number of edges of every new node.
+        edges_per_node: usize,
+    },
+
+    /// Watts–Strogatz small world: a ring lattice whose edges are rewired to random targets
+    /// with a probability, keeping a high clustering with short paths.
+    WattsStrogatz {
+        /// Number of ring neighbors of each node, rounded down to an even number.
+        neighbors: usize,
+
+        /// Probability of rewiring each lattice edge.
+        rewiring: f64,
+    },
+}
+
+impl Default for RandomGraphModel {
+    /// Create the default random graph model.
+    ///
+    /// # Returns
+    ///
+    /// Erdős–Rényi model without edges.
+    fn default() -> Self {
+        Self::ErdosRenyi { probability: 0.0 }
+    }
+}
+
+/// Randomly generated undirected graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedGraph {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Undirected edges as `(source, target)` with `source < target`, in ascending order.
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl GeneratedGraph {
+    /// Group the edges by source node with a weight, in the format accepted by `set_edges`
+    /// of the algorithms on undirected graphs.
+    ///
+    /// # Arguments
+    ///
+    /// - `weight`: Weight of every edge.
+    ///
+    /// # Returns
+    ///
+    /// Vector of tuples where each tuple contains a node and its associated edges, each edge once.
+    pub fn nodes<W: Copy>(&self, weight: W) -> Vec<(usize, Vec<(usize, W)>)> {
+        let mut nodes: Vec<(usize, Vec<(usize, W)>)> = (0..self.total_nodes)
+            .map(|node| (node, Vec::new()))
+            .collect();
+
+        for &(source, target) in &self.edges {
+            nodes[source].1.push((target, weight));
+        }
+
+        nodes
+    }
+
+    /// Group the edges by source node with a weight in both directions, in the format accepted
+    /// by the algorithms on directed graphs, such as `set_nodes` of Dijkstra's Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `weight`: Weight of every edge.
+    ///
+    /// # Returns
+    ///
+    /// Vector of tuples where each tuple contains a node and its associated edges, each edge twice.
+    pub fn directed_nodes<W: Copy>(&self, weight: W) -> Vec<(usize, Vec<(usize, W)>)> {
+        let mut nodes = self.nodes(weight);
+
+        for &(source, target) in &self.edges {
+            nodes[target].1.push((source, weight));
+        }
+
+        nodes
+    }
+}
+
+/// Random Graph Generator.
+/// Generate reproducible synthetic graphs from a seed with the Erdős–Rényi, Barabási–Albert or
+/// Watts–Strogatz model. Sparse `G(n, p)` graphs are generated in time linear in their size
+/// by skipping geometrically distributed runs of absent edges.
+#[derive(Debug, Clone)]
+pub struct GraphGenerator {
+    /// Total number of nodes to generate.
+    pub total_nodes: usize,
+
+    /// Random graph model.
+    pub model: RandomGraphModel,
+
+    /// Seed of the generator.
+    pub seed: u64,
+}
+
+impl Default for GraphGenerator {
+    /// Create a new default instance of the random graph generator.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the random graph generator.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GraphGenerator {
+    /// Create a new instance of the random graph generator.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the random graph generator.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            model: RandomGraphModel::default(),
+            seed: 0,
+        }
+    }
+
+    /// Set the total number of nodes to generate.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = total;
+    }
+
+    /// Set the random graph model.
+    ///
+    /// # Arguments
+    ///
+    /// - `model`: Random graph model.
+    pub fn set_model(&mut self, model: RandomGraphModel) {
+        self.model = model;
+    }
+
+    /// Set the seed of the generator.
+    ///
+    /// # Arguments
+    ///
+    /// - `seed`: Seed, the same seed always produces the same graph.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    /// Generate a graph.
+    ///
+    /// # Returns
+    ///
+    /// Generated graph.
+    pub fn generate(&self) -> GeneratedGraph {
+        let mut random = Random::new(self.seed);
+        let n = self.total_nodes;
+
+        let edges = match self.model {
+            RandomGraphModel::ErdosRenyi { probability } => {
+                Self::erdos_renyi(&mut random, n, probability)
+            }
+            RandomGraphModel::BarabasiAlbert { edges_per_node } => {
+                Self::barabasi_albert(&mut random, n, edges_per_node)
+            }
+            RandomGraphModel::WattsStrogatz {
+                neighbors,
+                rewiring,
+            } => Self::watts_strogatz(&mut random, n, neighbors / 2, rewiring),
+        };
+
+        GeneratedGraph {
+            total_nodes: n,
+            edges: edges.into_iter().collect(),
+        }
+    }
+
+    /// Generate a `G(n, p)` graph, jumping over the pairs without an edge (Batagelj and Brandes).
+    ///
+    /// # Arguments
+    ///
+    /// - `random`: Random number generator.
+    /// - `n`: Number of nodes.
+    /// - `probability`: Probability of each edge.
+    ///
+    /// # Returns
+    ///
+    /// Edges of the graph.
+    fn erdos_renyi(random: &mut Random, n: usize, probability: f64) -> BTreeSet<(usize, usize)> {
+        let mut edges = BTreeSet::new();
+
+        if probability <= 0.0 {
+            return edges;
+        }
+
+        if probability >= 1.0 {
+            return (0..n)
+                .flat_map(|target| (0..target).map(move |source| (source, target)))
+                .collect();
+        }
+
+        // Pairs are enumerated as (w, v) with w < v, the gap to the next edge being geometric.
+        let log = (1.0 - probability).ln();
+        let (mut v, mut w) = (1, 0usize.wrapping_sub(1));
+
+        while v < n {
+            let skip = ((1.0 - random.next_f64()).ln() / log).floor() as usize;
+            w = w.wrapping_add(1).saturating_add(skip);
+
+            while w >= v && v < n {
+                w -= v;
+                v += 1;
+            }
+
+            if v < n {
+                edges.insert((w, v));
+            }
+        }
+
+        edges
+    }
+
+    /// Generate a preferential attachment graph, starting from a clique of `m + 1` nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `random`: Random number generator.
+    /// - `n`: Number of nodes.
+    /// - `m`: Number of edges of every new node.
+    ///
+    /// # Returns
+    ///
+    /// Edges of the graph.
+    fn barabasi_albert(random: &mut Random, n: usize, m: usize) -> BTreeSet<(usize, usize)> {
+        let initial = n.min(m + 1);
+        let mut edges: BTreeSet<(usize, usize)> = (0..initial)
+            .flat_map(|target| (0..target).map(move |source| (source, target)))
+            .collect();
+
+        // Every node appears once per incident edge, so uniform picks follow the degrees.
+        let mut endpoints: Vec<usize> = edges
+            .iter()
+            .flat_map(|&(source, target)| [source, target])
+            .collect();
+
+        for node in initial..n {
+            let mut targets = BTreeSet::new();
+
+            while targets.len() < m {
+                targets.insert(endpoints[random.below(endpoints.len())]);
+            }
+
+            for target in targets {
+                edges.insert((target, node));
+                endpoints.extend([target, node]);
+            }
+        }
+
+        edges
+    }
+
+    /// Generate a small world graph by rewiring a ring lattice.
+    ///
+    /// # Arguments
+    ///
+    /// - `random`: Random number generator.
+    /// - `n`: Number of nodes.
+    /// - `half`: Number of ring neighbors on each side.
+    /// - `rewiring`: Probability of rewiring each lattice edge.
+    ///
+    /// # Returns
+    ///
+    /// Edges of the graph.
+    fn watts_strogatz(
+        random: &mut Random,
+        n: usize,
+        half: usize,
+        rewiring: f64,
+    ) -> BTreeSet<(usize, usize)> {
+        let half = half.min(n.saturating_sub(1) / 2);
+        let key = |a: usize, b: usize| (a.min(b), a.max(b));
+        let mut edges: BTreeSet<(usize, usize)> = (0..n)
+            .flat_map(|node| (1..=half).map(move |offset| key(node, (node + offset) % n)))
+            .collect();
+
+        // Rewire the far end of each lattice edge, keeping the graph simple.
+        for offset in 1..=half {
+            for node in 0..n {
+                let edge = key(node, (node + offset) % n);
+
+                if random.next_f64() >= rewiring || !edges.contains(&edge) {
+                    continue;
+                }
+
+                let free: Vec<usize> = (0..n)
+                    .filter(|&target| target != node && !edges.contains(&key(node, target)))
+                    .collect();
+
+                if !free.is_empty() {
+                    edges.remove(&edge);
+                    edges.insert(key(node, free[random.below(free.len())]));
+                }
+            }
+        }
+
+        edges
+    }
+}
+
+impl GraphAlgorithm for GraphGenerator {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = GeneratedGraph;
+
+    /// Run the random graph generator.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the random graph generator.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the generated graph.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.generate())
+    }
+}
+
+impl ConfiguredAlgorithm for GraphGenerator {
+    /// Describe the configuration of the random graph generator.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("generators")
+            .with("total_nodes", self.total_nodes)
+            .with("model", self.model)
+            .with("seed", self.seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Degree of each node.
+    fn degrees(graph: &GeneratedGraph) -> Vec<usize> {
+        let mut degrees = vec![0; graph.total_nodes];
+
+        for &(source, target) in &graph.edges {
+            degrees[source] += 1;
+            degrees[target] += 1;
+        }
+
+        degrees
+    }
+
+    #[test]
+    fn test_new() {
+        let generator = GraphGenerator::new();
+        let generator_default = GraphGenerator::default();
+
+        assert_eq!(generator.seed, 0);
+        assert_eq!(generator_default.run(None).unwrap().edges, vec![]);
+    }
+
+    #[test]
+    fn test_erdos_renyi() {
+        let mut generator = GraphGenerator::new();
+        generator.set_total_nodes(400);
+        generator.set_model(RandomGraphModel::ErdosRenyi { probability: 0.05 });
+        generator.set_seed(7);
+
+        let graph = generator.run(None).unwrap();
+
+        // About 0.05 * 400 * 399 / 2 = 3990 edges, identical for the same seed.
+        assert!((3700..4300).contains(&graph.edges.len()));
+        assert!(graph
+            .edges
+            .iter()
+            .all(|&(source, target)| source < target && target < 400));
+        assert_eq!(generator.generate(), graph);
+
+        generator.set_total_nodes(5);
+        generator.set_model(RandomGraphModel::ErdosRenyi { probability: 1.0 });
+
+        assert_eq!(generator.generate().edges.len(), 10);
+    }
+
+    #[test]
+    fn test_barabasi_albert() {
+        let mut generator = GraphGenerator::new();
+        generator.set_total_nodes(500);
+        generator.set_model(RandomGraphModel::BarabasiAlbert { edges_per_node: 2 });
+        generator.set_seed(3);
+
+        let graph = generator.generate();
+        let degrees = degrees(&graph);
+
+        // A triangle plus two edges per new node, with hubs far above the minimum degree.
+        assert_eq!(graph.edges.len(), 3 + 2 * 497);
+        assert_eq!(degrees.iter().min(), Some(&2));
+        assert!(degrees.iter().max().unwrap() > &20);
+    }
+
+    #[test]
+    fn test_watts_strogatz() {
+        let mut generator = GraphGenerator::new();
+        generator.set_total_nodes(20);
+        generator.set_model(RandomGraphModel::WattsStrogatz {
+            neighbors: 4,
+            rewiring: 0.0,
+        });
+
+        let lattice = generator.generate();
+
+        assert_eq!(lattice.edges.len(), 40);
+        assert!(degrees(&lattice).iter().all(|&degree| degree == 4));
+
+        generator.set_model(RandomGraphModel::WattsStrogatz {
+            neighbors: 4,
+            rewiring: 0.3,
+        });
+
+        let rewired = generator.generate();
+
+        assert_eq!(rewired.edges.len(), 40);
+        assert_ne!(rewired, lattice);
+        assert_eq!(rewired.directed_nodes(1)[0].1.len(), degrees(&rewired)[0]);
+    }
+}
@@ -0,0 +1,486 @@
+use std::collections::BTreeMap;
+
+use crate::DijkstraAlgorithm;
+
+/// Rule connecting the points of a geometric graph.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Neighborhood {
+    /// Connect each point to its `k` nearest other points, keeping an edge if either end chose it.
+    Nearest(usize),
+
+    /// Connect every pair of points within a Euclidean distance.
+    Radius(f64),
+}
+
+impl Default for Neighborhood {
+    /// Create the default neighborhood, the 8 nearest points.
+    ///
+    /// # Returns
+    ///
+    /// Default neighborhood.
+    fn default() -> Self {
+        Neighborhood::Nearest(8)
+    }
+}
+
+/// Geometric Graph.
+/// Build an undirected graph over a cloud of points in `D` dimensions, such as 2D or 3D samples of
+/// a robot's configuration space, weighting each edge by the Euclidean distance between its ends.
+/// Neighbors are found with a k-d tree instead of comparing every pair of points.
+#[derive(Debug, Clone)]
+pub struct GeometricGraph<const D: usize> {
+    /// Coordinates of each point.
+    pub points: Vec<[f64; D]>,
+
+    /// Rule connecting the points.
+    pub neighborhood: Neighborhood,
+}
+
+impl<const D: usize> Default for GeometricGraph<D> {
+    /// Create a new default instance of the geometric graph.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the geometric graph.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const D: usize> GeometricGraph<D> {
+    /// Create a new instance of the geometric graph.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the geometric graph.
+    pub fn new() -> Self {
+        Self {
+            points: Vec::new(),
+            neighborhood: Neighborhood::default(),
+        }
+    }
+
+    /// Add a point as the next node.
+    ///
+    /// # Arguments
+    ///
+    /// - `point`: Coordinates of the point.
+    ///
+    /// # Returns
+    ///
+    /// Node of the point.
+    pub fn set_point(&mut self, point: [f64; D]) -> usize {
+        self.points.push(point);
+        self.points.len() - 1
+    }
+
+    /// Add points as the next nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `points`: Coordinates of the points.
+    pub fn set_points(&mut self, points: &[[f64; D]]) {
+        self.points.extend_from_slice(points);
+    }
+
+    /// Set the rule connecting the points.
+    ///
+    /// # Arguments
+    ///
+    /// - `neighborhood`: k nearest neighbors or radius.
+    pub fn set_neighborhood(&mut self, neighborhood: Neighborhood) {
+        self.neighborhood = neighborhood;
+    }
+
+    /// Compute the Euclidean distance between two nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Distance between the points of the nodes.
+    pub fn distance(&self, source: usize, target: usize) -> f64 {
+        squared_distance(&self.points[source], &self.points[target]).sqrt()
+    }
+
+    /// Find the nodes within a distance of a point.
+    ///
+    /// # Arguments
+    ///
+    /// - `point`: Coordinates of the query point.
+    /// - `radius`: Maximum distance.
+    ///
+    /// # Returns
+    ///
+    /// Nodes within the radius, in ascending order.
+    pub fn within(&self, point: &[f64; D], radius: f64) -> Vec<usize> {
+        let mut found = Vec::new();
+
+        self.tree()
+            .within(&self.points, point, radius * radius, &mut found);
+        found.sort_unstable();
+        found
+    }
+
+    /// Find the nearest nodes to a point.
+    ///
+    /// # Arguments
+    ///
+    /// - `point`: Coordinates of the query point.
+    /// - `k`: Number of nodes.
+    ///
+    /// # Returns
+    ///
+    /// Up to `k` nodes as `(node, distance)`, nearest first.
+    pub fn nearest(&self, point: &[f64; D], k: usize) -> Vec<(usize, f64)> {
+        self.tree()
+            .nearest(&self.points, point, k, None)
+            .into_iter()
+            .map(|(distance, node)| (node, distance.sqrt()))
+            .collect()
+    }
+
+    /// Build the edges of the graph.
+    ///
+    /// # Returns
+    ///
+    /// Undirected edges as `(source, target, distance)` with `source < target`, sorted.
+    pub fn edges(&self) -> Vec<(usize, usize, f64)> {
+        let tree = self.tree();
+        let mut edges = BTreeMap::new();
+
+        for (node, point) in self.points.iter().enumerate() {
+            let neighbors = match self.neighborhood {
+                Neighborhood::Nearest(k) => tree
+                    .nearest(&self.points, point, k, Some(node))
+                    .into_iter()
+                    .map(|(_, neighbor)| neighbor)
+                    .collect(),
+                Neighborhood::Radius(radius) => {
+                    let mut found = Vec::new();
+                    tree.within(&self.points, point, radius * radius, &mut found);
+                    found
+                }
+            };
+
+            for neighbor in neighbors.into_iter().filter(|&neighbor| neighbor != node) {
+                let key = (node.min(neighbor), node.max(neighbor));
+                edges.insert(key, self.distance(node, neighbor));
+            }
+        }
+
+        edges
+            .into_iter()
+            .map(|((source, target), distance)| (source, target, distance))
+            .collect()
+    }
+
+    /// Build a graph for Dijkstra's Algorithm with integer weights.
+    ///
+    /// # Arguments
+    ///
+    /// - `unit`: Distance of one weight unit, e.g. `0.01` for centimetres with points in metres.
+    ///
+    /// # Returns
+    ///
+    /// Graph with every node and the rounded weights of both directions of every edge.
+    pub fn to_dijkstra(&self, unit: f64) -> DijkstraAlgorithm {
+        let mut graph = DijkstraAlgorithm::new();
+        let mut nodes = vec![Vec::new(); self.points.len()];
+
+        for (source, target, distance) in self.edges() {
+            let weight = (distance / unit).round() as usize;
+
+            nodes[source].push((target, weight));
+            nodes[target].push((source, weight));
+        }
+
+        graph.set_nodes(nodes.into_iter().enumerate().collect());
+        graph
+    }
+
+    /// Build a k-d tree over the points.
+    ///
+    /// # Returns
+    ///
+    /// k-d tree of every node.
+    fn tree(&self) -> KdTree {
+        let mut order: Vec<usize> = (0..self.points.len()).collect();
+        build(&self.points, &mut order, 0);
+
+        KdTree { order }
+    }
+}
+
+/// k-d tree stored implicitly: the median of each range splits it along the axis of its depth.
+#[derive(Debug)]
+struct KdTree {
+    /// Nodes arranged so that every range is split by its middle element.
+    order: Vec<usize>,
+}
+
+impl KdTree {
+    /// Collect the nodes within a squared distance of a point.
+    ///
+    /// # Arguments
+    ///
+    /// - `points`: Coordinates of the nodes.
+    /// - `point`: Coordinates of the query point.
+    /// - `bound`: Squared maximum distance.
+    /// - `found`: Nodes found so far.
+    fn within<const D: usize>(
+        &self,
+        points: &[[f64; D]],
+        point: &[f64; D],
+        bound: f64,
+        found: &mut Vec<usize>,
+    ) {
+        let mut stack = vec![(0, self.order.len(), 0)];
+
+        while let Some((low, high, depth)) = stack.pop() {
+            if low >= high {
+                continue;
+            }
+
+            let middle = (low + high) / 2;
+            let node = self.order[middle];
+            let difference = point[depth % D] - points[node][depth % D];
+
+            if squared_distance(point, &points[node]) <= bound {
+                found.push(node);
+            }
+
+            if difference <= 0.0 || difference * difference <= bound {
+                stack.push((low, middle, depth + 1));
+            }
+
+            if difference >= 0.0 || difference * difference <= bound {
+                stack.push((middle + 1, high, depth + 1));
+            }
+        }
+    }
+
+    /// Find the nearest nodes to a point.
+    ///
+    /// # Arguments
+    ///
+    /// - `points`: Coordinates of the nodes.
+    /// - `point`: Coordinates of the query point.
+    /// - `k`: Number of nodes.
+    /// - `excluded`: Node left out, such as the query point itself.
+    ///
+    /// # Returns
+    ///
+    /// Up to `k` nodes as `(squared distance, node)`, nearest first.
+    fn nearest<const D: usize>(
+        &self,
+        points: &[[f64; D]],
+        point: &[f64; D],
+        k: usize,
+        excluded: Option<usize>,
+    ) -> Vec<(f64, usize)> {
+        let mut best: Vec<(f64, usize)> = Vec::with_capacity(k + 1);
+
+        if k > 0 {
+            self.search(
+                points,
+                point,
+                k,
+                excluded,
+                (0, self.order.len(), 0),
+                &mut best,
+            );
+        }
+
+        best
+    }
+
+    /// Visit a range of the tree for the nearest neighbor search, near side first.
+    ///
+    /// # Arguments
+    ///
+    /// - `points`: Coordinates of the nodes.
+    /// - `point`: Coordinates of the query point.
+    /// - `k`: Number of nodes.
+    /// - `excluded`: Node left out.
+    /// - `range`: Range of the tree as `(low, high, depth)`.
+    /// - `best`: Nearest nodes found so far, sorted.
+    fn search<const D: usize>(
+        &self,
+        points: &[[f64; D]],
+        point: &[f64; D],
+        k: usize,
+        excluded: Option<usize>,
+        (low, high, depth): (usize, usize, usize),
+        best: &mut Vec<(f64, usize)>,
+    ) {
+        if low >= high {
+            return;
+        }
+
+        let middle = (low + high) / 2;
+        let node = self.order[middle];
+
+        if Some(node) != excluded {
+            let candidate = (squared_distance(point, &points[node]), node);
+
+            if best.len() < k || candidate < best[k - 1] {
+                let position = best.partition_point(|entry| *entry < candidate);
+                best.insert(position, candidate);
+                best.truncate(k);
+            }
+        }
+
+        let difference = point[depth % D] - points[node][depth % D];
+        let (near, far) = if difference <= 0.0 {
+            ((low, middle, depth + 1), (middle + 1, high, depth + 1))
+        } else {
+            ((middle + 1, high, depth + 1), (low, middle, depth + 1))
+        };
+
+        self.search(points, point, k, excluded, near, best);
+
+        if best.len() < k || difference * difference <= best[k - 1].0 {
+            self.search(points, point, k, excluded, far, best);
+        }
+    }
+}
+
+/// Arrange nodes into an implicit k-d tree.
+///
+/// # Arguments
+///
+/// - `points`: Coordinates of the nodes.
+/// - `order`: Nodes of the range to arrange.
+/// - `depth`: Depth of the range, selecting the split axis.
+fn build<const D: usize>(points: &[[f64; D]], order: &mut [usize], depth: usize) {
+    if order.len() <= 1 {
+        return;
+    }
+
+    let middle = order.len() / 2;
+    let axis = depth % D;
+
+    order.select_nth_unstable_by(middle, |&a, &b| points[a][axis].total_cmp(&points[b][axis]));
+
+    let (left, right) = order.split_at_mut(middle);
+    build(points, left, depth + 1);
+    build(points, &mut right[1..], depth + 1);
+}
+
+/// Compute the squared Euclidean distance between two points.
+///
+/// # Arguments
+///
+/// - `a`: First point.
+/// - `b`: Second point.
+///
+/// # Returns
+///
+/// Squared distance.
+fn squared_distance<const D: usize>(a: &[f64; D], b: &[f64; D]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GraphAlgorithm;
+
+    fn cloud() -> Vec<[f64; 3]> {
+        (0..200)
+            .map(|i| {
+                let i = i as f64;
+                [(i * 7.3) % 11.0, (i * 3.1) % 13.0, (i * 5.7) % 7.0]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_new() {
+        let graph = GeometricGraph::<2>::new();
+        let graph_default = GeometricGraph::<3>::default();
+
+        assert!(graph.points.is_empty());
+        assert_eq!(graph_default.neighborhood, Neighborhood::Nearest(8));
+    }
+
+    #[test]
+    fn test_edges_radius() {
+        let mut graph = GeometricGraph::new();
+        graph.set_points(&[[0.0, 0.0], [3.0, 4.0], [6.0, 8.0], [0.0, 1.0]]);
+        graph.set_neighborhood(Neighborhood::Radius(5.0));
+
+        assert_eq!(
+            graph.edges(),
+            vec![
+                (0, 1, 5.0),
+                (0, 3, 1.0),
+                (1, 2, 5.0),
+                (1, 3, 18.0_f64.sqrt())
+            ]
+        );
+        assert_eq!(graph.within(&[3.0, 3.0], 1.5), vec![1]);
+    }
+
+    #[test]
+    fn test_edges_nearest() {
+        let mut graph = GeometricGraph::new();
+        graph.set_points(&[[0.0], [1.0], [3.0], [10.0]]);
+        graph.set_neighborhood(Neighborhood::Nearest(1));
+
+        assert_eq!(graph.edges(), vec![(0, 1, 1.0), (1, 2, 2.0), (2, 3, 7.0)]);
+
+        let nearest = graph.nearest(&[2.6], 2);
+        assert_eq!(nearest.len(), 2);
+        assert_eq!((nearest[0].0, nearest[1].0), (2, 1));
+        assert!((nearest[1].1 - 1.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let mut graph = GeometricGraph::new();
+        graph.set_points(&cloud());
+
+        for node in [0, 57, 199] {
+            let mut expected: Vec<(f64, usize)> = (0..200)
+                .filter(|&other| other != node)
+                .map(|other| (graph.distance(node, other), other))
+                .collect();
+            expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+            let nearest: Vec<usize> = graph
+                .nearest(&graph.points[node], 6)
+                .into_iter()
+                .map(|(other, _)| other)
+                .filter(|&other| other != node)
+                .collect();
+            let within: Vec<usize> = (0..200)
+                .filter(|&other| graph.distance(node, other) <= 2.5)
+                .collect();
+
+            assert_eq!(
+                nearest,
+                expected[..nearest.len()]
+                    .iter()
+                    .map(|&(_, other)| other)
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(graph.within(&graph.points[node], 2.5), within);
+        }
+    }
+
+    #[test]
+    fn test_to_dijkstra() {
+        let mut graph = GeometricGraph::new();
+        graph.set_points(&[[0.0, 0.0], [1.0, 0.0], [2.0, 0.5], [2.0, -3.0]]);
+        graph.set_neighborhood(Neighborhood::Nearest(2));
+
+        let distances = graph.to_dijkstra(0.01).run(Some(0)).unwrap();
+
+        assert_eq!(distances, vec![0, 100, 206, 416]);
+    }
+}
@@ -0,0 +1,435 @@
+use std::collections::BinaryHeap;
+
+use crate::{dijkstra::State, BellmanFordAlgorithm, GraphAlgorithm, GraphError};
+
+/// Shortest cycle of a graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortestCycle {
+    /// Total weight of the cycle.
+    pub weight: i64,
+
+    /// Nodes of the cycle in order, the last one leading back to the first.
+    pub nodes: Vec<usize>,
+}
+
+/// Neighbors of each node with the weight and index of the edge.
+type Adjacency = Vec<Vec<(usize, usize, usize)>>;
+
+/// Girth and Shortest Cycle.
+/// Find the cycle of minimum total weight, or with the fewest edges for the girth, in a directed or
+/// undirected graph. Directed graphs with negative weights are reweighted with Bellman-Ford potentials,
+/// which keep the weight of every cycle, and a negative weight cycle is reported with its nodes.
+#[derive(Debug, Clone)]
+pub struct GirthAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Edges in the graph.
+    pub edges: Vec<(usize, usize, i32)>,
+
+    /// Whether the edges are directed.
+    pub directed: bool,
+}
+
+impl Default for GirthAlgorithm {
+    /// Create a new default instance of the girth algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the girth algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GirthAlgorithm {
+    /// Create a new instance of the girth algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the girth algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            edges: Vec::new(),
+            directed: true,
+        }
+    }
+
+    /// Set a single edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: i32) {
+        self.edges.push((source, target, weight));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, i32)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+    }
+
+    /// Set whether the edges are directed.
+    ///
+    /// # Arguments
+    ///
+    /// - `directed`: Whether the edges are directed.
+    pub fn set_directed(&mut self, directed: bool) {
+        self.directed = directed;
+    }
+
+    /// Build the graph for Bellman-Ford Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Directed graph with the same cycles.
+    fn bellman_ford(&self) -> BellmanFordAlgorithm {
+        let mut algorithm = BellmanFordAlgorithm::new();
+        algorithm.set_total_nodes(self.total_nodes);
+        algorithm.add_weighted_edges(self.edges.iter().copied());
+
+        algorithm
+    }
+
+    /// Find a negative weight cycle of a directed graph.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of a negative weight cycle, or `None` if there is none.
+    pub fn negative_cycle(&self) -> Option<Vec<usize>> {
+        self.bellman_ford().negative_cycle()
+    }
+
+    /// Find the cycle with the fewest edges.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of the cycle, whose length is the girth, or `None` if the graph is acyclic.
+    pub fn girth(&self) -> Option<Vec<usize>> {
+        self.minimum_cycle(&vec![1; self.edges.len()])
+            .map(|cycle| cycle.nodes)
+    }
+
+    /// Find the cycle of minimum total weight.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the shortest cycle or `None` if the graph is acyclic,
+    /// or an error if a negative weight cycle makes it unbounded. Undirected graphs need non-negative
+    /// weights, since a negative edge walked back and forth is already a negative closed walk.
+    pub fn shortest_cycle(&self) -> Result<Option<ShortestCycle>, GraphError> {
+        if self.edges.iter().all(|&(_, _, weight)| weight >= 0) {
+            let weights: Vec<usize> = self.edges.iter().map(|&(_, _, w)| w as usize).collect();
+            return Ok(self.minimum_cycle(&weights));
+        }
+
+        if !self.directed || self.negative_cycle().is_some() {
+            return Err(GraphError::NegativeWeightCycle);
+        }
+
+        // Potentials from a virtual source connected to every node make all weights non-negative.
+        let mut algorithm = self.bellman_ford();
+        let source = self.total_nodes;
+        algorithm.set_node(source, (0..source).map(|node| (node, 0)).collect());
+
+        let potentials = algorithm.run(Some(source as isize))?;
+        let weights: Vec<usize> = self
+            .edges
+            .iter()
+            .map(|&(u, v, w)| (w as i64 + potentials[u] as i64 - potentials[v] as i64) as usize)
+            .collect();
+
+        Ok(self.minimum_cycle(&weights))
+    }
+
+    /// Find the cycle of minimum total weight for non-negative weights.
+    ///
+    /// # Arguments
+    ///
+    /// - `weights`: Non-negative weight of each edge, changing every cycle weight by the same amount
+    ///   as the original weights.
+    ///
+    /// # Returns
+    ///
+    /// Shortest cycle, or `None` if the graph is acyclic.
+    fn minimum_cycle(&self, weights: &[usize]) -> Option<ShortestCycle> {
+        let n = self.total_nodes;
+        let mut adjacency: Adjacency = vec![Vec::new(); n];
+        let mut best: Option<(usize, Vec<usize>, Vec<usize>)> = None;
+        let mut offer = |weight: usize, (nodes, mut edges): (Vec<usize>, Vec<usize>), closing| {
+            if best.as_ref().map_or(true, |(known, _, _)| weight < *known) {
+                edges.push(closing);
+                best = Some((weight, nodes, edges));
+            }
+        };
+
+        for (index, (&(source, target, _), &weight)) in self.edges.iter().zip(weights).enumerate() {
+            adjacency[source].push((target, weight, index));
+
+            if !self.directed {
+                adjacency[target].push((source, weight, index));
+            }
+        }
+
+        if self.directed {
+            // The shortest cycle through a node closes a shortest path from it with an edge back.
+            for node in 0..n {
+                let (distances, parents) = Self::dijkstra(&adjacency, node, None);
+
+                for (last, edges) in adjacency.iter().enumerate() {
+                    for &(target, weight, index) in edges {
+                        if target == node && distances[last] != usize::MAX {
+                            offer(
+                                distances[last] + weight,
+                                Self::path(&parents, node, last),
+                                index,
+                            );
+                        }
+                    }
+                }
+            }
+        } else {
+            // The shortest cycle through an edge closes a shortest path avoiding it.
+            for (index, &(source, target, _)) in self.edges.iter().enumerate() {
+                let (distances, parents) = Self::dijkstra(&adjacency, source, Some(index));
+
+                if distances[target] != usize::MAX {
+                    offer(
+                        distances[target] + weights[index],
+                        Self::path(&parents, source, target),
+                        index,
+                    );
+                }
+            }
+        }
+
+        // Recover the original weight from the edges along the cycle.
+        let (_, nodes, edges) = best?;
+        let weight = edges.iter().map(|&index| self.edges[index].2 as i64).sum();
+
+        Some(ShortestCycle { weight, nodes })
+    }
+
+    /// Compute the shortest paths from a source with Dijkstra's Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `adjacency`: Neighbors of each node with the weight and index of the edge.
+    /// - `source`: Source node.
+    /// - `skip`: Index of an edge to ignore.
+    ///
+    /// # Returns
+    ///
+    /// Distance of each node, `usize::MAX` if unreachable, and its parent with the index of the
+    /// edge from it.
+    fn dijkstra(
+        adjacency: &Adjacency,
+        source: usize,
+        skip: Option<usize>,
+    ) -> (Vec<usize>, Vec<(usize, usize)>) {
+        let n = adjacency.len();
+        let mut distances = vec![usize::MAX; n];
+        let mut parents = vec![(usize::MAX, usize::MAX); n];
+        let mut queue = BinaryHeap::from([State {
+            cost: 0,
+            position: source,
+        }]);
+
+        distances[source] = 0;
+
+        while let Some(State { cost, position }) = queue.pop() {
+            if cost > distances[position] {
+                continue;
+            }
+
+            for &(neighbor, weight, index) in &adjacency[position] {
+                if Some(index) != skip && cost + weight < distances[neighbor] {
+                    distances[neighbor] = cost + weight;
+                    parents[neighbor] = (position, index);
+                    queue.push(State {
+                        cost: cost + weight,
+                        position: neighbor,
+                    });
+                }
+            }
+        }
+
+        (distances, parents)
+    }
+
+    /// Follow the parents from a node back to the source.
+    ///
+    /// # Arguments
+    ///
+    /// - `parents`: Parent of each node with the index of the edge from it.
+    /// - `source`: Source node.
+    /// - `node`: Last node of the path.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of the path from the source and the indices of its edges.
+    fn path(parents: &[(usize, usize)], source: usize, node: usize) -> (Vec<usize>, Vec<usize>) {
+        let mut path = vec![node];
+        let mut edges = Vec::new();
+
+        while *path.last().unwrap_or(&source) != source {
+            let (parent, index) = parents[path[path.len() - 1]];
+            path.push(parent);
+            edges.push(index);
+        }
+
+        path.reverse();
+        edges.reverse();
+        (path, edges)
+    }
+}
+
+impl GraphAlgorithm for GirthAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Option<ShortestCycle>;
+
+    /// Run the girth algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the girth algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the cycle of minimum total weight, or an error if a negative weight cycle exists.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        self.shortest_cycle()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let algorithm = GirthAlgorithm::new();
+        let algorithm_default = GirthAlgorithm::default();
+
+        assert!(algorithm.directed);
+        assert_eq!(algorithm_default.girth(), None);
+        assert_eq!(algorithm.run(None), Ok(None));
+    }
+
+    #[test]
+    fn test_run_directed() {
+        // Heavy triangle 0 - 1 - 2 and light square 2 - 3 - 4 - 5.
+        let mut algorithm = GirthAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 5)]),
+            (1, vec![(2, 5)]),
+            (2, vec![(0, 5), (3, 1)]),
+            (3, vec![(4, 1)]),
+            (4, vec![(5, 1)]),
+            (5, vec![(2, 1)]),
+        ]);
+
+        assert_eq!(algorithm.girth(), Some(vec![0, 1, 2]));
+        assert_eq!(
+            algorithm.run(None).unwrap(),
+            Some(ShortestCycle {
+                weight: 4,
+                nodes: vec![2, 3, 4, 5],
+            })
+        );
+
+        // Negative weights without a negative cycle are reweighted.
+        algorithm.set_edge(1, 0, -4);
+
+        assert_eq!(
+            algorithm.shortest_cycle().unwrap(),
+            Some(ShortestCycle {
+                weight: 1,
+                nodes: vec![0, 1],
+            })
+        );
+
+        algorithm.set_edge(0, 1, -3);
+
+        assert_eq!(algorithm.run(None), Err(GraphError::NegativeWeightCycle));
+        assert_eq!(algorithm.negative_cycle(), Some(vec![0, 1]));
+    }
+
+    #[test]
+    fn test_run_undirected() {
+        // Square 0 - 1 - 2 - 3 with a heavy diagonal 0 - 2.
+        let mut algorithm = GirthAlgorithm::new();
+        algorithm.set_directed(false);
+        algorithm.set_edges(vec![
+            (0, vec![(1, 1), (3, 1), (2, 5)]),
+            (1, vec![(2, 1)]),
+            (2, vec![(3, 1)]),
+        ]);
+
+        assert_eq!(algorithm.girth().map(|cycle| cycle.len()), Some(3));
+        assert_eq!(algorithm.shortest_cycle().unwrap().unwrap().weight, 4);
+
+        // A tree has no cycle, and a negative undirected edge is a negative closed walk.
+        let mut tree = GirthAlgorithm::new();
+        tree.set_directed(false);
+        tree.set_edges(vec![(0, vec![(1, 1), (2, 1)])]);
+
+        assert_eq!(tree.run(None), Ok(None));
+
+        tree.set_edge(2, 3, -1);
+
+        assert_eq!(tree.run(None), Err(GraphError::NegativeWeightCycle));
+    }
+
+    #[test]
+    fn test_run_parallel_edges() {
+        // Two parallel undirected edges form a cycle through both of them.
+        let mut algorithm = GirthAlgorithm::new();
+        algorithm.set_directed(false);
+        algorithm.set_edge(1, 2, 1);
+        algorithm.set_edge(2, 1, 0);
+
+        let cycle = algorithm.shortest_cycle().unwrap().unwrap();
+
+        assert_eq!(cycle.weight, 1);
+        assert_eq!(cycle.nodes.len(), 2);
+
+        // The cheapest of parallel directed edges closes the cycle.
+        let mut algorithm = GirthAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 3), (1, 1)]), (1, vec![(0, 2)])]);
+
+        assert_eq!(
+            algorithm.shortest_cycle().unwrap(),
+            Some(ShortestCycle {
+                weight: 3,
+                nodes: vec![0, 1],
+            })
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use crate::{
+    disjoint_set::DisjointSet, BetweennessAlgorithm, ConfiguredAlgorithm, GraphAlgorithm,
+    GraphError, Provenance,
+};
+
+/// Level of the Girvan-Newman dendrogram.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommunityLevel {
+    /// Edges removed since the previous level, in order of removal.
+    pub removed_edges: Vec<(usize, usize)>,
+
+    /// Communities at this level, each sorted, ordered by their smallest node.
+    pub communities: Vec<Vec<usize>>,
+
+    /// Modularity of the communities on the original graph.
+    pub modularity: f64,
+}
+
+/// Girvan-Newman Algorithm.
+/// Detect communities of an undirected graph by repeatedly removing the edges of highest betweenness,
+/// which tend to bridge communities. Every time a community splits, the dendrogram records a level.
+#[derive(Debug, Clone)]
+pub struct GirvanNewmanAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Undirected edges in the graph.
+    pub edges: Vec<(usize, usize, usize)>,
+
+    /// Whether shortest paths use the edge weights instead of the number of edges.
+    pub weighted: bool,
+}
+
+impl Default for GirvanNewmanAlgorithm {
+    /// Create a new default instance of the Girvan-Newman Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the Girvan-Newman Algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GirvanNewmanAlgorithm {
+    /// Create a new instance of the Girvan-Newman Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the Girvan-Newman Algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            edges: Vec::new(),
+            weighted: false,
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge, used as its length when weighted.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.edges.push((source, target, weight));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+    }
+
+    /// Set whether shortest paths use the edge weights.
+    ///
+    /// # Arguments
+    ///
+    /// - `weighted`: Whether edge weights are lengths for the betweenness.
+    pub fn set_weighted(&mut self, weighted: bool) {
+        self.weighted = weighted;
+    }
+
+    /// Find the connected components of the graph restricted to some edges.
+    ///
+    /// # Arguments
+    ///
+    /// - `edges`: Edges to keep.
+    ///
+    /// # Returns
+    ///
+    /// Components, each sorted, ordered by their smallest node.
+    fn components(&self, edges: &[(usize, usize, usize)]) -> Vec<Vec<usize>> {
+        let mut set = DisjointSet::new(self.total_nodes);
+        let mut components: Vec<Vec<usize>> = Vec::new();
+        let mut index = vec![usize::MAX; self.total_nodes];
+
+        for &(source, target, _) in edges {
+            set.union(source, target);
+        }
+
+        for node in 0..self.total_nodes {
+            let root = set.find(node);
+
+            if index[root] == usize::MAX {
+                index[root] = components.len();
+                components.push(Vec::new());
+            }
+
+            components[index[root]].push(node);
+        }
+
+        components
+    }
+
+    /// Compute the modularity of communities on the original graph, counting every edge once.
+    ///
+    /// # Arguments
+    ///
+    /// - `communities`: Communities partitioning the nodes.
+    ///
+    /// # Returns
+    ///
+    /// Fraction of edges inside communities minus its expected value for random edges
+    /// with the same degrees.
+    pub fn modularity(&self, communities: &[Vec<usize>]) -> f64 {
+        let total = self.edges.len() as f64;
+
+        if total == 0.0 {
+            return 0.0;
+        }
+
+        let mut community = vec![usize::MAX; self.total_nodes];
+        let mut inside = vec![0.0; communities.len()];
+        let mut degrees = vec![0.0; communities.len()];
+
+        for (index, members) in communities.iter().enumerate() {
+            members.iter().for_each(|&node| community[node] = index);
+        }
+
+        for &(source, target, _) in &self.edges {
+            degrees[community[source]] += 1.0;
+            degrees[community[target]] += 1.0;
+
+            if community[source] == community[target] {
+                inside[community[source]] += 1.0;
+            }
+        }
+
+        inside
+            .iter()
+            .zip(&degrees)
+            .map(|(inside, degree)| inside / total - (degree / (2.0 * total)).powi(2))
+            .sum()
+    }
+
+    /// Build the dendrogram by removing edges of highest betweenness until no edge is left.
+    /// Edges tied for the highest betweenness are removed together.
+    ///
+    /// # Returns
+    ///
+    /// Levels from the connected components of the whole graph down to isolated nodes.
+    pub fn dendrogram(&self) -> Vec<CommunityLevel> {
+        let mut remaining = self.edges.clone();
+        let mut communities = self.components(&remaining);
+        let mut levels = vec![CommunityLevel {
+            removed_edges: Vec::new(),
+            modularity: self.modularity(&communities),
+            communities: communities.clone(),
+        }];
+        let mut removed_edges = Vec::new();
+
+        while !remaining.is_empty() {
+            let mut betweenness = BetweennessAlgorithm::new();
+            betweenness.set_total_nodes(self.total_nodes);
+            betweenness.set_directed(false);
+            betweenness.set_weighted(self.weighted);
+            betweenness.edges = remaining.clone();
+
+            let scores = betweenness.edge_betweenness();
+            let highest = scores.iter().copied().fold(f64::MIN, f64::max);
+            let mut kept = Vec::with_capacity(remaining.len());
+
+            for (edge, score) in remaining.into_iter().zip(scores) {
+                match score >= highest - 1e-9 {
+                    true => removed_edges.push((edge.0, edge.1)),
+                    false => kept.push(edge),
+                }
+            }
+
+            remaining = kept;
+            let next = self.components(&remaining);
+
+            if next.len() > communities.len() {
+                communities = next;
+                levels.push(CommunityLevel {
+                    removed_edges: std::mem::take(&mut removed_edges),
+                    modularity: self.modularity(&communities),
+                    communities: communities.clone(),
+                });
+            }
+        }
+
+        levels
+    }
+}
+
+impl GraphAlgorithm for GirvanNewmanAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<Vec<usize>>;
+
+    /// Run the Girvan-Newman Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the Girvan-Newman Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the communities of the dendrogram level with the highest modularity,
+    /// the coarsest one on ties.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let levels = self.dendrogram();
+        let best = levels.iter().fold(&levels[0], |best, level| {
+            match level.modularity > best.modularity + 1e-12 {
+                true => level,
+                false => best,
+            }
+        });
+
+        Ok(best.communities.clone())
+    }
+}
+
+impl ConfiguredAlgorithm for GirvanNewmanAlgorithm {
+    /// Describe the configuration of the Girvan-Newman algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("girvan_newman").with("weighted", self.weighted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let algorithm = GirvanNewmanAlgorithm::new();
+        let algorithm_default = GirvanNewmanAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert!(!algorithm_default.weighted);
+        assert_eq!(algorithm.run(None), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_run() {
+        // Two triangles joined by the bridge 2 - 3.
+        let mut algorithm = GirvanNewmanAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 1), (2, 1)]),
+            (1, vec![(2, 1)]),
+            (2, vec![(3, 1)]),
+            (3, vec![(4, 1), (5, 1)]),
+            (4, vec![(5, 1)]),
+        ]);
+
+        assert_eq!(
+            algorithm.run(None).unwrap(),
+            vec![vec![0, 1, 2], vec![3, 4, 5]]
+        );
+
+        let levels = algorithm.dendrogram();
+
+        assert_eq!(levels[0].communities, vec![vec![0, 1, 2, 3, 4, 5]]);
+        assert_eq!(levels[1].removed_edges, vec![(2, 3)]);
+        assert!((levels[1].modularity - 5.0 / 14.0).abs() < 1e-9);
+        assert_eq!(levels.last().unwrap().communities.len(), 6);
+    }
+
+    #[test]
+    fn test_dendrogram_weighted() {
+        // A square whose long edge 0 - 3 carries no shortest path, so 1 - 2 goes first.
+        let mut algorithm = GirvanNewmanAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 1), (3, 5)]),
+            (1, vec![(2, 1)]),
+            (2, vec![(3, 1)]),
+        ]);
+        algorithm.set_weighted(true);
+
+        let levels = algorithm.dendrogram();
+
+        assert_eq!(levels[1].removed_edges, vec![(1, 2), (0, 3)]);
+        assert_eq!(levels[1].communities, vec![vec![0, 1], vec![2, 3]]);
+    }
+}
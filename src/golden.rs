@@ -0,0 +1,240 @@
+use std::{
+    fmt::Debug,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Header line of a fixture, recording the crate version that wrote it.
+const HEADER: &str = concat!("# graph-algorithms-rs ", env!("CARGO_PKG_VERSION"));
+
+/// Outcome of checking a value against its fixture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GoldenOutcome {
+    /// Fixture did not exist or updates were requested, the value was written.
+    Recorded,
+
+    /// Value equals the recorded one.
+    Matched,
+}
+
+/// Error checking a value against its fixture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GoldenError {
+    /// Fixture name is empty or not a plain file name.
+    InvalidName(String),
+
+    /// Fixture could not be read or written.
+    Io(String),
+
+    /// Value differs from the recorded one.
+    Mismatch {
+        /// Name of the fixture.
+        name: String,
+
+        /// Header of the fixture, naming the crate version that recorded it.
+        recorded_by: String,
+
+        /// First differing line, 1-based.
+        line: usize,
+
+        /// Recorded value.
+        expected: String,
+
+        /// Current value.
+        actual: String,
+    },
+}
+
+/// Golden Fixtures.
+/// Record algorithm outputs on fixed graphs and seeds to disk, then assert that later runs produce
+/// the same outputs, catching behavioral drift between crate versions. Values are stored in their
+/// pretty `Debug` representation, so they should not contain hash maps or other unordered collections.
+/// Requires the optional `golden` feature.
+#[derive(Debug, Clone)]
+pub struct GoldenFixtures {
+    /// Directory holding the fixtures.
+    pub directory: PathBuf,
+
+    /// Whether to overwrite existing fixtures instead of comparing with them.
+    pub update: bool,
+}
+
+impl GoldenFixtures {
+    /// Create a new set of fixtures in a directory. Updates are enabled by setting the
+    /// `GRAPH_ALGORITHMS_UPDATE_GOLDEN` environment variable to `1`.
+    ///
+    /// # Arguments
+    ///
+    /// - `directory`: Directory holding the fixtures.
+    ///
+    /// # Returns
+    ///
+    /// New set of fixtures.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            update: std::env::var("GRAPH_ALGORITHMS_UPDATE_GOLDEN").is_ok_and(|value| value == "1"),
+        }
+    }
+
+    /// Set whether to overwrite existing fixtures.
+    ///
+    /// # Arguments
+    ///
+    /// - `update`: `true` to record the current values.
+    pub fn set_update(&mut self, update: bool) {
+        self.update = update;
+    }
+
+    /// Get the location of a fixture.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: Name of the fixture.
+    ///
+    /// # Returns
+    ///
+    /// Path of the fixture file.
+    pub fn path(&self, name: &str) -> PathBuf {
+        self.directory.join(format!("{name}.golden"))
+    }
+
+    /// Check a value against its fixture, recording it if the fixture does not exist yet.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: Name of the fixture, a plain file name.
+    /// - `value`: Value to check, such as the result of an algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing whether the value was recorded or matched, or an error if it differs.
+    pub fn check<T: Debug>(&self, name: &str, value: &T) -> Result<GoldenOutcome, GoldenError> {
+        if name.is_empty() || Path::new(name).file_name() != Some(name.as_ref()) {
+            return Err(GoldenError::InvalidName(name.to_string()));
+        }
+
+        let path = self.path(name);
+        let actual = format!("{value:#?}");
+
+        if self.update || !path.is_file() {
+            fs::create_dir_all(&self.directory)
+                .and_then(|_| fs::write(&path, format!("{HEADER}\n{actual}\n")))
+                .map_err(|error| GoldenError::Io(error.to_string()))?;
+
+            return Ok(GoldenOutcome::Recorded);
+        }
+
+        let contents =
+            fs::read_to_string(&path).map_err(|error| GoldenError::Io(error.to_string()))?;
+        let (recorded_by, expected) = contents.split_once('\n').unwrap_or(("", &contents));
+        let expected = expected.trim_end_matches('\n');
+
+        if expected == actual {
+            return Ok(GoldenOutcome::Matched);
+        }
+
+        let line = expected
+            .lines()
+            .zip(actual.lines())
+            .position(|(expected, actual)| expected != actual)
+            .unwrap_or_else(|| expected.lines().count().min(actual.lines().count()));
+
+        Err(GoldenError::Mismatch {
+            name: name.to_string(),
+            recorded_by: recorded_by.to_string(),
+            line: line + 1,
+            expected: expected.to_string(),
+            actual,
+        })
+    }
+
+    /// Assert that a value matches its fixture, for use in regression tests.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: Name of the fixture, a plain file name.
+    /// - `value`: Value to check, such as the result of an algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the first differing line if the value differs or the fixture cannot be used.
+    pub fn assert<T: Debug>(&self, name: &str, value: &T) {
+        match self.check(name, value) {
+            Ok(_) => {}
+            Err(GoldenError::Mismatch {
+                name,
+                recorded_by,
+                line,
+                expected,
+                actual,
+            }) => panic!(
+                "golden fixture `{name}` ({recorded_by}) differs at line {line}:\n- {}\n+ {}",
+                expected.lines().nth(line - 1).unwrap_or(""),
+                actual.lines().nth(line - 1).unwrap_or(""),
+            ),
+            Err(error) => panic!("golden fixture `{name}` cannot be checked: {error:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let fixtures = GoldenFixtures::new("fixtures");
+
+        assert_eq!(fixtures.directory, PathBuf::from("fixtures"));
+        assert_eq!(
+            fixtures.path("dijkstra"),
+            PathBuf::from("fixtures/dijkstra.golden")
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let directory = std::env::temp_dir().join(format!("graph-golden-{}", std::process::id()));
+        let mut fixtures = GoldenFixtures::new(&directory);
+        fixtures.set_update(false);
+
+        let distances: Vec<usize> = vec![0, 2, 3];
+
+        assert_eq!(
+            fixtures.check("dijkstra", &distances),
+            Ok(GoldenOutcome::Recorded)
+        );
+        assert_eq!(
+            fixtures.check("dijkstra", &distances),
+            Ok(GoldenOutcome::Matched)
+        );
+        fixtures.assert("dijkstra", &distances);
+
+        // A drift in the results is reported at its first differing line.
+        let drifted = vec![0, 2, 4];
+
+        match fixtures.check("dijkstra", &drifted) {
+            Err(GoldenError::Mismatch {
+                recorded_by, line, ..
+            }) => {
+                assert_eq!(recorded_by, HEADER);
+                assert_eq!(line, 4);
+            }
+            outcome => panic!("unexpected outcome {outcome:?}"),
+        }
+
+        fixtures.set_update(true);
+
+        assert_eq!(
+            fixtures.check("dijkstra", &drifted),
+            Ok(GoldenOutcome::Recorded)
+        );
+        assert_eq!(
+            fixtures.check("../dijkstra", &drifted),
+            Err(GoldenError::InvalidName("../dijkstra".to_string()))
+        );
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,318 @@
+use std::ops::Deref;
+
+use crate::Weight;
+
+/// Directed graph with dense node indices and weights of any type, built once and passed by
+/// reference to the `run_on` functions of Dijkstra's, Bellman-Ford and Floyd-Warshall algorithms
+/// instead of copying it into the storage of each of them. The minimum spanning tree, maximum flow
+/// and centrality algorithms are created from it with `From`, which copies its edges into their own
+/// storage.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiGraph<W = usize> {
+    /// Outgoing edges of each node as `(target, weight)`.
+    pub adjacency: Vec<Vec<(usize, W)>>,
+}
+
+impl<W> Default for DiGraph<W> {
+    /// Create a new default directed graph.
+    ///
+    /// # Returns
+    ///
+    /// New default directed graph.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W> DiGraph<W> {
+    /// Create a new empty directed graph.
+    ///
+    /// # Returns
+    ///
+    /// New directed graph.
+    pub fn new() -> Self {
+        Self {
+            adjacency: Vec::new(),
+        }
+    }
+
+    /// Add a single directed edge, adding its nodes if needed.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn add_edge(&mut self, source: usize, target: usize, weight: W) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].push((target, weight));
+    }
+
+    /// Add directed edges, adding their nodes if needed.
+    ///
+    /// # Arguments
+    ///
+    /// - `edges`: Edges as `(source, target, weight)`.
+    pub fn add_weighted_edges(&mut self, edges: impl IntoIterator<Item = (usize, usize, W)>) {
+        for (source, target, weight) in edges {
+            self.add_edge(source, target, weight);
+        }
+    }
+
+    /// Set the total number of nodes in the graph, adding the missing nodes without edges.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        if self.adjacency.len() < total {
+            self.adjacency.resize_with(total, Vec::new);
+        }
+    }
+
+    /// Get the total number of nodes.
+    ///
+    /// # Returns
+    ///
+    /// Number of nodes.
+    pub fn total_nodes(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Get the total number of directed edges.
+    ///
+    /// # Returns
+    ///
+    /// Number of edges.
+    pub fn total_edges(&self) -> usize {
+        self.adjacency.iter().map(Vec::len).sum()
+    }
+
+    /// Get the outgoing edges of a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node.
+    ///
+    /// # Returns
+    ///
+    /// Edges as `(target, weight)`, empty for nodes out of range.
+    pub fn neighbors(&self, node: usize) -> &[(usize, W)] {
+        self.adjacency.get(node).map_or(&[], Vec::as_slice)
+    }
+}
+
+impl<W: Copy> DiGraph<W> {
+    /// Iterate over the edges by source node.
+    ///
+    /// # Returns
+    ///
+    /// Iterator over the edges as `(source, target, weight)`.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, W)> + '_ {
+        self.adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(source, edges)| {
+                edges
+                    .iter()
+                    .map(move |&(target, weight)| (source, target, weight))
+            })
+    }
+}
+
+/// Undirected graph, stored as a directed graph with both directions of every edge so that it can
+/// be passed wherever a `DiGraph` is accepted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Graph<W = usize> {
+    /// Both directions of every edge, a self-loop only once.
+    pub arcs: DiGraph<W>,
+}
+
+impl<W> Default for Graph<W> {
+    /// Create a new default undirected graph.
+    ///
+    /// # Returns
+    ///
+    /// New default undirected graph.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W> Graph<W> {
+    /// Create a new empty undirected graph.
+    ///
+    /// # Returns
+    ///
+    /// New undirected graph.
+    pub fn new() -> Self {
+        Self {
+            arcs: DiGraph::new(),
+        }
+    }
+
+    /// Set the total number of nodes in the graph, adding the missing nodes without edges.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.arcs.set_total_nodes(total);
+    }
+}
+
+impl<W: Copy> Graph<W> {
+    /// Add a single undirected edge, adding its nodes if needed.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: One end of the edge.
+    /// - `target`: Other end of the edge.
+    /// - `weight`: Weight of the edge.
+    pub fn add_edge(&mut self, source: usize, target: usize, weight: W) {
+        self.arcs.add_edge(source, target, weight);
+
+        if source != target {
+            self.arcs.add_edge(target, source, weight);
+        }
+    }
+
+    /// Add undirected edges, adding their nodes if needed.
+    ///
+    /// # Arguments
+    ///
+    /// - `edges`: Edges as `(source, target, weight)`.
+    pub fn add_weighted_edges(&mut self, edges: impl IntoIterator<Item = (usize, usize, W)>) {
+        for (source, target, weight) in edges {
+            self.add_edge(source, target, weight);
+        }
+    }
+}
+
+impl<W> Deref for Graph<W> {
+    /// Type of the directed graph.
+    type Target = DiGraph<W>;
+
+    /// Borrow the undirected graph as a directed graph with both directions of every edge.
+    ///
+    /// # Returns
+    ///
+    /// Directed graph.
+    fn deref(&self) -> &Self::Target {
+        &self.arcs
+    }
+}
+
+/// Shortest paths from a single source with the predecessor of each node on its path, as returned
+/// by Dijkstra's and the Bellman-Ford algorithms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortestPaths<W = usize> {
+    /// Source of the run.
+    pub start: usize,
+
+    /// Distance of each node, `Weight::INFINITY` if unreachable.
+    pub distances: Vec<W>,
+
+    /// Predecessor of each node on its shortest path, `usize::MAX` for the start and unreachable
+    /// nodes.
+    pub predecessors: Vec<usize>,
+}
+
+impl<W: Weight> ShortestPaths<W> {
+    /// Get the distance of a node from the start.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node.
+    ///
+    /// # Returns
+    ///
+    /// Distance, or `None` if the node is unreachable or out of range.
+    pub fn distance(&self, node: usize) -> Option<W> {
+        self.distances
+            .get(node)
+            .copied()
+            .filter(|&distance| distance != W::INFINITY)
+    }
+
+    /// Reconstruct the shortest path to a node by following the predecessors back to the start.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of the path from the start to the node, or `None` if the node is unreachable or out
+    /// of range.
+    pub fn path_to(&self, node: usize) -> Option<Vec<usize>> {
+        self.distance(node)?;
+
+        let mut path = vec![node];
+        let mut current = node;
+
+        while current != self.start {
+            current = *self.predecessors.get(current)?;
+
+            // Without negative weight cycles the predecessors form a tree rooted at the start.
+            if current == usize::MAX || path.len() == self.distances.len() {
+                return None;
+            }
+
+            path.push(current);
+        }
+
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digraph() {
+        let mut graph = DiGraph::new();
+        graph.add_weighted_edges([(0, 2, 4), (2, 1, 1)]);
+        graph.set_total_nodes(4);
+
+        assert_eq!(graph.total_nodes(), 4);
+        assert_eq!(graph.total_edges(), 2);
+        assert_eq!(graph.neighbors(2), &[(1, 1)]);
+        assert!(graph.neighbors(9).is_empty());
+        assert_eq!(
+            graph.edges().collect::<Vec<_>>(),
+            vec![(0, 2, 4), (2, 1, 1)]
+        );
+        assert_eq!(DiGraph::<f64>::default().total_nodes(), 0);
+    }
+
+    #[test]
+    fn test_graph() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edges([(0, 1, 2.5), (1, 1, 1.0)]);
+
+        assert_eq!(graph.total_nodes(), 2);
+        assert_eq!(graph.total_edges(), 3);
+        assert_eq!(graph.neighbors(1), &[(0, 2.5), (1, 1.0)]);
+        assert_eq!(Graph::<i32>::default(), Graph::new());
+    }
+
+    #[test]
+    fn test_shortest_paths() {
+        let paths = ShortestPaths {
+            start: 1,
+            distances: vec![3, 0, usize::MAX, 5],
+            predecessors: vec![1, usize::MAX, usize::MAX, 0],
+        };
+
+        assert_eq!(paths.distance(3), Some(5));
+        assert_eq!(paths.distance(2), None);
+        assert_eq!(paths.path_to(3), Some(vec![1, 0, 3]));
+        assert_eq!(paths.path_to(1), Some(vec![1]));
+        assert_eq!(paths.path_to(2), None);
+        assert_eq!(paths.path_to(9), None);
+    }
+}
@@ -0,0 +1,472 @@
+use crate::{DijkstraAlgorithm, GraphAlgorithm, GraphError};
+
+/// Moves allowed between the cells of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Connectivity {
+    /// Moves to the 4 orthogonal neighbors.
+    #[default]
+    Four,
+
+    /// Moves to the 4 orthogonal and the 4 diagonal neighbors.
+    Eight,
+}
+
+/// Grid Graph.
+/// Build the graph of a 2D grid of walkable and blocked cells, as used for pathfinding in games.
+/// Every walkable cell is a node numbered row by row, `row * width + column`, and a move costs the
+/// step cost of its direction times the cost of the entered cell.
+#[derive(Debug, Clone)]
+pub struct GridGraph {
+    /// Number of columns.
+    pub width: usize,
+
+    /// Number of rows.
+    pub height: usize,
+
+    /// Cost of entering each cell, row by row, or `None` if the cell is blocked.
+    pub costs: Vec<Option<usize>>,
+
+    /// Moves allowed between cells.
+    pub connectivity: Connectivity,
+
+    /// Cost of an orthogonal step.
+    pub orthogonal_step: usize,
+
+    /// Cost of a diagonal step, e.g. 14 with an orthogonal step of 10 to approximate `√2`.
+    pub diagonal_step: usize,
+
+    /// Whether a diagonal move may pass a blocked orthogonal neighbor.
+    pub corner_cutting: bool,
+}
+
+impl Default for GridGraph {
+    /// Create a new default instance of the grid graph.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the grid graph.
+    fn default() -> Self {
+        Self::new(0, 0)
+    }
+}
+
+impl GridGraph {
+    /// Create a new instance of the grid graph with every cell walkable at cost 1.
+    ///
+    /// # Arguments
+    ///
+    /// - `width`: Number of columns.
+    /// - `height`: Number of rows.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the grid graph.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            costs: vec![Some(1); width * height],
+            connectivity: Connectivity::Four,
+            orthogonal_step: 1,
+            diagonal_step: 1,
+            corner_cutting: false,
+        }
+    }
+
+    /// Create a grid graph from rows of walkable (`true`) and blocked (`false`) cells.
+    ///
+    /// # Arguments
+    ///
+    /// - `rows`: Rows of cells. Cells missing from shorter rows are blocked.
+    ///
+    /// # Returns
+    ///
+    /// Grid graph with the walkable cells at cost 1.
+    pub fn from_walkable(rows: &[Vec<bool>]) -> Self {
+        let rows: Vec<Vec<Option<usize>>> = rows
+            .iter()
+            .map(|row| row.iter().map(|&walkable| walkable.then_some(1)).collect())
+            .collect();
+
+        Self::from_costs(&rows)
+    }
+
+    /// Create a grid graph from rows of cell costs.
+    ///
+    /// # Arguments
+    ///
+    /// - `rows`: Rows of costs of entering each cell, `None` if blocked. Cells missing from shorter
+    ///   rows are blocked.
+    ///
+    /// # Returns
+    ///
+    /// Grid graph with the given costs.
+    pub fn from_costs(rows: &[Vec<Option<usize>>]) -> Self {
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut grid = Self::new(width, rows.len());
+
+        for (row, cells) in rows.iter().enumerate() {
+            for column in 0..width {
+                grid.costs[row * width + column] = cells.get(column).copied().flatten();
+            }
+        }
+
+        grid
+    }
+
+    /// Create a grid graph from a text map, one line per row.
+    /// `#` is a blocked cell, a digit from `1` to `9` a cell of that cost, and any other character a
+    /// walkable cell at cost 1.
+    ///
+    /// # Arguments
+    ///
+    /// - `map`: Text map.
+    ///
+    /// # Returns
+    ///
+    /// Grid graph of the map.
+    pub fn from_ascii(map: &str) -> Self {
+        let rows: Vec<Vec<Option<usize>>> = map
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|cell| match cell {
+                        '#' => None,
+                        '1'..='9' => cell.to_digit(10).map(|cost| cost as usize),
+                        _ => Some(1),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self::from_costs(&rows)
+    }
+
+    /// Set the moves allowed between cells.
+    ///
+    /// # Arguments
+    ///
+    /// - `connectivity`: 4- or 8-connected moves.
+    pub fn set_connectivity(&mut self, connectivity: Connectivity) {
+        self.connectivity = connectivity;
+    }
+
+    /// Set the cost of orthogonal and diagonal steps.
+    ///
+    /// # Arguments
+    ///
+    /// - `orthogonal`: Cost of an orthogonal step.
+    /// - `diagonal`: Cost of a diagonal step.
+    pub fn set_step_costs(&mut self, orthogonal: usize, diagonal: usize) {
+        self.orthogonal_step = orthogonal;
+        self.diagonal_step = diagonal;
+    }
+
+    /// Set whether a diagonal move may pass a blocked orthogonal neighbor.
+    ///
+    /// # Arguments
+    ///
+    /// - `allowed`: Whether corners may be cut.
+    pub fn set_corner_cutting(&mut self, allowed: bool) {
+        self.corner_cutting = allowed;
+    }
+
+    /// Set the cost of a cell.
+    ///
+    /// # Arguments
+    ///
+    /// - `row`: Row of the cell.
+    /// - `column`: Column of the cell.
+    /// - `cost`: Cost of entering the cell, or `None` to block it.
+    pub fn set_cost(&mut self, row: usize, column: usize, cost: Option<usize>) {
+        if row < self.height && column < self.width {
+            self.costs[row * self.width + column] = cost;
+        }
+    }
+
+    /// Get the node of a cell.
+    ///
+    /// # Arguments
+    ///
+    /// - `row`: Row of the cell.
+    /// - `column`: Column of the cell.
+    ///
+    /// # Returns
+    ///
+    /// Node of the cell, or `None` if the cell is outside the grid or blocked.
+    pub fn node(&self, row: usize, column: usize) -> Option<usize> {
+        let node = row * self.width + column;
+
+        (row < self.height && column < self.width && self.costs[node].is_some()).then_some(node)
+    }
+
+    /// Get the cell of a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node of the cell.
+    ///
+    /// # Returns
+    ///
+    /// Cell as `(row, column)`.
+    pub fn cell(&self, node: usize) -> (usize, usize) {
+        (node / self.width, node % self.width)
+    }
+
+    /// Translate a path of nodes into cells.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: Nodes of the path.
+    ///
+    /// # Returns
+    ///
+    /// Cells of the path as `(row, column)`.
+    pub fn cells(&self, path: &[usize]) -> Vec<(usize, usize)> {
+        path.iter().map(|&node| self.cell(node)).collect()
+    }
+
+    /// Get the moves from a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node of the cell.
+    ///
+    /// # Returns
+    ///
+    /// Reachable neighbors as `(node, cost)`, empty if the cell is blocked.
+    pub fn neighbors(&self, node: usize) -> Vec<(usize, usize)> {
+        if node >= self.costs.len() || self.costs[node].is_none() {
+            return Vec::new();
+        }
+
+        let (row, column) = self.cell(node);
+        let offset = |dr: isize, dc: isize| {
+            let row = row.checked_add_signed(dr)?;
+            let column = column.checked_add_signed(dc)?;
+
+            (column < self.width).then(|| self.node(row, column))?
+        };
+
+        let mut neighbors = Vec::new();
+
+        for (dr, dc) in [(-1, 0), (0, -1), (0, 1), (1, 0)] {
+            if let Some(target) = offset(dr, dc) {
+                neighbors.push((
+                    target,
+                    self.orthogonal_step * self.costs[target].unwrap_or(0),
+                ));
+            }
+        }
+
+        if self.connectivity == Connectivity::Eight {
+            for (dr, dc) in [(-1, -1), (-1, 1), (1, -1), (1, 1)] {
+                let Some(target) = offset(dr, dc) else {
+                    continue;
+                };
+
+                if self.corner_cutting || (offset(dr, 0).is_some() && offset(0, dc).is_some()) {
+                    neighbors.push((target, self.diagonal_step * self.costs[target].unwrap_or(0)));
+                }
+            }
+        }
+
+        neighbors
+    }
+
+    /// Build the adjacency lists of the walkable cells.
+    ///
+    /// # Returns
+    ///
+    /// Nodes with their edges, ready for the `set_nodes` of the shortest path algorithms.
+    pub fn nodes(&self) -> Vec<(usize, Vec<(usize, usize)>)> {
+        (0..self.costs.len())
+            .filter(|&node| self.costs[node].is_some())
+            .map(|node| (node, self.neighbors(node)))
+            .collect()
+    }
+
+    /// Build a graph for Dijkstra's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Graph of the walkable cells.
+    pub fn to_dijkstra(&self) -> DijkstraAlgorithm {
+        let mut graph = DijkstraAlgorithm::new();
+        graph.set_nodes(self.nodes());
+        graph
+    }
+
+    /// Estimate the cost between two nodes without obstacles.
+    /// The Manhattan distance for 4-connected grids, or the octile distance for 8-connected grids,
+    /// times the cheapest cell cost. It never overestimates, as required by A* search.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Lower bound of the cost from the source to the target.
+    pub fn heuristic(&self, source: usize, target: usize) -> usize {
+        let (sr, sc) = self.cell(source);
+        let (tr, tc) = self.cell(target);
+        let (rows, columns) = (sr.abs_diff(tr), sc.abs_diff(tc));
+        let cheapest = self.costs.iter().flatten().min().copied().unwrap_or(0);
+
+        let steps = match self.connectivity {
+            Connectivity::Four => (rows + columns) * self.orthogonal_step,
+            Connectivity::Eight => {
+                let diagonal = rows.min(columns);
+                let straight = rows.max(columns) - diagonal;
+
+                diagonal * self.diagonal_step.min(2 * self.orthogonal_step)
+                    + straight * self.orthogonal_step
+            }
+        };
+
+        steps * cheapest
+    }
+
+    /// Find a cheapest path between two cells.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source cell as `(row, column)`.
+    /// - `target`: Target cell as `(row, column)`.
+    ///
+    /// # Returns
+    ///
+    /// Cost and cells of the path, or `None` if a cell is blocked or unreachable.
+    pub fn shortest_path(
+        &self,
+        source: (usize, usize),
+        target: (usize, usize),
+    ) -> Option<(usize, Vec<(usize, usize)>)> {
+        let source = self.node(source.0, source.1)?;
+        let target = self.node(target.0, target.1)?;
+        let (cost, path) = self.to_dijkstra().shortest_path(source, target)?;
+
+        Some((cost, self.cells(&path)))
+    }
+}
+
+impl GraphAlgorithm for GridGraph {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<usize>;
+
+    /// Run Dijkstra's Algorithm over the grid.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the cost to every node, `usize::MAX` for blocked or unreachable cells, or
+    /// an error if the start is missing or blocked.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let start = start.ok_or(GraphError::MissingStartNode)?;
+
+        if start >= self.costs.len() || self.costs[start].is_none() {
+            return Err(GraphError::MissingStartNode);
+        }
+
+        let mut graph = DijkstraAlgorithm::new();
+        graph.set_nodes(
+            (0..self.costs.len())
+                .map(|node| (node, self.neighbors(node)))
+                .collect(),
+        );
+        graph.run(Some(start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let grid = GridGraph::new(3, 2);
+        let grid_default = GridGraph::default();
+
+        assert_eq!(grid.costs, vec![Some(1); 6]);
+        assert_eq!(grid.connectivity, Connectivity::Four);
+        assert!(grid_default.costs.is_empty());
+    }
+
+    #[test]
+    fn test_from_walkable() {
+        let grid = GridGraph::from_walkable(&[vec![true, false, true], vec![true, true]]);
+
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid.node(0, 0), Some(0));
+        assert_eq!(grid.node(0, 1), None);
+        assert_eq!(grid.node(1, 2), None);
+        assert_eq!(grid.node(2, 0), None);
+        assert_eq!(grid.cell(4), (1, 1));
+        assert_eq!(grid.neighbors(0), vec![(3, 1)]);
+        assert_eq!(grid.neighbors(1), vec![]);
+    }
+
+    #[test]
+    fn test_from_ascii() {
+        let grid = GridGraph::from_ascii("..#\n.5.");
+
+        assert_eq!(
+            grid.costs,
+            vec![Some(1), Some(1), None, Some(1), Some(5), Some(1)]
+        );
+        assert_eq!(grid.neighbors(3), vec![(0, 1), (4, 5)]);
+    }
+
+    #[test]
+    fn test_neighbors_eight() {
+        let mut grid = GridGraph::from_ascii("...\n.#.\n...");
+        grid.set_connectivity(Connectivity::Eight);
+        grid.set_step_costs(10, 14);
+
+        assert_eq!(grid.neighbors(0), vec![(1, 10), (3, 10)]);
+        assert_eq!(grid.neighbors(1), vec![(0, 10), (2, 10)]);
+
+        grid.set_corner_cutting(true);
+        grid.set_cost(0, 0, Some(3));
+
+        assert_eq!(grid.neighbors(4), vec![]);
+        assert_eq!(grid.neighbors(3), vec![(0, 30), (6, 10), (1, 14), (7, 14)]);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let grid = GridGraph::from_ascii("....\n.##.\n.9..\n.2..");
+
+        let (cost, path) = grid.shortest_path((0, 0), (2, 2)).unwrap();
+        assert_eq!(cost, 6);
+        assert_eq!(
+            path,
+            vec![(0, 0), (0, 1), (0, 2), (0, 3), (1, 3), (2, 3), (2, 2)]
+        );
+        assert_eq!(grid.heuristic(0, 10), 4);
+
+        assert!(grid.shortest_path((0, 0), (1, 1)).is_none());
+    }
+
+    #[test]
+    fn test_run() {
+        let mut grid = GridGraph::from_ascii("..\n#.");
+        grid.set_connectivity(Connectivity::Eight);
+
+        assert_eq!(grid.run(Some(0)).unwrap(), vec![0, 1, usize::MAX, 2]);
+        assert_eq!(grid.run(Some(2)), Err(GraphError::MissingStartNode));
+        assert_eq!(grid.run(None), Err(GraphError::MissingStartNode));
+
+        grid.set_corner_cutting(true);
+
+        assert_eq!(grid.run(Some(0)).unwrap()[3], 1);
+    }
+}
@@ -0,0 +1,387 @@
+use crate::{GraphAlgorithm, GraphError, MemoryEstimate, MemoryEstimation};
+
+/// Held-Karp Algorithm.
+/// Find an optimal travelling salesman tour or Hamiltonian path by dynamic programming over node subsets.
+/// Time and memory grow as `2^n`, so it suits small instances (up to about 20 nodes), and graphs
+/// above `MAX_NODES` are refused.
+#[derive(Debug, Clone)]
+pub struct HeldKarpAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Cost of each directed edge, `usize::MAX` if there is no edge.
+    pub costs: Vec<Vec<usize>>,
+}
+
+impl Default for HeldKarpAlgorithm {
+    /// Create a new default instance of the Held-Karp Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the Held-Karp Algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HeldKarpAlgorithm {
+    /// Largest number of nodes solved, whose tables already take gigabytes.
+    pub const MAX_NODES: usize = 24;
+
+    /// Create a new instance of the Held-Karp Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the Held-Karp Algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            costs: Vec::new(),
+        }
+    }
+
+    /// Set a single directed edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.costs[source][target] = self.costs[source][target].min(weight);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the full cost matrix, e.g. pairwise distances or the result of Floyd-Warshall Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `costs`: Cost between each pair of nodes, `usize::MAX` if there is no edge.
+    pub fn set_costs(&mut self, costs: Vec<Vec<usize>>) {
+        self.total_nodes = costs.len();
+        self.costs = costs;
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.costs.resize(self.total_nodes, Vec::new());
+
+        for row in &mut self.costs {
+            row.resize(self.total_nodes, usize::MAX);
+        }
+    }
+
+    /// Solve the dynamic program.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Node the walk starts from, or `None` for any node.
+    /// - `cycle`: Whether the walk must return to its start.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the optimal cost and node sequence, or `None` if no Hamiltonian cycle or
+    /// path exists, or an error if the graph has more than `MAX_NODES` nodes.
+    fn solve(
+        &self,
+        start: Option<usize>,
+        cycle: bool,
+    ) -> Result<Option<(usize, Vec<usize>)>, GraphError> {
+        let n = self.total_nodes;
+
+        if n > Self::MAX_NODES {
+            return Err(GraphError::TooManyNodes(Self::MAX_NODES));
+        }
+
+        if n == 0 || start.is_some_and(|start| start >= n) {
+            return Ok(None);
+        }
+
+        Ok(self.tables(n, start, cycle))
+    }
+
+    /// Fill the tables of the dynamic program and follow the parents of the best walk.
+    ///
+    /// # Arguments
+    ///
+    /// - `n`: Number of nodes, at least one and at most `MAX_NODES`.
+    /// - `start`: Node the walk starts from, or `None` for any node.
+    /// - `cycle`: Whether the walk must return to its start.
+    ///
+    /// # Returns
+    ///
+    /// Optimal cost and node sequence, or `None` if no Hamiltonian cycle or path exists.
+    fn tables(&self, n: usize, start: Option<usize>, cycle: bool) -> Option<(usize, Vec<usize>)> {
+        let full = (1usize << n) - 1;
+
+        // best[mask][node]: cheapest walk visiting the nodes of `mask` and ending at `node`.
+        let mut best = vec![vec![usize::MAX; n]; full + 1];
+        let mut parent = vec![vec![usize::MAX; n]; full + 1];
+
+        for node in 0..n {
+            if start.map_or(true, |start| start == node) {
+                best[1 << node][node] = 0;
+            }
+        }
+
+        for mask in 1..=full {
+            for last in 0..n {
+                let cost = best[mask][last];
+
+                if cost == usize::MAX {
+                    continue;
+                }
+
+                for next in (0..n).filter(|&next| mask & (1 << next) == 0) {
+                    let Some(total) = cost.checked_add(self.costs[last][next]) else {
+                        continue;
+                    };
+
+                    let extended = mask | (1 << next);
+
+                    if total < best[extended][next] {
+                        best[extended][next] = total;
+                        parent[extended][next] = last;
+                    }
+                }
+            }
+        }
+
+        let closing = |last: usize| match cycle {
+            true => start
+                .map_or(Some(0), |start| self.costs[last][start].checked_add(0))
+                .filter(|&cost| cost != usize::MAX),
+            false => Some(0),
+        };
+
+        let (cost, mut last) = (0..n)
+            .filter(|&last| best[full][last] != usize::MAX)
+            .filter_map(|last| Some((best[full][last].checked_add(closing(last)?)?, last)))
+            .min()?;
+
+        let mut walk = Vec::with_capacity(n + 1);
+        let mut mask = full;
+
+        while last != usize::MAX {
+            walk.push(last);
+            let previous = parent[mask][last];
+            mask &= !(1 << last);
+            last = previous;
+        }
+
+        walk.reverse();
+
+        if cycle {
+            walk.push(walk[0]);
+        }
+
+        Some((cost, walk))
+    }
+
+    /// Find an optimal Hamiltonian path.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Node the path must start from, or `None` for any node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the optimal cost and nodes of the path, or `None` if no Hamiltonian path
+    /// exists, or an error if the graph has more than `MAX_NODES` nodes.
+    pub fn hamiltonian_path(
+        &self,
+        start: Option<usize>,
+    ) -> Result<Option<(usize, Vec<usize>)>, GraphError> {
+        self.solve(start, false)
+    }
+}
+
+impl GraphAlgorithm for HeldKarpAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = (usize, Vec<usize>);
+
+    /// Run the Held-Karp Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node of the tour, node 0 if omitted.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the optimal tour cost and its nodes, starting and ending at the start node,
+    /// or an error if no Hamiltonian cycle exists or the graph has more than `MAX_NODES` nodes.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        self.solve(Some(start.unwrap_or(0)), true)?
+            .ok_or(GraphError::Infeasible)
+    }
+}
+
+impl MemoryEstimation for HeldKarpAlgorithm {
+    /// Estimate the memory of the Held-Karp Algorithm, whose cost and parent tables hold a row of
+    /// V entries for each of the 2^V subsets of nodes.
+    ///
+    /// # Returns
+    ///
+    /// Memory estimate of a run.
+    fn estimate_memory(&self) -> MemoryEstimate {
+        let n = self.total_nodes;
+        let subsets = match n < usize::BITS as usize {
+            true => 1usize << n,
+            false => usize::MAX,
+        };
+        let table = MemoryEstimate::bytes::<Vec<usize>>(subsets)
+            .saturating_add(MemoryEstimate::bytes::<usize>(subsets.saturating_mul(n)));
+
+        MemoryEstimate::new(
+            MemoryEstimate::bytes::<Vec<usize>>(n)
+                .saturating_add(MemoryEstimate::bytes::<usize>(n.saturating_mul(n))),
+            table.saturating_mul(2),
+            MemoryEstimate::bytes::<usize>(n + 1),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symmetric(costs: &[&[usize]]) -> HeldKarpAlgorithm {
+        let mut algorithm = HeldKarpAlgorithm::new();
+        algorithm.set_costs(costs.iter().map(|row| row.to_vec()).collect());
+        algorithm
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = HeldKarpAlgorithm::new();
+        let algorithm_default = HeldKarpAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.costs.len(), 0);
+    }
+
+    #[test]
+    fn test_run() {
+        let algorithm = symmetric(&[
+            &[0, 10, 15, 20],
+            &[10, 0, 35, 25],
+            &[15, 35, 0, 30],
+            &[20, 25, 30, 0],
+        ]);
+
+        assert_eq!(algorithm.run(None).unwrap(), (80, vec![0, 2, 3, 1, 0]));
+
+        let (cost, tour) = algorithm.run(Some(2)).unwrap();
+
+        assert_eq!(cost, 80);
+        assert_eq!((tour[0], tour[4]), (2, 2));
+    }
+
+    #[test]
+    fn test_run_directed() {
+        let mut algorithm = HeldKarpAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 1), (2, 5)]),
+            (1, vec![(2, 1)]),
+            (2, vec![(0, 1)]),
+        ]);
+
+        assert_eq!(algorithm.run(Some(1)).unwrap(), (3, vec![1, 2, 0, 1]));
+    }
+
+    #[test]
+    fn test_run_infeasible() {
+        let mut algorithm = HeldKarpAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 1)]), (1, vec![(2, 1)])]);
+
+        assert_eq!(algorithm.run(None), Err(GraphError::Infeasible));
+        assert_eq!(
+            algorithm.hamiltonian_path(None),
+            Ok(Some((2, vec![0, 1, 2])))
+        );
+        assert_eq!(algorithm.hamiltonian_path(Some(1)), Ok(None));
+    }
+
+    #[test]
+    fn test_hamiltonian_path() {
+        let algorithm = symmetric(&[
+            &[0, 10, 15, 20],
+            &[10, 0, 35, 25],
+            &[15, 35, 0, 30],
+            &[20, 25, 30, 0],
+        ]);
+
+        assert_eq!(
+            algorithm.hamiltonian_path(None),
+            Ok(Some((50, vec![3, 1, 0, 2])))
+        );
+    }
+
+    #[test]
+    fn test_max_nodes() {
+        let mut algorithm = HeldKarpAlgorithm::new();
+        algorithm.set_total_nodes(HeldKarpAlgorithm::MAX_NODES + 1);
+
+        assert_eq!(
+            algorithm.run(None),
+            Err(GraphError::TooManyNodes(HeldKarpAlgorithm::MAX_NODES))
+        );
+        assert_eq!(
+            algorithm.hamiltonian_path(None),
+            Err(GraphError::TooManyNodes(HeldKarpAlgorithm::MAX_NODES))
+        );
+
+        // A start outside the largest graph solved is answered before any table is built.
+        algorithm = HeldKarpAlgorithm::new();
+        algorithm.set_total_nodes(HeldKarpAlgorithm::MAX_NODES);
+
+        assert_eq!(algorithm.hamiltonian_path(Some(usize::MAX)), Ok(None));
+
+        algorithm.set_total_nodes(64);
+
+        assert_eq!(
+            algorithm.run(Some(0)),
+            Err(GraphError::TooManyNodes(HeldKarpAlgorithm::MAX_NODES))
+        );
+    }
+
+    #[test]
+    fn test_estimate_memory() {
+        let mut algorithm = HeldKarpAlgorithm::new();
+        algorithm.set_total_nodes(4);
+
+        let entry = std::mem::size_of::<usize>();
+
+        assert_eq!(
+            algorithm.estimate_memory().working,
+            2 * 16 * (std::mem::size_of::<Vec<usize>>() + 4 * entry)
+        );
+
+        algorithm.set_total_nodes(64);
+
+        assert_eq!(algorithm.estimate_memory().total(), usize::MAX);
+    }
+}
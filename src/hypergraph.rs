@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+
+/// Hypergraph.
+/// Store hyperedges joining any number of nodes, and expand them into plain graphs for the other algorithms.
+#[derive(Debug, Clone)]
+pub struct Hypergraph {
+    /// Total number of nodes in the hypergraph.
+    pub total_nodes: usize,
+
+    /// Hyperedges as their sorted, distinct nodes and their weight.
+    pub hyperedges: Vec<(Vec<usize>, usize)>,
+}
+
+impl Default for Hypergraph {
+    /// Create a new default instance of the hypergraph.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the hypergraph.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hypergraph {
+    /// Create a new instance of the hypergraph.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the hypergraph.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            hyperedges: Vec::new(),
+        }
+    }
+
+    /// Set a hyperedge to the hypergraph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Nodes joined by the hyperedge.
+    /// - `weight`: Weight of the hyperedge.
+    ///
+    /// # Returns
+    ///
+    /// Index of the hyperedge.
+    pub fn set_hyperedge(&mut self, mut nodes: Vec<usize>, weight: usize) -> usize {
+        nodes.sort_unstable();
+        nodes.dedup();
+
+        if let Some(&last) = nodes.last() {
+            self.set_total_nodes(last + 1);
+        }
+
+        self.hyperedges.push((nodes, weight));
+        self.hyperedges.len() - 1
+    }
+
+    /// Set the total number of nodes in the hypergraph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the hypergraph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+    }
+
+    /// Compute the degree of each node.
+    ///
+    /// # Returns
+    ///
+    /// Number of hyperedges containing each node.
+    pub fn degrees(&self) -> Vec<usize> {
+        let mut degrees = vec![0; self.total_nodes];
+
+        for (nodes, _) in &self.hyperedges {
+            for &node in nodes {
+                degrees[node] += 1;
+            }
+        }
+
+        degrees
+    }
+
+    /// Expand each hyperedge into a clique over its nodes.
+    /// Pairs sharing several hyperedges get the sum of their weights.
+    ///
+    /// # Returns
+    ///
+    /// Undirected edges as `(source, target, weight)` with `source < target`, sorted.
+    pub fn clique_expansion(&self) -> Vec<(usize, usize, usize)> {
+        let mut edges: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+
+        for (nodes, weight) in &self.hyperedges {
+            for (index, &source) in nodes.iter().enumerate() {
+                for &target in &nodes[index + 1..] {
+                    *edges.entry((source, target)).or_default() += weight;
+                }
+            }
+        }
+
+        edges
+            .into_iter()
+            .map(|((source, target), weight)| (source, target, weight))
+            .collect()
+    }
+
+    /// Expand the hypergraph into its bipartite incidence graph.
+    /// Hyperedge `i` becomes node `total_nodes + i`, joined to each of its nodes.
+    ///
+    /// # Returns
+    ///
+    /// Undirected edges as `(node, hyperedge node, weight)`.
+    pub fn star_expansion(&self) -> Vec<(usize, usize, usize)> {
+        self.hyperedges
+            .iter()
+            .enumerate()
+            .flat_map(|(index, (nodes, weight))| {
+                nodes
+                    .iter()
+                    .map(move |&node| (node, self.total_nodes + index, *weight))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hypergraph() -> Hypergraph {
+        let mut hypergraph = Hypergraph::new();
+        hypergraph.set_hyperedge(vec![2, 0, 1], 1);
+        hypergraph.set_hyperedge(vec![1, 2, 2], 3);
+        hypergraph.set_total_nodes(4);
+        hypergraph
+    }
+
+    #[test]
+    fn test_new() {
+        let hypergraph = Hypergraph::new();
+        let hypergraph_default = Hypergraph::default();
+
+        assert_eq!(hypergraph.total_nodes, 0);
+        assert_eq!(hypergraph_default.hyperedges.len(), 0);
+    }
+
+    #[test]
+    fn test_set_hyperedge() {
+        let hypergraph = hypergraph();
+
+        assert_eq!(hypergraph.hyperedges[1], (vec![1, 2], 3));
+        assert_eq!(hypergraph.degrees(), vec![1, 2, 2, 0]);
+    }
+
+    #[test]
+    fn test_clique_expansion() {
+        assert_eq!(
+            hypergraph().clique_expansion(),
+            vec![(0, 1, 1), (0, 2, 1), (1, 2, 4)]
+        );
+    }
+
+    #[test]
+    fn test_star_expansion() {
+        assert_eq!(
+            hypergraph().star_expansion(),
+            vec![(0, 4, 1), (1, 4, 1), (2, 4, 1), (1, 5, 3), (2, 5, 3)]
+        );
+    }
+}
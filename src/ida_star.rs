@@ -0,0 +1,412 @@
+use crate::{GraphAlgorithm, GraphError};
+
+/// Search problem over an implicit graph, whose states are generated on demand.
+pub trait SearchProblem {
+    /// Type of state.
+    type State: Clone + PartialEq;
+
+    /// Generate the states reachable in one move.
+    ///
+    /// # Arguments
+    ///
+    /// - `state`: Current state.
+    ///
+    /// # Returns
+    ///
+    /// Next states with the cost of the move.
+    fn successors(&self, state: &Self::State) -> Vec<(Self::State, usize)>;
+
+    /// Estimate the remaining cost to a goal. It must never overestimate for the path to be optimal.
+    ///
+    /// # Arguments
+    ///
+    /// - `state`: Current state.
+    ///
+    /// # Returns
+    ///
+    /// Lower bound of the cost to the nearest goal.
+    fn heuristic(&self, state: &Self::State) -> usize;
+
+    /// Check whether a state is a goal.
+    ///
+    /// # Arguments
+    ///
+    /// - `state`: Current state.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the search can stop at the state.
+    fn is_goal(&self, state: &Self::State) -> bool;
+}
+
+/// Path found by a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchPath<S> {
+    /// Total cost of the path.
+    pub cost: usize,
+
+    /// States of the path, from the start to the goal.
+    pub states: Vec<S>,
+
+    /// Number of depth-first iterations, one per cost bound.
+    pub iterations: usize,
+
+    /// Number of expanded states over all iterations.
+    pub expansions: usize,
+}
+
+/// Outcome of one bounded depth-first iteration.
+enum Bound {
+    /// A goal was reached within the bound.
+    Found,
+
+    /// Smallest estimated cost beyond the bound, `usize::MAX` if nothing lies beyond.
+    Next(usize),
+
+    /// The expansion budget ran out.
+    Exhausted,
+}
+
+/// IDA* Algorithm.
+/// Find a cheapest path to a goal of an implicit graph with iterative deepening A*: depth-first
+/// searches bounded by the estimated total cost `g + h` are repeated with the smallest exceeding
+/// estimate as the next bound. Memory stays linear in the path length, as only the current path is
+/// stored, which suits puzzles whose state spaces are too large for the open and closed sets of A*.
+#[derive(Debug, Clone)]
+pub struct IdaStarAlgorithm<P> {
+    /// Search problem.
+    pub problem: P,
+
+    /// Maximum number of expanded states over all iterations, if any.
+    pub max_expansions: Option<usize>,
+}
+
+impl<P: Default> Default for IdaStarAlgorithm<P> {
+    /// Create a new default instance of IDA* Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of IDA* Algorithm.
+    fn default() -> Self {
+        Self::new(P::default())
+    }
+}
+
+impl<P> IdaStarAlgorithm<P> {
+    /// Create a new instance of IDA* Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `problem`: Search problem.
+    ///
+    /// # Returns
+    ///
+    /// New instance of IDA* Algorithm.
+    pub fn new(problem: P) -> Self {
+        Self {
+            problem,
+            max_expansions: None,
+        }
+    }
+
+    /// Set the maximum number of expanded states over all iterations.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_expansions`: Maximum number of expansions.
+    pub fn set_max_expansions(&mut self, max_expansions: usize) {
+        self.max_expansions = Some(max_expansions);
+    }
+}
+
+impl<P: SearchProblem> IdaStarAlgorithm<P> {
+    /// Search a cheapest path from a start state to a goal.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Start state.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the path, `MissingTargetNode` if no goal is reachable, or
+    /// `NoConvergence` if the expansion budget runs out.
+    pub fn search(&self, start: P::State) -> Result<SearchPath<P::State>, GraphError> {
+        let mut bound = self.problem.heuristic(&start);
+        let mut path = vec![start];
+        let mut cost = 0;
+        let mut expansions = 0;
+        let mut iterations = 0;
+
+        loop {
+            iterations += 1;
+
+            match self.deepen(&mut path, &mut cost, bound, &mut expansions) {
+                Bound::Found => {
+                    return Ok(SearchPath {
+                        cost,
+                        states: path,
+                        iterations,
+                        expansions,
+                    })
+                }
+                Bound::Next(usize::MAX) => return Err(GraphError::MissingTargetNode),
+                Bound::Next(next) => bound = next,
+                Bound::Exhausted => return Err(GraphError::NoConvergence),
+            }
+        }
+    }
+
+    /// Search depth-first from the last state of the path within a cost bound.
+    /// States already on the path are skipped, so cycles are never followed.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: Current path, left at the goal if one is found.
+    /// - `cost`: Cost of the current path.
+    /// - `bound`: Bound of the estimated total cost.
+    /// - `expansions`: Number of expanded states so far.
+    ///
+    /// # Returns
+    ///
+    /// Whether a goal was found, or the next bound.
+    fn deepen(
+        &self,
+        path: &mut Vec<P::State>,
+        cost: &mut usize,
+        bound: usize,
+        expansions: &mut usize,
+    ) -> Bound {
+        let state = &path[path.len() - 1];
+        let estimate = cost.saturating_add(self.problem.heuristic(state));
+
+        if estimate > bound {
+            return Bound::Next(estimate);
+        }
+
+        if self.problem.is_goal(state) {
+            return Bound::Found;
+        }
+
+        if self
+            .max_expansions
+            .is_some_and(|limit| *expansions >= limit)
+        {
+            return Bound::Exhausted;
+        }
+
+        *expansions += 1;
+
+        let mut next = usize::MAX;
+
+        for (successor, weight) in self.problem.successors(state) {
+            if path.contains(&successor) {
+                continue;
+            }
+
+            path.push(successor);
+            *cost += weight;
+
+            match self.deepen(path, cost, bound, expansions) {
+                Bound::Next(estimate) => next = next.min(estimate),
+                outcome => return outcome,
+            }
+
+            *cost -= weight;
+            path.pop();
+        }
+
+        Bound::Next(next)
+    }
+}
+
+impl<P: SearchProblem> GraphAlgorithm for IdaStarAlgorithm<P> {
+    /// Type of node.
+    type Node = P::State;
+
+    /// Type of weight.
+    type Weight = SearchPath<P::State>;
+
+    /// Run IDA* Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Start state.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the cheapest path to a goal, or an error.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        self.search(start.ok_or(GraphError::MissingStartNode)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, VecDeque};
+
+    use super::*;
+
+    /// Sliding 8-puzzle, 0 being the blank.
+    #[derive(Debug, Clone, Default)]
+    struct Puzzle;
+
+    impl SearchProblem for Puzzle {
+        type State = [u8; 9];
+
+        fn successors(&self, state: &Self::State) -> Vec<(Self::State, usize)> {
+            let blank = state.iter().position(|&tile| tile == 0).unwrap();
+            let (row, column) = (blank / 3, blank % 3);
+            let mut moves = Vec::new();
+
+            for (near, tile) in [
+                (row > 0, blank.wrapping_sub(3)),
+                (row < 2, blank + 3),
+                (column > 0, blank.wrapping_sub(1)),
+                (column < 2, blank + 1),
+            ] {
+                if near {
+                    let mut next = *state;
+                    next.swap(blank, tile);
+                    moves.push((next, 1));
+                }
+            }
+
+            moves
+        }
+
+        fn heuristic(&self, state: &Self::State) -> usize {
+            state
+                .iter()
+                .enumerate()
+                .filter(|&(_, &tile)| tile != 0)
+                .map(|(index, &tile)| {
+                    let goal = tile as usize - 1;
+                    (index / 3).abs_diff(goal / 3) + (index % 3).abs_diff(goal % 3)
+                })
+                .sum()
+        }
+
+        fn is_goal(&self, state: &Self::State) -> bool {
+            *state == [1, 2, 3, 4, 5, 6, 7, 8, 0]
+        }
+    }
+
+    /// Explicit weighted graph without a heuristic.
+    struct Graph {
+        edges: Vec<Vec<(usize, usize)>>,
+        goal: usize,
+    }
+
+    impl SearchProblem for Graph {
+        type State = usize;
+
+        fn successors(&self, state: &usize) -> Vec<(usize, usize)> {
+            self.edges[*state].clone()
+        }
+
+        fn heuristic(&self, _state: &usize) -> usize {
+            0
+        }
+
+        fn is_goal(&self, state: &usize) -> bool {
+            *state == self.goal
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = IdaStarAlgorithm::new(Puzzle);
+        let algorithm_default = IdaStarAlgorithm::<Puzzle>::default();
+
+        assert_eq!(algorithm.max_expansions, None);
+        assert_eq!(algorithm_default.max_expansions, None);
+        assert_eq!(algorithm.run(None), Err(GraphError::MissingStartNode));
+    }
+
+    #[test]
+    fn test_run() {
+        let algorithm = IdaStarAlgorithm::new(Puzzle);
+        let path = algorithm.run(Some([1, 2, 3, 0, 4, 6, 7, 5, 8])).unwrap();
+
+        assert_eq!(path.cost, 3);
+        assert_eq!(
+            path.states,
+            vec![
+                [1, 2, 3, 0, 4, 6, 7, 5, 8],
+                [1, 2, 3, 4, 0, 6, 7, 5, 8],
+                [1, 2, 3, 4, 5, 6, 7, 0, 8],
+                [1, 2, 3, 4, 5, 6, 7, 8, 0],
+            ]
+        );
+        assert_eq!(path.iterations, 1);
+    }
+
+    #[test]
+    fn test_search_optimal() {
+        // Breadth-first search over the whole puzzle gives the optimal move counts.
+        let goal = [1, 2, 3, 4, 5, 6, 7, 8, 0];
+        let mut distances = HashMap::from([(goal, 0)]);
+        let mut queue = VecDeque::from([goal]);
+
+        while let Some(state) = queue.pop_front() {
+            for (next, _) in Puzzle.successors(&state) {
+                if !distances.contains_key(&next) {
+                    distances.insert(next, distances[&state] + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let algorithm = IdaStarAlgorithm::new(Puzzle);
+
+        for depth in [12, 20] {
+            let start = *distances
+                .iter()
+                .filter(|&(_, &distance)| distance == depth)
+                .map(|(state, _)| state)
+                .min()
+                .unwrap();
+            let path = algorithm.search(start).unwrap();
+
+            assert_eq!(path.cost, distances[&start]);
+            assert_eq!(path.states.len(), path.cost + 1);
+        }
+
+        // Half of the arrangements cannot be solved.
+        let mut algorithm = IdaStarAlgorithm::new(Puzzle);
+        algorithm.set_max_expansions(1000);
+
+        assert_eq!(
+            algorithm.search([2, 1, 3, 4, 5, 6, 7, 8, 0]),
+            Err(GraphError::NoConvergence)
+        );
+    }
+
+    #[test]
+    fn test_search_graph() {
+        let graph = Graph {
+            edges: vec![
+                vec![(1, 4), (2, 1)],
+                vec![(3, 1)],
+                vec![(1, 2), (3, 5)],
+                vec![(0, 1)],
+                vec![],
+            ],
+            goal: 3,
+        };
+
+        let path = IdaStarAlgorithm::new(graph).search(0).unwrap();
+
+        assert_eq!((path.cost, path.states), (4, vec![0, 2, 1, 3]));
+
+        let graph = Graph {
+            edges: vec![vec![(1, 1)], vec![(0, 1)], vec![]],
+            goal: 2,
+        };
+
+        assert_eq!(
+            IdaStarAlgorithm::new(graph).search(0),
+            Err(GraphError::MissingTargetNode)
+        );
+    }
+}
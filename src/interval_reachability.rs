@@ -0,0 +1,358 @@
+use crate::{GraphAlgorithm, GraphError, TopologicalSortAlgorithm};
+
+/// Interval Reachability Index.
+/// Label every node of a directed acyclic graph with the sorted intervals of post-order numbers
+/// of the nodes it can reach, answering exact reachability queries by binary search.
+/// Nodes appended with edges to existing nodes only, as in append-only provenance graphs,
+/// are labeled incrementally without rebuilding the index.
+#[derive(Debug, Clone)]
+pub struct IntervalReachabilityIndex {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Successors of each node.
+    pub adjacency: Vec<Vec<usize>>,
+
+    /// Post-order number of each node, empty until the index is built.
+    numbers: Vec<usize>,
+
+    /// Intervals of post-order numbers reachable from each node.
+    intervals: Vec<Vec<(usize, usize)>>,
+}
+
+impl Default for IntervalReachabilityIndex {
+    /// Create a new default instance of the interval reachability index.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the interval reachability index.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IntervalReachabilityIndex {
+    /// Create a new instance of the interval reachability index.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the interval reachability index.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+            numbers: Vec::new(),
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Set a single directed edge to the graph. The index must be built again afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].push(target);
+        self.numbers.clear();
+    }
+
+    /// Set multiple nodes' edges to the graph. The index must be built again afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its successors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph. The index must be built again afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, Vec::new());
+        self.numbers.clear();
+    }
+
+    /// Check whether the index is built and up to date.
+    ///
+    /// # Returns
+    ///
+    /// `true` if queries can be answered.
+    pub fn is_built(&self) -> bool {
+        self.numbers.len() == self.total_nodes
+            && self.intervals.len() == self.total_nodes
+            && self.total_nodes > 0
+    }
+
+    /// Build the labels with a depth-first search, numbering the nodes in post-order.
+    ///
+    /// # Returns
+    ///
+    /// Result of the build, or an error if the graph has a cycle.
+    pub fn build(&mut self) -> Result<(), GraphError> {
+        let mut sort = TopologicalSortAlgorithm::new();
+        sort.set_total_nodes(self.total_nodes);
+        sort.adjacency = self.adjacency.clone();
+        sort.run(None)?;
+
+        let mut numbers = vec![usize::MAX; self.total_nodes];
+        let mut intervals = vec![Vec::new(); self.total_nodes];
+        let mut next = 0;
+
+        for root in 0..self.total_nodes {
+            if numbers[root] != usize::MAX {
+                continue;
+            }
+
+            let mut stack = vec![(root, 0)];
+
+            while let Some((node, child)) = stack.pop() {
+                match self.adjacency[node].get(child) {
+                    Some(&target) => {
+                        stack.push((node, child + 1));
+
+                        if numbers[target] == usize::MAX {
+                            stack.push((target, 0));
+                        }
+                    }
+                    None => {
+                        numbers[node] = next;
+                        intervals[node] = Self::merge(
+                            next,
+                            self.adjacency[node]
+                                .iter()
+                                .map(|&target| &intervals[target]),
+                        );
+                        next += 1;
+                    }
+                }
+            }
+        }
+
+        self.numbers = numbers;
+        self.intervals = intervals;
+
+        Ok(())
+    }
+
+    /// Append a node whose edges lead to existing nodes only.
+    /// If the index is built, the new node is labeled without rebuilding.
+    ///
+    /// # Arguments
+    ///
+    /// - `targets`: Existing nodes the new node points to, e.g. the inputs it was derived from.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the ID of the new node, or an error if a target does not exist.
+    pub fn append_node(&mut self, targets: Vec<usize>) -> Result<usize, GraphError> {
+        if targets.iter().any(|&target| target >= self.total_nodes) {
+            return Err(GraphError::MissingTargetNode);
+        }
+
+        let node = self.total_nodes;
+        let built = self.is_built() || self.total_nodes == 0;
+
+        self.total_nodes += 1;
+
+        if built {
+            // Post-order numbers are a permutation of the node IDs, so the next free number is `node`.
+            let label = Self::merge(node, targets.iter().map(|&target| &self.intervals[target]));
+            self.numbers.push(node);
+            self.intervals.push(label);
+        }
+
+        self.adjacency.push(targets);
+
+        Ok(node)
+    }
+
+    /// Merge the intervals of the successors with the number of a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `number`: Post-order number of the node.
+    /// - `labels`: Intervals of the successors.
+    ///
+    /// # Returns
+    ///
+    /// Sorted disjoint intervals, with adjacent intervals joined.
+    fn merge<'a>(
+        number: usize,
+        labels: impl Iterator<Item = &'a Vec<(usize, usize)>>,
+    ) -> Vec<(usize, usize)> {
+        let mut all: Vec<(usize, usize)> = labels.flatten().copied().collect();
+        all.push((number, number));
+        all.sort_unstable();
+
+        let mut merged: Vec<(usize, usize)> = Vec::with_capacity(all.len());
+
+        for (low, high) in all {
+            match merged.last_mut() {
+                Some(last) if low <= last.1 + 1 => last.1 = last.1.max(high),
+                _ => merged.push((low, high)),
+            }
+        }
+
+        merged
+    }
+
+    /// Check whether a path leads from one node to another.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Whether the target is reachable, or `None` if the index is not built or a node is unknown.
+    pub fn can_reach(&self, source: usize, target: usize) -> Option<bool> {
+        if !self.is_built() || source.max(target) >= self.total_nodes {
+            return None;
+        }
+
+        let number = self.numbers[target];
+        let intervals = &self.intervals[source];
+        let index = intervals.partition_point(|&(low, _)| low <= number);
+
+        Some(index > 0 && intervals[index - 1].1 >= number)
+    }
+
+    /// Get the number of intervals of a node, a measure of the index size.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node.
+    ///
+    /// # Returns
+    ///
+    /// Number of intervals, or `None` if the index is not built or the node is unknown.
+    pub fn label_size(&self, node: usize) -> Option<usize> {
+        match self.is_built() {
+            true => self.intervals.get(node).map(Vec::len),
+            false => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Random;
+
+    fn closure(adjacency: &[Vec<usize>]) -> Vec<Vec<bool>> {
+        (0..adjacency.len())
+            .map(|source| {
+                let mut reachable = vec![false; adjacency.len()];
+                let mut stack = vec![source];
+
+                while let Some(node) = stack.pop() {
+                    if !reachable[node] {
+                        reachable[node] = true;
+                        stack.extend(&adjacency[node]);
+                    }
+                }
+
+                reachable
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_new() {
+        let index = IntervalReachabilityIndex::new();
+        let index_default = IntervalReachabilityIndex::default();
+
+        assert!(!index.is_built());
+        assert_eq!(index_default.total_nodes, 0);
+        assert_eq!(index.can_reach(0, 0), None);
+    }
+
+    #[test]
+    fn test_can_reach() {
+        let mut index = IntervalReachabilityIndex::new();
+        index.set_edges(vec![
+            (0, vec![1, 2]),
+            (1, vec![3]),
+            (2, vec![3]),
+            (4, vec![2]),
+        ]);
+        index.build().unwrap();
+
+        assert_eq!(index.can_reach(0, 3), Some(true));
+        assert_eq!(index.can_reach(4, 3), Some(true));
+        assert_eq!(index.can_reach(4, 1), Some(false));
+        assert_eq!(index.can_reach(3, 0), Some(false));
+        assert_eq!(index.can_reach(2, 2), Some(true));
+        assert_eq!(index.label_size(0), Some(1));
+
+        index.set_edge(3, 5);
+
+        assert_eq!(index.can_reach(0, 3), None);
+    }
+
+    #[test]
+    fn test_append_node() {
+        let mut index = IntervalReachabilityIndex::new();
+
+        let a = index.append_node(vec![]).unwrap();
+        let b = index.append_node(vec![a]).unwrap();
+        let c = index.append_node(vec![]).unwrap();
+        let d = index.append_node(vec![b, c]).unwrap();
+
+        assert!(index.is_built());
+        assert_eq!(index.can_reach(d, a), Some(true));
+        assert_eq!(index.can_reach(b, c), Some(false));
+        assert_eq!(index.label_size(d), Some(1));
+        assert_eq!(
+            index.append_node(vec![9]),
+            Err(GraphError::MissingTargetNode)
+        );
+    }
+
+    #[test]
+    fn test_can_reach_random() {
+        let mut random = Random::new(11);
+        let mut index = IntervalReachabilityIndex::new();
+
+        for node in 0..60 {
+            let targets = (0..random.below(4))
+                .filter(|_| node > 0)
+                .map(|_| random.below(node))
+                .collect();
+            index.append_node(targets).unwrap();
+        }
+
+        let mut rebuilt = index.clone();
+        rebuilt.build().unwrap();
+        let reachable = closure(&index.adjacency);
+
+        for (source, row) in reachable.iter().enumerate() {
+            for (target, &expected) in row.iter().enumerate() {
+                assert_eq!(index.can_reach(source, target), Some(expected));
+                assert_eq!(rebuilt.can_reach(source, target), Some(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn test_build_cycle() {
+        let mut index = IntervalReachabilityIndex::new();
+        index.set_edges(vec![(0, vec![1]), (1, vec![0])]);
+
+        assert_eq!(index.build(), Err(GraphError::CycleDetected));
+        assert!(!index.is_built());
+    }
+}
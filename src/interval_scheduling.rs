@@ -0,0 +1,267 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::{ChordalAlgorithm, GraphAlgorithm, GraphError};
+
+/// Interval Scheduling (Interval Graph Coloring).
+/// Assign intervals, such as meetings, to the minimum number of machines, such as rooms,
+/// so that intervals on the same machine do not overlap.
+#[derive(Debug, Clone, Default)]
+pub struct IntervalSchedulingAlgorithm {
+    /// Intervals as half-open `(start, end)` ranges, so that touching intervals do not overlap.
+    pub intervals: Vec<(usize, usize)>,
+}
+
+impl IntervalSchedulingAlgorithm {
+    /// Create a new instance of the interval scheduling algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the interval scheduling algorithm.
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Set a single interval.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Start of the interval, inclusive.
+    /// - `end`: End of the interval, exclusive.
+    ///
+    /// # Returns
+    ///
+    /// Index of the interval.
+    pub fn set_interval(&mut self, start: usize, end: usize) -> usize {
+        self.intervals.push((start, end));
+        self.intervals.len() - 1
+    }
+
+    /// Set multiple intervals.
+    ///
+    /// # Arguments
+    ///
+    /// - `intervals`: Vector of `(start, end)` intervals.
+    pub fn set_intervals(&mut self, intervals: Vec<(usize, usize)>) {
+        for (start, end) in intervals {
+            self.set_interval(start, end);
+        }
+    }
+
+    /// Build the interval graph, with an edge between every pair of overlapping intervals.
+    ///
+    /// # Returns
+    ///
+    /// Chordal graph whose nodes are the intervals.
+    pub fn interval_graph(&self) -> ChordalAlgorithm {
+        let mut graph = ChordalAlgorithm::new();
+        graph.set_total_nodes(self.intervals.len());
+
+        let mut order: Vec<usize> = (0..self.intervals.len()).collect();
+        order.sort_by_key(|&index| self.intervals[index]);
+
+        // Sweeping by start, each interval overlaps the earlier ones that have not ended yet.
+        for (position, &index) in order.iter().enumerate() {
+            let (start, end) = self.intervals[index];
+
+            for &other in &order[position + 1..] {
+                let (other_start, other_end) = self.intervals[other];
+
+                if other_start >= end {
+                    break;
+                }
+
+                if start < end && other_start < other_end {
+                    graph.set_edge(index, other);
+                }
+            }
+        }
+
+        graph
+    }
+
+    /// Group the intervals by the machine they are assigned to.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the intervals of each machine in order of start, or an error.
+    pub fn machines(&self) -> Result<Vec<Vec<usize>>, GraphError> {
+        let assignment = self.run(None)?;
+        let total = assignment.iter().map(|&machine| machine + 1).max();
+        let mut machines = vec![Vec::new(); total.unwrap_or_default()];
+
+        let mut order: Vec<usize> = (0..self.intervals.len()).collect();
+        order.sort_by_key(|&index| (self.intervals[index].0, index));
+
+        for index in order {
+            machines[assignment[index]].push(index);
+        }
+
+        Ok(machines)
+    }
+}
+
+impl GraphAlgorithm for IntervalSchedulingAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<usize>;
+
+    /// Run the interval scheduling algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the interval scheduling algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the machine of each interval, using as many machines as the largest
+    /// number of intervals overlapping at once, or an error.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        self.validate(None)?;
+
+        let mut order: Vec<usize> = (0..self.intervals.len()).collect();
+        order.sort_by_key(|&index| (self.intervals[index].0, index));
+
+        let mut assignment = vec![0; self.intervals.len()];
+        let mut busy: BinaryHeap<Reverse<(usize, usize)>> = BinaryHeap::new();
+        let mut free: BinaryHeap<Reverse<usize>> = BinaryHeap::new();
+        let mut total = 0;
+
+        // Greedy by left endpoint: reuse the lowest machine released before the interval starts.
+        for index in order {
+            let (start, end) = self.intervals[index];
+
+            // An empty interval overlaps nothing and fits on any machine.
+            if start == end {
+                assignment[index] = 0;
+
+                if total == 0 {
+                    total = 1;
+                    free.push(Reverse(0));
+                }
+
+                continue;
+            }
+
+            while let Some(&Reverse((released, machine))) = busy.peek() {
+                if released > start {
+                    break;
+                }
+
+                busy.pop();
+                free.push(Reverse(machine));
+            }
+
+            let machine = match free.pop() {
+                Some(Reverse(machine)) => machine,
+                None => {
+                    total += 1;
+                    total - 1
+                }
+            };
+
+            assignment[index] = machine;
+            busy.push(Reverse((end, machine)));
+        }
+
+        Ok(assignment)
+    }
+
+    /// Check that every interval ends no earlier than it starts.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the interval scheduling algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing nothing, or an error if an interval is reversed.
+    fn validate(&self, _start: Option<&Self::Node>) -> Result<(), GraphError> {
+        match self.intervals.iter().any(|&(start, end)| start > end) {
+            true => Err(GraphError::Infeasible),
+            false => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meetings() -> IntervalSchedulingAlgorithm {
+        let mut algorithm = IntervalSchedulingAlgorithm::new();
+        algorithm.set_intervals(vec![
+            (9, 11),
+            (10, 12),
+            (11, 13),
+            (9, 10),
+            (12, 14),
+            (10, 11),
+        ]);
+        algorithm
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = IntervalSchedulingAlgorithm::new();
+        let algorithm_default = IntervalSchedulingAlgorithm::default();
+
+        assert!(algorithm.intervals.is_empty());
+        assert!(algorithm_default.intervals.is_empty());
+    }
+
+    #[test]
+    fn test_run() {
+        let algorithm = meetings();
+        let assignment = algorithm.run(None).unwrap();
+
+        assert_eq!(assignment, vec![0, 1, 0, 1, 1, 2]);
+
+        for (a, &(start_a, end_a)) in algorithm.intervals.iter().enumerate() {
+            for (b, &(start_b, end_b)) in algorithm.intervals.iter().enumerate().skip(a + 1) {
+                if start_a < end_b && start_b < end_a {
+                    assert_ne!(assignment[a], assignment[b]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_matches_maximum_clique() {
+        let algorithm = meetings();
+        let graph = algorithm.interval_graph();
+        let machines = algorithm.machines().unwrap();
+
+        assert!(graph.is_interval_graph());
+        assert_eq!(machines.len(), graph.maximum_clique().unwrap().len());
+        assert_eq!(machines, vec![vec![0, 2], vec![3, 1, 4], vec![5]]);
+    }
+
+    #[test]
+    fn test_run_touching_and_empty_intervals() {
+        let mut algorithm = IntervalSchedulingAlgorithm::new();
+        algorithm.set_intervals(vec![(0, 5), (5, 10), (3, 3)]);
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![0, 0, 0]);
+        assert!(algorithm.interval_graph().adjacency[2].is_empty());
+    }
+
+    #[test]
+    fn test_run_empty() {
+        let algorithm = IntervalSchedulingAlgorithm::new();
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![]);
+        assert_eq!(algorithm.machines().unwrap(), Vec::<Vec<usize>>::new());
+    }
+
+    #[test]
+    fn test_run_reversed_interval() {
+        let mut algorithm = IntervalSchedulingAlgorithm::new();
+        algorithm.set_interval(4, 2);
+
+        assert_eq!(algorithm.try_run(None), Err(GraphError::Infeasible));
+    }
+}
@@ -0,0 +1,662 @@
+use std::collections::HashMap;
+
+use crate::{GraphAlgorithm, GraphError};
+
+/// Kind of correspondence between the pattern and the target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MatchKind {
+    /// Both graphs are the same up to node IDs.
+    Isomorphism,
+
+    /// The pattern is an induced subgraph of the target: two matched nodes are adjacent in the
+    /// target exactly when they are adjacent in the pattern.
+    #[default]
+    InducedSubgraph,
+
+    /// Every pattern edge appears between the matched target nodes, which may have further edges.
+    Subgraph,
+}
+
+/// Graph with labeled nodes and edges, such as atoms and bonds of a molecule.
+#[derive(Debug, Clone, Default)]
+pub struct LabeledGraph {
+    /// Whether the edges are directed.
+    pub directed: bool,
+
+    /// Label of each node.
+    pub labels: Vec<usize>,
+
+    /// Label of each edge, keyed by `(source, target)`, and by `(target, source)` as well if the
+    /// graph is undirected.
+    pub edges: HashMap<(usize, usize), usize>,
+
+    /// Successors of each node.
+    pub successors: Vec<Vec<usize>>,
+
+    /// Predecessors of each node.
+    pub predecessors: Vec<Vec<usize>>,
+}
+
+impl LabeledGraph {
+    /// Create a new labeled graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `directed`: Whether the edges are directed.
+    ///
+    /// # Returns
+    ///
+    /// New graph without nodes.
+    pub fn new(directed: bool) -> Self {
+        Self {
+            directed,
+            ..Self::default()
+        }
+    }
+
+    /// Set the label of a node, adding the node if needed.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node.
+    /// - `label`: Label of the node, e.g. an element.
+    pub fn set_label(&mut self, node: usize, label: usize) {
+        self.set_total_nodes(node + 1);
+        self.labels[node] = label;
+    }
+
+    /// Set a single edge to the graph. Setting an edge again replaces its label.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `label`: Label of the edge, e.g. a bond order.
+    pub fn set_edge(&mut self, source: usize, target: usize, label: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+
+        let mut link = |source: usize, target: usize| {
+            if self.edges.insert((source, target), label).is_none() {
+                self.successors[source].push(target);
+                self.predecessors[target].push(source);
+            }
+        };
+
+        link(source, target);
+
+        if !self.directed && source != target {
+            link(target, source);
+        }
+    }
+
+    /// Set the total number of nodes in the graph, labeling new nodes with 0.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        if total > self.labels.len() {
+            self.labels.resize(total, 0);
+            self.successors.resize(total, Vec::new());
+            self.predecessors.resize(total, Vec::new());
+        }
+    }
+
+    /// Get the number of nodes.
+    ///
+    /// # Returns
+    ///
+    /// Number of nodes.
+    pub fn total_nodes(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Get the label of an edge.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Label of the edge, or `None` if there is no such edge.
+    pub fn edge(&self, source: usize, target: usize) -> Option<usize> {
+        self.edges.get(&(source, target)).copied()
+    }
+}
+
+/// Graph Isomorphism.
+/// Match a pattern graph against a target graph with the VF2 algorithm, enumerating every mapping
+/// of the pattern nodes to target nodes that preserves node labels, edge labels and adjacency.
+/// The search extends a partial mapping one pair at a time, preferring nodes adjacent to the
+/// mapped ones, and prunes pairs whose unmapped neighborhoods cannot correspond.
+#[derive(Debug, Clone)]
+pub struct IsomorphismAlgorithm {
+    /// Pattern graph.
+    pub pattern: LabeledGraph,
+
+    /// Target graph.
+    pub target: LabeledGraph,
+
+    /// Kind of correspondence.
+    pub kind: MatchKind,
+
+    /// Maximum number of mappings to enumerate, if any.
+    pub limit: Option<usize>,
+}
+
+impl Default for IsomorphismAlgorithm {
+    /// Create a new default instance of the graph isomorphism.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the graph isomorphism.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IsomorphismAlgorithm {
+    /// Create a new instance of the graph isomorphism.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the graph isomorphism.
+    pub fn new() -> Self {
+        Self {
+            pattern: LabeledGraph::default(),
+            target: LabeledGraph::default(),
+            kind: MatchKind::default(),
+            limit: None,
+        }
+    }
+
+    /// Set the pattern graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `pattern`: Pattern graph.
+    pub fn set_pattern(&mut self, pattern: LabeledGraph) {
+        self.pattern = pattern;
+    }
+
+    /// Set the target graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `target`: Target graph.
+    pub fn set_target(&mut self, target: LabeledGraph) {
+        self.target = target;
+    }
+
+    /// Set the kind of correspondence.
+    ///
+    /// # Arguments
+    ///
+    /// - `kind`: Isomorphism, induced subgraph or subgraph.
+    pub fn set_kind(&mut self, kind: MatchKind) {
+        self.kind = kind;
+    }
+
+    /// Set the maximum number of mappings to enumerate.
+    ///
+    /// # Arguments
+    ///
+    /// - `limit`: Maximum number of mappings.
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = Some(limit);
+    }
+
+    /// Check whether the pattern matches the target.
+    ///
+    /// # Returns
+    ///
+    /// `true` if at least one mapping exists.
+    pub fn is_match(&self) -> bool {
+        !self.search(Some(1)).is_empty()
+    }
+
+    /// Enumerate the mappings of the pattern into the target.
+    ///
+    /// # Returns
+    ///
+    /// Mappings up to the limit, each giving the target node of every pattern node.
+    pub fn mappings(&self) -> Vec<Vec<usize>> {
+        self.search(self.limit)
+    }
+
+    /// Enumerate the mappings of the pattern into the target up to a limit.
+    ///
+    /// # Arguments
+    ///
+    /// - `limit`: Maximum number of mappings, if any.
+    ///
+    /// # Returns
+    ///
+    /// Mappings in the order they are found.
+    fn search(&self, limit: Option<usize>) -> Vec<Vec<usize>> {
+        let (pattern, target) = (&self.pattern, &self.target);
+        let mut mappings = Vec::new();
+
+        let sizes_fit = match self.kind {
+            MatchKind::Isomorphism => {
+                pattern.total_nodes() == target.total_nodes()
+                    && pattern.edges.len() == target.edges.len()
+            }
+            _ => pattern.total_nodes() <= target.total_nodes(),
+        };
+
+        if !sizes_fit || limit == Some(0) {
+            return mappings;
+        }
+
+        let mut state = State {
+            pattern_core: vec![usize::MAX; pattern.total_nodes()],
+            target_core: vec![usize::MAX; target.total_nodes()],
+            pattern_depth: vec![0; pattern.total_nodes()],
+            target_depth: vec![0; target.total_nodes()],
+        };
+
+        self.extend(&mut state, 0, limit, &mut mappings);
+
+        mappings
+    }
+
+    /// Extend a partial mapping by one pair in every feasible way.
+    ///
+    /// # Arguments
+    ///
+    /// - `state`: Partial mapping and terminal sets.
+    /// - `depth`: Number of mapped pairs.
+    /// - `limit`: Maximum number of mappings, if any.
+    /// - `mappings`: Complete mappings found so far.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the limit is reached and the search stops.
+    fn extend(
+        &self,
+        state: &mut State,
+        depth: usize,
+        limit: Option<usize>,
+        mappings: &mut Vec<Vec<usize>>,
+    ) -> bool {
+        if depth == self.pattern.total_nodes() {
+            mappings.push(state.pattern_core.clone());
+
+            return limit.is_some_and(|limit| mappings.len() >= limit);
+        }
+
+        // Prefer the smallest pattern node adjacent to the mapping, and match it against target
+        // nodes adjacent to the mapping as well.
+        let (node, terminal) =
+            match unmapped(&state.pattern_core, &state.pattern_depth, true).next() {
+                Some(node) => (node, true),
+                None => match unmapped(&state.pattern_core, &state.pattern_depth, false).next() {
+                    Some(node) => (node, false),
+                    None => return false,
+                },
+            };
+
+        let candidates: Vec<usize> =
+            unmapped(&state.target_core, &state.target_depth, terminal).collect();
+
+        for candidate in candidates {
+            if !self.feasible(state, node, candidate) {
+                continue;
+            }
+
+            state.push(&self.pattern, &self.target, node, candidate, depth + 1);
+
+            if self.extend(state, depth + 1, limit, mappings) {
+                return true;
+            }
+
+            state.pop(&self.pattern, &self.target, node, candidate, depth + 1);
+        }
+
+        false
+    }
+
+    /// Check whether a pair can extend the partial mapping.
+    ///
+    /// # Arguments
+    ///
+    /// - `state`: Partial mapping and terminal sets.
+    /// - `node`: Pattern node.
+    /// - `candidate`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the labels and the edges to mapped nodes correspond and the unmapped
+    /// neighborhoods can still correspond.
+    fn feasible(&self, state: &State, node: usize, candidate: usize) -> bool {
+        let (pattern, target) = (&self.pattern, &self.target);
+
+        if pattern.labels[node] != target.labels[candidate]
+            || pattern.edge(node, node) != target.edge(candidate, candidate)
+                && (self.kind != MatchKind::Subgraph || pattern.edge(node, node).is_some())
+        {
+            return false;
+        }
+
+        // Edges between the new pair and the mapped nodes, in both directions.
+        for (outgoing, pattern_neighbors) in [
+            (true, &pattern.successors[node]),
+            (false, &pattern.predecessors[node]),
+        ] {
+            for &neighbor in pattern_neighbors {
+                let image = state.pattern_core[neighbor];
+
+                if neighbor == node || image == usize::MAX {
+                    continue;
+                }
+
+                let (pattern_edge, target_edge) = if outgoing {
+                    (pattern.edge(node, neighbor), target.edge(candidate, image))
+                } else {
+                    (pattern.edge(neighbor, node), target.edge(image, candidate))
+                };
+
+                if pattern_edge != target_edge {
+                    return false;
+                }
+            }
+        }
+
+        // Without further edges allowed, edges of the target pair need a pattern counterpart.
+        if self.kind != MatchKind::Subgraph {
+            for (outgoing, target_neighbors) in [
+                (true, &target.successors[candidate]),
+                (false, &target.predecessors[candidate]),
+            ] {
+                for &neighbor in target_neighbors {
+                    let preimage = state.target_core[neighbor];
+
+                    if neighbor == candidate || preimage == usize::MAX {
+                        continue;
+                    }
+
+                    let pattern_edge = if outgoing {
+                        pattern.edge(node, preimage)
+                    } else {
+                        pattern.edge(preimage, node)
+                    };
+
+                    if pattern_edge.is_none() {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        // Look ahead: unmapped neighbors in and outside the terminal sets.
+        let count = |neighbors: &[usize], core: &[usize], depths: &[usize]| {
+            neighbors.iter().fold((0, 0), |(terminal, new), &neighbor| {
+                match (core[neighbor] == usize::MAX, depths[neighbor] > 0) {
+                    (true, true) => (terminal + 1, new),
+                    (true, false) => (terminal, new + 1),
+                    _ => (terminal, new),
+                }
+            })
+        };
+
+        [
+            (&pattern.successors[node], &target.successors[candidate]),
+            (&pattern.predecessors[node], &target.predecessors[candidate]),
+        ]
+        .iter()
+        .all(|(pattern_neighbors, target_neighbors)| {
+            let (pattern_terminal, pattern_new) =
+                count(pattern_neighbors, &state.pattern_core, &state.pattern_depth);
+            let (target_terminal, target_new) =
+                count(target_neighbors, &state.target_core, &state.target_depth);
+
+            match self.kind {
+                MatchKind::Isomorphism => {
+                    pattern_terminal == target_terminal && pattern_new == target_new
+                }
+                MatchKind::InducedSubgraph => {
+                    pattern_terminal <= target_terminal && pattern_new <= target_new
+                }
+                MatchKind::Subgraph => {
+                    pattern_terminal <= target_terminal
+                        && pattern_terminal + pattern_new <= target_terminal + target_new
+                }
+            }
+        })
+    }
+}
+
+/// List the unmapped nodes of a graph.
+///
+/// # Arguments
+///
+/// - `core`: Image of each node, `usize::MAX` if unmapped.
+/// - `depths`: Depth at which each node joined the terminal set, 0 if it has not.
+/// - `terminal`: Whether to list only the nodes of the terminal set.
+///
+/// # Returns
+///
+/// Unmapped nodes in ascending order.
+fn unmapped<'a>(
+    core: &'a [usize],
+    depths: &'a [usize],
+    terminal: bool,
+) -> impl Iterator<Item = usize> + 'a {
+    (0..core.len()).filter(move |&node| core[node] == usize::MAX && (!terminal || depths[node] > 0))
+}
+
+/// Partial mapping of the VF2 search.
+#[derive(Debug)]
+struct State {
+    /// Target node of each pattern node, `usize::MAX` if unmapped.
+    pattern_core: Vec<usize>,
+
+    /// Pattern node of each target node, `usize::MAX` if unmapped.
+    target_core: Vec<usize>,
+
+    /// Depth at which each pattern node joined the mapping or its neighborhood, 0 if it has not.
+    pattern_depth: Vec<usize>,
+
+    /// Depth at which each target node joined the mapping or its neighborhood, 0 if it has not.
+    target_depth: Vec<usize>,
+}
+
+impl State {
+    /// Add a pair to the mapping and its neighbors to the terminal sets.
+    ///
+    /// # Arguments
+    ///
+    /// - `pattern`: Pattern graph.
+    /// - `target`: Target graph.
+    /// - `node`: Pattern node.
+    /// - `candidate`: Target node.
+    /// - `depth`: Depth of the pair.
+    fn push(
+        &mut self,
+        pattern: &LabeledGraph,
+        target: &LabeledGraph,
+        node: usize,
+        candidate: usize,
+        depth: usize,
+    ) {
+        self.pattern_core[node] = candidate;
+        self.target_core[candidate] = node;
+
+        for (graph, depths, node) in [
+            (pattern, &mut self.pattern_depth, node),
+            (target, &mut self.target_depth, candidate),
+        ] {
+            let neighbors = graph.successors[node]
+                .iter()
+                .chain(&graph.predecessors[node]);
+
+            for &neighbor in std::iter::once(&node).chain(neighbors) {
+                if depths[neighbor] == 0 {
+                    depths[neighbor] = depth;
+                }
+            }
+        }
+    }
+
+    /// Remove a pair from the mapping, restoring the terminal sets.
+    ///
+    /// # Arguments
+    ///
+    /// - `pattern`: Pattern graph.
+    /// - `target`: Target graph.
+    /// - `node`: Pattern node.
+    /// - `candidate`: Target node.
+    /// - `depth`: Depth of the pair.
+    fn pop(
+        &mut self,
+        pattern: &LabeledGraph,
+        target: &LabeledGraph,
+        node: usize,
+        candidate: usize,
+        depth: usize,
+    ) {
+        self.pattern_core[node] = usize::MAX;
+        self.target_core[candidate] = usize::MAX;
+
+        for (graph, depths, node) in [
+            (pattern, &mut self.pattern_depth, node),
+            (target, &mut self.target_depth, candidate),
+        ] {
+            let neighbors = graph.successors[node]
+                .iter()
+                .chain(&graph.predecessors[node]);
+
+            for &neighbor in std::iter::once(&node).chain(neighbors) {
+                if depths[neighbor] == depth {
+                    depths[neighbor] = 0;
+                }
+            }
+        }
+    }
+}
+
+impl GraphAlgorithm for IsomorphismAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<Vec<usize>>;
+
+    /// Run the graph isomorphism.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the graph isomorphism.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the mappings of the pattern into the target.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.mappings())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cycle(length: usize, directed: bool) -> LabeledGraph {
+        let mut graph = LabeledGraph::new(directed);
+
+        for node in 0..length {
+            graph.set_edge(node, (node + 1) % length, 0);
+        }
+
+        graph
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = IsomorphismAlgorithm::new();
+        let algorithm_default = IsomorphismAlgorithm::default();
+
+        assert_eq!(algorithm.kind, MatchKind::InducedSubgraph);
+        assert_eq!(algorithm_default.limit, None);
+        assert_eq!(algorithm.run(None).unwrap(), vec![Vec::<usize>::new()]);
+    }
+
+    #[test]
+    fn test_isomorphism() {
+        let mut algorithm = IsomorphismAlgorithm::new();
+        algorithm.set_kind(MatchKind::Isomorphism);
+        algorithm.set_pattern(cycle(4, false));
+
+        let mut target = LabeledGraph::new(false);
+        target.set_edge(0, 2, 0);
+        target.set_edge(2, 1, 0);
+        target.set_edge(1, 3, 0);
+        target.set_edge(3, 0, 0);
+        algorithm.set_target(target);
+
+        // The 8 symmetries of the square.
+        let mappings = algorithm.mappings();
+        assert_eq!(mappings.len(), 8);
+        assert_eq!(mappings[0], vec![0, 2, 1, 3]);
+
+        algorithm.set_target(cycle(4, true));
+        assert!(!algorithm.is_match());
+
+        algorithm.set_pattern(cycle(4, true));
+        assert_eq!(algorithm.mappings().len(), 4);
+    }
+
+    #[test]
+    fn test_induced_subgraph() {
+        // A path of 3 nodes appears in a square, but not induced in a triangle.
+        let mut pattern = LabeledGraph::new(false);
+        pattern.set_edge(0, 1, 0);
+        pattern.set_edge(1, 2, 0);
+
+        let mut algorithm = IsomorphismAlgorithm::new();
+        algorithm.set_pattern(pattern);
+        algorithm.set_target(cycle(3, false));
+
+        assert!(!algorithm.is_match());
+
+        algorithm.set_kind(MatchKind::Subgraph);
+        assert_eq!(algorithm.mappings().len(), 6);
+
+        algorithm.set_kind(MatchKind::InducedSubgraph);
+        algorithm.set_target(cycle(4, false));
+        assert_eq!(algorithm.mappings().len(), 8);
+
+        algorithm.set_limit(3);
+        assert_eq!(
+            algorithm.run(None).unwrap(),
+            vec![vec![0, 1, 2], vec![0, 3, 2], vec![1, 0, 3]]
+        );
+    }
+
+    #[test]
+    fn test_labels() {
+        // C=O in acetic acid CH3-C(=O)-OH, heavy atoms only: C, C, O, O.
+        let mut molecule = LabeledGraph::new(false);
+        molecule.set_label(0, 6);
+        molecule.set_label(1, 6);
+        molecule.set_label(2, 8);
+        molecule.set_label(3, 8);
+        molecule.set_edge(0, 1, 1);
+        molecule.set_edge(1, 2, 2);
+        molecule.set_edge(1, 3, 1);
+
+        let mut carbonyl = LabeledGraph::new(false);
+        carbonyl.set_label(0, 6);
+        carbonyl.set_label(1, 8);
+        carbonyl.set_edge(0, 1, 2);
+
+        let mut algorithm = IsomorphismAlgorithm::new();
+        algorithm.set_pattern(carbonyl);
+        algorithm.set_target(molecule);
+
+        assert_eq!(algorithm.mappings(), vec![vec![1, 2]]);
+        assert_eq!(algorithm.target.edge(3, 1), Some(1));
+    }
+}
@@ -0,0 +1,447 @@
+use std::{collections::HashMap, hash::Hash};
+
+#[cfg(feature = "bellman_ford")]
+use crate::BellmanFordAlgorithm;
+#[cfg(feature = "dijkstra")]
+use crate::DijkstraAlgorithm;
+#[cfg(feature = "floyd_warshall")]
+use crate::FloydWarshallAlgorithm;
+#[cfg(any(
+    feature = "dijkstra",
+    feature = "bellman_ford",
+    feature = "floyd_warshall"
+))]
+use crate::Weight;
+
+/// Mapping between node identifiers of any type, such as airport codes or UUIDs, and the dense
+/// indices used by the algorithms. Indices are given in order of first insertion.
+#[derive(Debug, Clone)]
+pub struct NodeIndex<N> {
+    /// Identifier of each index.
+    ids: Vec<N>,
+
+    /// Index of each identifier.
+    indices: HashMap<N, usize>,
+}
+
+impl<N: Eq + Hash + Clone> Default for NodeIndex<N> {
+    /// Create a new default node index.
+    ///
+    /// # Returns
+    ///
+    /// New default node index.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Eq + Hash + Clone> FromIterator<N> for NodeIndex<N> {
+    /// Create a node index from identifiers, skipping repeated ones.
+    ///
+    /// # Arguments
+    ///
+    /// - `ids`: Node identifiers.
+    ///
+    /// # Returns
+    ///
+    /// New node index.
+    fn from_iter<I: IntoIterator<Item = N>>(ids: I) -> Self {
+        let mut index = Self::new();
+
+        for id in ids {
+            index.insert(id);
+        }
+
+        index
+    }
+}
+
+impl<N: Eq + Hash + Clone> NodeIndex<N> {
+    /// Create a new empty node index.
+    ///
+    /// # Returns
+    ///
+    /// New node index.
+    pub fn new() -> Self {
+        Self {
+            ids: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    /// Get the index of a node, adding the node if it is new.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: Node identifier.
+    ///
+    /// # Returns
+    ///
+    /// Index of the node.
+    pub fn insert(&mut self, id: N) -> usize {
+        if let Some(&index) = self.indices.get(&id) {
+            return index;
+        }
+
+        self.ids.push(id.clone());
+        self.indices.insert(id, self.ids.len() - 1);
+        self.ids.len() - 1
+    }
+
+    /// Get the index of a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: Node identifier.
+    ///
+    /// # Returns
+    ///
+    /// Index of the node, or `None` if it is unknown.
+    pub fn index(&self, id: &N) -> Option<usize> {
+        self.indices.get(id).copied()
+    }
+
+    /// Get the identifier of an index.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`: Node index.
+    ///
+    /// # Returns
+    ///
+    /// Identifier of the node, or `None` if the index is out of range.
+    pub fn id(&self, index: usize) -> Option<&N> {
+        self.ids.get(index)
+    }
+
+    /// Get the identifiers in index order.
+    ///
+    /// # Returns
+    ///
+    /// Identifier of each index.
+    pub fn ids(&self) -> &[N] {
+        &self.ids
+    }
+
+    /// Get the number of nodes.
+    ///
+    /// # Returns
+    ///
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Determine whether the index has no nodes.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no node was inserted.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+}
+
+/// Keyed Graph.
+/// Graph whose nodes are identified by any `Eq + Hash + Clone` type, kept with an internal index
+/// mapping so that the algorithms built from it run on dense indices and their results can be
+/// translated back to identifiers.
+#[derive(Debug, Clone)]
+pub struct KeyedGraph<N, W = usize> {
+    /// Mapping between node identifiers and indices.
+    pub nodes: NodeIndex<N>,
+
+    /// Directed edges between node indices as `(source, target, weight)`, in insertion order.
+    pub edges: Vec<(usize, usize, W)>,
+}
+
+impl<N: Eq + Hash + Clone, W> Default for KeyedGraph<N, W> {
+    /// Create a new default keyed graph.
+    ///
+    /// # Returns
+    ///
+    /// New default keyed graph.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Eq + Hash + Clone, W> KeyedGraph<N, W> {
+    /// Create a new empty keyed graph.
+    ///
+    /// # Returns
+    ///
+    /// New keyed graph.
+    pub fn new() -> Self {
+        Self {
+            nodes: NodeIndex::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// Add a node without edges.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: Node identifier.
+    ///
+    /// # Returns
+    ///
+    /// Index of the node.
+    pub fn add_node(&mut self, id: N) -> usize {
+        self.nodes.insert(id)
+    }
+
+    /// Add a single directed edge, adding its nodes if they are new.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn add_edge(&mut self, source: N, target: N, weight: W) {
+        let source = self.nodes.insert(source);
+        let target = self.nodes.insert(target);
+
+        self.edges.push((source, target, weight));
+    }
+
+    /// Add directed edges, adding their nodes if they are new.
+    ///
+    /// # Arguments
+    ///
+    /// - `edges`: Edges as `(source, target, weight)`.
+    pub fn add_weighted_edges(&mut self, edges: impl IntoIterator<Item = (N, N, W)>) {
+        for (source, target, weight) in edges {
+            self.add_edge(source, target, weight);
+        }
+    }
+
+    /// Get the total number of nodes.
+    ///
+    /// # Returns
+    ///
+    /// Number of nodes.
+    pub fn total_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Get the index of a node, to pass to the algorithms.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: Node identifier.
+    ///
+    /// # Returns
+    ///
+    /// Index of the node, or `None` if it is unknown.
+    pub fn index(&self, id: &N) -> Option<usize> {
+        self.nodes.index(id)
+    }
+
+    /// Translate node indices from the results of an algorithm, such as a path, to identifiers.
+    ///
+    /// # Arguments
+    ///
+    /// - `indices`: Node indices.
+    ///
+    /// # Returns
+    ///
+    /// Identifiers of the nodes, or `None` if an index is out of range.
+    pub fn labels(&self, indices: &[usize]) -> Option<Vec<N>> {
+        indices
+            .iter()
+            .map(|&index| self.nodes.id(index).cloned())
+            .collect()
+    }
+
+    /// Key values indexed by node, such as distances, by node identifier.
+    ///
+    /// # Arguments
+    ///
+    /// - `values`: Value of each node index.
+    ///
+    /// # Returns
+    ///
+    /// Value of each node by identifier.
+    pub fn by_label<T>(&self, values: impl IntoIterator<Item = T>) -> HashMap<N, T> {
+        self.nodes.ids().iter().cloned().zip(values).collect()
+    }
+}
+
+impl<N: Eq + Hash + Clone, W: Clone> KeyedGraph<N, W> {
+    /// Group the edges by source index, in the format accepted by `set_nodes` of the shortest path algorithms and `set_edges` of the others.
+    ///
+    /// # Returns
+    ///
+    /// Vector of tuples where each tuple contains a node index and its associated edges.
+    pub fn indexed_nodes(&self) -> Vec<(usize, Vec<(usize, W)>)> {
+        let mut nodes: Vec<(usize, Vec<(usize, W)>)> = (0..self.total_nodes())
+            .map(|node| (node, Vec::new()))
+            .collect();
+
+        for (source, target, weight) in &self.edges {
+            nodes[*source].1.push((*target, weight.clone()));
+        }
+
+        nodes
+    }
+}
+
+#[cfg(feature = "dijkstra")]
+impl<N: Eq + Hash + Clone, W: Weight> KeyedGraph<N, W> {
+    /// Build Dijkstra's Algorithm on the node indices.
+    ///
+    /// # Returns
+    ///
+    /// Dijkstra's Algorithm with every node and edge of the graph.
+    pub fn dijkstra(&self) -> DijkstraAlgorithm<W> {
+        let mut algorithm = DijkstraAlgorithm::with_weight_type();
+        algorithm.set_total_nodes(self.total_nodes());
+        algorithm.add_weighted_edges(self.edges.iter().copied());
+
+        algorithm
+    }
+
+    /// Find a shortest path between two nodes by identifier with Dijkstra's Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Cost and identifiers of the nodes of the path, or `None` if a node is unknown, the target is
+    /// unreachable or the search reaches a negative weight.
+    pub fn shortest_path(&self, source: &N, target: &N) -> Option<(W, Vec<N>)> {
+        let (cost, path) = self
+            .dijkstra()
+            .run_to_target(self.index(source)?, self.index(target)?)
+            .ok()?;
+
+        Some((cost, self.labels(&path)?))
+    }
+}
+
+#[cfg(feature = "bellman_ford")]
+impl<N: Eq + Hash + Clone, W: Weight> KeyedGraph<N, W> {
+    /// Build the Bellman-Ford Algorithm on the node indices.
+    ///
+    /// # Returns
+    ///
+    /// Bellman-Ford Algorithm with every node and edge of the graph.
+    pub fn bellman_ford(&self) -> BellmanFordAlgorithm<W> {
+        let mut algorithm = BellmanFordAlgorithm::with_weight_type();
+        algorithm.set_total_nodes(self.total_nodes());
+        algorithm.add_weighted_edges(self.edges.iter().copied());
+
+        algorithm
+    }
+}
+
+#[cfg(feature = "floyd_warshall")]
+impl<N: Eq + Hash + Clone, W: Weight> KeyedGraph<N, W> {
+    /// Build the Floyd-Warshall Algorithm on the node indices.
+    ///
+    /// # Returns
+    ///
+    /// Floyd-Warshall Algorithm with every node and edge of the graph.
+    pub fn floyd_warshall(&self) -> FloydWarshallAlgorithm<W> {
+        let mut algorithm = FloydWarshallAlgorithm::with_weight_type();
+        algorithm.set_total_nodes(self.total_nodes());
+        algorithm.add_weighted_edges(self.edges.iter().copied());
+
+        algorithm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GraphAlgorithm;
+
+    fn airports() -> KeyedGraph<&'static str> {
+        let mut graph = KeyedGraph::new();
+        graph.add_weighted_edges([
+            ("JFK", "LHR", 7),
+            ("LHR", "CDG", 1),
+            ("JFK", "CDG", 9),
+            ("CDG", "NRT", 12),
+        ]);
+        graph.add_node("SYD");
+
+        graph
+    }
+
+    #[test]
+    fn test_node_index() {
+        let mut index: NodeIndex<String> = ["a", "b", "a"].map(String::from).into_iter().collect();
+
+        assert_eq!(index.len(), 2);
+        assert_eq!(index.insert("c".to_string()), 2);
+        assert_eq!(index.insert("a".to_string()), 0);
+        assert_eq!(index.index(&"b".to_string()), Some(1));
+        assert_eq!(index.index(&"z".to_string()), None);
+        assert_eq!(index.id(2).map(String::as_str), Some("c"));
+        assert_eq!(index.id(3), None);
+        assert!(!index.is_empty());
+        assert!(NodeIndex::<u64>::default().is_empty());
+    }
+
+    #[test]
+    fn test_keyed_graph() {
+        let graph = airports();
+
+        assert_eq!(graph.total_nodes(), 5);
+        assert_eq!(graph.index(&"SYD"), Some(4));
+        assert_eq!(graph.labels(&[0, 2]), Some(vec!["JFK", "CDG"]));
+        assert_eq!(graph.labels(&[0, 2, 9]), None);
+        assert_eq!(graph.labels(&[]), Some(vec![]));
+        assert_eq!(graph.indexed_nodes()[0], (0, vec![(1, 7), (2, 9)]));
+        assert!(KeyedGraph::<u32, i32>::default().edges.is_empty());
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let graph = airports();
+        let distances = graph.by_label(graph.dijkstra().run(Some(0)).unwrap());
+
+        assert_eq!(distances["NRT"], 20);
+        assert_eq!(distances["SYD"], usize::MAX);
+        assert_eq!(
+            graph.shortest_path(&"JFK", &"NRT"),
+            Some((20, vec!["JFK", "LHR", "CDG", "NRT"]))
+        );
+        assert_eq!(graph.shortest_path(&"JFK", &"SYD"), None);
+        assert_eq!(graph.shortest_path(&"JFK", &"BOS"), None);
+
+        let mut graph: KeyedGraph<char, i64> = KeyedGraph::new();
+        graph.add_weighted_edges([('a', 'b', 1), ('b', 'c', -3), ('c', 'b', 1)]);
+
+        assert_eq!(graph.shortest_path(&'a', &'c'), None);
+    }
+
+    #[test]
+    fn test_bellman_ford_and_floyd_warshall() {
+        let mut graph: KeyedGraph<String, i32> = KeyedGraph::new();
+        graph.add_edge("a".to_string(), "b".to_string(), 4);
+        graph.add_edge("b".to_string(), "c".to_string(), -2);
+        graph.add_edge("a".to_string(), "c".to_string(), 3);
+
+        let paths = graph.bellman_ford().run_with_predecessors(Some(0)).unwrap();
+
+        assert_eq!(
+            graph.labels(&paths.path_to(2).unwrap()),
+            Some(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+        );
+        assert_eq!(
+            graph
+                .floyd_warshall()
+                .shortest_paths()
+                .unwrap()
+                .distance(0, 2),
+            Some(2)
+        );
+    }
+}
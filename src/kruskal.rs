@@ -0,0 +1,248 @@
+use crate::{
+    disjoint_set::DisjointSet, DiGraph, GraphAlgorithm, GraphError, MemoryEstimate,
+    MemoryEstimation, MstAlgorithm,
+};
+
+/// Kruskal's Algorithm.
+/// Find a minimum spanning forest of an undirected weighted graph by adding the lightest edges that do not form a cycle.
+#[derive(Debug, Clone)]
+pub struct KruskalAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Edges in the graph.
+    pub edges: Vec<(usize, usize, usize)>,
+}
+
+impl Default for KruskalAlgorithm {
+    /// Create a new default instance of Kruskal's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of Kruskal's Algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KruskalAlgorithm {
+    /// Create a new instance of Kruskal's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of Kruskal's Algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            edges: Vec::new(),
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.edges.push((source, target, weight));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+    }
+}
+
+impl From<&DiGraph<usize>> for KruskalAlgorithm {
+    /// Create an instance of Kruskal's Algorithm from a shared graph, copying its edges and nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `graph`: Graph whose edges are read as undirected edges.
+    ///
+    /// # Returns
+    ///
+    /// New instance of Kruskal's Algorithm with the edges of the graph.
+    fn from(graph: &DiGraph<usize>) -> Self {
+        let mut algorithm = Self::new();
+
+        for (source, target, weight) in graph.edges() {
+            algorithm.set_edge(source, target, weight);
+        }
+
+        algorithm.set_total_nodes(graph.total_nodes());
+        algorithm
+    }
+}
+
+impl MstAlgorithm for KruskalAlgorithm {
+    /// Compute the minimum spanning forest with Kruskal's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Edges of the minimum spanning forest.
+    fn minimum_spanning_tree(&self) -> Vec<(usize, usize, usize)> {
+        let mut edges = self.edges.clone();
+        edges.sort_by_key(|&(_, _, weight)| weight);
+
+        let mut components = DisjointSet::new(self.total_nodes);
+        let mut tree = Vec::new();
+
+        for (source, target, weight) in edges {
+            if components.union(source, target) {
+                tree.push((source, target, weight));
+            }
+        }
+
+        tree
+    }
+}
+
+impl GraphAlgorithm for KruskalAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<(usize, usize, usize)>;
+
+    /// Run Kruskal's Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in Kruskal's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the edges of the minimum spanning forest.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.minimum_spanning_tree())
+    }
+}
+
+impl MemoryEstimation for KruskalAlgorithm {
+    /// Estimate the memory of Kruskal's Algorithm, which sorts a copy of the edges and keeps a
+    /// parent and a rank per node.
+    ///
+    /// # Returns
+    ///
+    /// Memory estimate of a run.
+    fn estimate_memory(&self) -> MemoryEstimate {
+        let edges = MemoryEstimate::bytes::<(usize, usize, usize)>(self.edges.len());
+
+        MemoryEstimate::new(
+            edges,
+            edges.saturating_add(MemoryEstimate::bytes::<usize>(
+                self.total_nodes.saturating_mul(2),
+            )),
+            MemoryEstimate::bytes::<(usize, usize, usize)>(self.total_nodes.saturating_sub(1)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Graph;
+
+    #[test]
+    fn test_new() {
+        let algorithm = KruskalAlgorithm::new();
+        let algorithm_default = KruskalAlgorithm::default();
+
+        assert_eq!(algorithm.edges.len(), 0);
+        assert_eq!(algorithm_default.edges.len(), 0);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = KruskalAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 7), (3, 5)]),
+            (1, vec![(2, 8), (3, 9), (4, 7)]),
+            (2, vec![(4, 5)]),
+            (3, vec![(4, 15), (5, 6)]),
+            (4, vec![(5, 8), (6, 9)]),
+            (5, vec![(6, 11)]),
+        ]);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.len(), 6);
+        assert_eq!(algorithm.total_weight(), 39);
+    }
+
+    #[test]
+    fn test_run_empty_graph() {
+        let algorithm = KruskalAlgorithm::new();
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_run_disconnected_graph() {
+        let mut algorithm = KruskalAlgorithm::new();
+        algorithm.set_edge(0, 1, 2);
+        algorithm.set_edge(2, 3, 1);
+        algorithm.set_total_nodes(5);
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![(2, 3, 1), (0, 1, 2)]);
+    }
+
+    #[test]
+    fn test_run_parallel_edges_and_self_loops() {
+        let mut algorithm = KruskalAlgorithm::new();
+        algorithm.set_edge(0, 0, 0);
+        algorithm.set_edge(0, 1, 5);
+        algorithm.set_edge(1, 0, 3);
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![(1, 0, 3)]);
+    }
+
+    #[test]
+    fn test_estimate_memory() {
+        let mut algorithm = KruskalAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 7), (2, 5)]), (1, vec![(2, 8)])]);
+
+        let estimate = algorithm.estimate_memory();
+
+        assert_eq!(estimate.input, 3 * 24);
+        assert_eq!(estimate.output, 2 * 24);
+    }
+
+    #[test]
+    fn test_from_graph() {
+        let mut graph = DiGraph::new();
+        graph.add_weighted_edges([(0, 1, 7), (0, 2, 5), (1, 2, 8)]);
+        graph.set_total_nodes(4);
+
+        let algorithm = KruskalAlgorithm::from(&graph);
+
+        assert_eq!(algorithm.total_nodes, 4);
+        assert_eq!(algorithm.run(None).unwrap(), vec![(0, 2, 5), (0, 1, 7)]);
+
+        let mut graph = Graph::new();
+        graph.add_weighted_edges([(0, 1, 7), (0, 2, 5), (1, 2, 8)]);
+
+        assert_eq!(KruskalAlgorithm::from(&*graph).total_weight(), 12);
+    }
+}
@@ -2,6 +2,20 @@
 
 use std::{error::Error, fmt};
 
+mod disjoint_set;
+mod priority_queue;
+mod random;
+mod scc;
+
+pub mod events;
+pub use events::*;
+
+pub mod weight;
+pub use weight::*;
+
+pub mod graph;
+pub use graph::*;
+
 #[cfg(feature = "bellman_ford")]
 pub mod bellman_ford;
 pub use bellman_ford::*;
@@ -14,6 +28,310 @@ pub use dijkstra::*;
 pub mod floyd_warshall;
 pub use floyd_warshall::*;
 
+#[cfg(feature = "od_matrix")]
+pub mod od_matrix;
+pub use od_matrix::*;
+
+#[cfg(feature = "kruskal")]
+pub mod kruskal;
+pub use kruskal::*;
+
+#[cfg(feature = "prim")]
+pub mod prim;
+pub use prim::*;
+
+#[cfg(feature = "boruvka")]
+pub mod boruvka;
+pub use boruvka::*;
+
+#[cfg(feature = "simplification")]
+pub mod simplification;
+pub use simplification::*;
+
+#[cfg(feature = "strahler")]
+pub mod strahler;
+pub use strahler::*;
+
+#[cfg(feature = "topological_sort")]
+pub mod topological_sort;
+pub use topological_sort::*;
+
+#[cfg(feature = "series_parallel")]
+pub mod series_parallel;
+pub use series_parallel::*;
+
+#[cfg(feature = "chordal")]
+pub mod chordal;
+pub use chordal::*;
+
+#[cfg(feature = "treewidth")]
+pub mod treewidth;
+pub use treewidth::*;
+
+#[cfg(feature = "tree_decomposition_dp")]
+pub mod tree_decomposition_dp;
+pub use tree_decomposition_dp::*;
+
+#[cfg(feature = "max_flow")]
+pub mod max_flow;
+pub use max_flow::*;
+
+#[cfg(feature = "properties")]
+pub mod properties;
+pub use properties::*;
+
+#[cfg(feature = "display")]
+pub mod display;
+pub use display::*;
+
+#[cfg(feature = "loader")]
+pub mod loader;
+pub use loader::*;
+
+#[cfg(feature = "matching")]
+pub mod matching;
+pub use matching::*;
+
+#[cfg(feature = "morphism")]
+pub mod morphism;
+pub use morphism::*;
+
+#[cfg(feature = "bipartite")]
+pub mod bipartite;
+pub use bipartite::*;
+
+#[cfg(feature = "constrained_path")]
+pub mod constrained_path;
+pub use constrained_path::*;
+
+#[cfg(feature = "hypergraph")]
+pub mod hypergraph;
+pub use hypergraph::*;
+
+#[cfg(feature = "signed")]
+pub mod signed;
+pub use signed::*;
+
+#[cfg(feature = "approximation")]
+pub mod approximation;
+pub use approximation::*;
+
+#[cfg(feature = "rewiring")]
+pub mod rewiring;
+pub use rewiring::*;
+
+#[cfg(feature = "eulerian")]
+pub mod eulerian;
+pub use eulerian::*;
+
+#[cfg(feature = "sbm")]
+pub mod sbm;
+pub use sbm::*;
+
+#[cfg(feature = "spectral")]
+pub mod spectral;
+pub use spectral::*;
+
+#[cfg(feature = "held_karp")]
+pub mod held_karp;
+pub use held_karp::*;
+
+#[cfg(feature = "embeddings")]
+pub mod embeddings;
+#[cfg(feature = "embeddings")]
+pub use embeddings::*;
+
+#[cfg(feature = "tsp")]
+pub mod tsp;
+pub use tsp::*;
+
+#[cfg(feature = "ann")]
+pub mod ann;
+pub use ann::*;
+
+#[cfg(feature = "chinese_postman")]
+pub mod chinese_postman;
+pub use chinese_postman::*;
+
+#[cfg(feature = "bloom_reachability")]
+pub mod bloom_reachability;
+pub use bloom_reachability::*;
+
+#[cfg(feature = "pagerank")]
+pub mod pagerank;
+pub use pagerank::*;
+
+#[cfg(feature = "interval_reachability")]
+pub mod interval_reachability;
+pub use interval_reachability::*;
+
+#[cfg(feature = "dag_lca")]
+pub mod dag_lca;
+pub use dag_lca::*;
+
+#[cfg(feature = "betweenness")]
+pub mod betweenness;
+pub use betweenness::*;
+
+#[cfg(feature = "dependency_resolver")]
+pub mod dependency_resolver;
+pub use dependency_resolver::*;
+
+#[cfg(feature = "deadlock")]
+pub mod deadlock;
+pub use deadlock::*;
+
+#[cfg(feature = "centrality")]
+pub mod centrality;
+pub use centrality::*;
+
+#[cfg(feature = "feedback_vertex_set")]
+pub mod feedback_vertex_set;
+pub use feedback_vertex_set::*;
+
+#[cfg(feature = "edit_graph")]
+pub mod edit_graph;
+pub use edit_graph::*;
+
+#[cfg(feature = "girvan_newman")]
+pub mod girvan_newman;
+pub use girvan_newman::*;
+
+#[cfg(feature = "triangles")]
+pub mod triangles;
+pub use triangles::*;
+
+#[cfg(feature = "eccentricity")]
+pub mod eccentricity;
+pub use eccentricity::*;
+
+#[cfg(feature = "analysis")]
+pub mod analysis;
+pub use analysis::*;
+
+#[cfg(feature = "transitive_closure")]
+pub mod transitive_closure;
+pub use transitive_closure::*;
+
+#[cfg(feature = "energy_routing")]
+pub mod energy_routing;
+pub use energy_routing::*;
+
+#[cfg(feature = "terrain")]
+pub mod terrain;
+pub use terrain::*;
+
+#[cfg(feature = "cycles")]
+pub mod cycles;
+pub use cycles::*;
+
+#[cfg(feature = "girth")]
+pub mod girth;
+pub use girth::*;
+
+#[cfg(feature = "transit")]
+pub mod transit;
+pub use transit::*;
+
+#[cfg(feature = "tree_lca")]
+pub mod tree_lca;
+pub use tree_lca::*;
+
+#[cfg(feature = "round_trip")]
+pub mod round_trip;
+pub use round_trip::*;
+
+#[cfg(feature = "tree")]
+pub mod tree;
+pub use tree::*;
+
+#[cfg(feature = "corpus")]
+pub mod corpus;
+pub use corpus::*;
+
+#[cfg(feature = "golden")]
+pub mod golden;
+#[cfg(feature = "golden")]
+pub use golden::*;
+
+#[cfg(feature = "generators")]
+pub mod generators;
+pub use generators::*;
+
+#[cfg(feature = "grid")]
+pub mod grid;
+pub use grid::*;
+
+#[cfg(feature = "geometric")]
+pub mod geometric;
+pub use geometric::*;
+
+#[cfg(feature = "isomorphism")]
+pub mod isomorphism;
+pub use isomorphism::*;
+
+#[cfg(feature = "random_walk")]
+pub mod random_walk;
+pub use random_walk::*;
+
+#[cfg(feature = "ida_star")]
+pub mod ida_star;
+pub use ida_star::*;
+
+#[cfg(feature = "astar")]
+pub mod astar;
+pub use astar::*;
+
+#[cfg(feature = "interval_scheduling")]
+pub mod interval_scheduling;
+pub use interval_scheduling::*;
+
+#[cfg(feature = "contraction_hierarchies")]
+pub mod contraction_hierarchies;
+pub use contraction_hierarchies::*;
+
+#[cfg(feature = "densest_subgraph")]
+pub mod densest_subgraph;
+pub use densest_subgraph::*;
+
+#[cfg(feature = "alt")]
+pub mod alt;
+pub use alt::*;
+
+#[cfg(feature = "algebra")]
+pub mod algebra;
+pub use algebra::*;
+
+#[cfg(feature = "path_count")]
+pub mod path_count;
+pub use path_count::*;
+
+#[cfg(feature = "all_shortest_paths")]
+pub mod all_shortest_paths;
+pub use all_shortest_paths::*;
+
+#[cfg(feature = "budgeted_apsp")]
+pub mod budgeted_apsp;
+pub use budgeted_apsp::*;
+
+#[cfg(feature = "router")]
+pub mod router;
+pub use router::*;
+
+#[cfg(feature = "keyed")]
+pub mod keyed;
+pub use keyed::*;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "arrow")]
+pub use arrow::*;
+
+#[cfg(any(feature = "sql", feature = "neo4j"))]
+pub mod connectors;
+#[cfg(any(feature = "sql", feature = "neo4j"))]
+pub use connectors::*;
+
 /// Error type for graph algorithms.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GraphError {
@@ -22,6 +340,60 @@ pub enum GraphError {
 
     /// Graph does not contain a start node.
     MissingStartNode,
+
+    /// Graph does not contain a target node.
+    MissingTargetNode,
+
+    /// Iterative algorithm did not converge within the maximum number of iterations.
+    NoConvergence,
+
+    /// Graph contains a cycle where an acyclic graph is required.
+    CycleDetected,
+
+    /// Graph is not series-parallel between its terminals.
+    NotSeriesParallel,
+
+    /// Graph is not chordal.
+    NotChordal,
+
+    /// Node mapping assigns the same ID to several nodes.
+    InvalidMapping,
+
+    /// Graph is not bipartite.
+    NotBipartite,
+
+    /// Signed graph is not structurally balanced.
+    NotBalanced,
+
+    /// Graph has no Eulerian trail, because of its degrees or disconnected edges.
+    NotEulerian,
+
+    /// No solution satisfies the constraints of the problem.
+    Infeasible,
+
+    /// Graph is not a tree.
+    NotTree,
+
+    /// Edge refers to a node outside the graph.
+    NodeOutOfRange(usize),
+
+    /// Path weights exceed the range of the weight type.
+    WeightOverflow,
+
+    /// Edge between two nodes has a negative weight where only non-negative weights are supported.
+    NegativeWeight(usize, usize),
+
+    /// Matrix or vector dimensions do not match the operation.
+    DimensionMismatch,
+
+    /// Estimated memory of a run, in bytes, exceeds the allowed limit.
+    MemoryLimitExceeded(usize),
+
+    /// Pair of nodes, such as a pickup and its delivery, repeats a node within itself or another pair.
+    InvalidPair(usize, usize),
+
+    /// Graph has more nodes than the algorithm supports, the limit given.
+    TooManyNodes(usize),
 }
 
 impl Error for GraphError {}
@@ -59,6 +431,270 @@ pub trait GraphAlgorithm {
     ///
     /// Result containing the weight of the shortest path, or an error.
     fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError>;
+
+    /// Check that the input can be processed without panicking.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node, if applicable.
+    ///
+    /// # Returns
+    ///
+    /// Result containing nothing, or an error describing the malformed input.
+    fn validate(&self, _start: Option<&Self::Node>) -> Result<(), GraphError> {
+        Ok(())
+    }
+
+    /// Run the graph algorithm after validating its input, returning an error instead of panicking
+    /// on malformed graphs, such as edges to unknown nodes or weights that would overflow.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node, if applicable.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the result of the algorithm, or an error.
+    fn try_run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        self.validate(start.as_ref())?;
+        self.run(start)
+    }
+}
+
+/// Configuration an algorithm ran with, recorded next to its results for provenance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Provenance {
+    /// Name of the algorithm, as its feature.
+    pub algorithm: &'static str,
+
+    /// Version of the crate.
+    pub version: &'static str,
+
+    /// Options in effect as `(name, value)`, such as strategies, heuristics, cutoffs and seeds.
+    pub configuration: Vec<(&'static str, String)>,
+}
+
+impl Provenance {
+    /// Create a new provenance without options.
+    ///
+    /// # Arguments
+    ///
+    /// - `algorithm`: Name of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New provenance of the current crate version.
+    pub fn new(algorithm: &'static str) -> Self {
+        Self {
+            algorithm,
+            version: env!("CARGO_PKG_VERSION"),
+            configuration: Vec::new(),
+        }
+    }
+
+    /// Add an option.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: Name of the option.
+    /// - `value`: Value of the option, recorded in its `Debug` representation.
+    ///
+    /// # Returns
+    ///
+    /// Provenance with the option.
+    pub fn with(mut self, name: &'static str, value: impl fmt::Debug) -> Self {
+        self.configuration.push((name, format!("{value:?}")));
+        self
+    }
+}
+
+impl fmt::Display for Provenance {
+    /// Display the provenance as `algorithm version name=value ...`.
+    ///
+    /// # Arguments
+    ///
+    /// - `f`: Formatter.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the formatted provenance.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.algorithm, self.version)?;
+
+        for (name, value) in &self.configuration {
+            write!(f, " {name}={value}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Result of an algorithm with the configuration that produced it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recorded<T> {
+    /// Result of the algorithm.
+    pub value: T,
+
+    /// Configuration the algorithm ran with.
+    pub provenance: Provenance,
+}
+
+/// A trait for algorithms whose results depend on options beyond the graph.
+pub trait ConfiguredAlgorithm: GraphAlgorithm {
+    /// Describe the configuration of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance;
+
+    /// Run the algorithm and record its configuration with the result.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node, if applicable.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the result with its provenance, or an error.
+    fn run_recorded(
+        &self,
+        start: Option<Self::Node>,
+    ) -> Result<Recorded<Self::Weight>, GraphError> {
+        Ok(Recorded {
+            value: self.run(start)?,
+            provenance: self.provenance(),
+        })
+    }
+}
+
+/// Memory a run is expected to allocate, in bytes, counting the elements of its collections
+/// without allocator overhead or spare capacity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryEstimate {
+    /// Bytes held by the graph and options of the algorithm.
+    pub input: usize,
+
+    /// Peak bytes of the working state, such as distances, queues and tables.
+    pub working: usize,
+
+    /// Bytes of the result.
+    pub output: usize,
+}
+
+impl MemoryEstimate {
+    /// Create a new memory estimate.
+    ///
+    /// # Arguments
+    ///
+    /// - `input`: Bytes held by the input.
+    /// - `working`: Peak bytes of the working state.
+    /// - `output`: Bytes of the result.
+    ///
+    /// # Returns
+    ///
+    /// New memory estimate.
+    pub fn new(input: usize, working: usize, output: usize) -> Self {
+        Self {
+            input,
+            working,
+            output,
+        }
+    }
+
+    /// Compute the total memory of a run, saturating at `usize::MAX`.
+    ///
+    /// # Returns
+    ///
+    /// Sum of the input, working and output bytes.
+    pub fn total(&self) -> usize {
+        self.input
+            .saturating_add(self.working)
+            .saturating_add(self.output)
+    }
+
+    /// Determine whether a run stays within a limit.
+    ///
+    /// # Arguments
+    ///
+    /// - `limit`: Maximum number of bytes.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the total memory does not exceed the limit.
+    pub fn fits(&self, limit: usize) -> bool {
+        self.total() <= limit
+    }
+
+    /// Compute the bytes of a number of values, saturating at `usize::MAX`.
+    ///
+    /// # Arguments
+    ///
+    /// - `count`: Number of values.
+    ///
+    /// # Returns
+    ///
+    /// Bytes of the values.
+    pub fn bytes<T>(count: usize) -> usize {
+        count.saturating_mul(std::mem::size_of::<T>())
+    }
+}
+
+/// A trait for algorithms that can estimate their memory before running, so that oversized jobs
+/// are rejected before allocating.
+pub trait MemoryEstimation: GraphAlgorithm {
+    /// Estimate the memory of a run from the size of the input.
+    ///
+    /// # Returns
+    ///
+    /// Memory estimate of a run.
+    fn estimate_memory(&self) -> MemoryEstimate;
+
+    /// Run the algorithm after validating its input, unless its estimated memory exceeds a limit.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node, if applicable.
+    /// - `limit`: Maximum number of bytes.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the result of the algorithm, or an error.
+    fn run_within(
+        &self,
+        start: Option<Self::Node>,
+        limit: usize,
+    ) -> Result<Self::Weight, GraphError> {
+        let estimate = self.estimate_memory();
+
+        if !estimate.fits(limit) {
+            return Err(GraphError::MemoryLimitExceeded(estimate.total()));
+        }
+
+        self.try_run(start)
+    }
+}
+
+/// A trait for minimum spanning tree algorithms.
+pub trait MstAlgorithm {
+    /// Compute the minimum spanning tree, or a minimum spanning forest if the graph is disconnected.
+    ///
+    /// # Returns
+    ///
+    /// Edges of the minimum spanning forest as `(source, target, weight)`.
+    fn minimum_spanning_tree(&self) -> Vec<(usize, usize, usize)>;
+
+    /// Compute the total weight of the minimum spanning tree.
+    ///
+    /// # Returns
+    ///
+    /// Sum of the weights of the minimum spanning forest.
+    fn total_weight(&self) -> usize {
+        self.minimum_spanning_tree()
+            .iter()
+            .map(|&(_, _, weight)| weight)
+            .sum()
+    }
 }
 
 #[cfg(test)]
@@ -76,5 +712,86 @@ mod tests {
             format!("{}", GraphError::MissingStartNode),
             "MissingStartNode"
         );
+
+        assert_eq!(
+            format!("{}", GraphError::MissingTargetNode),
+            "MissingTargetNode"
+        );
+
+        assert_eq!(format!("{}", GraphError::NoConvergence), "NoConvergence");
+
+        assert_eq!(format!("{}", GraphError::CycleDetected), "CycleDetected");
+
+        assert_eq!(
+            format!("{}", GraphError::NotSeriesParallel),
+            "NotSeriesParallel"
+        );
+
+        assert_eq!(format!("{}", GraphError::NotChordal), "NotChordal");
+
+        assert_eq!(format!("{}", GraphError::InvalidMapping), "InvalidMapping");
+
+        assert_eq!(format!("{}", GraphError::NotBipartite), "NotBipartite");
+
+        assert_eq!(format!("{}", GraphError::NotBalanced), "NotBalanced");
+
+        assert_eq!(format!("{}", GraphError::NotEulerian), "NotEulerian");
+
+        assert_eq!(format!("{}", GraphError::Infeasible), "Infeasible");
+
+        assert_eq!(format!("{}", GraphError::NotTree), "NotTree");
+
+        assert_eq!(
+            format!("{}", GraphError::NodeOutOfRange(3)),
+            "NodeOutOfRange(3)"
+        );
+
+        assert_eq!(format!("{}", GraphError::WeightOverflow), "WeightOverflow");
+
+        assert_eq!(
+            format!("{}", GraphError::DimensionMismatch),
+            "DimensionMismatch"
+        );
+
+        assert_eq!(
+            format!("{}", GraphError::MemoryLimitExceeded(64)),
+            "MemoryLimitExceeded(64)"
+        );
+
+        assert_eq!(
+            format!("{}", GraphError::InvalidPair(1, 1)),
+            "InvalidPair(1, 1)"
+        );
+
+        assert_eq!(
+            format!("{}", GraphError::TooManyNodes(24)),
+            "TooManyNodes(24)"
+        );
+    }
+
+    #[test]
+    fn test_memory_estimate() {
+        let estimate = MemoryEstimate::new(16, MemoryEstimate::bytes::<u32>(4), usize::MAX);
+
+        assert_eq!(estimate.working, 16);
+        assert_eq!(estimate.total(), usize::MAX);
+        assert!(!estimate.fits(1 << 30));
+        assert!(MemoryEstimate::default().fits(0));
+    }
+
+    #[test]
+    fn test_provenance() {
+        let provenance = Provenance::new("example")
+            .with("seed", 7)
+            .with("heuristic", "fill");
+
+        assert_eq!(provenance.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(
+            provenance.to_string(),
+            format!(
+                "example {} seed=7 heuristic=\"fill\"",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
     }
 }
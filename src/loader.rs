@@ -0,0 +1,320 @@
+use std::collections::BTreeMap;
+
+/// Error of a single edge record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordError {
+    /// Record could not be parsed as `source target [weight]`.
+    Parse(String),
+
+    /// Node is outside of the allowed range.
+    BadNode(usize),
+
+    /// Weight is negative while negative weights are forbidden.
+    NegativeWeight(i32),
+}
+
+/// Report of a bulk load.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LoadReport {
+    /// Number of records loaded into the graph.
+    pub loaded: usize,
+
+    /// Rejected records as `(record index, error)`.
+    pub errors: Vec<(usize, RecordError)>,
+}
+
+impl LoadReport {
+    /// Determine whether every record was loaded.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no record was rejected.
+    pub fn is_clean(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Graph loaded from the valid records.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LoadedGraph {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Valid edges as `(source, target, weight)`, in record order.
+    pub edges: Vec<(usize, usize, i32)>,
+
+    /// Diagnostics of the load.
+    pub report: LoadReport,
+}
+
+impl LoadedGraph {
+    /// Group the edges by source node, in the format accepted by `set_nodes` of the shortest path algorithms and `set_edges` of the others.
+    ///
+    /// # Returns
+    ///
+    /// Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn nodes(&self) -> Vec<(usize, Vec<(usize, i32)>)> {
+        let mut nodes: BTreeMap<usize, Vec<(usize, i32)>> = BTreeMap::new();
+
+        for &(source, target, weight) in &self.edges {
+            nodes.entry(source).or_default().push((target, weight));
+        }
+
+        nodes.into_iter().collect()
+    }
+}
+
+/// Bulk Loader.
+/// Load edge records such as `"0 1 5"` or `"0,1,5"`, keeping the valid ones and reporting the rest instead of aborting.
+#[derive(Debug, Clone)]
+pub struct BulkLoader {
+    /// Maximum number of nodes, records referencing other nodes are rejected.
+    pub max_nodes: Option<usize>,
+
+    /// Whether negative weights are accepted.
+    pub allow_negative_weights: bool,
+
+    /// Weight of records without an explicit weight.
+    pub default_weight: i32,
+}
+
+impl Default for BulkLoader {
+    /// Create a new default instance of the bulk loader.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the bulk loader.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BulkLoader {
+    /// Create a new instance of the bulk loader.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the bulk loader.
+    pub fn new() -> Self {
+        Self {
+            max_nodes: None,
+            allow_negative_weights: false,
+            default_weight: 1,
+        }
+    }
+
+    /// Set the maximum number of nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_nodes`: Maximum number of nodes, nodes must be lower than this value.
+    pub fn set_max_nodes(&mut self, max_nodes: usize) {
+        self.max_nodes = Some(max_nodes);
+    }
+
+    /// Set whether negative weights are accepted.
+    ///
+    /// # Arguments
+    ///
+    /// - `allow`: `true` to accept negative weights, e.g. for Bellman-Ford Algorithm.
+    pub fn set_allow_negative_weights(&mut self, allow: bool) {
+        self.allow_negative_weights = allow;
+    }
+
+    /// Set the weight of records without an explicit weight.
+    ///
+    /// # Arguments
+    ///
+    /// - `weight`: Default weight.
+    pub fn set_default_weight(&mut self, weight: i32) {
+        self.default_weight = weight;
+    }
+
+    /// Parse and validate a single record.
+    ///
+    /// # Arguments
+    ///
+    /// - `record`: Record to parse.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the edge, or the error of the record.
+    pub fn parse(&self, record: &str) -> Result<(usize, usize, i32), RecordError> {
+        let fields: Vec<&str> = record
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|field| !field.is_empty())
+            .collect();
+
+        let parse_error = || RecordError::Parse(record.to_string());
+
+        if fields.len() < 2 || fields.len() > 3 {
+            return Err(parse_error());
+        }
+
+        let source: usize = fields[0].parse().map_err(|_| parse_error())?;
+        let target: usize = fields[1].parse().map_err(|_| parse_error())?;
+        let weight: i32 = match fields.get(2) {
+            Some(field) => field.parse().map_err(|_| parse_error())?,
+            None => self.default_weight,
+        };
+
+        if let Some(max_nodes) = self.max_nodes {
+            if let Some(&node) = [source, target].iter().find(|&&node| node >= max_nodes) {
+                return Err(RecordError::BadNode(node));
+            }
+        }
+
+        if weight < 0 && !self.allow_negative_weights {
+            return Err(RecordError::NegativeWeight(weight));
+        }
+
+        Ok((source, target, weight))
+    }
+
+    /// Load a graph from edge records.
+    /// Blank records and records starting with `#` are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// - `records`: Iterator of records.
+    ///
+    /// # Returns
+    ///
+    /// Graph built from the valid records, with a report of the rejected ones.
+    pub fn load<I, S>(&self, records: I) -> LoadedGraph
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut graph = LoadedGraph::default();
+
+        for (index, record) in records.into_iter().enumerate() {
+            let record = record.as_ref().trim();
+
+            if record.is_empty() || record.starts_with('#') {
+                continue;
+            }
+
+            match self.parse(record) {
+                Ok((source, target, weight)) => {
+                    graph.edges.push((source, target, weight));
+                    graph.total_nodes = graph.total_nodes.max(source + 1).max(target + 1);
+                    graph.report.loaded += 1;
+                }
+                Err(error) => graph.report.errors.push((index, error)),
+            }
+        }
+
+        graph
+    }
+
+    /// Load a graph from typed rows, such as the result of a database query.
+    /// Rows are validated like records, so negative nodes and out-of-range weights are rejected.
+    ///
+    /// # Arguments
+    ///
+    /// - `rows`: Iterator of rows as `(source, target, weight)`, without weight for the default one.
+    ///
+    /// # Returns
+    ///
+    /// Graph built from the valid rows, with a report of the rejected ones.
+    pub fn load_rows<I>(&self, rows: I) -> LoadedGraph
+    where
+        I: IntoIterator<Item = (i64, i64, Option<i64>)>,
+    {
+        self.load(
+            rows.into_iter()
+                .map(|(source, target, weight)| match weight {
+                    Some(weight) => format!("{source} {target} {weight}"),
+                    None => format!("{source} {target}"),
+                }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let loader = BulkLoader::new();
+        let loader_default = BulkLoader::default();
+
+        assert_eq!(loader.max_nodes, None);
+        assert!(!loader_default.allow_negative_weights);
+    }
+
+    #[test]
+    fn test_load() {
+        let mut loader = BulkLoader::new();
+        loader.set_max_nodes(4);
+
+        let graph = loader.load([
+            "# source target weight",
+            "0 1 5",
+            "0,2,3",
+            "1 two 4",
+            "",
+            "2 7 1",
+            "2 3 -1",
+            "3 0",
+            "1 2 3 4",
+        ]);
+
+        assert_eq!(graph.edges, vec![(0, 1, 5), (0, 2, 3), (3, 0, 1)]);
+        assert_eq!(graph.total_nodes, 4);
+        assert_eq!(graph.report.loaded, 3);
+        assert_eq!(
+            graph.report.errors,
+            vec![
+                (3, RecordError::Parse("1 two 4".to_string())),
+                (5, RecordError::BadNode(7)),
+                (6, RecordError::NegativeWeight(-1)),
+                (8, RecordError::Parse("1 2 3 4".to_string())),
+            ]
+        );
+        assert!(!graph.report.is_clean());
+    }
+
+    #[test]
+    fn test_load_negative_weights() {
+        let mut loader = BulkLoader::new();
+        loader.set_allow_negative_weights(true);
+        loader.set_default_weight(2);
+
+        let graph = loader.load(vec!["0 1 -3".to_string(), "1 0".to_string()]);
+
+        assert_eq!(graph.edges, vec![(0, 1, -3), (1, 0, 2)]);
+        assert!(graph.report.is_clean());
+    }
+
+    #[test]
+    fn test_nodes() {
+        let graph = BulkLoader::new().load(["1 2 4", "0 1 1", "1 0 2"]);
+
+        assert_eq!(
+            graph.nodes(),
+            vec![(0, vec![(1, 1)]), (1, vec![(2, 4), (0, 2)])]
+        );
+    }
+
+    #[test]
+    fn test_load_rows() {
+        let graph = BulkLoader::new().load_rows([
+            (0, 1, Some(5)),
+            (1, 2, None),
+            (-1, 2, Some(1)),
+            (2, 0, Some(i64::MAX)),
+        ]);
+
+        assert_eq!(graph.edges, vec![(0, 1, 5), (1, 2, 1)]);
+        assert_eq!(
+            graph.report.errors,
+            vec![
+                (2, RecordError::Parse("-1 2 1".to_string())),
+                (3, RecordError::Parse(format!("2 0 {}", i64::MAX))),
+            ]
+        );
+    }
+}
@@ -0,0 +1,1018 @@
+use crate::{ConfiguredAlgorithm, GraphAlgorithm, GraphError, Provenance};
+
+/// Marker of a missing vertex, edge endpoint or blossom.
+const NONE: usize = usize::MAX;
+
+/// Blossom Algorithm.
+/// Find a maximum matching of a general undirected graph with Edmonds' blossom algorithm,
+/// optionally maximizing the total weight of the matched edges.
+#[derive(Debug, Clone)]
+pub struct MatchingAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Edges in the graph.
+    pub edges: Vec<(usize, usize, usize)>,
+
+    /// Whether the maximum weight matching must also have maximum cardinality.
+    pub max_cardinality: bool,
+}
+
+impl Default for MatchingAlgorithm {
+    /// Create a new default instance of the Blossom Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the Blossom Algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MatchingAlgorithm {
+    /// Create a new instance of the Blossom Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the Blossom Algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            edges: Vec::new(),
+            max_cardinality: false,
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.edges.push((source, target, weight));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+    }
+
+    /// Set whether the maximum weight matching must also have maximum cardinality.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_cardinality`: `true` to only consider matchings of maximum cardinality.
+    pub fn set_max_cardinality(&mut self, max_cardinality: bool) {
+        self.max_cardinality = max_cardinality;
+    }
+
+    /// Compute a maximum cardinality matching, ignoring the weights.
+    ///
+    /// # Returns
+    ///
+    /// Matched pairs as `(node, mate)` with `node < mate`, sorted.
+    pub fn maximum_matching(&self) -> Vec<(usize, usize)> {
+        let edges = self.edges.iter().map(|&(s, t, _)| (s, t, 1)).collect();
+
+        Self::pairs(Blossom::new(self.total_nodes, edges).solve(true))
+    }
+
+    /// Compute a maximum weight matching.
+    ///
+    /// # Returns
+    ///
+    /// Matched pairs as `(node, mate)` with `node < mate`, sorted.
+    pub fn maximum_weight_matching(&self) -> Vec<(usize, usize)> {
+        let edges = self
+            .edges
+            .iter()
+            .map(|&(s, t, w)| (s, t, w as i64))
+            .collect();
+
+        Self::pairs(Blossom::new(self.total_nodes, edges).solve(self.max_cardinality))
+    }
+
+    /// Convert the mate of each node into matched pairs.
+    ///
+    /// # Arguments
+    ///
+    /// - `mates`: Mate of each node, `None` if the node is unmatched.
+    ///
+    /// # Returns
+    ///
+    /// Matched pairs as `(node, mate)` with `node < mate`, sorted.
+    fn pairs(mates: Vec<Option<usize>>) -> Vec<(usize, usize)> {
+        mates
+            .into_iter()
+            .enumerate()
+            .filter_map(|(node, mate)| mate.filter(|&mate| node < mate).map(|mate| (node, mate)))
+            .collect()
+    }
+}
+
+impl GraphAlgorithm for MatchingAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<(usize, usize)>;
+
+    /// Run the Blossom Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the Blossom Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the pairs of a maximum cardinality matching.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.maximum_matching())
+    }
+}
+
+impl ConfiguredAlgorithm for MatchingAlgorithm {
+    /// Describe the configuration of the blossom algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("matching").with("max_cardinality", self.max_cardinality)
+    }
+}
+
+/// State of the primal-dual blossom algorithm.
+///
+/// Vertices are numbered `0..n` and non-trivial blossoms `n..2n`.
+/// Edge `k` has the endpoints `2k` and `2k + 1`, so `p ^ 1` is the opposite endpoint of `p`.
+struct Blossom {
+    /// Number of vertices.
+    n: usize,
+
+    /// Edges with their weights.
+    edges: Vec<(usize, usize, i64)>,
+
+    /// Vertex of each endpoint.
+    endpoint: Vec<usize>,
+
+    /// Remote endpoints of the edges incident to each vertex.
+    neighbors: Vec<Vec<usize>>,
+
+    /// Remote endpoint of the matched edge of each vertex.
+    mate: Vec<usize>,
+
+    /// Label of each vertex and top-level blossom: 0 free, 1 S-vertex, 2 T-vertex, 5 while scanning.
+    label: Vec<u8>,
+
+    /// Endpoint through which each labeled vertex or blossom got its label.
+    label_end: Vec<usize>,
+
+    /// Top-level blossom containing each vertex.
+    in_blossom: Vec<usize>,
+
+    /// Parent blossom of each vertex or blossom.
+    parent: Vec<usize>,
+
+    /// Sub-blossoms of each blossom, in cycle order starting at the base.
+    children: Vec<Vec<usize>>,
+
+    /// Base vertex of each blossom.
+    base: Vec<usize>,
+
+    /// Endpoints of the edges connecting consecutive sub-blossoms.
+    endpoints: Vec<Vec<usize>>,
+
+    /// Least-slack edge to a different S-blossom, or to an S-vertex for free vertices.
+    best_edge: Vec<usize>,
+
+    /// Least-slack edges to neighboring S-blossoms of each S-blossom.
+    best_edges: Vec<Option<Vec<usize>>>,
+
+    /// Unused blossom indices.
+    unused: Vec<usize>,
+
+    /// Dual variables of vertices and blossoms.
+    dual: Vec<i64>,
+
+    /// Whether each edge has zero slack.
+    allowed: Vec<bool>,
+
+    /// S-vertices to scan.
+    queue: Vec<usize>,
+}
+
+impl Blossom {
+    /// Create the state of the blossom algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `n`: Number of vertices.
+    /// - `edges`: Edges with their weights.
+    ///
+    /// # Returns
+    ///
+    /// State with an empty matching.
+    fn new(n: usize, edges: Vec<(usize, usize, i64)>) -> Self {
+        let edges: Vec<(usize, usize, i64)> = edges.into_iter().filter(|e| e.0 != e.1).collect();
+        let max_weight = edges.iter().map(|e| e.2).max().unwrap_or(0).max(0);
+        let endpoint = (0..2 * edges.len())
+            .map(|p| match p % 2 {
+                0 => edges[p / 2].0,
+                _ => edges[p / 2].1,
+            })
+            .collect();
+
+        let mut neighbors = vec![Vec::new(); n];
+
+        for (k, &(i, j, _)) in edges.iter().enumerate() {
+            neighbors[i].push(2 * k + 1);
+            neighbors[j].push(2 * k);
+        }
+
+        Self {
+            n,
+            endpoint,
+            neighbors,
+            mate: vec![NONE; n],
+            label: vec![0; 2 * n],
+            label_end: vec![NONE; 2 * n],
+            in_blossom: (0..n).collect(),
+            parent: vec![NONE; 2 * n],
+            children: vec![Vec::new(); 2 * n],
+            base: (0..n).chain(std::iter::repeat(NONE).take(n)).collect(),
+            endpoints: vec![Vec::new(); 2 * n],
+            best_edge: vec![NONE; 2 * n],
+            best_edges: vec![None; 2 * n],
+            unused: (n..2 * n).collect(),
+            dual: [vec![max_weight; n], vec![0; n]].concat(),
+            allowed: vec![false; edges.len()],
+            queue: Vec::new(),
+            edges,
+        }
+    }
+
+    /// Compute the slack of an edge.
+    ///
+    /// # Arguments
+    ///
+    /// - `k`: Edge.
+    ///
+    /// # Returns
+    ///
+    /// Slack of the edge, zero for tight edges.
+    fn slack(&self, k: usize) -> i64 {
+        let (i, j, weight) = self.edges[k];
+        self.dual[i] + self.dual[j] - 2 * weight
+    }
+
+    /// Collect the vertices of a blossom.
+    ///
+    /// # Arguments
+    ///
+    /// - `b`: Vertex or blossom.
+    ///
+    /// # Returns
+    ///
+    /// Vertices contained in the blossom.
+    fn leaves(&self, b: usize) -> Vec<usize> {
+        if b < self.n {
+            return vec![b];
+        }
+
+        self.children[b]
+            .iter()
+            .flat_map(|&child| self.leaves(child))
+            .collect()
+    }
+
+    /// Position of a sub-blossom, wrapping negative indices around the cycle.
+    ///
+    /// # Arguments
+    ///
+    /// - `b`: Blossom.
+    /// - `j`: Index, possibly negative.
+    ///
+    /// # Returns
+    ///
+    /// Index into the sub-blossoms of the blossom.
+    fn at(&self, b: usize, j: isize) -> usize {
+        j.rem_euclid(self.children[b].len() as isize) as usize
+    }
+
+    /// Label a vertex and its top-level blossom, labeling the mate of a T-blossom as S.
+    ///
+    /// # Arguments
+    ///
+    /// - `w`: Vertex.
+    /// - `t`: Label, 1 for S and 2 for T.
+    /// - `p`: Endpoint through which the label was reached.
+    fn assign_label(&mut self, w: usize, t: u8, p: usize) {
+        let b = self.in_blossom[w];
+        self.label[w] = t;
+        self.label[b] = t;
+        self.label_end[w] = p;
+        self.label_end[b] = p;
+        self.best_edge[w] = NONE;
+        self.best_edge[b] = NONE;
+
+        if t == 1 {
+            let leaves = self.leaves(b);
+            self.queue.extend(leaves);
+        } else {
+            let mate = self.mate[self.base[b]];
+            self.assign_label(self.endpoint[mate], 1, mate ^ 1);
+        }
+    }
+
+    /// Trace back from two S-vertices to find a new blossom or an augmenting path.
+    ///
+    /// # Arguments
+    ///
+    /// - `v`: First S-vertex.
+    /// - `w`: Second S-vertex.
+    ///
+    /// # Returns
+    ///
+    /// Base of the new blossom, or `NONE` if an augmenting path was found.
+    fn scan_blossom(&mut self, mut v: usize, mut w: usize) -> usize {
+        let mut path = Vec::new();
+        let mut base = NONE;
+
+        while v != NONE {
+            let b = self.in_blossom[v];
+
+            if self.label[b] & 4 != 0 {
+                base = self.base[b];
+                break;
+            }
+
+            path.push(b);
+            self.label[b] = 5;
+
+            if self.label_end[b] == NONE {
+                v = NONE;
+            } else {
+                let t = self.in_blossom[self.endpoint[self.label_end[b]]];
+                v = self.endpoint[self.label_end[t]];
+            }
+
+            if w != NONE {
+                std::mem::swap(&mut v, &mut w);
+            }
+        }
+
+        for b in path {
+            self.label[b] = 1;
+        }
+
+        base
+    }
+
+    /// Create a new blossom from the cycle closed by an edge.
+    ///
+    /// # Arguments
+    ///
+    /// - `base`: Base vertex of the blossom.
+    /// - `k`: Edge closing the cycle.
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (v, w, _) = self.edges[k];
+        let bb = self.in_blossom[base];
+        let mut bv = self.in_blossom[v];
+        let mut bw = self.in_blossom[w];
+        let b = self.unused.pop().expect("blossom index available");
+
+        self.base[b] = base;
+        self.parent[b] = NONE;
+        self.parent[bb] = b;
+
+        let mut path = Vec::new();
+        let mut endpoints = Vec::new();
+
+        while bv != bb {
+            self.parent[bv] = b;
+            path.push(bv);
+            endpoints.push(self.label_end[bv]);
+            bv = self.in_blossom[self.endpoint[self.label_end[bv]]];
+        }
+
+        path.push(bb);
+        path.reverse();
+        endpoints.reverse();
+        endpoints.push(2 * k);
+
+        while bw != bb {
+            self.parent[bw] = b;
+            path.push(bw);
+            endpoints.push(self.label_end[bw] ^ 1);
+            bw = self.in_blossom[self.endpoint[self.label_end[bw]]];
+        }
+
+        self.children[b] = path.clone();
+        self.endpoints[b] = endpoints;
+        self.label[b] = 1;
+        self.label_end[b] = self.label_end[bb];
+        self.dual[b] = 0;
+
+        for v in self.leaves(b) {
+            // Former T-vertices become S-vertices and must be scanned.
+            if self.label[self.in_blossom[v]] == 2 {
+                self.queue.push(v);
+            }
+
+            self.in_blossom[v] = b;
+        }
+
+        let mut best_to = vec![NONE; 2 * self.n];
+
+        for bv in path {
+            let lists: Vec<Vec<usize>> = match self.best_edges[bv].take() {
+                Some(list) => vec![list],
+                None => self
+                    .leaves(bv)
+                    .into_iter()
+                    .map(|v| self.neighbors[v].iter().map(|p| p / 2).collect())
+                    .collect(),
+            };
+
+            for k in lists.into_iter().flatten() {
+                let (i, j, _) = self.edges[k];
+                let j = if self.in_blossom[j] == b { i } else { j };
+                let bj = self.in_blossom[j];
+
+                if bj != b
+                    && self.label[bj] == 1
+                    && (best_to[bj] == NONE || self.slack(k) < self.slack(best_to[bj]))
+                {
+                    best_to[bj] = k;
+                }
+            }
+
+            self.best_edge[bv] = NONE;
+        }
+
+        let best: Vec<usize> = best_to.into_iter().filter(|&k| k != NONE).collect();
+        self.best_edge[b] = best
+            .iter()
+            .copied()
+            .min_by_key(|&k| self.slack(k))
+            .unwrap_or(NONE);
+        self.best_edges[b] = Some(best);
+    }
+
+    /// Expand a blossom into its sub-blossoms.
+    ///
+    /// # Arguments
+    ///
+    /// - `b`: Blossom.
+    /// - `end_stage`: Whether the expansion happens at the end of a stage, recursively expanding zero-dual sub-blossoms.
+    fn expand_blossom(&mut self, b: usize, end_stage: bool) {
+        for s in self.children[b].clone() {
+            self.parent[s] = NONE;
+
+            if s < self.n {
+                self.in_blossom[s] = s;
+            } else if end_stage && self.dual[s] == 0 {
+                self.expand_blossom(s, end_stage);
+            } else {
+                for v in self.leaves(s) {
+                    self.in_blossom[v] = s;
+                }
+            }
+        }
+
+        // Relabel the sub-blossoms of an expanded T-blossom along the even path to its entry.
+        if !end_stage && self.label[b] == 2 {
+            let len = self.children[b].len() as isize;
+            let entry = self.in_blossom[self.endpoint[self.label_end[b] ^ 1]];
+            let mut j = self.children[b].iter().position(|&c| c == entry).unwrap() as isize;
+            let (step, trick) = if j & 1 != 0 {
+                j -= len;
+                (1, 0)
+            } else {
+                (-1, 1)
+            };
+
+            let mut p = self.label_end[b];
+
+            while j != 0 {
+                let q = self.endpoints[b][self.at(b, j - trick)];
+                self.label[self.endpoint[p ^ 1]] = 0;
+                self.label[self.endpoint[q ^ trick as usize ^ 1]] = 0;
+                self.assign_label(self.endpoint[p ^ 1], 2, p);
+                self.allowed[q / 2] = true;
+                j += step;
+                p = self.endpoints[b][self.at(b, j - trick)] ^ trick as usize;
+                self.allowed[p / 2] = true;
+                j += step;
+            }
+
+            let bv = self.children[b][self.at(b, j)];
+            self.label[self.endpoint[p ^ 1]] = 2;
+            self.label[bv] = 2;
+            self.label_end[self.endpoint[p ^ 1]] = p;
+            self.label_end[bv] = p;
+            self.best_edge[bv] = NONE;
+            j += step;
+
+            while self.children[b][self.at(b, j)] != entry {
+                let bv = self.children[b][self.at(b, j)];
+                j += step;
+
+                if self.label[bv] == 1 {
+                    continue;
+                }
+
+                if let Some(v) = self.leaves(bv).into_iter().find(|&v| self.label[v] != 0) {
+                    self.label[v] = 0;
+                    self.label[self.endpoint[self.mate[self.base[bv]]]] = 0;
+                    self.assign_label(v, 2, self.label_end[v]);
+                }
+            }
+        }
+
+        self.label[b] = 0;
+        self.label_end[b] = NONE;
+        self.children[b].clear();
+        self.endpoints[b].clear();
+        self.base[b] = NONE;
+        self.best_edges[b] = None;
+        self.best_edge[b] = NONE;
+        self.unused.push(b);
+    }
+
+    /// Swap matched and unmatched edges inside a blossom so that a vertex becomes its base.
+    ///
+    /// # Arguments
+    ///
+    /// - `b`: Blossom.
+    /// - `v`: Vertex that becomes the base.
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+
+        while self.parent[t] != b {
+            t = self.parent[t];
+        }
+
+        if t >= self.n {
+            self.augment_blossom(t, v);
+        }
+
+        let i = self.children[b].iter().position(|&c| c == t).unwrap();
+        let mut j = i as isize;
+        let (step, trick) = if i & 1 != 0 {
+            j -= self.children[b].len() as isize;
+            (1, 0)
+        } else {
+            (-1, 1)
+        };
+
+        while j != 0 {
+            j += step;
+            let t = self.children[b][self.at(b, j)];
+            let p = self.endpoints[b][self.at(b, j - trick)] ^ trick as usize;
+
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p]);
+            }
+
+            j += step;
+            let t = self.children[b][self.at(b, j)];
+
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p ^ 1]);
+            }
+
+            self.mate[self.endpoint[p]] = p ^ 1;
+            self.mate[self.endpoint[p ^ 1]] = p;
+        }
+
+        self.children[b].rotate_left(i);
+        self.endpoints[b].rotate_left(i);
+        self.base[b] = self.base[self.children[b][0]];
+    }
+
+    /// Augment the matching along the path through an edge between two S-vertices.
+    ///
+    /// # Arguments
+    ///
+    /// - `k`: Edge.
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+
+        for (mut s, mut p) in [(v, 2 * k + 1), (w, 2 * k)] {
+            loop {
+                let bs = self.in_blossom[s];
+
+                if bs >= self.n {
+                    self.augment_blossom(bs, s);
+                }
+
+                self.mate[s] = p;
+
+                if self.label_end[bs] == NONE {
+                    break;
+                }
+
+                let bt = self.in_blossom[self.endpoint[self.label_end[bs]]];
+                s = self.endpoint[self.label_end[bt]];
+                let j = self.endpoint[self.label_end[bt] ^ 1];
+
+                if bt >= self.n {
+                    self.augment_blossom(bt, j);
+                }
+
+                self.mate[j] = self.label_end[bt];
+                p = self.label_end[bt] ^ 1;
+            }
+        }
+    }
+
+    /// Scan the queued S-vertices, growing the alternating forest.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the matching was augmented.
+    fn scan(&mut self) -> bool {
+        while let Some(v) = self.queue.pop() {
+            for p in self.neighbors[v].clone() {
+                let k = p / 2;
+                let w = self.endpoint[p];
+
+                if self.in_blossom[v] == self.in_blossom[w] {
+                    continue;
+                }
+
+                let mut slack = 0;
+
+                if !self.allowed[k] {
+                    slack = self.slack(k);
+                    self.allowed[k] = slack <= 0;
+                }
+
+                if self.allowed[k] {
+                    if self.label[self.in_blossom[w]] == 0 {
+                        self.assign_label(w, 2, p ^ 1);
+                    } else if self.label[self.in_blossom[w]] == 1 {
+                        let base = self.scan_blossom(v, w);
+
+                        if base == NONE {
+                            self.augment_matching(k);
+                            return true;
+                        }
+
+                        self.add_blossom(base, k);
+                    } else if self.label[w] == 0 {
+                        self.label[w] = 2;
+                        self.label_end[w] = p ^ 1;
+                    }
+                } else if self.label[self.in_blossom[w]] == 1 {
+                    let b = self.in_blossom[v];
+
+                    if self.best_edge[b] == NONE || slack < self.slack(self.best_edge[b]) {
+                        self.best_edge[b] = k;
+                    }
+                } else if self.label[w] == 0
+                    && (self.best_edge[w] == NONE || slack < self.slack(self.best_edge[w]))
+                {
+                    self.best_edge[w] = k;
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Run the primal-dual stages until no augmenting path remains.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_cardinality`: Whether only maximum cardinality matchings are considered.
+    ///
+    /// # Returns
+    ///
+    /// Mate of each vertex, `None` if the vertex is unmatched.
+    fn solve(mut self, max_cardinality: bool) -> Vec<Option<usize>> {
+        let n = self.n;
+
+        for _ in 0..n {
+            self.label.fill(0);
+            self.best_edge.fill(NONE);
+            self.best_edges[n..].fill(None);
+            self.allowed.fill(false);
+            self.queue.clear();
+
+            for v in 0..n {
+                if self.mate[v] == NONE && self.label[self.in_blossom[v]] == 0 {
+                    self.assign_label(v, 1, NONE);
+                }
+            }
+
+            let augmented = loop {
+                if self.scan() {
+                    break true;
+                }
+
+                // Delta 1: decrease the duals of single vertices to zero, ending the search.
+                let mut delta = None;
+
+                if !max_cardinality {
+                    delta = self.dual[..n].iter().min().map(|&d| (d, 1, NONE));
+                }
+
+                let mut consider = |candidate: (i64, u8, usize)| {
+                    if delta.map_or(true, |(d, _, _)| candidate.0 < d) {
+                        delta = Some(candidate);
+                    }
+                };
+
+                // Delta 2: edge between an S-vertex and a free vertex.
+                for v in 0..n {
+                    if self.label[self.in_blossom[v]] == 0 && self.best_edge[v] != NONE {
+                        consider((self.slack(self.best_edge[v]), 2, self.best_edge[v]));
+                    }
+                }
+
+                // Delta 3: edge between two S-blossoms.
+                for b in 0..2 * n {
+                    if self.parent[b] == NONE && self.label[b] == 1 && self.best_edge[b] != NONE {
+                        consider((self.slack(self.best_edge[b]) / 2, 3, self.best_edge[b]));
+                    }
+                }
+
+                // Delta 4: dual of a T-blossom reaching zero.
+                for b in n..2 * n {
+                    if self.base[b] != NONE && self.parent[b] == NONE && self.label[b] == 2 {
+                        consider((self.dual[b], 4, b));
+                    }
+                }
+
+                let (delta, kind, target) = delta.unwrap_or_else(|| {
+                    let d = self.dual[..n].iter().min().copied().unwrap_or(0);
+                    (d.max(0), 1, NONE)
+                });
+
+                for v in 0..n {
+                    match self.label[self.in_blossom[v]] {
+                        1 => self.dual[v] -= delta,
+                        2 => self.dual[v] += delta,
+                        _ => {}
+                    }
+                }
+
+                for b in n..2 * n {
+                    if self.base[b] != NONE && self.parent[b] == NONE {
+                        match self.label[b] {
+                            1 => self.dual[b] += delta,
+                            2 => self.dual[b] -= delta,
+                            _ => {}
+                        }
+                    }
+                }
+
+                match kind {
+                    1 => break false,
+                    2 => {
+                        self.allowed[target] = true;
+                        let (i, j, _) = self.edges[target];
+                        let i = if self.label[self.in_blossom[i]] == 0 {
+                            j
+                        } else {
+                            i
+                        };
+                        self.queue.push(i);
+                    }
+                    3 => {
+                        self.allowed[target] = true;
+                        self.queue.push(self.edges[target].0);
+                    }
+                    _ => self.expand_blossom(target, false),
+                }
+            };
+
+            if !augmented {
+                break;
+            }
+
+            // Expand the S-blossoms whose dual dropped to zero.
+            for b in n..2 * n {
+                if self.parent[b] == NONE
+                    && self.base[b] != NONE
+                    && self.label[b] == 1
+                    && self.dual[b] == 0
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+
+        self.mate
+            .iter()
+            .map(|&p| (p != NONE).then(|| self.endpoint[p]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weighted(edges: &[(usize, usize, usize)]) -> MatchingAlgorithm {
+        let mut algorithm = MatchingAlgorithm::new();
+
+        for &(source, target, weight) in edges {
+            algorithm.set_edge(source, target, weight);
+        }
+
+        algorithm
+    }
+
+    fn weight(algorithm: &MatchingAlgorithm, pairs: &[(usize, usize)]) -> usize {
+        pairs
+            .iter()
+            .map(|&(u, v)| {
+                algorithm
+                    .edges
+                    .iter()
+                    .filter(|&&(s, t, _)| (s, t) == (u, v) || (t, s) == (u, v))
+                    .map(|&(_, _, w)| w)
+                    .max()
+                    .unwrap()
+            })
+            .sum()
+    }
+
+    /// Best `(weight, cardinality)` over all matchings by exhaustive search, preferring cardinality if requested.
+    fn brute_force(
+        edges: &[(usize, usize, usize)],
+        used: u32,
+        cardinality: bool,
+    ) -> (usize, usize) {
+        let Some((&(s, t, w), rest)) = edges.split_first() else {
+            return (0, 0);
+        };
+
+        let skip = brute_force(rest, used, cardinality);
+
+        if s == t || used & (1 << s) != 0 || used & (1 << t) != 0 {
+            return skip;
+        }
+
+        let (weight, size) = brute_force(rest, used | 1 << s | 1 << t, cardinality);
+        let take = (weight + w, size + 1);
+
+        let key = |(weight, size): (usize, usize)| match cardinality {
+            true => (size, weight),
+            false => (weight, size),
+        };
+
+        if key(take) > key(skip) {
+            take
+        } else {
+            skip
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = MatchingAlgorithm::new();
+        let algorithm_default = MatchingAlgorithm::default();
+
+        assert_eq!(algorithm.edges.len(), 0);
+        assert!(!algorithm_default.max_cardinality);
+    }
+
+    #[test]
+    fn test_run() {
+        // Triangle with a pendant edge on each corner requires a blossom.
+        let algorithm = weighted(&[
+            (0, 1, 1),
+            (1, 2, 1),
+            (2, 0, 1),
+            (0, 3, 1),
+            (1, 4, 1),
+            (2, 5, 1),
+        ]);
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![(0, 3), (1, 4), (2, 5)]);
+    }
+
+    #[test]
+    fn test_run_odd_cycle() {
+        let algorithm = weighted(&[(0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 4, 1), (4, 0, 1)]);
+
+        assert_eq!(algorithm.maximum_matching().len(), 2);
+    }
+
+    #[test]
+    fn test_run_empty() {
+        let algorithm = MatchingAlgorithm::new();
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_maximum_weight_matching() {
+        let mut algorithm = weighted(&[(1, 2, 5), (2, 3, 11), (3, 4, 5)]);
+
+        assert_eq!(algorithm.maximum_weight_matching(), vec![(2, 3)]);
+
+        algorithm.set_max_cardinality(true);
+
+        assert_eq!(algorithm.maximum_weight_matching(), vec![(1, 2), (3, 4)]);
+    }
+
+    #[test]
+    fn test_maximum_weight_matching_blossoms() {
+        // Nested S-blossom.
+        let algorithm = weighted(&[
+            (1, 2, 19),
+            (1, 3, 20),
+            (1, 8, 8),
+            (2, 3, 25),
+            (2, 4, 18),
+            (3, 5, 18),
+            (4, 5, 13),
+            (4, 7, 7),
+            (5, 6, 7),
+        ]);
+
+        assert_eq!(
+            algorithm.maximum_weight_matching(),
+            vec![(1, 8), (2, 3), (4, 7), (5, 6)]
+        );
+
+        // T-blossom expanded and relabeled.
+        let algorithm = weighted(&[
+            (1, 2, 45),
+            (1, 5, 45),
+            (2, 3, 50),
+            (3, 4, 45),
+            (4, 5, 50),
+            (1, 6, 30),
+            (3, 9, 35),
+            (4, 8, 35),
+            (5, 7, 26),
+            (9, 10, 5),
+        ]);
+
+        assert_eq!(
+            algorithm.maximum_weight_matching(),
+            vec![(1, 6), (2, 3), (4, 8), (5, 7), (9, 10)]
+        );
+    }
+
+    #[test]
+    fn test_maximum_weight_matching_random() {
+        let mut seed: u64 = 7;
+        let mut next = |bound: usize| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) as usize % bound
+        };
+
+        for _ in 0..300 {
+            let nodes = 2 + next(7);
+            let edges: Vec<(usize, usize, usize)> = (0..next(14))
+                .map(|_| (next(nodes), next(nodes), 1 + next(20)))
+                .collect();
+
+            let mut algorithm = weighted(&edges);
+            let (best_weight, _) = brute_force(&edges, 0, false);
+            let (cardinality_weight, cardinality) = brute_force(&edges, 0, true);
+
+            assert_eq!(
+                weight(&algorithm, &algorithm.maximum_weight_matching()),
+                best_weight
+            );
+            assert_eq!(algorithm.maximum_matching().len(), cardinality);
+
+            algorithm.set_max_cardinality(true);
+            let matching = algorithm.maximum_weight_matching();
+
+            assert_eq!(matching.len(), cardinality);
+            assert_eq!(weight(&algorithm, &matching), cardinality_weight);
+        }
+    }
+}
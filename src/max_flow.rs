@@ -0,0 +1,462 @@
+use std::collections::VecDeque;
+
+use crate::{DiGraph, GraphAlgorithm, GraphError, MemoryEstimate, MemoryEstimation};
+
+/// Maximum Flow (Dinic's Algorithm).
+/// Compute the maximum flow from a source to a sink and the minimum cut separating them.
+#[derive(Debug, Clone)]
+pub struct MaxFlowAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Directed edges in the graph with their capacity.
+    pub edges: Vec<(usize, usize, usize)>,
+
+    /// Node the flow leaves from.
+    pub source: Option<usize>,
+
+    /// Node the flow arrives at.
+    pub sink: Option<usize>,
+}
+
+/// Minimum cut separating the source from the sink.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinimumCut {
+    /// Total capacity of the cut, equal to the maximum flow.
+    pub value: usize,
+
+    /// Nodes reachable from the source in the residual graph, sorted.
+    pub source_side: Vec<usize>,
+
+    /// Saturated edges from the source side to the sink side.
+    pub edges: Vec<(usize, usize, usize)>,
+}
+
+/// Residual graph used by Dinic's Algorithm.
+struct Residual {
+    /// Target, remaining capacity and reverse edge index of each residual edge.
+    edges: Vec<(usize, usize, usize)>,
+
+    /// Residual edge indices of each node.
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl Residual {
+    /// Compute the BFS levels from the source.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    ///
+    /// # Returns
+    ///
+    /// Level of each node, `usize::MAX` if unreachable.
+    fn levels(&self, source: usize) -> Vec<usize> {
+        let mut level = vec![usize::MAX; self.adjacency.len()];
+        let mut queue = VecDeque::from([source]);
+        level[source] = 0;
+
+        while let Some(node) = queue.pop_front() {
+            for &index in &self.adjacency[node] {
+                let (target, capacity, _) = self.edges[index];
+
+                if capacity > 0 && level[target] == usize::MAX {
+                    level[target] = level[node] + 1;
+                    queue.push_back(target);
+                }
+            }
+        }
+
+        level
+    }
+
+    /// Push a blocking flow along level-increasing paths.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Current node.
+    /// - `sink`: Sink node.
+    /// - `limit`: Maximum flow to push.
+    /// - `level`: BFS levels.
+    /// - `next`: Next residual edge to try for each node.
+    ///
+    /// # Returns
+    ///
+    /// Flow pushed to the sink.
+    fn push(
+        &mut self,
+        node: usize,
+        sink: usize,
+        limit: usize,
+        level: &[usize],
+        next: &mut [usize],
+    ) -> usize {
+        if node == sink {
+            return limit;
+        }
+
+        while next[node] < self.adjacency[node].len() {
+            let index = self.adjacency[node][next[node]];
+            let (target, capacity, reverse) = self.edges[index];
+
+            if capacity > 0 && level[target] == level[node] + 1 {
+                let pushed = self.push(target, sink, limit.min(capacity), level, next);
+
+                if pushed > 0 {
+                    self.edges[index].1 -= pushed;
+                    self.edges[reverse].1 += pushed;
+                    return pushed;
+                }
+            }
+
+            next[node] += 1;
+        }
+
+        0
+    }
+}
+
+impl Default for MaxFlowAlgorithm {
+    /// Create a new default instance of the maximum flow algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the maximum flow algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MaxFlowAlgorithm {
+    /// Create a new instance of the maximum flow algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the maximum flow algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            edges: Vec::new(),
+            source: None,
+            sink: None,
+        }
+    }
+
+    /// Set a single directed edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `capacity`: Capacity of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, capacity: usize) {
+        self.edges.push((source, target, capacity));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            for (target, capacity) in edges {
+                self.set_edge(source, target, capacity);
+            }
+        }
+    }
+
+    /// Set the source and the sink of the flow.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Node the flow leaves from.
+    /// - `sink`: Node the flow arrives at.
+    pub fn set_terminals(&mut self, source: usize, sink: usize) {
+        self.source = Some(source);
+        self.sink = Some(sink);
+        self.total_nodes = self.total_nodes.max(source + 1).max(sink + 1);
+    }
+
+    /// Compute the maximum flow and the final residual graph.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the flow value, the residual graph and the source, or an error if a terminal is missing.
+    fn max_flow(&self) -> Result<(usize, Residual, usize), GraphError> {
+        let source = self.source.ok_or(GraphError::MissingStartNode)?;
+        let sink = self.sink.ok_or(GraphError::MissingTargetNode)?;
+
+        let mut residual = Residual {
+            edges: Vec::with_capacity(self.edges.len() * 2),
+            adjacency: vec![Vec::new(); self.total_nodes],
+        };
+
+        for &(from, to, capacity) in &self.edges {
+            let index = residual.edges.len();
+            residual.edges.push((to, capacity, index + 1));
+            residual.edges.push((from, 0, index));
+            residual.adjacency[from].push(index);
+            residual.adjacency[to].push(index + 1);
+        }
+
+        let mut flow = 0;
+
+        if source == sink {
+            return Ok((flow, residual, source));
+        }
+
+        loop {
+            let level = residual.levels(source);
+
+            if level[sink] == usize::MAX {
+                break;
+            }
+
+            let mut next = vec![0; self.total_nodes];
+
+            loop {
+                let pushed = residual.push(source, sink, usize::MAX, &level, &mut next);
+
+                if pushed == 0 {
+                    break;
+                }
+
+                flow += pushed;
+            }
+        }
+
+        Ok((flow, residual, source))
+    }
+
+    /// Compute the minimum cut separating the source from the sink.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the minimum cut, or an error if a terminal is missing.
+    pub fn minimum_cut(&self) -> Result<MinimumCut, GraphError> {
+        let (value, residual, source) = self.max_flow()?;
+        let level = residual.levels(source);
+        let reachable = |node: usize| level[node] != usize::MAX;
+
+        let source_side = (0..self.total_nodes)
+            .filter(|&node| reachable(node))
+            .collect();
+        let edges = self
+            .edges
+            .iter()
+            .copied()
+            .filter(|&(from, to, _)| reachable(from) && !reachable(to))
+            .collect();
+
+        Ok(MinimumCut {
+            value,
+            source_side,
+            edges,
+        })
+    }
+}
+
+impl From<&DiGraph<usize>> for MaxFlowAlgorithm {
+    /// Create an instance of the maximum flow algorithm from a shared graph, copying its edges and nodes.
+    ///
+    /// The source and the sink are set with `set_terminals`.
+    ///
+    /// # Arguments
+    ///
+    /// - `graph`: Graph whose edge weights are the capacities.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the maximum flow algorithm with the edges of the graph.
+    fn from(graph: &DiGraph<usize>) -> Self {
+        let mut algorithm = Self::new();
+
+        for (source, target, weight) in graph.edges() {
+            algorithm.set_edge(source, target, weight);
+        }
+
+        algorithm.total_nodes = algorithm.total_nodes.max(graph.total_nodes());
+        algorithm
+    }
+}
+
+impl GraphAlgorithm for MaxFlowAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = usize;
+
+    /// Run the maximum flow algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used, the terminals are set with `set_terminals`.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the value of the maximum flow, or an error if a terminal is missing.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        self.max_flow().map(|(flow, _, _)| flow)
+    }
+}
+
+impl MemoryEstimation for MaxFlowAlgorithm {
+    /// Estimate the memory of Dinic's Algorithm, whose residual graph holds every edge and its
+    /// reverse, with a level, an edge pointer and a queue slot per node.
+    ///
+    /// # Returns
+    ///
+    /// Memory estimate of a run.
+    fn estimate_memory(&self) -> MemoryEstimate {
+        let n = self.total_nodes;
+        let residual = self.edges.len().saturating_mul(2);
+
+        MemoryEstimate::new(
+            MemoryEstimate::bytes::<(usize, usize, usize)>(self.edges.len()),
+            MemoryEstimate::bytes::<(usize, usize, usize)>(residual)
+                .saturating_add(MemoryEstimate::bytes::<Vec<usize>>(n))
+                .saturating_add(MemoryEstimate::bytes::<usize>(residual))
+                .saturating_add(MemoryEstimate::bytes::<usize>(n.saturating_mul(3))),
+            MemoryEstimate::bytes::<usize>(1),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let algorithm = MaxFlowAlgorithm::new();
+        let algorithm_default = MaxFlowAlgorithm::default();
+
+        assert_eq!(algorithm.edges.len(), 0);
+        assert_eq!(algorithm_default.edges.len(), 0);
+    }
+
+    #[test]
+    fn test_missing_terminals() {
+        let mut algorithm = MaxFlowAlgorithm::new();
+
+        assert_eq!(algorithm.run(None), Err(GraphError::MissingStartNode));
+
+        algorithm.source = Some(0);
+
+        assert_eq!(algorithm.run(None), Err(GraphError::MissingTargetNode));
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = MaxFlowAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 16), (2, 13)]),
+            (1, vec![(2, 10), (3, 12)]),
+            (2, vec![(1, 4), (4, 14)]),
+            (3, vec![(2, 9), (5, 20)]),
+            (4, vec![(3, 7), (5, 4)]),
+        ]);
+        algorithm.set_terminals(0, 5);
+
+        assert_eq!(algorithm.run(None).unwrap(), 23);
+    }
+
+    #[test]
+    fn test_run_disconnected() {
+        let mut algorithm = MaxFlowAlgorithm::new();
+        algorithm.set_edge(0, 1, 5);
+        algorithm.set_terminals(0, 2);
+
+        assert_eq!(algorithm.run(None).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_run_same_terminals() {
+        let mut algorithm = MaxFlowAlgorithm::new();
+        algorithm.set_edge(0, 1, 5);
+        algorithm.set_terminals(1, 1);
+
+        assert_eq!(algorithm.run(None).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_minimum_cut() {
+        let mut algorithm = MaxFlowAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 16), (2, 13)]),
+            (1, vec![(2, 10), (3, 12)]),
+            (2, vec![(1, 4), (4, 14)]),
+            (3, vec![(2, 9), (5, 20)]),
+            (4, vec![(3, 7), (5, 4)]),
+        ]);
+        algorithm.set_terminals(0, 5);
+
+        let cut = algorithm.minimum_cut().unwrap();
+
+        assert_eq!(cut.value, 23);
+        assert_eq!(cut.source_side, vec![0, 1, 2, 4]);
+        assert_eq!(cut.edges, vec![(1, 3, 12), (4, 3, 7), (4, 5, 4)]);
+        assert_eq!(
+            cut.edges.iter().map(|&(_, _, c)| c).sum::<usize>(),
+            cut.value
+        );
+    }
+
+    #[test]
+    fn test_minimum_cut_bottleneck() {
+        let mut algorithm = MaxFlowAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 10)]),
+            (1, vec![(2, 1)]),
+            (2, vec![(3, 10)]),
+        ]);
+        algorithm.set_terminals(0, 3);
+
+        let cut = algorithm.minimum_cut().unwrap();
+
+        assert_eq!(cut.value, 1);
+        assert_eq!(cut.source_side, vec![0, 1]);
+        assert_eq!(cut.edges, vec![(1, 2, 1)]);
+    }
+
+    #[test]
+    fn test_estimate_memory() {
+        let mut algorithm = MaxFlowAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 3), (2, 2)]), (1, vec![(2, 1)])]);
+
+        assert_eq!(
+            algorithm.estimate_memory().working,
+            6 * 24 + 3 * 24 + 6 * 8 + 9 * 8
+        );
+    }
+
+    #[test]
+    fn test_from_graph() {
+        let mut graph = DiGraph::new();
+        graph.add_weighted_edges([
+            (0, 1, 16),
+            (0, 2, 13),
+            (1, 2, 10),
+            (1, 3, 12),
+            (2, 1, 4),
+            (2, 4, 14),
+            (3, 2, 9),
+            (3, 5, 20),
+            (4, 3, 7),
+            (4, 5, 4),
+        ]);
+        graph.set_total_nodes(7);
+
+        let mut algorithm = MaxFlowAlgorithm::from(&graph);
+
+        assert_eq!(algorithm.total_nodes, 7);
+        assert_eq!(algorithm.run(None), Err(GraphError::MissingStartNode));
+
+        algorithm.set_terminals(0, 5);
+
+        assert_eq!(algorithm.run(None).unwrap(), 23);
+    }
+}
@@ -0,0 +1,275 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::GraphError;
+
+/// Translation table between old and new node IDs.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Translation {
+    /// New ID of each old ID.
+    pub forward: BTreeMap<usize, usize>,
+
+    /// Old ID of each new ID.
+    pub backward: BTreeMap<usize, usize>,
+}
+
+impl Translation {
+    /// Translate an old node ID.
+    ///
+    /// # Arguments
+    ///
+    /// - `old`: Old node ID.
+    ///
+    /// # Returns
+    ///
+    /// New node ID, if the node exists.
+    pub fn to_new(&self, old: usize) -> Option<usize> {
+        self.forward.get(&old).copied()
+    }
+
+    /// Translate a new node ID back.
+    ///
+    /// # Arguments
+    ///
+    /// - `new`: New node ID.
+    ///
+    /// # Returns
+    ///
+    /// Old node ID, if the node exists.
+    pub fn to_old(&self, new: usize) -> Option<usize> {
+        self.backward.get(&new).copied()
+    }
+}
+
+/// Graph Morphism.
+/// Store a mutable graph with node attributes, and relabel or compact its node IDs consistently.
+#[derive(Debug, Clone)]
+pub struct GraphMorphism<A> {
+    /// Nodes in the graph.
+    pub nodes: BTreeSet<usize>,
+
+    /// Edges in the graph.
+    pub edges: Vec<(usize, usize, usize)>,
+
+    /// Attributes of the nodes.
+    pub attributes: BTreeMap<usize, A>,
+}
+
+impl<A> Default for GraphMorphism<A> {
+    /// Create a new default instance of the graph morphism.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the graph morphism.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> GraphMorphism<A> {
+    /// Create a new instance of the graph morphism.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the graph morphism.
+    pub fn new() -> Self {
+        Self {
+            nodes: BTreeSet::new(),
+            edges: Vec::new(),
+            attributes: BTreeMap::new(),
+        }
+    }
+
+    /// Set a node to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node.
+    pub fn set_node(&mut self, node: usize) {
+        self.nodes.insert(node);
+    }
+
+    /// Set a single edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.edges.push((source, target, weight));
+        self.nodes.insert(source);
+        self.nodes.insert(target);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            self.set_node(source);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the attribute of a node, adding the node if needed.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node.
+    /// - `attribute`: Attribute of the node.
+    pub fn set_attribute(&mut self, node: usize, attribute: A) {
+        self.nodes.insert(node);
+        self.attributes.insert(node, attribute);
+    }
+
+    /// Remove a node with its edges and attribute.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node to remove.
+    pub fn remove_node(&mut self, node: usize) {
+        self.nodes.remove(&node);
+        self.attributes.remove(&node);
+        self.edges
+            .retain(|&(source, target, _)| source != node && target != node);
+    }
+
+    /// Get the total number of node IDs needed by array-based algorithms.
+    ///
+    /// # Returns
+    ///
+    /// Largest node ID plus one.
+    pub fn total_nodes(&self) -> usize {
+        self.nodes.last().map_or(0, |&node| node + 1)
+    }
+
+    /// Relabel the nodes. Nodes missing from the mapping keep their ID.
+    ///
+    /// # Arguments
+    ///
+    /// - `mapping`: New ID of each relabeled node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the translation table, or an error if two nodes would share an ID.
+    pub fn relabel(&mut self, mapping: &BTreeMap<usize, usize>) -> Result<Translation, GraphError> {
+        let mut translation = Translation::default();
+
+        for &old in &self.nodes {
+            let new = mapping.get(&old).copied().unwrap_or(old);
+
+            if translation.backward.insert(new, old).is_some() {
+                return Err(GraphError::InvalidMapping);
+            }
+
+            translation.forward.insert(old, new);
+        }
+
+        self.apply(&translation);
+
+        Ok(translation)
+    }
+
+    /// Remove the gaps in the node numbering, keeping the relative order of the nodes.
+    ///
+    /// # Returns
+    ///
+    /// Translation table.
+    pub fn compact_ids(&mut self) -> Translation {
+        let mut translation = Translation::default();
+
+        for (new, &old) in self.nodes.iter().enumerate() {
+            translation.forward.insert(old, new);
+            translation.backward.insert(new, old);
+        }
+
+        self.apply(&translation);
+
+        translation
+    }
+
+    /// Apply a translation table to the nodes, edges and attributes.
+    ///
+    /// # Arguments
+    ///
+    /// - `translation`: Translation table covering every node.
+    fn apply(&mut self, translation: &Translation) {
+        self.nodes = translation.backward.keys().copied().collect();
+
+        for (source, target, _) in &mut self.edges {
+            *source = translation.forward[source];
+            *target = translation.forward[target];
+        }
+
+        self.attributes = std::mem::take(&mut self.attributes)
+            .into_iter()
+            .map(|(node, attribute)| (translation.forward[&node], attribute))
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let graph: GraphMorphism<&str> = GraphMorphism::new();
+        let graph_default: GraphMorphism<&str> = GraphMorphism::default();
+
+        assert_eq!(graph.total_nodes(), 0);
+        assert_eq!(graph_default.edges.len(), 0);
+    }
+
+    #[test]
+    fn test_compact_ids() {
+        let mut graph = GraphMorphism::new();
+        graph.set_edges(vec![
+            (0, vec![(3, 1)]),
+            (3, vec![(7, 2)]),
+            (7, vec![(9, 3)]),
+        ]);
+        graph.set_attribute(7, "depot");
+        graph.remove_node(0);
+
+        let translation = graph.compact_ids();
+
+        assert_eq!(graph.total_nodes(), 3);
+        assert_eq!(graph.edges, vec![(0, 1, 2), (1, 2, 3)]);
+        assert_eq!(graph.attributes, BTreeMap::from([(1, "depot")]));
+        assert_eq!(translation.to_new(7), Some(1));
+        assert_eq!(translation.to_old(2), Some(9));
+        assert_eq!(translation.to_new(0), None);
+    }
+
+    #[test]
+    fn test_relabel() {
+        let mut graph = GraphMorphism::new();
+        graph.set_edges(vec![(0, vec![(1, 5)]), (1, vec![(2, 6)])]);
+        graph.set_attribute(0, 'a');
+
+        let translation = graph.relabel(&BTreeMap::from([(0, 10), (2, 0)])).unwrap();
+
+        assert_eq!(graph.edges, vec![(10, 1, 5), (1, 0, 6)]);
+        assert_eq!(graph.attributes, BTreeMap::from([(10, 'a')]));
+        assert_eq!(translation.to_new(1), Some(1));
+        assert_eq!(translation.to_old(0), Some(2));
+    }
+
+    #[test]
+    fn test_relabel_collision() {
+        let mut graph: GraphMorphism<()> = GraphMorphism::new();
+        graph.set_edge(0, 1, 1);
+
+        assert_eq!(
+            graph.relabel(&BTreeMap::from([(0, 1)])),
+            Err(GraphError::InvalidMapping)
+        );
+        assert_eq!(graph.edges, vec![(0, 1, 1)]);
+    }
+}
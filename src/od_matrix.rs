@@ -0,0 +1,403 @@
+use crate::{ConfiguredAlgorithm, GraphAlgorithm, GraphError, Provenance};
+
+/// Origin-Destination Matrix.
+/// Build a gravity-model demand matrix between graph nodes and balance it with Furness (IPF) iterations.
+#[derive(Debug, Clone)]
+pub struct OdMatrixAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Trips produced at each node.
+    pub productions: Vec<f64>,
+
+    /// Trips attracted to each node.
+    pub attractions: Vec<f64>,
+
+    /// Travel cost between each pair of nodes, `i32::MAX` if unreachable.
+    pub costs: Vec<Vec<i32>>,
+
+    /// Deterrence function turning a travel cost into an attractiveness factor.
+    pub deterrence: fn(i32) -> f64,
+
+    /// Maximum number of balancing iterations.
+    pub max_iterations: usize,
+
+    /// Maximum relative error of row and column totals to stop balancing.
+    pub tolerance: f64,
+}
+
+/// Default deterrence function, decreasing with the travel cost.
+///
+/// # Arguments
+///
+/// - `cost`: Travel cost between two nodes.
+///
+/// # Returns
+///
+/// Attractiveness factor of the pair.
+fn inverse_cost(cost: i32) -> f64 {
+    1.0 / (1.0 + cost.max(0) as f64)
+}
+
+impl Default for OdMatrixAlgorithm {
+    /// Create a new default instance of the OD matrix builder.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the OD matrix builder.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OdMatrixAlgorithm {
+    /// Create a new instance of the OD matrix builder.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the OD matrix builder.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            productions: Vec::new(),
+            attractions: Vec::new(),
+            costs: Vec::new(),
+            deterrence: inverse_cost,
+            max_iterations: 100,
+            tolerance: 1e-6,
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.productions.resize(self.total_nodes, 0.0);
+        self.attractions.resize(self.total_nodes, 0.0);
+    }
+
+    /// Set the trips produced at a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Origin node.
+    /// - `trips`: Number of trips produced.
+    pub fn set_production(&mut self, node: usize, trips: f64) {
+        self.set_total_nodes(node + 1);
+        self.productions[node] = trips;
+    }
+
+    /// Set the trips attracted to a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Destination node.
+    /// - `trips`: Number of trips attracted.
+    pub fn set_attraction(&mut self, node: usize, trips: f64) {
+        self.set_total_nodes(node + 1);
+        self.attractions[node] = trips;
+    }
+
+    /// Set the travel cost matrix, e.g. the result of Floyd-Warshall Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `costs`: Travel cost between each pair of nodes.
+    pub fn set_costs(&mut self, costs: Vec<Vec<i32>>) {
+        self.set_total_nodes(costs.len());
+        self.costs = costs;
+    }
+
+    /// Set the deterrence function.
+    ///
+    /// # Arguments
+    ///
+    /// - `deterrence`: Function turning a travel cost into an attractiveness factor.
+    pub fn set_deterrence(&mut self, deterrence: fn(i32) -> f64) {
+        self.deterrence = deterrence;
+    }
+
+    /// Set the maximum number of balancing iterations.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_iterations`: Maximum number of balancing iterations.
+    pub fn set_max_iterations(&mut self, max_iterations: usize) {
+        self.max_iterations = max_iterations;
+    }
+
+    /// Set the tolerance of the balancing.
+    ///
+    /// # Arguments
+    ///
+    /// - `tolerance`: Maximum relative error of row and column totals.
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        self.tolerance = tolerance;
+    }
+
+    /// Build the unbalanced gravity-model seed matrix.
+    ///
+    /// # Returns
+    ///
+    /// Matrix where each cell is `production * attraction * deterrence(cost)`.
+    pub fn gravity(&self) -> Vec<Vec<f64>> {
+        let mut matrix = vec![vec![0.0; self.total_nodes]; self.total_nodes];
+
+        for (origin, row) in matrix.iter_mut().enumerate() {
+            for (destination, cell) in row.iter_mut().enumerate() {
+                let cost = self
+                    .costs
+                    .get(origin)
+                    .and_then(|costs| costs.get(destination))
+                    .copied()
+                    .unwrap_or(i32::MAX);
+
+                // Unreachable pairs cannot exchange any demand.
+                if cost == i32::MAX {
+                    continue;
+                }
+
+                *cell = self.productions[origin]
+                    * self.attractions[destination]
+                    * (self.deterrence)(cost);
+            }
+        }
+
+        matrix
+    }
+
+    /// Balance a seed matrix so that its row totals match the productions
+    /// and its column totals match the attractions (Furness / IPF method).
+    ///
+    /// Attractions are scaled to the total of productions beforehand.
+    ///
+    /// # Arguments
+    ///
+    /// - `seed`: Seed matrix to balance.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the balanced matrix, or an error if the balancing does not converge.
+    pub fn balance(&self, seed: Vec<Vec<f64>>) -> Result<Vec<Vec<f64>>, GraphError> {
+        let mut matrix = seed;
+        let total_productions: f64 = self.productions.iter().sum();
+        let total_attractions: f64 = self.attractions.iter().sum();
+
+        if total_productions == 0.0 || total_attractions == 0.0 {
+            return Ok(matrix);
+        }
+
+        let scale = total_productions / total_attractions;
+        let attractions: Vec<f64> = self.attractions.iter().map(|a| a * scale).collect();
+
+        for _ in 0..self.max_iterations {
+            // Scale each row to its production.
+            for (row, &production) in matrix.iter_mut().zip(&self.productions) {
+                let total: f64 = row.iter().sum();
+
+                if total > 0.0 {
+                    row.iter_mut().for_each(|cell| *cell *= production / total);
+                }
+            }
+
+            // Scale each column to its attraction.
+            for (column, &attraction) in attractions.iter().enumerate() {
+                let total: f64 = matrix.iter().map(|row| row[column]).sum();
+
+                if total > 0.0 {
+                    matrix
+                        .iter_mut()
+                        .for_each(|row| row[column] *= attraction / total);
+                }
+            }
+
+            if self.error(&matrix, &attractions) <= self.tolerance {
+                return Ok(matrix);
+            }
+        }
+
+        Err(GraphError::NoConvergence)
+    }
+
+    /// Compute the maximum relative error of row and column totals.
+    ///
+    /// # Arguments
+    ///
+    /// - `matrix`: Matrix to check.
+    /// - `attractions`: Target column totals.
+    ///
+    /// # Returns
+    ///
+    /// Maximum relative error.
+    fn error(&self, matrix: &[Vec<f64>], attractions: &[f64]) -> f64 {
+        let relative = |total: f64, target: f64| {
+            if target == 0.0 {
+                0.0
+            } else {
+                ((total - target) / target).abs()
+            }
+        };
+
+        let rows = matrix
+            .iter()
+            .zip(&self.productions)
+            .filter(|(row, _)| row.iter().any(|&cell| cell > 0.0))
+            .map(|(row, &production)| relative(row.iter().sum(), production));
+
+        let columns = attractions
+            .iter()
+            .enumerate()
+            .filter(|&(column, _)| matrix.iter().any(|row| row[column] > 0.0))
+            .map(|(column, &attraction)| {
+                relative(matrix.iter().map(|row| row[column]).sum(), attraction)
+            });
+
+        rows.chain(columns).fold(0.0, f64::max)
+    }
+}
+
+impl GraphAlgorithm for OdMatrixAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<Vec<f64>>;
+
+    /// Build and balance the origin-destination demand matrix.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the OD matrix builder.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the demand between each pair of nodes, or an error if applicable.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        self.balance(self.gravity())
+    }
+}
+
+impl ConfiguredAlgorithm for OdMatrixAlgorithm {
+    /// Describe the configuration of the OD matrix algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("od_matrix")
+            .with("max_iterations", self.max_iterations)
+            .with("tolerance", self.tolerance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(left: f64, right: f64) {
+        assert!((left - right).abs() < 1e-3, "{left} != {right}");
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = OdMatrixAlgorithm::new();
+        let algorithm_default = OdMatrixAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.total_nodes, 0);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = OdMatrixAlgorithm::new();
+        algorithm.set_costs(vec![vec![0, 2, 4], vec![2, 0, 3], vec![4, 3, 0]]);
+        algorithm.set_production(0, 100.0);
+        algorithm.set_production(1, 50.0);
+        algorithm.set_production(2, 50.0);
+        algorithm.set_attraction(0, 40.0);
+        algorithm.set_attraction(1, 60.0);
+        algorithm.set_attraction(2, 100.0);
+
+        let result = algorithm.run(None).unwrap();
+
+        for (row, production) in result.iter().zip([100.0, 50.0, 50.0]) {
+            assert_close(row.iter().sum(), production);
+        }
+
+        for (column, attraction) in [40.0, 60.0, 100.0].into_iter().enumerate() {
+            assert_close(result.iter().map(|row| row[column]).sum(), attraction);
+        }
+    }
+
+    #[test]
+    fn test_run_scales_attractions() {
+        let mut algorithm = OdMatrixAlgorithm::new();
+        algorithm.set_costs(vec![vec![0, 1], vec![1, 0]]);
+        algorithm.set_production(0, 10.0);
+        algorithm.set_production(1, 10.0);
+        algorithm.set_attraction(0, 1.0);
+        algorithm.set_attraction(1, 3.0);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_close(result[0][0] + result[1][0], 5.0);
+        assert_close(result[0][1] + result[1][1], 15.0);
+    }
+
+    #[test]
+    fn test_run_unreachable_pair() {
+        let mut algorithm = OdMatrixAlgorithm::new();
+        algorithm.set_costs(vec![vec![0, i32::MAX], vec![1, 0]]);
+        algorithm.set_production(0, 10.0);
+        algorithm.set_production(1, 20.0);
+        algorithm.set_attraction(0, 15.0);
+        algorithm.set_attraction(1, 15.0);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result[0][1], 0.0);
+        assert_close(result[0][0], 10.0);
+        assert_close(result[1][0], 5.0);
+        assert_close(result[1][1], 15.0);
+    }
+
+    #[test]
+    fn test_run_empty() {
+        let algorithm = OdMatrixAlgorithm::new();
+
+        assert_eq!(algorithm.run(None).unwrap(), Vec::<Vec<f64>>::new());
+    }
+
+    #[test]
+    fn test_gravity() {
+        let mut algorithm = OdMatrixAlgorithm::new();
+        algorithm.set_costs(vec![vec![0, 1], vec![1, 0]]);
+        algorithm.set_deterrence(|cost| if cost == 0 { 0.0 } else { 1.0 });
+        algorithm.set_production(0, 2.0);
+        algorithm.set_attraction(1, 3.0);
+
+        let result = algorithm.gravity();
+
+        assert_eq!(result, vec![vec![0.0, 6.0], vec![0.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_balance_no_convergence() {
+        let mut algorithm = OdMatrixAlgorithm::new();
+        algorithm.set_costs(vec![vec![0, 1], vec![1, 0]]);
+        algorithm.set_production(0, 10.0);
+        algorithm.set_production(1, 30.0);
+        algorithm.set_attraction(0, 30.0);
+        algorithm.set_attraction(1, 10.0);
+        algorithm.set_max_iterations(1);
+        algorithm.set_tolerance(0.0);
+
+        assert_eq!(
+            algorithm.balance(vec![vec![1.0, 0.0], vec![1.0, 1.0]]),
+            Err(GraphError::NoConvergence)
+        );
+    }
+}
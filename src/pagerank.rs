@@ -0,0 +1,399 @@
+use crate::{
+    AlgorithmEvent, ConfiguredAlgorithm, EventSink, GraphAlgorithm, GraphError, MemoryEstimate,
+    MemoryEstimation, ObservableAlgorithm, Provenance,
+};
+
+/// PageRank Algorithm.
+/// Score the nodes of a directed graph by the stationary distribution of a random surfer who follows
+/// an outgoing edge with the damping probability and otherwise jumps to a random node, uniformly
+/// or according to a personalization distribution over seed nodes.
+/// Nodes without outgoing edges spread their score like a jump.
+#[derive(Debug, Clone)]
+pub struct PageRankAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Successors of each node.
+    pub adjacency: Vec<Vec<usize>>,
+
+    /// Probability of following an edge instead of jumping.
+    pub damping: f64,
+
+    /// Maximum total change of the scores to stop iterating.
+    pub tolerance: f64,
+
+    /// Maximum number of iterations.
+    pub max_iterations: usize,
+
+    /// Probability of jumping to each node, uniform if empty.
+    pub personalization: Vec<f64>,
+}
+
+impl Default for PageRankAlgorithm {
+    /// Create a new default instance of the PageRank Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the PageRank Algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PageRankAlgorithm {
+    /// Create a new instance of the PageRank Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the PageRank Algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+            damping: 0.85,
+            tolerance: 1e-6,
+            max_iterations: 100,
+            personalization: Vec::new(),
+        }
+    }
+
+    /// Set a single directed edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node, e.g. the citing paper.
+    /// - `target`: Target node, e.g. the cited paper.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].push(target);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its successors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, Vec::new());
+    }
+
+    /// Set the damping factor.
+    ///
+    /// # Arguments
+    ///
+    /// - `damping`: Probability of following an edge, usually 0.85.
+    pub fn set_damping(&mut self, damping: f64) {
+        self.damping = damping;
+    }
+
+    /// Set the tolerance of the iterations.
+    ///
+    /// # Arguments
+    ///
+    /// - `tolerance`: Maximum total change of the scores to stop iterating.
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        self.tolerance = tolerance;
+    }
+
+    /// Set the maximum number of iterations.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_iterations`: Maximum number of iterations.
+    pub fn set_max_iterations(&mut self, max_iterations: usize) {
+        self.max_iterations = max_iterations;
+    }
+
+    /// Set the personalization distribution for topic-sensitive scores: jumps restart at the seed nodes
+    /// in proportion to their weights. An empty list restores uniform jumps.
+    ///
+    /// # Arguments
+    ///
+    /// - `seeds`: Seed nodes with their non-negative restart weights.
+    pub fn set_personalization(&mut self, seeds: Vec<(usize, f64)>) {
+        self.personalization.clear();
+
+        for (node, weight) in seeds {
+            self.set_total_nodes(node + 1);
+            self.personalization.resize(self.total_nodes, 0.0);
+            self.personalization[node] += weight.max(0.0);
+        }
+    }
+
+    /// Compute the jump distribution.
+    ///
+    /// # Returns
+    ///
+    /// Probability of jumping to each node, uniform if no seed has a positive weight.
+    fn restart(&self) -> Vec<f64> {
+        let n = self.total_nodes;
+        let total: f64 = self.personalization.iter().sum();
+
+        match total > 0.0 {
+            true => (0..n)
+                .map(|node| self.personalization.get(node).map_or(0.0, |w| w / total))
+                .collect(),
+            false => vec![1.0 / n as f64; n],
+        }
+    }
+
+    /// Iterate the scores until they converge, emitting the change of each iteration.
+    ///
+    /// # Arguments
+    ///
+    /// - `sink`: Receiver of the events.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the score of each node, or an error if the scores do not converge.
+    fn iterate<S: EventSink + ?Sized>(&self, sink: &mut S) -> Result<Vec<f64>, GraphError> {
+        let n = self.total_nodes;
+
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+
+        let restart = self.restart();
+        let mut scores = restart.clone();
+
+        for iteration in 0..self.max_iterations {
+            let dangling: f64 = (0..n)
+                .filter(|&node| self.adjacency[node].is_empty())
+                .map(|node| scores[node])
+                .sum();
+            let jump = 1.0 - self.damping + self.damping * dangling;
+            let mut next: Vec<f64> = restart.iter().map(|r| jump * r).collect();
+
+            for (node, targets) in self.adjacency.iter().enumerate() {
+                let share = self.damping * scores[node] / targets.len() as f64;
+
+                for &target in targets {
+                    next[target] += share;
+                }
+            }
+
+            let change: f64 = next.iter().zip(&scores).map(|(a, b)| (a - b).abs()).sum();
+            scores = next;
+            sink.emit(AlgorithmEvent::IterationFinished { iteration, change });
+
+            if change <= self.tolerance {
+                return Ok(scores);
+            }
+        }
+
+        Err(GraphError::NoConvergence)
+    }
+}
+
+impl GraphAlgorithm for PageRankAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<f64>;
+
+    /// Run the PageRank Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the PageRank Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the score of each node, summing to one,
+    /// or an error if the scores do not converge within the maximum number of iterations.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        self.iterate(&mut ())
+    }
+}
+
+impl ConfiguredAlgorithm for PageRankAlgorithm {
+    /// Describe the configuration of the PageRank.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("pagerank")
+            .with("damping", self.damping)
+            .with("tolerance", self.tolerance)
+            .with("max_iterations", self.max_iterations)
+            .with("personalization", &self.personalization)
+    }
+}
+
+impl ObservableAlgorithm for PageRankAlgorithm {
+    /// Run the PageRank Algorithm and emit the total change of the scores after each iteration.
+    ///
+    /// # Arguments
+    ///
+    /// - `_start`: Starting node. This is not used in the PageRank Algorithm.
+    /// - `sink`: Receiver of the events.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the score of each node, or an error if the scores do not converge.
+    fn run_observed(
+        &self,
+        _start: Option<Self::Node>,
+        sink: &mut dyn EventSink,
+    ) -> Result<Self::Weight, GraphError> {
+        self.iterate(sink)
+    }
+}
+
+impl MemoryEstimation for PageRankAlgorithm {
+    /// Estimate the memory of the PageRank Algorithm, which keeps the restart distribution and the
+    /// scores of the next iteration next to the current scores.
+    ///
+    /// # Returns
+    ///
+    /// Memory estimate of a run.
+    fn estimate_memory(&self) -> MemoryEstimate {
+        let n = self.total_nodes;
+        let edges: usize = self.adjacency.iter().map(Vec::len).sum();
+
+        MemoryEstimate::new(
+            MemoryEstimate::bytes::<Vec<usize>>(n)
+                .saturating_add(MemoryEstimate::bytes::<usize>(edges))
+                .saturating_add(MemoryEstimate::bytes::<f64>(self.personalization.len())),
+            MemoryEstimate::bytes::<f64>(n.saturating_mul(2)),
+            MemoryEstimate::bytes::<f64>(n),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(left: f64, right: f64) {
+        assert!((left - right).abs() < 1e-6, "{left} != {right}");
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = PageRankAlgorithm::new();
+        let algorithm_default = PageRankAlgorithm::default();
+
+        assert_eq!(algorithm.damping, 0.85);
+        assert_eq!(algorithm_default.total_nodes, 0);
+        assert_eq!(algorithm.run(None), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_run() {
+        // Reference values from the damped power iteration, e.g. networkx.pagerank.
+        let mut algorithm = PageRankAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 2]),
+            (1, vec![2]),
+            (2, vec![0]),
+            (3, vec![2]),
+        ]);
+
+        let scores = algorithm.run(None).unwrap();
+
+        assert_close(scores.iter().sum(), 1.0);
+        assert_close(scores[0], 0.372526);
+        assert_close(scores[1], 0.195824);
+        assert_close(scores[2], 0.394150);
+        assert_close(scores[3], 0.0375);
+    }
+
+    #[test]
+    fn test_run_dangling() {
+        let mut algorithm = PageRankAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1]), (2, vec![1])]);
+
+        let scores = algorithm.run(None).unwrap();
+
+        assert_close(scores.iter().sum(), 1.0);
+        assert!(scores[1] > scores[0]);
+        assert_close(scores[0], scores[2]);
+    }
+
+    #[test]
+    fn test_run_no_convergence() {
+        let mut algorithm = PageRankAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1]), (1, vec![2]), (2, vec![0, 1])]);
+        algorithm.set_max_iterations(2);
+
+        assert_eq!(algorithm.run(None), Err(GraphError::NoConvergence));
+    }
+
+    #[test]
+    fn test_run_personalized() {
+        let mut algorithm = PageRankAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1]), (1, vec![0]), (2, vec![3]), (3, vec![2])]);
+        algorithm.set_personalization(vec![(0, 3.0), (1, 1.0)]);
+        algorithm.set_tolerance(1e-12);
+        algorithm.set_max_iterations(1000);
+
+        let scores = algorithm.run(None).unwrap();
+
+        // The restart never reaches the other component.
+        assert_close(scores.iter().sum(), 1.0);
+        assert_close(scores[2] + scores[3], 0.0);
+        assert!(scores[0] > scores[1]);
+
+        // Stationary equations: s0 = 0.15 * 0.75 + 0.85 * s1, s1 = 0.15 * 0.25 + 0.85 * s0.
+        assert_close(scores[0], (0.1125 + 0.85 * 0.0375) / (1.0 - 0.85 * 0.85));
+
+        algorithm.set_personalization(vec![]);
+
+        assert_close(algorithm.run(None).unwrap()[2], 0.25);
+    }
+
+    #[test]
+    fn test_estimate_memory() {
+        let mut algorithm = PageRankAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1, 2]), (1, vec![2])]);
+
+        let estimate = algorithm.estimate_memory();
+
+        assert_eq!(estimate.input, 3 * 24 + 3 * 8);
+        assert_eq!(estimate.working, 48);
+        assert_eq!(estimate.output, 24);
+    }
+
+    #[test]
+    fn test_run_observed() {
+        let mut algorithm = PageRankAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1]), (1, vec![2]), (2, vec![0, 1])]);
+
+        let mut events = Vec::new();
+        let scores = algorithm.run_observed(None, &mut events).unwrap();
+
+        assert_eq!(scores, algorithm.run(None).unwrap());
+
+        let changes: Vec<f64> = events
+            .iter()
+            .map(|event| match *event {
+                AlgorithmEvent::IterationFinished { change, .. } => change,
+                _ => f64::NAN,
+            })
+            .collect();
+
+        assert!(changes.last().unwrap() <= &algorithm.tolerance);
+        assert!(changes[..changes.len() - 1]
+            .iter()
+            .all(|&change| change > algorithm.tolerance));
+    }
+}
@@ -0,0 +1,449 @@
+use std::{collections::VecDeque, fmt, ops::AddAssign};
+
+use crate::{DijkstraAlgorithm, GraphAlgorithm, GraphError};
+
+/// Arithmetic used to count shortest paths, whose number can grow exponentially with the graph.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PathCounting {
+    /// Count exactly with arbitrarily large integers.
+    #[default]
+    Exact,
+
+    /// Count modulo a number, such as a large prime for hashing or a power of ten for the last
+    /// digits, in constant space per node. A zero modulus counts exactly.
+    Modulo(u64),
+}
+
+/// Unsigned integer of arbitrary size counting paths.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PathCount {
+    /// Base 2^32 digits, least significant first, without leading zeros.
+    limbs: Vec<u32>,
+}
+
+impl PathCount {
+    /// Create a count of zero paths.
+    ///
+    /// # Returns
+    ///
+    /// Zero.
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// Determine whether no path was counted.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the count is zero.
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Convert the count to a machine integer.
+    ///
+    /// # Returns
+    ///
+    /// Count, or `None` if it does not fit in 64 bits.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs[..] {
+            [] => Some(0),
+            [low] => Some(low as u64),
+            [low, high] => Some((high as u64) << 32 | low as u64),
+            _ => None,
+        }
+    }
+
+    /// Compute the remainder of the count divided by a modulus, as `PathCounting::Modulo` counts.
+    ///
+    /// # Arguments
+    ///
+    /// - `modulus`: Positive modulus.
+    ///
+    /// # Returns
+    ///
+    /// Count modulo the modulus.
+    pub fn remainder(&self, modulus: u64) -> u64 {
+        self.limbs.iter().rev().fold(0, |remainder, &limb| {
+            (((remainder as u128) << 32 | limb as u128) % modulus as u128) as u64
+        })
+    }
+}
+
+impl From<u64> for PathCount {
+    /// Create a count from a machine integer.
+    ///
+    /// # Arguments
+    ///
+    /// - `value`: Number of paths.
+    ///
+    /// # Returns
+    ///
+    /// New count.
+    fn from(value: u64) -> Self {
+        let mut limbs = vec![value as u32, (value >> 32) as u32];
+
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+
+        Self { limbs }
+    }
+}
+
+impl AddAssign<&PathCount> for PathCount {
+    /// Add the paths of another count.
+    ///
+    /// # Arguments
+    ///
+    /// - `other`: Count to add.
+    fn add_assign(&mut self, other: &PathCount) {
+        if self.limbs.len() < other.limbs.len() {
+            self.limbs.resize(other.limbs.len(), 0);
+        }
+
+        let mut carry = 0;
+
+        for (index, limb) in self.limbs.iter_mut().enumerate() {
+            let sum = *limb as u64 + other.limbs.get(index).copied().unwrap_or(0) as u64 + carry;
+            *limb = sum as u32;
+            carry = sum >> 32;
+        }
+
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+}
+
+impl fmt::Display for PathCount {
+    /// Display the count in decimal.
+    ///
+    /// # Arguments
+    ///
+    /// - `f`: Formatter.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the formatted count.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Divide by 10^9 repeatedly to collect the decimal digits in groups of nine.
+        let mut limbs = self.limbs.clone();
+        let mut groups = Vec::new();
+
+        while !limbs.is_empty() {
+            let mut remainder = 0;
+
+            for limb in limbs.iter_mut().rev() {
+                let value = remainder << 32 | *limb as u64;
+                *limb = (value / 1_000_000_000) as u32;
+                remainder = value % 1_000_000_000;
+            }
+
+            while limbs.last() == Some(&0) {
+                limbs.pop();
+            }
+
+            groups.push(remainder);
+        }
+
+        match groups.pop() {
+            Some(first) => write!(f, "{first}")?,
+            None => write!(f, "0")?,
+        }
+
+        for group in groups.iter().rev() {
+            write!(f, "{group:09}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl DijkstraAlgorithm {
+    /// Count the distinct shortest paths by weight from a node to every node, parallel edges
+    /// giving distinct paths. Counts add up along the edges on shortest paths, in order of distance.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `counting`: Arithmetic of the counts.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the number of shortest paths to each node, zero if unreachable, or an error
+    /// if the start is missing or a cycle of zero weight lies on shortest paths, giving infinitely many.
+    pub fn count_shortest_paths(
+        &self,
+        start: usize,
+        counting: PathCounting,
+    ) -> Result<Vec<PathCount>, GraphError> {
+        self.validate_count_start(start)?;
+
+        let distances = self.run(Some(start))?;
+
+        self.count_paths(start, &distances, counting, |weight| weight)
+    }
+
+    /// Count the distinct paths with the fewest edges from a node to every node, as found by a
+    /// breadth-first search ignoring the weights.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `counting`: Arithmetic of the counts.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the number of paths with the fewest edges to each node, zero if unreachable,
+    /// or an error if the start is missing.
+    pub fn count_fewest_hop_paths(
+        &self,
+        start: usize,
+        counting: PathCounting,
+    ) -> Result<Vec<PathCount>, GraphError> {
+        self.validate_count_start(start)?;
+
+        let mut hops = vec![usize::MAX; self.graph.len()];
+        let mut queue = VecDeque::from([start]);
+        hops[start] = 0;
+
+        while let Some(node) = queue.pop_front() {
+            for &(neighbor, _) in self.graph.get(&node).into_iter().flatten() {
+                if neighbor < hops.len() && hops[neighbor] == usize::MAX {
+                    hops[neighbor] = hops[node] + 1;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        self.count_paths(start, &hops, counting, |_| 1)
+    }
+
+    /// Check that the start of a count is a node numbered below the size of the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing nothing, or an error if the start is missing.
+    fn validate_count_start(&self, start: usize) -> Result<(), GraphError> {
+        match start < self.graph.len() && self.graph.contains_key(&start) {
+            true => Ok(()),
+            false => Err(GraphError::MissingStartNode),
+        }
+    }
+
+    /// Count the paths over the tight edges, those whose length matches the distances, in a
+    /// topological order of these edges.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `distances`: Distance of each node, `usize::MAX` if unreachable.
+    /// - `counting`: Arithmetic of the counts.
+    /// - `length`: Length of an edge from its weight.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the number of paths to each node, or an error if the tight edges form a
+    /// cycle.
+    fn count_paths(
+        &self,
+        start: usize,
+        distances: &[usize],
+        counting: PathCounting,
+        length: impl Fn(usize) -> usize,
+    ) -> Result<Vec<PathCount>, GraphError> {
+        let n = distances.len();
+        let mut tight = vec![Vec::new(); n];
+        let mut indegree = vec![0; n];
+
+        for (node, &distance) in distances.iter().enumerate() {
+            if distance == usize::MAX {
+                continue;
+            }
+
+            for &(neighbor, weight) in self.graph.get(&node).into_iter().flatten() {
+                if neighbor < n && distance.checked_add(length(weight)) == Some(distances[neighbor])
+                {
+                    tight[node].push(neighbor);
+                    indegree[neighbor] += 1;
+                }
+            }
+        }
+
+        let counts = match counting {
+            PathCounting::Modulo(modulus) if modulus > 0 => {
+                let add = |count: &mut u64, other: &u64| {
+                    *count = ((*count as u128 + *other as u128) % modulus as u128) as u64;
+                };
+
+                Self::accumulate(start, &tight, indegree, 1 % modulus, add)?
+                    .into_iter()
+                    .map(PathCount::from)
+                    .collect()
+            }
+            _ => {
+                let add = |count: &mut PathCount, other: &PathCount| *count += other;
+
+                Self::accumulate(start, &tight, indegree, PathCount::from(1), add)?
+            }
+        };
+
+        Ok(counts)
+    }
+
+    /// Add up the counts along acyclic edges from the start (Kahn's Algorithm).
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node.
+    /// - `edges`: Targets of the edges of each node.
+    /// - `indegree`: Number of edges into each node.
+    /// - `one`: Count of the start.
+    /// - `add`: Addition of counts.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the count of each node, or an error if the edges reachable from the start
+    /// form a cycle.
+    fn accumulate<C: Clone + Default>(
+        start: usize,
+        edges: &[Vec<usize>],
+        mut indegree: Vec<usize>,
+        one: C,
+        add: impl Fn(&mut C, &C),
+    ) -> Result<Vec<C>, GraphError> {
+        let mut counts = vec![C::default(); edges.len()];
+        let mut queue = VecDeque::new();
+
+        // A cycle through the start leaves it waiting for its own count.
+        if indegree[start] > 0 {
+            return Err(GraphError::CycleDetected);
+        }
+
+        counts[start] = one;
+        queue.push_back(start);
+
+        while let Some(node) = queue.pop_front() {
+            for &neighbor in &edges[node] {
+                let count = counts[node].clone();
+                add(&mut counts[neighbor], &count);
+                indegree[neighbor] -= 1;
+
+                if indegree[neighbor] == 0 {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        match indegree.iter().all(|&degree| degree == 0) {
+            true => Ok(counts),
+            false => Err(GraphError::CycleDetected),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ladder of `rungs` squares, each doubling the number of shortest paths.
+    fn ladder(rungs: usize) -> DijkstraAlgorithm {
+        let mut algorithm = DijkstraAlgorithm::new();
+
+        for rung in 0..rungs {
+            let (node, next) = (2 * rung, 2 * rung + 2);
+            algorithm.add_weighted_edges(vec![
+                (node, node + 1, 1),
+                (node, next, 2),
+                (node + 1, next, 1),
+            ]);
+        }
+
+        algorithm
+    }
+
+    #[test]
+    fn test_path_count() {
+        let mut count = PathCount::from(u64::MAX);
+        count += &PathCount::from(1);
+
+        assert_eq!(count.to_u64(), None);
+        assert_eq!(count.to_string(), "18446744073709551616");
+        assert_eq!(count.remainder(1_000_000_007), 582_344_008);
+        assert_eq!(PathCount::zero().to_string(), "0");
+        assert!(PathCount::zero().is_zero());
+        assert_eq!(PathCount::from(1_000_000_000).to_string(), "1000000000");
+    }
+
+    #[test]
+    fn test_count_shortest_paths() {
+        let mut algorithm = ladder(3);
+        algorithm.set_total_nodes(8);
+
+        let counts: Vec<_> = algorithm
+            .count_shortest_paths(0, PathCounting::Exact)
+            .unwrap()
+            .iter()
+            .map(|count| count.to_u64().unwrap())
+            .collect();
+
+        assert_eq!(counts, vec![1, 1, 2, 2, 4, 4, 8, 0]);
+    }
+
+    #[test]
+    fn test_count_shortest_paths_exact_and_modulo() {
+        let algorithm = ladder(70);
+        let exact = algorithm
+            .count_shortest_paths(0, PathCounting::Exact)
+            .unwrap();
+        let modulo = algorithm
+            .count_shortest_paths(0, PathCounting::Modulo(1_000_000_007))
+            .unwrap();
+
+        assert_eq!(exact[140].to_string(), "1180591620717411303424");
+        assert_eq!(
+            modulo[140].to_u64(),
+            Some(exact[140].remainder(1_000_000_007))
+        );
+    }
+
+    #[test]
+    fn test_count_fewest_hop_paths() {
+        let algorithm = ladder(2);
+        let counts: Vec<_> = algorithm
+            .count_fewest_hop_paths(0, PathCounting::Exact)
+            .unwrap()
+            .iter()
+            .map(|count| count.to_u64().unwrap())
+            .collect();
+
+        // The direct edges of weight two are the only paths of fewest edges.
+        assert_eq!(counts, vec![1, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn test_count_zero_weight_cycle() {
+        let mut algorithm = DijkstraAlgorithm::new();
+        algorithm.add_weighted_edges(vec![(0, 1, 1), (1, 2, 0), (2, 1, 0)]);
+
+        assert_eq!(
+            algorithm.count_shortest_paths(0, PathCounting::Exact),
+            Err(GraphError::CycleDetected)
+        );
+        assert_eq!(
+            algorithm
+                .count_fewest_hop_paths(0, PathCounting::Exact)
+                .unwrap(),
+            vec![PathCount::from(1), PathCount::from(1), PathCount::from(1)]
+        );
+        assert_eq!(
+            algorithm.count_shortest_paths(5, PathCounting::Exact),
+            Err(GraphError::MissingStartNode)
+        );
+    }
+}
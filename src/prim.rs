@@ -0,0 +1,316 @@
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::{dijkstra::State, DiGraph, GraphAlgorithm, GraphError, MstAlgorithm};
+
+/// Prim's Algorithm.
+/// Find a minimum spanning forest of an undirected weighted graph by growing a tree from the lightest adjacent edge.
+#[derive(Debug, Clone)]
+pub struct PrimAlgorithm {
+    /// Graph to search.
+    pub graph: HashMap<usize, Vec<(usize, usize)>>,
+}
+
+impl Default for PrimAlgorithm {
+    /// Create a new default instance of Prim's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of Prim's Algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrimAlgorithm {
+    /// Create a new instance of Prim's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of Prim's Algorithm.
+    pub fn new() -> Self {
+        PrimAlgorithm {
+            graph: HashMap::new(),
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.graph.entry(source).or_default().push((target, weight));
+        self.graph.entry(target).or_default().push((source, weight));
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            self.graph.entry(source).or_default();
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        for node in 0..total {
+            self.graph.entry(node).or_default();
+        }
+    }
+
+    /// Grow a minimum spanning tree from a root node.
+    ///
+    /// # Arguments
+    ///
+    /// - `root`: Root node of the tree.
+    /// - `visited`: Nodes already included in a tree.
+    /// - `tree`: Edges of the spanning forest to extend.
+    fn grow(
+        &self,
+        root: usize,
+        visited: &mut HashSet<usize>,
+        tree: &mut Vec<(usize, usize, usize)>,
+    ) {
+        let mut priority_queue = BinaryHeap::new();
+        let mut parents = HashMap::new();
+        let mut best = HashMap::new();
+
+        best.insert(root, 0);
+        priority_queue.push(State {
+            cost: 0,
+            position: root,
+        });
+
+        while let Some(State { cost, position }) = priority_queue.pop() {
+            // Skip nodes already connected by a lighter edge.
+            if !visited.insert(position) {
+                continue;
+            }
+
+            if let Some(&parent) = parents.get(&position) {
+                tree.push((parent, position, cost));
+            }
+
+            for &(neighbor, weight) in self.graph.get(&position).into_iter().flatten() {
+                if visited.contains(&neighbor) {
+                    continue;
+                }
+
+                if best.get(&neighbor).map(|&b| weight < b).unwrap_or(true) {
+                    best.insert(neighbor, weight);
+                    parents.insert(neighbor, position);
+                    priority_queue.push(State {
+                        cost: weight,
+                        position: neighbor,
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl From<&DiGraph<usize>> for PrimAlgorithm {
+    /// Create an instance of Prim's Algorithm from a shared graph, copying its edges and nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `graph`: Graph whose edges are read as undirected edges.
+    ///
+    /// # Returns
+    ///
+    /// New instance of Prim's Algorithm with the edges of the graph.
+    fn from(graph: &DiGraph<usize>) -> Self {
+        let mut algorithm = Self::new();
+
+        for (source, target, weight) in graph.edges() {
+            algorithm.set_edge(source, target, weight);
+        }
+
+        algorithm.set_total_nodes(graph.total_nodes());
+        algorithm
+    }
+}
+
+impl MstAlgorithm for PrimAlgorithm {
+    /// Compute the minimum spanning forest with Prim's Algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Edges of the minimum spanning forest.
+    fn minimum_spanning_tree(&self) -> Vec<(usize, usize, usize)> {
+        let mut nodes: Vec<usize> = self.graph.keys().copied().collect();
+        nodes.sort_unstable();
+
+        let mut visited = HashSet::new();
+        let mut tree = Vec::new();
+
+        for node in nodes {
+            if !visited.contains(&node) {
+                self.grow(node, &mut visited, &mut tree);
+            }
+        }
+
+        tree
+    }
+}
+
+impl GraphAlgorithm for PrimAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<(usize, usize, usize)>;
+
+    /// Run Prim's Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Root node. If provided, only the tree of its component is computed.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the edges of the minimum spanning tree (or forest).
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        match start {
+            Some(root) => {
+                let mut tree = Vec::new();
+                self.grow(root, &mut HashSet::new(), &mut tree);
+
+                Ok(tree)
+            }
+            None => Ok(self.minimum_spanning_tree()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Graph, KruskalAlgorithm};
+
+    #[test]
+    fn test_new() {
+        let algorithm = PrimAlgorithm::new();
+        let algorithm_default = PrimAlgorithm::default();
+
+        assert_eq!(algorithm.graph.len(), 0);
+        assert_eq!(algorithm_default.graph.len(), 0);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = PrimAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 7), (3, 5)]),
+            (1, vec![(2, 8), (3, 9), (4, 7)]),
+            (2, vec![(4, 5)]),
+            (3, vec![(4, 15), (5, 6)]),
+            (4, vec![(5, 8), (6, 9)]),
+            (5, vec![(6, 11)]),
+        ]);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.len(), 6);
+        assert_eq!(algorithm.total_weight(), 39);
+    }
+
+    #[test]
+    fn test_run_from_start() {
+        let mut algorithm = PrimAlgorithm::new();
+        algorithm.set_edge(0, 1, 4);
+        algorithm.set_edge(1, 2, 1);
+        algorithm.set_edge(0, 2, 2);
+        algorithm.set_edge(3, 4, 1);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![(0, 2, 2), (2, 1, 1)]);
+    }
+
+    #[test]
+    fn test_run_disconnected_graph() {
+        let mut algorithm = PrimAlgorithm::new();
+        algorithm.set_edge(0, 1, 2);
+        algorithm.set_edge(2, 3, 1);
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![(0, 1, 2), (2, 3, 1)]);
+
+        algorithm.set_total_nodes(5);
+
+        assert_eq!(algorithm.graph.len(), 5);
+        assert_eq!(algorithm.graph[&4], vec![]);
+        assert_eq!(algorithm.run(None).unwrap(), vec![(0, 1, 2), (2, 3, 1)]);
+        assert_eq!(algorithm.run(Some(4)).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_run_empty_graph() {
+        let algorithm = PrimAlgorithm::new();
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![]);
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_same_weight_as_kruskal() {
+        let edges = vec![
+            (0, vec![(1, 4), (7, 8)]),
+            (1, vec![(2, 8), (7, 11)]),
+            (2, vec![(3, 7), (5, 4), (8, 2)]),
+            (3, vec![(4, 9), (5, 14)]),
+            (4, vec![(5, 10)]),
+            (5, vec![(6, 2)]),
+            (6, vec![(7, 1), (8, 6)]),
+            (7, vec![(8, 7)]),
+        ];
+
+        let mut prim = PrimAlgorithm::new();
+        prim.set_edges(edges.clone());
+
+        let mut kruskal = KruskalAlgorithm::new();
+        kruskal.set_edges(edges);
+
+        let algorithms: Vec<&dyn MstAlgorithm> = vec![&prim, &kruskal];
+
+        for algorithm in algorithms {
+            assert_eq!(algorithm.total_weight(), 37);
+        }
+    }
+
+    #[test]
+    fn test_from_graph() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edges([
+            (0, 1, 7),
+            (0, 3, 5),
+            (1, 2, 8),
+            (1, 3, 9),
+            (1, 4, 7),
+            (2, 4, 5),
+            (3, 4, 15),
+            (3, 5, 6),
+            (4, 5, 8),
+            (4, 6, 9),
+            (5, 6, 11),
+        ]);
+        graph.set_total_nodes(8);
+
+        let algorithm = PrimAlgorithm::from(&*graph);
+
+        assert_eq!(algorithm.graph.len(), 8);
+        assert_eq!(algorithm.run(None).unwrap().len(), 6);
+        assert_eq!(algorithm.total_weight(), 39);
+    }
+}
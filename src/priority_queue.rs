@@ -0,0 +1,447 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+};
+
+use crate::Weight;
+
+/// Weight ordered by its total order, so that float keys can be kept in heaps.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Ordered<K>(pub(crate) K);
+
+impl<K: Weight> PartialEq for Ordered<K> {
+    /// Determine whether two weights are equal in the total order.
+    ///
+    /// # Arguments
+    ///
+    /// - `other`: The other weight to compare.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the weights are equal.
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<K: Weight> Eq for Ordered<K> {}
+
+impl<K: Weight> Ord for Ordered<K> {
+    /// Compare two weights with their total order.
+    ///
+    /// # Arguments
+    ///
+    /// - `other`: The other weight to compare.
+    ///
+    /// # Returns
+    ///
+    /// Ordering of the two weights.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<K: Weight> PartialOrd for Ordered<K> {
+    /// Compare two weights partially.
+    ///
+    /// # Arguments
+    ///
+    /// - `other`: The other weight to compare.
+    ///
+    /// # Returns
+    ///
+    /// Ordering of the two weights.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Min-priority queue of `(key, value)` entries popped in increasing key order.
+pub(crate) trait MinQueue<K> {
+    /// Add an entry to the queue.
+    ///
+    /// # Arguments
+    ///
+    /// - `key`: Priority of the entry, lower first.
+    /// - `value`: Value of the entry.
+    fn push(&mut self, key: K, value: usize);
+
+    /// Remove an entry with the smallest key.
+    ///
+    /// # Returns
+    ///
+    /// Key and value of the entry, or `None` if the queue is empty.
+    fn pop(&mut self) -> Option<(K, usize)>;
+}
+
+/// Binary heap from the standard library.
+#[derive(Debug, Clone)]
+pub(crate) struct BinaryQueue<K> {
+    /// Entries of the heap.
+    heap: BinaryHeap<Reverse<(Ordered<K>, usize)>>,
+}
+
+impl<K: Weight> Default for BinaryQueue<K> {
+    /// Create an empty binary heap.
+    ///
+    /// # Returns
+    ///
+    /// Empty binary heap.
+    fn default() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+        }
+    }
+}
+
+impl<K: Weight> MinQueue<K> for BinaryQueue<K> {
+    /// Add an entry to the queue.
+    ///
+    /// # Arguments
+    ///
+    /// - `key`: Priority of the entry, lower first.
+    /// - `value`: Value of the entry.
+    fn push(&mut self, key: K, value: usize) {
+        self.heap.push(Reverse((Ordered(key), value)));
+    }
+
+    /// Remove an entry with the smallest key.
+    ///
+    /// # Returns
+    ///
+    /// Key and value of the entry, or `None` if the queue is empty.
+    fn pop(&mut self) -> Option<(K, usize)> {
+        self.heap.pop().map(|Reverse((key, value))| (key.0, value))
+    }
+}
+
+/// Implicit 4-ary heap, shallower than a binary heap so that pushes are cheaper.
+#[derive(Debug, Clone)]
+pub(crate) struct FourAryQueue<K> {
+    /// Entries of the heap, the children of `i` at `4i + 1` to `4i + 4`.
+    heap: Vec<(Ordered<K>, usize)>,
+}
+
+impl<K: Weight> Default for FourAryQueue<K> {
+    /// Create an empty 4-ary heap.
+    ///
+    /// # Returns
+    ///
+    /// Empty 4-ary heap.
+    fn default() -> Self {
+        Self { heap: Vec::new() }
+    }
+}
+
+impl<K: Weight> MinQueue<K> for FourAryQueue<K> {
+    /// Add an entry to the queue.
+    ///
+    /// # Arguments
+    ///
+    /// - `key`: Priority of the entry, lower first.
+    /// - `value`: Value of the entry.
+    fn push(&mut self, key: K, value: usize) {
+        let mut index = self.heap.len();
+        self.heap.push((Ordered(key), value));
+
+        while index > 0 {
+            let parent = (index - 1) / 4;
+
+            if self.heap[parent] <= self.heap[index] {
+                break;
+            }
+
+            self.heap.swap(parent, index);
+            index = parent;
+        }
+    }
+
+    /// Remove an entry with the smallest key.
+    ///
+    /// # Returns
+    ///
+    /// Key and value of the entry, or `None` if the queue is empty.
+    fn pop(&mut self) -> Option<(K, usize)> {
+        let last = self.heap.len().checked_sub(1)?;
+        self.heap.swap(0, last);
+        let top = self.heap.pop();
+        let mut index = 0;
+
+        loop {
+            let first = 4 * index + 1;
+            let smallest =
+                (first..(first + 4).min(self.heap.len())).min_by_key(|&child| self.heap[child]);
+
+            match smallest {
+                Some(child) if self.heap[child] < self.heap[index] => {
+                    self.heap.swap(child, index);
+                    index = child;
+                }
+                _ => break,
+            }
+        }
+
+        top.map(|(key, value)| (key.0, value))
+    }
+}
+
+/// Pairing heap stored in an arena, with constant time pushes.
+#[derive(Debug, Clone)]
+pub(crate) struct PairingQueue<K> {
+    /// Entries with their first child and next sibling.
+    nodes: Vec<PairingNode<K>>,
+
+    /// Root of the heap.
+    root: Option<usize>,
+
+    /// Number of entries in the heap.
+    len: usize,
+}
+
+/// Entry of a pairing heap.
+#[derive(Debug, Clone)]
+struct PairingNode<K> {
+    /// Key and value of the entry.
+    entry: (Ordered<K>, usize),
+
+    /// First child of the entry.
+    child: Option<usize>,
+
+    /// Next sibling of the entry.
+    sibling: Option<usize>,
+}
+
+impl<K: Weight> Default for PairingQueue<K> {
+    /// Create an empty pairing heap.
+    ///
+    /// # Returns
+    ///
+    /// Empty pairing heap.
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            root: None,
+            len: 0,
+        }
+    }
+}
+
+impl<K: Weight> PairingQueue<K> {
+    /// Merge two heaps, the root with the larger entry becoming the first child of the other.
+    ///
+    /// # Arguments
+    ///
+    /// - `a`: Root of the first heap.
+    /// - `b`: Root of the second heap.
+    ///
+    /// # Returns
+    ///
+    /// Root of the merged heap.
+    fn meld(&mut self, a: usize, b: usize) -> usize {
+        let (parent, child) = match self.nodes[a].entry <= self.nodes[b].entry {
+            true => (a, b),
+            false => (b, a),
+        };
+
+        self.nodes[child].sibling = self.nodes[parent].child;
+        self.nodes[parent].child = Some(child);
+        parent
+    }
+}
+
+impl<K: Weight> MinQueue<K> for PairingQueue<K> {
+    /// Add an entry to the queue.
+    ///
+    /// # Arguments
+    ///
+    /// - `key`: Priority of the entry, lower first.
+    /// - `value`: Value of the entry.
+    fn push(&mut self, key: K, value: usize) {
+        let node = self.nodes.len();
+        self.nodes.push(PairingNode {
+            entry: (Ordered(key), value),
+            child: None,
+            sibling: None,
+        });
+        self.len += 1;
+        self.root = Some(match self.root {
+            Some(root) => self.meld(root, node),
+            None => node,
+        });
+    }
+
+    /// Remove an entry with the smallest key.
+    ///
+    /// # Returns
+    ///
+    /// Key and value of the entry, or `None` if the queue is empty.
+    fn pop(&mut self) -> Option<(K, usize)> {
+        let root = self.root?;
+        let (key, value) = self.nodes[root].entry;
+
+        // Two-pass pairing: meld the children in pairs, then meld the pairs from the last one.
+        let mut pairs = Vec::new();
+        let mut next = self.nodes[root].child;
+
+        while let Some(first) = next {
+            let second = self.nodes[first].sibling;
+            self.nodes[first].sibling = None;
+
+            match second {
+                Some(second) => {
+                    next = self.nodes[second].sibling;
+                    self.nodes[second].sibling = None;
+                    pairs.push(self.meld(first, second));
+                }
+                None => {
+                    next = None;
+                    pairs.push(first);
+                }
+            }
+        }
+
+        self.root = pairs.into_iter().rev().reduce(|a, b| self.meld(a, b));
+        self.len -= 1;
+
+        // Reclaim the arena once the heap is empty.
+        if self.len == 0 {
+            self.nodes.clear();
+        }
+
+        Some((key.0, value))
+    }
+}
+
+/// Radix heap for monotone keys: every pushed key must be at least the last popped key, as in
+/// Dijkstra's Algorithm with non-negative weights. Keys are bucketed by their `Weight::radix_key`.
+#[derive(Debug, Clone)]
+pub(crate) struct RadixQueue<K> {
+    /// Entries grouped by the highest bit in which their radix key differs from the last popped one.
+    buckets: Vec<Vec<(u64, K, usize)>>,
+
+    /// Radix key of the last popped key.
+    last: u64,
+}
+
+impl<K: Weight> Default for RadixQueue<K> {
+    /// Create an empty radix heap.
+    ///
+    /// # Returns
+    ///
+    /// Empty radix heap.
+    fn default() -> Self {
+        Self {
+            buckets: vec![Vec::new(); u64::BITS as usize + 1],
+            last: 0,
+        }
+    }
+}
+
+impl<K> RadixQueue<K> {
+    /// Find the bucket of a key.
+    ///
+    /// # Arguments
+    ///
+    /// - `radix`: Radix key of an entry.
+    ///
+    /// # Returns
+    ///
+    /// Zero if the key equals the last popped key, else one plus its highest differing bit.
+    fn bucket(&self, radix: u64) -> usize {
+        (u64::BITS - (radix ^ self.last).leading_zeros()) as usize
+    }
+}
+
+impl<K: Weight> MinQueue<K> for RadixQueue<K> {
+    /// Add an entry to the queue.
+    ///
+    /// # Arguments
+    ///
+    /// - `key`: Priority of the entry, not smaller than the last popped key.
+    /// - `value`: Value of the entry.
+    fn push(&mut self, key: K, value: usize) {
+        let radix = key.radix_key();
+        debug_assert!(radix >= self.last, "radix heap keys must be monotone");
+        let bucket = self.bucket(radix);
+        self.buckets[bucket].push((radix, key, value));
+    }
+
+    /// Remove an entry with the smallest key.
+    ///
+    /// # Returns
+    ///
+    /// Key and value of the entry, or `None` if the queue is empty.
+    fn pop(&mut self) -> Option<(K, usize)> {
+        if self.buckets[0].is_empty() {
+            // Redistribute the first non-empty bucket around its smallest key.
+            let index = self.buckets.iter().position(|bucket| !bucket.is_empty())?;
+            let entries = std::mem::take(&mut self.buckets[index]);
+            self.last = entries.iter().map(|&(radix, _, _)| radix).min()?;
+
+            for (radix, key, value) in entries {
+                let bucket = self.bucket(radix);
+                self.buckets[bucket].push((radix, key, value));
+            }
+        }
+
+        self.buckets[0].pop().map(|(_, key, value)| (key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Random;
+
+    fn check(mut queue: impl MinQueue<usize>) {
+        let mut random = Random::new(11);
+        let mut expected = Vec::new();
+        let mut last = 0;
+
+        // Interleave pushes and pops with keys never below the last popped key.
+        for round in 0..500 {
+            for _ in 0..3 {
+                let key = last + random.below(50);
+                queue.push(key, round);
+                expected.push(key);
+            }
+
+            expected.sort_unstable_by(|a, b| b.cmp(a));
+            let (key, _) = queue.pop().unwrap();
+            assert_eq!(Some(key), expected.pop());
+            last = key;
+        }
+
+        while let Some((key, _)) = queue.pop() {
+            assert_eq!(Some(key), expected.pop());
+        }
+
+        assert!(expected.is_empty());
+    }
+
+    fn check_floats(mut queue: impl MinQueue<f64>) {
+        for (value, key) in [2.5, 0.0, 7.25, -0.0, 1.0].into_iter().enumerate() {
+            queue.push(key, value);
+        }
+
+        let keys: Vec<f64> = std::iter::from_fn(|| queue.pop().map(|(key, _)| key)).collect();
+
+        assert_eq!(keys, vec![0.0, 0.0, 1.0, 2.5, 7.25]);
+    }
+
+    #[test]
+    fn test_queues() {
+        check(BinaryQueue::default());
+        check(FourAryQueue::default());
+        check(PairingQueue::default());
+        check(RadixQueue::default());
+    }
+
+    #[test]
+    fn test_float_queues() {
+        check_floats(BinaryQueue::default());
+        check_floats(FourAryQueue::default());
+        check_floats(PairingQueue::default());
+        check_floats(RadixQueue::default());
+    }
+}
@@ -0,0 +1,376 @@
+use crate::disjoint_set::DisjointSet;
+
+/// Graph Properties.
+/// Cheap predicates describing the structure of a graph, used to select a suitable algorithm.
+#[derive(Debug, Clone)]
+pub struct GraphProperties {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Edges in the graph.
+    pub edges: Vec<(usize, usize)>,
+
+    /// Whether the edges are directed.
+    pub directed: bool,
+}
+
+impl Default for GraphProperties {
+    /// Create a new default instance of the graph properties.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the graph properties.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GraphProperties {
+    /// Create a new instance of the graph properties for a directed graph.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the graph properties.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            edges: Vec::new(),
+            directed: true,
+        }
+    }
+
+    /// Set a single edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.edges.push((source, target));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its neighbors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+    }
+
+    /// Set whether the edges are directed.
+    ///
+    /// # Arguments
+    ///
+    /// - `directed`: `true` for a directed graph, `false` for an undirected one.
+    pub fn set_directed(&mut self, directed: bool) {
+        self.directed = directed;
+    }
+
+    /// Compute the in-degree and out-degree of each node.
+    /// For undirected graphs both are the number of incident edge ends.
+    ///
+    /// # Returns
+    ///
+    /// In-degrees and out-degrees.
+    fn degrees(&self) -> (Vec<usize>, Vec<usize>) {
+        let mut in_degree = vec![0; self.total_nodes];
+        let mut out_degree = vec![0; self.total_nodes];
+
+        for &(source, target) in &self.edges {
+            out_degree[source] += 1;
+            in_degree[target] += 1;
+        }
+
+        if !self.directed {
+            for (in_d, out_d) in in_degree.iter_mut().zip(out_degree.iter_mut()) {
+                *in_d += *out_d;
+                *out_d = *in_d;
+            }
+        }
+
+        (in_degree, out_degree)
+    }
+
+    /// Count the nodes reachable from a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Node to start from.
+    /// - `reverse`: Follow the edges backwards.
+    /// - `undirected`: Follow the edges in both directions.
+    ///
+    /// # Returns
+    ///
+    /// Whether each node is reachable.
+    fn reachable(&self, start: usize, reverse: bool, undirected: bool) -> Vec<bool> {
+        let mut adjacency = vec![Vec::new(); self.total_nodes];
+
+        for &(source, target) in &self.edges {
+            if !reverse || undirected {
+                adjacency[source].push(target);
+            }
+
+            if reverse || undirected {
+                adjacency[target].push(source);
+            }
+        }
+
+        let mut visited = vec![false; self.total_nodes];
+        let mut stack = vec![start];
+        visited[start] = true;
+
+        while let Some(node) = stack.pop() {
+            for &next in &adjacency[node] {
+                if !visited[next] {
+                    visited[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+
+        visited
+    }
+
+    /// Determine whether the graph is a directed acyclic graph. Edges are treated as directed.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the graph has no directed cycle.
+    pub fn is_dag(&self) -> bool {
+        let mut in_degree = vec![0; self.total_nodes];
+        let mut adjacency = vec![Vec::new(); self.total_nodes];
+
+        for &(source, target) in &self.edges {
+            adjacency[source].push(target);
+            in_degree[target] += 1;
+        }
+
+        let mut stack: Vec<usize> = (0..self.total_nodes)
+            .filter(|&node| in_degree[node] == 0)
+            .collect();
+        let mut visited = 0;
+
+        while let Some(node) = stack.pop() {
+            visited += 1;
+
+            for &target in &adjacency[node] {
+                in_degree[target] -= 1;
+
+                if in_degree[target] == 0 {
+                    stack.push(target);
+                }
+            }
+        }
+
+        visited == self.total_nodes
+    }
+
+    /// Determine whether the graph is a forest. A directed forest also requires every node to have at most one parent.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the graph has no cycle, ignoring edge directions.
+    pub fn is_forest(&self) -> bool {
+        if self.directed && self.degrees().0.iter().any(|&d| d > 1) {
+            return false;
+        }
+
+        let mut components = DisjointSet::new(self.total_nodes);
+
+        self.edges
+            .iter()
+            .all(|&(source, target)| components.union(source, target))
+    }
+
+    /// Determine whether the graph is a tree. A directed tree is an arborescence rooted at its only source.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the graph is a connected forest.
+    pub fn is_tree(&self) -> bool {
+        self.total_nodes > 0 && self.edges.len() == self.total_nodes - 1 && self.is_forest()
+    }
+
+    /// Determine whether the graph is connected, ignoring edge directions.
+    ///
+    /// # Returns
+    ///
+    /// `true` if every node is reachable from every other node.
+    pub fn is_connected(&self) -> bool {
+        self.total_nodes == 0 || self.reachable(0, false, true).into_iter().all(|v| v)
+    }
+
+    /// Determine whether the graph is strongly connected. Undirected graphs are strongly connected when connected.
+    ///
+    /// # Returns
+    ///
+    /// `true` if every node can reach every other node along the edge directions.
+    pub fn is_strongly_connected(&self) -> bool {
+        if !self.directed {
+            return self.is_connected();
+        }
+
+        self.total_nodes == 0
+            || (self.reachable(0, false, false).into_iter().all(|v| v)
+                && self.reachable(0, true, false).into_iter().all(|v| v))
+    }
+
+    /// Determine whether the graph has an Eulerian circuit, using every edge exactly once.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the degree conditions hold and all edges belong to one connected component.
+    pub fn is_eulerian(&self) -> bool {
+        let (in_degree, out_degree) = self.degrees();
+
+        let balanced = match self.directed {
+            true => in_degree == out_degree,
+            false => in_degree.iter().all(|d| d % 2 == 0),
+        };
+
+        if !balanced {
+            return false;
+        }
+
+        // With balanced degrees, weak connectivity of the non-isolated nodes is enough.
+        let Some(&(start, _)) = self.edges.first() else {
+            return true;
+        };
+
+        let visited = self.reachable(start, false, true);
+
+        (0..self.total_nodes).all(|node| visited[node] || out_degree[node] + in_degree[node] == 0)
+    }
+
+    /// Determine whether every node has the same degree.
+    /// For directed graphs, every in-degree and out-degree must be the same.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the graph is regular.
+    pub fn is_regular(&self) -> bool {
+        let (in_degree, out_degree) = self.degrees();
+
+        in_degree
+            .iter()
+            .chain(&out_degree)
+            .all(|&degree| degree == in_degree[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn undirected(nodes: Vec<(usize, Vec<usize>)>) -> GraphProperties {
+        let mut properties = GraphProperties::new();
+        properties.set_directed(false);
+        properties.set_edges(nodes);
+        properties
+    }
+
+    fn directed(nodes: Vec<(usize, Vec<usize>)>) -> GraphProperties {
+        let mut properties = GraphProperties::new();
+        properties.set_edges(nodes);
+        properties
+    }
+
+    #[test]
+    fn test_new() {
+        let properties = GraphProperties::new();
+        let properties_default = GraphProperties::default();
+
+        assert!(properties.directed);
+        assert_eq!(properties_default.edges.len(), 0);
+    }
+
+    #[test]
+    fn test_is_dag() {
+        assert!(directed(vec![(0, vec![1, 2]), (1, vec![2])]).is_dag());
+        assert!(!directed(vec![(0, vec![1]), (1, vec![2]), (2, vec![0])]).is_dag());
+        assert!(!directed(vec![(0, vec![0])]).is_dag());
+    }
+
+    #[test]
+    fn test_is_forest_and_tree() {
+        let tree = undirected(vec![(0, vec![1, 2]), (2, vec![3])]);
+
+        assert!(tree.is_forest());
+        assert!(tree.is_tree());
+
+        let mut forest = tree.clone();
+        forest.set_total_nodes(5);
+
+        assert!(forest.is_forest());
+        assert!(!forest.is_tree());
+
+        let cycle = undirected(vec![(0, vec![1]), (1, vec![2]), (2, vec![0])]);
+
+        assert!(!cycle.is_forest());
+        assert!(!cycle.is_tree());
+        assert!(!GraphProperties::new().is_tree());
+    }
+
+    #[test]
+    fn test_is_tree_directed() {
+        assert!(directed(vec![(0, vec![1, 2]), (2, vec![3])]).is_tree());
+        assert!(!directed(vec![(0, vec![2]), (1, vec![2])]).is_tree());
+    }
+
+    #[test]
+    fn test_is_connected() {
+        assert!(directed(vec![(0, vec![1]), (2, vec![1])]).is_connected());
+        assert!(!undirected(vec![(0, vec![1]), (2, vec![3])]).is_connected());
+        assert!(GraphProperties::new().is_connected());
+    }
+
+    #[test]
+    fn test_is_strongly_connected() {
+        assert!(directed(vec![(0, vec![1]), (1, vec![2]), (2, vec![0])]).is_strongly_connected());
+        assert!(!directed(vec![(0, vec![1]), (1, vec![2])]).is_strongly_connected());
+        assert!(undirected(vec![(0, vec![1]), (1, vec![2])]).is_strongly_connected());
+    }
+
+    #[test]
+    fn test_is_eulerian() {
+        assert!(undirected(vec![(0, vec![1, 2]), (1, vec![2])]).is_eulerian());
+        assert!(!undirected(vec![(0, vec![1]), (1, vec![2])]).is_eulerian());
+        assert!(directed(vec![(0, vec![1]), (1, vec![2]), (2, vec![0])]).is_eulerian());
+        assert!(
+            !directed(vec![(0, vec![1]), (1, vec![0]), (2, vec![3]), (3, vec![2])]).is_eulerian()
+        );
+
+        let mut isolated = directed(vec![(0, vec![1]), (1, vec![0])]);
+        isolated.set_total_nodes(4);
+
+        assert!(isolated.is_eulerian());
+    }
+
+    #[test]
+    fn test_is_regular() {
+        assert!(
+            undirected(vec![(0, vec![1]), (1, vec![2]), (2, vec![3]), (3, vec![0])]).is_regular()
+        );
+        assert!(!undirected(vec![(0, vec![1, 2])]).is_regular());
+        assert!(directed(vec![(0, vec![1]), (1, vec![2]), (2, vec![0])]).is_regular());
+        assert!(!directed(vec![(0, vec![1, 2]), (1, vec![0]), (2, vec![0])]).is_regular());
+        assert!(GraphProperties::new().is_regular());
+    }
+}
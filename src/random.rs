@@ -0,0 +1,77 @@
+/// Seeded pseudo-random number generator (SplitMix64) used by randomized algorithms,
+/// so that results are reproducible without external dependencies.
+#[derive(Debug, Clone)]
+pub(crate) struct Random {
+    /// Current state.
+    state: u64,
+}
+
+impl Random {
+    /// Create a new generator.
+    ///
+    /// # Arguments
+    ///
+    /// - `seed`: Seed of the sequence.
+    ///
+    /// # Returns
+    ///
+    /// New generator.
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Generate the next 64 random bits.
+    ///
+    /// # Returns
+    ///
+    /// Random number.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Generate a random index.
+    ///
+    /// # Arguments
+    ///
+    /// - `bound`: Exclusive upper bound, must be positive.
+    ///
+    /// # Returns
+    ///
+    /// Random number in `0..bound`.
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// Generate a random float.
+    ///
+    /// # Returns
+    ///
+    /// Random number in `0.0..1.0`.
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random() {
+        let mut random = Random::new(42);
+        let mut other = Random::new(42);
+
+        for _ in 0..100 {
+            assert_eq!(random.next_u64(), other.next_u64());
+            assert!(random.below(7) < 7);
+            assert!((0.0..1.0).contains(&random.next_f64()));
+            other.below(7);
+            other.next_f64();
+        }
+    }
+}
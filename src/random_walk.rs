@@ -0,0 +1,354 @@
+use crate::{random::Random, ConfiguredAlgorithm, GraphAlgorithm, GraphError, Provenance};
+
+/// Random Walk.
+/// Simulate reproducible random walks over a weighted graph from given start nodes, as used to
+/// sample node sequences for embeddings such as node2vec. Each step follows an edge with
+/// probability proportional to its weight, or jumps back to the start with the restart probability.
+#[derive(Debug, Clone)]
+pub struct RandomWalkAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Neighbors of each node with the weight of the edge.
+    pub adjacency: Vec<Vec<(usize, f64)>>,
+
+    /// Whether the edges are directed.
+    pub directed: bool,
+
+    /// Number of walks started from each start node.
+    pub walks_per_node: usize,
+
+    /// Number of nodes in each walk.
+    pub walk_length: usize,
+
+    /// Probability of jumping back to the start before each step.
+    pub restart_probability: f64,
+
+    /// Seed of the walks.
+    pub seed: u64,
+}
+
+impl Default for RandomWalkAlgorithm {
+    /// Create a new default instance of the random walk.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the random walk.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RandomWalkAlgorithm {
+    /// Create a new instance of the random walk.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the random walk.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+            directed: false,
+            walks_per_node: 1,
+            walk_length: 20,
+            restart_probability: 0.0,
+            seed: 0,
+        }
+    }
+
+    /// Set a single edge to the graph, in both directions unless the graph is directed.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: f64) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].push((target, weight));
+
+        if !self.directed && source != target {
+            self.adjacency[target].push((source, weight));
+        }
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, f64)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, Vec::new());
+    }
+
+    /// Set whether the edges set afterwards are directed.
+    ///
+    /// # Arguments
+    ///
+    /// - `directed`: Whether the edges are directed.
+    pub fn set_directed(&mut self, directed: bool) {
+        self.directed = directed;
+    }
+
+    /// Set the number and length of the walks.
+    ///
+    /// # Arguments
+    ///
+    /// - `walks_per_node`: Number of walks started from each start node.
+    /// - `walk_length`: Number of nodes in each walk.
+    pub fn set_walks(&mut self, walks_per_node: usize, walk_length: usize) {
+        self.walks_per_node = walks_per_node;
+        self.walk_length = walk_length;
+    }
+
+    /// Set the probability of jumping back to the start before each step.
+    ///
+    /// # Arguments
+    ///
+    /// - `probability`: Restart probability between 0 and 1.
+    pub fn set_restart_probability(&mut self, probability: f64) {
+        self.restart_probability = probability.clamp(0.0, 1.0);
+    }
+
+    /// Set the seed of the walks.
+    ///
+    /// # Arguments
+    ///
+    /// - `seed`: Seed of the random number generator.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    /// Generate the walks, `walks_per_node` rounds over the start nodes in turn.
+    ///
+    /// # Arguments
+    ///
+    /// - `starts`: Start nodes. Nodes outside the graph are skipped.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of each walk. A walk stops early at a node without outgoing edges.
+    pub fn walks(&self, starts: &[usize]) -> Vec<Vec<usize>> {
+        let mut random = Random::new(self.seed);
+        let starts: Vec<usize> = starts
+            .iter()
+            .copied()
+            .filter(|&start| start < self.total_nodes)
+            .collect();
+        let mut walks = Vec::with_capacity(self.walks_per_node * starts.len());
+
+        for _ in 0..self.walks_per_node {
+            for &start in &starts {
+                walks.push(self.walk(start, &mut random));
+            }
+        }
+
+        walks
+    }
+
+    /// Count how often each node is visited by the walks from a start node.
+    /// With a restart probability, the normalized counts estimate personalized PageRank.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Start node.
+    ///
+    /// # Returns
+    ///
+    /// Number of visits of each node, the start included.
+    pub fn visits(&self, start: usize) -> Vec<usize> {
+        let mut visits = vec![0; self.total_nodes];
+
+        for node in self.walks(&[start]).into_iter().flatten() {
+            visits[node] += 1;
+        }
+
+        visits
+    }
+
+    /// Generate a single random walk.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: First node of the walk.
+    /// - `random`: Random number generator.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of the walk.
+    fn walk(&self, start: usize, random: &mut Random) -> Vec<usize> {
+        let mut walk = Vec::with_capacity(self.walk_length);
+
+        if self.walk_length > 0 {
+            walk.push(start);
+        }
+
+        while walk.len() < self.walk_length {
+            let current = walk[walk.len() - 1];
+
+            if self.restart_probability > 0.0 && random.next_f64() < self.restart_probability {
+                walk.push(start);
+                continue;
+            }
+
+            let candidates = &self.adjacency[current];
+            let total: f64 = candidates.iter().map(|&(_, weight)| weight.max(0.0)).sum();
+
+            if total <= 0.0 {
+                break;
+            }
+
+            let mut threshold = random.next_f64() * total;
+            let mut chosen = candidates[candidates.len() - 1].0;
+
+            for &(next, weight) in candidates {
+                if threshold < weight.max(0.0) {
+                    chosen = next;
+                    break;
+                }
+
+                threshold -= weight.max(0.0);
+            }
+
+            walk.push(chosen);
+        }
+
+        walk
+    }
+}
+
+impl GraphAlgorithm for RandomWalkAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<Vec<usize>>;
+
+    /// Run the random walk.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Start node of the walks, or `None` to start from every node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the walks, or an error if the start node is not in the graph.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        match start {
+            Some(start) if start >= self.total_nodes => Err(GraphError::MissingStartNode),
+            Some(start) => Ok(self.walks(&[start])),
+            None => Ok(self.walks(&(0..self.total_nodes).collect::<Vec<_>>())),
+        }
+    }
+}
+
+impl ConfiguredAlgorithm for RandomWalkAlgorithm {
+    /// Describe the configuration of the random walk.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("random_walk")
+            .with("walks_per_node", self.walks_per_node)
+            .with("walk_length", self.walk_length)
+            .with("restart_probability", self.restart_probability)
+            .with("seed", self.seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let algorithm = RandomWalkAlgorithm::new();
+        let algorithm_default = RandomWalkAlgorithm::default();
+
+        assert_eq!(algorithm.walk_length, 20);
+        assert_eq!(algorithm_default.restart_probability, 0.0);
+        assert_eq!(algorithm.run(Some(0)), Err(GraphError::MissingStartNode));
+    }
+
+    #[test]
+    fn test_walks() {
+        let mut algorithm = RandomWalkAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 1.0), (2, 3.0)]), (1, vec![(2, 1.0)])]);
+        algorithm.set_walks(3, 6);
+        algorithm.set_seed(7);
+
+        let walks = algorithm.walks(&[0, 2, 9]);
+
+        assert_eq!(walks.len(), 6);
+        assert_eq!(walks, algorithm.walks(&[0, 2]));
+        assert!(walks.iter().all(|walk| walk.len() == 6));
+        assert!(walks
+            .iter()
+            .all(|walk| walk.windows(2).all(|step| step[0] != step[1])));
+        assert_eq!(walks[0][0], 0);
+        assert_eq!(walks[1][0], 2);
+
+        algorithm.set_seed(8);
+        assert_ne!(algorithm.walks(&[0, 2]), walks);
+    }
+
+    #[test]
+    fn test_walks_directed() {
+        let mut algorithm = RandomWalkAlgorithm::new();
+        algorithm.set_directed(true);
+        algorithm.set_edge(0, 1, 1.0);
+        algorithm.set_edge(1, 2, 1.0);
+        algorithm.set_total_nodes(4);
+
+        assert_eq!(algorithm.run(Some(0)).unwrap(), vec![vec![0, 1, 2]]);
+        assert_eq!(
+            algorithm.run(None).unwrap(),
+            vec![vec![0, 1, 2], vec![1, 2], vec![2], vec![3]]
+        );
+    }
+
+    #[test]
+    fn test_restart() {
+        // A long cycle: with restarts, nodes just after the start are visited most.
+        let mut algorithm = RandomWalkAlgorithm::new();
+        algorithm.set_directed(true);
+
+        for node in 0..11 {
+            algorithm.set_edge(node, (node + 1) % 11, 1.0);
+        }
+
+        algorithm.set_walks(20, 50);
+        algorithm.set_restart_probability(0.3);
+
+        let visits = algorithm.visits(0);
+
+        assert_eq!(visits.iter().sum::<usize>(), 1000);
+        assert!(visits[0] > visits[1] && visits[1] > visits[3] && visits[3] > visits[6]);
+        assert_eq!(
+            algorithm
+                .run_recorded(Some(0))
+                .unwrap()
+                .provenance
+                .configuration[2],
+            ("restart_probability", "0.3".to_string())
+        );
+    }
+}
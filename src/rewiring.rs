@@ -0,0 +1,273 @@
+use std::collections::HashSet;
+
+use crate::{random::Random, ConfiguredAlgorithm, GraphAlgorithm, GraphError, Provenance};
+
+/// Degree-Preserving Rewiring.
+/// Generate null models of an undirected weighted graph by random double-edge swaps,
+/// keeping every node's degree while destroying higher-order structure such as clustering.
+#[derive(Debug, Clone)]
+pub struct RewiringAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Edges in the graph.
+    pub edges: Vec<(usize, usize, usize)>,
+
+    /// Seed of the random swaps.
+    pub seed: u64,
+
+    /// Number of attempted swaps per edge.
+    pub swaps_per_edge: usize,
+}
+
+impl Default for RewiringAlgorithm {
+    /// Create a new default instance of the rewiring algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the rewiring algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RewiringAlgorithm {
+    /// Create a new instance of the rewiring algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the rewiring algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            edges: Vec::new(),
+            seed: 0,
+            swaps_per_edge: 10,
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.edges.push((source, target, weight));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the seed of the random swaps.
+    ///
+    /// # Arguments
+    ///
+    /// - `seed`: Seed, the same seed always produces the same null model.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    /// Set the number of attempted swaps per edge.
+    ///
+    /// # Arguments
+    ///
+    /// - `swaps_per_edge`: Number of attempted swaps per edge.
+    pub fn set_swaps_per_edge(&mut self, swaps_per_edge: usize) {
+        self.swaps_per_edge = swaps_per_edge;
+    }
+
+    /// Rewire the graph with a given seed.
+    /// Swaps `(a, b), (c, d)` into `(a, d), (c, b)` or `(a, c), (b, d)`, each edge keeping its weight,
+    /// and rejects swaps creating self-loops or parallel edges.
+    ///
+    /// # Arguments
+    ///
+    /// - `seed`: Seed of the random swaps.
+    ///
+    /// # Returns
+    ///
+    /// Rewired edges.
+    fn rewire(&self, seed: u64) -> Vec<(usize, usize, usize)> {
+        let mut random = Random::new(seed);
+        let mut edges = self.edges.clone();
+        let key = |a: usize, b: usize| (a.min(b), a.max(b));
+        let mut present: HashSet<(usize, usize)> =
+            edges.iter().map(|&(a, b, _)| key(a, b)).collect();
+
+        if edges.len() < 2 {
+            return edges;
+        }
+
+        for _ in 0..self.swaps_per_edge * edges.len() {
+            let first = random.below(edges.len());
+            let second = random.below(edges.len());
+            let (a, b, first_weight) = edges[first];
+            let (mut c, mut d, second_weight) = edges[second];
+
+            if random.below(2) == 1 {
+                std::mem::swap(&mut c, &mut d);
+            }
+
+            if first == second
+                || a == d
+                || c == b
+                || present.contains(&key(a, d))
+                || present.contains(&key(c, b))
+            {
+                continue;
+            }
+
+            present.remove(&key(a, b));
+            present.remove(&key(c, d));
+            present.insert(key(a, d));
+            present.insert(key(c, b));
+            edges[first] = (a, d, first_weight);
+            edges[second] = (c, b, second_weight);
+        }
+
+        edges
+    }
+
+    /// Generate several independent null models, using consecutive seeds from the configured one.
+    ///
+    /// # Arguments
+    ///
+    /// - `count`: Number of null models.
+    ///
+    /// # Returns
+    ///
+    /// Edges of each null model.
+    pub fn null_models(&self, count: usize) -> Vec<Vec<(usize, usize, usize)>> {
+        (0..count as u64)
+            .map(|offset| self.rewire(self.seed.wrapping_add(offset)))
+            .collect()
+    }
+}
+
+impl GraphAlgorithm for RewiringAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<(usize, usize, usize)>;
+
+    /// Run the rewiring algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the rewiring algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the edges of the null model.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.rewire(self.seed))
+    }
+}
+
+impl ConfiguredAlgorithm for RewiringAlgorithm {
+    /// Describe the configuration of the degree-preserving rewiring.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("rewiring")
+            .with("seed", self.seed)
+            .with("swaps_per_edge", self.swaps_per_edge)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn degrees(total_nodes: usize, edges: &[(usize, usize, usize)]) -> Vec<usize> {
+        let mut degrees = vec![0; total_nodes];
+
+        for &(source, target, _) in edges {
+            degrees[source] += 1;
+            degrees[target] += 1;
+        }
+
+        degrees
+    }
+
+    fn ring() -> RewiringAlgorithm {
+        let mut algorithm = RewiringAlgorithm::new();
+
+        for node in 0..10 {
+            algorithm.set_edge(node, (node + 1) % 10, node);
+            algorithm.set_edge(node, (node + 2) % 10, 1);
+        }
+
+        algorithm
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = RewiringAlgorithm::new();
+        let algorithm_default = RewiringAlgorithm::default();
+
+        assert_eq!(algorithm.swaps_per_edge, 10);
+        assert_eq!(algorithm_default.edges.len(), 0);
+    }
+
+    #[test]
+    fn test_run() {
+        let algorithm = ring();
+        let edges = algorithm.run(None).unwrap();
+
+        assert_ne!(edges, algorithm.edges);
+        assert_eq!(degrees(10, &edges), degrees(10, &algorithm.edges));
+
+        let mut weights: Vec<usize> = edges.iter().map(|&(_, _, w)| w).collect();
+        weights.sort_unstable();
+        let mut original: Vec<usize> = algorithm.edges.iter().map(|&(_, _, w)| w).collect();
+        original.sort_unstable();
+
+        assert_eq!(weights, original);
+
+        let pairs: HashSet<(usize, usize)> = edges
+            .iter()
+            .map(|&(a, b, _)| (a.min(b), a.max(b)))
+            .collect();
+
+        assert_eq!(pairs.len(), edges.len());
+        assert!(edges.iter().all(|&(a, b, _)| a != b));
+    }
+
+    #[test]
+    fn test_run_seeded() {
+        let mut algorithm = ring();
+        algorithm.set_seed(7);
+
+        assert_eq!(algorithm.run(None), algorithm.run(None));
+
+        let models = algorithm.null_models(2);
+
+        assert_eq!(models[0], algorithm.run(None).unwrap());
+        assert_ne!(models[0], models[1]);
+    }
+
+    #[test]
+    fn test_run_no_swaps() {
+        let mut algorithm = ring();
+        algorithm.set_swaps_per_edge(0);
+
+        assert_eq!(algorithm.run(None).unwrap(), algorithm.edges);
+    }
+}
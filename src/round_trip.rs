@@ -0,0 +1,469 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+use crate::{ConfiguredAlgorithm, GraphAlgorithm, GraphError, Provenance};
+
+/// Closed route starting and ending at the same node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundTrip {
+    /// Nodes of the route, the first and last being the start.
+    pub path: Vec<usize>,
+
+    /// Total weight of the route.
+    pub cost: usize,
+
+    /// Via nodes the route turns around.
+    pub via: Vec<usize>,
+
+    /// Weight of the edges traversed more than once, counted for every repeated traversal.
+    pub repeated: usize,
+}
+
+/// Round Trip.
+/// Generate a loop route from a start node with a total cost near a target, such as a jogging route.
+/// Via candidates are the nodes about a third of the target away from the start; each pair of candidates
+/// closes a triangle `start → a → b → start` whose middle segment is found with a bidirectional
+/// Dijkstra search. The triangle minimizing the deviation from the target plus the repeated weight,
+/// which penalizes out-and-back sections, is chosen.
+#[derive(Debug, Clone)]
+pub struct RoundTripAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Neighbors of each node with the weight of the undirected edge.
+    pub adjacency: Vec<Vec<(usize, usize)>>,
+
+    /// Target total cost of the route.
+    pub target_cost: usize,
+
+    /// Accepted deviation of the via candidates from a third of the target, in percent.
+    pub tolerance: usize,
+
+    /// Maximum number of via candidates.
+    pub max_candidates: usize,
+}
+
+impl Default for RoundTripAlgorithm {
+    /// Create a new default instance of the round trip algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the round trip algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RoundTripAlgorithm {
+    /// Create a new instance of the round trip algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the round trip algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+            target_cost: 0,
+            tolerance: 25,
+            max_candidates: 16,
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].push((target, weight));
+        self.adjacency[target].push((source, weight));
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        if total > self.total_nodes {
+            self.total_nodes = total;
+            self.adjacency.resize(total, Vec::new());
+        }
+    }
+
+    /// Set the target total cost of the route.
+    ///
+    /// # Arguments
+    ///
+    /// - `cost`: Target total cost.
+    pub fn set_target_cost(&mut self, cost: usize) {
+        self.target_cost = cost;
+    }
+
+    /// Set how the via candidates are selected.
+    ///
+    /// # Arguments
+    ///
+    /// - `tolerance`: Accepted deviation from a third of the target, in percent.
+    /// - `max_candidates`: Maximum number of via candidates, the closest to a third of the target first.
+    pub fn set_candidates(&mut self, tolerance: usize, max_candidates: usize) {
+        self.tolerance = tolerance;
+        self.max_candidates = max_candidates;
+    }
+
+    /// Generate a round trip from a start node.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Start and end node of the route.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the best round trip, `None` if no pair of candidates closes a loop,
+    /// or an error if the start node is unknown.
+    pub fn round_trip(&self, start: usize) -> Result<Option<RoundTrip>, GraphError> {
+        if start >= self.total_nodes {
+            return Err(GraphError::MissingStartNode);
+        }
+
+        let (distances, parents) = self.shortest_paths(start);
+        let third = self.target_cost / 3;
+        let slack = third * self.tolerance / 100;
+
+        let mut candidates: Vec<usize> = (0..self.total_nodes)
+            .filter(|&node| node != start && distances[node].abs_diff(third) <= slack)
+            .collect();
+        candidates.sort_by_key(|&node| (distances[node].abs_diff(third), node));
+        candidates.truncate(self.max_candidates);
+
+        let mut best: Option<(usize, RoundTrip)> = None;
+
+        for (index, &first) in candidates.iter().enumerate() {
+            for &second in &candidates[index + 1..] {
+                let Some((_, middle)) = self.bidirectional(first, second) else {
+                    continue;
+                };
+
+                let mut path = Self::tree_path(&parents, first);
+                path.extend(&middle[1..]);
+                path.extend(Self::tree_path(&parents, second).iter().rev().skip(1));
+
+                let trip = self.evaluate(path, vec![first, second]);
+                let score = trip.cost.abs_diff(self.target_cost) + trip.repeated;
+
+                if best.as_ref().map_or(true, |(known, _)| score < *known) {
+                    best = Some((score, trip));
+                }
+            }
+        }
+
+        Ok(best.map(|(_, trip)| trip))
+    }
+
+    /// Compute the shortest paths from a node with Dijkstra's Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    ///
+    /// # Returns
+    ///
+    /// Distance and parent of each node, `usize::MAX` if unreachable.
+    fn shortest_paths(&self, source: usize) -> (Vec<usize>, Vec<usize>) {
+        let mut distances = vec![usize::MAX; self.total_nodes];
+        let mut parents = vec![usize::MAX; self.total_nodes];
+        let mut queue = BinaryHeap::from([Reverse((0, source))]);
+        distances[source] = 0;
+
+        while let Some(Reverse((cost, node))) = queue.pop() {
+            if cost > distances[node] {
+                continue;
+            }
+
+            for &(neighbor, weight) in &self.adjacency[node] {
+                if cost + weight < distances[neighbor] {
+                    distances[neighbor] = cost + weight;
+                    parents[neighbor] = node;
+                    queue.push(Reverse((cost + weight, neighbor)));
+                }
+            }
+        }
+
+        (distances, parents)
+    }
+
+    /// Find a shortest path with a bidirectional Dijkstra search, stopping once the two frontiers
+    /// together exceed the best path met in the middle.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Cost and nodes of the path, or `None` if the target is unreachable.
+    fn bidirectional(&self, source: usize, target: usize) -> Option<(usize, Vec<usize>)> {
+        let mut distances = [HashMap::from([(source, 0)]), HashMap::from([(target, 0)])];
+        let mut parents: [HashMap<usize, usize>; 2] = [HashMap::new(), HashMap::new()];
+        let mut queues = [
+            BinaryHeap::from([Reverse((0, source))]),
+            BinaryHeap::from([Reverse((0, target))]),
+        ];
+        let mut best: Option<(usize, usize)> = (source == target).then_some((0, source));
+
+        loop {
+            let fronts = queues
+                .each_ref()
+                .map(|queue| queue.peek().map(|&Reverse((cost, _))| cost));
+
+            let side = match fronts {
+                [Some(forward), Some(backward)] => {
+                    if best.is_some_and(|(cost, _)| forward + backward >= cost) {
+                        break;
+                    }
+
+                    usize::from(backward < forward)
+                }
+                _ => break,
+            };
+
+            let Some(Reverse((cost, node))) = queues[side].pop() else {
+                break;
+            };
+
+            if cost > distances[side][&node] {
+                continue;
+            }
+
+            for &(neighbor, weight) in &self.adjacency[node] {
+                let next = cost + weight;
+
+                if distances[side]
+                    .get(&neighbor)
+                    .map_or(true, |&known| next < known)
+                {
+                    distances[side].insert(neighbor, next);
+                    parents[side].insert(neighbor, node);
+                    queues[side].push(Reverse((next, neighbor)));
+
+                    if let Some(&other) = distances[1 - side].get(&neighbor) {
+                        if best.map_or(true, |(known, _)| next + other < known) {
+                            best = Some((next + other, neighbor));
+                        }
+                    }
+                }
+            }
+        }
+
+        let (cost, meeting) = best?;
+        let mut path = vec![meeting];
+
+        while let Some(&parent) = parents[0].get(&path[path.len() - 1]) {
+            path.push(parent);
+        }
+
+        path.reverse();
+
+        while let Some(&parent) = parents[1].get(&path[path.len() - 1]) {
+            path.push(parent);
+        }
+
+        Some((cost, path))
+    }
+
+    /// Follow the parents of a shortest path tree from a node back to its root.
+    ///
+    /// # Arguments
+    ///
+    /// - `parents`: Parent of each node, `usize::MAX` at the root.
+    /// - `node`: Node.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of the path from the root.
+    fn tree_path(parents: &[usize], node: usize) -> Vec<usize> {
+        let mut path = vec![node];
+
+        while parents[path[path.len() - 1]] != usize::MAX {
+            path.push(parents[path[path.len() - 1]]);
+        }
+
+        path.reverse();
+        path
+    }
+
+    /// Measure a closed route.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: Nodes of the route.
+    /// - `via`: Via nodes of the route.
+    ///
+    /// # Returns
+    ///
+    /// Round trip with its cost and repeated weight.
+    fn evaluate(&self, path: Vec<usize>, via: Vec<usize>) -> RoundTrip {
+        let mut traversed = HashMap::new();
+        let mut cost = 0;
+        let mut repeated = 0;
+
+        for step in path.windows(2) {
+            let weight = self.adjacency[step[0]]
+                .iter()
+                .filter(|&&(neighbor, _)| neighbor == step[1])
+                .map(|&(_, weight)| weight)
+                .min()
+                .unwrap_or(0);
+            let count = traversed
+                .entry((step[0].min(step[1]), step[0].max(step[1])))
+                .or_insert(0);
+
+            if *count > 0 {
+                repeated += weight;
+            }
+
+            *count += 1;
+            cost += weight;
+        }
+
+        RoundTrip {
+            path,
+            cost,
+            via,
+            repeated,
+        }
+    }
+}
+
+impl GraphAlgorithm for RoundTripAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Option<RoundTrip>;
+
+    /// Run the round trip algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Start and end node of the route.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the best round trip, or an error if the start node is missing.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        self.round_trip(start.ok_or(GraphError::MissingStartNode)?)
+    }
+}
+
+impl ConfiguredAlgorithm for RoundTripAlgorithm {
+    /// Describe the configuration of the round trip algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("round_trip")
+            .with("target_cost", self.target_cost)
+            .with("tolerance", self.tolerance)
+            .with("max_candidates", self.max_candidates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Grid of `size` by `size` nodes with unit edges.
+    fn grid(size: usize) -> RoundTripAlgorithm {
+        let mut algorithm = RoundTripAlgorithm::new();
+
+        for row in 0..size {
+            for column in 0..size {
+                let node = row * size + column;
+
+                if column + 1 < size {
+                    algorithm.set_edge(node, node + 1, 1);
+                }
+
+                if row + 1 < size {
+                    algorithm.set_edge(node, node + size, 1);
+                }
+            }
+        }
+
+        algorithm
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = RoundTripAlgorithm::new();
+        let algorithm_default = RoundTripAlgorithm::default();
+
+        assert_eq!(algorithm.tolerance, 25);
+        assert_eq!(algorithm_default.max_candidates, 16);
+        assert_eq!(algorithm.run(None), Err(GraphError::MissingStartNode));
+        assert_eq!(algorithm.run(Some(0)), Err(GraphError::MissingStartNode));
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = grid(6);
+        algorithm.set_target_cost(12);
+
+        let trip = algorithm.run(Some(14)).unwrap().unwrap();
+
+        assert_eq!(trip.path.first(), Some(&14));
+        assert_eq!(trip.path.last(), Some(&14));
+        assert_eq!(trip.cost, 12);
+        assert_eq!(trip.repeated, 0);
+        assert_eq!(trip.via.len(), 2);
+    }
+
+    #[test]
+    fn test_run_path() {
+        // On a path every loop goes out and back.
+        let mut algorithm = RoundTripAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 2)]),
+            (1, vec![(2, 2)]),
+            (2, vec![(3, 2)]),
+        ]);
+        algorithm.set_target_cost(12);
+
+        assert_eq!(algorithm.run(Some(0)), Ok(None));
+
+        algorithm.set_candidates(50, 16);
+        let trip = algorithm.run(Some(0)).unwrap().unwrap();
+
+        assert_eq!(trip.path, vec![0, 1, 2, 3, 2, 1, 0]);
+        assert_eq!((trip.cost, trip.repeated), (12, 6));
+
+        algorithm.set_target_cost(100);
+
+        assert_eq!(algorithm.run(Some(0)), Ok(None));
+        assert_eq!(algorithm.bidirectional(3, 0).map(|(cost, _)| cost), Some(6));
+    }
+}
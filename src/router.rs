@@ -0,0 +1,936 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    fmt,
+    io::{self, BufRead, Write},
+};
+
+use crate::{
+    AStarAlgorithm, BulkLoader, ContractionHierarchiesAlgorithm, ContractionHierarchy,
+    LandmarkHeuristic, LoadReport,
+};
+
+/// Version of the artifact format written by `Router::write_artifact`.
+const ARTIFACT_VERSION: usize = 1;
+
+/// Mean radius of the Earth in meters, for the lengths of OpenStreetMap ways.
+const EARTH_RADIUS: f64 = 6_371_000.0;
+
+/// Preprocessing run once when the router is built, to speed up the queries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Preprocessing {
+    /// No preprocessing, queries run a bidirectional Dijkstra search.
+    #[default]
+    None,
+
+    /// Contraction Hierarchies, the fastest queries on road networks after the longest preprocessing.
+    ContractionHierarchies,
+
+    /// A* with this number of landmarks (ALT), quick to preprocess and to update.
+    Landmarks(usize),
+}
+
+/// Configuration of a router.
+#[derive(Debug, Clone)]
+pub struct RouterConfig {
+    /// Preprocessing of the graph.
+    pub preprocessing: Preprocessing,
+
+    /// Loader validating edge lists, negative weights being always rejected.
+    pub loader: BulkLoader,
+
+    /// Whether the edges of edge lists can be travelled in both directions.
+    pub undirected: bool,
+}
+
+impl Default for RouterConfig {
+    /// Create a new default router configuration.
+    ///
+    /// # Returns
+    ///
+    /// New default router configuration.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RouterConfig {
+    /// Create a new router configuration, without preprocessing and with directed edges.
+    ///
+    /// # Returns
+    ///
+    /// New router configuration.
+    pub fn new() -> Self {
+        Self {
+            preprocessing: Preprocessing::None,
+            loader: BulkLoader::new(),
+            undirected: false,
+        }
+    }
+
+    /// Set the preprocessing of the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `preprocessing`: Preprocessing run when the router is built.
+    pub fn set_preprocessing(&mut self, preprocessing: Preprocessing) {
+        self.preprocessing = preprocessing;
+    }
+
+    /// Set the loader validating edge lists.
+    ///
+    /// # Arguments
+    ///
+    /// - `loader`: Bulk loader.
+    pub fn set_loader(&mut self, loader: BulkLoader) {
+        self.loader = loader;
+    }
+
+    /// Set whether the edges of edge lists can be travelled in both directions.
+    ///
+    /// # Arguments
+    ///
+    /// - `undirected`: Whether to add the reverse of every edge.
+    pub fn set_undirected(&mut self, undirected: bool) {
+        self.undirected = undirected;
+    }
+}
+
+/// Error building or restoring a router.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouterError {
+    /// OpenStreetMap data could not be parsed.
+    Osm(String),
+
+    /// Artifact could not be read or does not match the format.
+    Artifact(String),
+}
+
+impl std::error::Error for RouterError {}
+
+impl fmt::Display for RouterError {
+    /// Display the error message.
+    ///
+    /// # Arguments
+    ///
+    /// - `f`: Formatter.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the formatted error message.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Osm(message) => write!(f, "invalid OpenStreetMap data: {message}"),
+            Self::Artifact(message) => write!(f, "invalid router artifact: {message}"),
+        }
+    }
+}
+
+/// Location of a node loaded from OpenStreetMap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Location {
+    /// OpenStreetMap ID of the node.
+    pub id: i64,
+
+    /// Latitude in degrees.
+    pub latitude: f64,
+
+    /// Longitude in degrees.
+    pub longitude: f64,
+}
+
+/// Route Service.
+/// Bundle graph loading from edge lists or OpenStreetMap, optional preprocessing with Contraction
+/// Hierarchies or landmarks, single pair, many-to-many and isochrone queries, and artifacts saving
+/// the preprocessed graph, behind one configuration.
+#[derive(Debug, Clone)]
+pub struct Router {
+    /// Configuration of the router.
+    pub config: RouterConfig,
+
+    /// Graph with its reverse, guided by the landmarks if any.
+    pub graph: AStarAlgorithm<LandmarkHeuristic>,
+
+    /// Contraction hierarchy, if preprocessed.
+    pub hierarchy: Option<ContractionHierarchy>,
+
+    /// Location of each node, empty for graphs without coordinates.
+    pub locations: Vec<Location>,
+}
+
+impl Router {
+    /// Create a new router on a graph and run its preprocessing.
+    ///
+    /// # Arguments
+    ///
+    /// - `config`: Configuration of the router.
+    /// - `total_nodes`: Total number of nodes in the graph.
+    /// - `edges`: Directed edges as `(source, target, weight)`.
+    ///
+    /// # Returns
+    ///
+    /// New router.
+    pub fn new(
+        config: RouterConfig,
+        total_nodes: usize,
+        edges: impl IntoIterator<Item = (usize, usize, usize)>,
+    ) -> Self {
+        let mut graph = AStarAlgorithm::with_heuristic(LandmarkHeuristic::default());
+        graph.set_total_nodes(total_nodes);
+
+        for (source, target, weight) in edges {
+            graph.set_edge(source, target, weight);
+        }
+
+        let mut router = Self {
+            config,
+            graph,
+            hierarchy: None,
+            locations: Vec::new(),
+        };
+
+        router.preprocess();
+        router
+    }
+
+    /// Create a new router from edge records such as `"0 1 5"`, validated by the loader of the
+    /// configuration.
+    ///
+    /// # Arguments
+    ///
+    /// - `config`: Configuration of the router.
+    /// - `records`: Iterator of records.
+    ///
+    /// # Returns
+    ///
+    /// New router on the valid records, with a report of the rejected ones.
+    pub fn from_edge_list<I, S>(config: RouterConfig, records: I) -> (Self, LoadReport)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut loader = config.loader.clone();
+        loader.set_allow_negative_weights(false);
+
+        let loaded = loader.load(records);
+        let undirected = config.undirected;
+        let edges = loaded.edges.iter().flat_map(|&(source, target, weight)| {
+            let weight = weight as usize;
+            let reverse = undirected.then_some((target, source, weight));
+
+            std::iter::once((source, target, weight)).chain(reverse)
+        });
+
+        (
+            Self::new(config.clone(), loaded.total_nodes, edges),
+            loaded.report,
+        )
+    }
+
+    /// Create a new router from an OpenStreetMap XML extract. Ways with a `highway` tag become
+    /// edges between their consecutive nodes, weighted by their length in meters, in one direction
+    /// for `oneway` ways and roundabouts. Nodes are numbered by increasing OpenStreetMap ID.
+    ///
+    /// # Arguments
+    ///
+    /// - `config`: Configuration of the router.
+    /// - `xml`: Content of an `.osm` file.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the new router, or an error if a node lacks its ID or coordinates.
+    pub fn from_osm_xml(config: RouterConfig, xml: &str) -> Result<Self, RouterError> {
+        let mut coordinates: HashMap<i64, (f64, f64)> = HashMap::new();
+        let mut ways: Vec<(Vec<i64>, i8)> = Vec::new();
+        let mut way: Option<(Vec<i64>, bool, i8)> = None;
+
+        for piece in xml.split('<').skip(1) {
+            let tag = piece
+                .find('>')
+                .map(|end| &piece[..end])
+                .ok_or_else(|| RouterError::Osm("unterminated tag".to_string()))?;
+
+            if tag.starts_with('?') || tag.starts_with('!') {
+                continue;
+            }
+
+            let closing = tag.starts_with('/');
+            let tag = tag.trim_start_matches('/').trim_end_matches('/');
+            let name = tag.split_whitespace().next().unwrap_or_default();
+            let attributes = Self::attributes(tag);
+
+            match (name, closing) {
+                ("node", false) => {
+                    let number = |key: &str| {
+                        attributes
+                            .get(key)
+                            .and_then(|value| value.parse::<f64>().ok())
+                            .ok_or_else(|| RouterError::Osm(format!("node without {key}: {tag}")))
+                    };
+                    let id = attributes
+                        .get("id")
+                        .and_then(|value| value.parse().ok())
+                        .ok_or_else(|| RouterError::Osm(format!("node without id: {tag}")))?;
+
+                    coordinates.insert(id, (number("lat")?, number("lon")?));
+                }
+                ("way", false) => way = Some((Vec::new(), false, 0)),
+                ("way", true) => {
+                    if let Some((nodes, true, oneway)) = way.take() {
+                        ways.push((nodes, oneway));
+                    }
+                }
+                ("nd", false) => {
+                    if let (Some((nodes, _, _)), Some(id)) = (
+                        &mut way,
+                        attributes.get("ref").and_then(|id| id.parse().ok()),
+                    ) {
+                        nodes.push(id);
+                    }
+                }
+                ("tag", false) => {
+                    if let Some((_, highway, oneway)) = &mut way {
+                        match (attributes.get("k"), attributes.get("v")) {
+                            (Some(&"highway"), _) => *highway = true,
+                            (Some(&"oneway"), Some(&("yes" | "true" | "1"))) => *oneway = 1,
+                            (Some(&"oneway"), Some(&"-1")) => *oneway = -1,
+                            (Some(&"junction"), Some(&"roundabout")) if *oneway == 0 => *oneway = 1,
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut ids: Vec<i64> = ways
+            .iter()
+            .flat_map(|(nodes, _)| nodes)
+            .copied()
+            .filter(|id| coordinates.contains_key(id))
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+
+        let index: HashMap<i64, usize> = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let mut edges = Vec::new();
+
+        for (nodes, oneway) in &ways {
+            for pair in nodes.windows(2) {
+                let (Some(&a), Some(&b)) = (index.get(&pair[0]), index.get(&pair[1])) else {
+                    continue;
+                };
+                let length = Self::haversine(coordinates[&pair[0]], coordinates[&pair[1]]);
+                let length = length.round() as usize;
+
+                if *oneway >= 0 {
+                    edges.push((a, b, length));
+                }
+
+                if *oneway <= 0 {
+                    edges.push((b, a, length));
+                }
+            }
+        }
+
+        let mut router = Self::new(config, ids.len(), edges);
+        router.locations = ids
+            .iter()
+            .map(|&id| Location {
+                id,
+                latitude: coordinates[&id].0,
+                longitude: coordinates[&id].1,
+            })
+            .collect();
+
+        Ok(router)
+    }
+
+    /// Parse the attributes of an XML tag.
+    ///
+    /// # Arguments
+    ///
+    /// - `tag`: Content of the tag between its angle brackets.
+    ///
+    /// # Returns
+    ///
+    /// Values of the attributes by name.
+    fn attributes(tag: &str) -> HashMap<&str, &str> {
+        let mut attributes = HashMap::new();
+        let mut rest = tag;
+
+        while let Some(equals) = rest.find('=') {
+            let key = rest[..equals].split_whitespace().last().unwrap_or_default();
+            let value = rest[equals + 1..].trim_start();
+            let Some(quote) = value.chars().next().filter(|&c| c == '"' || c == '\'') else {
+                break;
+            };
+            let Some(end) = value[1..].find(quote) else {
+                break;
+            };
+
+            attributes.insert(key, &value[1..end + 1]);
+            rest = &value[end + 2..];
+        }
+
+        attributes
+    }
+
+    /// Compute the great-circle distance between two points.
+    ///
+    /// # Arguments
+    ///
+    /// - `a`: Latitude and longitude of the first point, in degrees.
+    /// - `b`: Latitude and longitude of the second point, in degrees.
+    ///
+    /// # Returns
+    ///
+    /// Distance in meters.
+    fn haversine(a: (f64, f64), b: (f64, f64)) -> f64 {
+        let (latitude_a, latitude_b) = (a.0.to_radians(), b.0.to_radians());
+        let half_latitude = (latitude_b - latitude_a) / 2.0;
+        let half_longitude = (b.1 - a.1).to_radians() / 2.0;
+        let h = half_latitude.sin().powi(2)
+            + latitude_a.cos() * latitude_b.cos() * half_longitude.sin().powi(2);
+
+        2.0 * EARTH_RADIUS * h.sqrt().min(1.0).asin()
+    }
+
+    /// Run the preprocessing of the configuration, replacing any previous one.
+    fn preprocess(&mut self) {
+        self.hierarchy = None;
+        self.graph.heuristic = LandmarkHeuristic::default();
+
+        match self.config.preprocessing {
+            Preprocessing::None => {}
+            Preprocessing::ContractionHierarchies => {
+                let mut algorithm = ContractionHierarchiesAlgorithm::new();
+                algorithm.set_total_nodes(self.graph.total_nodes);
+
+                for (source, edges) in self.graph.adjacency.iter().enumerate() {
+                    for &(target, weight) in edges {
+                        algorithm.set_edge(source, target, weight);
+                    }
+                }
+
+                self.hierarchy = Some(algorithm.preprocess());
+            }
+            Preprocessing::Landmarks(count) => {
+                self.graph.heuristic = LandmarkHeuristic::farthest(&self.graph, count);
+            }
+        }
+    }
+
+    /// Get the total number of nodes in the graph.
+    ///
+    /// # Returns
+    ///
+    /// Number of nodes.
+    pub fn total_nodes(&self) -> usize {
+        self.graph.total_nodes
+    }
+
+    /// Find the node nearest to a point, for graphs loaded from OpenStreetMap.
+    ///
+    /// # Arguments
+    ///
+    /// - `latitude`: Latitude in degrees.
+    /// - `longitude`: Longitude in degrees.
+    ///
+    /// # Returns
+    ///
+    /// Nearest node, or `None` if the graph has no locations.
+    pub fn nearest_node(&self, latitude: f64, longitude: f64) -> Option<usize> {
+        self.locations
+            .iter()
+            .map(|location| {
+                Self::haversine(
+                    (latitude, longitude),
+                    (location.latitude, location.longitude),
+                )
+            })
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(node, _)| node)
+    }
+
+    /// Find a shortest route between two nodes with the preprocessed graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Cost and nodes of the route, or `None` if the target is unreachable.
+    pub fn route(&self, source: usize, target: usize) -> Option<(usize, Vec<usize>)> {
+        if source >= self.total_nodes() || target >= self.total_nodes() {
+            return None;
+        }
+
+        match (&self.hierarchy, self.graph.heuristic.landmarks.is_empty()) {
+            (Some(hierarchy), _) => hierarchy.shortest_path(source, target),
+            // Landmark bounds are only consistent for the unidirectional search on any graph.
+            (None, false) => self.graph.shortest_path(source, target),
+            (None, true) => self.graph.bidirectional_path(source, target),
+        }
+    }
+
+    /// Compute the distances from several sources to several targets, such as a matrix for
+    /// vehicle routing.
+    ///
+    /// # Arguments
+    ///
+    /// - `sources`: Source nodes.
+    /// - `targets`: Target nodes.
+    ///
+    /// # Returns
+    ///
+    /// Matrix whose entry `[i][j]` is the distance from `sources[i]` to `targets[j]`, `usize::MAX`
+    /// if unreachable.
+    pub fn many_to_many(&self, sources: &[usize], targets: &[usize]) -> Vec<Vec<usize>> {
+        sources
+            .iter()
+            .map(|&source| match &self.hierarchy {
+                Some(hierarchy) => targets
+                    .iter()
+                    .map(|&target| {
+                        hierarchy
+                            .shortest_path(source, target)
+                            .map_or(usize::MAX, |(cost, _)| cost)
+                    })
+                    .collect(),
+                None => {
+                    let distances = self.distances_within(source, usize::MAX);
+
+                    targets
+                        .iter()
+                        .map(|&target| distances.get(&target).copied().unwrap_or(usize::MAX))
+                        .collect()
+                }
+            })
+            .collect()
+    }
+
+    /// Find the nodes reachable from a node within a cost, such as the area reachable in 15 minutes.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `limit`: Maximum cost.
+    ///
+    /// # Returns
+    ///
+    /// Reachable nodes with their distance, sorted by node.
+    pub fn isochrone(&self, source: usize, limit: usize) -> Vec<(usize, usize)> {
+        let mut nodes: Vec<(usize, usize)> =
+            self.distances_within(source, limit).into_iter().collect();
+        nodes.sort_unstable();
+        nodes
+    }
+
+    /// Compute the distances from a node with Dijkstra's Algorithm, up to a cost.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `limit`: Maximum cost.
+    ///
+    /// # Returns
+    ///
+    /// Distances of the nodes within the cost.
+    fn distances_within(&self, source: usize, limit: usize) -> HashMap<usize, usize> {
+        let mut distances = HashMap::new();
+
+        if source >= self.total_nodes() {
+            return distances;
+        }
+
+        let mut queue = BinaryHeap::from([Reverse((0, source))]);
+        distances.insert(source, 0);
+
+        while let Some(Reverse((cost, node))) = queue.pop() {
+            if cost > distances[&node] {
+                continue;
+            }
+
+            for &(neighbor, weight) in &self.graph.adjacency[node] {
+                let next = cost.saturating_add(weight);
+
+                if next <= limit && distances.get(&neighbor).map_or(true, |&known| next < known) {
+                    distances.insert(neighbor, next);
+                    queue.push(Reverse((next, neighbor)));
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Save the graph and its preprocessing as a text artifact, so that services can start without
+    /// preprocessing again.
+    ///
+    /// # Arguments
+    ///
+    /// - `writer`: Destination of the artifact, such as a file.
+    ///
+    /// # Returns
+    ///
+    /// Result containing nothing, or an error from the writer.
+    pub fn write_artifact(&self, writer: &mut impl Write) -> io::Result<()> {
+        let preprocessing = match self.config.preprocessing {
+            Preprocessing::None => "none 0".to_string(),
+            Preprocessing::ContractionHierarchies => "ch 0".to_string(),
+            Preprocessing::Landmarks(count) => format!("landmarks {count}"),
+        };
+        let list = |values: &[usize]| {
+            values
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        writeln!(writer, "router {ARTIFACT_VERSION} {preprocessing}")?;
+        writeln!(writer, "nodes {}", self.total_nodes())?;
+
+        for (node, location) in self.locations.iter().enumerate() {
+            let Location {
+                id,
+                latitude,
+                longitude,
+            } = location;
+            writeln!(writer, "location {node} {id} {latitude} {longitude}")?;
+        }
+
+        for (source, edges) in self.graph.adjacency.iter().enumerate() {
+            for (target, weight) in edges {
+                writeln!(writer, "edge {source} {target} {weight}")?;
+            }
+        }
+
+        if let Some(hierarchy) = &self.hierarchy {
+            let mut edges: Vec<_> = hierarchy.edges.iter().collect();
+            edges.sort_unstable();
+
+            writeln!(writer, "rank {}", list(&hierarchy.rank))?;
+            writeln!(writer, "shortcuts {}", hierarchy.shortcuts)?;
+
+            for (&(source, target), &(weight, middle)) in edges {
+                let middle = middle.map_or("-".to_string(), |middle| middle.to_string());
+                writeln!(writer, "ch {source} {target} {weight} {middle}")?;
+            }
+        }
+
+        let heuristic = &self.graph.heuristic;
+
+        for (index, landmark) in heuristic.landmarks.iter().enumerate() {
+            writeln!(writer, "landmark {landmark}")?;
+            writeln!(writer, "from {}", list(&heuristic.from_landmark[index]))?;
+            writeln!(writer, "to {}", list(&heuristic.to_landmark[index]))?;
+        }
+
+        Ok(())
+    }
+
+    /// Restore a router from an artifact written by `write_artifact`, without preprocessing.
+    ///
+    /// # Arguments
+    ///
+    /// - `reader`: Source of the artifact, such as a buffered file.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the router, or an error if the artifact cannot be read or parsed.
+    pub fn read_artifact(reader: impl BufRead) -> Result<Self, RouterError> {
+        let mut config = RouterConfig::new();
+        let mut graph = AStarAlgorithm::with_heuristic(LandmarkHeuristic::default());
+        let mut locations = Vec::new();
+        let mut rank = Vec::new();
+        let mut shortcuts = 0;
+        let mut edges = HashMap::new();
+
+        for (number, line) in reader.lines().enumerate() {
+            let line = line.map_err(|error| RouterError::Artifact(error.to_string()))?;
+            let error = || RouterError::Artifact(format!("line {}: {line}", number + 1));
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let numbers = |from: usize| -> Result<Vec<usize>, RouterError> {
+                fields[from..]
+                    .iter()
+                    .map(|field| field.parse().map_err(|_| error()))
+                    .collect()
+            };
+
+            match fields.first().copied() {
+                Some("router") => {
+                    let version: usize = fields.get(1).and_then(|v| v.parse().ok()).unwrap_or(0);
+                    let count = fields.get(3).and_then(|count| count.parse().ok());
+
+                    config.preprocessing = match (version, fields.get(2).copied(), count) {
+                        (ARTIFACT_VERSION, Some("none"), Some(_)) => Preprocessing::None,
+                        (ARTIFACT_VERSION, Some("ch"), Some(_)) => {
+                            Preprocessing::ContractionHierarchies
+                        }
+                        (ARTIFACT_VERSION, Some("landmarks"), Some(count)) => {
+                            Preprocessing::Landmarks(count)
+                        }
+                        _ => return Err(error()),
+                    };
+                }
+                Some("nodes") => graph.set_total_nodes(*numbers(1)?.first().ok_or_else(error)?),
+                Some("location") => {
+                    let parsed = (
+                        fields.get(2).and_then(|id| id.parse().ok()),
+                        fields.get(3).and_then(|latitude| latitude.parse().ok()),
+                        fields.get(4).and_then(|longitude| longitude.parse().ok()),
+                    );
+                    let (Some(id), Some(latitude), Some(longitude)) = parsed else {
+                        return Err(error());
+                    };
+
+                    locations.push(Location {
+                        id,
+                        latitude,
+                        longitude,
+                    });
+                }
+                Some("edge") => match numbers(1)?[..] {
+                    [source, target, weight] if source.max(target) < graph.total_nodes => {
+                        graph.set_edge(source, target, weight)
+                    }
+                    _ => return Err(error()),
+                },
+                Some("rank") => rank = numbers(1)?,
+                Some("shortcuts") => shortcuts = *numbers(1)?.first().ok_or_else(error)?,
+                Some("ch") => {
+                    let middle = match fields.get(4) {
+                        Some(&"-") => None,
+                        Some(middle) => Some(middle.parse().map_err(|_| error())?),
+                        None => return Err(error()),
+                    };
+
+                    match fields[1..4]
+                        .iter()
+                        .map(|field| field.parse())
+                        .collect::<Result<Vec<usize>, _>>()
+                    {
+                        Ok(edge) => edges.insert((edge[0], edge[1]), (edge[2], middle)),
+                        Err(_) => return Err(error()),
+                    };
+                }
+                Some("landmark") => {
+                    let landmark = *numbers(1)?.first().ok_or_else(error)?;
+                    graph.heuristic.landmarks.push(landmark);
+                }
+                Some("from") => graph.heuristic.from_landmark.push(numbers(1)?),
+                Some("to") => graph.heuristic.to_landmark.push(numbers(1)?),
+                None => {}
+                Some(_) => return Err(error()),
+            }
+        }
+
+        let hierarchy = match config.preprocessing {
+            Preprocessing::ContractionHierarchies => {
+                if rank.len() != graph.total_nodes {
+                    return Err(RouterError::Artifact("missing ranks".to_string()));
+                }
+
+                let mut upward = vec![Vec::new(); rank.len()];
+                let mut downward = vec![Vec::new(); rank.len()];
+
+                for (&(source, target), &(weight, _)) in &edges {
+                    if source.max(target) >= rank.len() {
+                        return Err(RouterError::Artifact("edge out of range".to_string()));
+                    }
+
+                    match rank[source] < rank[target] {
+                        true => upward[source].push((target, weight)),
+                        false => downward[target].push((source, weight)),
+                    }
+                }
+
+                for neighbors in upward.iter_mut().chain(downward.iter_mut()) {
+                    neighbors.sort_unstable();
+                }
+
+                Some(ContractionHierarchy {
+                    rank,
+                    upward,
+                    downward,
+                    edges,
+                    shortcuts,
+                })
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            config,
+            graph,
+            hierarchy,
+            locations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Edge records of a small road network.
+    const ROADS: &str = "0 1 4\n0 2 1\n2 1 2\n1 3 5\n2 3 8\n3 4 3\n4 0 7\n# closed road\n5 x 1\n";
+
+    fn router(preprocessing: Preprocessing) -> Router {
+        let mut config = RouterConfig::new();
+        config.set_preprocessing(preprocessing);
+
+        Router::from_edge_list(config, ROADS.lines()).0
+    }
+
+    #[test]
+    fn test_new() {
+        let config = RouterConfig::new();
+        let config_default = RouterConfig::default();
+
+        assert_eq!(config.preprocessing, Preprocessing::None);
+        assert_eq!(config_default.preprocessing, Preprocessing::None);
+        assert!(!config.undirected);
+    }
+
+    #[test]
+    fn test_from_edge_list() {
+        let (router, report) = Router::from_edge_list(RouterConfig::new(), ROADS.lines());
+
+        assert_eq!(router.total_nodes(), 5);
+        assert_eq!(report.loaded, 7);
+        assert_eq!(report.errors.len(), 1);
+
+        let mut config = RouterConfig::new();
+        config.set_undirected(true);
+        let (router, _) = Router::from_edge_list(config, ["0 1 2", "1 2 -3"]);
+
+        assert_eq!(router.route(1, 0), Some((2, vec![1, 0])));
+        assert_eq!(router.route(1, 2), None);
+    }
+
+    #[test]
+    fn test_route() {
+        let nodes: Vec<usize> = (0..5).collect();
+        let distances = router(Preprocessing::None).many_to_many(&nodes, &nodes);
+
+        for preprocessing in [
+            Preprocessing::None,
+            Preprocessing::ContractionHierarchies,
+            Preprocessing::Landmarks(2),
+        ] {
+            let router = router(preprocessing);
+
+            for (source, row) in distances.iter().enumerate() {
+                for (target, &distance) in row.iter().enumerate() {
+                    let route = router.route(source, target).unwrap();
+
+                    assert_eq!(route.0, distance);
+                    assert_eq!(route.1.first(), Some(&source));
+                    assert_eq!(route.1.last(), Some(&target));
+                }
+            }
+
+            assert_eq!(router.route(0, 7), None);
+        }
+    }
+
+    #[test]
+    fn test_many_to_many_and_isochrone() {
+        for preprocessing in [Preprocessing::None, Preprocessing::ContractionHierarchies] {
+            let router = router(preprocessing);
+
+            assert_eq!(
+                router.many_to_many(&[0, 3], &[1, 4]),
+                vec![vec![3, 11], vec![13, 3]]
+            );
+        }
+
+        assert_eq!(
+            router(Preprocessing::None).isochrone(0, 4),
+            vec![(0, 0), (1, 3), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn test_artifact() {
+        for preprocessing in [
+            Preprocessing::None,
+            Preprocessing::ContractionHierarchies,
+            Preprocessing::Landmarks(2),
+        ] {
+            let router = router(preprocessing);
+            let mut artifact = Vec::new();
+            router.write_artifact(&mut artifact).unwrap();
+
+            let restored = Router::read_artifact(artifact.as_slice()).unwrap();
+
+            assert_eq!(restored.config.preprocessing, preprocessing);
+            assert_eq!(restored.graph.adjacency, router.graph.adjacency);
+            assert_eq!(restored.hierarchy, router.hierarchy);
+            assert_eq!(
+                restored.graph.heuristic.from_landmark,
+                router.graph.heuristic.from_landmark
+            );
+            assert_eq!(restored.route(3, 1), router.route(3, 1));
+        }
+
+        assert_eq!(
+            Router::read_artifact("router 1 none 0\nnodes 2\nedge 0 5 1\n".as_bytes()).err(),
+            Some(RouterError::Artifact("line 3: edge 0 5 1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_from_osm_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<osm version="0.6">
+  <node id="30" lat="52.5200" lon="13.4050"/>
+  <node id="10" lat="52.5210" lon="13.4050"/>
+  <node id="20" lat="52.5210" lon="13.4070">
+    <tag k="highway" v="traffic_signals"/>
+  </node>
+  <node id="40" lat="52.6000" lon="13.5000"/>
+  <way id="1">
+    <nd ref="30"/>
+    <nd ref="10"/>
+    <nd ref="20"/>
+    <tag k="highway" v="residential"/>
+    <tag k="oneway" v="yes"/>
+  </way>
+  <way id="2">
+    <nd ref="20"/>
+    <nd ref="30"/>
+    <tag k="highway" v="primary"/>
+  </way>
+  <way id="3">
+    <nd ref="40"/>
+    <nd ref="30"/>
+    <tag k="building" v="yes"/>
+  </way>
+</osm>"#;
+
+        let router = Router::from_osm_xml(RouterConfig::new(), xml).unwrap();
+        let ids: Vec<i64> = router
+            .locations
+            .iter()
+            .map(|location| location.id)
+            .collect();
+
+        // Nodes 10, 20 and 30 are on roads; 0.001° of latitude is about 111 m.
+        assert_eq!(ids, vec![10, 20, 30]);
+        assert_eq!(router.graph.adjacency[2], vec![(0, 111), (1, 175)]);
+        assert_eq!(router.route(0, 2).map(|route| route.1), Some(vec![0, 1, 2]));
+        assert_eq!(router.route(1, 0), Some((286, vec![1, 2, 0])));
+        assert_eq!(router.nearest_node(52.52, 13.4049), Some(2));
+        assert!(matches!(
+            Router::from_osm_xml(RouterConfig::new(), r#"<node id="1" lat="x" lon="0"/>"#),
+            Err(RouterError::Osm(_))
+        ));
+    }
+}
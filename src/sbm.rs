@@ -0,0 +1,338 @@
+use std::collections::HashMap;
+
+use crate::{random::Random, ConfiguredAlgorithm, GraphAlgorithm, GraphError, Provenance};
+
+/// Graph generated by a stochastic block model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockGraph {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Undirected edges as `(source, target)` with `source < target`.
+    pub edges: Vec<(usize, usize)>,
+
+    /// Planted block of each node.
+    pub blocks: Vec<usize>,
+}
+
+/// Stochastic Block Model.
+/// Generate an undirected graph with planted communities, where nodes of the same block
+/// are joined with one probability and nodes of different blocks with another.
+#[derive(Debug, Clone)]
+pub struct StochasticBlockModel {
+    /// Number of nodes in each block.
+    pub block_sizes: Vec<usize>,
+
+    /// Probability of an edge inside a block.
+    pub intra_probability: f64,
+
+    /// Probability of an edge between blocks.
+    pub inter_probability: f64,
+
+    /// Seed of the generator.
+    pub seed: u64,
+}
+
+impl Default for StochasticBlockModel {
+    /// Create a new default instance of the stochastic block model.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the stochastic block model.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StochasticBlockModel {
+    /// Create a new instance of the stochastic block model.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the stochastic block model.
+    pub fn new() -> Self {
+        Self {
+            block_sizes: Vec::new(),
+            intra_probability: 0.0,
+            inter_probability: 0.0,
+            seed: 0,
+        }
+    }
+
+    /// Set the number of nodes in each block.
+    ///
+    /// # Arguments
+    ///
+    /// - `block_sizes`: Number of nodes in each block.
+    pub fn set_block_sizes(&mut self, block_sizes: Vec<usize>) {
+        self.block_sizes = block_sizes;
+    }
+
+    /// Set the edge probabilities.
+    ///
+    /// # Arguments
+    ///
+    /// - `intra`: Probability of an edge inside a block.
+    /// - `inter`: Probability of an edge between blocks.
+    pub fn set_probabilities(&mut self, intra: f64, inter: f64) {
+        self.intra_probability = intra;
+        self.inter_probability = inter;
+    }
+
+    /// Set the seed of the generator.
+    ///
+    /// # Arguments
+    ///
+    /// - `seed`: Seed, the same seed always produces the same graph.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    /// Generate a graph.
+    ///
+    /// # Returns
+    ///
+    /// Generated graph with its planted blocks.
+    pub fn generate(&self) -> BlockGraph {
+        let mut random = Random::new(self.seed);
+        let blocks: Vec<usize> = self
+            .block_sizes
+            .iter()
+            .enumerate()
+            .flat_map(|(block, &size)| std::iter::repeat(block).take(size))
+            .collect();
+
+        let mut edges = Vec::new();
+
+        for source in 0..blocks.len() {
+            for target in source + 1..blocks.len() {
+                let probability = match blocks[source] == blocks[target] {
+                    true => self.intra_probability,
+                    false => self.inter_probability,
+                };
+
+                if random.next_f64() < probability {
+                    edges.push((source, target));
+                }
+            }
+        }
+
+        BlockGraph {
+            total_nodes: blocks.len(),
+            edges,
+            blocks,
+        }
+    }
+}
+
+impl GraphAlgorithm for StochasticBlockModel {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = BlockGraph;
+
+    /// Run the stochastic block model generator.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the stochastic block model.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the generated graph with its planted blocks.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.generate())
+    }
+}
+
+impl ConfiguredAlgorithm for StochasticBlockModel {
+    /// Describe the configuration of the stochastic block model.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("sbm")
+            .with("block_sizes", &self.block_sizes)
+            .with("intra_probability", self.intra_probability)
+            .with("inter_probability", self.inter_probability)
+            .with("seed", self.seed)
+    }
+}
+
+/// Sizes of the groups of two partitions and of their intersections.
+struct Contingency {
+    /// Number of nodes labeled `(left, right)`.
+    joint: HashMap<(usize, usize), usize>,
+
+    /// Number of nodes with each label of the first partition.
+    left: HashMap<usize, usize>,
+
+    /// Number of nodes with each label of the second partition.
+    right: HashMap<usize, usize>,
+}
+
+impl Contingency {
+    /// Count the co-occurrences of the labels of two partitions.
+    ///
+    /// # Arguments
+    ///
+    /// - `left`: Label of each node in the first partition.
+    /// - `right`: Label of each node in the second partition.
+    ///
+    /// # Returns
+    ///
+    /// Contingency table of the partitions.
+    fn new(left: &[usize], right: &[usize]) -> Self {
+        let mut table = Self {
+            joint: HashMap::new(),
+            left: HashMap::new(),
+            right: HashMap::new(),
+        };
+
+        for (&a, &b) in left.iter().zip(right) {
+            *table.joint.entry((a, b)).or_default() += 1;
+            *table.left.entry(a).or_default() += 1;
+            *table.right.entry(b).or_default() += 1;
+        }
+
+        table
+    }
+}
+
+/// Compute the normalized mutual information of two partitions, normalized by the mean of their entropies.
+///
+/// # Arguments
+///
+/// - `left`: Label of each node in the first partition, e.g. the planted blocks.
+/// - `right`: Label of each node in the second partition, e.g. the detected communities.
+///
+/// # Returns
+///
+/// Score between 0.0 (independent) and 1.0 (identical up to relabeling).
+pub fn normalized_mutual_information(left: &[usize], right: &[usize]) -> f64 {
+    let table = Contingency::new(left, right);
+    let total = left.len().min(right.len()) as f64;
+    let entropy = |sizes: &HashMap<usize, usize>| -> f64 {
+        sizes
+            .values()
+            .map(|&size| size as f64 / total)
+            .map(|p| -p * p.ln())
+            .sum()
+    };
+
+    let mutual: f64 = table
+        .joint
+        .iter()
+        .map(|(&(a, b), &size)| {
+            let expected = (table.left[&a] * table.right[&b]) as f64 / total;
+            size as f64 / total * (size as f64 / expected).ln()
+        })
+        .sum();
+
+    match entropy(&table.left) + entropy(&table.right) {
+        0.0 => 1.0,
+        sum => (2.0 * mutual / sum).clamp(0.0, 1.0),
+    }
+}
+
+/// Compute the adjusted Rand index of two partitions.
+///
+/// # Arguments
+///
+/// - `left`: Label of each node in the first partition, e.g. the planted blocks.
+/// - `right`: Label of each node in the second partition, e.g. the detected communities.
+///
+/// # Returns
+///
+/// Score of 1.0 for identical partitions up to relabeling, around 0.0 for random ones.
+pub fn adjusted_rand_index(left: &[usize], right: &[usize]) -> f64 {
+    let pairs = |size: usize| (size * size.saturating_sub(1) / 2) as f64;
+    let table = Contingency::new(left, right);
+
+    let index: f64 = table.joint.into_values().map(pairs).sum();
+    let left_pairs: f64 = table.left.into_values().map(pairs).sum();
+    let right_pairs: f64 = table.right.into_values().map(pairs).sum();
+    let expected = left_pairs * right_pairs / pairs(left.len().min(right.len())).max(1.0);
+    let maximum = (left_pairs + right_pairs) / 2.0;
+
+    match maximum - expected {
+        0.0 => 1.0,
+        denominator => (index - expected) / denominator,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(left: f64, right: f64) {
+        assert!((left - right).abs() < 1e-9, "{left} != {right}");
+    }
+
+    #[test]
+    fn test_new() {
+        let model = StochasticBlockModel::new();
+        let model_default = StochasticBlockModel::default();
+
+        assert_eq!(model.seed, 0);
+        assert_eq!(model_default.block_sizes.len(), 0);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut model = StochasticBlockModel::new();
+        model.set_block_sizes(vec![20, 30]);
+        model.set_probabilities(0.5, 0.05);
+        model.set_seed(1);
+
+        let graph = model.run(None).unwrap();
+        let (intra, inter): (Vec<&(usize, usize)>, Vec<_>) = graph
+            .edges
+            .iter()
+            .partition(|&&(source, target)| graph.blocks[source] == graph.blocks[target]);
+
+        assert_eq!(graph.total_nodes, 50);
+        assert_eq!(graph.blocks[19..21], [0, 1]);
+        assert!(intra.len() > 4 * inter.len());
+        assert!(graph.edges.iter().all(|&(source, target)| source < target));
+        assert_eq!(model.run(None).unwrap(), graph);
+    }
+
+    #[test]
+    fn test_run_probabilities() {
+        let mut model = StochasticBlockModel::new();
+        model.set_block_sizes(vec![3, 2]);
+        model.set_probabilities(1.0, 0.0);
+
+        assert_eq!(model.generate().edges, vec![(0, 1), (0, 2), (1, 2), (3, 4)]);
+    }
+
+    #[test]
+    fn test_normalized_mutual_information() {
+        assert_close(
+            normalized_mutual_information(&[0, 0, 1, 1], &[5, 5, 3, 3]),
+            1.0,
+        );
+        assert_close(
+            normalized_mutual_information(&[0, 0, 1, 1], &[0, 1, 0, 1]),
+            0.0,
+        );
+        assert_close(normalized_mutual_information(&[0, 0, 0], &[1, 1, 1]), 1.0);
+        assert_close(
+            normalized_mutual_information(&[0, 0, 0, 1, 1, 1], &[0, 0, 1, 1, 2, 2]),
+            0.5158037429793888,
+        );
+    }
+
+    #[test]
+    fn test_adjusted_rand_index() {
+        assert_close(adjusted_rand_index(&[0, 0, 1, 1], &[1, 1, 0, 0]), 1.0);
+        assert_close(adjusted_rand_index(&[0, 0, 1, 1], &[0, 1, 0, 1]), -0.5);
+        assert_close(
+            adjusted_rand_index(&[0, 0, 0, 1, 1, 1], &[0, 0, 1, 1, 2, 2]),
+            0.24242424242424243,
+        );
+    }
+}
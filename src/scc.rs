@@ -0,0 +1,99 @@
+/// Find the strongly connected components of a directed graph with Tarjan's Algorithm.
+///
+/// # Arguments
+///
+/// - `adjacency`: Successors of each node.
+///
+/// # Returns
+///
+/// Components with their nodes in ascending order, in reverse topological order:
+/// every edge between components leads to an earlier component.
+pub(crate) fn strongly_connected_components(adjacency: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let n = adjacency.len();
+    let mut index = vec![usize::MAX; n];
+    let mut low = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut next = 0;
+
+    for root in 0..n {
+        if index[root] != usize::MAX {
+            continue;
+        }
+
+        // Explicit call stack of nodes with the position of their next successor.
+        let mut calls = vec![(root, 0)];
+        index[root] = next;
+        low[root] = next;
+        next += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some(&mut (node, ref mut child)) = calls.last_mut() {
+            if let Some(&target) = adjacency[node].get(*child) {
+                *child += 1;
+
+                if index[target] == usize::MAX {
+                    index[target] = next;
+                    low[target] = next;
+                    next += 1;
+                    stack.push(target);
+                    on_stack[target] = true;
+                    calls.push((target, 0));
+                } else if on_stack[target] {
+                    low[node] = low[node].min(index[target]);
+                }
+
+                continue;
+            }
+
+            calls.pop();
+
+            if let Some(&(parent, _)) = calls.last() {
+                low[parent] = low[parent].min(low[node]);
+            }
+
+            if low[node] == index[node] {
+                let mut component = Vec::new();
+
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component.push(member);
+
+                    if member == node {
+                        break;
+                    }
+                }
+
+                component.sort_unstable();
+                components.push(component);
+            }
+        }
+    }
+
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let adjacency = vec![
+            vec![1],
+            vec![2],
+            vec![0, 3],
+            vec![4],
+            vec![5],
+            vec![3],
+            vec![],
+        ];
+
+        assert_eq!(
+            strongly_connected_components(&adjacency),
+            vec![vec![3, 4, 5], vec![0, 1, 2], vec![6]]
+        );
+    }
+}
@@ -0,0 +1,415 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+use crate::{GraphAlgorithm, GraphError};
+
+/// Series-Parallel Recognition.
+/// Detect two-terminal series-parallel graphs by repeatedly merging edges in series or in parallel.
+#[derive(Debug, Clone)]
+pub struct SeriesParallelAlgorithm {
+    /// Undirected edges in the graph, identified by their index.
+    pub edges: Vec<(usize, usize)>,
+
+    /// First terminal of the graph.
+    pub source: Option<usize>,
+
+    /// Second terminal of the graph.
+    pub sink: Option<usize>,
+}
+
+/// Single step of the series-parallel reduction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reduction {
+    /// Two edges meeting at a non-terminal node of degree two are replaced by a new edge.
+    Series {
+        /// Removed node between the edges.
+        node: usize,
+
+        /// First merged edge.
+        first: usize,
+
+        /// Second merged edge.
+        second: usize,
+
+        /// New edge.
+        edge: usize,
+    },
+
+    /// Two edges between the same pair of nodes are replaced by a new edge.
+    Parallel {
+        /// First merged edge.
+        first: usize,
+
+        /// Second merged edge.
+        second: usize,
+
+        /// New edge.
+        edge: usize,
+    },
+}
+
+/// Reduction sequence of a series-parallel graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeriesParallelDecomposition {
+    /// Reductions in the order they were applied.
+    /// New edges are numbered after the original ones.
+    pub reductions: Vec<Reduction>,
+
+    /// Single edge between the terminals the graph reduces to.
+    pub root: usize,
+}
+
+impl SeriesParallelDecomposition {
+    /// Evaluate a quantity over the decomposition bottom-up,
+    /// e.g. the equivalent resistance of an electrical network or the shortest path between the terminals.
+    ///
+    /// # Arguments
+    ///
+    /// - `values`: Value of each original edge.
+    /// - `series`: Combine two edges in series.
+    /// - `parallel`: Combine two edges in parallel.
+    ///
+    /// # Returns
+    ///
+    /// Value of the whole graph, or `None` if a value of an original edge is missing.
+    pub fn evaluate<T, S, P>(&self, values: &[T], series: S, parallel: P) -> Option<T>
+    where
+        T: Clone,
+        S: Fn(&T, &T) -> T,
+        P: Fn(&T, &T) -> T,
+    {
+        let mut values: Vec<Option<T>> = values.iter().cloned().map(Some).collect();
+
+        for reduction in &self.reductions {
+            let (value, edge) = match *reduction {
+                Reduction::Series {
+                    first,
+                    second,
+                    edge,
+                    ..
+                } => (
+                    series(values.get(first)?.as_ref()?, values.get(second)?.as_ref()?),
+                    edge,
+                ),
+                Reduction::Parallel {
+                    first,
+                    second,
+                    edge,
+                } => (
+                    parallel(values.get(first)?.as_ref()?, values.get(second)?.as_ref()?),
+                    edge,
+                ),
+            };
+
+            if values.len() <= edge {
+                values.resize(edge + 1, None);
+            }
+
+            values[edge] = Some(value);
+        }
+
+        values.get(self.root).cloned().flatten()
+    }
+}
+
+impl Default for SeriesParallelAlgorithm {
+    /// Create a new default instance of the series-parallel recognition.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the series-parallel recognition.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SeriesParallelAlgorithm {
+    /// Create a new instance of the series-parallel recognition.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the series-parallel recognition.
+    pub fn new() -> Self {
+        Self {
+            edges: Vec::new(),
+            source: None,
+            sink: None,
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Index of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize) -> usize {
+        self.edges.push((source, target));
+        self.edges.len() - 1
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its neighbors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the terminals of the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: First terminal.
+    /// - `sink`: Second terminal.
+    pub fn set_terminals(&mut self, source: usize, sink: usize) {
+        self.source = Some(source);
+        self.sink = Some(sink);
+    }
+
+    /// Determine whether the graph is series-parallel between its terminals.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the graph reduces to a single edge between the terminals.
+    pub fn is_series_parallel(&self) -> bool {
+        self.run(None).is_ok()
+    }
+}
+
+impl GraphAlgorithm for SeriesParallelAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = SeriesParallelDecomposition;
+
+    /// Run the series-parallel recognition.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used, the terminals are set with `set_terminals`.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the reduction sequence, or an error if the graph is not series-parallel.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let source = self.source.ok_or(GraphError::MissingStartNode)?;
+        let sink = self.sink.ok_or(GraphError::MissingStartNode)?;
+
+        let mut edges: HashMap<usize, (usize, usize)> =
+            self.edges.iter().copied().enumerate().collect();
+        let mut incident: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
+
+        for (&edge, &(u, v)) in &edges {
+            incident.entry(u).or_default().insert(edge);
+            incident.entry(v).or_default().insert(edge);
+        }
+
+        let other = |(u, v): (usize, usize), node: usize| if u == node { v } else { u };
+        let mut next_edge = self.edges.len();
+        let mut reductions = Vec::new();
+        let mut queue: VecDeque<usize> = incident.keys().copied().collect();
+
+        while let Some(node) = queue.pop_front() {
+            // Merge parallel edges incident to the node.
+            'parallel: loop {
+                let mut by_neighbor: BTreeMap<usize, usize> = BTreeMap::new();
+
+                for &edge in incident.get(&node).into_iter().flatten() {
+                    let neighbor = other(edges[&edge], node);
+
+                    if let Some(first) = by_neighbor.insert(neighbor, edge) {
+                        if neighbor == node {
+                            break 'parallel;
+                        }
+
+                        let second = edge;
+                        let edge = next_edge;
+                        next_edge += 1;
+
+                        for removed in [first, second] {
+                            edges.remove(&removed);
+                            incident.get_mut(&node).map(|e| e.remove(&removed));
+                            incident.get_mut(&neighbor).map(|e| e.remove(&removed));
+                        }
+
+                        edges.insert(edge, (node, neighbor));
+                        incident.entry(node).or_default().insert(edge);
+                        incident.entry(neighbor).or_default().insert(edge);
+                        reductions.push(Reduction::Parallel {
+                            first,
+                            second,
+                            edge,
+                        });
+                        queue.push_back(neighbor);
+
+                        continue 'parallel;
+                    }
+                }
+
+                break;
+            }
+
+            // Merge the two edges of a non-terminal node of degree two.
+            if node == source || node == sink {
+                continue;
+            }
+
+            let pair: Vec<usize> = incident.get(&node).into_iter().flatten().copied().collect();
+
+            if let [first, second] = pair[..] {
+                let (a, b) = (other(edges[&first], node), other(edges[&second], node));
+
+                if a == node || b == node || a == b {
+                    continue;
+                }
+
+                let edge = next_edge;
+                next_edge += 1;
+
+                edges.remove(&first);
+                edges.remove(&second);
+                incident.remove(&node);
+                incident.get_mut(&a).map(|e| e.remove(&first));
+                incident.get_mut(&b).map(|e| e.remove(&second));
+
+                edges.insert(edge, (a, b));
+                incident.entry(a).or_default().insert(edge);
+                incident.entry(b).or_default().insert(edge);
+                reductions.push(Reduction::Series {
+                    node,
+                    first,
+                    second,
+                    edge,
+                });
+                queue.push_back(a);
+                queue.push_back(b);
+            }
+        }
+
+        match edges.into_iter().collect::<Vec<_>>()[..] {
+            [(root, (u, v))] if (u, v) == (source, sink) || (v, u) == (source, sink) => {
+                Ok(SeriesParallelDecomposition { reductions, root })
+            }
+            _ => Err(GraphError::NotSeriesParallel),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let algorithm = SeriesParallelAlgorithm::new();
+        let algorithm_default = SeriesParallelAlgorithm::default();
+
+        assert_eq!(algorithm.edges.len(), 0);
+        assert_eq!(algorithm_default.edges.len(), 0);
+    }
+
+    #[test]
+    fn test_missing_terminals() {
+        let algorithm = SeriesParallelAlgorithm::new();
+
+        assert_eq!(algorithm.run(None), Err(GraphError::MissingStartNode));
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = SeriesParallelAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 2]),
+            (1, vec![3]),
+            (2, vec![3]),
+            (0, vec![3]),
+        ]);
+        algorithm.set_terminals(0, 3);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.reductions.len(), 4);
+        assert!(algorithm.is_series_parallel());
+    }
+
+    #[test]
+    fn test_run_single_edge() {
+        let mut algorithm = SeriesParallelAlgorithm::new();
+        algorithm.set_edge(0, 1);
+        algorithm.set_terminals(1, 0);
+
+        assert_eq!(
+            algorithm.run(None).unwrap(),
+            SeriesParallelDecomposition {
+                reductions: vec![],
+                root: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_run_wheatstone_bridge() {
+        let mut algorithm = SeriesParallelAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1, 2]), (1, vec![2, 3]), (2, vec![3])]);
+        algorithm.set_terminals(0, 3);
+
+        assert_eq!(algorithm.run(None), Err(GraphError::NotSeriesParallel));
+        assert!(!algorithm.is_series_parallel());
+    }
+
+    #[test]
+    fn test_run_dangling_edge() {
+        let mut algorithm = SeriesParallelAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1]), (1, vec![2, 3])]);
+        algorithm.set_terminals(0, 2);
+
+        assert_eq!(algorithm.run(None), Err(GraphError::NotSeriesParallel));
+    }
+
+    #[test]
+    fn test_run_empty_graph() {
+        let mut algorithm = SeriesParallelAlgorithm::new();
+        algorithm.set_terminals(0, 1);
+
+        assert_eq!(algorithm.run(None), Err(GraphError::NotSeriesParallel));
+    }
+
+    #[test]
+    fn test_evaluate_resistance() {
+        let mut algorithm = SeriesParallelAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1, 2]), (1, vec![3]), (2, vec![3])]);
+        algorithm.set_terminals(0, 3);
+
+        let result = algorithm.run(None).unwrap();
+        let resistance: f64 = result
+            .evaluate(&[1.0, 2.0, 3.0, 4.0], |a, b| a + b, |a, b| a * b / (a + b))
+            .unwrap();
+
+        assert!((resistance - 2.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_evaluate_shortest_path() {
+        let mut algorithm = SeriesParallelAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1, 2, 3]), (1, vec![3]), (2, vec![3])]);
+        algorithm.set_terminals(0, 3);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(
+            result.evaluate(&[1, 5, 9, 2, 1], |a, b| a + b, |a, b| *a.min(b)),
+            Some(3)
+        );
+        assert_eq!(result.evaluate(&[1], |a, b| a + b, |a, b| *a.min(b)), None);
+    }
+}
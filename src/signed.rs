@@ -0,0 +1,291 @@
+use std::collections::VecDeque;
+
+use crate::{GraphAlgorithm, GraphError};
+
+/// Sign of an edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sign {
+    /// Positive relation, e.g. trust.
+    Positive,
+
+    /// Negative relation, e.g. distrust.
+    Negative,
+}
+
+/// Structural Balance.
+/// Determine whether a signed undirected graph is balanced, i.e. its nodes split into two factions
+/// with positive edges inside and negative edges between them, and estimate its frustration index.
+#[derive(Debug, Clone)]
+pub struct SignedGraphAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Edges in the graph.
+    pub edges: Vec<(usize, usize, Sign)>,
+}
+
+impl Default for SignedGraphAlgorithm {
+    /// Create a new default instance of the structural balance algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the structural balance algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SignedGraphAlgorithm {
+    /// Create a new instance of the structural balance algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the structural balance algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            edges: Vec::new(),
+        }
+    }
+
+    /// Set a single undirected signed edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `sign`: Sign of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, sign: Sign) {
+        self.edges.push((source, target, sign));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its signed edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, Sign)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, sign) in edges {
+                self.set_edge(source, target, sign);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+    }
+
+    /// Determine whether an edge is frustrated by a partition.
+    ///
+    /// # Arguments
+    ///
+    /// - `edge`: Signed edge.
+    /// - `partition`: Faction (0 or 1) of each node.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a positive edge joins two factions or a negative edge lies inside one.
+    fn is_frustrated(&(source, target, sign): &(usize, usize, Sign), partition: &[usize]) -> bool {
+        (partition[source] == partition[target]) == (sign == Sign::Negative)
+    }
+
+    /// Count the edges frustrated by a partition.
+    ///
+    /// # Arguments
+    ///
+    /// - `partition`: Faction (0 or 1) of each node.
+    ///
+    /// # Returns
+    ///
+    /// Number of frustrated edges.
+    pub fn frustration(&self, partition: &[usize]) -> usize {
+        self.edges
+            .iter()
+            .filter(|edge| Self::is_frustrated(edge, partition))
+            .count()
+    }
+
+    /// Assign factions along a breadth-first search, switching faction across negative edges.
+    ///
+    /// # Returns
+    ///
+    /// Faction (0 or 1) of each node. The partition has no frustrated edge if the graph is balanced.
+    fn spanning_partition(&self) -> Vec<usize> {
+        let mut adjacency = vec![Vec::new(); self.total_nodes];
+
+        for &(source, target, sign) in &self.edges {
+            let switch = usize::from(sign == Sign::Negative);
+            adjacency[source].push((target, switch));
+            adjacency[target].push((source, switch));
+        }
+
+        let mut partition = vec![usize::MAX; self.total_nodes];
+
+        for root in 0..self.total_nodes {
+            if partition[root] != usize::MAX {
+                continue;
+            }
+
+            partition[root] = 0;
+            let mut queue = VecDeque::from([root]);
+
+            while let Some(node) = queue.pop_front() {
+                for &(neighbor, switch) in &adjacency[node] {
+                    if partition[neighbor] == usize::MAX {
+                        partition[neighbor] = partition[node] ^ switch;
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+
+        partition
+    }
+
+    /// Determine whether the graph is structurally balanced.
+    ///
+    /// # Returns
+    ///
+    /// `true` if every cycle has an even number of negative edges.
+    pub fn is_balanced(&self) -> bool {
+        self.frustration(&self.spanning_partition()) == 0
+    }
+
+    /// Estimate the frustration index, the minimum number of edges whose removal balances the graph.
+    /// Starting from a spanning tree partition, nodes switch faction while that reduces the frustration.
+    ///
+    /// # Returns
+    ///
+    /// Upper bound of the frustration index and the partition achieving it.
+    pub fn frustration_index(&self) -> (usize, Vec<usize>) {
+        let mut partition = self.spanning_partition();
+        let mut incident = vec![Vec::new(); self.total_nodes];
+
+        for (index, &(source, target, _)) in self.edges.iter().enumerate() {
+            incident[source].push(index);
+
+            if source != target {
+                incident[target].push(index);
+            }
+        }
+
+        let mut improved = true;
+
+        while improved {
+            improved = false;
+
+            for node in 0..self.total_nodes {
+                let (frustrated, satisfied): (Vec<usize>, Vec<usize>) = incident[node]
+                    .iter()
+                    .filter(|&&index| self.edges[index].0 != self.edges[index].1)
+                    .partition(|&&index| Self::is_frustrated(&self.edges[index], &partition));
+
+                if frustrated.len() > satisfied.len() {
+                    partition[node] ^= 1;
+                    improved = true;
+                }
+            }
+        }
+
+        (self.frustration(&partition), partition)
+    }
+}
+
+impl GraphAlgorithm for SignedGraphAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<usize>;
+
+    /// Run the structural balance check.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the structural balance check.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the faction (0 or 1) of each node, or an error if the graph is not balanced.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let partition = self.spanning_partition();
+
+        match self.frustration(&partition) {
+            0 => Ok(partition),
+            _ => Err(GraphError::NotBalanced),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use Sign::{Negative, Positive};
+
+    #[test]
+    fn test_new() {
+        let algorithm = SignedGraphAlgorithm::new();
+        let algorithm_default = SignedGraphAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.edges.len(), 0);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = SignedGraphAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, Positive), (2, Negative)]),
+            (1, vec![(3, Negative)]),
+            (2, vec![(3, Positive)]),
+            (4, vec![]),
+        ]);
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![0, 0, 1, 1, 0]);
+        assert!(algorithm.is_balanced());
+        assert_eq!(algorithm.frustration_index().0, 0);
+    }
+
+    #[test]
+    fn test_run_not_balanced() {
+        let mut algorithm = SignedGraphAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, Negative), (2, Negative)]),
+            (1, vec![(2, Negative)]),
+        ]);
+
+        assert_eq!(algorithm.run(None), Err(GraphError::NotBalanced));
+        assert!(!algorithm.is_balanced());
+        assert_eq!(algorithm.frustration_index().0, 1);
+    }
+
+    #[test]
+    fn test_frustration_index_local_search() {
+        // The spanning tree puts node 3 with its only positive neighbor, frustrating its three negative edges.
+        let mut algorithm = SignedGraphAlgorithm::new();
+        algorithm.set_edges(vec![
+            (
+                0,
+                vec![(1, Positive), (2, Positive), (3, Positive), (4, Positive)],
+            ),
+            (3, vec![(1, Negative), (2, Negative), (4, Negative)]),
+        ]);
+
+        assert_eq!(algorithm.frustration(&algorithm.spanning_partition()), 3);
+
+        let (frustration, partition) = algorithm.frustration_index();
+
+        assert_eq!(frustration, 1);
+        assert_eq!(algorithm.frustration(&partition), 1);
+    }
+}
@@ -0,0 +1,382 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::{GraphAlgorithm, GraphError};
+
+/// Graph Simplification.
+/// Contract degree-2 pass-through nodes by merging their incident edges while preserving shortest paths between the remaining nodes.
+#[derive(Debug, Clone)]
+pub struct SimplificationAlgorithm {
+    /// Graph to simplify.
+    pub graph: HashMap<usize, Vec<(usize, usize)>>,
+
+    /// Nodes that must never be contracted.
+    pub protected: HashSet<usize>,
+}
+
+/// Result of the graph simplification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimplifiedGraph {
+    /// Simplified graph, keeping the cheapest of parallel edges.
+    pub graph: HashMap<usize, Vec<(usize, usize)>>,
+
+    /// Contracted nodes, in the order they were removed.
+    pub removed: Vec<usize>,
+
+    /// Contracted nodes traversed by each simplified edge, in path order.
+    pub via: HashMap<(usize, usize), Vec<usize>>,
+}
+
+impl SimplifiedGraph {
+    /// Expand a path of the simplified graph back to a path of the original graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: Sequence of nodes in the simplified graph.
+    ///
+    /// # Returns
+    ///
+    /// Sequence of nodes in the original graph.
+    pub fn expand_path(&self, path: &[usize]) -> Vec<usize> {
+        let mut expanded = Vec::with_capacity(path.len());
+
+        for (index, &node) in path.iter().enumerate() {
+            if index > 0 {
+                if let Some(via) = self.via.get(&(path[index - 1], node)) {
+                    expanded.extend_from_slice(via);
+                }
+            }
+
+            expanded.push(node);
+        }
+
+        expanded
+    }
+}
+
+impl Default for SimplificationAlgorithm {
+    /// Create a new default instance of the graph simplification.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the graph simplification.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SimplificationAlgorithm {
+    /// Create a new instance of the graph simplification.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the graph simplification.
+    pub fn new() -> Self {
+        Self {
+            graph: HashMap::new(),
+            protected: HashSet::new(),
+        }
+    }
+
+    /// Set a single directed edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.graph.entry(source).or_default().push((target, weight));
+        self.graph.entry(target).or_default();
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            self.graph.entry(source).or_default();
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Protect a node from being contracted, e.g. a query endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node to keep.
+    pub fn set_protected(&mut self, node: usize) {
+        self.protected.insert(node);
+    }
+
+    /// Determine whether a node only passes traffic between its two neighbors.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node to check.
+    /// - `outgoing`: Outgoing edges of each node.
+    /// - `incoming`: Incoming neighbors of each node.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the node can be contracted.
+    fn is_pass_through(
+        &self,
+        node: usize,
+        outgoing: &HashMap<usize, HashMap<usize, (usize, Vec<usize>)>>,
+        incoming: &HashMap<usize, BTreeSet<usize>>,
+    ) -> bool {
+        if self.protected.contains(&node) {
+            return false;
+        }
+
+        let targets: BTreeSet<usize> = outgoing[&node].keys().copied().collect();
+        let sources = &incoming[&node];
+        let neighbors: Vec<usize> = targets.union(sources).copied().collect();
+
+        if neighbors.len() != 2 || neighbors.contains(&node) {
+            return false;
+        }
+
+        let other = |neighbor: usize| {
+            if neighbor == neighbors[0] {
+                neighbors[1]
+            } else {
+                neighbors[0]
+            }
+        };
+
+        // Every way in must have a way out on the other side, and vice versa.
+        !sources.is_empty()
+            && sources
+                .iter()
+                .all(|&source| targets.contains(&other(source)))
+            && targets
+                .iter()
+                .all(|&target| sources.contains(&other(target)))
+    }
+}
+
+impl GraphAlgorithm for SimplificationAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = SimplifiedGraph;
+
+    /// Run the graph simplification.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the graph simplification.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the simplified graph and the mapping back to the original graph.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let mut outgoing: HashMap<usize, HashMap<usize, (usize, Vec<usize>)>> = HashMap::new();
+        let mut incoming: HashMap<usize, BTreeSet<usize>> = HashMap::new();
+
+        for (&source, edges) in &self.graph {
+            outgoing.entry(source).or_default();
+            incoming.entry(source).or_default();
+
+            for &(target, weight) in edges {
+                outgoing.entry(target).or_default();
+                incoming.entry(target).or_default().insert(source);
+
+                let edge = outgoing
+                    .entry(source)
+                    .or_default()
+                    .entry(target)
+                    .or_insert((weight, Vec::new()));
+                edge.0 = edge.0.min(weight);
+            }
+        }
+
+        let mut worklist: BTreeSet<usize> = outgoing.keys().copied().collect();
+        let mut removed = Vec::new();
+
+        while let Some(node) = worklist.pop_first() {
+            if !outgoing.contains_key(&node) || !self.is_pass_through(node, &outgoing, &incoming) {
+                continue;
+            }
+
+            let targets = outgoing.remove(&node).unwrap_or_default();
+            let sources = incoming.remove(&node).unwrap_or_default();
+
+            for &source in &sources {
+                let (in_weight, in_via) = outgoing
+                    .get_mut(&source)
+                    .and_then(|edges| edges.remove(&node))
+                    .unwrap_or_default();
+
+                for (&target, (out_weight, out_via)) in &targets {
+                    if target == source {
+                        continue;
+                    }
+
+                    let weight = in_weight + out_weight;
+                    let via: Vec<usize> = in_via
+                        .iter()
+                        .copied()
+                        .chain([node])
+                        .chain(out_via.iter().copied())
+                        .collect();
+
+                    let edges = outgoing.entry(source).or_default();
+
+                    // Keep only the cheapest of parallel edges.
+                    if edges.get(&target).map(|&(w, _)| weight < w).unwrap_or(true) {
+                        edges.insert(target, (weight, via));
+                    }
+
+                    incoming.entry(target).or_default().insert(source);
+                }
+            }
+
+            for target in targets.keys() {
+                if let Some(sources) = incoming.get_mut(target) {
+                    sources.remove(&node);
+                }
+            }
+
+            worklist.extend(sources);
+            worklist.extend(targets.into_keys());
+            removed.push(node);
+        }
+
+        let mut graph = HashMap::new();
+        let mut via = HashMap::new();
+
+        for (source, edges) in outgoing {
+            let mut adjacency = Vec::with_capacity(edges.len());
+
+            for (target, (weight, nodes)) in edges {
+                adjacency.push((target, weight));
+
+                if !nodes.is_empty() {
+                    via.insert((source, target), nodes);
+                }
+            }
+
+            adjacency.sort_unstable();
+            graph.insert(source, adjacency);
+        }
+
+        Ok(SimplifiedGraph {
+            graph,
+            removed,
+            via,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let algorithm = SimplificationAlgorithm::new();
+        let algorithm_default = SimplificationAlgorithm::default();
+
+        assert_eq!(algorithm.graph.len(), 0);
+        assert_eq!(algorithm_default.graph.len(), 0);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = SimplificationAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 2), (4, 1)]),
+            (1, vec![(2, 3)]),
+            (2, vec![(3, 4)]),
+            (4, vec![(3, 1)]),
+            (3, vec![(5, 1)]),
+        ]);
+        algorithm.set_protected(3);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.removed, vec![1, 2, 4]);
+        assert_eq!(result.graph[&0], vec![(3, 2)]);
+        assert_eq!(result.graph[&3], vec![(5, 1)]);
+        assert_eq!(result.via[&(0, 3)], vec![4]);
+        assert_eq!(result.expand_path(&[0, 3, 5]), vec![0, 4, 3, 5]);
+    }
+
+    #[test]
+    fn test_run_two_way_chain() {
+        let mut algorithm = SimplificationAlgorithm::new();
+
+        for (source, target, weight) in [(0, 1, 1), (1, 2, 2), (2, 3, 3), (3, 4, 1), (4, 0, 9)] {
+            algorithm.set_edge(source, target, weight);
+            algorithm.set_edge(target, source, weight);
+        }
+
+        algorithm.set_protected(0);
+        algorithm.set_protected(3);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.graph[&0], vec![(3, 6)]);
+        assert_eq!(result.graph[&3], vec![(0, 6)]);
+        assert_eq!(result.expand_path(&[0, 3]), vec![0, 1, 2, 3]);
+        assert_eq!(result.expand_path(&[3, 0]), vec![3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_run_keeps_junctions() {
+        let mut algorithm = SimplificationAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 1), (2, 1), (3, 1)])]);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert!(result.removed.is_empty());
+        assert_eq!(result.graph[&0], vec![(1, 1), (2, 1), (3, 1)]);
+    }
+
+    #[test]
+    fn test_run_keeps_dead_ends() {
+        let mut algorithm = SimplificationAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 1)]), (2, vec![(1, 1)])]);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert!(result.removed.is_empty());
+    }
+
+    #[test]
+    fn test_run_cycle() {
+        let mut algorithm = SimplificationAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 1)]),
+            (1, vec![(2, 1)]),
+            (2, vec![(0, 1)]),
+        ]);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.removed.len(), 1);
+        assert_eq!(result.graph.len(), 2);
+    }
+
+    #[test]
+    fn test_run_empty_graph() {
+        let algorithm = SimplificationAlgorithm::new();
+
+        assert_eq!(
+            algorithm.run(None).unwrap(),
+            SimplifiedGraph {
+                graph: HashMap::new(),
+                removed: vec![],
+                via: HashMap::new(),
+            }
+        );
+    }
+}
@@ -0,0 +1,376 @@
+use crate::{random::Random, ConfiguredAlgorithm, GraphAlgorithm, GraphError, Provenance};
+
+/// Spectral coordinates of the nodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpectralCoordinates {
+    /// Coordinates of each node, one per dimension.
+    pub coordinates: Vec<Vec<f64>>,
+
+    /// Laplacian eigenvalue of each dimension, in increasing order.
+    pub eigenvalues: Vec<f64>,
+}
+
+/// Spectral Embedding.
+/// Place the nodes of an undirected weighted graph at the coordinates given by the eigenvectors
+/// of the smallest non-zero Laplacian eigenvalues, computed by power iteration with deflation.
+#[derive(Debug, Clone)]
+pub struct SpectralAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Edges in the graph.
+    pub edges: Vec<(usize, usize, f64)>,
+
+    /// Number of dimensions of the embedding.
+    pub dimensions: usize,
+
+    /// Maximum number of iterations per dimension.
+    pub max_iterations: usize,
+
+    /// Maximum change of an eigenvector to stop iterating.
+    pub tolerance: f64,
+
+    /// Seed of the initial vectors.
+    pub seed: u64,
+}
+
+impl Default for SpectralAlgorithm {
+    /// Create a new default instance of the spectral embedding.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the spectral embedding.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpectralAlgorithm {
+    /// Create a new instance of the spectral embedding.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the spectral embedding.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            edges: Vec::new(),
+            dimensions: 2,
+            max_iterations: 10_000,
+            tolerance: 1e-9,
+            seed: 0,
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: f64) {
+        self.edges.push((source, target, weight));
+        self.total_nodes = self.total_nodes.max(source + 1).max(target + 1);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, f64)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+    }
+
+    /// Set the number of dimensions of the embedding.
+    ///
+    /// # Arguments
+    ///
+    /// - `dimensions`: Number of dimensions, e.g. 2 or 3 for a layout.
+    pub fn set_dimensions(&mut self, dimensions: usize) {
+        self.dimensions = dimensions;
+    }
+
+    /// Set the maximum number of iterations per dimension.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_iterations`: Maximum number of iterations.
+    pub fn set_max_iterations(&mut self, max_iterations: usize) {
+        self.max_iterations = max_iterations;
+    }
+
+    /// Set the tolerance of the iterations.
+    ///
+    /// # Arguments
+    ///
+    /// - `tolerance`: Maximum change of an eigenvector to stop iterating.
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        self.tolerance = tolerance;
+    }
+
+    /// Set the seed of the initial vectors.
+    ///
+    /// # Arguments
+    ///
+    /// - `seed`: Seed of the initial vectors.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    /// Multiply a vector by the Laplacian matrix.
+    ///
+    /// # Arguments
+    ///
+    /// - `vector`: Vector to multiply.
+    ///
+    /// # Returns
+    ///
+    /// Laplacian times the vector.
+    fn laplacian(&self, vector: &[f64]) -> Vec<f64> {
+        let mut result = vec![0.0; self.total_nodes];
+
+        for &(source, target, weight) in &self.edges {
+            let difference = weight * (vector[source] - vector[target]);
+            result[source] += difference;
+            result[target] -= difference;
+        }
+
+        result
+    }
+
+    /// Remove the components along the given orthonormal vectors and normalize.
+    ///
+    /// # Arguments
+    ///
+    /// - `vector`: Vector to orthonormalize.
+    /// - `basis`: Orthonormal vectors.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the vector vanished.
+    fn orthonormalize(vector: &mut [f64], basis: &[Vec<f64>]) -> bool {
+        for base in basis {
+            let dot: f64 = vector.iter().zip(base).map(|(a, b)| a * b).sum();
+            vector.iter_mut().zip(base).for_each(|(a, b)| *a -= dot * b);
+        }
+
+        let norm = vector.iter().map(|a| a * a).sum::<f64>().sqrt();
+
+        if norm < 1e-12 {
+            return false;
+        }
+
+        vector.iter_mut().for_each(|a| *a /= norm);
+        true
+    }
+}
+
+impl GraphAlgorithm for SpectralAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = SpectralCoordinates;
+
+    /// Run the spectral embedding.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the spectral embedding.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the coordinates of each node, or an error if an eigenvector does not converge.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let n = self.total_nodes;
+        let dimensions = self.dimensions.min(n.saturating_sub(1));
+        let mut random = Random::new(self.seed);
+
+        // Power iteration on `shift * I - L` finds the smallest Laplacian eigenvalues first,
+        // since the largest eigenvalue is at most twice the maximum weighted degree.
+        let mut degrees = vec![0.0; n];
+
+        for &(source, target, weight) in &self.edges {
+            degrees[source] += weight.abs();
+            degrees[target] += weight.abs();
+        }
+
+        let shift = 2.0 * degrees.iter().copied().fold(0.0, f64::max) + 1.0;
+        let mut basis = vec![vec![1.0 / (n as f64).sqrt(); n]];
+        let mut eigenvalues = Vec::with_capacity(dimensions);
+
+        for _ in 0..dimensions {
+            let mut vector: Vec<f64> = (0..n).map(|_| random.next_f64() - 0.5).collect();
+
+            if !Self::orthonormalize(&mut vector, &basis) {
+                return Err(GraphError::NoConvergence);
+            }
+
+            let mut converged = false;
+
+            for _ in 0..self.max_iterations {
+                let product = self.laplacian(&vector);
+                let mut next: Vec<f64> = vector
+                    .iter()
+                    .zip(&product)
+                    .map(|(v, l)| shift * v - l)
+                    .collect();
+
+                if !Self::orthonormalize(&mut next, &basis) {
+                    return Err(GraphError::NoConvergence);
+                }
+
+                let change = next
+                    .iter()
+                    .zip(&vector)
+                    .map(|(a, b)| (a - b).abs())
+                    .fold(0.0, f64::max);
+
+                vector = next;
+
+                if change <= self.tolerance {
+                    converged = true;
+                    break;
+                }
+            }
+
+            if !converged {
+                return Err(GraphError::NoConvergence);
+            }
+
+            // Fix the sign so that the largest component is positive.
+            let largest =
+                vector.iter().copied().fold(
+                    0.0,
+                    |largest: f64, a| if a.abs() > largest.abs() { a } else { largest },
+                );
+
+            if largest < 0.0 {
+                vector.iter_mut().for_each(|a| *a = -*a);
+            }
+
+            let product = self.laplacian(&vector);
+            eigenvalues.push(vector.iter().zip(&product).map(|(a, b)| a * b).sum());
+            basis.push(vector);
+        }
+
+        let coordinates = (0..n)
+            .map(|node| basis[1..].iter().map(|vector| vector[node]).collect())
+            .collect();
+
+        Ok(SpectralCoordinates {
+            coordinates,
+            eigenvalues,
+        })
+    }
+}
+
+impl ConfiguredAlgorithm for SpectralAlgorithm {
+    /// Describe the configuration of the spectral embedding.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("spectral")
+            .with("dimensions", self.dimensions)
+            .with("max_iterations", self.max_iterations)
+            .with("tolerance", self.tolerance)
+            .with("seed", self.seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(left: f64, right: f64) {
+        assert!((left - right).abs() < 1e-6, "{left} != {right}");
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = SpectralAlgorithm::new();
+        let algorithm_default = SpectralAlgorithm::default();
+
+        assert_eq!(algorithm.dimensions, 2);
+        assert_eq!(algorithm_default.total_nodes, 0);
+    }
+
+    #[test]
+    fn test_run() {
+        // Path graph: the Fiedler vector is proportional to cos(pi * (2i + 1) / 2n).
+        let mut algorithm = SpectralAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 1.0)]),
+            (1, vec![(2, 1.0)]),
+            (2, vec![(3, 1.0)]),
+        ]);
+        algorithm.set_dimensions(1);
+
+        let result = algorithm.run(None).unwrap();
+        let expected: Vec<f64> = (0..4)
+            .map(|i| (std::f64::consts::PI * (2 * i + 1) as f64 / 8.0).cos())
+            .collect();
+        let norm = expected.iter().map(|a| a * a).sum::<f64>().sqrt();
+
+        assert_close(
+            result.eigenvalues[0],
+            2.0 - 2.0 * (std::f64::consts::PI / 4.0).cos(),
+        );
+
+        for (coordinates, expected) in result.coordinates.iter().zip(&expected) {
+            assert_eq!(coordinates.len(), 1);
+            assert_close(coordinates[0].abs(), (expected / norm).abs());
+        }
+    }
+
+    #[test]
+    fn test_run_two_clusters() {
+        // Two triangles joined by a weak edge are separated by the first coordinate.
+        let mut algorithm = SpectralAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 1.0), (2, 1.0)]),
+            (1, vec![(2, 1.0)]),
+            (3, vec![(4, 1.0), (5, 1.0)]),
+            (4, vec![(5, 1.0)]),
+            (2, vec![(3, 0.1)]),
+        ]);
+        algorithm.set_seed(3);
+
+        let result = algorithm.run(None).unwrap();
+        let sides: Vec<bool> = result.coordinates.iter().map(|c| c[0] > 0.0).collect();
+
+        assert_eq!(result.coordinates[0].len(), 2);
+        assert!(result.eigenvalues[0] <= result.eigenvalues[1]);
+        assert!(sides[..3].iter().all(|&side| side == sides[0]));
+        assert!(sides[3..].iter().all(|&side| side != sides[0]));
+    }
+
+    #[test]
+    fn test_run_no_convergence() {
+        let mut algorithm = SpectralAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 1.0)]), (1, vec![(2, 1.0)])]);
+        algorithm.set_max_iterations(1);
+        algorithm.set_tolerance(0.0);
+
+        assert_eq!(algorithm.run(None), Err(GraphError::NoConvergence));
+    }
+}
@@ -0,0 +1,285 @@
+use std::collections::VecDeque;
+
+use crate::{GraphAlgorithm, GraphError};
+
+/// Strahler Numbers.
+/// Compute the Strahler order and level metrics of every node in a tree or a directed acyclic graph.
+#[derive(Debug, Clone)]
+pub struct StrahlerAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Children of each node.
+    pub adjacency: Vec<Vec<usize>>,
+}
+
+/// Hierarchy metrics of each node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HierarchyMetrics {
+    /// Strahler order, `1` for leaves.
+    pub strahler: Vec<usize>,
+
+    /// Longest distance from a root, `0` for roots.
+    pub depth: Vec<usize>,
+
+    /// Longest distance to a leaf, `0` for leaves.
+    pub height: Vec<usize>,
+}
+
+impl HierarchyMetrics {
+    /// Group the nodes by depth.
+    ///
+    /// # Returns
+    ///
+    /// Nodes of each level, starting with the roots.
+    pub fn levels(&self) -> Vec<Vec<usize>> {
+        let mut levels = vec![Vec::new(); self.depth.iter().max().map_or(0, |&d| d + 1)];
+
+        for (node, &depth) in self.depth.iter().enumerate() {
+            levels[depth].push(node);
+        }
+
+        levels
+    }
+
+    /// Compute the largest Strahler order, i.e. the order of the whole network.
+    ///
+    /// # Returns
+    ///
+    /// Largest Strahler order, or `0` for an empty graph.
+    pub fn max_strahler(&self) -> usize {
+        self.strahler.iter().copied().max().unwrap_or(0)
+    }
+}
+
+impl Default for StrahlerAlgorithm {
+    /// Create a new default instance of the Strahler numbers algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the Strahler numbers algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StrahlerAlgorithm {
+    /// Create a new instance of the Strahler numbers algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the Strahler numbers algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+        }
+    }
+
+    /// Set a single edge from a parent to a child.
+    ///
+    /// # Arguments
+    ///
+    /// - `parent`: Parent node.
+    /// - `child`: Child node.
+    pub fn set_edge(&mut self, parent: usize, child: usize) {
+        self.set_total_nodes(parent.max(child) + 1);
+        self.adjacency[parent].push(child);
+    }
+
+    /// Set multiple nodes' children.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its children.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (parent, children) in nodes {
+            self.set_total_nodes(parent + 1);
+
+            for child in children {
+                self.set_edge(parent, child);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, Vec::new());
+    }
+
+    /// Order the nodes so that every parent comes before its children.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the topological order, or an error if the graph has a cycle.
+    fn topological_order(&self) -> Result<Vec<usize>, GraphError> {
+        let mut in_degree = vec![0; self.total_nodes];
+
+        for children in &self.adjacency {
+            for &child in children {
+                in_degree[child] += 1;
+            }
+        }
+
+        let mut queue: VecDeque<usize> = (0..self.total_nodes)
+            .filter(|&node| in_degree[node] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.total_nodes);
+
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+
+            for &child in &self.adjacency[node] {
+                in_degree[child] -= 1;
+
+                if in_degree[child] == 0 {
+                    queue.push_back(child);
+                }
+            }
+        }
+
+        if order.len() < self.total_nodes {
+            return Err(GraphError::CycleDetected);
+        }
+
+        Ok(order)
+    }
+}
+
+impl GraphAlgorithm for StrahlerAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = HierarchyMetrics;
+
+    /// Run the Strahler numbers algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the Strahler numbers algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the hierarchy metrics, or an error if the graph has a cycle.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let order = self.topological_order()?;
+
+        let mut depth = vec![0; self.total_nodes];
+
+        for &node in &order {
+            for &child in &self.adjacency[node] {
+                depth[child] = depth[child].max(depth[node] + 1);
+            }
+        }
+
+        let mut strahler = vec![1; self.total_nodes];
+        let mut height = vec![0; self.total_nodes];
+
+        for &node in order.iter().rev() {
+            let children = &self.adjacency[node];
+
+            if children.is_empty() {
+                continue;
+            }
+
+            let highest = children.iter().map(|&c| strahler[c]).max().unwrap_or(1);
+            let count = children.iter().filter(|&&c| strahler[c] == highest).count();
+
+            // Two or more branches of the same highest order raise the order.
+            strahler[node] = if count > 1 { highest + 1 } else { highest };
+            height[node] = children.iter().map(|&c| height[c] + 1).max().unwrap_or(0);
+        }
+
+        Ok(HierarchyMetrics {
+            strahler,
+            depth,
+            height,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let algorithm = StrahlerAlgorithm::new();
+        let algorithm_default = StrahlerAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.total_nodes, 0);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = StrahlerAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 2]),
+            (1, vec![3, 4]),
+            (2, vec![5, 6]),
+            (3, vec![7, 8]),
+        ]);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.strahler, vec![3, 2, 2, 2, 1, 1, 1, 1, 1]);
+        assert_eq!(result.depth, vec![0, 1, 1, 2, 2, 2, 2, 3, 3]);
+        assert_eq!(result.height, vec![3, 2, 1, 1, 0, 0, 0, 0, 0]);
+        assert_eq!(result.max_strahler(), 3);
+        assert_eq!(
+            result.levels(),
+            vec![vec![0], vec![1, 2], vec![3, 4, 5, 6], vec![7, 8]]
+        );
+    }
+
+    #[test]
+    fn test_run_unbalanced_tree() {
+        let mut algorithm = StrahlerAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1, 2]), (1, vec![3, 4])]);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.strahler, vec![2, 2, 1, 1, 1]);
+    }
+
+    #[test]
+    fn test_run_dag() {
+        let mut algorithm = StrahlerAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 2]),
+            (1, vec![3]),
+            (2, vec![3]),
+            (4, vec![3]),
+        ]);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.strahler, vec![2, 1, 1, 1, 1]);
+        assert_eq!(result.depth, vec![0, 1, 1, 2, 0]);
+        assert_eq!(result.height, vec![2, 1, 1, 0, 1]);
+    }
+
+    #[test]
+    fn test_run_cycle() {
+        let mut algorithm = StrahlerAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1]), (1, vec![0])]);
+
+        assert_eq!(algorithm.run(None), Err(GraphError::CycleDetected));
+    }
+
+    #[test]
+    fn test_run_empty_graph() {
+        let algorithm = StrahlerAlgorithm::new();
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.max_strahler(), 0);
+        assert!(result.levels().is_empty());
+    }
+}
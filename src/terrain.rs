@@ -0,0 +1,262 @@
+use crate::DijkstraAlgorithm;
+
+/// Gravitational acceleration in m/s².
+const GRAVITY: f64 = 9.81;
+
+/// Cost model deriving the weight of an edge from its length and climb.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CostModel {
+    /// Length along the slope in metres.
+    #[default]
+    Distance,
+
+    /// Walking time in seconds from Tobler's hiking function,
+    /// `6 * exp(-3.5 * |slope + 0.05|)` km/h, fastest on a gentle descent.
+    Hiking,
+
+    /// Mechanical energy in joules to ride at a constant speed against gravity, rolling resistance
+    /// and air drag, zero when coasting downhill.
+    Cycling {
+        /// Mass of rider and bicycle in kilograms.
+        mass: f64,
+
+        /// Riding speed in metres per second.
+        speed: f64,
+
+        /// Rolling resistance coefficient, e.g. 0.005 for road tires.
+        rolling_resistance: f64,
+
+        /// Drag area in square metres, e.g. 0.4 for a road position.
+        drag_area: f64,
+    },
+}
+
+/// Terrain Graph.
+/// Derive directed edge weights from node positions and elevations with a cost model, so that
+/// terrain data becomes a runnable graph. Each road yields one weight per direction, since climbing
+/// and descending cost differently.
+#[derive(Debug, Clone)]
+pub struct TerrainGraph {
+    /// Planar position of each node in metres, e.g. projected coordinates.
+    pub positions: Vec<(f64, f64)>,
+
+    /// Elevation of each node in metres.
+    pub elevations: Vec<f64>,
+
+    /// Roads between nodes, traversable in both directions.
+    pub roads: Vec<(usize, usize)>,
+
+    /// Cost model of the weights.
+    pub model: CostModel,
+}
+
+impl Default for TerrainGraph {
+    /// Create a new default instance of the terrain graph.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the terrain graph.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TerrainGraph {
+    /// Create a new instance of the terrain graph.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the terrain graph.
+    pub fn new() -> Self {
+        Self {
+            positions: Vec::new(),
+            elevations: Vec::new(),
+            roads: Vec::new(),
+            model: CostModel::default(),
+        }
+    }
+
+    /// Set the position and elevation of a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node to place.
+    /// - `position`: Planar position in metres.
+    /// - `elevation`: Elevation in metres.
+    pub fn set_node(&mut self, node: usize, position: (f64, f64), elevation: f64) {
+        if node >= self.positions.len() {
+            self.positions.resize(node + 1, (0.0, 0.0));
+            self.elevations.resize(node + 1, 0.0);
+        }
+
+        self.positions[node] = position;
+        self.elevations[node] = elevation;
+    }
+
+    /// Set a road between two nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: First node.
+    /// - `target`: Second node.
+    pub fn set_road(&mut self, source: usize, target: usize) {
+        self.roads.push((source, target));
+    }
+
+    /// Set the cost model of the weights.
+    ///
+    /// # Arguments
+    ///
+    /// - `model`: Cost model.
+    pub fn set_model(&mut self, model: CostModel) {
+        self.model = model;
+    }
+
+    /// Compute the cost of travelling directly from one node to another.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Cost in the unit of the cost model.
+    pub fn cost(&self, source: usize, target: usize) -> f64 {
+        let (x1, y1) = self.positions[source];
+        let (x2, y2) = self.positions[target];
+        let run = (x2 - x1).hypot(y2 - y1);
+        let rise = self.elevations[target] - self.elevations[source];
+        let length = run.hypot(rise);
+
+        match self.model {
+            CostModel::Distance => length,
+            CostModel::Hiking => {
+                let slope = if run > 0.0 { rise / run } else { 0.0 };
+                let speed = 6.0 * (-3.5 * (slope + 0.05).abs()).exp() / 3.6;
+
+                run / speed
+            }
+            CostModel::Cycling {
+                mass,
+                speed,
+                rolling_resistance,
+                drag_area,
+            } => {
+                let (sine, cosine) = match length > 0.0 {
+                    true => (rise / length, run / length),
+                    false => (0.0, 1.0),
+                };
+                let force = mass * GRAVITY * (sine + rolling_resistance * cosine)
+                    + 0.5 * 1.225 * drag_area * speed * speed;
+
+                force.max(0.0) * length
+            }
+        }
+    }
+
+    /// Derive the weights of both directions of every road.
+    ///
+    /// # Returns
+    ///
+    /// Directed edges as `(source, target, cost)`.
+    pub fn weights(&self) -> Vec<(usize, usize, f64)> {
+        self.roads
+            .iter()
+            .flat_map(|&(a, b)| [(a, b, self.cost(a, b)), (b, a, self.cost(b, a))])
+            .collect()
+    }
+
+    /// Build a graph for Dijkstra's Algorithm with integer weights.
+    ///
+    /// # Arguments
+    ///
+    /// - `unit`: Cost of one weight unit, e.g. `1.0` for whole seconds or metres.
+    ///
+    /// # Returns
+    ///
+    /// Graph with every node and the rounded weights of both directions of every road.
+    pub fn to_dijkstra(&self, unit: f64) -> DijkstraAlgorithm {
+        let mut graph = DijkstraAlgorithm::new();
+        let mut nodes = vec![Vec::new(); self.positions.len()];
+
+        for (source, target, cost) in self.weights() {
+            nodes[source].push((target, (cost / unit).round() as usize));
+        }
+
+        graph.set_nodes(nodes.into_iter().enumerate().collect());
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GraphAlgorithm;
+
+    fn assert_close(left: f64, right: f64) {
+        assert!((left - right).abs() < 1e-6, "{left} != {right}");
+    }
+
+    #[test]
+    fn test_new() {
+        let graph = TerrainGraph::new();
+        let graph_default = TerrainGraph::default();
+
+        assert_eq!(graph.model, CostModel::Distance);
+        assert!(graph_default.roads.is_empty());
+    }
+
+    #[test]
+    fn test_cost() {
+        // 1 km run with a 100 m climb.
+        let mut graph = TerrainGraph::new();
+        graph.set_node(0, (0.0, 0.0), 0.0);
+        graph.set_node(1, (600.0, 800.0), 100.0);
+
+        assert_close(graph.cost(0, 1), 1000.0_f64.hypot(100.0));
+
+        graph.set_model(CostModel::Hiking);
+
+        assert_close(
+            graph.cost(0, 1),
+            1000.0 / (6.0 * (-3.5_f64 * 0.15).exp() / 3.6),
+        );
+        assert!(graph.cost(1, 0) < graph.cost(0, 1));
+
+        graph.set_model(CostModel::Cycling {
+            mass: 80.0,
+            speed: 5.0,
+            rolling_resistance: 0.005,
+            drag_area: 0.4,
+        });
+
+        // Climbing costs at least the potential energy, a steep descent costs nothing.
+        assert!(graph.cost(0, 1) > 80.0 * GRAVITY * 100.0);
+        assert_eq!(graph.cost(1, 0), 0.0);
+    }
+
+    #[test]
+    fn test_to_dijkstra() {
+        // A flat detour 0 - 2 - 1 beats the hill 0 - 3 - 1 for hikers.
+        let mut graph = TerrainGraph::new();
+        graph.set_node(0, (0.0, 0.0), 0.0);
+        graph.set_node(1, (2000.0, 0.0), 0.0);
+        graph.set_node(2, (1000.0, 500.0), 0.0);
+        graph.set_node(3, (1000.0, 0.0), 300.0);
+        graph.set_road(0, 2);
+        graph.set_road(2, 1);
+        graph.set_road(0, 3);
+        graph.set_road(3, 1);
+
+        assert_eq!(graph.weights().len(), 8);
+
+        let distances = graph.to_dijkstra(1.0).run(Some(0)).unwrap();
+        assert!(distances[3] < distances[2]);
+
+        graph.set_model(CostModel::Hiking);
+
+        let (_, path) = graph.to_dijkstra(1.0).shortest_path(0, 1).unwrap();
+        assert_eq!(path, vec![0, 2, 1]);
+    }
+}
@@ -0,0 +1,435 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+};
+
+use crate::{GraphAlgorithm, GraphError};
+
+/// Topological Sort (Kahn's Algorithm).
+/// Order the nodes of a directed acyclic graph so that every edge points from an earlier node to a later one.
+#[derive(Debug, Clone)]
+pub struct TopologicalSortAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Successors of each node.
+    pub adjacency: Vec<Vec<usize>>,
+}
+
+impl Default for TopologicalSortAlgorithm {
+    /// Create a new default instance of Topological Sort.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of Topological Sort.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TopologicalSortAlgorithm {
+    /// Create a new instance of Topological Sort.
+    ///
+    /// # Returns
+    ///
+    /// New instance of Topological Sort.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+        }
+    }
+
+    /// Set a single directed edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Node that must come first.
+    /// - `target`: Node that must come after the source.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].push(target);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its successors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, Vec::new());
+    }
+
+    /// Count the incoming edges of each node.
+    ///
+    /// # Returns
+    ///
+    /// In-degree of each node.
+    fn in_degrees(&self) -> Vec<usize> {
+        let mut in_degree = vec![0; self.total_nodes];
+
+        for targets in &self.adjacency {
+            for &target in targets {
+                in_degree[target] += 1;
+            }
+        }
+
+        in_degree
+    }
+
+    /// Group the nodes into generations: the first generation contains the nodes without predecessors,
+    /// and every next one the nodes whose predecessors all belong to earlier generations.
+    ///
+    /// Nodes of the same generation do not depend on each other and can be processed in parallel.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the nodes of each generation in ascending order, or an error if the graph has a cycle.
+    pub fn topological_generations(&self) -> Result<Vec<Vec<usize>>, GraphError> {
+        let mut in_degree = self.in_degrees();
+        let mut generation: Vec<usize> = (0..self.total_nodes)
+            .filter(|&node| in_degree[node] == 0)
+            .collect();
+        let mut generations = Vec::new();
+        let mut visited = 0;
+
+        while !generation.is_empty() {
+            let mut next = Vec::new();
+
+            for &node in &generation {
+                for &target in &self.adjacency[node] {
+                    in_degree[target] -= 1;
+
+                    if in_degree[target] == 0 {
+                        next.push(target);
+                    }
+                }
+            }
+
+            next.sort_unstable();
+            visited += generation.len();
+            generations.push(std::mem::replace(&mut generation, next));
+        }
+
+        if visited < self.total_nodes {
+            return Err(GraphError::CycleDetected);
+        }
+
+        Ok(generations)
+    }
+
+    /// Order the nodes topologically, choosing among the available nodes the one with the smallest priority.
+    ///
+    /// Ties between equal priorities are broken by the smaller node, so the output is deterministic.
+    ///
+    /// # Arguments
+    ///
+    /// - `priority`: Priority of each node, smaller values are scheduled first.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the nodes in topological order, or an error if the graph has a cycle.
+    pub fn sort_by_priority<P, F>(&self, priority: F) -> Result<Vec<usize>, GraphError>
+    where
+        P: Ord,
+        F: Fn(usize) -> P,
+    {
+        let mut in_degree = self.in_degrees();
+        let mut heap: BinaryHeap<Reverse<(P, usize)>> = (0..self.total_nodes)
+            .filter(|&node| in_degree[node] == 0)
+            .map(|node| Reverse((priority(node), node)))
+            .collect();
+        let mut order = Vec::with_capacity(self.total_nodes);
+
+        while let Some(Reverse((_, node))) = heap.pop() {
+            order.push(node);
+
+            for &target in &self.adjacency[node] {
+                in_degree[target] -= 1;
+
+                if in_degree[target] == 0 {
+                    heap.push(Reverse((priority(target), target)));
+                }
+            }
+        }
+
+        if order.len() < self.total_nodes {
+            return Err(GraphError::CycleDetected);
+        }
+
+        Ok(order)
+    }
+
+    /// Compute the lexicographically smallest topological order.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the nodes in topological order, or an error if the graph has a cycle.
+    pub fn lexicographic_sort(&self) -> Result<Vec<usize>, GraphError> {
+        self.sort_by_priority(|node| node)
+    }
+
+    /// Compute the transitive reduction: the fewest edges with the same reachability as the graph.
+    /// Successors of each node are visited in topological order, so an edge is redundant exactly
+    /// when its target is reachable from an earlier kept successor. Reachability is kept in bitsets.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the kept edges sorted by source and target, or an error if the graph has a cycle.
+    pub fn transitive_reduction(&self) -> Result<Vec<(usize, usize)>, GraphError> {
+        let n = self.total_nodes;
+        let order = self.run(None)?;
+        let words = n.div_ceil(64);
+        let mut position = vec![0; n];
+        let mut reach = vec![0u64; n * words];
+        let mut edges = Vec::new();
+
+        for (index, &node) in order.iter().enumerate() {
+            position[node] = index;
+        }
+
+        for &node in order.iter().rev() {
+            let mut successors = self.adjacency[node].clone();
+            successors.sort_unstable_by_key(|&target| position[target]);
+            successors.dedup();
+
+            let mut covered = vec![0u64; words];
+
+            for target in successors {
+                if covered[target / 64] & (1 << (target % 64)) != 0 {
+                    continue;
+                }
+
+                edges.push((node, target));
+                covered[target / 64] |= 1 << (target % 64);
+
+                let descendants = &reach[target * words..(target + 1) * words];
+                covered
+                    .iter_mut()
+                    .zip(descendants)
+                    .for_each(|(a, b)| *a |= b);
+            }
+
+            reach[node * words..(node + 1) * words].copy_from_slice(&covered);
+        }
+
+        edges.sort_unstable();
+        Ok(edges)
+    }
+}
+
+impl GraphAlgorithm for TopologicalSortAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<usize>;
+
+    /// Run Topological Sort.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in Topological Sort.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the nodes in topological order, or an error if the graph has a cycle.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let mut in_degree = self.in_degrees();
+        let mut queue: VecDeque<usize> = (0..self.total_nodes)
+            .filter(|&node| in_degree[node] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.total_nodes);
+
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+
+            for &target in &self.adjacency[node] {
+                in_degree[target] -= 1;
+
+                if in_degree[target] == 0 {
+                    queue.push_back(target);
+                }
+            }
+        }
+
+        if order.len() < self.total_nodes {
+            return Err(GraphError::CycleDetected);
+        }
+
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let algorithm = TopologicalSortAlgorithm::new();
+        let algorithm_default = TopologicalSortAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.total_nodes, 0);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = TopologicalSortAlgorithm::new();
+        algorithm.set_edges(vec![
+            (5, vec![2, 0]),
+            (4, vec![0, 1]),
+            (2, vec![3]),
+            (3, vec![1]),
+        ]);
+
+        let result = algorithm.run(None).unwrap();
+        let position = |node: usize| result.iter().position(|&n| n == node).unwrap();
+
+        assert_eq!(result.len(), 6);
+
+        for (source, targets) in algorithm.adjacency.iter().enumerate() {
+            for &target in targets {
+                assert!(position(source) < position(target));
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_cycle() {
+        let mut algorithm = TopologicalSortAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1]), (1, vec![2]), (2, vec![1])]);
+
+        assert_eq!(algorithm.run(None), Err(GraphError::CycleDetected));
+    }
+
+    #[test]
+    fn test_run_empty_graph() {
+        let algorithm = TopologicalSortAlgorithm::new();
+
+        assert_eq!(algorithm.run(None).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_topological_generations() {
+        let mut algorithm = TopologicalSortAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![2, 3]),
+            (1, vec![3]),
+            (2, vec![4]),
+            (3, vec![4]),
+            (4, vec![]),
+            (5, vec![]),
+        ]);
+
+        assert_eq!(
+            algorithm.topological_generations().unwrap(),
+            vec![vec![0, 1, 5], vec![2, 3], vec![4]]
+        );
+    }
+
+    #[test]
+    fn test_topological_generations_longest_dependency() {
+        let mut algorithm = TopologicalSortAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1, 2]), (1, vec![2])]);
+
+        assert_eq!(
+            algorithm.topological_generations().unwrap(),
+            vec![vec![0], vec![1], vec![2]]
+        );
+    }
+
+    #[test]
+    fn test_topological_generations_cycle() {
+        let mut algorithm = TopologicalSortAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1]), (1, vec![0]), (2, vec![])]);
+
+        assert_eq!(
+            algorithm.topological_generations(),
+            Err(GraphError::CycleDetected)
+        );
+    }
+
+    #[test]
+    fn test_lexicographic_sort() {
+        let mut algorithm = TopologicalSortAlgorithm::new();
+        algorithm.set_edges(vec![(3, vec![1]), (2, vec![0]), (4, vec![0, 1])]);
+
+        assert_eq!(algorithm.lexicographic_sort().unwrap(), vec![2, 3, 4, 0, 1]);
+    }
+
+    #[test]
+    fn test_sort_by_priority() {
+        let mut algorithm = TopologicalSortAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![3]), (1, vec![3]), (2, vec![3])]);
+
+        let weights = [1, 5, 3, 0];
+
+        assert_eq!(
+            algorithm
+                .sort_by_priority(|node| Reverse(weights[node]))
+                .unwrap(),
+            vec![1, 2, 0, 3]
+        );
+    }
+
+    #[test]
+    fn test_sort_by_priority_ties() {
+        let mut algorithm = TopologicalSortAlgorithm::new();
+        algorithm.set_total_nodes(3);
+
+        assert_eq!(algorithm.sort_by_priority(|_| 0).unwrap(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_sort_by_priority_cycle() {
+        let mut algorithm = TopologicalSortAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1]), (1, vec![0])]);
+
+        assert_eq!(
+            algorithm.lexicographic_sort(),
+            Err(GraphError::CycleDetected)
+        );
+    }
+
+    #[test]
+    fn test_transitive_reduction() {
+        let mut algorithm = TopologicalSortAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 2, 3, 4]),
+            (1, vec![3, 3]),
+            (2, vec![3, 4]),
+            (3, vec![4]),
+            (5, vec![]),
+        ]);
+
+        assert_eq!(
+            algorithm.transitive_reduction().unwrap(),
+            vec![(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]
+        );
+
+        algorithm.set_edge(4, 0);
+
+        assert_eq!(
+            algorithm.transitive_reduction(),
+            Err(GraphError::CycleDetected)
+        );
+    }
+}
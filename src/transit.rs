@@ -0,0 +1,579 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::{ConfiguredAlgorithm, GraphAlgorithm, GraphError, Provenance};
+
+/// Ride on a single trip.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leg {
+    /// Trip ridden.
+    pub trip: usize,
+
+    /// Stop where the trip is boarded.
+    pub from: usize,
+
+    /// Stop where the trip is left.
+    pub to: usize,
+
+    /// Departure time from the boarding stop.
+    pub departure: usize,
+
+    /// Arrival time at the alighting stop.
+    pub arrival: usize,
+}
+
+/// Journey through a transit network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Journey {
+    /// Departure time of the first leg.
+    pub departure: usize,
+
+    /// Arrival time of the last leg.
+    pub arrival: usize,
+
+    /// Legs of the journey, one per trip ridden.
+    pub legs: Vec<Leg>,
+}
+
+impl Journey {
+    /// Build a journey from its legs.
+    ///
+    /// # Arguments
+    ///
+    /// - `legs`: Legs of the journey, at least one.
+    ///
+    /// # Returns
+    ///
+    /// Journey spanning the legs.
+    fn from_legs(legs: Vec<Leg>) -> Self {
+        Self {
+            departure: legs[0].departure,
+            arrival: legs[legs.len() - 1].arrival,
+            legs,
+        }
+    }
+
+    /// Stops where the journey boards, changes and ends.
+    ///
+    /// # Returns
+    ///
+    /// Transfer pattern of the journey.
+    pub fn pattern(&self) -> Vec<usize> {
+        let mut pattern = vec![self.legs[0].from];
+        pattern.extend(self.legs.iter().map(|leg| leg.to));
+        pattern
+    }
+}
+
+/// Direct rides between pairs of stops as `(departure, arrival, trip)`.
+type DirectRides = HashMap<(usize, usize), Vec<(usize, usize, usize)>>;
+
+/// Best arrival at each stop after each round, with the leg that improved it.
+struct Rounds {
+    /// Time from which each stop can be left after each round, transfer time included.
+    ready: Vec<Vec<usize>>,
+
+    /// Trip, boarding and alighting index of the leg improving each stop in each round.
+    parents: Vec<Vec<Option<(usize, usize, usize)>>>,
+}
+
+/// Precomputed transfer patterns answering queries from their source stops.
+#[derive(Debug, Clone, Default)]
+pub struct TransferPatterns {
+    /// Patterns of the optimal journeys between each pair of stops, over all departure times.
+    pub patterns: BTreeMap<(usize, usize), BTreeSet<Vec<usize>>>,
+
+    /// Direct rides between each pair of stops as `(departure, arrival, trip)`,
+    /// keeping the rides not overtaken by a later one, so that arrivals increase with departures.
+    direct: DirectRides,
+
+    /// Minimum time to change between trips.
+    transfer_time: usize,
+}
+
+impl TransferPatterns {
+    /// Find the earliest arrival from a precomputed source stop.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source stop, one of the precomputed stops.
+    /// - `target`: Target stop.
+    /// - `departure`: Earliest departure time.
+    ///
+    /// # Returns
+    ///
+    /// Journey arriving first with the fewest trips, or `None` if the target cannot be reached
+    /// or the source was not precomputed.
+    pub fn query(&self, source: usize, target: usize, departure: usize) -> Option<Journey> {
+        self.patterns
+            .get(&(source, target))?
+            .iter()
+            .filter_map(|pattern| self.evaluate(pattern, departure))
+            .min_by_key(|journey| (journey.arrival, journey.legs.len()))
+    }
+
+    /// Follow a transfer pattern with the first direct ride of each segment.
+    ///
+    /// # Arguments
+    ///
+    /// - `pattern`: Stops where the journey boards, changes and ends.
+    /// - `departure`: Earliest departure time.
+    ///
+    /// # Returns
+    ///
+    /// Journey along the pattern, or `None` if a segment has no more rides.
+    fn evaluate(&self, pattern: &[usize], departure: usize) -> Option<Journey> {
+        let mut ready = departure;
+        let mut legs = Vec::new();
+
+        for segment in pattern.windows(2) {
+            let rides = self.direct.get(&(segment[0], segment[1]))?;
+            let &(departure, arrival, trip) =
+                rides.get(rides.partition_point(|&(departure, _, _)| departure < ready))?;
+
+            legs.push(Leg {
+                trip,
+                from: segment[0],
+                to: segment[1],
+                departure,
+                arrival,
+            });
+            ready = arrival + self.transfer_time;
+        }
+
+        Some(Journey::from_legs(legs))
+    }
+}
+
+/// Transit Routing and Transfer Patterns.
+/// Route through a timetable of trips with a round-based connection scan, where round `k` finds
+/// the earliest arrivals using at most `k` trips. Transfer patterns, the sequences of stops where the
+/// optimal journeys board and change over all departure times, are precomputed for popular source stops:
+/// a query then only follows those few patterns through a table of direct rides, in microseconds.
+#[derive(Debug, Clone)]
+pub struct TransitAlgorithm {
+    /// Total number of stops in the network.
+    pub total_stops: usize,
+
+    /// Trips as sequences of `(stop, arrival, departure)`.
+    pub trips: Vec<Vec<(usize, usize, usize)>>,
+
+    /// Minimum time to change between trips.
+    pub transfer_time: usize,
+
+    /// Maximum number of trips in a journey.
+    pub max_trips: usize,
+}
+
+impl Default for TransitAlgorithm {
+    /// Create a new default instance of the transit algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the transit algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransitAlgorithm {
+    /// Create a new instance of the transit algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the transit algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_stops: 0,
+            trips: Vec::new(),
+            transfer_time: 0,
+            max_trips: 5,
+        }
+    }
+
+    /// Set a trip to the timetable.
+    ///
+    /// # Arguments
+    ///
+    /// - `stops`: Stops of the trip in order as `(stop, arrival, departure)`.
+    ///
+    /// # Returns
+    ///
+    /// ID of the trip.
+    pub fn set_trip(&mut self, stops: Vec<(usize, usize, usize)>) -> usize {
+        for &(stop, _, _) in &stops {
+            self.total_stops = self.total_stops.max(stop + 1);
+        }
+
+        self.trips.push(stops);
+        self.trips.len() - 1
+    }
+
+    /// Set the minimum time to change between trips.
+    ///
+    /// # Arguments
+    ///
+    /// - `time`: Minimum transfer time.
+    pub fn set_transfer_time(&mut self, time: usize) {
+        self.transfer_time = time;
+    }
+
+    /// Set the maximum number of trips in a journey.
+    ///
+    /// # Arguments
+    ///
+    /// - `trips`: Maximum number of trips.
+    pub fn set_max_trips(&mut self, trips: usize) {
+        self.max_trips = trips;
+    }
+
+    /// Find the earliest arrival with a connection scan.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source stop.
+    /// - `target`: Target stop.
+    /// - `departure`: Earliest departure time.
+    ///
+    /// # Returns
+    ///
+    /// Journey arriving first with the fewest trips, or `None` if the target cannot be reached.
+    pub fn earliest_arrival(
+        &self,
+        source: usize,
+        target: usize,
+        departure: usize,
+    ) -> Option<Journey> {
+        if source == target || target >= self.total_stops {
+            return None;
+        }
+
+        let rounds = self.scan(&self.connections(), source, departure);
+        let best = rounds.ready.last()?[target];
+        let round = rounds
+            .ready
+            .iter()
+            .position(|ready| ready[target] == best)?;
+
+        (round > 0).then(|| self.journey(&rounds, round, target))
+    }
+
+    /// Precompute the transfer patterns from source stops.
+    ///
+    /// # Arguments
+    ///
+    /// - `sources`: Source stops, such as the most popular ones.
+    ///
+    /// # Returns
+    ///
+    /// Transfer patterns answering queries from the source stops.
+    pub fn transfer_patterns(&self, sources: &[usize]) -> TransferPatterns {
+        let connections = self.connections();
+        let mut patterns: BTreeMap<(usize, usize), BTreeSet<Vec<usize>>> = BTreeMap::new();
+
+        for &source in sources {
+            let departures: BTreeSet<usize> = self
+                .trips
+                .iter()
+                .flat_map(|trip| &trip[..trip.len().saturating_sub(1)])
+                .filter(|&&(stop, _, _)| stop == source)
+                .map(|&(_, _, departure)| departure)
+                .collect();
+
+            // Every journey improving on fewer trips is optimal for its departure time.
+            for departure in departures {
+                let rounds = self.scan(&connections, source, departure);
+
+                for round in 1..rounds.ready.len() {
+                    for target in 0..self.total_stops {
+                        if rounds.ready[round][target] < rounds.ready[round - 1][target] {
+                            patterns
+                                .entry((source, target))
+                                .or_default()
+                                .insert(self.journey(&rounds, round, target).pattern());
+                        }
+                    }
+                }
+            }
+        }
+
+        TransferPatterns {
+            patterns,
+            direct: self.direct_rides(),
+            transfer_time: self.transfer_time,
+        }
+    }
+
+    /// List the connections between consecutive stops of the trips.
+    ///
+    /// # Returns
+    ///
+    /// Trip and index of the departing stop of each connection, by departure and arrival time.
+    fn connections(&self) -> Vec<(usize, usize)> {
+        let mut connections: Vec<(usize, usize)> = self
+            .trips
+            .iter()
+            .enumerate()
+            .flat_map(|(trip, stops)| (1..stops.len()).map(move |index| (trip, index - 1)))
+            .collect();
+
+        connections.sort_by_key(|&(trip, index)| {
+            let stops = &self.trips[trip];
+            (stops[index].2, stops[index + 1].1, trip, index)
+        });
+
+        connections
+    }
+
+    /// Scan the connections once per round, boarding trips from the stops reached in the previous round.
+    ///
+    /// # Arguments
+    ///
+    /// - `connections`: Connections by departure time.
+    /// - `source`: Source stop.
+    /// - `departure`: Earliest departure time.
+    ///
+    /// # Returns
+    ///
+    /// Arrivals and parents of every round until no stop improves.
+    fn scan(&self, connections: &[(usize, usize)], source: usize, departure: usize) -> Rounds {
+        let mut start = vec![usize::MAX; self.total_stops];
+        start[source] = departure;
+
+        let mut rounds = Rounds {
+            ready: vec![start],
+            parents: vec![vec![None; self.total_stops]],
+        };
+
+        for _ in 0..self.max_trips {
+            let previous = &rounds.ready[rounds.ready.len() - 1];
+            let mut ready = previous.clone();
+            let mut parents = vec![None; self.total_stops];
+            let mut boarded = vec![None; self.trips.len()];
+
+            for &(trip, index) in connections {
+                let (from, _, departure) = self.trips[trip][index];
+                let (to, arrival, _) = self.trips[trip][index + 1];
+
+                if boarded[trip].is_none() && previous[from] <= departure {
+                    boarded[trip] = Some(index);
+                }
+
+                if let Some(board) = boarded[trip] {
+                    if to != source && arrival + self.transfer_time < ready[to] {
+                        ready[to] = arrival + self.transfer_time;
+                        parents[to] = Some((trip, board, index + 1));
+                    }
+                }
+            }
+
+            if parents.iter().all(Option::is_none) {
+                break;
+            }
+
+            rounds.ready.push(ready);
+            rounds.parents.push(parents);
+        }
+
+        rounds
+    }
+
+    /// Reconstruct a journey from the parents of the rounds.
+    ///
+    /// # Arguments
+    ///
+    /// - `rounds`: Arrivals and parents of the rounds.
+    /// - `round`: Round in which the target was reached.
+    /// - `target`: Target stop.
+    ///
+    /// # Returns
+    ///
+    /// Journey to the target.
+    fn journey(&self, rounds: &Rounds, round: usize, target: usize) -> Journey {
+        let mut legs = Vec::new();
+        let mut stop = target;
+
+        for round in (1..=round).rev() {
+            if let Some((trip, board, alight)) = rounds.parents[round][stop] {
+                let from = self.trips[trip][board].0;
+                legs.push(Leg {
+                    trip,
+                    from,
+                    to: stop,
+                    departure: self.trips[trip][board].2,
+                    arrival: self.trips[trip][alight].1,
+                });
+                stop = from;
+            }
+        }
+
+        legs.reverse();
+        Journey::from_legs(legs)
+    }
+
+    /// Collect the direct rides between every pair of stops of each trip.
+    ///
+    /// # Returns
+    ///
+    /// Rides by departure time, without the rides overtaken by a later one.
+    fn direct_rides(&self) -> DirectRides {
+        let mut direct = DirectRides::new();
+
+        for (trip, stops) in self.trips.iter().enumerate() {
+            for (board, &(from, _, departure)) in stops.iter().enumerate() {
+                for &(to, arrival, _) in &stops[board + 1..] {
+                    direct
+                        .entry((from, to))
+                        .or_default()
+                        .push((departure, arrival, trip));
+                }
+            }
+        }
+
+        for rides in direct.values_mut() {
+            rides.sort_unstable();
+
+            // Keep the rides arriving strictly before every later departure.
+            let mut kept: Vec<(usize, usize, usize)> = Vec::new();
+
+            for &ride in rides.iter().rev() {
+                if kept
+                    .last()
+                    .map_or(true, |&(_, arrival, _)| ride.1 < arrival)
+                {
+                    kept.push(ride);
+                }
+            }
+
+            kept.reverse();
+            *rides = kept;
+        }
+
+        direct
+    }
+}
+
+impl GraphAlgorithm for TransitAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = TransferPatterns;
+
+    /// Run the transfer pattern precomputation.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Source stop.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the transfer patterns from the source stop, or an error if it is missing.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let source = start.ok_or(GraphError::MissingStartNode)?;
+
+        if source >= self.total_stops {
+            return Err(GraphError::MissingStartNode);
+        }
+
+        Ok(self.transfer_patterns(&[source]))
+    }
+}
+
+impl ConfiguredAlgorithm for TransitAlgorithm {
+    /// Describe the configuration of the transit algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("transit")
+            .with("transfer_time", self.transfer_time)
+            .with("max_trips", self.max_trips)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn network() -> TransitAlgorithm {
+        let mut algorithm = TransitAlgorithm::new();
+        algorithm.set_transfer_time(2);
+
+        // Slow line 0 - 1 - 2 - 3 every 20 minutes and an express 0 - 4 with a feeder 4 - 3.
+        for start in [0, 20, 40] {
+            algorithm.set_trip(vec![
+                (0, start, start),
+                (1, start + 10, start + 11),
+                (2, start + 20, start + 21),
+                (3, start + 30, start + 30),
+            ]);
+        }
+
+        algorithm.set_trip(vec![(0, 5, 5), (4, 12, 12)]);
+        algorithm.set_trip(vec![(4, 14, 14), (3, 22, 22)]);
+        algorithm.set_trip(vec![(4, 13, 13), (2, 16, 16)]);
+        algorithm
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = TransitAlgorithm::new();
+        let algorithm_default = TransitAlgorithm::default();
+
+        assert_eq!(algorithm.max_trips, 5);
+        assert_eq!(algorithm_default.earliest_arrival(0, 1, 0), None);
+        assert_eq!(
+            algorithm.run(Some(0)).unwrap_err(),
+            GraphError::MissingStartNode
+        );
+    }
+
+    #[test]
+    fn test_earliest_arrival() {
+        let algorithm = network();
+        let journey = algorithm.earliest_arrival(0, 3, 0).unwrap();
+
+        // The express and the feeder beat the slow line, which misses the 4 - 2 connection by the transfer.
+        assert_eq!(journey.arrival, 22);
+        assert_eq!(journey.pattern(), vec![0, 4, 3]);
+        assert_eq!(algorithm.earliest_arrival(0, 3, 6).unwrap().arrival, 50);
+        assert_eq!(
+            algorithm.earliest_arrival(0, 2, 0).unwrap().pattern(),
+            vec![0, 2]
+        );
+        assert_eq!(algorithm.earliest_arrival(3, 0, 0), None);
+
+        let mut direct = network();
+        direct.set_max_trips(1);
+
+        assert_eq!(direct.earliest_arrival(0, 3, 0).unwrap().arrival, 30);
+    }
+
+    #[test]
+    fn test_run() {
+        let algorithm = network();
+        let patterns = algorithm.run(Some(0)).unwrap();
+
+        assert_eq!(
+            patterns.patterns[&(0, 3)],
+            BTreeSet::from([vec![0, 3], vec![0, 4, 3]])
+        );
+
+        // Queries through the patterns match a full scan at every departure time.
+        for departure in 0..60 {
+            for target in 1..algorithm.total_stops {
+                assert_eq!(
+                    patterns
+                        .query(0, target, departure)
+                        .map(|journey| journey.arrival),
+                    algorithm
+                        .earliest_arrival(0, target, departure)
+                        .map(|journey| journey.arrival)
+                );
+            }
+        }
+
+        assert_eq!(patterns.query(1, 3, 0), None);
+    }
+}
@@ -0,0 +1,351 @@
+use crate::{scc, GraphAlgorithm, GraphError, MemoryEstimate, MemoryEstimation};
+
+/// Reachability matrix with one bit per pair of nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReachabilityMatrix {
+    /// Number of nodes.
+    pub size: usize,
+
+    /// Number of `u64` words in a row.
+    pub words_per_row: usize,
+
+    /// Rows of bits, bit `v` of row `u` set if `u` reaches `v`.
+    pub bits: Vec<u64>,
+}
+
+impl ReachabilityMatrix {
+    /// Create an empty reachability matrix.
+    ///
+    /// # Arguments
+    ///
+    /// - `size`: Number of nodes.
+    ///
+    /// # Returns
+    ///
+    /// Matrix with no pair reachable.
+    pub fn new(size: usize) -> Self {
+        let words_per_row = size.div_ceil(64);
+
+        Self {
+            size,
+            words_per_row,
+            bits: vec![0; size * words_per_row],
+        }
+    }
+
+    /// Get the words of a row.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Node of the row.
+    ///
+    /// # Returns
+    ///
+    /// Bits of the nodes reachable from the source.
+    pub fn row(&self, source: usize) -> &[u64] {
+        &self.bits[source * self.words_per_row..(source + 1) * self.words_per_row]
+    }
+
+    /// Check whether a node reaches another one.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a path of at least one edge leads from the source to the target.
+    pub fn reaches(&self, source: usize, target: usize) -> bool {
+        source < self.size
+            && target < self.size
+            && self.row(source)[target / 64] & (1 << (target % 64)) != 0
+    }
+
+    /// List the nodes reachable from a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    ///
+    /// # Returns
+    ///
+    /// Reachable nodes in ascending order.
+    pub fn reachable(&self, source: usize) -> Vec<usize> {
+        self.row(source)
+            .iter()
+            .enumerate()
+            .flat_map(|(index, &word)| {
+                (0..64)
+                    .filter(move |bit| word & (1 << bit) != 0)
+                    .map(move |bit| index * 64 + bit)
+            })
+            .collect()
+    }
+
+    /// Count the nodes reachable from a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    ///
+    /// # Returns
+    ///
+    /// Number of reachable nodes.
+    pub fn count(&self, source: usize) -> usize {
+        self.row(source)
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+}
+
+/// Transitive Closure.
+/// Compute which nodes of a directed graph reach which others, without distances. Strongly connected
+/// components are condensed, and each component's row of bits is the union of its successors' rows,
+/// merged 64 nodes at a time.
+#[derive(Debug, Clone)]
+pub struct TransitiveClosureAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Successors of each node.
+    pub adjacency: Vec<Vec<usize>>,
+}
+
+impl Default for TransitiveClosureAlgorithm {
+    /// Create a new default instance of the transitive closure.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the transitive closure.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransitiveClosureAlgorithm {
+    /// Create a new instance of the transitive closure.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the transitive closure.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+        }
+    }
+
+    /// Set a single directed edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].push(target);
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its successors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, Vec::new());
+    }
+
+    /// Compute the transitive closure.
+    ///
+    /// # Returns
+    ///
+    /// Reachability matrix, where a node reaches itself only if it lies on a cycle.
+    pub fn closure(&self) -> ReachabilityMatrix {
+        let n = self.total_nodes;
+        let mut matrix = ReachabilityMatrix::new(n);
+        let words = matrix.words_per_row;
+        let components = scc::strongly_connected_components(&self.adjacency);
+        let mut component = vec![0; n];
+
+        for (index, members) in components.iter().enumerate() {
+            members.iter().for_each(|&node| component[node] = index);
+        }
+
+        // Components come in reverse topological order, so successors are complete before use.
+        let mut rows = vec![0u64; components.len() * words];
+
+        for (index, members) in components.iter().enumerate() {
+            let (done, rest) = rows.split_at_mut(index * words);
+            let row = &mut rest[..words];
+
+            for &node in members {
+                for &target in &self.adjacency[node] {
+                    row[target / 64] |= 1 << (target % 64);
+
+                    let successor = component[target];
+
+                    if successor != index {
+                        let other = &done[successor * words..(successor + 1) * words];
+                        row.iter_mut().zip(other).for_each(|(a, b)| *a |= b);
+                    }
+                }
+            }
+
+            // Members of a cycle reach each other and themselves.
+            if members
+                .iter()
+                .any(|&node| row[node / 64] & (1 << (node % 64)) != 0)
+            {
+                members
+                    .iter()
+                    .for_each(|&node| row[node / 64] |= 1 << (node % 64));
+            }
+        }
+
+        for node in 0..n {
+            let row = &rows[component[node] * words..(component[node] + 1) * words];
+            matrix.bits[node * words..(node + 1) * words].copy_from_slice(row);
+        }
+
+        matrix
+    }
+}
+
+impl GraphAlgorithm for TransitiveClosureAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = ReachabilityMatrix;
+
+    /// Run the transitive closure.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the transitive closure.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the reachability matrix.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.closure())
+    }
+}
+
+impl MemoryEstimation for TransitiveClosureAlgorithm {
+    /// Estimate the memory of the transitive closure, one bit per pair of nodes for the matrix and
+    /// at most as many for the rows of the strongly connected components.
+    ///
+    /// # Returns
+    ///
+    /// Memory estimate of a run.
+    fn estimate_memory(&self) -> MemoryEstimate {
+        let n = self.total_nodes;
+        let edges: usize = self.adjacency.iter().map(Vec::len).sum();
+        let bits = MemoryEstimate::bytes::<u64>(n.div_ceil(64).saturating_mul(n));
+
+        MemoryEstimate::new(
+            MemoryEstimate::bytes::<Vec<usize>>(n)
+                .saturating_add(MemoryEstimate::bytes::<usize>(edges)),
+            bits.saturating_add(MemoryEstimate::bytes::<usize>(n.saturating_mul(4))),
+            bits,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Random;
+
+    #[test]
+    fn test_new() {
+        let algorithm = TransitiveClosureAlgorithm::new();
+        let algorithm_default = TransitiveClosureAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.closure(), ReachabilityMatrix::new(0));
+    }
+
+    #[test]
+    fn test_run() {
+        // Cycle 1 - 2 - 3 reached from 0 and leading to 4; 5 has a self-loop.
+        let mut algorithm = TransitiveClosureAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1]),
+            (1, vec![2]),
+            (2, vec![3]),
+            (3, vec![1, 4]),
+            (5, vec![5]),
+        ]);
+        algorithm.set_total_nodes(7);
+
+        let matrix = algorithm.run(None).unwrap();
+
+        assert_eq!(matrix.reachable(0), vec![1, 2, 3, 4]);
+        assert_eq!(matrix.reachable(2), vec![1, 2, 3, 4]);
+        assert_eq!(matrix.reachable(4), vec![]);
+        assert_eq!(matrix.reachable(5), vec![5]);
+        assert_eq!(matrix.count(1), 4);
+        assert!(matrix.reaches(3, 2));
+        assert!(!matrix.reaches(4, 0));
+        assert!(!matrix.reaches(0, 0));
+        assert!(!matrix.reaches(0, 9));
+    }
+
+    #[test]
+    fn test_run_random() {
+        let mut random = Random::new(3);
+        let mut algorithm = TransitiveClosureAlgorithm::new();
+        algorithm.set_total_nodes(150);
+
+        for _ in 0..200 {
+            algorithm.set_edge(random.below(150), random.below(150));
+        }
+
+        let matrix = algorithm.closure();
+
+        for source in 0..150 {
+            // Reference: depth-first search over paths of at least one edge.
+            let mut seen = [false; 150];
+            let mut stack = algorithm.adjacency[source].clone();
+
+            while let Some(node) = stack.pop() {
+                if !seen[node] {
+                    seen[node] = true;
+                    stack.extend(&algorithm.adjacency[node]);
+                }
+            }
+
+            for (target, &seen) in seen.iter().enumerate() {
+                assert_eq!(matrix.reaches(source, target), seen);
+            }
+        }
+    }
+
+    #[test]
+    fn test_estimate_memory() {
+        let mut algorithm = TransitiveClosureAlgorithm::new();
+        algorithm.set_total_nodes(65);
+
+        assert_eq!(algorithm.estimate_memory().output, 65 * 2 * 8);
+    }
+}
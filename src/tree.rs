@@ -0,0 +1,446 @@
+use crate::{GraphAlgorithm, GraphError};
+
+/// Centroid decomposition of a tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CentroidDecomposition {
+    /// Root of the decomposition tree, the centroid of the whole tree.
+    pub root: usize,
+
+    /// Parent of each node in the decomposition tree, `None` at the root.
+    pub parents: Vec<Option<usize>>,
+
+    /// Depth of each node in the decomposition tree, at most `log2(n)`.
+    pub levels: Vec<usize>,
+
+    /// Centroid ancestors of each node from the root down to the node itself, with their distance.
+    pub ancestors: Vec<Vec<(usize, usize)>>,
+}
+
+impl CentroidDecomposition {
+    /// Compute the distance between two nodes through their lowest common centroid ancestor,
+    /// which lies on the path between them.
+    ///
+    /// # Arguments
+    ///
+    /// - `first`: First node.
+    /// - `second`: Second node.
+    ///
+    /// # Returns
+    ///
+    /// Distance between the nodes, or `None` if a node is unknown.
+    pub fn distance(&self, first: usize, second: usize) -> Option<usize> {
+        let (first, second) = (self.ancestors.get(first)?, self.ancestors.get(second)?);
+
+        first
+            .iter()
+            .zip(second)
+            .take_while(|((a, _), (b, _))| a == b)
+            .last()
+            .map(|((_, to_first), (_, to_second))| to_first + to_second)
+    }
+}
+
+/// Tree Utilities.
+/// Diameter, center and centroid of a weighted tree, after validating that the graph is connected
+/// with one edge less than its nodes. The diameter is found with two traversals: the farthest node
+/// from any node is an end of a diameter, whose farthest node is the other end.
+#[derive(Debug, Clone)]
+pub struct TreeAlgorithm {
+    /// Total number of nodes in the tree.
+    pub total_nodes: usize,
+
+    /// Neighbors of each node with the weight of the edge.
+    pub adjacency: Vec<Vec<(usize, usize)>>,
+}
+
+impl Default for TreeAlgorithm {
+    /// Create a new default instance of the tree utilities.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the tree utilities.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TreeAlgorithm {
+    /// Create a new instance of the tree utilities.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the tree utilities.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+        }
+    }
+
+    /// Set a single undirected edge to the tree.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].push((target, weight));
+        self.adjacency[target].push((source, weight));
+    }
+
+    /// Set multiple nodes' edges to the tree.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the tree.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the tree.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        if total > self.total_nodes {
+            self.total_nodes = total;
+            self.adjacency.resize(total, Vec::new());
+        }
+    }
+
+    /// Check that the graph is a tree: non-empty, connected and with one edge less than its nodes.
+    ///
+    /// # Returns
+    ///
+    /// Result containing nothing, or an error if the graph is not a tree.
+    pub fn validate(&self) -> Result<(), GraphError> {
+        let edges = self.adjacency.iter().map(Vec::len).sum::<usize>() / 2;
+
+        if self.total_nodes == 0 || edges + 1 != self.total_nodes {
+            return Err(GraphError::NotTree);
+        }
+
+        let (distances, _) = self.traverse(0, &[]);
+
+        match distances.contains(&usize::MAX) {
+            true => Err(GraphError::NotTree),
+            false => Ok(()),
+        }
+    }
+
+    /// Find a longest path of the tree.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the length and nodes of the diameter, or an error if the graph is not a tree.
+    pub fn diameter(&self) -> Result<(usize, Vec<usize>), GraphError> {
+        self.validate()?;
+
+        let (distances, _) = self.traverse(0, &[]);
+        let first = Self::farthest(&distances);
+        let (distances, parents) = self.traverse(first, &[]);
+        let second = Self::farthest(&distances);
+
+        let mut path = vec![second];
+
+        while path[path.len() - 1] != first {
+            path.push(parents[path[path.len() - 1]]);
+        }
+
+        Ok((distances[second], path))
+    }
+
+    /// Find the center of the tree, the nodes of minimum eccentricity. It lies on every diameter,
+    /// where the eccentricity of a node is its distance to the farther end.
+    ///
+    /// # Returns
+    ///
+    /// Result containing one or two center nodes in ascending order, or an error if the graph is not a tree.
+    pub fn center(&self) -> Result<Vec<usize>, GraphError> {
+        let (length, path) = self.diameter()?;
+        let (from_end, _) = self.traverse(path[0], &[]);
+        let eccentricity = |node: usize| from_end[node].max(length - from_end[node]);
+        let radius = path
+            .iter()
+            .map(|&node| eccentricity(node))
+            .min()
+            .unwrap_or(0);
+
+        let mut center: Vec<usize> = path
+            .into_iter()
+            .filter(|&node| eccentricity(node) == radius)
+            .collect();
+        center.sort_unstable();
+
+        Ok(center)
+    }
+
+    /// Find the centroid of the tree, the nodes whose removal leaves components of at most half the nodes.
+    ///
+    /// # Returns
+    ///
+    /// Result containing one or two centroid nodes in ascending order, or an error if the graph is not a tree.
+    pub fn centroid(&self) -> Result<Vec<usize>, GraphError> {
+        self.validate()?;
+
+        let n = self.total_nodes;
+        let (distances, parents) = self.traverse(0, &[]);
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by_key(|&node| std::cmp::Reverse(distances[node]));
+
+        // Subtree sizes accumulate from the leaves, the largest component being a child or the rest.
+        let mut sizes = vec![1; n];
+        let mut largest = vec![0; n];
+
+        for &node in &order {
+            largest[node] = largest[node].max(n - sizes[node]);
+
+            if node != 0 {
+                let parent = parents[node];
+                sizes[parent] += sizes[node];
+                largest[parent] = largest[parent].max(sizes[node]);
+            }
+        }
+
+        Ok((0..n).filter(|&node| 2 * largest[node] <= n).collect())
+    }
+
+    /// Decompose the tree by repeatedly removing a centroid of every component, so that each node
+    /// has `O(log n)` centroid ancestors and any path passes through the lowest common one.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the centroid decomposition, or an error if the graph is not a tree.
+    pub fn centroid_decomposition(&self) -> Result<CentroidDecomposition, GraphError> {
+        self.validate()?;
+
+        let n = self.total_nodes;
+        let mut removed = vec![false; n];
+        let mut sizes = vec![0; n];
+        let mut decomposition = CentroidDecomposition {
+            root: 0,
+            parents: vec![None; n],
+            levels: vec![0; n],
+            ancestors: vec![Vec::new(); n],
+        };
+        let mut components = vec![(0, None)];
+
+        while let Some((start, parent)) = components.pop() {
+            // Nodes of the component in traversal order, with their parent in it.
+            let mut order = vec![(start, usize::MAX)];
+            let mut index = 0;
+
+            while index < order.len() {
+                let (node, from) = order[index];
+
+                for &(neighbor, _) in &self.adjacency[node] {
+                    if neighbor != from && !removed[neighbor] {
+                        order.push((neighbor, node));
+                    }
+                }
+
+                index += 1;
+            }
+
+            for &(node, _) in &order {
+                sizes[node] = 1;
+            }
+
+            for &(node, from) in order.iter().skip(1).rev() {
+                sizes[from] += sizes[node];
+            }
+
+            // Walk towards the heavy child until every part has at most half the nodes.
+            let total = order.len();
+            let mut centroid = start;
+            let mut from = usize::MAX;
+
+            while let Some(&(heavy, _)) = self.adjacency[centroid].iter().find(|&&(neighbor, _)| {
+                neighbor != from && !removed[neighbor] && 2 * sizes[neighbor] > total
+            }) {
+                from = centroid;
+                centroid = heavy;
+            }
+
+            let (distances, _) = self.traverse(centroid, &removed);
+
+            for &(node, _) in &order {
+                decomposition.ancestors[node].push((centroid, distances[node]));
+            }
+
+            decomposition.parents[centroid] = parent;
+            decomposition.levels[centroid] = decomposition.ancestors[centroid].len() - 1;
+
+            if parent.is_none() {
+                decomposition.root = centroid;
+            }
+
+            removed[centroid] = true;
+
+            for &(neighbor, _) in &self.adjacency[centroid] {
+                if !removed[neighbor] {
+                    components.push((neighbor, Some(centroid)));
+                }
+            }
+        }
+
+        Ok(decomposition)
+    }
+
+    /// Traverse the tree from a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `removed`: Nodes not to enter, none if empty.
+    ///
+    /// # Returns
+    ///
+    /// Distance and parent of each node, `usize::MAX` if unreachable.
+    fn traverse(&self, source: usize, removed: &[bool]) -> (Vec<usize>, Vec<usize>) {
+        let mut distances = vec![usize::MAX; self.total_nodes];
+        let mut parents = vec![usize::MAX; self.total_nodes];
+        let mut stack = vec![source];
+        distances[source] = 0;
+
+        while let Some(node) = stack.pop() {
+            for &(neighbor, weight) in &self.adjacency[node] {
+                if distances[neighbor] == usize::MAX && removed.get(neighbor) != Some(&true) {
+                    distances[neighbor] = distances[node] + weight;
+                    parents[neighbor] = node;
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        (distances, parents)
+    }
+
+    /// Find the farthest node, the smallest one on ties.
+    ///
+    /// # Arguments
+    ///
+    /// - `distances`: Distance of each node.
+    ///
+    /// # Returns
+    ///
+    /// Farthest node.
+    fn farthest(distances: &[usize]) -> usize {
+        (0..distances.len())
+            .max_by_key(|&node| (distances[node], std::cmp::Reverse(node)))
+            .unwrap_or(0)
+    }
+}
+
+impl GraphAlgorithm for TreeAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = (usize, Vec<usize>);
+
+    /// Run the tree utilities.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the tree utilities.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the length and nodes of the diameter, or an error if the graph is not a tree.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        self.diameter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let algorithm = TreeAlgorithm::new();
+        let algorithm_default = TreeAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.validate(), Err(GraphError::NotTree));
+    }
+
+    #[test]
+    fn test_run() {
+        // Path 0 - 1 - 2 - 3 - 4 with a branch 2 - 5 - 6.
+        let mut algorithm = TreeAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 1)]),
+            (1, vec![(2, 1)]),
+            (2, vec![(3, 1), (5, 1)]),
+            (3, vec![(4, 1)]),
+            (5, vec![(6, 1)]),
+        ]);
+
+        assert_eq!(algorithm.run(None), Ok((4, vec![0, 1, 2, 3, 4])));
+        assert_eq!(algorithm.center(), Ok(vec![2]));
+        assert_eq!(algorithm.centroid(), Ok(vec![2]));
+
+        // A heavy edge moves the center but not the centroid.
+        algorithm.set_total_nodes(8);
+        algorithm.set_edge(6, 7, 10);
+
+        assert_eq!(algorithm.diameter().unwrap().0, 14);
+        assert_eq!(algorithm.center(), Ok(vec![6]));
+        assert_eq!(algorithm.centroid(), Ok(vec![2]));
+    }
+
+    #[test]
+    fn test_centroid_decomposition() {
+        // Path of 15 nodes with weights 1 to 14, decomposed into a balanced tree of depth 3.
+        let mut algorithm = TreeAlgorithm::new();
+
+        for node in 0..14 {
+            algorithm.set_edge(node, node + 1, node + 1);
+        }
+
+        let decomposition = algorithm.centroid_decomposition().unwrap();
+
+        assert_eq!(decomposition.root, 7);
+        assert_eq!(decomposition.parents[3], Some(7));
+        assert_eq!(decomposition.parents[0], Some(1));
+        assert_eq!(decomposition.levels.iter().max(), Some(&3));
+        assert_eq!(
+            decomposition.ancestors[2],
+            vec![(7, 25), (3, 3), (1, 2), (2, 0)]
+        );
+
+        for first in 0..15 {
+            let (distances, _) = algorithm.traverse(first, &[]);
+
+            for (second, &distance) in distances.iter().enumerate() {
+                assert_eq!(decomposition.distance(first, second), Some(distance));
+            }
+        }
+
+        assert_eq!(decomposition.distance(0, 15), None);
+    }
+
+    #[test]
+    fn test_run_not_tree() {
+        let mut algorithm = TreeAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 1)]), (1, vec![(2, 1)])]);
+        algorithm.set_total_nodes(4);
+
+        assert_eq!(algorithm.run(None), Err(GraphError::NotTree));
+
+        algorithm.set_edge(2, 0, 1);
+
+        assert_eq!(algorithm.centroid(), Err(GraphError::NotTree));
+    }
+}
@@ -0,0 +1,932 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{
+    EliminationHeuristic, GraphAlgorithm, GraphError, TreeDecomposition, TreewidthAlgorithm,
+};
+
+/// Kind of a node of a nice tree decomposition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NiceNode {
+    /// Leaf with an empty bag.
+    Leaf,
+
+    /// Bag of the child plus the given graph node.
+    Introduce(usize),
+
+    /// Bag of the child minus the given graph node.
+    Forget(usize),
+
+    /// Two children with the same bag.
+    Join,
+}
+
+/// Nice tree decomposition, where every node is a leaf, introduce, forget or join node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NiceTreeDecomposition {
+    /// Kind of each node.
+    pub kinds: Vec<NiceNode>,
+
+    /// Sorted bag of each node.
+    pub bags: Vec<Vec<usize>>,
+
+    /// Children of each node. Children always come before their parent.
+    pub children: Vec<Vec<usize>>,
+
+    /// Root node with an empty bag.
+    pub root: usize,
+}
+
+/// Dynamic programming over a nice tree decomposition.
+///
+/// Each callback computes the table of a node from the tables of its children.
+pub trait TreeDecompositionDp {
+    /// Table computed for each node of the decomposition.
+    type Table;
+
+    /// Compute the table of a leaf node.
+    ///
+    /// # Returns
+    ///
+    /// Table of the empty bag.
+    fn leaf(&self) -> Self::Table;
+
+    /// Compute the table of an introduce node.
+    ///
+    /// # Arguments
+    ///
+    /// - `bag`: Bag of the node, including the introduced node.
+    /// - `node`: Introduced graph node.
+    /// - `child`: Table of the child.
+    ///
+    /// # Returns
+    ///
+    /// Table of the node.
+    fn introduce(&self, bag: &[usize], node: usize, child: &Self::Table) -> Self::Table;
+
+    /// Compute the table of a forget node.
+    ///
+    /// # Arguments
+    ///
+    /// - `bag`: Bag of the node, without the forgotten node.
+    /// - `node`: Forgotten graph node.
+    /// - `child`: Table of the child.
+    ///
+    /// # Returns
+    ///
+    /// Table of the node.
+    fn forget(&self, bag: &[usize], node: usize, child: &Self::Table) -> Self::Table;
+
+    /// Compute the table of a join node.
+    ///
+    /// # Arguments
+    ///
+    /// - `bag`: Bag of the node and both children.
+    /// - `left`: Table of the first child.
+    /// - `right`: Table of the second child.
+    ///
+    /// # Returns
+    ///
+    /// Table of the node.
+    fn join(&self, bag: &[usize], left: &Self::Table, right: &Self::Table) -> Self::Table;
+}
+
+impl NiceTreeDecomposition {
+    /// Convert a tree decomposition into a nice tree decomposition.
+    ///
+    /// # Arguments
+    ///
+    /// - `decomposition`: Tree decomposition to convert.
+    ///
+    /// # Returns
+    ///
+    /// Nice tree decomposition rooted at an empty bag.
+    pub fn new(decomposition: &TreeDecomposition) -> Self {
+        let mut nice = Self {
+            kinds: Vec::new(),
+            bags: Vec::new(),
+            children: Vec::new(),
+            root: 0,
+        };
+
+        let total = decomposition.bags.len();
+
+        if total == 0 {
+            nice.root = nice.push(NiceNode::Leaf, Vec::new(), Vec::new());
+            return nice;
+        }
+
+        let mut neighbors = vec![Vec::new(); total];
+
+        for &(a, b) in &decomposition.edges {
+            neighbors[a].push(b);
+            neighbors[b].push(a);
+        }
+
+        // Order the bags so that every parent comes before its children.
+        let mut parent = vec![usize::MAX; total];
+        let mut order = vec![0];
+        parent[0] = 0;
+
+        let mut index = 0;
+
+        while index < order.len() {
+            let bag = order[index];
+            index += 1;
+
+            for &next in &neighbors[bag] {
+                if parent[next] == usize::MAX {
+                    parent[next] = bag;
+                    order.push(next);
+                }
+            }
+        }
+
+        let mut tops: Vec<Vec<usize>> = vec![Vec::new(); total];
+
+        for &bag in order.iter().rev() {
+            let target = &decomposition.bags[bag];
+            let branches: Vec<usize> = match tops[bag].is_empty() {
+                true => vec![nice.chain(None, target)],
+                false => std::mem::take(&mut tops[bag])
+                    .into_iter()
+                    .map(|child| nice.chain(Some(child), target))
+                    .collect(),
+            };
+
+            let top = branches
+                .into_iter()
+                .reduce(|left, right| nice.push(NiceNode::Join, target.clone(), vec![left, right]))
+                .unwrap_or_default();
+
+            if bag == 0 {
+                nice.root = nice.chain(Some(top), &[]);
+            } else {
+                tops[parent[bag]].push(top);
+            }
+        }
+
+        nice
+    }
+
+    /// Add a node to the decomposition.
+    ///
+    /// # Arguments
+    ///
+    /// - `kind`: Kind of the node.
+    /// - `bag`: Bag of the node.
+    /// - `children`: Children of the node.
+    ///
+    /// # Returns
+    ///
+    /// Index of the new node.
+    fn push(&mut self, kind: NiceNode, bag: Vec<usize>, children: Vec<usize>) -> usize {
+        self.kinds.push(kind);
+        self.bags.push(bag);
+        self.children.push(children);
+        self.kinds.len() - 1
+    }
+
+    /// Add forget and introduce nodes turning the bag of a node into the target bag.
+    ///
+    /// # Arguments
+    ///
+    /// - `from`: Node to start from, or `None` to start from a new leaf.
+    /// - `target`: Sorted target bag.
+    ///
+    /// # Returns
+    ///
+    /// Index of the node with the target bag.
+    fn chain(&mut self, from: Option<usize>, target: &[usize]) -> usize {
+        let mut current = from.unwrap_or_else(|| self.push(NiceNode::Leaf, Vec::new(), Vec::new()));
+
+        for node in self.bags[current].clone() {
+            if !target.contains(&node) {
+                let bag: Vec<usize> = self.bags[current]
+                    .iter()
+                    .copied()
+                    .filter(|&n| n != node)
+                    .collect();
+                current = self.push(NiceNode::Forget(node), bag, vec![current]);
+            }
+        }
+
+        for &node in target {
+            if !self.bags[current].contains(&node) {
+                let mut bag = self.bags[current].clone();
+                bag.push(node);
+                bag.sort_unstable();
+                current = self.push(NiceNode::Introduce(node), bag, vec![current]);
+            }
+        }
+
+        current
+    }
+
+    /// Compute the table of the root by running the dynamic programming bottom-up.
+    ///
+    /// # Arguments
+    ///
+    /// - `dp`: Dynamic programming callbacks.
+    ///
+    /// # Returns
+    ///
+    /// Table of the root node.
+    pub fn solve<D: TreeDecompositionDp>(&self, dp: &D) -> D::Table {
+        let mut tables: Vec<Option<D::Table>> = Vec::with_capacity(self.kinds.len());
+
+        for (index, kind) in self.kinds.iter().enumerate() {
+            let bag = &self.bags[index];
+            let mut child = |position: usize| {
+                tables[self.children[index][position]]
+                    .take()
+                    .expect("children come before their parent")
+            };
+
+            let table = match *kind {
+                NiceNode::Leaf => dp.leaf(),
+                NiceNode::Introduce(node) => dp.introduce(bag, node, &child(0)),
+                NiceNode::Forget(node) => dp.forget(bag, node, &child(0)),
+                NiceNode::Join => {
+                    let left = child(0);
+                    dp.join(bag, &left, &child(1))
+                }
+            };
+
+            tables.push(Some(table));
+        }
+
+        tables[self.root].take().expect("root is computed")
+    }
+}
+
+/// Table of a dynamic programming: best weight and chosen nodes per state of the bag.
+pub type DpTable<K> = HashMap<K, (usize, BTreeSet<usize>)>;
+
+/// Keep the better of two entries of a table.
+///
+/// # Arguments
+///
+/// - `table`: Table to update.
+/// - `key`: State of the bag.
+/// - `entry`: Weight and chosen nodes.
+/// - `better`: Whether a weight beats another.
+fn keep<K: std::hash::Hash + Eq>(
+    table: &mut DpTable<K>,
+    key: K,
+    entry: (usize, BTreeSet<usize>),
+    better: fn(usize, usize) -> bool,
+) {
+    // Ties prefer the lexicographically smallest set, keeping the output deterministic.
+    match table.get(&key) {
+        Some((weight, set))
+            if !better(entry.0, *weight) && (entry.0 != *weight || entry.1 >= *set) => {}
+        _ => {
+            table.insert(key, entry);
+        }
+    }
+}
+
+/// Maximum Weight Independent Set.
+/// Find an independent set of maximum total weight by dynamic programming over a tree decomposition.
+#[derive(Debug, Clone)]
+pub struct IndependentSetAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Neighbors of each node.
+    pub adjacency: Vec<BTreeSet<usize>>,
+
+    /// Weight of each node.
+    pub weights: Vec<usize>,
+
+    /// Heuristic used to build the tree decomposition.
+    pub heuristic: EliminationHeuristic,
+}
+
+/// Minimum Weight Dominating Set.
+/// Find a dominating set of minimum total weight by dynamic programming over a tree decomposition.
+#[derive(Debug, Clone)]
+pub struct DominatingSetAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Neighbors of each node.
+    pub adjacency: Vec<BTreeSet<usize>>,
+
+    /// Weight of each node.
+    pub weights: Vec<usize>,
+
+    /// Heuristic used to build the tree decomposition.
+    pub heuristic: EliminationHeuristic,
+}
+
+impl Default for IndependentSetAlgorithm {
+    /// Create a new default instance of the maximum weight independent set algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the maximum weight independent set algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IndependentSetAlgorithm {
+    /// Create a new instance of the maximum weight independent set algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the maximum weight independent set algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+            weights: Vec::new(),
+            heuristic: EliminationHeuristic::default(),
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+
+        if source != target {
+            self.adjacency[source].insert(target);
+            self.adjacency[target].insert(source);
+        }
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its neighbors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the weight of a node. Nodes have a weight of one by default.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node of the graph.
+    /// - `weight`: Weight of the node.
+    pub fn set_weight(&mut self, node: usize, weight: usize) {
+        self.set_total_nodes(node + 1);
+        self.weights[node] = weight;
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, BTreeSet::new());
+        self.weights.resize(self.total_nodes, 1);
+    }
+
+    /// Set the heuristic used to build the tree decomposition.
+    ///
+    /// # Arguments
+    ///
+    /// - `heuristic`: Elimination heuristic.
+    pub fn set_heuristic(&mut self, heuristic: EliminationHeuristic) {
+        self.heuristic = heuristic;
+    }
+
+    /// Build the nice tree decomposition of the graph.
+    ///
+    /// # Returns
+    ///
+    /// Nice tree decomposition of the graph.
+    fn decomposition(&self) -> NiceTreeDecomposition {
+        let treewidth = TreewidthAlgorithm {
+            total_nodes: self.total_nodes,
+            adjacency: self.adjacency.clone(),
+            heuristic: self.heuristic,
+        };
+
+        NiceTreeDecomposition::new(&treewidth.decompose(&treewidth.elimination_ordering()))
+    }
+}
+
+impl Default for DominatingSetAlgorithm {
+    /// Create a new default instance of the minimum weight dominating set algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the minimum weight dominating set algorithm.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DominatingSetAlgorithm {
+    /// Create a new instance of the minimum weight dominating set algorithm.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the minimum weight dominating set algorithm.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+            weights: Vec::new(),
+            heuristic: EliminationHeuristic::default(),
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+
+        if source != target {
+            self.adjacency[source].insert(target);
+            self.adjacency[target].insert(source);
+        }
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its neighbors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the weight of a node. Nodes have a weight of one by default.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node of the graph.
+    /// - `weight`: Weight of the node.
+    pub fn set_weight(&mut self, node: usize, weight: usize) {
+        self.set_total_nodes(node + 1);
+        self.weights[node] = weight;
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, BTreeSet::new());
+        self.weights.resize(self.total_nodes, 1);
+    }
+
+    /// Set the heuristic used to build the tree decomposition.
+    ///
+    /// # Arguments
+    ///
+    /// - `heuristic`: Elimination heuristic.
+    pub fn set_heuristic(&mut self, heuristic: EliminationHeuristic) {
+        self.heuristic = heuristic;
+    }
+
+    /// Build the nice tree decomposition of the graph.
+    ///
+    /// # Returns
+    ///
+    /// Nice tree decomposition of the graph.
+    fn decomposition(&self) -> NiceTreeDecomposition {
+        let treewidth = TreewidthAlgorithm {
+            total_nodes: self.total_nodes,
+            adjacency: self.adjacency.clone(),
+            heuristic: self.heuristic,
+        };
+
+        NiceTreeDecomposition::new(&treewidth.decompose(&treewidth.elimination_ordering()))
+    }
+}
+
+impl TreeDecompositionDp for IndependentSetAlgorithm {
+    /// Best independent set for each subset of the bag it contains.
+    type Table = DpTable<Vec<usize>>;
+
+    /// Start with the empty set.
+    fn leaf(&self) -> Self::Table {
+        HashMap::from([(Vec::new(), (0, BTreeSet::new()))])
+    }
+
+    /// Extend every set with the introduced node if it has no chosen neighbor in the bag.
+    fn introduce(&self, _bag: &[usize], node: usize, child: &Self::Table) -> Self::Table {
+        let mut table = child.clone();
+
+        for (chosen, (weight, set)) in child {
+            if chosen
+                .iter()
+                .any(|other| self.adjacency[node].contains(other))
+            {
+                continue;
+            }
+
+            let mut chosen = chosen.clone();
+            chosen.push(node);
+            chosen.sort_unstable();
+
+            let mut set = set.clone();
+            set.insert(node);
+
+            table.insert(chosen, (weight + self.weights[node], set));
+        }
+
+        table
+    }
+
+    /// Keep the heaviest set for each remaining subset of the bag.
+    fn forget(&self, _bag: &[usize], node: usize, child: &Self::Table) -> Self::Table {
+        let mut table = HashMap::new();
+
+        for (chosen, entry) in child {
+            let chosen: Vec<usize> = chosen.iter().copied().filter(|&n| n != node).collect();
+            keep(&mut table, chosen, entry.clone(), |a, b| a > b);
+        }
+
+        table
+    }
+
+    /// Merge the sets of both children choosing the same subset of the bag.
+    fn join(&self, _bag: &[usize], left: &Self::Table, right: &Self::Table) -> Self::Table {
+        let mut table = HashMap::new();
+
+        for (chosen, (left_weight, left_set)) in left {
+            if let Some((right_weight, right_set)) = right.get(chosen) {
+                // Nodes of the bag are counted by both children.
+                let shared: usize = chosen.iter().map(|&node| self.weights[node]).sum();
+                let set = left_set.union(right_set).copied().collect();
+
+                table.insert(chosen.clone(), (left_weight + right_weight - shared, set));
+            }
+        }
+
+        table
+    }
+}
+
+/// State of a bag node in the dominating set dynamic programming.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Domination {
+    /// Node is in the dominating set.
+    Chosen,
+
+    /// Node is not in the set but has a chosen neighbor.
+    Dominated,
+
+    /// Node is not in the set and must still be dominated by a node introduced later.
+    Pending,
+}
+
+impl TreeDecompositionDp for DominatingSetAlgorithm {
+    /// Best dominating set for each assignment of states to the bag.
+    type Table = DpTable<Vec<(usize, Domination)>>;
+
+    /// Start with the empty set.
+    fn leaf(&self) -> Self::Table {
+        HashMap::from([(Vec::new(), (0, BTreeSet::new()))])
+    }
+
+    /// Either choose the introduced node, dominating its bag neighbors, or leave it out.
+    fn introduce(&self, _bag: &[usize], node: usize, child: &Self::Table) -> Self::Table {
+        let mut table = HashMap::new();
+
+        for (states, (weight, set)) in child {
+            let adjacent = |other: usize| self.adjacency[node].contains(&other);
+
+            // The introduced node joins the set and dominates its bag neighbors.
+            let mut chosen: Vec<(usize, Domination)> = states
+                .iter()
+                .map(|&(other, state)| match state {
+                    Domination::Pending if adjacent(other) => (other, Domination::Dominated),
+                    _ => (other, state),
+                })
+                .chain([(node, Domination::Chosen)])
+                .collect();
+            chosen.sort_unstable();
+
+            let mut chosen_set = set.clone();
+            chosen_set.insert(node);
+            keep(
+                &mut table,
+                chosen,
+                (weight + self.weights[node], chosen_set),
+                |a, b| a < b,
+            );
+
+            // The introduced node stays out of the set.
+            let dominated = states
+                .iter()
+                .any(|&(other, state)| state == Domination::Chosen && adjacent(other));
+            let state = match dominated {
+                true => Domination::Dominated,
+                false => Domination::Pending,
+            };
+
+            let mut skipped = states.clone();
+            skipped.push((node, state));
+            skipped.sort_unstable();
+            keep(&mut table, skipped, (*weight, set.clone()), |a, b| a < b);
+        }
+
+        table
+    }
+
+    /// Drop the forgotten node if it is dominated, keeping the lightest set for each state.
+    fn forget(&self, _bag: &[usize], node: usize, child: &Self::Table) -> Self::Table {
+        let mut table = HashMap::new();
+
+        for (states, entry) in child {
+            // A forgotten node can no longer be dominated.
+            if states.contains(&(node, Domination::Pending)) {
+                continue;
+            }
+
+            let states: Vec<(usize, Domination)> =
+                states.iter().copied().filter(|&(n, _)| n != node).collect();
+            keep(&mut table, states, entry.clone(), |a, b| a < b);
+        }
+
+        table
+    }
+
+    /// Merge the sets of both children agreeing on the chosen nodes of the bag.
+    fn join(&self, _bag: &[usize], left: &Self::Table, right: &Self::Table) -> Self::Table {
+        let mut table = HashMap::new();
+
+        for (left_states, (left_weight, left_set)) in left {
+            for (right_states, (right_weight, right_set)) in right {
+                let mut states = Vec::with_capacity(left_states.len());
+                let mut shared = 0;
+
+                for (&(node, a), &(_, b)) in left_states.iter().zip(right_states) {
+                    let state = match (a, b) {
+                        (Domination::Chosen, Domination::Chosen) => {
+                            shared += self.weights[node];
+                            Domination::Chosen
+                        }
+                        (Domination::Chosen, _) | (_, Domination::Chosen) => break,
+                        (Domination::Pending, Domination::Pending) => Domination::Pending,
+                        _ => Domination::Dominated,
+                    };
+
+                    states.push((node, state));
+                }
+
+                if states.len() < left_states.len() {
+                    continue;
+                }
+
+                let set = left_set.union(right_set).copied().collect();
+                keep(
+                    &mut table,
+                    states,
+                    (left_weight + right_weight - shared, set),
+                    |a, b| a < b,
+                );
+            }
+        }
+
+        table
+    }
+}
+
+impl GraphAlgorithm for IndependentSetAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = (usize, Vec<usize>);
+
+    /// Run the maximum weight independent set algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the maximum weight independent set algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the total weight and the nodes of the independent set.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let table = self.decomposition().solve(self);
+        let (weight, set) = table.get(&Vec::new()).cloned().unwrap_or_default();
+
+        Ok((weight, set.into_iter().collect()))
+    }
+}
+
+impl GraphAlgorithm for DominatingSetAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = (usize, Vec<usize>);
+
+    /// Run the minimum weight dominating set algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the minimum weight dominating set algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the total weight and the nodes of the dominating set.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let table = self.decomposition().solve(self);
+        let (weight, set) = table.get(&Vec::new()).cloned().unwrap_or_default();
+
+        Ok((weight, set.into_iter().collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Count the nodes of a nice tree decomposition.
+    struct CountNodes;
+
+    impl TreeDecompositionDp for CountNodes {
+        type Table = usize;
+
+        fn leaf(&self) -> usize {
+            0
+        }
+
+        fn introduce(&self, _bag: &[usize], _node: usize, child: &usize) -> usize {
+            child + 1
+        }
+
+        fn forget(&self, _bag: &[usize], _node: usize, child: &usize) -> usize {
+            *child
+        }
+
+        fn join(&self, bag: &[usize], left: &usize, right: &usize) -> usize {
+            left + right - bag.len()
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = IndependentSetAlgorithm::new();
+        let algorithm_default = DominatingSetAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.total_nodes, 0);
+    }
+
+    #[test]
+    fn test_nice_tree_decomposition() {
+        let mut treewidth = TreewidthAlgorithm::new();
+        treewidth.set_edges(vec![
+            (0, vec![1, 2]),
+            (1, vec![3]),
+            (2, vec![4, 5]),
+            (5, vec![6]),
+        ]);
+
+        let decomposition = treewidth.run(None).unwrap();
+        let nice = NiceTreeDecomposition::new(&decomposition);
+
+        assert!(nice.bags[nice.root].is_empty());
+        assert_eq!(nice.solve(&CountNodes), 7);
+
+        for (index, kind) in nice.kinds.iter().enumerate() {
+            let children = &nice.children[index];
+
+            assert!(children.iter().all(|&child| child < index));
+
+            match *kind {
+                NiceNode::Leaf => assert!(children.is_empty() && nice.bags[index].is_empty()),
+                NiceNode::Introduce(node) | NiceNode::Forget(node) => {
+                    assert_eq!(children.len(), 1);
+                    assert_ne!(
+                        nice.bags[index].contains(&node),
+                        nice.bags[children[0]].contains(&node)
+                    );
+                }
+                NiceNode::Join => {
+                    assert_eq!(nice.bags[children[0]], nice.bags[index]);
+                    assert_eq!(nice.bags[children[1]], nice.bags[index]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_nice_tree_decomposition_empty() {
+        let nice = NiceTreeDecomposition::new(&TreeDecomposition {
+            bags: vec![],
+            edges: vec![],
+            width: 0,
+        });
+
+        assert_eq!(nice.kinds, vec![NiceNode::Leaf]);
+        assert_eq!(nice.solve(&CountNodes), 0);
+    }
+
+    #[test]
+    fn test_run_independent_set() {
+        let mut algorithm = IndependentSetAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1]),
+            (1, vec![2]),
+            (2, vec![3]),
+            (3, vec![4]),
+            (4, vec![0]),
+        ]);
+        algorithm.set_weight(0, 5);
+
+        assert_eq!(algorithm.run(None).unwrap(), (6, vec![0, 2]));
+    }
+
+    #[test]
+    fn test_run_independent_set_star() {
+        let mut algorithm = IndependentSetAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1, 2, 3])]);
+
+        assert_eq!(algorithm.run(None).unwrap(), (3, vec![1, 2, 3]));
+
+        algorithm.set_weight(0, 4);
+
+        assert_eq!(algorithm.run(None).unwrap(), (4, vec![0]));
+    }
+
+    #[test]
+    fn test_run_independent_set_isolated_nodes() {
+        let mut algorithm = IndependentSetAlgorithm::new();
+        algorithm.set_edge(0, 1);
+        algorithm.set_total_nodes(4);
+        algorithm.set_heuristic(EliminationHeuristic::MinFill);
+
+        assert_eq!(algorithm.run(None).unwrap(), (3, vec![0, 2, 3]));
+    }
+
+    #[test]
+    fn test_run_dominating_set() {
+        let mut algorithm = DominatingSetAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1]),
+            (1, vec![2]),
+            (2, vec![3]),
+            (3, vec![4]),
+            (4, vec![5]),
+        ]);
+
+        let (weight, set) = algorithm.run(None).unwrap();
+
+        assert_eq!(weight, 2);
+
+        for node in 0..6 {
+            assert!(
+                set.contains(&node) || set.iter().any(|s| algorithm.adjacency[node].contains(s))
+            );
+        }
+    }
+
+    #[test]
+    fn test_run_dominating_set_weighted() {
+        let mut algorithm = DominatingSetAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1, 2, 3]), (1, vec![2])]);
+        algorithm.set_weight(0, 10);
+
+        assert_eq!(algorithm.run(None).unwrap(), (2, vec![1, 3]));
+    }
+
+    #[test]
+    fn test_run_empty_graph() {
+        assert_eq!(
+            IndependentSetAlgorithm::new().run(None).unwrap(),
+            (0, vec![])
+        );
+        assert_eq!(
+            DominatingSetAlgorithm::new().run(None).unwrap(),
+            (0, vec![])
+        );
+    }
+}
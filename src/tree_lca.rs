@@ -0,0 +1,362 @@
+use crate::{GraphAlgorithm, GraphError};
+
+/// Binary lifting table of a rooted forest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LcaIndex {
+    /// Root of the tree containing each node.
+    pub roots: Vec<usize>,
+
+    /// Number of edges from each node to its root.
+    pub depths: Vec<usize>,
+
+    /// Total edge weight from each node to its root.
+    pub distances: Vec<usize>,
+
+    /// Ancestor `2^k` levels up of each node in row `k`, the root being its own ancestor.
+    pub ancestors: Vec<Vec<usize>>,
+}
+
+impl LcaIndex {
+    /// Find an ancestor of a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node.
+    /// - `levels`: Number of levels up.
+    ///
+    /// # Returns
+    ///
+    /// Ancestor of the node, or `None` if the node is unknown or not that deep.
+    pub fn ancestor(&self, node: usize, levels: usize) -> Option<usize> {
+        if levels > *self.depths.get(node)? {
+            return None;
+        }
+
+        Some(self.lift(node, levels))
+    }
+
+    /// Find the lowest common ancestor of two nodes, a node counting as its own ancestor.
+    ///
+    /// # Arguments
+    ///
+    /// - `first`: First node.
+    /// - `second`: Second node.
+    ///
+    /// # Returns
+    ///
+    /// Lowest common ancestor, or `None` if a node is unknown or the nodes are in different trees.
+    pub fn lca(&self, first: usize, second: usize) -> Option<usize> {
+        if self.roots.get(first)? != self.roots.get(second)? {
+            return None;
+        }
+
+        let (deeper, other) = match self.depths[first] >= self.depths[second] {
+            true => (first, second),
+            false => (second, first),
+        };
+        let mut a = self.lift(deeper, self.depths[deeper] - self.depths[other]);
+        let mut b = other;
+
+        if a == b {
+            return Some(a);
+        }
+
+        for level in self.ancestors.iter().rev() {
+            if level[a] != level[b] {
+                a = level[a];
+                b = level[b];
+            }
+        }
+
+        Some(self.ancestors[0][a])
+    }
+
+    /// Compute the weighted distance between two nodes along the tree.
+    ///
+    /// # Arguments
+    ///
+    /// - `first`: First node.
+    /// - `second`: Second node.
+    ///
+    /// # Returns
+    ///
+    /// Sum of the edge weights on the path, or `None` if the nodes are not connected.
+    pub fn distance(&self, first: usize, second: usize) -> Option<usize> {
+        let lca = self.lca(first, second)?;
+
+        Some(self.distances[first] + self.distances[second] - 2 * self.distances[lca])
+    }
+
+    /// Count the edges between two nodes along the tree.
+    ///
+    /// # Arguments
+    ///
+    /// - `first`: First node.
+    /// - `second`: Second node.
+    ///
+    /// # Returns
+    ///
+    /// Number of edges on the path, or `None` if the nodes are not connected.
+    pub fn hops(&self, first: usize, second: usize) -> Option<usize> {
+        let lca = self.lca(first, second)?;
+
+        Some(self.depths[first] + self.depths[second] - 2 * self.depths[lca])
+    }
+
+    /// Move a node up by a number of levels within its depth.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node.
+    /// - `levels`: Number of levels up, at most the depth of the node.
+    ///
+    /// # Returns
+    ///
+    /// Ancestor of the node.
+    fn lift(&self, mut node: usize, levels: usize) -> usize {
+        for (bit, level) in self.ancestors.iter().enumerate() {
+            if levels >> bit & 1 == 1 {
+                node = level[node];
+            }
+        }
+
+        node
+    }
+}
+
+/// Tree Lowest Common Ancestor.
+/// Answer lowest common ancestor and distance queries on a weighted tree or forest with binary lifting:
+/// building the table of `2^k`-th ancestors takes `O(n log n)` and each query `O(log n)`.
+#[derive(Debug, Clone)]
+pub struct TreeLcaAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Neighbors of each node with the weight of the edge.
+    pub adjacency: Vec<Vec<(usize, usize)>>,
+}
+
+impl Default for TreeLcaAlgorithm {
+    /// Create a new default instance of the tree lowest common ancestor.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the tree lowest common ancestor.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TreeLcaAlgorithm {
+    /// Create a new instance of the tree lowest common ancestor.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the tree lowest common ancestor.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+        }
+    }
+
+    /// Set a single undirected edge to the tree.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge, such as a branch length.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+        self.adjacency[source].push((target, weight));
+        self.adjacency[target].push((source, weight));
+    }
+
+    /// Set multiple nodes' edges to the tree.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the tree.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the tree.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        if total > self.total_nodes {
+            self.total_nodes = total;
+            self.adjacency.resize(total, Vec::new());
+        }
+    }
+
+    /// Build the binary lifting table.
+    ///
+    /// # Arguments
+    ///
+    /// - `root`: Root of its tree, the other trees being rooted at their smallest node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the lifting table, or an error if the root is unknown or the graph has a cycle.
+    pub fn build(&self, root: Option<usize>) -> Result<LcaIndex, GraphError> {
+        let n = self.total_nodes;
+
+        if root.is_some_and(|root| root >= n) {
+            return Err(GraphError::MissingStartNode);
+        }
+
+        let mut roots = vec![usize::MAX; n];
+        let mut depths = vec![0; n];
+        let mut distances = vec![0; n];
+        let mut parents: Vec<usize> = (0..n).collect();
+
+        for start in root.into_iter().chain(0..n) {
+            if roots[start] != usize::MAX {
+                continue;
+            }
+
+            roots[start] = start;
+            let mut stack = vec![start];
+
+            // Every neighbor but the parent must be new, or an edge closes a cycle.
+            while let Some(node) = stack.pop() {
+                let mut parent_edge = node != start;
+
+                for &(neighbor, weight) in &self.adjacency[node] {
+                    if parent_edge && neighbor == parents[node] {
+                        parent_edge = false;
+                        continue;
+                    }
+
+                    if roots[neighbor] != usize::MAX {
+                        return Err(GraphError::CycleDetected);
+                    }
+
+                    roots[neighbor] = start;
+                    parents[neighbor] = node;
+                    depths[neighbor] = depths[node] + 1;
+                    distances[neighbor] = distances[node] + weight;
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        let levels = (usize::BITS - n.leading_zeros()).max(1) as usize;
+        let mut ancestors = vec![parents];
+
+        for level in 1..levels {
+            let previous = &ancestors[level - 1];
+            let next = previous.iter().map(|&node| previous[node]).collect();
+            ancestors.push(next);
+        }
+
+        Ok(LcaIndex {
+            roots,
+            depths,
+            distances,
+            ancestors,
+        })
+    }
+}
+
+impl GraphAlgorithm for TreeLcaAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = LcaIndex;
+
+    /// Run the tree lowest common ancestor.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Root of its tree, or `None` to root every tree at its smallest node.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the lifting table, or an error if the root is unknown or the graph has a cycle.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        self.build(start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree() -> TreeLcaAlgorithm {
+        //         0
+        //      3/   \1
+        //      1     2
+        //    1/ \2    \4
+        //    3   4     5
+        //        |1
+        //        6
+        let mut algorithm = TreeLcaAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![(1, 3), (2, 1)]),
+            (1, vec![(3, 1), (4, 2)]),
+            (2, vec![(5, 4)]),
+            (4, vec![(6, 1)]),
+        ]);
+        algorithm
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = TreeLcaAlgorithm::new();
+        let algorithm_default = TreeLcaAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.run(None).unwrap().roots, vec![]);
+        assert_eq!(algorithm.run(Some(0)), Err(GraphError::MissingStartNode));
+    }
+
+    #[test]
+    fn test_run() {
+        let index = tree().run(Some(0)).unwrap();
+
+        assert_eq!(index.lca(6, 3), Some(1));
+        assert_eq!(index.lca(6, 5), Some(0));
+        assert_eq!(index.lca(4, 6), Some(4));
+        assert_eq!(index.lca(2, 2), Some(2));
+        assert_eq!(index.distance(6, 5), Some(11));
+        assert_eq!(index.hops(6, 5), Some(5));
+        assert_eq!(index.ancestor(6, 2), Some(1));
+        assert_eq!(index.ancestor(6, 4), None);
+        assert_eq!(index.lca(0, 9), None);
+
+        // Rooting elsewhere changes the ancestors but not the distances.
+        let rerooted = tree().run(Some(6)).unwrap();
+
+        assert_eq!(rerooted.lca(3, 5), Some(1));
+        assert_eq!(rerooted.distance(6, 5), Some(11));
+    }
+
+    #[test]
+    fn test_run_forest() {
+        let mut algorithm = tree();
+        algorithm.set_edge(8, 7, 2);
+
+        let index = algorithm.run(None).unwrap();
+
+        assert_eq!(index.roots[8], 7);
+        assert_eq!(index.lca(7, 8), Some(7));
+        assert_eq!(index.distance(3, 8), None);
+
+        algorithm.set_edge(3, 6, 1);
+
+        assert_eq!(algorithm.run(None), Err(GraphError::CycleDetected));
+    }
+}
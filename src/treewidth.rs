@@ -0,0 +1,422 @@
+use std::collections::BTreeSet;
+
+use crate::{ConfiguredAlgorithm, GraphAlgorithm, GraphError, Provenance};
+
+/// Heuristic choosing the next node to eliminate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EliminationHeuristic {
+    /// Eliminate the node with the fewest neighbors.
+    #[default]
+    MinDegree,
+
+    /// Eliminate the node whose elimination adds the fewest fill-in edges.
+    MinFill,
+}
+
+/// Treewidth Heuristic.
+/// Build a tree decomposition of an undirected graph from a greedy elimination ordering and report its width.
+#[derive(Debug, Clone)]
+pub struct TreewidthAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Neighbors of each node.
+    pub adjacency: Vec<BTreeSet<usize>>,
+
+    /// Heuristic choosing the elimination ordering.
+    pub heuristic: EliminationHeuristic,
+}
+
+/// Tree decomposition of a graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeDecomposition {
+    /// Nodes of the graph contained in each bag, sorted.
+    pub bags: Vec<Vec<usize>>,
+
+    /// Edges of the tree between bags.
+    pub edges: Vec<(usize, usize)>,
+
+    /// Width of the decomposition, i.e. the size of the largest bag minus one.
+    pub width: usize,
+}
+
+impl Default for TreewidthAlgorithm {
+    /// Create a new default instance of the treewidth heuristic.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the treewidth heuristic.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TreewidthAlgorithm {
+    /// Create a new instance of the treewidth heuristic.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the treewidth heuristic.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+            heuristic: EliminationHeuristic::default(),
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+
+        if source != target {
+            self.adjacency[source].insert(target);
+            self.adjacency[target].insert(source);
+        }
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its neighbors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, BTreeSet::new());
+    }
+
+    /// Set the heuristic choosing the elimination ordering.
+    ///
+    /// # Arguments
+    ///
+    /// - `heuristic`: Elimination heuristic.
+    pub fn set_heuristic(&mut self, heuristic: EliminationHeuristic) {
+        self.heuristic = heuristic;
+    }
+
+    /// Count the edges missing between the neighbors of a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `graph`: Current graph.
+    /// - `node`: Node to check.
+    ///
+    /// # Returns
+    ///
+    /// Number of fill-in edges its elimination would add.
+    fn fill_in(graph: &[BTreeSet<usize>], node: usize) -> usize {
+        let neighbors: Vec<usize> = graph[node].iter().copied().collect();
+
+        neighbors
+            .iter()
+            .enumerate()
+            .map(|(index, &a)| {
+                neighbors[index + 1..]
+                    .iter()
+                    .filter(|&b| !graph[a].contains(b))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Compute the elimination ordering chosen by the heuristic.
+    ///
+    /// # Returns
+    ///
+    /// Nodes in elimination order.
+    pub fn elimination_ordering(&self) -> Vec<usize> {
+        let mut graph = self.adjacency.clone();
+        let mut eliminated = vec![false; self.total_nodes];
+        let mut ordering = Vec::with_capacity(self.total_nodes);
+
+        for _ in 0..self.total_nodes {
+            let remaining = (0..self.total_nodes).filter(|&node| !eliminated[node]);
+            let node = match self.heuristic {
+                EliminationHeuristic::MinDegree => {
+                    remaining.min_by_key(|&node| (graph[node].len(), node))
+                }
+                EliminationHeuristic::MinFill => remaining
+                    .min_by_key(|&node| (Self::fill_in(&graph, node), graph[node].len(), node)),
+            }
+            .unwrap_or_default();
+
+            Self::eliminate(&mut graph, node);
+            eliminated[node] = true;
+            ordering.push(node);
+        }
+
+        ordering
+    }
+
+    /// Remove a node from the graph after turning its neighborhood into a clique.
+    ///
+    /// # Arguments
+    ///
+    /// - `graph`: Current graph.
+    /// - `node`: Node to eliminate.
+    fn eliminate(graph: &mut [BTreeSet<usize>], node: usize) {
+        let neighbors: Vec<usize> = std::mem::take(&mut graph[node]).into_iter().collect();
+
+        for &a in &neighbors {
+            graph[a].remove(&node);
+
+            for &b in &neighbors {
+                if a != b {
+                    graph[a].insert(b);
+                }
+            }
+        }
+    }
+
+    /// Build a tree decomposition from an elimination ordering.
+    ///
+    /// # Arguments
+    ///
+    /// - `ordering`: Elimination ordering containing every node once.
+    ///
+    /// # Returns
+    ///
+    /// Tree decomposition with one bag per node.
+    pub fn decompose(&self, ordering: &[usize]) -> TreeDecomposition {
+        let mut graph = self.adjacency.clone();
+        let mut position = vec![0; self.total_nodes];
+
+        for (index, &node) in ordering.iter().enumerate() {
+            position[node] = index;
+        }
+
+        let mut bags = Vec::with_capacity(ordering.len());
+        let mut edges = Vec::new();
+        let mut roots = Vec::new();
+
+        for &node in ordering {
+            let bag: Vec<usize> = graph[node].iter().copied().chain([node]).collect();
+
+            // Attach the bag to the bag of the next eliminated neighbor.
+            match graph[node]
+                .iter()
+                .min_by_key(|&&neighbor| position[neighbor])
+            {
+                Some(&next) => edges.push((position[node], position[next])),
+                None => roots.push(position[node]),
+            }
+
+            let mut bag = bag;
+            bag.sort_unstable();
+            bags.push(bag);
+
+            Self::eliminate(&mut graph, node);
+        }
+
+        // Join the trees of the connected components.
+        for pair in roots.windows(2) {
+            edges.push((pair[0], pair[1]));
+        }
+
+        let width = bags
+            .iter()
+            .map(Vec::len)
+            .max()
+            .unwrap_or(0)
+            .saturating_sub(1);
+
+        TreeDecomposition { bags, edges, width }
+    }
+}
+
+impl GraphAlgorithm for TreewidthAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = TreeDecomposition;
+
+    /// Run the treewidth heuristic.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the treewidth heuristic.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the tree decomposition.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.decompose(&self.elimination_ordering()))
+    }
+}
+
+impl ConfiguredAlgorithm for TreewidthAlgorithm {
+    /// Describe the configuration of the treewidth algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("treewidth").with("heuristic", self.heuristic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_valid(algorithm: &TreewidthAlgorithm, decomposition: &TreeDecomposition) {
+        // Every edge is covered by a bag.
+        for (node, neighbors) in algorithm.adjacency.iter().enumerate() {
+            for &neighbor in neighbors {
+                assert!(decomposition
+                    .bags
+                    .iter()
+                    .any(|bag| bag.contains(&node) && bag.contains(&neighbor)));
+            }
+        }
+
+        // The bags form a tree.
+        assert_eq!(
+            decomposition.edges.len(),
+            decomposition.bags.len().saturating_sub(1)
+        );
+
+        // The bags containing a node are connected.
+        for node in 0..algorithm.total_nodes {
+            let bags: Vec<usize> = (0..decomposition.bags.len())
+                .filter(|&bag| decomposition.bags[bag].contains(&node))
+                .collect();
+            let links = decomposition
+                .edges
+                .iter()
+                .filter(|(a, b)| bags.contains(a) && bags.contains(b))
+                .count();
+
+            assert_eq!(links, bags.len() - 1);
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = TreewidthAlgorithm::new();
+        let algorithm_default = TreewidthAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.heuristic, EliminationHeuristic::MinDegree);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = TreewidthAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 3]),
+            (1, vec![2, 4]),
+            (2, vec![5]),
+            (3, vec![4, 6]),
+            (4, vec![5, 7]),
+            (5, vec![8]),
+            (6, vec![7]),
+            (7, vec![8]),
+        ]);
+
+        for heuristic in [
+            EliminationHeuristic::MinDegree,
+            EliminationHeuristic::MinFill,
+        ] {
+            algorithm.set_heuristic(heuristic);
+
+            let result = algorithm.run(None).unwrap();
+
+            assert_eq!(result.width, 3);
+            assert_valid(&algorithm, &result);
+        }
+    }
+
+    #[test]
+    fn test_run_tree() {
+        let mut algorithm = TreewidthAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1, 2]), (1, vec![3, 4]), (2, vec![5])]);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.width, 1);
+        assert_valid(&algorithm, &result);
+    }
+
+    #[test]
+    fn test_run_cycle() {
+        let mut algorithm = TreewidthAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1]),
+            (1, vec![2]),
+            (2, vec![3]),
+            (3, vec![4]),
+            (4, vec![0]),
+        ]);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.width, 2);
+        assert_valid(&algorithm, &result);
+    }
+
+    #[test]
+    fn test_run_clique() {
+        let mut algorithm = TreewidthAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1, 2, 3]), (1, vec![2, 3]), (2, vec![3])]);
+        algorithm.set_heuristic(EliminationHeuristic::MinFill);
+
+        assert_eq!(algorithm.run(None).unwrap().width, 3);
+    }
+
+    #[test]
+    fn test_run_disconnected_graph() {
+        let mut algorithm = TreewidthAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![1]), (2, vec![3])]);
+        algorithm.set_total_nodes(5);
+
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.width, 1);
+        assert_valid(&algorithm, &result);
+    }
+
+    #[test]
+    fn test_run_empty_graph() {
+        let algorithm = TreewidthAlgorithm::new();
+        let result = algorithm.run(None).unwrap();
+
+        assert_eq!(result.width, 0);
+        assert!(result.bags.is_empty());
+    }
+
+    #[test]
+    fn test_elimination_ordering_min_fill() {
+        let mut algorithm = TreewidthAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 2]),
+            (1, vec![2]),
+            (2, vec![3]),
+            (3, vec![4]),
+        ]);
+        algorithm.set_heuristic(EliminationHeuristic::MinFill);
+
+        assert_eq!(algorithm.elimination_ordering(), vec![4, 3, 0, 1, 2]);
+    }
+}
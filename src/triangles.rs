@@ -0,0 +1,267 @@
+use std::collections::BTreeSet;
+
+use crate::{GraphAlgorithm, GraphError};
+
+/// Triangle Counting.
+/// Count the triangles of an undirected graph exactly by orienting every edge toward the node of
+/// higher degree, so that each triangle is found once from its lowest node and no node has more than
+/// `O(sqrt(m))` higher neighbors. The counts give the local and global clustering coefficients.
+#[derive(Debug, Clone)]
+pub struct TriangleAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Neighbors of each node.
+    pub adjacency: Vec<BTreeSet<usize>>,
+}
+
+impl Default for TriangleAlgorithm {
+    /// Create a new default instance of the triangle counting.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the triangle counting.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TriangleAlgorithm {
+    /// Create a new instance of the triangle counting.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the triangle counting.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            adjacency: Vec::new(),
+        }
+    }
+
+    /// Set a single undirected edge to the graph. Self-loops are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    pub fn set_edge(&mut self, source: usize, target: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+
+        if source != target {
+            self.adjacency[source].insert(target);
+            self.adjacency[target].insert(source);
+        }
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its neighbors.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<usize>)>) {
+        for (source, targets) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for target in targets {
+                self.set_edge(source, target);
+            }
+        }
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.adjacency.resize(self.total_nodes, BTreeSet::new());
+    }
+
+    /// Count the triangles through every node.
+    ///
+    /// # Returns
+    ///
+    /// Number of triangles containing each node.
+    pub fn triangles(&self) -> Vec<usize> {
+        let n = self.total_nodes;
+        let rank = |node: usize| (self.adjacency[node].len(), node);
+        let higher: Vec<Vec<usize>> = (0..n)
+            .map(|node| {
+                self.adjacency[node]
+                    .iter()
+                    .copied()
+                    .filter(|&neighbor| rank(neighbor) > rank(node))
+                    .collect()
+            })
+            .collect();
+        let mut counts = vec![0; n];
+        let mut marked = vec![usize::MAX; n];
+
+        for (node, neighbors) in higher.iter().enumerate() {
+            neighbors
+                .iter()
+                .for_each(|&neighbor| marked[neighbor] = node);
+
+            for &middle in neighbors {
+                for &last in &higher[middle] {
+                    if marked[last] == node {
+                        counts[node] += 1;
+                        counts[middle] += 1;
+                        counts[last] += 1;
+                    }
+                }
+            }
+        }
+
+        counts
+    }
+
+    /// Count the triangles of the graph.
+    ///
+    /// # Returns
+    ///
+    /// Total number of triangles.
+    pub fn total_triangles(&self) -> usize {
+        self.triangles().iter().sum::<usize>() / 3
+    }
+
+    /// Compute the local clustering coefficient of every node.
+    ///
+    /// # Returns
+    ///
+    /// Fraction of pairs of neighbors of each node that are adjacent, zero below two neighbors.
+    pub fn local_clustering(&self) -> Vec<f64> {
+        self.triangles()
+            .iter()
+            .zip(&self.adjacency)
+            .map(|(&triangles, neighbors)| match neighbors.len() {
+                0 | 1 => 0.0,
+                degree => 2.0 * triangles as f64 / (degree * (degree - 1)) as f64,
+            })
+            .collect()
+    }
+
+    /// Compute the average of the local clustering coefficients.
+    ///
+    /// # Returns
+    ///
+    /// Average local clustering coefficient over all nodes, zero for an empty graph.
+    pub fn average_clustering(&self) -> f64 {
+        match self.total_nodes {
+            0 => 0.0,
+            n => self.local_clustering().iter().sum::<f64>() / n as f64,
+        }
+    }
+
+    /// Compute the global clustering coefficient, also known as transitivity.
+    ///
+    /// # Returns
+    ///
+    /// Fraction of connected triples of nodes that are closed into triangles, zero without triples.
+    pub fn global_clustering(&self) -> f64 {
+        let triples: usize = self
+            .adjacency
+            .iter()
+            .map(|neighbors| neighbors.len() * neighbors.len().saturating_sub(1) / 2)
+            .sum();
+
+        match triples {
+            0 => 0.0,
+            triples => 3.0 * self.total_triangles() as f64 / triples as f64,
+        }
+    }
+}
+
+impl GraphAlgorithm for TriangleAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = Vec<f64>;
+
+    /// Run the triangle counting.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node. This is not used in the triangle counting.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the local clustering coefficient of each node.
+    fn run(&self, _start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        Ok(self.local_clustering())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Random;
+
+    #[test]
+    fn test_new() {
+        let algorithm = TriangleAlgorithm::new();
+        let algorithm_default = TriangleAlgorithm::default();
+
+        assert_eq!(algorithm.total_nodes, 0);
+        assert_eq!(algorithm_default.global_clustering(), 0.0);
+        assert_eq!(algorithm.average_clustering(), 0.0);
+    }
+
+    #[test]
+    fn test_run() {
+        // Two triangles sharing the edge 1 - 2, and a pendant node 4.
+        let mut algorithm = TriangleAlgorithm::new();
+        algorithm.set_edges(vec![
+            (0, vec![1, 2]),
+            (1, vec![2, 3]),
+            (2, vec![3, 4]),
+            (3, vec![3]),
+        ]);
+
+        assert_eq!(algorithm.triangles(), vec![1, 2, 2, 1, 0]);
+        assert_eq!(algorithm.total_triangles(), 2);
+        assert_eq!(
+            algorithm.run(None).unwrap(),
+            vec![1.0, 2.0 / 3.0, 1.0 / 3.0, 1.0, 0.0]
+        );
+
+        // Triples: 1 + 3 + 6 + 1 + 0 = 11.
+        assert!((algorithm.global_clustering() - 6.0 / 11.0).abs() < 1e-12);
+        assert!((algorithm.average_clustering() - 3.0 / 5.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_triangles_random() {
+        let mut random = Random::new(7);
+        let mut algorithm = TriangleAlgorithm::new();
+        algorithm.set_total_nodes(30);
+
+        for _ in 0..150 {
+            algorithm.set_edge(random.below(30), random.below(30));
+        }
+
+        let mut expected = vec![0; 30];
+
+        for a in 0..30 {
+            for b in a + 1..30 {
+                for c in b + 1..30 {
+                    let adjacency = &algorithm.adjacency;
+
+                    if adjacency[a].contains(&b)
+                        && adjacency[b].contains(&c)
+                        && adjacency[a].contains(&c)
+                    {
+                        expected[a] += 1;
+                        expected[b] += 1;
+                        expected[c] += 1;
+                    }
+                }
+            }
+        }
+
+        assert_eq!(algorithm.triangles(), expected);
+    }
+}
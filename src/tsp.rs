@@ -0,0 +1,1001 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    disjoint_set::DisjointSet, ConfiguredAlgorithm, GraphAlgorithm, GraphError, Provenance,
+};
+
+/// Heuristic building the initial tour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TourConstruction {
+    /// Repeatedly visit the nearest unvisited node.
+    #[default]
+    NearestNeighbor,
+
+    /// Repeatedly add the cheapest edge that keeps the edges a set of paths.
+    GreedyEdge,
+}
+
+/// Stop served outside its time window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeWindowViolation {
+    /// Node served late.
+    pub node: usize,
+
+    /// Time at which the service starts.
+    pub arrival: usize,
+
+    /// Latest allowed start of the service.
+    pub latest: usize,
+}
+
+/// Tour scheduled against time windows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeWindowTour {
+    /// Nodes of the tour, starting and ending at the start node.
+    pub tour: Vec<usize>,
+
+    /// Start of the service at each position of the tour, after waiting for its window to open.
+    pub arrivals: Vec<usize>,
+
+    /// Length of the tour.
+    pub length: usize,
+
+    /// Stops served after their window closed, empty if the tour is feasible.
+    pub violations: Vec<TimeWindowViolation>,
+}
+
+/// Schedule of a closed tour.
+struct Schedule {
+    /// Total lateness over the windows.
+    lateness: usize,
+
+    /// Length of the tour.
+    length: usize,
+
+    /// Start of the service at each position of the tour.
+    arrivals: Vec<usize>,
+}
+
+/// TSP Heuristics.
+/// Build a travelling salesman tour of a symmetric graph with a construction heuristic,
+/// then improve it with 2-opt and Or-opt local search until no move shortens it.
+/// Unlike the Held-Karp Algorithm the tour is not guaranteed optimal, but it scales to hundreds of nodes.
+#[derive(Debug, Clone)]
+pub struct TspHeuristicAlgorithm {
+    /// Total number of nodes in the graph.
+    pub total_nodes: usize,
+
+    /// Cost between each pair of nodes, `usize::MAX` if there is no edge.
+    pub costs: Vec<Vec<usize>>,
+
+    /// Heuristic building the initial tour.
+    pub construction: TourConstruction,
+
+    /// Whether to improve the initial tour with 2-opt and Or-opt moves.
+    pub local_search: bool,
+
+    /// Earliest and latest start of the service at each node, unconstrained if missing.
+    pub time_windows: Vec<(usize, usize)>,
+
+    /// Duration of the service at each node, zero if missing.
+    pub service_times: Vec<usize>,
+
+    /// Pickup and delivery pairs, the pickup to be visited before its delivery.
+    pub pickup_deliveries: Vec<(usize, usize)>,
+}
+
+impl Default for TspHeuristicAlgorithm {
+    /// Create a new default instance of the TSP heuristics.
+    ///
+    /// # Returns
+    ///
+    /// New default instance of the TSP heuristics.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TspHeuristicAlgorithm {
+    /// Create a new instance of the TSP heuristics.
+    ///
+    /// # Returns
+    ///
+    /// New instance of the TSP heuristics.
+    pub fn new() -> Self {
+        Self {
+            total_nodes: 0,
+            costs: Vec::new(),
+            construction: TourConstruction::default(),
+            local_search: true,
+            time_windows: Vec::new(),
+            service_times: Vec::new(),
+            pickup_deliveries: Vec::new(),
+        }
+    }
+
+    /// Set a single undirected edge to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    /// - `weight`: Weight of the edge.
+    pub fn set_edge(&mut self, source: usize, target: usize, weight: usize) {
+        self.set_total_nodes(source.max(target) + 1);
+
+        let weight = self.costs[source][target].min(weight);
+        self.costs[source][target] = weight;
+        self.costs[target][source] = weight;
+    }
+
+    /// Set multiple nodes' edges to the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes`: Vector of tuples where each tuple contains a node and its associated edges.
+    pub fn set_edges(&mut self, nodes: Vec<(usize, Vec<(usize, usize)>)>) {
+        for (source, edges) in nodes {
+            self.set_total_nodes(source + 1);
+
+            for (target, weight) in edges {
+                self.set_edge(source, target, weight);
+            }
+        }
+    }
+
+    /// Set the full symmetric cost matrix, e.g. pairwise distances or the result of Floyd-Warshall Algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// - `costs`: Cost between each pair of nodes, `usize::MAX` if there is no edge.
+    pub fn set_costs(&mut self, costs: Vec<Vec<usize>>) {
+        self.total_nodes = costs.len();
+        self.costs = costs;
+    }
+
+    /// Set the total number of nodes in the graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `total`: Total number of nodes in the graph.
+    pub fn set_total_nodes(&mut self, total: usize) {
+        self.total_nodes = self.total_nodes.max(total);
+        self.costs.resize(self.total_nodes, Vec::new());
+
+        for row in &mut self.costs {
+            row.resize(self.total_nodes, usize::MAX);
+        }
+    }
+
+    /// Set the heuristic building the initial tour.
+    ///
+    /// # Arguments
+    ///
+    /// - `construction`: Construction heuristic.
+    pub fn set_construction(&mut self, construction: TourConstruction) {
+        self.construction = construction;
+    }
+
+    /// Set whether to improve the initial tour with local search.
+    ///
+    /// # Arguments
+    ///
+    /// - `local_search`: Whether to run 2-opt and Or-opt.
+    pub fn set_local_search(&mut self, local_search: bool) {
+        self.local_search = local_search;
+    }
+
+    /// Set the time window of a node, in the same unit as the costs.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node.
+    /// - `earliest`: Earliest start of the service, the vehicle waiting if it arrives before.
+    /// - `latest`: Latest start of the service.
+    pub fn set_time_window(&mut self, node: usize, earliest: usize, latest: usize) {
+        if node >= self.time_windows.len() {
+            self.time_windows.resize(node + 1, (0, usize::MAX));
+        }
+
+        self.time_windows[node] = (earliest, latest);
+    }
+
+    /// Set the duration of the service at a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node.
+    /// - `duration`: Duration of the service.
+    pub fn set_service_time(&mut self, node: usize, duration: usize) {
+        if node >= self.service_times.len() {
+            self.service_times.resize(node + 1, 0);
+        }
+
+        self.service_times[node] = duration;
+    }
+
+    /// Set a pickup and delivery pair, such as a parcel collected at one stop and dropped at another.
+    ///
+    /// # Arguments
+    ///
+    /// - `pickup`: Node where the load is picked up.
+    /// - `delivery`: Node where the load is delivered, after the pickup on the same tour.
+    ///   Each node belongs to at most one pair, checked when the tour is built.
+    pub fn set_pickup_delivery(&mut self, pickup: usize, delivery: usize) {
+        self.pickup_deliveries.push((pickup, delivery));
+    }
+
+    /// Get the cost between two nodes, wide enough to add and subtract missing edges.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: Source node.
+    /// - `target`: Target node.
+    ///
+    /// # Returns
+    ///
+    /// Cost of the edge.
+    fn cost(&self, source: usize, target: usize) -> i128 {
+        self.costs[source][target] as i128
+    }
+
+    /// Compute the length of a closed tour.
+    ///
+    /// # Arguments
+    ///
+    /// - `tour`: Nodes of the tour, ending at its first node.
+    ///
+    /// # Returns
+    ///
+    /// Length of the tour, or `None` if it uses a missing edge.
+    pub fn tour_length(&self, tour: &[usize]) -> Option<usize> {
+        tour.windows(2)
+            .try_fold(0usize, |length, pair| match self.costs[pair[0]][pair[1]] {
+                usize::MAX => None,
+                cost => length.checked_add(cost),
+            })
+    }
+
+    /// Build a tour by repeatedly visiting the nearest unvisited node, breaking ties by node ID.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: First node of the tour.
+    ///
+    /// # Returns
+    ///
+    /// Nodes in visiting order, without returning to the start.
+    pub fn nearest_neighbor(&self, start: usize) -> Vec<usize> {
+        let mut visited = vec![false; self.total_nodes];
+        let mut order = vec![start];
+        visited[start] = true;
+
+        while order.len() < self.total_nodes {
+            let current = order[order.len() - 1];
+            let next = (0..self.total_nodes)
+                .filter(|&node| !visited[node])
+                .min_by_key(|&node| (self.costs[current][node], node))
+                .unwrap_or(current);
+
+            visited[next] = true;
+            order.push(next);
+        }
+
+        order
+    }
+
+    /// Build a tour from the cheapest edges that keep every degree at most two and close no cycle,
+    /// then join the ends of the resulting paths.
+    ///
+    /// # Returns
+    ///
+    /// Nodes in visiting order starting from node 0, without returning to the start.
+    pub fn greedy_edge(&self) -> Vec<usize> {
+        let n = self.total_nodes;
+        let mut edges: Vec<(usize, usize, usize)> = (0..n)
+            .flat_map(|source| (source + 1..n).map(move |target| (source, target)))
+            .map(|(source, target)| (self.costs[source][target], source, target))
+            .collect();
+        edges.sort_unstable();
+
+        let mut neighbors = vec![Vec::with_capacity(2); n];
+        let mut components = DisjointSet::new(n);
+        let mut added = 0;
+
+        for (_, source, target) in edges {
+            if added + 1 >= n {
+                break;
+            }
+
+            if neighbors[source].len() < 2
+                && neighbors[target].len() < 2
+                && components.union(source, target)
+            {
+                neighbors[source].push(target);
+                neighbors[target].push(source);
+                added += 1;
+            }
+        }
+
+        // Chain the paths, each walked from its lowest free end.
+        let mut visited = vec![false; n];
+        let mut order = Vec::with_capacity(n);
+
+        while let Some(mut current) =
+            (0..n).find(|&node| !visited[node] && neighbors[node].len() < 2)
+        {
+            loop {
+                visited[current] = true;
+                order.push(current);
+
+                match neighbors[current].iter().find(|&&next| !visited[next]) {
+                    Some(&next) => current = next,
+                    None => break,
+                }
+            }
+        }
+
+        if let Some(position) = order.iter().position(|&node| node == 0) {
+            order.rotate_left(position);
+        }
+
+        order
+    }
+
+    /// Improve a tour with 2-opt moves: reverse a section whenever that shortens the tour.
+    ///
+    /// # Arguments
+    ///
+    /// - `order`: Nodes in visiting order, improved in place.
+    pub fn two_opt(&self, order: &mut [usize]) {
+        let n = order.len();
+        let mut improved = n >= 4;
+
+        while improved {
+            improved = false;
+
+            for i in 0..n - 1 {
+                for j in i + 2..n {
+                    if i == 0 && j == n - 1 {
+                        continue;
+                    }
+
+                    let (a, b) = (order[i], order[i + 1]);
+                    let (c, d) = (order[j], order[(j + 1) % n]);
+                    let delta =
+                        self.cost(a, c) + self.cost(b, d) - self.cost(a, b) - self.cost(c, d);
+
+                    if delta < 0 {
+                        order[i + 1..=j].reverse();
+                        improved = true;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Improve a tour with Or-opt moves: move a section of up to three nodes, possibly reversed,
+    /// to another position whenever that shortens the tour.
+    ///
+    /// # Arguments
+    ///
+    /// - `order`: Nodes in visiting order, improved in place.
+    pub fn or_opt(&self, order: &mut Vec<usize>) {
+        let n = order.len();
+
+        'search: loop {
+            for length in 1..=3 {
+                if n < length + 3 {
+                    break;
+                }
+
+                for i in 0..=n - length {
+                    let (first, last) = (order[i], order[i + length - 1]);
+                    let previous = order[(i + n - 1) % n];
+                    let next = order[(i + length) % n];
+                    let gain = self.cost(previous, first) + self.cost(last, next)
+                        - self.cost(previous, next);
+
+                    let mut rest: Vec<usize> = order[..i]
+                        .iter()
+                        .chain(&order[i + length..])
+                        .copied()
+                        .collect();
+                    let m = rest.len();
+
+                    for j in 0..m {
+                        let (p, q) = (rest[j], rest[(j + 1) % m]);
+
+                        if p == previous {
+                            continue;
+                        }
+
+                        let forward = self.cost(p, first) + self.cost(last, q) - self.cost(p, q);
+                        let backward = self.cost(p, last) + self.cost(first, q) - self.cost(p, q);
+
+                        if forward.min(backward) < gain {
+                            let mut section = order[i..i + length].to_vec();
+
+                            if backward < forward {
+                                section.reverse();
+                            }
+
+                            rest.splice(j + 1..j + 1, section);
+                            *order = rest;
+                            continue 'search;
+                        }
+                    }
+                }
+            }
+
+            break;
+        }
+    }
+
+    /// Build a tour respecting the time windows: insert the stops by increasing deadline at the
+    /// position adding the least lateness, then length, and improve the tour by relocating and
+    /// swapping stops. The vehicle leaves the start node when its window opens and must return
+    /// before it closes. If no tour meets every window, the one with the least total lateness is kept.
+    /// A pickup is inserted together with its delivery after it, and no move breaks that precedence;
+    /// a pair involving the start node always holds, the tour leaving from and returning to it.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Start and end node of the tour.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the scheduled tour with its violations, or an error if the start node is
+    /// unknown, a pickup and delivery pair is invalid or no stop can be inserted without a missing
+    /// edge.
+    pub fn time_window_tour(&self, start: usize) -> Result<TimeWindowTour, GraphError> {
+        if start >= self.total_nodes {
+            return Err(GraphError::MissingStartNode);
+        }
+
+        self.validate_pickup_deliveries()?;
+
+        let deliveries: HashMap<usize, usize> = self
+            .pickup_deliveries
+            .iter()
+            .copied()
+            .filter(|&(pickup, delivery)| pickup != start && delivery != start)
+            .collect();
+        let mut stops: Vec<usize> = (0..self.total_nodes)
+            .filter(|&node| node != start && !deliveries.values().any(|&delivery| delivery == node))
+            .collect();
+        stops.sort_by_key(|&node| {
+            let (earliest, latest) = self.window(node);
+            (latest, earliest, node)
+        });
+
+        let mut order = vec![start];
+
+        for stop in stops {
+            let mut candidates = Vec::new();
+
+            for position in 1..=order.len() {
+                let mut candidate = order.clone();
+                candidate.insert(position, stop);
+
+                match deliveries.get(&stop) {
+                    Some(&delivery) => {
+                        for after in position + 1..=candidate.len() {
+                            let mut paired = candidate.clone();
+                            paired.insert(after, delivery);
+                            candidates.push(paired);
+                        }
+                    }
+                    None => candidates.push(candidate),
+                }
+            }
+
+            order = candidates
+                .into_iter()
+                .filter_map(|candidate| {
+                    let schedule = self.schedule(&candidate)?;
+                    Some(((schedule.lateness, schedule.length), candidate))
+                })
+                .min_by_key(|&(value, _)| value)
+                .ok_or(GraphError::Infeasible)?
+                .1;
+        }
+
+        if self.local_search {
+            self.improve_schedule(&mut order);
+        }
+
+        let schedule = self.schedule(&order).ok_or(GraphError::Infeasible)?;
+        order.push(start);
+
+        let violations = order
+            .iter()
+            .zip(&schedule.arrivals)
+            .filter_map(|(&node, &arrival)| {
+                let (_, latest) = self.window(node);
+
+                (arrival > latest).then_some(TimeWindowViolation {
+                    node,
+                    arrival,
+                    latest,
+                })
+            })
+            .collect();
+
+        Ok(TimeWindowTour {
+            tour: order,
+            arrivals: schedule.arrivals,
+            length: schedule.length,
+            violations,
+        })
+    }
+
+    /// Check that the pickup and delivery pairs refer to nodes of the graph, each node in at most
+    /// one pair and never as both the pickup and the delivery.
+    ///
+    /// # Returns
+    ///
+    /// Result with an error naming the first node out of range or the first invalid pair.
+    fn validate_pickup_deliveries(&self) -> Result<(), GraphError> {
+        let mut paired = HashSet::new();
+
+        for &(pickup, delivery) in &self.pickup_deliveries {
+            if let Some(node) = [pickup, delivery]
+                .into_iter()
+                .find(|&node| node >= self.total_nodes)
+            {
+                return Err(GraphError::NodeOutOfRange(node));
+            }
+
+            if pickup == delivery || !paired.insert(pickup) || !paired.insert(delivery) {
+                return Err(GraphError::InvalidPair(pickup, delivery));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the time window of a node.
+    ///
+    /// # Arguments
+    ///
+    /// - `node`: Node.
+    ///
+    /// # Returns
+    ///
+    /// Earliest and latest start of the service.
+    fn window(&self, node: usize) -> (usize, usize) {
+        self.time_windows
+            .get(node)
+            .copied()
+            .unwrap_or((0, usize::MAX))
+    }
+
+    /// Schedule a tour, waiting at each stop until its window opens.
+    ///
+    /// # Arguments
+    ///
+    /// - `order`: Nodes in visiting order, without returning to the start.
+    ///
+    /// # Returns
+    ///
+    /// Schedule of the closed tour, or `None` if it uses a missing edge.
+    fn schedule(&self, order: &[usize]) -> Option<Schedule> {
+        let start = order[0];
+        let mut time = self.window(start).0;
+        let mut schedule = Schedule {
+            lateness: 0,
+            length: 0,
+            arrivals: vec![time],
+        };
+
+        for (position, &node) in order.iter().enumerate().skip(1).chain([(0, &start)]) {
+            let previous = order[(position + order.len() - 1) % order.len()];
+            let cost = match self.costs[previous][node] {
+                usize::MAX => return None,
+                cost => cost,
+            };
+            let service = self.service_times.get(previous).copied().unwrap_or(0);
+            let (earliest, latest) = self.window(node);
+
+            // The return to the start only has to meet its window, without waiting for it.
+            time = time.saturating_add(service).saturating_add(cost);
+
+            if position > 0 {
+                time = time.max(earliest);
+            }
+
+            schedule.lateness = schedule
+                .lateness
+                .saturating_add(time.saturating_sub(latest));
+            schedule.length += cost;
+            schedule.arrivals.push(time);
+        }
+
+        Some(schedule)
+    }
+
+    /// Check that every pickup comes before its delivery.
+    ///
+    /// # Arguments
+    ///
+    /// - `order`: Nodes in visiting order starting at the start node.
+    ///
+    /// # Returns
+    ///
+    /// Whether the precedence of every pair not involving the start node holds.
+    fn precedence_holds(&self, order: &[usize]) -> bool {
+        let mut positions = vec![usize::MAX; self.total_nodes];
+
+        for (position, &node) in order.iter().enumerate() {
+            positions[node] = position;
+        }
+
+        self.pickup_deliveries.iter().all(|&(pickup, delivery)| {
+            pickup == order[0] || delivery == order[0] || positions[pickup] < positions[delivery]
+        })
+    }
+
+    /// Improve a tour by relocating single stops and swapping pairs of stops while that reduces
+    /// the total lateness, or the length at equal lateness.
+    ///
+    /// # Arguments
+    ///
+    /// - `order`: Nodes in visiting order starting at the start node, improved in place.
+    fn improve_schedule(&self, order: &mut Vec<usize>) {
+        let objective = |order: &[usize]| match self.precedence_holds(order) {
+            true => self
+                .schedule(order)
+                .map_or((usize::MAX, usize::MAX), |schedule| {
+                    (schedule.lateness, schedule.length)
+                }),
+            false => (usize::MAX, usize::MAX),
+        };
+        let mut best = objective(order);
+        let n = order.len();
+
+        'search: loop {
+            for i in 1..n {
+                for j in 1..n {
+                    if i == j {
+                        continue;
+                    }
+
+                    let mut relocated = order.clone();
+                    let stop = relocated.remove(i);
+                    relocated.insert(j, stop);
+
+                    let mut swapped = order.clone();
+                    swapped.swap(i, j);
+
+                    for candidate in [relocated, swapped] {
+                        let value = objective(&candidate);
+
+                        if value < best {
+                            best = value;
+                            *order = candidate;
+                            continue 'search;
+                        }
+                    }
+                }
+            }
+
+            break;
+        }
+    }
+}
+
+impl GraphAlgorithm for TspHeuristicAlgorithm {
+    /// Type of node.
+    type Node = usize;
+
+    /// Type of weight.
+    type Weight = (usize, Vec<usize>);
+
+    /// Run the TSP heuristics.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: Starting node of the tour, node 0 if omitted.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the tour length and its nodes, starting and ending at the start node,
+    /// or an error if the tour found uses a missing edge.
+    fn run(&self, start: Option<Self::Node>) -> Result<Self::Weight, GraphError> {
+        let start = start.unwrap_or(0);
+
+        if start >= self.total_nodes {
+            return Err(GraphError::MissingStartNode);
+        }
+
+        let mut order = match self.construction {
+            TourConstruction::NearestNeighbor => self.nearest_neighbor(start),
+            TourConstruction::GreedyEdge => self.greedy_edge(),
+        };
+
+        if self.local_search {
+            loop {
+                let before = order.clone();
+                self.two_opt(&mut order);
+                self.or_opt(&mut order);
+
+                if order == before {
+                    break;
+                }
+            }
+        }
+
+        if let Some(position) = order.iter().position(|&node| node == start) {
+            order.rotate_left(position);
+        }
+
+        order.push(start);
+
+        let length = self.tour_length(&order).ok_or(GraphError::Infeasible)?;
+
+        Ok((length, order))
+    }
+}
+
+impl ConfiguredAlgorithm for TspHeuristicAlgorithm {
+    /// Describe the configuration of the TSP heuristics.
+    ///
+    /// # Returns
+    ///
+    /// Provenance of the results.
+    fn provenance(&self) -> Provenance {
+        Provenance::new("tsp")
+            .with("construction", self.construction)
+            .with("local_search", self.local_search)
+            .with("time_windows", &self.time_windows)
+            .with("service_times", &self.service_times)
+            .with("pickup_deliveries", &self.pickup_deliveries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Points on a circle, shuffled, with rounded Euclidean distances.
+    fn circle(n: usize) -> TspHeuristicAlgorithm {
+        let points: Vec<(f64, f64)> = (0..n)
+            .map(|i| (i * 7 % n) as f64 * std::f64::consts::TAU / n as f64)
+            .map(|angle| (1000.0 * angle.cos(), 1000.0 * angle.sin()))
+            .collect();
+
+        let mut algorithm = TspHeuristicAlgorithm::new();
+        algorithm.set_costs(
+            points
+                .iter()
+                .map(|a| {
+                    points
+                        .iter()
+                        .map(|b| ((a.0 - b.0).hypot(a.1 - b.1)).round() as usize)
+                        .collect()
+                })
+                .collect(),
+        );
+
+        algorithm
+    }
+
+    #[test]
+    fn test_new() {
+        let algorithm = TspHeuristicAlgorithm::new();
+        let algorithm_default = TspHeuristicAlgorithm::default();
+
+        assert_eq!(algorithm.construction, TourConstruction::NearestNeighbor);
+        assert!(algorithm_default.local_search);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut algorithm = TspHeuristicAlgorithm::new();
+        algorithm.set_costs(vec![
+            vec![0, 10, 15, 20],
+            vec![10, 0, 35, 25],
+            vec![15, 35, 0, 30],
+            vec![20, 25, 30, 0],
+        ]);
+
+        let (length, tour) = algorithm.run(Some(2)).unwrap();
+
+        assert_eq!(length, 80);
+        assert_eq!((tour.len(), tour[0], tour[4]), (5, 2, 2));
+        assert_eq!(algorithm.tour_length(&tour), Some(80));
+    }
+
+    #[test]
+    fn test_run_circle() {
+        // The optimal tour visits the points in angular order.
+        let mut algorithm = circle(40);
+        let optimal = {
+            let mut order: Vec<usize> = (0..40).map(|k| k * 23 % 40).collect();
+            order.push(order[0]);
+            algorithm.tour_length(&order).unwrap()
+        };
+
+        for construction in [
+            TourConstruction::NearestNeighbor,
+            TourConstruction::GreedyEdge,
+        ] {
+            algorithm.set_construction(construction);
+
+            let (length, mut tour) = algorithm.run(None).unwrap();
+            tour.sort_unstable();
+            tour.dedup();
+
+            assert_eq!(length, optimal);
+            assert_eq!(tour, (0..40).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_local_search() {
+        let mut algorithm = circle(30);
+        algorithm.set_local_search(false);
+
+        let mut order: Vec<usize> = (0..30).collect();
+        let closed = |order: &[usize]| [order, &order[..1]].concat();
+        let initial = algorithm.tour_length(&closed(&order)).unwrap();
+
+        algorithm.two_opt(&mut order);
+        let after_two_opt = algorithm.tour_length(&closed(&order)).unwrap();
+        algorithm.or_opt(&mut order);
+        let after_or_opt = algorithm.tour_length(&closed(&order)).unwrap();
+
+        assert!(after_two_opt < initial);
+        assert!(after_or_opt <= after_two_opt);
+        assert_eq!(order.len(), 30);
+    }
+
+    #[test]
+    fn test_greedy_edge() {
+        let mut algorithm = TspHeuristicAlgorithm::new();
+        algorithm.set_costs(vec![
+            vec![0, 1, 9, 9, 2],
+            vec![1, 0, 3, 9, 9],
+            vec![9, 3, 0, 4, 9],
+            vec![9, 9, 4, 0, 9],
+            vec![2, 9, 9, 9, 0],
+        ]);
+
+        assert_eq!(algorithm.greedy_edge(), vec![0, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_time_window_tour() {
+        let mut algorithm = TspHeuristicAlgorithm::new();
+        algorithm.set_costs(vec![
+            vec![0, 10, 15, 20],
+            vec![10, 0, 35, 25],
+            vec![15, 35, 0, 30],
+            vec![20, 25, 30, 0],
+        ]);
+
+        // Node 3 must be served first and node 1 last, unlike in the shortest tour.
+        algorithm.set_time_window(3, 0, 25);
+        algorithm.set_time_window(1, 80, 100);
+        algorithm.set_service_time(2, 5);
+
+        let tour = algorithm.time_window_tour(0).unwrap();
+
+        assert_eq!(tour.tour, vec![0, 3, 2, 1, 0]);
+        assert_eq!(tour.arrivals, vec![0, 20, 50, 90, 100]);
+        assert_eq!(tour.length, 95);
+        assert!(tour.violations.is_empty());
+
+        // Windows that cannot all be met are reported with the least total lateness.
+        algorithm.set_time_window(2, 0, 10);
+        let tour = algorithm.time_window_tour(0).unwrap();
+
+        assert_eq!(tour.tour, vec![0, 2, 3, 1, 0]);
+        assert_eq!(
+            tour.violations,
+            vec![
+                TimeWindowViolation {
+                    node: 2,
+                    arrival: 15,
+                    latest: 10,
+                },
+                TimeWindowViolation {
+                    node: 3,
+                    arrival: 50,
+                    latest: 25,
+                },
+            ]
+        );
+        assert_eq!(
+            algorithm.time_window_tour(4),
+            Err(GraphError::MissingStartNode)
+        );
+    }
+
+    #[test]
+    fn test_pickup_delivery() {
+        let mut algorithm = TspHeuristicAlgorithm::new();
+        algorithm.set_costs(vec![
+            vec![0, 10, 15, 20],
+            vec![10, 0, 35, 25],
+            vec![15, 35, 0, 30],
+            vec![20, 25, 30, 0],
+        ]);
+
+        // Both directions of the shortest tour are equally long, the pair picks one.
+        let mut forward = algorithm.clone();
+        forward.set_pickup_delivery(1, 3);
+
+        let mut backward = algorithm.clone();
+        backward.set_pickup_delivery(3, 1);
+
+        assert_eq!(
+            forward.time_window_tour(0).unwrap().tour,
+            vec![0, 1, 3, 2, 0]
+        );
+        assert_eq!(
+            backward.time_window_tour(0).unwrap().tour,
+            vec![0, 2, 3, 1, 0]
+        );
+
+        // Delivering to node 2 first would be shorter, but its load is picked up at node 3.
+        algorithm.set_costs(vec![
+            vec![0, 1, 1, 9],
+            vec![1, 0, 9, 1],
+            vec![1, 9, 0, 1],
+            vec![9, 1, 1, 0],
+        ]);
+        algorithm.set_time_window(1, 0, 2);
+        algorithm.set_pickup_delivery(3, 2);
+
+        let tour = algorithm.time_window_tour(0).unwrap();
+
+        assert_eq!(tour.tour, vec![0, 1, 3, 2, 0]);
+        assert!(tour.violations.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_pickup_delivery() {
+        let mut algorithm = TspHeuristicAlgorithm::new();
+        algorithm.set_costs(vec![
+            vec![0, 1, 2, 1],
+            vec![1, 0, 1, 2],
+            vec![2, 1, 0, 1],
+            vec![1, 2, 1, 0],
+        ]);
+
+        for (pairs, error) in [
+            (vec![(1, 2), (2, 3)], GraphError::InvalidPair(2, 3)),
+            (vec![(1, 1)], GraphError::InvalidPair(1, 1)),
+            (vec![(1, 9)], GraphError::NodeOutOfRange(9)),
+            (vec![(1, 2), (1, 3)], GraphError::InvalidPair(1, 3)),
+            (vec![(1, 2), (3, 2)], GraphError::InvalidPair(3, 2)),
+        ] {
+            let mut paired = algorithm.clone();
+
+            for (pickup, delivery) in pairs {
+                paired.set_pickup_delivery(pickup, delivery);
+            }
+
+            assert_eq!(paired.time_window_tour(0), Err(error));
+        }
+
+        algorithm.set_pickup_delivery(1, 2);
+        algorithm.set_pickup_delivery(3, 0);
+
+        let mut tour = algorithm.time_window_tour(0).unwrap().tour;
+        tour.sort_unstable();
+
+        assert_eq!(tour, vec![0, 0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_run_infeasible() {
+        let mut algorithm = TspHeuristicAlgorithm::new();
+        algorithm.set_edges(vec![(0, vec![(1, 1)]), (1, vec![(2, 1)])]);
+
+        assert_eq!(algorithm.run(None), Err(GraphError::Infeasible));
+        assert_eq!(algorithm.run(Some(3)), Err(GraphError::MissingStartNode));
+    }
+}
@@ -0,0 +1,176 @@
+use std::{cmp::Ordering, fmt::Debug, ops::Add};
+
+/// Numeric type of edge weights and distances, such as `usize`, `i64` or `f64`.
+pub trait Weight: Copy + Debug + PartialOrd + Add<Output = Self> + Send + Sync + 'static {
+    /// Weight of an empty path.
+    const ZERO: Self;
+
+    /// Distance of an unreachable node: the maximum of integers and infinity for floats.
+    const INFINITY: Self;
+
+    /// Add two weights, checking for overflow.
+    ///
+    /// # Arguments
+    ///
+    /// - `other`: Weight to add.
+    ///
+    /// # Returns
+    ///
+    /// Sum of the weights, or `None` if it overflows. Floats never overflow.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Add two weights, clamping at the bounds of the type.
+    ///
+    /// # Arguments
+    ///
+    /// - `other`: Weight to add.
+    ///
+    /// # Returns
+    ///
+    /// Sum of the weights.
+    fn saturating_add(self, other: Self) -> Self;
+
+    /// Compare two weights with a total order, so that floats can be kept in a priority queue.
+    ///
+    /// # Arguments
+    ///
+    /// - `other`: Weight to compare with.
+    ///
+    /// # Returns
+    ///
+    /// Ordering of the weights.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Convert the weight to the signed integers of the algorithm events, saturating.
+    ///
+    /// # Returns
+    ///
+    /// Weight as a signed 64-bit integer.
+    fn to_i64(self) -> i64;
+
+    /// Compute a percentage of the weight, such as the penalty of a soft constraint, saturating.
+    ///
+    /// # Arguments
+    ///
+    /// - `percent`: Percentage of the weight.
+    ///
+    /// # Returns
+    ///
+    /// `percent` percent of the weight, rounded down for integers.
+    fn percent(self, percent: usize) -> Self;
+
+    /// Map a non-negative weight to an unsigned integer of the same order, for radix heaps.
+    ///
+    /// # Returns
+    ///
+    /// Unsigned key increasing with the weight.
+    fn radix_key(self) -> u64;
+}
+
+macro_rules! integer_weight {
+    ($($t:ty),*) => {$(
+        impl Weight for $t {
+            const ZERO: Self = 0;
+            const INFINITY: Self = <$t>::MAX;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn saturating_add(self, other: Self) -> Self {
+                <$t>::saturating_add(self, other)
+            }
+
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                self.cmp(other)
+            }
+
+            fn to_i64(self) -> i64 {
+                i64::try_from(self).unwrap_or(i64::MAX)
+            }
+
+            fn percent(self, percent: usize) -> Self {
+                let percent = <$t>::try_from(percent).unwrap_or(<$t>::MAX);
+
+                // Split the weight so that large weights do not overflow before the division.
+                (self / 100)
+                    .saturating_mul(percent)
+                    .saturating_add((self % 100).saturating_mul(percent) / 100)
+            }
+
+            fn radix_key(self) -> u64 {
+                self as u64
+            }
+        }
+    )*};
+}
+
+macro_rules! float_weight {
+    ($($t:ty),*) => {$(
+        impl Weight for $t {
+            const ZERO: Self = 0.0;
+            const INFINITY: Self = <$t>::INFINITY;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                Some(self + other)
+            }
+
+            fn saturating_add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                <$t>::total_cmp(self, other)
+            }
+
+            fn to_i64(self) -> i64 {
+                self as i64
+            }
+
+            fn percent(self, percent: usize) -> Self {
+                self * percent as Self / 100.0
+            }
+
+            fn radix_key(self) -> u64 {
+                // Adding zero turns a negative zero into a positive one.
+                (self + 0.0).to_bits() as u64
+            }
+        }
+    )*};
+}
+
+integer_weight!(i32, i64, u32, u64, usize);
+float_weight!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_weight() {
+        assert_eq!(<i32 as Weight>::checked_add(i32::MAX, 1), None);
+        assert_eq!(<i64 as Weight>::saturating_add(i64::MIN, -1), i64::MIN);
+        assert_eq!(<u64 as Weight>::INFINITY, u64::MAX);
+        assert_eq!(Weight::total_cmp(&3usize, &4), Ordering::Less);
+        assert_eq!(u64::MAX.to_i64(), i64::MAX);
+        assert_eq!((-5i32).to_i64(), -5);
+        assert_eq!(250usize.percent(20), 50);
+        assert_eq!((usize::MAX / 2).percent(300), usize::MAX);
+        assert_eq!(7u32.radix_key(), 7);
+    }
+
+    #[test]
+    fn test_float_weight() {
+        assert_eq!(
+            <f64 as Weight>::checked_add(f64::MAX, f64::MAX),
+            Some(f64::INFINITY)
+        );
+        assert_eq!(<f32 as Weight>::INFINITY, f32::INFINITY);
+        assert_eq!(Weight::total_cmp(&1.5f64, &f64::NAN), Ordering::Less);
+        assert_eq!(2.7f64.to_i64(), 2);
+        assert_eq!(f64::INFINITY.to_i64(), i64::MAX);
+        assert_eq!(2.5f64.percent(20), 0.5);
+        assert_eq!((-0.0f32).radix_key(), 0);
+        assert!(1.5f64.radix_key() < 2.0f64.radix_key());
+    }
+}